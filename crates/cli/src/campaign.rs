@@ -0,0 +1,266 @@
+//! Campaign instructions: create a distributor, set its time and root, withdraw
+//!
+//! Instructions are built with the client crate and signed by the configured
+//! keypair, which must be the owner (create, withdraw) or an operator (set time
+//! and root) of the distributor. Mints with a transfer hook need extra accounts
+//! these commands do not resolve.
+
+use clap::{Args, ValueEnum};
+use serde_json::json;
+use solana_sdk::pubkey::Pubkey;
+use std::path::PathBuf;
+use token_distributor_client::accounts::{deserialize_account, AccountFetcher};
+use token_distributor_client::state::{ActivationType, NonceState};
+use token_distributor_client::{instruction, instructions, pda};
+
+use crate::rpc::Connection;
+use crate::{read_json, tree_files, Report, RpcArgs};
+
+/// Clock the distribution window is measured on
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Activation {
+    /// Unix timestamps
+    Timestamp,
+    /// Slots, immune to validator clock drift
+    Slot,
+}
+
+#[derive(Args)]
+pub struct CreateDistributorArgs {
+    #[command(flatten)]
+    rpc: RpcArgs,
+    /// Mint of the distributed token
+    #[arg(long)]
+    mint: Pubkey,
+    /// Raw amount of tokens deposited into the vault
+    #[arg(long)]
+    amount: u64,
+    /// Operator allowed to set time and root, the signer by default
+    #[arg(long)]
+    operator: Option<Pubkey>,
+    /// Token account the deposit is taken from, the signer's associated token account by default
+    #[arg(long)]
+    owner_token_account: Option<Pubkey>,
+    /// Length of the claim window in seconds (slots with --activation slot), the config default if unset
+    #[arg(long)]
+    duration: Option<i64>,
+    /// Account receiving unclaimed tokens on clawback
+    #[arg(long)]
+    clawback_receiver: Option<Pubkey>,
+    /// Merkle tree version, 1 (sha256) by default
+    #[arg(long)]
+    tree_version: Option<u8>,
+    /// Delay after the end before the owner can withdraw
+    #[arg(long)]
+    withdraw_delay: Option<i64>,
+    /// Clock of the distribution window
+    #[arg(long, value_enum)]
+    activation: Option<Activation>,
+    /// Nonce to create the distributor under, greater than every nonce the owner has used,
+    /// so its address is known in advance; the owner's next nonce by default
+    #[arg(long)]
+    nonce: Option<u32>,
+    /// Display name of the campaign shown by wallets and explorers, set with --uri
+    #[arg(long, requires = "uri")]
+    name: Option<String>,
+    /// URI of the campaign's off-chain metadata (recipient list, terms), set with --name
+    #[arg(long, requires = "name")]
+    uri: Option<String>,
+}
+
+#[derive(Args)]
+pub struct SetTimeArgs {
+    #[command(flatten)]
+    rpc: RpcArgs,
+    #[arg(long)]
+    distributor: Pubkey,
+    /// Start of the claim window, a Unix timestamp (a slot for slot-activated distributors)
+    #[arg(long)]
+    start_time: i64,
+}
+
+#[derive(Args)]
+pub struct SetRootArgs {
+    #[command(flatten)]
+    rpc: RpcArgs,
+    #[arg(long)]
+    distributor: Pubkey,
+    /// Directory written by build-tree for this distributor
+    #[arg(long)]
+    out: PathBuf,
+}
+
+#[derive(Args)]
+pub struct WithdrawArgs {
+    #[command(flatten)]
+    rpc: RpcArgs,
+    #[arg(long)]
+    distributor: Pubkey,
+    /// Token account receiving the remaining tokens, the signer's associated token account by default
+    #[arg(long)]
+    owner_token_account: Option<Pubkey>,
+}
+
+/// Report of a sent transaction, with the addresses it concerns
+fn sent(action: &str, signature: String, mut json: serde_json::Value) -> Report {
+    let mut text = format!("{}\n", action);
+    for (name, value) in json.as_object().into_iter().flatten() {
+        text += &format!("{}: {}\n", name.replace('_', " "), value.as_str().map_or(value.to_string(), str::to_string));
+    }
+    text += &format!("signature: {}\n", signature);
+    json["signature"] = json!(signature);
+    Report { text, json }
+}
+
+pub fn create_distributor(args: CreateDistributorArgs) -> Result<Report, String> {
+    let connection = Connection::new(&args.rpc)?;
+    let owner = connection.signer_key();
+    let token_program = connection.token_program(&args.mint)?;
+    let owner_token_account = args
+        .owner_token_account
+        .unwrap_or_else(|| connection.signer_token_account(&args.mint, &token_program));
+
+    // create_distributor uses the owner's next nonce, create_distributor_with_nonce the given one
+    let (owner_nonce, _) = pda::derive_owner_nonce(&owner);
+    let next_nonce = match connection.fetch_account_data(&owner_nonce).map_err(|err| err.to_string())? {
+        Some(data) => deserialize_account::<NonceState>(&data).map_err(|err| err.to_string())?.nonce + 1,
+        None => 1,
+    };
+    let nonce = args.nonce.unwrap_or(next_nonce);
+    if nonce < next_nonce {
+        return Err(format!("nonce {} is already used, the owner's next nonce is {}", nonce, next_nonce));
+    }
+    let (distributor, _) = pda::derive_distributor(&args.mint, &owner, nonce);
+
+    let operator = args.operator.unwrap_or(owner);
+    let activation_type = args.activation.map(|activation| match activation {
+        Activation::Timestamp => ActivationType::Timestamp,
+        Activation::Slot => ActivationType::Slot,
+    });
+    let ix = match args.nonce {
+        Some(nonce) => instructions::create_distributor_with_nonce(
+            &owner,
+            &operator,
+            &args.mint,
+            &owner_token_account,
+            &token_program,
+            instruction::CreateDistributorWithNonce {
+                nonce,
+                initial_total_amount: args.amount,
+                duration: args.duration,
+                clawback_receiver: args.clawback_receiver,
+                tree_version: args.tree_version,
+                withdraw_delay: args.withdraw_delay,
+                activation_type,
+            },
+        ),
+        None => instructions::create_distributor(
+            &owner,
+            &operator,
+            &args.mint,
+            &owner_token_account,
+            &token_program,
+            nonce,
+            instruction::CreateDistributor {
+                initial_total_amount: args.amount,
+                duration: args.duration,
+                clawback_receiver: args.clawback_receiver,
+                tree_version: args.tree_version,
+                withdraw_delay: args.withdraw_delay,
+                activation_type,
+            },
+        ),
+    };
+    let mut ixs = vec![ix];
+    // Metadata is set in the creating transaction so the campaign is never shown unnamed
+    if let (Some(name), Some(uri)) = (args.name, args.uri) {
+        ixs.push(instructions::set_distributor_metadata(&distributor, &owner, name, uri));
+    }
+    let signature = connection.send(&ixs)?;
+    Ok(sent(
+        "created distributor",
+        signature.to_string(),
+        json!({
+            "distributor": distributor.to_string(),
+            "vault": pda::derive_vault(&distributor).0.to_string(),
+            "nonce": nonce,
+        }),
+    ))
+}
+
+pub fn set_time(args: SetTimeArgs) -> Result<Report, String> {
+    let connection = Connection::new(&args.rpc)?;
+    let ix = instructions::set_time(&args.distributor, &connection.signer_key(), args.start_time);
+    let signature = connection.send(&[ix])?;
+    let distributor = connection.distributor(&args.distributor)?;
+    Ok(sent(
+        "set time",
+        signature.to_string(),
+        json!({
+            "distributor": args.distributor.to_string(),
+            "start_time": distributor.start_time,
+            "end_time": distributor.end_time,
+        }),
+    ))
+}
+
+pub fn set_root(args: SetRootArgs) -> Result<Report, String> {
+    // The tree must have been built for this distributor, leaves are bound to it
+    let summary = read_json(&args.out.join(tree_files::TREE_FILE))?;
+    if summary["distributor"].as_str() != Some(args.distributor.to_string().as_str()) {
+        return Err(format!(
+            "{} was built for distributor {}, not {}",
+            args.out.display(),
+            summary["distributor"],
+            args.distributor
+        ));
+    }
+    let root: [u8; 32] = serde_json::from_value(summary["root"].clone())
+        .map_err(|err| format!("invalid root in {}: {}", args.out.display(), err))?;
+
+    let connection = Connection::new(&args.rpc)?;
+    let distributor = connection.distributor(&args.distributor)?;
+    if summary["tree_version"].as_u64() != Some(distributor.tree_version as u64) {
+        return Err(format!(
+            "tree version {} does not match the distributor's tree version {}",
+            summary["tree_version"], distributor.tree_version
+        ));
+    }
+    let ix = instructions::set_merkle_root(&args.distributor, &connection.signer_key(), root);
+    let signature = connection.send(&[ix])?;
+    Ok(sent(
+        "set merkle root",
+        signature.to_string(),
+        json!({
+            "distributor": args.distributor.to_string(),
+            "root_hex": tree_files::to_hex(&root),
+            "entries": summary["entries"],
+        }),
+    ))
+}
+
+pub fn withdraw(args: WithdrawArgs) -> Result<Report, String> {
+    let connection = Connection::new(&args.rpc)?;
+    let distributor = connection.distributor(&args.distributor)?;
+    let token_program = connection.token_program(&distributor.token_mint)?;
+    let owner_token_account = args
+        .owner_token_account
+        .unwrap_or_else(|| connection.signer_token_account(&distributor.token_mint, &token_program));
+    let ix = instructions::withdraw(
+        &args.distributor,
+        &connection.signer_key(),
+        &distributor.token_mint,
+        &owner_token_account,
+        &token_program,
+    );
+    let signature = connection.send(&[ix])?;
+    Ok(sent(
+        "withdrew and closed distributor",
+        signature.to_string(),
+        json!({
+            "distributor": args.distributor.to_string(),
+            "owner_token_account": owner_token_account.to_string(),
+            "unclaimed": distributor.initial_total_amount.saturating_sub(distributor.total_claimed),
+        }),
+    ))
+}
@@ -0,0 +1,227 @@
+//! Command line tooling for token distributor campaigns
+//!
+//! Builds merkle trees and per-claimant proof files with the same hashing as
+//! the program, so operators no longer hand-encode roots and proofs, and runs
+//! a campaign on chain: create the distributor, set its time and root, monitor
+//! claims from the program's events and withdraw what is left.
+//!
+//! Every command takes `--output text|json`. JSON output is a single line object on
+//! stdout, including errors (`{ "error": ... }` with a failing exit code), so
+//! launch pipelines and CI jobs can consume results without scraping logs.
+//! `monitor` prints one such line per claim before its summary.
+
+mod campaign;
+mod monitor;
+mod rpc;
+mod tree_files;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use merkle_distributor_tree::{entries_from_csv, entries_from_json, MerkleTree};
+use serde_json::{json, Value};
+use solana_sdk::pubkey::Pubkey;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+/// RPC URL used when --url is not given, a local test validator
+pub const DEFAULT_URL: &str = "http://127.0.0.1:8899";
+
+#[derive(Parser)]
+#[command(name = "token-distributor", version, about = "Command line tooling for token distributor campaigns")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Print results as text or as a single JSON object, errors included, for scripts and CI jobs
+    #[arg(long, global = true, value_enum, default_value_t = Output::Text)]
+    output: Output,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Build the merkle tree of a distributor and write tree.json and one
+    /// proof file per claimant to <OUT>/proofs/<claimant>.json
+    BuildTree {
+        /// Allocations, CSV lines `claimant,amount[,unlock_time]` or a JSON
+        /// array of `{ claimant, amount, unlock_time? }` objects (by extension)
+        #[arg(long)]
+        input: PathBuf,
+        /// Distributor the tree is built for, leaves are bound to it
+        #[arg(long)]
+        distributor: Pubkey,
+        /// Output directory
+        #[arg(long)]
+        out: PathBuf,
+    },
+    /// Print a claimant's proof file from a directory written by build-tree
+    Proof {
+        /// Directory written by build-tree
+        #[arg(long)]
+        out: PathBuf,
+        #[arg(long)]
+        claimant: Pubkey,
+    },
+    /// Print the root of a directory written by build-tree
+    Root {
+        /// Directory written by build-tree
+        #[arg(long)]
+        out: PathBuf,
+    },
+    /// Create a distributor at the owner's next nonce and deposit its tokens
+    CreateDistributor(campaign::CreateDistributorArgs),
+    /// Set the start time of a distributor, signed by an operator
+    SetTime(campaign::SetTimeArgs),
+    /// Set the merkle root of a distributor from a directory written by build-tree, signed by an operator
+    SetRoot(campaign::SetRootArgs),
+    /// Print the claims of a distributor, decoded from the program's events
+    Monitor(monitor::MonitorArgs),
+    /// Withdraw the remaining tokens and close the distributor once it ended, signed by the owner
+    Withdraw(campaign::WithdrawArgs),
+}
+
+/// Connection options of the commands sending transactions or reading accounts
+#[derive(Args)]
+pub struct RpcArgs {
+    /// RPC URL of the cluster
+    #[arg(long, short = 'u', default_value = DEFAULT_URL)]
+    pub url: String,
+    /// Keypair file of the signer, which also pays transaction fees
+    #[arg(long, short = 'k', default_value = "~/.config/solana/id.json")]
+    pub keypair: String,
+}
+
+/// Output format selected with --output
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+pub enum Output {
+    Text,
+    Json,
+}
+
+impl Output {
+    /// Output format of the raw arguments, so errors parsing them are formatted too
+    fn from_args(args: &[String]) -> Self {
+        match args.windows(2).find(|pair| pair[0] == "--output") {
+            Some(pair) if pair[1] == "json" => Output::Json,
+            _ => Output::Text,
+        }
+    }
+
+    /// Prints a report of a command, or one item of a streaming command
+    pub fn print(self, report: &Report) {
+        match self {
+            Output::Text => print!("{}", report.text),
+            Output::Json => println!("{}", report.json),
+        }
+    }
+}
+
+/// Result of a command, printed as text or JSON depending on --output
+pub struct Report {
+    pub text: String,
+    pub json: Value,
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("cannot read {}: {}", path.display(), err))
+}
+
+fn read_json(path: &Path) -> Result<Value, String> {
+    serde_json::from_str(&read(path)?).map_err(|err| format!("invalid JSON in {}: {}", path.display(), err))
+}
+
+/// Text lines of a tree summary
+fn summary_text(summary: &Value) -> String {
+    format!(
+        "distributor: {}\ntree version: {}\nroot: {}\nentries: {}\n",
+        summary["distributor"].as_str().unwrap_or_default(),
+        summary["tree_version"],
+        summary["root_hex"].as_str().unwrap_or_default(),
+        summary["entries"],
+    )
+}
+
+fn build_tree(input: &Path, distributor: &Pubkey, out_dir: &Path) -> Result<Report, String> {
+    let contents = read(input)?;
+    let entries = match input.extension().and_then(|extension| extension.to_str()) {
+        Some("json") => entries_from_json(&contents),
+        _ => entries_from_csv(&contents),
+    }
+    .map_err(|err| format!("{}: {}", input.display(), err))?;
+    let tree = MerkleTree::new(distributor, entries).map_err(|err| err.to_string())?;
+
+    tree_files::write_tree(out_dir, distributor, &tree)?;
+    let mut summary = tree_files::tree_summary(distributor, &tree);
+    let text = format!("wrote {} proof files to {}\n{}", tree.len(), out_dir.display(), summary_text(&summary));
+    summary["out"] = json!(out_dir.display().to_string());
+    Ok(Report { text, json: summary })
+}
+
+fn proof(out_dir: &Path, claimant: &Pubkey) -> Result<Report, String> {
+    let file = read_json(&tree_files::proof_path(out_dir, claimant))?;
+
+    let mut text = format!(
+        "claimant: {}\namount: {}\n",
+        file["claimant"].as_str().unwrap_or_default(),
+        file["amount"].as_str().unwrap_or_default(),
+    );
+    if let Some(unlock_time) = file["unlock_time"].as_str() {
+        text += &format!("unlock time: {}\n", unlock_time);
+    }
+    text += "proof:\n";
+    for node in file["proof"].as_array().into_iter().flatten() {
+        let node: Vec<u8> = serde_json::from_value(node.clone()).unwrap_or_default();
+        text += &format!("  {}\n", tree_files::to_hex(&node));
+    }
+    Ok(Report { text, json: file })
+}
+
+fn root(out_dir: &Path) -> Result<Report, String> {
+    let summary = read_json(&out_dir.join(tree_files::TREE_FILE))?;
+    Ok(Report { text: summary_text(&summary), json: summary })
+}
+
+fn run(command: Command, output: Output) -> Result<Report, String> {
+    match command {
+        Command::BuildTree { input, distributor, out } => build_tree(&input, &distributor, &out),
+        Command::Proof { out, claimant } => proof(&out, &claimant),
+        Command::Root { out } => root(&out),
+        Command::CreateDistributor(args) => campaign::create_distributor(args),
+        Command::SetTime(args) => campaign::set_time(args),
+        Command::SetRoot(args) => campaign::set_root(args),
+        Command::Monitor(args) => monitor::monitor(args, output),
+        Command::Withdraw(args) => campaign::withdraw(args),
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = match Cli::try_parse() {
+        Ok(cli) => cli,
+        Err(err) if !err.use_stderr() => err.exit(),
+        Err(err) => {
+            let args: Vec<String> = std::env::args().collect();
+            if Output::from_args(&args) == Output::Json {
+                // The error itself, without clap's usage and help hints
+                let rendered = err.render().to_string();
+                let message = rendered.split("\n\n").next().unwrap_or_default().trim_start_matches("error: ");
+                println!("{}", json!({ "error": message.split_whitespace().collect::<Vec<_>>().join(" ") }));
+                return ExitCode::FAILURE;
+            }
+            err.exit()
+        }
+    };
+
+    match run(cli.command, cli.output) {
+        Ok(report) => {
+            cli.output.print(&report);
+            ExitCode::SUCCESS
+        }
+        Err(err) if cli.output == Output::Text => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+        Err(err) => {
+            println!("{}", json!({ "error": err }));
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,265 @@
+//! Claim monitoring from the program's events
+//!
+//! Events are emitted through self-CPIs, so claims are read from the inner
+//! instructions of the transactions touching the distributor, oldest first,
+//! and decoded with the client crate.
+
+use clap::Args;
+use serde_json::json;
+use solana_client::rpc_client::{GetConfirmedSignaturesForAddress2Config, RpcClient};
+use solana_client::rpc_config::RpcTransactionConfig;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_transaction_status_client_types::{
+    EncodedConfirmedTransactionWithStatusMeta, UiInstruction, UiLoadedAddresses, UiTransactionEncoding,
+};
+use std::str::FromStr;
+use std::thread::sleep;
+use std::time::Duration;
+use token_distributor_client::event::TokensClaimed;
+use token_distributor_client::events::{decode_cpi_event, TokenDistributorEvent};
+use token_distributor_client::ID;
+
+use crate::{Output, Report, DEFAULT_URL};
+
+/// Signatures requested per getSignaturesForAddress call, the RPC maximum
+const SIGNATURES_PAGE: usize = 1000;
+
+#[derive(Args)]
+pub struct MonitorArgs {
+    /// RPC URL of the cluster
+    #[arg(long, short = 'u', default_value = DEFAULT_URL)]
+    url: String,
+    #[arg(long)]
+    distributor: Pubkey,
+    /// Keep polling for new claims instead of exiting after the existing ones
+    #[arg(long)]
+    follow: bool,
+    /// Seconds between polls with --follow
+    #[arg(long, default_value_t = 5)]
+    poll_interval: u64,
+}
+
+/// A claim decoded from a transaction
+struct Claim {
+    signature: Signature,
+    slot: u64,
+    block_time: Option<i64>,
+    event: TokensClaimed,
+}
+
+impl Claim {
+    fn report(&self) -> Report {
+        let event = &self.event;
+        Report {
+            text: format!(
+                "{} {} claimed {} of {} (protocol fee {}) in {}\n",
+                self.slot,
+                event.claimant,
+                event.user_amount_claimed,
+                event.user_max_amount,
+                event.protocol_fee,
+                self.signature,
+            ),
+            json: json!({
+                "event": "TokensClaimed",
+                "signature": self.signature.to_string(),
+                "slot": self.slot,
+                "block_time": self.block_time,
+                "claimant": event.claimant.to_string(),
+                "amount": event.user_amount_claimed.to_string(),
+                "max_amount": event.user_max_amount.to_string(),
+                "total_claimed": event.total_claimed.to_string(),
+                "protocol_fee": event.protocol_fee.to_string(),
+                "phase": event.phase,
+                "bonus_amount": event.bonus_amount.to_string(),
+            }),
+        }
+    }
+}
+
+/// Successful transactions touching the address after `until`, oldest first
+fn signatures_since(
+    client: &RpcClient,
+    address: &Pubkey,
+    until: Option<Signature>,
+) -> Result<Vec<Signature>, String> {
+    let mut signatures = Vec::new();
+    let mut before = None;
+    loop {
+        let config = GetConfirmedSignaturesForAddress2Config {
+            before,
+            until,
+            limit: Some(SIGNATURES_PAGE),
+            commitment: Some(client.commitment()),
+        };
+        let page = client
+            .get_signatures_for_address_with_config(address, config)
+            .map_err(|err| format!("cannot fetch signatures of {}: {}", address, err))?;
+        let last = page.len() < SIGNATURES_PAGE;
+        for status in page {
+            let signature = Signature::from_str(&status.signature).map_err(|err| err.to_string())?;
+            before = Some(signature);
+            if status.err.is_none() {
+                signatures.push(signature);
+            }
+        }
+        if last {
+            break;
+        }
+    }
+    signatures.reverse();
+    Ok(signatures)
+}
+
+/// Fetches a transaction with the inner instructions events are read from
+fn fetch_transaction(client: &RpcClient, signature: &Signature) -> Result<EncodedConfirmedTransactionWithStatusMeta, String> {
+    let config = RpcTransactionConfig {
+        encoding: Some(UiTransactionEncoding::Base64),
+        commitment: Some(client.commitment()),
+        max_supported_transaction_version: Some(0),
+    };
+    client
+        .get_transaction_with_config(signature, config)
+        .map_err(|err| format!("cannot fetch transaction {}: {}", signature, err))
+}
+
+/// Claims from the distributor in a transaction, decoded from its inner instructions
+fn transaction_claims(
+    distributor: &Pubkey,
+    signature: &Signature,
+    confirmed: EncodedConfirmedTransactionWithStatusMeta,
+) -> Result<Vec<Claim>, String> {
+    let transaction = confirmed
+        .transaction
+        .transaction
+        .decode()
+        .ok_or_else(|| format!("cannot decode transaction {}", signature))?;
+    let Some(meta) = confirmed.transaction.meta else {
+        return Ok(Vec::new());
+    };
+
+    // Inner instructions index the static keys followed by the keys loaded from lookup tables
+    let mut keys = transaction.message.static_account_keys().to_vec();
+    let loaded: Option<UiLoadedAddresses> = meta.loaded_addresses.into();
+    if let Some(loaded) = loaded {
+        for key in loaded.writable.iter().chain(&loaded.readonly) {
+            keys.push(Pubkey::from_str(key).map_err(|err| err.to_string())?);
+        }
+    }
+
+    let mut claims = Vec::new();
+    let inner_instructions: Option<Vec<_>> = meta.inner_instructions.into();
+    for instruction in inner_instructions.into_iter().flatten().flat_map(|inner| inner.instructions) {
+        let UiInstruction::Compiled(instruction) = instruction else {
+            continue;
+        };
+        if keys.get(instruction.program_id_index as usize) != Some(&ID) {
+            continue;
+        }
+        let data = bs58::decode(&instruction.data).into_vec().map_err(|err| err.to_string())?;
+        if let Some(TokenDistributorEvent::TokensClaimed(event)) = decode_cpi_event(&data).map_err(|err| err.to_string())? {
+            if event.distributor == *distributor {
+                claims.push(Claim { signature: *signature, slot: confirmed.slot, block_time: confirmed.block_time, event });
+            }
+        }
+    }
+    Ok(claims)
+}
+
+/// Prints every claim of the distributor as it is decoded, then a summary
+pub fn monitor(args: MonitorArgs, output: Output) -> Result<Report, String> {
+    let client = RpcClient::new_with_commitment(args.url, CommitmentConfig::confirmed());
+    let (mut count, mut claimed) = (0u64, 0u128);
+    let mut newest = None;
+    loop {
+        for signature in signatures_since(&client, &args.distributor, newest)? {
+            for claim in transaction_claims(&args.distributor, &signature, fetch_transaction(&client, &signature)?)? {
+                output.print(&claim.report());
+                count += 1;
+                claimed += claim.event.user_amount_claimed as u128;
+            }
+            newest = Some(signature);
+        }
+        if !args.follow {
+            break;
+        }
+        sleep(Duration::from_secs(args.poll_interval));
+    }
+    Ok(Report {
+        text: format!("{} claims, {} claimed\n", count, claimed),
+        json: json!({ "distributor": args.distributor.to_string(), "claims": count, "claimed": claimed.to_string() }),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::event::EVENT_IX_TAG_LE;
+    use anchor_lang::Event;
+    use solana_sdk::message::{Message, VersionedMessage};
+    use solana_sdk::transaction::VersionedTransaction;
+    use token_distributor_client::event::{AmountScaling, ClaimFrozen};
+
+    fn event_data(event: &impl Event) -> String {
+        let mut data = EVENT_IX_TAG_LE.to_vec();
+        data.extend(event.data());
+        bs58::encode(data).into_string()
+    }
+
+    fn claimed(distributor: Pubkey, amount: u64) -> TokensClaimed {
+        TokensClaimed {
+            distributor,
+            claimant: Pubkey::new_unique(),
+            user_amount_claimed: amount,
+            user_max_amount: amount,
+            total_claimed: amount,
+            protocol_fee: 0,
+            scaling: AmountScaling::default(),
+            phase: 0,
+            bonus_amount: 0,
+        }
+    }
+
+    #[test]
+    fn test_claims_decoded_from_inner_instructions() {
+        let (payer, distributor, other) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let message = Message::new_with_compiled_instructions(1, 0, 1, vec![payer, distributor, ID], Default::default(), vec![]);
+        let transaction = VersionedTransaction {
+            signatures: vec![Signature::default()],
+            message: VersionedMessage::Legacy(message),
+        };
+        let claim = claimed(distributor, 42);
+        let inner = |program_id_index: u8, data: String| json!({ "programIdIndex": program_id_index, "accounts": [], "data": data, "stackHeight": 2 });
+        let confirmed: EncodedConfirmedTransactionWithStatusMeta = serde_json::from_value(json!({
+            "slot": 7,
+            "blockTime": 1_700_000_000,
+            "transaction": bs58::encode(bincode::serialize(&transaction).unwrap()).into_string(),
+            "meta": {
+                "err": null,
+                "status": { "Ok": null },
+                "fee": 5000,
+                "preBalances": [],
+                "postBalances": [],
+                "innerInstructions": [{
+                    "index": 0,
+                    "instructions": [
+                        inner(2, event_data(&claim)),
+                        // Other events, other distributors and other programs are skipped
+                        inner(2, event_data(&ClaimFrozen { distributor, claimant: claim.claimant, unfreeze_after: 0 })),
+                        inner(2, event_data(&claimed(other, 1))),
+                        inner(1, event_data(&claimed(distributor, 1))),
+                    ],
+                }],
+            },
+        }))
+        .unwrap();
+
+        let claims = transaction_claims(&distributor, &Signature::default(), confirmed).unwrap();
+        assert_eq!(claims.len(), 1);
+        assert_eq!((claims[0].slot, claims[0].block_time), (7, Some(1_700_000_000)));
+        assert_eq!(claims[0].event.claimant, claim.claimant);
+        assert_eq!(claims[0].report().json["amount"], "42");
+    }
+}
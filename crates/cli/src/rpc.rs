@@ -0,0 +1,83 @@
+//! Connection to a cluster: account reads through the client crate's fetcher
+//! interface and transactions signed by the configured keypair
+
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{read_keypair_file, Keypair, Signature, Signer};
+use solana_sdk::transaction::Transaction;
+use std::path::PathBuf;
+use token_distributor_client::accounts::{fetch_distributor, AccountFetcher};
+use token_distributor_client::state::TokenDistributor;
+use token_distributor_client::ClientError;
+
+use crate::RpcArgs;
+
+/// RPC client and the keypair signing and paying for transactions
+pub struct Connection {
+    pub client: RpcClient,
+    pub signer: Keypair,
+}
+
+/// Expands a leading `~` to the home directory, as the Solana CLI does
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), std::env::var_os("HOME")) {
+        (Some(rest), Some(home)) => PathBuf::from(home).join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+impl Connection {
+    pub fn new(args: &RpcArgs) -> Result<Self, String> {
+        let path = expand_home(&args.keypair);
+        let signer =
+            read_keypair_file(&path).map_err(|err| format!("cannot read keypair {}: {}", path.display(), err))?;
+        let client = RpcClient::new_with_commitment(args.url.clone(), CommitmentConfig::confirmed());
+        Ok(Connection { client, signer })
+    }
+
+    pub fn signer_key(&self) -> Pubkey {
+        self.signer.pubkey()
+    }
+
+    /// Signs the instructions with the configured keypair and waits for their confirmation
+    pub fn send(&self, instructions: &[Instruction]) -> Result<Signature, String> {
+        let blockhash = self.client.get_latest_blockhash().map_err(|err| err.to_string())?;
+        let transaction =
+            Transaction::new_signed_with_payer(instructions, Some(&self.signer_key()), &[&self.signer], blockhash);
+        self.client
+            .send_and_confirm_transaction(&transaction)
+            .map_err(|err| format!("transaction failed: {}", err))
+    }
+
+    /// Fetches a distributor, failing if it does not exist
+    pub fn distributor(&self, distributor: &Pubkey) -> Result<TokenDistributor, String> {
+        fetch_distributor(self, distributor)
+            .map_err(|err| err.to_string())?
+            .ok_or_else(|| format!("distributor {} not found", distributor))
+    }
+
+    /// Token program owning a mint, SPL Token or Token 2022
+    pub fn token_program(&self, mint: &Pubkey) -> Result<Pubkey, String> {
+        self.client
+            .get_account(mint)
+            .map(|account| account.owner)
+            .map_err(|err| format!("cannot fetch mint {}: {}", mint, err))
+    }
+
+    /// The signer's associated token account of a mint
+    pub fn signer_token_account(&self, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+        get_associated_token_address_with_program_id(&self.signer_key(), mint, token_program)
+    }
+}
+
+impl AccountFetcher for Connection {
+    fn fetch_account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>, ClientError> {
+        self.client
+            .get_account_with_commitment(address, self.client.commitment())
+            .map(|response| response.value.map(|account| account.data))
+            .map_err(|err| ClientError::Fetch(err.to_string()))
+    }
+}
@@ -0,0 +1,101 @@
+//! Tree and proof files written for a campaign
+//!
+//! Layout of an output directory:
+//! - tree.json: distributor, tree version, root and entry count
+//! - proofs/<claimant>.json: one file per claimant, ready to pass to `claim`
+//!
+//! Amounts are written as decimal strings so JavaScript clients do not lose
+//! precision, roots and proof nodes as arrays of 32 bytes, the encoding the
+//! Anchor client expects for `[u8; 32]` arguments.
+
+use merkle_distributor_tree::{MerkleTree, TreeEntry};
+use serde_json::{json, Value};
+use solana_sdk::pubkey::Pubkey;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const TREE_FILE: &str = "tree.json";
+pub const PROOFS_DIR: &str = "proofs";
+
+/// Lowercase hex encoding of bytes
+pub fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Summary of a tree, written to tree.json
+pub fn tree_summary(distributor: &Pubkey, tree: &MerkleTree) -> Value {
+    let root = tree.get_root();
+    json!({
+        "distributor": distributor.to_string(),
+        "tree_version": tree.tree_version(),
+        "root": root.to_vec(),
+        "root_hex": to_hex(&root),
+        "entries": tree.len(),
+    })
+}
+
+/// Proof file of one claimant
+pub fn proof_file(distributor: &Pubkey, entry: &TreeEntry, proof: &[[u8; 32]]) -> Value {
+    json!({
+        "distributor": distributor.to_string(),
+        "claimant": entry.claimant.to_string(),
+        "amount": entry.amount.to_string(),
+        "unlock_time": entry.unlock_time.map(|unlock_time| unlock_time.to_string()),
+        "proof": proof.iter().map(|node| node.to_vec()).collect::<Vec<_>>(),
+    })
+}
+
+/// Path of a claimant's proof file in an output directory
+pub fn proof_path(out_dir: &Path, claimant: &Pubkey) -> PathBuf {
+    out_dir.join(PROOFS_DIR).join(format!("{}.json", claimant))
+}
+
+/// Write tree.json and every claimant's proof file
+pub fn write_tree(out_dir: &Path, distributor: &Pubkey, tree: &MerkleTree) -> Result<(), String> {
+    let write_json = |path: PathBuf, value: Value| {
+        fs::write(&path, serde_json::to_string_pretty(&value).unwrap() + "\n")
+            .map_err(|err| format!("cannot write {}: {}", path.display(), err))
+    };
+
+    fs::create_dir_all(out_dir.join(PROOFS_DIR))
+        .map_err(|err| format!("cannot create {}: {}", out_dir.display(), err))?;
+    write_json(out_dir.join(TREE_FILE), tree_summary(distributor, tree))?;
+    for (index, entry) in tree.entries().iter().enumerate() {
+        let proof = tree.get_proof(index).expect("entry index is in bounds");
+        write_json(proof_path(out_dir, &entry.claimant), proof_file(distributor, entry, &proof))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use merkle_distributor_tree::verify;
+
+    fn bytes32(value: &Value) -> [u8; 32] {
+        let bytes: Vec<u8> = serde_json::from_value(value.clone()).unwrap();
+        bytes.try_into().unwrap()
+    }
+
+    #[test]
+    fn test_proof_files_verify_against_root() {
+        let distributor = Pubkey::new_unique();
+        let entries: Vec<TreeEntry> = (0..5)
+            .map(|i| TreeEntry {
+                claimant: Pubkey::new_unique(),
+                amount: u64::MAX - i,
+                unlock_time: None,
+            })
+            .collect();
+        let tree = MerkleTree::new(&distributor, entries).unwrap();
+        let root = bytes32(&tree_summary(&distributor, &tree)["root"]);
+
+        for (index, entry) in tree.entries().iter().enumerate() {
+            let file = proof_file(&distributor, entry, &tree.get_proof(index).unwrap());
+            // Amounts survive as exact decimal strings
+            assert_eq!(file["amount"], (u64::MAX - index as u64).to_string());
+            let proof: Vec<[u8; 32]> = file["proof"].as_array().unwrap().iter().map(bytes32).collect();
+            assert!(verify(&proof, root, entry.leaf(&distributor)));
+        }
+    }
+}
@@ -0,0 +1,145 @@
+//! Account fetching and deserialization
+
+use std::collections::HashMap;
+use std::mem::size_of;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AccountDeserialize, Discriminator};
+use token_distributor::state::{
+    ClaimEscrow, ClaimStatus, DistributorMetadata, DistributorMint, MintRegistry, NonceState, ProgramConfig,
+    TokenDistributor,
+};
+
+use crate::pda::{
+    derive_claim_escrow, derive_claim_status, derive_config, derive_distributor_mint, derive_metadata,
+    derive_owner_nonce, derive_registry,
+};
+use crate::ClientError;
+
+/// Source of raw account data, e.g. an RPC client or an indexer's account store
+pub trait AccountFetcher {
+    /// Returns the data of the account, or `None` if it does not exist
+    fn fetch_account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>, ClientError>;
+}
+
+impl AccountFetcher for HashMap<Pubkey, Vec<u8>> {
+    fn fetch_account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>, ClientError> {
+        Ok(self.get(address).cloned())
+    }
+}
+
+/// Deserializes a zero-copy `TokenDistributor` account
+pub fn deserialize_distributor(data: &[u8]) -> Result<TokenDistributor, ClientError> {
+    let discriminator = TokenDistributor::DISCRIMINATOR;
+    if !data.starts_with(discriminator) {
+        return Err(ClientError::InvalidDiscriminator);
+    }
+    let body = data
+        .get(discriminator.len()..discriminator.len() + size_of::<TokenDistributor>())
+        .ok_or(ClientError::AccountDataTooSmall)?;
+    bytemuck::try_pod_read_unaligned(body).map_err(|e| ClientError::Deserialize(e.to_string()))
+}
+
+/// Deserializes a Borsh account of the program (`ClaimStatus`, `ProgramConfig`, ...)
+pub fn deserialize_account<T: AccountDeserialize>(data: &[u8]) -> Result<T, ClientError> {
+    T::try_deserialize(&mut &data[..]).map_err(|e| match e {
+        anchor_lang::error::Error::AnchorError(e)
+            if e.error_code_number == anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch as u32
+                || e.error_code_number == anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound as u32 =>
+        {
+            ClientError::InvalidDiscriminator
+        }
+        e => ClientError::Deserialize(e.to_string()),
+    })
+}
+
+/// Fetches a distributor, `None` if it does not exist (e.g. after withdraw closed it)
+pub fn fetch_distributor(
+    fetcher: &impl AccountFetcher,
+    distributor: &Pubkey,
+) -> Result<Option<TokenDistributor>, ClientError> {
+    fetcher
+        .fetch_account_data(distributor)?
+        .map(|data| deserialize_distributor(&data))
+        .transpose()
+}
+
+/// Fetches a claimant's claim status, `None` if they have not claimed yet
+pub fn fetch_claim_status(
+    fetcher: &impl AccountFetcher,
+    distributor: &Pubkey,
+    claimant: &Pubkey,
+) -> Result<Option<ClaimStatus>, ClientError> {
+    let (claim_status, _) = derive_claim_status(distributor, claimant);
+    fetcher
+        .fetch_account_data(&claim_status)?
+        .map(|data| deserialize_account(&data))
+        .transpose()
+}
+
+/// Fetches a distributor's metadata, `None` if the owner has not set any
+pub fn fetch_metadata(
+    fetcher: &impl AccountFetcher,
+    distributor: &Pubkey,
+) -> Result<Option<DistributorMetadata>, ClientError> {
+    let (metadata, _) = derive_metadata(distributor);
+    fetcher
+        .fetch_account_data(&metadata)?
+        .map(|data| deserialize_account(&data))
+        .transpose()
+}
+
+/// Fetches a claimant's claim escrow, `None` if the distributor is not escrowed or they have not claimed yet
+pub fn fetch_claim_escrow(
+    fetcher: &impl AccountFetcher,
+    distributor: &Pubkey,
+    claimant: &Pubkey,
+) -> Result<Option<ClaimEscrow>, ClientError> {
+    let (claim_escrow, _) = derive_claim_escrow(distributor, claimant);
+    fetcher
+        .fetch_account_data(&claim_escrow)?
+        .map(|data| deserialize_account(&data))
+        .transpose()
+}
+
+/// Fetches the program config, `None` before `initialize_config`
+pub fn fetch_config(fetcher: &impl AccountFetcher) -> Result<Option<ProgramConfig>, ClientError> {
+    let (config, _) = derive_config();
+    fetcher
+        .fetch_account_data(&config)?
+        .map(|data| deserialize_account(&data))
+        .transpose()
+}
+
+/// Fetches an owner's nonce state, `None` before their first distributor
+///
+/// The owner's next distributor is derived with `nonce + 1`
+pub fn fetch_owner_nonce(fetcher: &impl AccountFetcher, owner: &Pubkey) -> Result<Option<NonceState>, ClientError> {
+    let (owner_nonce, _) = derive_owner_nonce(owner);
+    fetcher
+        .fetch_account_data(&owner_nonce)?
+        .map(|data| deserialize_account(&data))
+        .transpose()
+}
+
+/// Fetches an extra mint of a multi-mint distributor, `None` if it was not added or has been withdrawn
+pub fn fetch_distributor_mint(
+    fetcher: &impl AccountFetcher,
+    distributor: &Pubkey,
+    token_mint: &Pubkey,
+) -> Result<Option<DistributorMint>, ClientError> {
+    let (distributor_mint, _) = derive_distributor_mint(distributor, token_mint);
+    fetcher
+        .fetch_account_data(&distributor_mint)?
+        .map(|data| deserialize_account(&data))
+        .transpose()
+}
+
+/// Fetches the registry of a mint's distributors, `None` until a distributor of the mint is synced
+pub fn fetch_registry(fetcher: &impl AccountFetcher, token_mint: &Pubkey) -> Result<Option<MintRegistry>, ClientError> {
+    let (registry, _) = derive_registry(token_mint);
+    fetcher
+        .fetch_account_data(&registry)?
+        .map(|data| deserialize_account(&data))
+        .transpose()
+}
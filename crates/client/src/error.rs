@@ -0,0 +1,35 @@
+use std::fmt;
+
+use anchor_lang::prelude::Pubkey;
+
+/// Errors raised while fetching or decoding program accounts and events, or building messages
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClientError {
+    /// The account data does not start with the expected discriminator
+    InvalidDiscriminator,
+    /// The account data is shorter than the account layout
+    AccountDataTooSmall,
+    /// The data could not be deserialized
+    Deserialize(String),
+    /// The account fetcher failed
+    Fetch(String),
+    /// The account does not exist
+    AccountNotFound(Pubkey),
+    /// The instructions could not be compiled into a transaction message
+    CompileMessage(String),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::InvalidDiscriminator => write!(f, "account discriminator does not match"),
+            ClientError::AccountDataTooSmall => write!(f, "account data is too small"),
+            ClientError::Deserialize(message) => write!(f, "failed to deserialize: {}", message),
+            ClientError::Fetch(message) => write!(f, "failed to fetch account: {}", message),
+            ClientError::AccountNotFound(address) => write!(f, "account {} not found", address),
+            ClientError::CompileMessage(message) => write!(f, "failed to compile message: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
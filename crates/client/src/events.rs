@@ -0,0 +1,118 @@
+//! Event decoding
+//!
+//! The program emits every event through a self-CPI (`emit_cpi!`), so events
+//! are read from the inner instructions of a transaction rather than its logs.
+//! The inner instruction data is the event instruction tag, the event
+//! discriminator and the Borsh-serialized event.
+
+use anchor_lang::event::EVENT_IX_TAG_LE;
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use token_distributor::event::*;
+
+use crate::ClientError;
+
+macro_rules! token_distributor_events {
+    ($($event:ident,)*) => {
+        /// Any event emitted by the token distributor program
+        pub enum TokenDistributorEvent {
+            $($event($event),)*
+        }
+
+        impl TokenDistributorEvent {
+            /// Name of the event, as in the IDL
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(TokenDistributorEvent::$event(_) => stringify!($event),)*
+                }
+            }
+
+            /// Decodes an event from its discriminator followed by its Borsh-serialized fields
+            ///
+            /// Returns `None` for data that does not start with a known event discriminator.
+            pub fn decode(data: &[u8]) -> Result<Option<Self>, ClientError> {
+                $(
+                    if let Some(mut fields) = data.strip_prefix($event::DISCRIMINATOR) {
+                        return $event::deserialize(&mut fields)
+                            .map(|event| Some(TokenDistributorEvent::$event(event)))
+                            .map_err(|e| ClientError::Deserialize(e.to_string()));
+                    }
+                )*
+                Ok(None)
+            }
+        }
+    };
+}
+
+token_distributor_events! {
+    DistributorCreated,
+    DistributorMetadataSet,
+    DistributorMetadataClosed,
+    DistributorMintAdded,
+    SharedVaultCreated,
+    SharedDistributorCreated,
+    SharedDistributorClosed,
+    SharedVaultWithdrawn,
+    ProgramConfigInitialized,
+    ProgramConfigUpdated,
+    OwnerDistributorLimitSet,
+    OwnerNonceMigrated,
+    ClaimStatusMigrated,
+    GlobalPauseSet,
+    OperatorAdded,
+    OperatorRemoved,
+    RelayerAdded,
+    RelayerRemoved,
+    StartTimeSet,
+    EndTimeExtended,
+    ClaimWindowsSet,
+    MerkleRootSet,
+    RegistrySynced,
+    RegistryEntryRemoved,
+    DistributorFinalized,
+    DistributionPaused,
+    DistributionUnpaused,
+    RootUpdateIntervalSet,
+    ClaimFeeSet,
+    ClaimLimitsSet,
+    CosignRequirementSet,
+    ClaimLogEnabled,
+    ClaimEscrowEnabled,
+    EarlyBonusSet,
+    ClaimGateSet,
+    ClaimAttestationSet,
+    AttestationIssued,
+    AttestationRevoked,
+    ClaimFeesWithdrawn,
+    TokensClaimed,
+    DistributionCompleted,
+    TokensEscrowed,
+    TokensReleased,
+    ExtraMintClaimed,
+    ClaimRedirected,
+    FrozenClaimReported,
+    FrozenClaimRedirected,
+    ClaimStatusAdjusted,
+    ClaimFrozen,
+    ClaimUnfrozen,
+    ClaimDelegateSet,
+    RentEscrowFunded,
+    RentEscrowWithdrawn,
+    TokensWithdrawn,
+    VaultClosed,
+    DistributorMintWithdrawn,
+    WithheldFeesHarvested,
+    Clawback,
+    ClaimStatusClosed,
+    ClaimStatusGarbageCollected,
+}
+
+/// Decodes the event carried by an inner instruction of the program
+///
+/// Returns `None` for inner instructions that are not event self-CPIs, so every
+/// inner instruction invoking the program can be passed in.
+pub fn decode_cpi_event(instruction_data: &[u8]) -> Result<Option<TokenDistributorEvent>, ClientError> {
+    match instruction_data.strip_prefix(EVENT_IX_TAG_LE) {
+        Some(data) => TokenDistributorEvent::decode(data),
+        None => Ok(None),
+    }
+}
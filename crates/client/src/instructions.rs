@@ -0,0 +1,444 @@
+//! Typed instruction builders
+//!
+//! Each builder fills in the PDAs, programs and sysvars of the instruction and
+//! takes the instruction arguments as the program's own `instruction::*` struct.
+//! Instructions not covered here can be built with [`build_instruction`] from
+//! `token_distributor::accounts::*` and `token_distributor::instruction::*`.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::sysvar;
+use anchor_lang::{system_program, InstructionData, ToAccountMetas};
+use token_distributor::state::ClaimWindow;
+use token_distributor::{accounts, instruction, ID};
+
+use crate::pda::*;
+
+/// Builds an instruction of the program from its accounts and arguments
+pub fn build_instruction(accounts: impl ToAccountMetas, args: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: ID,
+        accounts: accounts.to_account_metas(None),
+        data: args.data(),
+    }
+}
+
+/// `create_distributor`, for the owner's next nonce (`owner_nonce.nonce + 1`)
+pub fn create_distributor(
+    owner: &Pubkey,
+    operator: &Pubkey,
+    token_mint: &Pubkey,
+    owner_token_account: &Pubkey,
+    token_program: &Pubkey,
+    nonce: u32,
+    args: instruction::CreateDistributor,
+) -> Instruction {
+    let (distributor, _) = derive_distributor(token_mint, owner, nonce);
+    build_instruction(
+        accounts::CreateDistributor {
+            owner_nonce: derive_owner_nonce(owner).0,
+            distributor,
+            token_vault: derive_vault(&distributor).0,
+            token_mint: *token_mint,
+            owner_token_account: *owner_token_account,
+            owner: *owner,
+            operator: *operator,
+            system_program: system_program::ID,
+            token_program: *token_program,
+            rent: sysvar::rent::ID,
+            config: derive_config().0,
+            event_authority: derive_event_authority().0,
+            program: ID,
+        },
+        args,
+    )
+}
+
+/// `create_distributor_with_nonce`, for a nonce greater than `owner_nonce.nonce`
+pub fn create_distributor_with_nonce(
+    owner: &Pubkey,
+    operator: &Pubkey,
+    token_mint: &Pubkey,
+    owner_token_account: &Pubkey,
+    token_program: &Pubkey,
+    args: instruction::CreateDistributorWithNonce,
+) -> Instruction {
+    let (distributor, _) = derive_distributor(token_mint, owner, args.nonce);
+    build_instruction(
+        accounts::CreateDistributorWithNonce {
+            owner_nonce: derive_owner_nonce(owner).0,
+            distributor,
+            token_vault: derive_vault(&distributor).0,
+            token_mint: *token_mint,
+            owner_token_account: *owner_token_account,
+            owner: *owner,
+            operator: *operator,
+            system_program: system_program::ID,
+            token_program: *token_program,
+            rent: sysvar::rent::ID,
+            config: derive_config().0,
+            event_authority: derive_event_authority().0,
+            program: ID,
+        },
+        args,
+    )
+}
+
+/// `set_distributor_metadata`, signed by the owner before the distribution starts
+pub fn set_distributor_metadata(distributor: &Pubkey, owner: &Pubkey, name: String, uri: String) -> Instruction {
+    build_instruction(
+        accounts::SetDistributorMetadata {
+            distributor: *distributor,
+            metadata: derive_metadata(distributor).0,
+            owner: *owner,
+            system_program: system_program::ID,
+            event_authority: derive_event_authority().0,
+            program: ID,
+        },
+        instruction::SetDistributorMetadata { name, uri },
+    )
+}
+
+/// `set_time`, signed by an operator
+pub fn set_time(distributor: &Pubkey, operator: &Pubkey, start_time: i64) -> Instruction {
+    build_instruction(
+        accounts::SetTime {
+            distributor: *distributor,
+            operator: *operator,
+            event_authority: derive_event_authority().0,
+            program: ID,
+        },
+        instruction::SetTime { start_time },
+    )
+}
+
+/// `set_merkle_root`, signed by an operator
+pub fn set_merkle_root(distributor: &Pubkey, operator: &Pubkey, merkle_root: [u8; 32]) -> Instruction {
+    build_instruction(
+        accounts::SetMerkleRoot {
+            distributor: *distributor,
+            operator: *operator,
+            event_authority: derive_event_authority().0,
+            program: ID,
+        },
+        instruction::SetMerkleRoot { merkle_root },
+    )
+}
+
+/// `extend_time`, signed by an operator
+pub fn extend_time(distributor: &Pubkey, operator: &Pubkey, new_end_time: i64) -> Instruction {
+    build_instruction(
+        accounts::ExtendTime {
+            distributor: *distributor,
+            operator: *operator,
+            config: derive_config().0,
+            event_authority: derive_event_authority().0,
+            program: ID,
+        },
+        instruction::ExtendTime { new_end_time },
+    )
+}
+
+/// `set_claim_windows`, signed by an operator before the distribution starts
+pub fn set_claim_windows(distributor: &Pubkey, operator: &Pubkey, claim_windows: Vec<ClaimWindow>) -> Instruction {
+    build_instruction(
+        accounts::SetClaimWindows {
+            distributor: *distributor,
+            operator: *operator,
+            event_authority: derive_event_authority().0,
+            program: ID,
+        },
+        instruction::SetClaimWindows { claim_windows },
+    )
+}
+
+/// `set_early_bonus`, signed by the owner before the distribution starts
+///
+/// Raising the bonus pool deposits the difference from `owner_token_account`.
+pub fn set_early_bonus(
+    distributor: &Pubkey,
+    owner: &Pubkey,
+    token_mint: &Pubkey,
+    owner_token_account: &Pubkey,
+    token_program: &Pubkey,
+    args: instruction::SetEarlyBonus,
+) -> Instruction {
+    build_instruction(
+        accounts::SetEarlyBonus {
+            distributor: *distributor,
+            token_vault: derive_vault(distributor).0,
+            token_mint: *token_mint,
+            owner_token_account: *owner_token_account,
+            owner: *owner,
+            token_program: *token_program,
+            event_authority: derive_event_authority().0,
+            program: ID,
+        },
+        args,
+    )
+}
+
+/// `pause`, signed by the owner or an operator
+pub fn pause(distributor: &Pubkey, authority: &Pubkey) -> Instruction {
+    build_instruction(
+        accounts::Pause {
+            distributor: *distributor,
+            authority: *authority,
+            event_authority: derive_event_authority().0,
+            program: ID,
+        },
+        instruction::Pause {},
+    )
+}
+
+/// `unpause`, signed by the owner or an operator
+pub fn unpause(distributor: &Pubkey, authority: &Pubkey) -> Instruction {
+    build_instruction(
+        accounts::Unpause {
+            distributor: *distributor,
+            authority: *authority,
+            event_authority: derive_event_authority().0,
+            program: ID,
+        },
+        instruction::Unpause {},
+    )
+}
+
+/// `add_operator`, signed by the owner
+pub fn add_operator(distributor: &Pubkey, owner: &Pubkey, operator: Pubkey) -> Instruction {
+    build_instruction(
+        accounts::AddOperator {
+            distributor: *distributor,
+            owner: *owner,
+            event_authority: derive_event_authority().0,
+            program: ID,
+        },
+        instruction::AddOperator { operator },
+    )
+}
+
+/// `remove_operator`, signed by the owner
+pub fn remove_operator(distributor: &Pubkey, owner: &Pubkey, operator: Pubkey) -> Instruction {
+    build_instruction(
+        accounts::RemoveOperator {
+            distributor: *distributor,
+            owner: *owner,
+            event_authority: derive_event_authority().0,
+            program: ID,
+        },
+        instruction::RemoveOperator { operator },
+    )
+}
+
+/// Accounts of `claim` for a plain token distributor, with every optional account unset
+///
+/// Set the optional accounts the distributor needs (shared vault, memo program,
+/// protocol fee account, gates, claim log page, co-signature sysvar, claim escrow)
+/// before passing the accounts to [`claim`].
+pub fn claim_accounts(
+    distributor: &Pubkey,
+    claimant: &Pubkey,
+    payer: &Pubkey,
+    token_mint: &Pubkey,
+    claimant_token_account: &Pubkey,
+    token_program: &Pubkey,
+) -> accounts::Claim {
+    accounts::Claim {
+        distributor: *distributor,
+        claim_status: derive_claim_status(distributor, claimant).0,
+        token_vault: derive_vault(distributor).0,
+        shared_vault: None,
+        claimant_token_account: *claimant_token_account,
+        token_mint: *token_mint,
+        claimant: *claimant,
+        delegate: None,
+        payer: *payer,
+        system_program: system_program::ID,
+        token_program: *token_program,
+        memo_program: None,
+        protocol_fee_account: None,
+        gate_token_account: None,
+        attestation: None,
+        config: derive_config().0,
+        claim_log: None,
+        instructions_sysvar: None,
+        claim_escrow: None,
+        escrow_vault: None,
+        event_authority: derive_event_authority().0,
+        program: ID,
+    }
+}
+
+/// `claim` from the given accounts, see [`claim_accounts`]
+pub fn claim(accounts: accounts::Claim, args: instruction::Claim) -> Instruction {
+    build_instruction(accounts, args)
+}
+
+/// `release` of the unlocked tokens of a claim escrow, signed by the claimant
+pub fn release(
+    distributor: &Pubkey,
+    claimant: &Pubkey,
+    token_mint: &Pubkey,
+    claimant_token_account: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    build_instruction(
+        accounts::Release {
+            claim_escrow: derive_claim_escrow(distributor, claimant).0,
+            escrow_vault: derive_escrow_vault(distributor, claimant).0,
+            claimant_token_account: *claimant_token_account,
+            token_mint: *token_mint,
+            claimant: *claimant,
+            token_program: *token_program,
+            memo_program: None,
+            event_authority: derive_event_authority().0,
+            program: ID,
+        },
+        instruction::Release {},
+    )
+}
+
+/// `withdraw`, signed by the owner once the distribution ended
+pub fn withdraw(
+    distributor: &Pubkey,
+    owner: &Pubkey,
+    token_mint: &Pubkey,
+    owner_token_account: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    build_instruction(
+        accounts::Withdraw {
+            distributor: *distributor,
+            token_vault: derive_vault(distributor).0,
+            owner_token_account: *owner_token_account,
+            token_mint: *token_mint,
+            owner: *owner,
+            owner_nonce: derive_owner_nonce(owner).0,
+            token_program: *token_program,
+            event_authority: derive_event_authority().0,
+            program: ID,
+            registry: None,
+        },
+        instruction::Withdraw {},
+    )
+}
+
+/// `close_vault`, signed by the owner once the vault is empty and the distribution completed or ended
+pub fn close_vault(distributor: &Pubkey, owner: &Pubkey, token_mint: &Pubkey, token_program: &Pubkey) -> Instruction {
+    build_instruction(
+        accounts::CloseVault {
+            distributor: *distributor,
+            token_vault: derive_vault(distributor).0,
+            token_mint: *token_mint,
+            owner: *owner,
+            owner_nonce: derive_owner_nonce(owner).0,
+            token_program: *token_program,
+            event_authority: derive_event_authority().0,
+            program: ID,
+            registry: None,
+        },
+        instruction::CloseVault {},
+    )
+}
+
+/// `withdraw_partial`, signed by the owner before the start or once the distribution ended, keeping the distributor open
+pub fn withdraw_partial(
+    distributor: &Pubkey,
+    owner: &Pubkey,
+    token_mint: &Pubkey,
+    owner_token_account: &Pubkey,
+    token_program: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    build_instruction(
+        accounts::WithdrawPartial {
+            distributor: *distributor,
+            token_vault: derive_vault(distributor).0,
+            owner_token_account: *owner_token_account,
+            token_mint: *token_mint,
+            owner: *owner,
+            token_program: *token_program,
+            event_authority: derive_event_authority().0,
+            program: ID,
+        },
+        instruction::WithdrawPartial { amount },
+    )
+}
+
+/// `clawback` of an ended distribution to its clawback receiver's token account, callable by any keeper
+pub fn clawback(
+    distributor: &Pubkey,
+    owner: &Pubkey,
+    token_mint: &Pubkey,
+    clawback_token_account: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    build_instruction(
+        accounts::ClawbackVault {
+            distributor: *distributor,
+            token_vault: derive_vault(distributor).0,
+            clawback_token_account: *clawback_token_account,
+            token_mint: *token_mint,
+            owner: *owner,
+            owner_nonce: derive_owner_nonce(owner).0,
+            token_program: *token_program,
+            event_authority: derive_event_authority().0,
+            program: ID,
+            registry: None,
+        },
+        instruction::Clawback {},
+    )
+}
+
+/// `close_claim_status`, signed by the claimant, returning the rent to the claim status' `rent_payer`
+pub fn close_claim_status(distributor: &Pubkey, claimant: &Pubkey, rent_payer: &Pubkey) -> Instruction {
+    build_instruction(
+        accounts::CloseClaimStatus {
+            claim_status: derive_claim_status(distributor, claimant).0,
+            claimant: *claimant,
+            rent_payer: *rent_payer,
+            distributor_key: *distributor,
+            event_authority: derive_event_authority().0,
+            program: ID,
+        },
+        instruction::CloseClaimStatus {},
+    )
+}
+
+/// `garbage_collect_claim_status`, signed by any cranker, returning the rent to the claim status' `rent_payer`
+pub fn garbage_collect_claim_status(
+    distributor: &Pubkey,
+    claimant: &Pubkey,
+    rent_payer: &Pubkey,
+    cranker: &Pubkey,
+) -> Instruction {
+    build_instruction(
+        accounts::GarbageCollectClaimStatus {
+            claim_status: derive_claim_status(distributor, claimant).0,
+            claimant: *claimant,
+            rent_payer: *rent_payer,
+            distributor_key: *distributor,
+            cranker: *cranker,
+            event_authority: derive_event_authority().0,
+            program: ID,
+        },
+        instruction::GarbageCollectClaimStatus {},
+    )
+}
+
+/// `evict_registry_entry` of a distributor from its mint's registry, callable by any keeper once the
+/// distributor is closed, completed or ended, and by the mint authority or config admin as `authority` otherwise
+pub fn evict_registry_entry(distributor: &Pubkey, token_mint: &Pubkey, authority: Option<&Pubkey>) -> Instruction {
+    build_instruction(
+        accounts::EvictRegistryEntry {
+            registry: derive_registry(token_mint).0,
+            distributor: *distributor,
+            token_mint: *token_mint,
+            authority: authority.copied(),
+            config: derive_config().0,
+            event_authority: derive_event_authority().0,
+            program: ID,
+        },
+        instruction::EvictRegistryEntry {},
+    )
+}
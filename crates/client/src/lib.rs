@@ -0,0 +1,26 @@
+//! Rust client for the token distributor program
+//!
+//! Lets bots, indexers and keepers integrate without hand-rolling Borsh
+//! layouts from the IDL:
+//!
+//! - [`pda`]: PDA derivation (`derive_distributor`, `derive_vault`, `derive_claim_status`, ...)
+//! - [`instructions`]: typed instruction builders
+//! - [`accounts`]: account fetching and deserialization
+//! - [`events`]: decoding of the events carried by the program's self-CPIs
+//! - [`message`]: versioned messages with lookup table resolution, for wallet signing
+//!
+//! Account, argument and event types are the program's own, re-exported below,
+//! so they always match the deployed layout.
+
+pub mod accounts;
+mod error;
+pub mod events;
+pub mod instructions;
+pub mod message;
+pub mod pda;
+
+pub use error::ClientError;
+pub use token_distributor::{event, instruction, state, ID};
+
+/// Account structs of every instruction, as consumed by `instructions::build_instruction`
+pub use token_distributor::accounts as instruction_accounts;
@@ -0,0 +1,118 @@
+//! Transaction messages ready for wallet signing
+//!
+//! Backends compile the program's instructions into a [`VersionedMessage`] here
+//! and hand it to a browser wallet adapter as an unsigned transaction, so the
+//! frontend signs exactly what was built instead of re-assembling it:
+//!
+//! - [`versioned_message`]: v0 message, with accounts resolved through address lookup tables
+//! - [`legacy_message`]: legacy message, for wallets without v0 support
+//! - [`replace_fee_payer`]: swaps [`FEE_PAYER_PLACEHOLDER`] for the wallet once it is known
+//! - [`fetch_lookup_tables`]: loads the lookup tables a v0 message resolves accounts through
+//! - [`unsigned_transaction`]: wire format of the transaction, with empty signatures
+
+use anchor_lang::prelude::{pubkey, Pubkey};
+use anchor_lang::solana_program::hash::Hash;
+use anchor_lang::solana_program::instruction::Instruction;
+use solana_address_lookup_table_interface::state::AddressLookupTable;
+use solana_message::Message;
+
+use crate::accounts::AccountFetcher;
+use crate::ClientError;
+
+pub use solana_message::{v0, AddressLookupTableAccount, VersionedMessage};
+
+/// Fee payer of messages built before the signing wallet is known
+///
+/// Replace it with [`replace_fee_payer`] before the message is signed.
+pub const FEE_PAYER_PLACEHOLDER: Pubkey = pubkey!("FeePayerP1aceho1der111111111111111111111111");
+
+/// Compiles instructions into a v0 message paid by `payer`
+///
+/// Accounts found in `lookup_tables` are loaded through them instead of being
+/// listed in the message, except signers and invoked programs, which always
+/// stay static. Tables that hold none of the accounts are left out.
+pub fn versioned_message(
+    payer: &Pubkey,
+    instructions: &[Instruction],
+    lookup_tables: &[AddressLookupTableAccount],
+    recent_blockhash: Hash,
+) -> Result<VersionedMessage, ClientError> {
+    v0::Message::try_compile(payer, instructions, lookup_tables, recent_blockhash)
+        .map(VersionedMessage::V0)
+        .map_err(|e| ClientError::CompileMessage(e.to_string()))
+}
+
+/// Compiles instructions into a legacy message paid by `payer`
+pub fn legacy_message(payer: &Pubkey, instructions: &[Instruction], recent_blockhash: Hash) -> VersionedMessage {
+    VersionedMessage::Legacy(Message::new_with_blockhash(instructions, Some(payer), &recent_blockhash))
+}
+
+/// Replaces the [`FEE_PAYER_PLACEHOLDER`] of a message with the actual fee payer
+///
+/// The placeholder must be the message's fee payer, and the actual fee payer
+/// must not be one of its static accounts already: build the message with the
+/// actual fee payer instead when the instructions reference it.
+pub fn replace_fee_payer(message: &mut VersionedMessage, payer: &Pubkey) -> Result<(), ClientError> {
+    let account_keys = match message {
+        VersionedMessage::Legacy(message) => &mut message.account_keys,
+        VersionedMessage::V0(message) => &mut message.account_keys,
+    };
+    if account_keys.first() != Some(&FEE_PAYER_PLACEHOLDER) {
+        return Err(ClientError::CompileMessage("fee payer is not the placeholder".to_string()));
+    }
+    if account_keys.contains(payer) {
+        return Err(ClientError::CompileMessage(format!("fee payer {} is already in the message", payer)));
+    }
+    account_keys[0] = *payer;
+    Ok(())
+}
+
+/// Deserializes an address lookup table account
+pub fn deserialize_lookup_table(address: &Pubkey, data: &[u8]) -> Result<AddressLookupTableAccount, ClientError> {
+    let table = AddressLookupTable::deserialize(data).map_err(|e| ClientError::Deserialize(e.to_string()))?;
+    Ok(AddressLookupTableAccount {
+        key: *address,
+        addresses: table.addresses.to_vec(),
+    })
+}
+
+/// Fetches the address lookup tables a v0 message resolves accounts through
+pub fn fetch_lookup_tables(
+    fetcher: &impl AccountFetcher,
+    addresses: &[Pubkey],
+) -> Result<Vec<AddressLookupTableAccount>, ClientError> {
+    addresses
+        .iter()
+        .map(|address| {
+            let data = fetcher
+                .fetch_account_data(address)?
+                .ok_or(ClientError::AccountNotFound(*address))?;
+            deserialize_lookup_table(address, &data)
+        })
+        .collect()
+}
+
+/// Serializes the message as a transaction whose signatures are all zeroed
+///
+/// This is the format wallet adapters deserialize (`VersionedTransaction.deserialize`
+/// in `@solana/web3.js`) before asking the wallet to sign.
+pub fn unsigned_transaction(message: &VersionedMessage) -> Vec<u8> {
+    let signatures = message.header().num_required_signatures as usize;
+    let message = message.serialize();
+    let mut data = Vec::with_capacity(3 + signatures * 64 + message.len());
+
+    // Compact-u16 length prefix of the signatures
+    let mut remaining = signatures;
+    loop {
+        let byte = (remaining & 0x7f) as u8;
+        remaining >>= 7;
+        if remaining == 0 {
+            data.push(byte);
+            break;
+        }
+        data.push(byte | 0x80);
+    }
+    data.resize(data.len() + signatures * 64, 0);
+    data.extend_from_slice(&message);
+    data
+}
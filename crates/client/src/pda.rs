@@ -0,0 +1,95 @@
+//! PDA derivation, with the same seeds as the program's account constraints
+
+use anchor_lang::prelude::Pubkey;
+use token_distributor::constants::*;
+use token_distributor::ID;
+
+/// Program config: `["config"]`
+pub fn derive_config() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CONFIG_SEED.as_bytes()], &ID)
+}
+
+/// Owner nonce state: `["owner_nonce", owner]`
+pub fn derive_owner_nonce(owner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[OWNER_NONCE_SEED.as_bytes(), owner.as_ref()], &ID)
+}
+
+/// Distributor: `["distributor", mint, owner, nonce_le]`
+///
+/// `create_distributor` uses the owner's next nonce, `owner_nonce.nonce + 1`
+/// and `create_distributor_with_nonce` any greater nonce
+pub fn derive_distributor(token_mint: &Pubkey, owner: &Pubkey, nonce: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[DISTRIBUTOR_SEED.as_bytes(), token_mint.as_ref(), owner.as_ref(), &nonce.to_le_bytes()],
+        &ID,
+    )
+}
+
+/// Distributor token vault: `["vault", distributor]`
+pub fn derive_vault(distributor: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_SEED.as_bytes(), distributor.as_ref()], &ID)
+}
+
+/// Extra mint of a multi-mint distributor: `["distributor_mint", distributor, mint]`
+///
+/// Its token vault is [`derive_vault`] of the distributor mint
+pub fn derive_distributor_mint(distributor: &Pubkey, token_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[DISTRIBUTOR_MINT_SEED.as_bytes(), distributor.as_ref(), token_mint.as_ref()], &ID)
+}
+
+/// Lamport vault of a SOL distributor: `["sol_vault", distributor]`
+pub fn derive_sol_vault(distributor: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SOL_VAULT_SEED.as_bytes(), distributor.as_ref()], &ID)
+}
+
+/// Shared vault of an owner's mint: `["shared_vault", mint, owner]`
+///
+/// Its token vault is [`derive_vault`] of the shared vault
+pub fn derive_shared_vault(token_mint: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SHARED_VAULT_SEED.as_bytes(), token_mint.as_ref(), owner.as_ref()], &ID)
+}
+
+/// Rent escrow funding claim statuses: `["rent_escrow", distributor, owner]`
+pub fn derive_rent_escrow(distributor: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[RENT_ESCROW_SEED.as_bytes(), distributor.as_ref(), owner.as_ref()], &ID)
+}
+
+/// Registry of a mint's distributors: `["registry", mint]`
+pub fn derive_registry(token_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[REGISTRY_SEED.as_bytes(), token_mint.as_ref()], &ID)
+}
+
+/// Claim log page: `["claim_log", distributor, page_le]`
+pub fn derive_claim_log(distributor: &Pubkey, page: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CLAIM_LOG_SEED.as_bytes(), distributor.as_ref(), &page.to_le_bytes()], &ID)
+}
+
+/// Attestation of a subject: `["attestation", attestor, subject]`
+pub fn derive_attestation(attestor: &Pubkey, subject: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ATTESTATION_SEED.as_bytes(), attestor.as_ref(), subject.as_ref()], &ID)
+}
+
+/// Claim status: `["claim", distributor, claimant]`
+pub fn derive_claim_status(distributor: &Pubkey, claimant: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CLAIM_SEED.as_bytes(), distributor.as_ref(), claimant.as_ref()], &ID)
+}
+
+/// Claim escrow of an escrowed distributor: `["claim_escrow", distributor, claimant]`
+pub fn derive_claim_escrow(distributor: &Pubkey, claimant: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CLAIM_ESCROW_SEED.as_bytes(), distributor.as_ref(), claimant.as_ref()], &ID)
+}
+
+/// Token account of a claim escrow: `["escrow_vault", distributor, claimant]`
+pub fn derive_escrow_vault(distributor: &Pubkey, claimant: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ESCROW_VAULT_SEED.as_bytes(), distributor.as_ref(), claimant.as_ref()], &ID)
+}
+
+/// Distributor metadata: `["metadata", distributor]`
+pub fn derive_metadata(distributor: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[METADATA_SEED.as_bytes(), distributor.as_ref()], &ID)
+}
+
+/// Event authority signing the self-CPIs that carry events: `["__event_authority"]`
+pub fn derive_event_authority() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"__event_authority"], &ID)
+}
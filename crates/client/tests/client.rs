@@ -0,0 +1,292 @@
+//! Client helpers against the program's own seeds, layouts and discriminators
+
+use std::collections::HashMap;
+
+use anchor_lang::event::EVENT_IX_TAG_LE;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::hash::Hash;
+use anchor_lang::{AccountSerialize, Discriminator, Event, InstructionData};
+use token_distributor::event::{AmountScaling, ClaimRedirected, TokensClaimed};
+use token_distributor::state::{ClaimStatus, NonceState, ProgramConfig, TokenDistributor};
+use token_distributor_client::accounts::{
+    deserialize_distributor, fetch_claim_status, fetch_config, fetch_distributor, fetch_owner_nonce,
+};
+use token_distributor_client::events::{decode_cpi_event, TokenDistributorEvent};
+use token_distributor_client::message::*;
+use token_distributor_client::pda::*;
+use token_distributor_client::{instruction, instructions, ClientError, ID};
+
+#[test]
+fn test_pda_derivation() {
+    let mint = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let claimant = Pubkey::new_unique();
+
+    let (distributor, bump) = derive_distributor(&mint, &owner, 1);
+    let expected = Pubkey::create_program_address(
+        &[b"distributor", mint.as_ref(), owner.as_ref(), &1u32.to_le_bytes(), &[bump]],
+        &ID,
+    )
+    .unwrap();
+    assert_eq!(distributor, expected);
+    assert_ne!(distributor, derive_distributor(&mint, &owner, 2).0);
+    assert_eq!(
+        derive_vault(&distributor).0,
+        Pubkey::find_program_address(&[b"vault", distributor.as_ref()], &ID).0
+    );
+    assert_eq!(
+        derive_claim_status(&distributor, &claimant).0,
+        Pubkey::find_program_address(&[b"claim", distributor.as_ref(), claimant.as_ref()], &ID).0
+    );
+
+    // Extra mint and shared vaults hold their tokens in the vault of their own PDA
+    let (distributor_mint, _) = derive_distributor_mint(&distributor, &mint);
+    assert_eq!(
+        distributor_mint,
+        Pubkey::find_program_address(&[b"distributor_mint", distributor.as_ref(), mint.as_ref()], &ID).0
+    );
+    assert_ne!(derive_vault(&distributor_mint).0, derive_vault(&distributor).0);
+    assert_eq!(
+        derive_shared_vault(&mint, &owner).0,
+        Pubkey::find_program_address(&[b"shared_vault", mint.as_ref(), owner.as_ref()], &ID).0
+    );
+    assert_eq!(
+        derive_claim_log(&distributor, 1).0,
+        Pubkey::find_program_address(&[b"claim_log", distributor.as_ref(), &1u64.to_le_bytes()], &ID).0
+    );
+    assert_ne!(derive_claim_log(&distributor, 0).0, derive_claim_log(&distributor, 1).0);
+}
+
+
+#[test]
+fn test_claim_instruction() {
+    let distributor = Pubkey::new_unique();
+    let claimant = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let claimant_token_account = Pubkey::new_unique();
+    let token_program = Pubkey::new_unique();
+
+    let accounts =
+        || instructions::claim_accounts(&distributor, &claimant, &claimant, &mint, &claimant_token_account, &token_program);
+    let args = || instruction::Claim {
+        max_amount: 1_000,
+        proof: vec![[1u8; 32]],
+        amount: None,
+        unlock_time: None,
+        cosign_expiry: None,
+    };
+    let ix = instructions::claim(accounts(), args());
+    assert_eq!(ix.program_id, ID);
+    assert!(ix.data.starts_with(instruction::Claim::DISCRIMINATOR));
+    assert_eq!(ix.accounts[1].pubkey, derive_claim_status(&distributor, &claimant).0);
+    assert_eq!(ix.accounts[2].pubkey, derive_vault(&distributor).0);
+    // Unset optional accounts are passed as the program id
+    assert_eq!(ix.accounts[3].pubkey, ID);
+    // The claimant pays, so it is the only signer
+    assert_eq!(ix.accounts.iter().filter(|meta| meta.is_signer).count(), 1);
+
+    let shared_vault = Pubkey::new_unique();
+    let mut shared_accounts = accounts();
+    shared_accounts.shared_vault = Some(shared_vault);
+    let ix = instructions::claim(shared_accounts, args());
+    assert!(ix.accounts.iter().any(|meta| meta.pubkey == shared_vault && meta.is_writable));
+}
+
+
+#[test]
+fn test_keeper_instructions() {
+    let distributor = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let claimant = Pubkey::new_unique();
+    let rent_payer = Pubkey::new_unique();
+    let cranker = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let token_program = Pubkey::new_unique();
+
+    let ix = instructions::clawback(&distributor, &owner, &mint, &Pubkey::new_unique(), &token_program);
+    assert!(ix.data.starts_with(instruction::Clawback::DISCRIMINATOR));
+    assert_eq!(ix.accounts[1].pubkey, derive_vault(&distributor).0);
+    assert_eq!(ix.accounts[5].pubkey, derive_owner_nonce(&owner).0);
+    // Clawback is permissionless, the keeper only pays the fee
+    assert!(ix.accounts.iter().all(|meta| !meta.is_signer));
+
+    let ix = instructions::garbage_collect_claim_status(&distributor, &claimant, &rent_payer, &cranker);
+    assert_eq!(ix.accounts[0].pubkey, derive_claim_status(&distributor, &claimant).0);
+    let signers: Vec<_> = ix.accounts.iter().filter(|meta| meta.is_signer).map(|meta| meta.pubkey).collect();
+    assert_eq!(signers, vec![cranker]);
+    assert!(ix.accounts.iter().any(|meta| meta.pubkey == rent_payer && meta.is_writable));
+
+    let ix = instructions::extend_time(&distributor, &owner, 1_000);
+    assert_eq!(ix.data, instruction::ExtendTime { new_end_time: 1_000 }.data());
+    assert_eq!(ix.accounts[2].pubkey, derive_config().0);
+
+    let ix = instructions::pause(&distributor, &owner);
+    assert!(ix.data.starts_with(instruction::Pause::DISCRIMINATOR));
+    assert!(ix.accounts[1].is_signer);
+}
+
+
+#[test]
+fn test_account_deserialization() {
+    let distributor_key = Pubkey::new_unique();
+    let claimant = Pubkey::new_unique();
+    let distributor = TokenDistributor {
+        initial_total_amount: 5_000,
+        total_claimed: 1_200,
+        ..Default::default()
+    };
+    let mut distributor_data = TokenDistributor::DISCRIMINATOR.to_vec();
+    distributor_data.extend_from_slice(bytemuck::bytes_of(&distributor));
+
+    let claim_status = ClaimStatus {
+        claimed_amount: 1_200,
+        ..Default::default()
+    };
+    let mut claim_status_data = Vec::new();
+    claim_status.try_serialize(&mut claim_status_data).unwrap();
+
+    let mut store = HashMap::new();
+    store.insert(distributor_key, distributor_data.clone());
+    store.insert(derive_claim_status(&distributor_key, &claimant).0, claim_status_data.clone());
+
+    let fetched = fetch_distributor(&store, &distributor_key).unwrap().unwrap();
+    assert_eq!((fetched.initial_total_amount, fetched.total_claimed), (5_000, 1_200));
+    let fetched = fetch_claim_status(&store, &distributor_key, &claimant).unwrap().unwrap();
+    assert_eq!(fetched.claimed_amount, 1_200);
+    assert!(fetch_claim_status(&store, &distributor_key, &Pubkey::new_unique()).unwrap().is_none());
+
+    // Other accounts and truncated data are rejected
+    assert_eq!(deserialize_distributor(&claim_status_data).err(), Some(ClientError::InvalidDiscriminator));
+    assert_eq!(
+        deserialize_distributor(&distributor_data[..distributor_data.len() - 1]).err(),
+        Some(ClientError::AccountDataTooSmall)
+    );
+
+    // Config and owner nonce are fetched from their PDAs
+    let owner = Pubkey::new_unique();
+    let config = ProgramConfig { protocol_fee_bps: 25, ..Default::default() };
+    let nonce = NonceState { nonce: 3, ..Default::default() };
+    let (mut config_data, mut nonce_data) = (Vec::new(), Vec::new());
+    config.try_serialize(&mut config_data).unwrap();
+    nonce.try_serialize(&mut nonce_data).unwrap();
+    store.insert(derive_config().0, config_data);
+    store.insert(derive_owner_nonce(&owner).0, nonce_data);
+    assert_eq!(fetch_config(&store).unwrap().unwrap().protocol_fee_bps, 25);
+    assert_eq!(fetch_owner_nonce(&store, &owner).unwrap().unwrap().nonce, 3);
+    assert!(fetch_owner_nonce(&store, &Pubkey::new_unique()).unwrap().is_none());
+}
+
+
+#[test]
+fn test_event_decoding() {
+    let event = TokensClaimed {
+        distributor: Pubkey::new_unique(),
+        claimant: Pubkey::new_unique(),
+        user_amount_claimed: 400,
+        user_max_amount: 1_000,
+        total_claimed: 2_400,
+        protocol_fee: 4,
+        scaling: AmountScaling::default(),
+        phase: 1,
+        bonus_amount: 40,
+    };
+    let mut instruction_data = EVENT_IX_TAG_LE.to_vec();
+    instruction_data.extend_from_slice(&event.data());
+
+    let decoded = decode_cpi_event(&instruction_data).unwrap().unwrap();
+    assert_eq!(decoded.name(), "TokensClaimed");
+    let TokenDistributorEvent::TokensClaimed(decoded) = decoded else {
+        panic!("expected TokensClaimed");
+    };
+    assert_eq!((decoded.claimant, decoded.user_amount_claimed), (event.claimant, 400));
+
+    // Every event of the program is decoded
+    let event = ClaimRedirected {
+        distributor: Pubkey::new_unique(),
+        claimant: Pubkey::new_unique(),
+        recipient: Pubkey::new_unique(),
+        amount: 400,
+    };
+    let mut instruction_data = EVENT_IX_TAG_LE.to_vec();
+    instruction_data.extend_from_slice(&event.data());
+    let Some(TokenDistributorEvent::ClaimRedirected(decoded)) = decode_cpi_event(&instruction_data).unwrap() else {
+        panic!("expected ClaimRedirected");
+    };
+    assert_eq!((decoded.recipient, decoded.amount), (event.recipient, 400));
+
+    // Regular program instructions are not events
+    assert!(decode_cpi_event(&instruction::Withdraw {}.data()).unwrap().is_none());
+    // Truncated events fail to decode
+    assert!(decode_cpi_event(&instruction_data[..instruction_data.len() - 1]).is_err());
+}
+
+#[test]
+fn test_versioned_message() {
+    let distributor = Pubkey::new_unique();
+    let claimant = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let claimant_token_account = Pubkey::new_unique();
+    let token_program = Pubkey::new_unique();
+    let ix = instructions::claim(
+        instructions::claim_accounts(&distributor, &claimant, &claimant, &mint, &claimant_token_account, &token_program),
+        instruction::Claim {
+            max_amount: 1_000,
+            proof: vec![[1u8; 32]],
+            amount: None,
+            unlock_time: None,
+            cosign_expiry: None,
+        },
+    );
+    let blockhash = Hash::new_from_array([7u8; 32]);
+
+    // Lookup table holding the distributor's shared accounts: 56 bytes of metadata, then the addresses
+    let table_key = Pubkey::new_unique();
+    let vault = derive_vault(&distributor).0;
+    let mut table_data = vec![0u8; 56];
+    table_data[..4].copy_from_slice(&1u32.to_le_bytes());
+    table_data[4..12].copy_from_slice(&u64::MAX.to_le_bytes());
+    for address in [distributor, vault, mint, token_program, claimant] {
+        table_data.extend_from_slice(address.as_ref());
+    }
+    let mut store = HashMap::new();
+    store.insert(table_key, table_data);
+    let tables = fetch_lookup_tables(&store, &[table_key]).unwrap();
+    assert_eq!(tables[0].addresses.len(), 5);
+    assert_eq!(
+        fetch_lookup_tables(&store, &[Pubkey::new_unique(), table_key]).err().map(|e| matches!(e, ClientError::AccountNotFound(_))),
+        Some(true)
+    );
+
+    let message = versioned_message(&claimant, std::slice::from_ref(&ix), &tables, blockhash).unwrap();
+    let VersionedMessage::V0(v0) = &message else {
+        panic!("expected a v0 message");
+    };
+    assert_eq!(v0.account_keys[0], claimant);
+    assert_eq!(v0.recent_blockhash, blockhash);
+    // Distributor, vault, mint and token program are loaded through the table; the signing claimant stays static
+    assert_eq!(v0.address_table_lookups.len(), 1);
+    assert_eq!(v0.address_table_lookups[0].account_key, table_key);
+    assert_eq!(v0.address_table_lookups[0].writable_indexes.len() + v0.address_table_lookups[0].readonly_indexes.len(), 4);
+    assert!(!v0.account_keys.contains(&distributor) && !v0.account_keys.contains(&vault));
+    assert!(v0.account_keys.contains(&ID));
+
+    // Wire format: one zeroed signature, then the message
+    let transaction = unsigned_transaction(&message);
+    assert_eq!(transaction[0], 1);
+    assert!(transaction[1..65].iter().all(|&byte| byte == 0));
+    assert_eq!(&transaction[65..], &message.serialize()[..]);
+
+    // A sponsor paying for the claim is filled in once known
+    let sponsor = Pubkey::new_unique();
+    let mut message = versioned_message(&FEE_PAYER_PLACEHOLDER, std::slice::from_ref(&ix), &tables, blockhash).unwrap();
+    assert_eq!(message.header().num_required_signatures, 2);
+    replace_fee_payer(&mut message, &sponsor).unwrap();
+    assert_eq!(message.static_account_keys()[0], sponsor);
+    assert!(replace_fee_payer(&mut message, &sponsor).is_err());
+
+    // The placeholder can only be replaced by an account not already in the message
+    let mut message = legacy_message(&FEE_PAYER_PLACEHOLDER, &[ix], blockhash);
+    assert!(replace_fee_payer(&mut message, &claimant).is_err());
+    replace_fee_payer(&mut message, &sponsor).unwrap();
+    assert_eq!(message.static_account_keys()[0], sponsor);
+}
@@ -0,0 +1,29 @@
+use std::fmt;
+
+/// Errors raised while ingesting entries or building a tree
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TreeError {
+    /// A tree needs at least one entry
+    Empty,
+    /// Some entries have an unlock time and some do not
+    MixedTreeVersions,
+    /// The same claimant appears more than once
+    DuplicateClaimant(String),
+    /// An input line or record could not be parsed
+    Parse { line: usize, message: String },
+}
+
+impl fmt::Display for TreeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TreeError::Empty => write!(f, "cannot build a merkle tree without entries"),
+            TreeError::MixedTreeVersions => {
+                write!(f, "either all entries or none must have an unlock time")
+            }
+            TreeError::DuplicateClaimant(claimant) => write!(f, "duplicate claimant {}", claimant),
+            TreeError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+        }
+    }
+}
+
+impl std::error::Error for TreeError {}
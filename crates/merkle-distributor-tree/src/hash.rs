@@ -0,0 +1,59 @@
+use sha2::{Digest, Sha256};
+use solana_pubkey::Pubkey;
+
+/// Domain separator prepended to leaf hashes
+pub const LEAF_PREFIX: u8 = 0;
+
+/// Domain separator prepended to intermediate node hashes
+pub const NODE_PREFIX: u8 = 1;
+
+fn hashv(data: &[&[u8]]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    for part in data {
+        hasher.update(part);
+    }
+    hasher.finalize().into()
+}
+
+/// Computes the merkle leaf for a (distributor, claimant, amount) entry
+/// - Tree version 1: sha256(LEAF_PREFIX || distributor || claimant || amount_le)
+pub fn hash_leaf(distributor: &Pubkey, claimant: &Pubkey, amount: u64) -> [u8; 32] {
+    hashv(&[
+        &[LEAF_PREFIX],
+        distributor.as_ref(),
+        claimant.as_ref(),
+        &amount.to_le_bytes(),
+    ])
+}
+
+/// Computes the merkle leaf for a (distributor, claimant, amount, unlock_time) entry
+/// - Tree version 2: the version 1 leaf preimage followed by unlock_time_le
+pub fn hash_leaf_with_unlock_time(distributor: &Pubkey, claimant: &Pubkey, amount: u64, unlock_time: i64) -> [u8; 32] {
+    hashv(&[
+        &[LEAF_PREFIX],
+        distributor.as_ref(),
+        claimant.as_ref(),
+        &amount.to_le_bytes(),
+        &unlock_time.to_le_bytes(),
+    ])
+}
+
+/// Computes an intermediate merkle node from two children
+/// - sha256(NODE_PREFIX || sorted(a, b)), so proofs carry no left/right flags
+pub fn hash_node(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    if a <= b {
+        hashv(&[&[NODE_PREFIX], a, b])
+    } else {
+        hashv(&[&[NODE_PREFIX], b, a])
+    }
+}
+
+/// Computes the merkle root a proof leads to from a leaf
+pub fn compute_root(proof: &[[u8; 32]], leaf: [u8; 32]) -> [u8; 32] {
+    proof.iter().fold(leaf, |computed_hash, sibling| hash_node(&computed_hash, sibling))
+}
+
+/// Checks that a proof leads from a leaf to the given root
+pub fn verify(proof: &[[u8; 32]], root: [u8; 32], leaf: [u8; 32]) -> bool {
+    compute_root(proof, leaf) == root
+}
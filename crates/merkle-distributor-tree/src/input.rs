@@ -0,0 +1,110 @@
+use serde::Deserialize;
+use solana_pubkey::Pubkey;
+use std::str::FromStr;
+
+use crate::{TreeEntry, TreeError};
+
+/// Integer given as a JSON number or, to survive JavaScript tooling, as a decimal string
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum JsonInteger<T> {
+    Number(T),
+    Text(String),
+}
+
+impl<T: FromStr> JsonInteger<T>
+where
+    T::Err: std::fmt::Display,
+{
+    fn parse(self, line: usize, field: &str) -> Result<T, TreeError> {
+        match self {
+            JsonInteger::Number(value) => Ok(value),
+            JsonInteger::Text(text) => text.parse().map_err(|err| TreeError::Parse {
+                line,
+                message: format!("invalid {} {:?}: {}", field, text, err),
+            }),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct JsonEntry {
+    claimant: String,
+    amount: JsonInteger<u64>,
+    #[serde(default)]
+    unlock_time: Option<JsonInteger<i64>>,
+}
+
+fn parse_claimant(line: usize, claimant: &str) -> Result<Pubkey, TreeError> {
+    Pubkey::from_str(claimant.trim()).map_err(|err| TreeError::Parse {
+        line,
+        message: format!("invalid claimant {:?}: {}", claimant, err),
+    })
+}
+
+/// Parses entries from a JSON array of `{ "claimant", "amount", "unlock_time"? }` objects
+/// - Same format as the entries in tests/vectors/merkle_tree_vectors.json
+/// - Integers may be JSON numbers or decimal strings, a null unlock_time means none
+/// - Parse errors report the 1-based index of the offending entry as the line
+pub fn entries_from_json(json: &str) -> Result<Vec<TreeEntry>, TreeError> {
+    let entries: Vec<JsonEntry> = serde_json::from_str(json).map_err(|err| TreeError::Parse {
+        line: err.line(),
+        message: err.to_string(),
+    })?;
+
+    entries
+        .into_iter()
+        .enumerate()
+        .map(|(index, entry)| {
+            let line = index + 1;
+            Ok(TreeEntry {
+                claimant: parse_claimant(line, &entry.claimant)?,
+                amount: entry.amount.parse(line, "amount")?,
+                unlock_time: entry.unlock_time.map(|value| value.parse(line, "unlock_time")).transpose()?,
+            })
+        })
+        .collect()
+}
+
+/// Parses entries from CSV lines of `claimant,amount[,unlock_time]`
+/// - A first line starting with "claimant" is treated as a header
+/// - Blank lines are skipped, an empty unlock_time column means no unlock time
+pub fn entries_from_csv(csv: &str) -> Result<Vec<TreeEntry>, TreeError> {
+    let mut entries = Vec::new();
+
+    for (index, raw) in csv.lines().enumerate() {
+        let line = index + 1;
+        let raw = raw.trim();
+        if raw.is_empty() || (line == 1 && raw.to_ascii_lowercase().starts_with("claimant")) {
+            continue;
+        }
+
+        let columns: Vec<&str> = raw.split(',').map(str::trim).collect();
+        if !(2..=3).contains(&columns.len()) {
+            return Err(TreeError::Parse {
+                line,
+                message: format!("expected claimant,amount[,unlock_time], got {} columns", columns.len()),
+            });
+        }
+
+        let amount = columns[1].parse::<u64>().map_err(|err| TreeError::Parse {
+            line,
+            message: format!("invalid amount {:?}: {}", columns[1], err),
+        })?;
+        let unlock_time = match columns.get(2) {
+            Some(value) if !value.is_empty() => Some(value.parse::<i64>().map_err(|err| TreeError::Parse {
+                line,
+                message: format!("invalid unlock_time {:?}: {}", value, err),
+            })?),
+            _ => None,
+        };
+
+        entries.push(TreeEntry {
+            claimant: parse_claimant(line, columns[0])?,
+            amount,
+            unlock_time,
+        });
+    }
+
+    Ok(entries)
+}
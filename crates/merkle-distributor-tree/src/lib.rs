@@ -0,0 +1,170 @@
+//! Off-chain merkle tree builder for the token distributor program
+//!
+//! Builds the trees whose roots are set with `set_merkle_root` and the proofs
+//! passed to the claim instructions. Hashing and node ordering are
+//! byte-compatible with the program's `utils::verify`:
+//!
+//! - Tree version 1: `leaf = sha256(0x00 || distributor || claimant || amount_le)`
+//! - Tree version 2: the version 1 leaf preimage followed by `unlock_time_le`
+//! - Nodes: `sha256(0x01 || sorted(left, right))`, the last node of an odd level
+//!   is paired with itself
+
+mod error;
+mod hash;
+mod input;
+
+use std::collections::HashSet;
+
+use solana_pubkey::Pubkey;
+
+pub use error::TreeError;
+pub use hash::{compute_root, hash_leaf, hash_leaf_with_unlock_time, hash_node, verify, LEAF_PREFIX, NODE_PREFIX};
+pub use input::{entries_from_csv, entries_from_json};
+
+/// Tree version without per-user unlock times
+pub const TREE_VERSION: u8 = 1;
+
+/// Tree version with per-user unlock times
+pub const UNLOCK_TIME_TREE_VERSION: u8 = 2;
+
+/// A claimant's allocation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TreeEntry {
+    /// Address allowed to claim the allocation
+    pub claimant: Pubkey,
+    /// Total amount allocated, in raw base units
+    pub amount: u64,
+    /// Time before which the allocation cannot be claimed (tree version 2 only)
+    pub unlock_time: Option<i64>,
+}
+
+impl TreeEntry {
+    /// Computes this entry's leaf for the given distributor
+    pub fn leaf(&self, distributor: &Pubkey) -> [u8; 32] {
+        match self.unlock_time {
+            Some(unlock_time) => hash_leaf_with_unlock_time(distributor, &self.claimant, self.amount, unlock_time),
+            None => hash_leaf(distributor, &self.claimant, self.amount),
+        }
+    }
+}
+
+/// Merkle tree over the allocations of one distributor
+#[derive(Debug, Clone)]
+pub struct MerkleTree {
+    entries: Vec<TreeEntry>,
+    /// All levels, leaves first and the root last
+    nodes: Vec<[u8; 32]>,
+    leaf_count: usize,
+}
+
+impl MerkleTree {
+    /// Builds the tree for a distributor's allocations
+    /// - Entries keep their order, so entry i is proven with get_proof(i)
+    /// - All entries must agree on whether they carry an unlock time
+    /// - Each claimant may appear only once, as claims are tracked per claimant
+    pub fn new(distributor: &Pubkey, entries: Vec<TreeEntry>) -> Result<Self, TreeError> {
+        if entries.is_empty() {
+            return Err(TreeError::Empty);
+        }
+        let with_unlock_time = entries[0].unlock_time.is_some();
+        if entries.iter().any(|entry| entry.unlock_time.is_some() != with_unlock_time) {
+            return Err(TreeError::MixedTreeVersions);
+        }
+        let mut claimants = HashSet::with_capacity(entries.len());
+        if let Some(duplicate) = entries.iter().find(|entry| !claimants.insert(entry.claimant)) {
+            return Err(TreeError::DuplicateClaimant(duplicate.claimant.to_string()));
+        }
+
+        let leaves = entries.iter().map(|entry| entry.leaf(distributor)).collect();
+        let mut tree = Self::from_leaves(leaves);
+        tree.entries = entries;
+        Ok(tree)
+    }
+
+    /// Builds a tree from precomputed leaf hashes (any tree version)
+    /// - The resulting tree has no entries, so proof_for always returns None
+    pub fn from_leaves(leaves: Vec<[u8; 32]>) -> Self {
+        let leaf_count = leaves.len();
+        let mut nodes = leaves;
+
+        let mut level_start = 0;
+        let mut level_len = leaf_count;
+        while level_len > 1 {
+            for i in (0..level_len).step_by(2) {
+                let left = nodes[level_start + i];
+                let right = nodes[level_start + (i + 1).min(level_len - 1)];
+                nodes.push(hash_node(&left, &right));
+            }
+            level_start += level_len;
+            level_len = level_len.div_ceil(2);
+        }
+
+        Self {
+            entries: Vec::new(),
+            nodes,
+            leaf_count,
+        }
+    }
+
+    /// Tree version to create the distributor with
+    pub fn tree_version(&self) -> u8 {
+        match self.entries.first() {
+            Some(entry) if entry.unlock_time.is_some() => UNLOCK_TIME_TREE_VERSION,
+            _ => TREE_VERSION,
+        }
+    }
+
+    /// Number of leaves
+    pub fn len(&self) -> usize {
+        self.leaf_count
+    }
+
+    /// Whether the tree has no leaves
+    pub fn is_empty(&self) -> bool {
+        self.leaf_count == 0
+    }
+
+    /// Entries the tree was built from
+    pub fn entries(&self) -> &[TreeEntry] {
+        &self.entries
+    }
+
+    /// Root to pass to set_merkle_root
+    /// - All zeroes for an empty tree, which the program rejects
+    pub fn get_root(&self) -> [u8; 32] {
+        self.nodes.last().copied().unwrap_or_default()
+    }
+
+    /// Proof for the leaf at the given index, None if out of bounds
+    pub fn get_proof(&self, index: usize) -> Option<Vec<[u8; 32]>> {
+        if index >= self.leaf_count {
+            return None;
+        }
+
+        let mut proof = Vec::new();
+        let mut current_index = index;
+        let mut level_start = 0;
+        let mut level_len = self.leaf_count;
+        while level_len > 1 {
+            // The last node of an odd level is its own sibling
+            let sibling_index = if current_index.is_multiple_of(2) {
+                (current_index + 1).min(level_len - 1)
+            } else {
+                current_index - 1
+            };
+            proof.push(self.nodes[level_start + sibling_index]);
+
+            current_index /= 2;
+            level_start += level_len;
+            level_len = level_len.div_ceil(2);
+        }
+
+        Some(proof)
+    }
+
+    /// Entry and proof of a claimant, None if the claimant is not in the tree
+    pub fn proof_for(&self, claimant: &Pubkey) -> Option<(&TreeEntry, Vec<[u8; 32]>)> {
+        let index = self.entries.iter().position(|entry| entry.claimant == *claimant)?;
+        Some((&self.entries[index], self.get_proof(index)?))
+    }
+}
@@ -0,0 +1,102 @@
+//! Byte-compatibility with the on-chain verification and the shared test vectors
+
+use merkle_distributor_tree::{entries_from_csv, entries_from_json, MerkleTree, TreeEntry, TreeError};
+use serde_json::Value;
+use solana_pubkey::Pubkey;
+use std::str::FromStr;
+
+const VECTORS: &str = include_str!("../../../tests/vectors/merkle_tree_vectors.json");
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[test]
+fn test_matches_shared_vectors() {
+    let vectors: Value = serde_json::from_str(VECTORS).unwrap();
+    for case in vectors["cases"].as_array().unwrap() {
+        let distributor = Pubkey::from_str(case["distributor"].as_str().unwrap()).unwrap();
+        let entries = entries_from_json(&case["entries"].to_string()).unwrap();
+        let tree = MerkleTree::new(&distributor, entries).unwrap();
+
+        assert_eq!(tree.tree_version() as u64, case["tree_version"].as_u64().unwrap());
+        assert_eq!(to_hex(&tree.get_root()), case["root"].as_str().unwrap());
+        for (index, expected) in case["proofs"].as_array().unwrap().iter().enumerate() {
+            let proof: Vec<String> = tree.get_proof(index).unwrap().iter().map(|node| to_hex(node)).collect();
+            let expected: Vec<String> = serde_json::from_value(expected.clone()).unwrap();
+            assert_eq!(proof, expected, "{} proof {}", case["name"], index);
+        }
+    }
+}
+
+#[test]
+fn test_proofs_verify_on_chain() {
+    let distributor = Pubkey::new_unique();
+    for with_unlock_time in [false, true] {
+        let entries: Vec<TreeEntry> = (0..13)
+            .map(|i| TreeEntry {
+                claimant: Pubkey::new_unique(),
+                amount: 1_000 + i,
+                unlock_time: with_unlock_time.then_some(1_700_000_000 + i as i64),
+            })
+            .collect();
+        let tree = MerkleTree::new(&distributor, entries.clone()).unwrap();
+
+        for (index, entry) in entries.iter().enumerate() {
+            // Leaves as computed by the program
+            let leaf = match entry.unlock_time {
+                Some(unlock_time) => token_distributor::utils::hash_leaf_with_unlock_time(
+                    &distributor,
+                    &entry.claimant,
+                    entry.amount,
+                    unlock_time,
+                ),
+                None => token_distributor::utils::hash_leaf(&distributor, &entry.claimant, entry.amount),
+            };
+            assert_eq!(leaf, entry.leaf(&distributor));
+            assert!(token_distributor::utils::verify(&tree.get_proof(index).unwrap(), tree.get_root(), leaf));
+        }
+    }
+}
+
+#[test]
+fn test_csv_and_json_ingestion_agree() {
+    let a = Pubkey::new_unique();
+    let b = Pubkey::new_unique();
+    let csv = format!("claimant,amount,unlock_time\n{},100,5\n\n{},200,-1\n", a, b);
+    let json = format!(
+        r#"[{{"claimant":"{}","amount":100,"unlock_time":5}},{{"claimant":"{}","amount":"200","unlock_time":"-1"}}]"#,
+        a, b
+    );
+    assert_eq!(entries_from_csv(&csv).unwrap(), entries_from_json(&json).unwrap());
+
+    let entries = entries_from_csv(&format!("{},100\n{},200", a, b)).unwrap();
+    assert!(entries.iter().all(|entry| entry.unlock_time.is_none()));
+
+    assert!(matches!(entries_from_csv("not-a-key,1"), Err(TreeError::Parse { line: 1, .. })));
+    assert!(matches!(
+        entries_from_csv(&format!("{},100\n{},-5", a, b)),
+        Err(TreeError::Parse { line: 2, .. })
+    ));
+}
+
+#[test]
+fn test_rejects_invalid_entry_sets() {
+    let distributor = Pubkey::new_unique();
+    let claimant = Pubkey::new_unique();
+    let entry = |claimant, unlock_time| TreeEntry {
+        claimant,
+        amount: 1,
+        unlock_time,
+    };
+
+    assert_eq!(MerkleTree::new(&distributor, vec![]).unwrap_err(), TreeError::Empty);
+    assert_eq!(
+        MerkleTree::new(&distributor, vec![entry(claimant, None), entry(Pubkey::new_unique(), Some(1))]).unwrap_err(),
+        TreeError::MixedTreeVersions
+    );
+    assert_eq!(
+        MerkleTree::new(&distributor, vec![entry(claimant, None), entry(claimant, None)]).unwrap_err(),
+        TreeError::DuplicateClaimant(claimant.to_string())
+    );
+}
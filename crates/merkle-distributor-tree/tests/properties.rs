@@ -0,0 +1,107 @@
+//! Property tests over randomly generated trees
+//!
+//! Uses a small deterministic generator so failures are reproducible from the seed.
+
+use merkle_distributor_tree::{compute_root, verify, MerkleTree, TreeEntry};
+use solana_pubkey::Pubkey;
+
+const CASES: u64 = 200;
+
+/// xorshift64* generator
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound
+    }
+
+    fn pubkey(&mut self) -> Pubkey {
+        let mut bytes = [0u8; 32];
+        for chunk in bytes.chunks_mut(8) {
+            chunk.copy_from_slice(&self.next().to_le_bytes());
+        }
+        Pubkey::new_from_array(bytes)
+    }
+
+    fn tree(&mut self, distributor: &Pubkey) -> (Vec<TreeEntry>, MerkleTree) {
+        let len = 1 + self.below(64) as usize;
+        let with_unlock_time = self.below(2) == 1;
+        let entries: Vec<TreeEntry> = (0..len)
+            .map(|_| TreeEntry {
+                claimant: self.pubkey(),
+                amount: self.next(),
+                unlock_time: with_unlock_time.then(|| self.next() as i64),
+            })
+            .collect();
+        let tree = MerkleTree::new(distributor, entries.clone()).unwrap();
+        (entries, tree)
+    }
+}
+
+#[test]
+fn prop_every_entry_verifies() {
+    for seed in 1..=CASES {
+        let mut rng = Rng(seed);
+        let distributor = rng.pubkey();
+        let (entries, tree) = rng.tree(&distributor);
+
+        let depth = (entries.len() as f64).log2().ceil() as usize;
+        for (index, entry) in entries.iter().enumerate() {
+            let proof = tree.get_proof(index).unwrap();
+            assert_eq!(proof.len(), depth, "seed {}", seed);
+            assert!(verify(&proof, tree.get_root(), entry.leaf(&distributor)), "seed {}", seed);
+            assert_eq!(tree.proof_for(&entry.claimant).unwrap().1, proof, "seed {}", seed);
+        }
+        assert!(tree.get_proof(entries.len()).is_none());
+    }
+}
+
+#[test]
+fn prop_changed_entries_do_not_verify() {
+    for seed in 1..=CASES {
+        let mut rng = Rng(seed);
+        let distributor = rng.pubkey();
+        let (entries, tree) = rng.tree(&distributor);
+        let index = rng.below(entries.len() as u64) as usize;
+        let proof = tree.get_proof(index).unwrap();
+        let entry = entries[index];
+
+        let mut inflated = entry;
+        inflated.amount = entry.amount.wrapping_add(1);
+        assert!(!verify(&proof, tree.get_root(), inflated.leaf(&distributor)), "seed {}", seed);
+
+        let mut other_claimant = entry;
+        other_claimant.claimant = rng.pubkey();
+        assert!(!verify(&proof, tree.get_root(), other_claimant.leaf(&distributor)), "seed {}", seed);
+
+        // Leaves are bound to the distributor
+        assert!(!verify(&proof, tree.get_root(), entry.leaf(&rng.pubkey())), "seed {}", seed);
+    }
+}
+
+#[test]
+fn prop_root_depends_on_every_leaf() {
+    for seed in 1..=CASES {
+        let mut rng = Rng(seed);
+        let distributor = rng.pubkey();
+        let (mut entries, tree) = rng.tree(&distributor);
+        let index = rng.below(entries.len() as u64) as usize;
+        entries[index].amount = entries[index].amount.wrapping_add(1);
+
+        let changed = MerkleTree::new(&distributor, entries.clone()).unwrap();
+        assert_ne!(changed.get_root(), tree.get_root(), "seed {}", seed);
+        assert_eq!(
+            compute_root(&changed.get_proof(index).unwrap(), entries[index].leaf(&distributor)),
+            changed.get_root(),
+            "seed {}",
+            seed
+        );
+    }
+}
@@ -0,0 +1,377 @@
+//! Stress and property tests of the token distributor program
+//!
+//! Runs the program natively on `solana-program-test`, builds its instructions
+//! with `token-distributor-client` and its trees with `merkle-distributor-tree`,
+//! and checks the distributor's accounting invariants after every step of
+//! randomly generated campaigns (see `tests/stress.rs`).
+//!
+//! Campaigns are generated from a seed, so a failure reproduces with the seed
+//! it reports. The environment tunes a run:
+//!
+//! - `STRESS_SEED`: seed of the first case (default 0), case `i` uses `seed + i`
+//! - `STRESS_CASES`: number of random campaigns per test (default 4)
+//! - `STRESS_MAX_LEAVES_LOG2`: trees have up to `2^n` leaves (default 10, at most 20)
+
+use std::collections::HashSet;
+
+use anchor_lang::prelude::{AccountInfo, Clock, Pubkey};
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::solana_program::system_instruction;
+use anchor_spl::token_2022::spl_token_2022;
+use anchor_spl::token_2022::spl_token_2022::extension::StateWithExtensions;
+use merkle_distributor_tree::{MerkleTree, TreeEntry};
+use rand::seq::index;
+use rand::Rng;
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::account::Account;
+use solana_sdk::instruction::{Instruction, InstructionError};
+use solana_sdk::signature::{Keypair, Signature, Signer};
+use solana_sdk::signer::keypair::keypair_from_seed;
+use solana_sdk::system_program;
+use solana_sdk::transaction::{Transaction, TransactionError};
+use token_distributor::error::TokenDistributorError;
+use token_distributor::state::{ClaimStatus, ClaimWindow, TokenDistributor};
+use token_distributor_client::accounts::{deserialize_account, deserialize_distributor};
+use token_distributor_client::pda::{derive_claim_status, derive_distributor, derive_vault};
+use token_distributor_client::{instruction, instructions, ID};
+
+/// Mint decimals of every campaign
+pub const DECIMALS: u8 = 6;
+
+/// Clock start of every campaign (Unix timestamp)
+pub const GENESIS: i64 = 1_700_000_000;
+
+pub const DAY: i64 = 24 * 60 * 60;
+
+/// Largest allocation of a generated leaf, in raw base units
+pub const MAX_ALLOCATION: u64 = 1_000_000;
+
+/// Deepest trees the harness generates, 2^20 leaves
+pub const MAX_LEAVES_LOG2: u32 = 20;
+
+/// Run parameters, read from the environment
+#[derive(Debug, Clone, Copy)]
+pub struct StressConfig {
+    pub seed: u64,
+    pub cases: u64,
+    pub max_leaves_log2: u32,
+}
+
+impl StressConfig {
+    pub fn from_env() -> Self {
+        let var = |name: &str| std::env::var(name).ok().and_then(|value| value.parse::<u64>().ok());
+        StressConfig {
+            seed: var("STRESS_SEED").unwrap_or(0),
+            cases: var("STRESS_CASES").unwrap_or(4),
+            max_leaves_log2: var("STRESS_MAX_LEAVES_LOG2").map_or(10, |n| n.clamp(1, MAX_LEAVES_LOG2 as u64) as u32),
+        }
+    }
+
+    /// Seeds of the cases of this run
+    pub fn seeds(&self) -> impl Iterator<Item = u64> {
+        let seed = self.seed;
+        (0..self.cases).map(move |case| seed.wrapping_add(case))
+    }
+}
+
+/// A claimant whose keys the test holds, with the state the program should have recorded for them
+pub struct Claimant {
+    pub keypair: Keypair,
+    pub token_account: Pubkey,
+    /// Index of the claimant's leaf in the current tree
+    pub index: usize,
+    /// Amount the claimant should have claimed so far
+    pub claimed: u64,
+    /// Successful claims of the claimant
+    pub claims: u64,
+    /// Highest allocation of the claimant under any root set so far
+    pub max_allocation: u64,
+}
+
+impl Claimant {
+    pub fn pubkey(&self) -> Pubkey {
+        self.keypair.pubkey()
+    }
+}
+
+/// Keypair derived from the generator, so that a seed reproduces the same claimants
+pub fn random_keypair(rng: &mut impl Rng) -> Keypair {
+    keypair_from_seed(&rng.gen::<[u8; 32]>()).unwrap()
+}
+
+/// Generates a tree of `leaf_count` random allocations, the claimants' leaves at random indices
+///
+/// Returns the entries with each claimant's index. Leaves not held by a claimant
+/// belong to fresh addresses nobody can sign for.
+pub fn random_entries(rng: &mut impl Rng, leaf_count: usize, claimants: &[Pubkey]) -> (Vec<TreeEntry>, Vec<usize>) {
+    assert!(claimants.len() <= leaf_count);
+    let mut entries: Vec<TreeEntry> = (0..leaf_count)
+        .map(|_| TreeEntry { claimant: Pubkey::new_unique(), amount: rng.gen_range(1..=MAX_ALLOCATION), unlock_time: None })
+        .collect();
+    let indices = index::sample(rng, leaf_count, claimants.len()).into_vec();
+    for (claimant, index) in claimants.iter().zip(&indices) {
+        entries[*index].claimant = *claimant;
+    }
+    (entries, indices)
+}
+
+/// Leaf count of a random tree, at least `min` and at most `2^max_log2`
+pub fn random_leaf_count(rng: &mut impl Rng, min: usize, max_log2: u32) -> usize {
+    // Pick the depth first, so that small and large trees are equally likely
+    let min = min.max(2);
+    let max = (1usize << rng.gen_range(1..=max_log2)).max(min);
+    rng.gen_range(min..=max)
+}
+
+/// Native entrypoint of the program
+fn process_instruction(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    // Anchor's entrypoint ties the account slice to the account lifetimes
+    let accounts = Box::leak(accounts.to_vec().into_boxed_slice());
+    token_distributor::entry(program_id, accounts, data)
+}
+
+/// Custom program error of a failed transaction, if any
+pub fn error_code(error: &BanksClientError) -> Option<u32> {
+    match error {
+        BanksClientError::TransactionError(TransactionError::InstructionError(_, InstructionError::Custom(code)))
+        | BanksClientError::SimulationError {
+            err: TransactionError::InstructionError(_, InstructionError::Custom(code)),
+            ..
+        } => Some(*code),
+        _ => None,
+    }
+}
+
+/// Asserts a transaction failed with the given program error
+#[track_caller]
+pub fn assert_error(result: Result<(), BanksClientError>, error: TokenDistributorError, context: &str) {
+    match result {
+        Ok(()) => panic!("{}: expected {:?}, transaction succeeded", context, error),
+        Err(e) => assert_eq!(error_code(&e), Some(u32::from(error)), "{}: expected {:?}, got {:?}", context, error, e),
+    }
+}
+
+/// A funded distributor on a fresh bank, the owner being its operator
+pub struct Campaign {
+    pub context: ProgramTestContext,
+    pub owner: Keypair,
+    pub token_program: Pubkey,
+    pub mint: Pubkey,
+    pub distributor: Pubkey,
+    pub vault: Pubkey,
+    pub deposit: u64,
+    /// Signatures of the transactions sent so far, to avoid resending one
+    sent: HashSet<Signature>,
+}
+
+impl Campaign {
+    /// Sets up a mint and a distributor holding `deposit` tokens, open for `duration` seconds
+    ///
+    /// Time and root are not set yet.
+    pub async fn new(token_2022: bool, deposit: u64, duration: i64, tree_version: Option<u8>) -> Self {
+        let mut program_test = ProgramTest::default();
+        // Transaction errors carry what a failing case needs, RUST_LOG still overrides
+        solana_logger::setup_with_default("error");
+        program_test.add_program("token_distributor", ID, processor!(process_instruction));
+        let owner = Keypair::new();
+        program_test.add_account(owner.pubkey(), Account::new(1_000_000_000_000, 0, &system_program::ID));
+        let context = program_test.start_with_context().await;
+        let token_program = if token_2022 { spl_token_2022::ID } else { anchor_spl::token::ID };
+
+        let mut campaign = Campaign {
+            context,
+            owner,
+            token_program,
+            mint: Pubkey::default(),
+            distributor: Pubkey::default(),
+            vault: Pubkey::default(),
+            deposit,
+            sent: HashSet::new(),
+        };
+        campaign.warp_to(GENESIS).await;
+
+        // Mint the deposit to the owner and fund the distributor with it
+        let mint = Keypair::new();
+        let owner_key = campaign.owner.pubkey();
+        let rent = campaign.minimum_balance(spl_token_2022::state::Mint::LEN).await;
+        let create_mint = [
+            system_instruction::create_account(&owner_key, &mint.pubkey(), rent, spl_token_2022::state::Mint::LEN as u64, &token_program),
+            spl_token_2022::instruction::initialize_mint2(&token_program, &mint.pubkey(), &owner_key, None, DECIMALS).unwrap(),
+        ];
+        campaign.send(&create_mint, &[&mint]).await.unwrap();
+        campaign.mint = mint.pubkey();
+        let owner_token_account = campaign.create_token_account(&owner_key).await;
+        let mint_to =
+            spl_token_2022::instruction::mint_to(&token_program, &campaign.mint, &owner_token_account, &owner_key, &[], deposit)
+                .unwrap();
+        campaign.send(&[mint_to], &[]).await.unwrap();
+
+        campaign.distributor = derive_distributor(&campaign.mint, &owner_key, 1).0;
+        campaign.vault = derive_vault(&campaign.distributor).0;
+        let create = instructions::create_distributor(
+            &owner_key,
+            &owner_key,
+            &campaign.mint,
+            &owner_token_account,
+            &token_program,
+            1,
+            instruction::CreateDistributor {
+                initial_total_amount: deposit,
+                duration: Some(duration),
+                clawback_receiver: None,
+                tree_version,
+                withdraw_delay: None,
+                activation_type: None,
+            },
+        );
+        campaign.send(&[create], &[]).await.unwrap();
+        campaign
+    }
+
+    /// Signs with the owner and the given signers and processes the instructions
+    pub async fn send(&mut self, instructions: &[Instruction], signers: &[&Keypair]) -> Result<(), BanksClientError> {
+        let mut all_signers = vec![&self.owner];
+        all_signers.extend_from_slice(signers);
+        let mut tx = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.owner.pubkey()),
+            &all_signers,
+            self.context.last_blockhash,
+        );
+        // An identical transaction would be rejected as already processed
+        if !self.sent.insert(tx.signatures[0]) {
+            let blockhash = self.context.get_new_latest_blockhash().await.unwrap();
+            tx.sign(&all_signers, blockhash);
+            self.sent.insert(tx.signatures[0]);
+        }
+        self.context.banks_client.process_transaction(tx).await
+    }
+
+    /// Moves the clock to the given Unix timestamp
+    pub async fn warp_to(&mut self, unix_timestamp: i64) {
+        let mut clock: Clock = self.context.banks_client.get_sysvar().await.unwrap();
+        clock.unix_timestamp = unix_timestamp;
+        self.context.set_sysvar(&clock);
+    }
+
+    pub async fn minimum_balance(&mut self, len: usize) -> u64 {
+        self.context.banks_client.get_rent().await.unwrap().minimum_balance(len)
+    }
+
+    /// Creates a token account of the mint owned by `authority`, at a fresh address
+    pub async fn create_token_account(&mut self, authority: &Pubkey) -> Pubkey {
+        let account = Keypair::new();
+        let len = spl_token_2022::state::Account::LEN;
+        let rent = self.minimum_balance(len).await;
+        let instructions = [
+            system_instruction::create_account(&self.owner.pubkey(), &account.pubkey(), rent, len as u64, &self.token_program),
+            spl_token_2022::instruction::initialize_account3(&self.token_program, &account.pubkey(), &self.mint, authority).unwrap(),
+        ];
+        self.send(&instructions, &[&account]).await.unwrap();
+        account.pubkey()
+    }
+
+    /// Creates a token account for each claimant, whose leaves are at the given indices of `entries`
+    pub async fn add_claimants(&mut self, keypairs: Vec<Keypair>, indices: &[usize], entries: &[TreeEntry]) -> Vec<Claimant> {
+        let mut claimants = Vec::with_capacity(keypairs.len());
+        for (keypair, index) in keypairs.into_iter().zip(indices) {
+            let token_account = self.create_token_account(&keypair.pubkey()).await;
+            let max_allocation = entries[*index].amount;
+            claimants.push(Claimant { keypair, token_account, index: *index, claimed: 0, claims: 0, max_allocation });
+        }
+        claimants
+    }
+
+    pub async fn set_time(&mut self, start_time: i64) -> Result<(), BanksClientError> {
+        let ix = instructions::set_time(&self.distributor, &self.owner.pubkey(), start_time);
+        self.send(&[ix], &[]).await
+    }
+
+    pub async fn set_merkle_root(&mut self, merkle_root: [u8; 32]) -> Result<(), BanksClientError> {
+        let ix = instructions::set_merkle_root(&self.distributor, &self.owner.pubkey(), merkle_root);
+        self.send(&[ix], &[]).await
+    }
+
+    pub async fn set_claim_windows(&mut self, claim_windows: Vec<ClaimWindow>) -> Result<(), BanksClientError> {
+        let ix = instructions::set_claim_windows(&self.distributor, &self.owner.pubkey(), claim_windows);
+        self.send(&[ix], &[]).await
+    }
+
+    /// Claims `amount` (all that is pending if `None`) of `entry` with `proof`, signed by the claimant
+    ///
+    /// The owner pays the fees and rent.
+    pub async fn claim(
+        &mut self,
+        claimant: &Claimant,
+        entry: &TreeEntry,
+        proof: Vec<[u8; 32]>,
+        amount: Option<u64>,
+    ) -> Result<(), BanksClientError> {
+        let accounts = instructions::claim_accounts(
+            &self.distributor,
+            &claimant.pubkey(),
+            &self.owner.pubkey(),
+            &self.mint,
+            &claimant.token_account,
+            &self.token_program,
+        );
+        let mut ix = instructions::claim(
+            accounts,
+            instruction::Claim { max_amount: entry.amount, proof, amount, unlock_time: entry.unlock_time, cosign_expiry: None },
+        );
+        // The claimant is only optionally a signer, as claims may be signed by a delegate
+        ix.accounts.iter_mut().find(|meta| meta.pubkey == claimant.pubkey()).unwrap().is_signer = true;
+        self.send(&[ix], &[&claimant.keypair]).await
+    }
+
+    pub async fn account(&mut self, address: &Pubkey) -> Option<Account> {
+        self.context.banks_client.get_account(*address).await.unwrap()
+    }
+
+    pub async fn distributor(&mut self) -> TokenDistributor {
+        let address = self.distributor;
+        let data = self.account(&address).await.expect("distributor exists").data;
+        deserialize_distributor(&data).unwrap()
+    }
+
+    pub async fn claim_status(&mut self, claimant: &Pubkey) -> Option<ClaimStatus> {
+        let (address, _) = derive_claim_status(&self.distributor, claimant);
+        let data = self.account(&address).await?.data;
+        Some(deserialize_account(&data).unwrap())
+    }
+
+    /// Token balance of an account of either token program
+    pub async fn token_balance(&mut self, account: &Pubkey) -> u64 {
+        let data = self.account(account).await.expect("token account exists").data;
+        StateWithExtensions::<spl_token_2022::state::Account>::unpack(&data).unwrap().base.amount
+    }
+
+    /// Asserts the accounting invariants of the distributor against the claimants' expected state
+    ///
+    /// Only the given claimants may have claimed.
+    pub async fn check_invariants(&mut self, claimants: &[Claimant], context: &str) {
+        let distributor = self.distributor().await;
+        let vault = self.vault;
+        let vault_balance = self.token_balance(&vault).await;
+        assert!(distributor.total_claimed <= self.deposit, "{}: claimed more than deposited", context);
+        assert_eq!(vault_balance, self.deposit - distributor.total_claimed, "{}: vault out of sync with total_claimed", context);
+        let claimed: u64 = claimants.iter().map(|claimant| claimant.claimed).sum();
+        assert_eq!(distributor.total_claimed, claimed, "{}: total_claimed", context);
+        let claims: u64 = claimants.iter().map(|claimant| claimant.claims).sum();
+        assert_eq!(distributor.claims_count, claims, "{}: claims_count", context);
+
+        for claimant in claimants {
+            let recorded = self.claim_status(&claimant.pubkey()).await.map_or(0, |status| status.claimed_amount);
+            assert_eq!(recorded, claimant.claimed, "{}: claimed_amount of {}", context, claimant.pubkey());
+            assert!(recorded <= claimant.max_allocation, "{}: {} claimed beyond any allocation", context, claimant.pubkey());
+            let balance = self.token_balance(&claimant.token_account).await;
+            assert_eq!(balance, claimant.claimed, "{}: token balance of {}", context, claimant.pubkey());
+        }
+    }
+}
+
+/// Tree of the entries for the campaign's distributor
+pub fn build_tree(campaign: &Campaign, entries: Vec<TreeEntry>) -> MerkleTree {
+    MerkleTree::new(&campaign.distributor, entries).expect("generated entries are valid")
+}
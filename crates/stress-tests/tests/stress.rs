@@ -0,0 +1,270 @@
+//! Randomized campaigns checking the distributor's accounting invariants
+//!
+//! Each test runs `STRESS_CASES` campaigns from `STRESS_SEED` on, alternating
+//! SPL Token and Token 2022 mints, and reports the seed of a failing case.
+
+use merkle_distributor_tree::{MerkleTree, TreeEntry};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+use token_distributor::constants::MAX_ROOT_HISTORY;
+use token_distributor::error::TokenDistributorError;
+use token_distributor::state::ClaimWindow;
+use token_distributor_stress_tests::*;
+
+const DURATION: i64 = 30 * DAY;
+
+/// Most claimants holding keys in a campaign
+const MAX_CLAIMANTS: usize = 6;
+
+/// Most roots set during a campaign
+const MAX_ROUNDS: usize = 6;
+
+/// Asserts the outcome of a claim, then records a successful claim of `amount` in the model
+#[track_caller]
+fn expect_claim(
+    result: Result<(), BanksClientError>,
+    expected: Result<(), TokenDistributorError>,
+    claimant: &mut Claimant,
+    amount: u64,
+    context: &str,
+) {
+    match expected {
+        Ok(()) => {
+            result.unwrap_or_else(|e| panic!("{}: claim of {} failed: {:?}", context, amount, e));
+            claimant.claimed += amount;
+            claimant.claims += 1;
+        }
+        Err(error) => assert_error(result, error, context),
+    }
+}
+
+/// Claims in random order, in random portions, with valid, cached and invalid proofs
+#[tokio::test]
+async fn test_random_claim_order() {
+    let config = StressConfig::from_env();
+    for seed in config.seeds() {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let keypairs: Vec<Keypair> = (0..rng.gen_range(1..=MAX_CLAIMANTS)).map(|_| random_keypair(&mut rng)).collect();
+        let pubkeys: Vec<_> = keypairs.iter().map(|keypair| keypair.pubkey()).collect();
+        let leaf_count = random_leaf_count(&mut rng, pubkeys.len(), config.max_leaves_log2);
+        let (entries, indices) = random_entries(&mut rng, leaf_count, &pubkeys);
+        let deposit = entries.iter().map(|entry| entry.amount).sum();
+
+        let mut campaign = Campaign::new(seed % 2 == 1, deposit, DURATION, None).await;
+        let mut claimants = campaign.add_claimants(keypairs, &indices, &entries).await;
+        let tree = build_tree(&campaign, entries);
+        campaign.set_merkle_root(tree.get_root()).await.unwrap();
+        campaign.set_time(GENESIS + DAY).await.unwrap();
+        campaign.warp_to(GENESIS + DAY).await;
+
+        let steps = rng.gen_range(claimants.len()..=4 * claimants.len());
+        for step in 0..steps {
+            let context = format!("seed {} ({} leaves), step {}", seed, leaf_count, step);
+            let claimant = &mut claimants[rng.gen_range(0..pubkeys.len())];
+            let entry = tree.entries()[claimant.index];
+            let proof = tree.get_proof(claimant.index).unwrap();
+            let remaining = entry.amount - claimant.claimed;
+            let exhausted = if remaining == 0 { Err(TokenDistributorError::InvalidAmount) } else { Ok(()) };
+
+            match rng.gen_range(0..5) {
+                // All that is pending
+                0 => {
+                    let result = campaign.claim(claimant, &entry, proof, None).await;
+                    expect_claim(result, exhausted, claimant, remaining, &context);
+                }
+                // A random portion of what is pending
+                1 => {
+                    let amount = rng.gen_range(1..=remaining.max(1));
+                    let result = campaign.claim(claimant, &entry, proof, Some(amount)).await;
+                    expect_claim(result, exhausted, claimant, amount, &context);
+                }
+                // More than is pending
+                2 => {
+                    let result = campaign.claim(claimant, &entry, proof, Some(remaining + 1)).await;
+                    assert_error(result, TokenDistributorError::InvalidAmount, &context);
+                }
+                // No proof, which only claimants who claimed under this root may omit
+                3 => {
+                    let expected = match exhausted {
+                        Ok(()) if claimant.claims == 0 => Err(TokenDistributorError::InvalidProof),
+                        expected => expected,
+                    };
+                    let result = campaign.claim(claimant, &entry, vec![], None).await;
+                    expect_claim(result, expected, claimant, remaining, &context);
+                }
+                // A proof for more than the allocation
+                _ => {
+                    let inflated = TreeEntry { amount: entry.amount + 1, ..entry };
+                    let result = campaign.claim(claimant, &inflated, proof, None).await;
+                    assert_error(result, TokenDistributorError::InvalidProof, &context);
+                }
+            }
+            campaign.check_invariants(&claimants, &context).await;
+        }
+    }
+}
+
+/// Roots raise and lower allocations mid-campaign, proofs of the previous roots stay valid for a while
+#[tokio::test]
+async fn test_root_updates_mid_campaign() {
+    let config = StressConfig::from_env();
+    for seed in config.seeds() {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let keypairs: Vec<Keypair> = (0..rng.gen_range(1..=MAX_CLAIMANTS)).map(|_| random_keypair(&mut rng)).collect();
+        let pubkeys: Vec<_> = keypairs.iter().map(|keypair| keypair.pubkey()).collect();
+        let leaf_count = random_leaf_count(&mut rng, pubkeys.len(), config.max_leaves_log2);
+        let rounds: Vec<(Vec<TreeEntry>, Vec<usize>)> =
+            (0..rng.gen_range(2..=MAX_ROUNDS)).map(|_| random_entries(&mut rng, leaf_count, &pubkeys)).collect();
+        // The vault covers the highest allocation of every claimant
+        let deposit = (0..pubkeys.len())
+            .map(|claimant| rounds.iter().map(|(entries, indices)| entries[indices[claimant]].amount).max().unwrap())
+            .sum();
+
+        let mut campaign = Campaign::new(seed % 2 == 1, deposit, DURATION, None).await;
+        let (entries, indices) = &rounds[0];
+        let mut claimants = campaign.add_claimants(keypairs, indices, entries).await;
+        let trees: Vec<MerkleTree> = rounds.iter().map(|(entries, _)| build_tree(&campaign, entries.clone())).collect();
+        campaign.set_time(GENESIS + DAY).await.unwrap();
+        campaign.warp_to(GENESIS + DAY).await;
+
+        for (round, tree) in trees.iter().enumerate() {
+            let context = format!("seed {} ({} leaves), root {}", seed, leaf_count, round);
+            campaign.set_merkle_root(tree.get_root()).await.unwrap();
+            let (entries, indices) = &rounds[round];
+            for (claimant, index) in claimants.iter_mut().zip(indices) {
+                claimant.max_allocation = claimant.max_allocation.max(entries[*index].amount);
+            }
+
+            for step in 0..rng.gen_range(1..=2 * claimants.len()) {
+                let context = format!("{}, step {}", context, step);
+                let position = rng.gen_range(0..pubkeys.len());
+                let claimant = &mut claimants[position];
+                // Claim under this root or an earlier one, which may have left the root history
+                let proven_round = rng.gen_range(0..=round);
+                let index = rounds[proven_round].1[position];
+                let entry = trees[proven_round].entries()[index];
+                let proof = trees[proven_round].get_proof(index).unwrap();
+                let expected = if entry.amount < claimant.claimed {
+                    Err(TokenDistributorError::AllocationReduced)
+                } else if entry.amount == claimant.claimed {
+                    Err(TokenDistributorError::InvalidAmount)
+                } else if round - proven_round > MAX_ROOT_HISTORY {
+                    Err(TokenDistributorError::InvalidProof)
+                } else {
+                    Ok(())
+                };
+                let pending = entry.amount.saturating_sub(claimant.claimed);
+                let result = campaign.claim(claimant, &entry, proof, None).await;
+                expect_claim(result, expected, claimant, pending, &context);
+                campaign.check_invariants(&claimants, &context).await;
+            }
+        }
+    }
+}
+
+/// Claims on either side of the start, the end, claim window bounds and unlock times
+#[tokio::test]
+async fn test_time_boundaries() {
+    let config = StressConfig::from_env();
+    for seed in config.seeds() {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let unlock_times = rng.gen_bool(0.5);
+        let start = GENESIS + rng.gen_range(1..=30) * DAY + rng.gen_range(0..DAY);
+        let duration = rng.gen_range(4 * DAY..=60 * DAY);
+        let end = start + duration;
+        // The optional second phase opens `gap` seconds after the first closes
+        let first_end = start + rng.gen_range(1..duration / 2);
+        let second_start = first_end + rng.gen_range(2..DAY);
+        let phased = rng.gen_bool(0.5);
+        let unlock_time = rng.gen_range(if phased { second_start + 1 } else { start + 1 }..=end);
+
+        // The first claimant claims at every boundary, the second one once unlocked
+        let keypairs = vec![random_keypair(&mut rng), random_keypair(&mut rng)];
+        let pubkeys: Vec<_> = keypairs.iter().map(|keypair| keypair.pubkey()).collect();
+        let leaf_count = random_leaf_count(&mut rng, pubkeys.len(), config.max_leaves_log2);
+        let (mut entries, indices) = random_entries(&mut rng, leaf_count, &pubkeys);
+        entries[indices[0]].amount = entries[indices[0]].amount.max(16);
+        if unlock_times {
+            for entry in entries.iter_mut() {
+                entry.unlock_time = Some(start);
+            }
+            entries[indices[1]].unlock_time = Some(unlock_time);
+        }
+        let deposit = entries.iter().map(|entry| entry.amount).sum();
+
+        let tree_version = Some(if unlock_times { 2 } else { 1 });
+        let mut campaign = Campaign::new(seed % 2 == 1, deposit, duration, tree_version).await;
+        let mut claimants = campaign.add_claimants(keypairs, &indices, &entries).await;
+        let tree = build_tree(&campaign, entries);
+        campaign.set_merkle_root(tree.get_root()).await.unwrap();
+        campaign.set_time(start).await.unwrap();
+        if phased {
+            let windows = vec![ClaimWindow { start, end: first_end }, ClaimWindow { start: second_start, end }];
+            campaign.set_claim_windows(windows).await.unwrap();
+        }
+
+        // (time, claimant, expected outcome), claims of one token by the first claimant, all by the second
+        let mut attempts = vec![
+            (start - 1, 0, Err(TokenDistributorError::DistributionNotStarted)),
+            (start, 0, Ok(())),
+            (end, 0, Ok(())),
+            (end + 1, 0, Err(TokenDistributorError::DistributionEnded)),
+        ];
+        if phased {
+            attempts.push((first_end, 0, Ok(())));
+            attempts.push((first_end + 1, 0, Err(TokenDistributorError::OutsideClaimWindow)));
+            attempts.push((second_start, 0, Ok(())));
+        }
+        if unlock_times {
+            attempts.push((unlock_time - 1, 1, Err(TokenDistributorError::ClaimLocked)));
+            attempts.push((unlock_time, 1, Ok(())));
+        }
+        attempts.sort_by_key(|(time, _, _)| *time);
+
+        for (time, position, expected) in attempts {
+            let context = format!("seed {} ({} leaves), claimant {} at {}", seed, leaf_count, position, time);
+            campaign.warp_to(time).await;
+            let claimant = &mut claimants[position];
+            let entry = tree.entries()[claimant.index];
+            let proof = tree.get_proof(claimant.index).unwrap();
+            let amount = if position == 0 { 1 } else { entry.amount };
+            let result = campaign.claim(claimant, &entry, proof, Some(amount)).await;
+            expect_claim(result, expected, claimant, amount, &context);
+            campaign.check_invariants(&claimants, &context).await;
+        }
+    }
+}
+
+/// Every claimant of a 2^20 leaf tree claims with a full depth proof
+///
+/// Building the tree takes a while, run with `cargo test -p token-distributor-stress-tests -- --ignored`.
+#[tokio::test]
+#[ignore]
+async fn test_million_leaf_tree() {
+    let mut rng = StdRng::seed_from_u64(StressConfig::from_env().seed);
+    let leaf_count = 1 << MAX_LEAVES_LOG2;
+    let keypairs: Vec<Keypair> = (0..MAX_CLAIMANTS).map(|_| random_keypair(&mut rng)).collect();
+    let pubkeys: Vec<_> = keypairs.iter().map(|keypair| keypair.pubkey()).collect();
+    let (entries, indices) = random_entries(&mut rng, leaf_count, &pubkeys);
+    let deposit = entries.iter().map(|entry| entry.amount).sum();
+
+    let mut campaign = Campaign::new(true, deposit, DURATION, None).await;
+    let mut claimants = campaign.add_claimants(keypairs, &indices, &entries).await;
+    let tree = build_tree(&campaign, entries);
+    campaign.set_merkle_root(tree.get_root()).await.unwrap();
+    campaign.set_time(GENESIS + DAY).await.unwrap();
+    campaign.warp_to(GENESIS + DAY).await;
+
+    for position in 0..claimants.len() {
+        let context = format!("claimant {}", position);
+        let claimant = &mut claimants[position];
+        let entry = tree.entries()[claimant.index];
+        let proof = tree.get_proof(claimant.index).unwrap();
+        assert_eq!(proof.len(), MAX_LEAVES_LOG2 as usize);
+        let result = campaign.claim(claimant, &entry, proof, None).await;
+        expect_claim(result, Ok(()), claimant, entry.amount, &context);
+        campaign.check_invariants(&claimants, &context).await;
+    }
+}
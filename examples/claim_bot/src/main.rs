@@ -0,0 +1,365 @@
+//! Claim aggregator bot
+//!
+//! Loads a distribution's merkle tree, waits for the claim window to open and
+//! claims for every configured wallet, with priority fees and retries.
+//! Doubles as a stress-test driver: raise `concurrency` to hammer the program.
+//! Transaction fees rotate over a pool of fee payers, topped up from a treasury
+//! key when they run low, so large runs are not bound to one payer's balance
+//! and write lock.
+//!
+//! Usage:
+//!   cargo run -p token-distributor-claim-bot -- examples/claim_bot/config.example.json
+
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::associated_token::spl_associated_token_account::instruction::create_associated_token_account_idempotent;
+use merkle_distributor_tree::{entries_from_json, MerkleTree, TreeEntry};
+use serde::Deserialize;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::clock::Clock;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::signature::{read_keypair_file, Keypair, Signature, Signer};
+use solana_sdk::system_instruction;
+use solana_sdk::sysvar;
+use solana_sdk::transaction::Transaction;
+use std::error::Error;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread::sleep;
+use std::time::Duration;
+use token_distributor_client::accounts::{deserialize_account, fetch_claim_status, fetch_distributor, AccountFetcher};
+use token_distributor_client::state::{ProgramConfig, TokenDistributor};
+use token_distributor_client::{instruction, instructions, pda, ClientError};
+
+type BotResult<T> = Result<T, Box<dyn Error + Send + Sync>>;
+
+/// SPL Memo program, passed for claimant token accounts requiring incoming transfer memos
+const MEMO_PROGRAM_ID: Pubkey = solana_sdk::pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BotConfig {
+    rpc_url: String,
+    distributor: String,
+    /// JSON file with entries `{ claimant, amount, unlock_time? }`, same format as tests/vectors
+    tree: String,
+    /// Keypair files of the wallets to claim for, the first one pays transaction fees without `fee_payers`
+    wallets: Vec<String>,
+    /// Keypair files of the fee payers, rotated per transaction
+    #[serde(default)]
+    fee_payers: Vec<String>,
+    /// Keypair file of the treasury topping up fee payers that run low
+    #[serde(default)]
+    treasury: Option<String>,
+    /// Balance in lamports below which a fee payer is topped up before paying (0 skips the check)
+    #[serde(default)]
+    min_fee_payer_balance: u64,
+    /// Lamports sent to a fee payer by each top-up, at least enough to reach `min_fee_payer_balance`
+    #[serde(default)]
+    fee_payer_top_up: u64,
+    priority_fee_micro_lamports: u64,
+    compute_unit_limit: u32,
+    max_retries: u32,
+    poll_interval_ms: u64,
+    concurrency: usize,
+}
+
+/// A configured wallet with its tree entry and proof
+struct ClaimJob<'a> {
+    wallet: &'a Keypair,
+    entry: TreeEntry,
+    proof: Vec<[u8; 32]>,
+}
+
+/// Fee payers rotated per transaction, topped up from the treasury when they run low
+struct FeePayerPool<'a> {
+    payers: Vec<&'a Keypair>,
+    /// Number of transactions paid so far, transaction n is paid by payers[n % payers.len()]
+    paid: AtomicUsize,
+    treasury: Option<&'a Keypair>,
+    min_balance: u64,
+    top_up: u64,
+    /// Serializes top-ups, so concurrent claims do not top up the same payer twice
+    top_up_lock: Mutex<()>,
+}
+
+impl<'a> FeePayerPool<'a> {
+    /// Next fee payer in rotation, topped up first if its balance is below the minimum
+    fn next(&self, rpc: &Rpc) -> BotResult<&'a Keypair> {
+        let payer = self.payers[self.paid.fetch_add(1, Ordering::Relaxed) % self.payers.len()];
+        if self.min_balance > 0 {
+            self.ensure_balance(rpc, payer)?;
+        }
+        Ok(payer)
+    }
+
+    fn ensure_balance(&self, rpc: &Rpc, payer: &Keypair) -> BotResult<()> {
+        let _guard = self.top_up_lock.lock().map_err(|_| "fee payer top-up lock poisoned")?;
+        let balance = rpc.0.get_balance(&payer.pubkey())?;
+        if balance >= self.min_balance {
+            return Ok(());
+        }
+        let treasury = self.treasury.ok_or_else(|| {
+            format!("fee payer {} holds {} lamports and no treasury is configured to top it up", payer.pubkey(), balance)
+        })?;
+        let amount = self.top_up.max(self.min_balance - balance);
+        let transfer = system_instruction::transfer(&treasury.pubkey(), &payer.pubkey(), amount);
+        let signature = rpc.send(&[transfer], treasury, &[treasury])?;
+        println!("{}: topped up fee payer with {} lamports ({})", payer.pubkey(), amount, signature);
+        Ok(())
+    }
+}
+
+/// Accounts shared by every claim of the distribution
+struct Distribution {
+    address: Pubkey,
+    state: TokenDistributor,
+    token_program: Pubkey,
+    protocol_fee_account: Option<Pubkey>,
+    gate_token_program: Option<Pubkey>,
+}
+
+/// RPC client reading program accounts through the client crate's fetcher interface
+struct Rpc(RpcClient);
+
+impl AccountFetcher for Rpc {
+    fn fetch_account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>, ClientError> {
+        self.0
+            .get_account_with_commitment(address, self.0.commitment())
+            .map(|response| response.value.map(|account| account.data))
+            .map_err(|err| ClientError::Fetch(err.to_string()))
+    }
+}
+
+impl Rpc {
+    fn distributor(&self, address: &Pubkey) -> BotResult<TokenDistributor> {
+        fetch_distributor(self, address)?.ok_or_else(|| format!("distributor {} not found", address).into())
+    }
+
+    fn owner_of(&self, address: &Pubkey) -> BotResult<Pubkey> {
+        Ok(self.0.get_account(address)?.owner)
+    }
+
+    /// Program config, `None` until the global admin initializes it
+    fn program_config(&self) -> BotResult<Option<ProgramConfig>> {
+        let data = self.fetch_account_data(&pda::derive_config().0)?;
+        Ok(data.map(|data| deserialize_account(&data)).transpose()?)
+    }
+
+    /// Signs the instructions with a fresh blockhash and waits for their confirmation
+    fn send(&self, instructions: &[Instruction], fee_payer: &Keypair, signers: &[&Keypair]) -> BotResult<Signature> {
+        let blockhash = self.0.get_latest_blockhash()?;
+        let transaction = Transaction::new_signed_with_payer(instructions, Some(&fee_payer.pubkey()), signers, blockhash);
+        Ok(self.0.send_and_confirm_transaction(&transaction)?)
+    }
+
+    fn clock(&self) -> BotResult<Clock> {
+        Ok(bincode::deserialize(&self.0.get_account_data(&sysvar::clock::ID)?)?)
+    }
+}
+
+fn resolve_path(file: &str) -> PathBuf {
+    match (file.strip_prefix("~/"), std::env::var_os("HOME")) {
+        (Some(rest), Some(home)) => PathBuf::from(home).join(rest),
+        _ => PathBuf::from(file),
+    }
+}
+
+fn load_keypair(file: &str) -> BotResult<Keypair> {
+    read_keypair_file(resolve_path(file)).map_err(|err| format!("cannot read keypair {}: {}", file, err).into())
+}
+
+/// Claim flows of the program this bot does not build
+fn unsupported(distributor: &TokenDistributor) -> Option<&'static str> {
+    [
+        (distributor.is_native(), "native SOL distributors are claimed with claim_sol"),
+        (distributor.is_multi_mint(), "multi-mint distributors are claimed with claim_multi_mint"),
+        (distributor.has_recipients(), "recipient trees are claimed with claim_to_recipient"),
+        (distributor.requires_cosign(), "claims require an operator co-signature"),
+        (distributor.requires_attestation(), "claims require an attestation"),
+        (distributor.has_claim_log(), "claims are recorded in a claim log"),
+    ]
+    .into_iter()
+    .find_map(|(unsupported, reason)| unsupported.then_some(reason))
+}
+
+/// Waits until the operator has set the start time and the window has opened
+fn wait_for_window(rpc: &Rpc, address: &Pubkey, poll_interval: Duration) -> BotResult<TokenDistributor> {
+    loop {
+        let distributor = rpc.distributor(address)?;
+        let paused = distributor.is_paused()
+            || rpc.program_config()?.is_some_and(|config| config.paused);
+        // Window bounds are timestamps or slots depending on the distributor's activation type
+        let now = distributor.activation_now(&rpc.clock()?);
+        if distributor.end_time > 0 && now > distributor.end_time {
+            return Err("distribution has already ended".into());
+        }
+        if distributor.start_time > 0 && now >= distributor.start_time && !paused {
+            return Ok(distributor);
+        }
+        println!("Waiting for claim window (start_time = {}, now = {}, paused = {})", distributor.start_time, now, paused);
+        sleep(poll_interval);
+    }
+}
+
+/// Claims one wallet's allocation, retrying with exponential backoff, a fresh blockhash and the next fee payer
+fn claim_one(rpc: &Rpc, config: &BotConfig, fee_payers: &FeePayerPool, distribution: &Distribution, job: &ClaimJob) -> BotResult<()> {
+    let claimant = job.wallet.pubkey();
+    let state = &distribution.state;
+    let status = fetch_claim_status(rpc, &distribution.address, &claimant)?;
+    if status.as_ref().is_some_and(|status| status.claimed_amount >= job.entry.amount) {
+        println!("{}: already claimed", claimant);
+        return Ok(());
+    }
+    // An earlier claim under the current root cached the verified leaf, so the proof can be left out
+    let leaf = job.entry.leaf(&distribution.address);
+    let proof = match status {
+        Some(status) if status.has_verified_leaf(&leaf, state.root_update_count) => vec![],
+        _ => job.proof.clone(),
+    };
+
+    let claimant_token_account =
+        get_associated_token_address_with_program_id(&claimant, &state.token_mint, &distribution.token_program);
+    let mut accounts = instructions::claim_accounts(
+        &distribution.address,
+        &claimant,
+        &claimant,
+        &state.token_mint,
+        &claimant_token_account,
+        &distribution.token_program,
+    );
+    accounts.shared_vault = state.is_shared().then_some(state.shared_vault);
+    accounts.memo_program = Some(MEMO_PROGRAM_ID);
+    accounts.protocol_fee_account = distribution.protocol_fee_account;
+    accounts.gate_token_account = distribution
+        .gate_token_program
+        .map(|gate_token_program| get_associated_token_address_with_program_id(&claimant, &state.gate_mint, &gate_token_program));
+    // Escrowed distributors pay into the claimant's claim escrow, released later with `release`
+    if state.is_escrowed() {
+        accounts.claim_escrow = Some(pda::derive_claim_escrow(&distribution.address, &claimant).0);
+        accounts.escrow_vault = Some(pda::derive_escrow_vault(&distribution.address, &claimant).0);
+    }
+
+    let claim = instructions::claim(
+        accounts,
+        instruction::Claim {
+            max_amount: job.entry.amount,
+            proof,
+            amount: None,
+            unlock_time: job.entry.unlock_time,
+            cosign_expiry: None,
+        },
+    );
+
+    let mut attempt = 1;
+    loop {
+        // Each attempt is a new transaction, paid by the next fee payer of the pool
+        let fee_payer = fee_payers.next(rpc)?;
+        let instructions = [
+            ComputeBudgetInstruction::set_compute_unit_limit(config.compute_unit_limit),
+            ComputeBudgetInstruction::set_compute_unit_price(config.priority_fee_micro_lamports),
+            create_associated_token_account_idempotent(&fee_payer.pubkey(), &claimant, &state.token_mint, &distribution.token_program),
+            claim.clone(),
+        ];
+        let signers: &[&Keypair] = if claimant == fee_payer.pubkey() { &[fee_payer] } else { &[fee_payer, job.wallet] };
+        match rpc.send(&instructions, fee_payer, signers) {
+            Ok(signature) => {
+                println!("{}: claimed {} ({})", claimant, job.entry.amount, signature);
+                return Ok(());
+            }
+            Err(err) if attempt < config.max_retries => {
+                println!("{}: attempt {}/{} failed: {}", claimant, attempt, config.max_retries, err);
+                sleep(Duration::from_millis(500 << (attempt - 1)));
+                attempt += 1;
+            }
+            Err(err) => {
+                println!("{}: attempt {}/{} failed: {}", claimant, attempt, config.max_retries, err);
+                return Err(err);
+            }
+        }
+    }
+}
+
+fn run(config_path: &str) -> BotResult<bool> {
+    let config: BotConfig = serde_json::from_str(&std::fs::read_to_string(config_path)?)?;
+    let rpc = Rpc(RpcClient::new_with_commitment(config.rpc_url.clone(), CommitmentConfig::confirmed()));
+    let wallets = config.wallets.iter().map(|file| load_keypair(file)).collect::<BotResult<Vec<_>>>()?;
+    let pool = config.fee_payers.iter().map(|file| load_keypair(file)).collect::<BotResult<Vec<_>>>()?;
+    let treasury = config.treasury.as_deref().map(load_keypair).transpose()?;
+    // Without a fee payer pool the first wallet pays every transaction
+    let payers: Vec<&Keypair> = if pool.is_empty() {
+        vec![wallets.first().ok_or("no wallets configured")?]
+    } else {
+        pool.iter().collect()
+    };
+    let fee_payers = FeePayerPool {
+        payers,
+        paid: AtomicUsize::new(0),
+        treasury: treasury.as_ref(),
+        min_balance: config.min_fee_payer_balance,
+        top_up: config.fee_payer_top_up,
+        top_up_lock: Mutex::new(()),
+    };
+
+    let address: Pubkey = config.distributor.parse()?;
+    let tree = MerkleTree::new(&address, entries_from_json(&std::fs::read_to_string(resolve_path(&config.tree))?)?)?;
+    let root = tree.get_root();
+
+    let state = wait_for_window(&rpc, &address, Duration::from_millis(config.poll_interval_ms))?;
+    if let Some(reason) = unsupported(&state) {
+        return Err(format!("unsupported distributor: {}", reason).into());
+    }
+    // Refuse to claim against a tree the distributor does not know about, or whose grace period is over
+    if !state.known_roots(rpc.clock()?.unix_timestamp).any(|known| *known == root) {
+        let root: String = root.iter().map(|byte| format!("{:02x}", byte)).collect();
+        return Err(format!("tree root {} is not among the distributor's merkle roots", root).into());
+    }
+
+    let token_program = rpc.owner_of(&state.token_mint)?;
+    let protocol_fee_account = rpc.program_config()?
+        .filter(|program_config| program_config.protocol_fee_bps > 0)
+        .map(|program_config| {
+            get_associated_token_address_with_program_id(&program_config.protocol_fee_receiver, &state.token_mint, &token_program)
+        });
+    let gate_token_program = if state.is_gated() { Some(rpc.owner_of(&state.gate_mint)?) } else { None };
+    let distribution = Distribution { address, state, token_program, protocol_fee_account, gate_token_program };
+
+    // Match configured wallets with their tree entries
+    let mut jobs = Vec::new();
+    for wallet in &wallets {
+        match tree.proof_for(&wallet.pubkey()) {
+            Some((entry, proof)) => jobs.push(ClaimJob { wallet, entry: *entry, proof }),
+            None => println!("{}: not in the tree, skipping", wallet.pubkey()),
+        }
+    }
+
+    // Claim in waves of `concurrency` wallets
+    let mut failed = 0;
+    for wave in jobs.chunks(config.concurrency.max(1)) {
+        failed += std::thread::scope(|scope| {
+            let handles: Vec<_> = wave
+                .iter()
+                .map(|job| scope.spawn(|| claim_one(&rpc, &config, &fee_payers, &distribution, job)))
+                .collect();
+            handles.into_iter().map(|handle| handle.join()).filter(|result| !matches!(result, Ok(Ok(())))).count()
+        });
+    }
+    println!("Done: {} claimed or already claimed, {} failed", jobs.len() - failed, failed);
+    Ok(failed == 0)
+}
+
+fn main() -> ExitCode {
+    let config_path = std::env::args().nth(1).unwrap_or_else(|| "examples/claim_bot/config.json".to_string());
+    match run(&config_path) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -8,21 +8,226 @@ use anchor_lang::prelude::*;
  */
 
 #[constant]
-/// ===== TIMING CONSTANTS =====
+// ===== TIMING CONSTANTS =====
 
-/// Duration of each distribution period (14 days)
-/// - Applied when setting start_time to calculate end_time
+/// Default duration of each distribution period (14 days)
+/// - Default for ProgramConfig::default_duration, used when no explicit duration is provided at creation
 /// - Provides a reasonable window for users to claim their tokens
 /// - Value: 14 days * 24 hours * 60 minutes * 60 seconds = 1,209,600 seconds
 pub const DURATION: i64 = 14 * 24 * 60 * 60; // 14 days in seconds
 
+/// Minimum allowed distribution duration (48 hours)
+/// - Default lower bound for the duration provided at distributor creation
+/// - Overridable by the global admin via update_config
+/// - Value: 48 hours * 60 minutes * 60 seconds = 172,800 seconds
+pub const MIN_DURATION: i64 = 48 * 60 * 60; // 48 hours in seconds
+
+/// Maximum allowed distribution duration (180 days)
+/// - Default upper bound for the duration provided at distributor creation
+/// - Overridable by the global admin via update_config
+/// - Value: 180 days * 24 hours * 60 minutes * 60 seconds = 15,552,000 seconds
+pub const MAX_DURATION: i64 = 180 * 24 * 60 * 60; // 180 days in seconds
+
 /// Maximum allowed start time in the future (90 days)
 /// - Each modification limited to max 90 days from current time
 /// - Prevents setting times too far ahead in single operation
 /// - Value: 90 days * 24 hours * 60 minutes * 60 seconds = 7,776,000 seconds
 pub const MAX_START_TIME: i64 = 90 * 24 * 60 * 60; // 90 days in seconds
 
-/// ===== PDA SEED CONSTANTS =====
+/// Maximum delay between end_time and owner withdrawal (90 days)
+/// - Bounds the optional withdraw_delay configured at distributor creation
+/// - Value: 90 days * 24 hours * 60 minutes * 60 seconds = 7,776,000 seconds
+pub const MAX_WITHDRAW_DELAY: i64 = 90 * 24 * 60 * 60; // 90 days in seconds
+
+/// Timelock before a frozen claim can be unfrozen (7 days)
+/// - Measured from the time the claimant froze their claim
+/// - Gives users who leaked a key time to coordinate a remapping
+/// - Value: 7 days * 24 hours * 60 minutes * 60 seconds = 604,800 seconds
+pub const UNFREEZE_TIMELOCK: i64 = 7 * 24 * 60 * 60; // 7 days in seconds
+
+/// Grace period before the claim of a frozen token account can be redirected (7 days)
+/// - Measured from the time an operator reported the claimant's token account frozen
+/// - Gives claimants time to get their account thawed and claim themselves
+/// - Value: 7 days * 24 hours * 60 minutes * 60 seconds = 604,800 seconds
+pub const FROZEN_CLAIM_GRACE_PERIOD: i64 = 7 * 24 * 60 * 60; // 7 days in seconds
+
+/// Grace period after end_time before clawback is allowed (7 days)
+/// - Gives late claimants and operators time to react before funds move
+/// - Value: 7 days * 24 hours * 60 minutes * 60 seconds = 604,800 seconds
+pub const CLAWBACK_GRACE_PERIOD: i64 = 7 * 24 * 60 * 60; // 7 days in seconds
+
+/// Grace period after end_time before anyone may close stale ClaimStatus accounts (180 days)
+/// - Claimants keep sole control over their ClaimStatus until then
+/// - Value: 180 days * 24 hours * 60 minutes * 60 seconds = 15,552,000 seconds
+pub const CLAIM_STATUS_GC_GRACE_PERIOD: i64 = 180 * 24 * 60 * 60; // 180 days in seconds
+
+/// Nominal slot duration used to express second-based limits in slots (400ms)
+/// - Applies to distributors using slot-based activation
+/// - Actual slot times vary, so converted limits are approximate
+pub const MS_PER_SLOT: i64 = 400;
+
+/// Maximum configurable interval between merkle root updates (7 days)
+/// - Upper bound for the owner-configured root update interval
+/// - Prevents the owner from locking the operator out of root updates for too long
+/// - Value: 7 days * 24 hours * 60 minutes * 60 seconds = 604,800 seconds
+pub const MAX_ROOT_UPDATE_INTERVAL: i64 = 7 * 24 * 60 * 60; // 7 days in seconds
+
+/// Maximum period over which escrowed claims are released
+/// - Bounds how long claimants can be kept from their tokens by enable_claim_escrow
+pub const MAX_ESCROW_RELEASE_DURATION: i64 = 4 * 365 * 24 * 60 * 60; // 4 years in seconds
+
+// ===== OPERATOR CONSTANTS =====
+
+/// Maximum number of operators per distributor
+/// - Bounds the fixed-size operator list stored in TokenDistributor
+/// - Allows teams to share operations and rotate compromised keys
+pub const MAX_OPERATORS: usize = 5;
+
+/// Maximum number of relayers on a distributor's relayer allowlist
+/// - Bounds the fixed-size relayer list stored in TokenDistributor
+/// - An empty list lets any relayer submit claim_with_signature
+pub const MAX_RELAYERS: usize = 4;
+
+// ===== CLAIM CONSTANTS =====
+
+/// Maximum number of claims in a single claim_batch instruction
+/// - Each claim adds three accounts and a merkle proof to the transaction
+/// - Keeps batches within transaction size and compute limits
+pub const MAX_BATCH_CLAIMS: usize = 5;
+
+/// Domain separator for messages signed to authorize claim_with_signature
+/// - Prevents a claim authorization from being valid as any other signed message
+pub const CLAIM_MESSAGE_DOMAIN: &[u8] = b"token_distributor:claim";
+
+/// Domain separator for messages an operator signs to co-sign claims
+/// - Keeps a co-signature from being valid as a claimant voucher, or any other signed message
+pub const COSIGN_MESSAGE_DOMAIN: &[u8] = b"token_distributor:cosign";
+
+/// Maximum number of claim windows per distributor
+/// - Bounds the fixed-size claim window list stored in TokenDistributor
+/// - Phased distributions accept claims only inside one of their windows
+pub const MAX_CLAIM_WINDOWS: usize = 4;
+
+/// Memo attached to claim transfers into accounts that require incoming transfer memos
+/// - Token 2022 MemoTransfer extension rejects transfers without a preceding memo
+pub const CLAIM_MEMO: &[u8] = b"token_distributor claim";
+
+/// Maximum number of extra mints per multi-mint distributor
+/// - Bounds the per-mint claimed amounts stored in ClaimStatus
+/// - Each extra mint adds five accounts to a claim_multi_mint transaction
+pub const MAX_EXTRA_MINTS: usize = 3;
+
+/// Maximum lamport fee per claim (0.01 SOL)
+/// - Default upper bound for the owner-configured claim fee
+/// - Overridable by the global admin via update_config
+/// - Value: 10,000,000 lamports
+pub const MAX_CLAIM_FEE: u64 = 10_000_000;
+
+/// Maximum protocol fee on claims (10%)
+/// - Upper bound for the admin-configured protocol fee, in basis points
+pub const MAX_PROTOCOL_FEE_BPS: u16 = 1_000;
+
+/// Maximum early claim bonus (100%)
+/// - Upper bound for the owner-configured early_bonus_bps, doubling a claim at most
+pub const MAX_EARLY_BONUS_BPS: u16 = 10_000;
+
+/// Basis points in 100%
+pub const BASIS_POINTS_DENOMINATOR: u64 = 10_000;
+
+/// Maximum number of ClaimStatus accounts closed by one close_claim_status_many
+/// - Each close adds three accounts to the transaction
+pub const MAX_BATCH_CLOSES: usize = 10;
+
+// ===== METADATA CONSTANTS =====
+
+/// Maximum length of a distributor's display name in bytes
+/// - E.g. "OKX Boost Airdrop #3", shown by wallets and explorers instead of the PDA
+pub const MAX_METADATA_NAME_LEN: usize = 64;
+
+/// Maximum length of a distributor's metadata URI in bytes
+/// - E.g. an IPFS or Arweave link to the full recipient list and campaign terms
+pub const MAX_METADATA_URI_LEN: usize = 200;
+
+// ===== REGISTRY CONSTANTS =====
+
+/// Number of claim records per ClaimLog page
+/// - A page is 6,200 bytes, below the 10 KiB limit for accounts created by CPI
+/// - The claim that fills a page leaves the next claim to create the next one
+pub const CLAIM_LOG_PAGE_LEN: usize = 128;
+
+/// Maximum number of distributors listed in a per-mint registry
+/// - Bounds the fixed-size entry list stored in MintRegistry
+/// - Ended entries are pruned on every sync to free slots
+pub const MAX_REGISTRY_ENTRIES: usize = 16;
+
+// ===== MERKLE TREE CONSTANTS =====
+
+/// Default merkle tree hashing scheme version
+/// - Stored on each distributor so indexers can tell which scheme a root uses
+/// - Version 1: leaf = hash(LEAF_PREFIX || distributor || claimant || amount),
+///   node = hash(NODE_PREFIX || sorted(left, right))
+pub const TREE_VERSION: u8 = 1;
+
+/// Merkle tree hashing scheme version with per-user unlock times
+/// - Version 2: leaf = hash(LEAF_PREFIX || distributor || claimant || amount || unlock_time),
+///   nodes as in version 1
+/// - Claims are rejected before the leaf's unlock_time, enabling tiered unlocks
+pub const UNLOCK_TIME_TREE_VERSION: u8 = 2;
+
+/// Merkle tree hashing scheme version for multi-mint distributors
+/// - Version 3: leaf = hash(LEAF_PREFIX || distributor || claimant || amount || extra_amount_1 || ...),
+///   one little-endian u64 per extra mint in the order the mints were added, nodes as in version 1
+/// - Claimed with claim_multi_mint, which pays every mint in one transaction
+pub const MULTI_MINT_TREE_VERSION: u8 = 3;
+
+/// Merkle tree hashing scheme version with leaf-specified recipients
+/// - Version 4: leaf = hash(LEAF_PREFIX || distributor || claimant || recipient || amount),
+///   nodes as in version 1
+/// - The claimant signs the claim, tokens go to the recipient (e.g. a vesting contract or DAO sub-account)
+/// - Claimed with claim_to_recipient
+pub const RECIPIENT_TREE_VERSION: u8 = 4;
+
+/// Merkle tree hashing scheme version hashing with keccak256
+/// - Version 5: leaf and nodes as in version 1, hashed with keccak256 instead of sha256
+/// - Matches keccak-based tooling, one syscall per leaf and per proof level as for sha256
+pub const KECCAK_TREE_VERSION: u8 = 5;
+
+/// Maximum number of hashes in a merkle proof
+/// - Trees of up to 2^24 (~16.7M) leaves, a 1M leaf tree needs 20
+/// - Bounds the hashing cost of proof verification so integrators composing
+///   claims via CPI can budget compute units
+pub const MAX_PROOF_DEPTH: usize = 24;
+
+/// Domain separator prepended to leaf hashes
+pub const LEAF_PREFIX: u8 = 0;
+
+/// Domain separator prepended to intermediate node hashes
+pub const NODE_PREFIX: u8 = 1;
+
+/// Number of previous merkle roots claims may be proven against
+/// - Claims are accepted against the current root or any of the roots preceding it
+/// - Keeps proofs generated just before a root rotation valid
+pub const MAX_ROOT_HISTORY: usize = 3;
+
+/// Time a replaced merkle root stays valid for claims (24 hours)
+/// - Measured from the root update that replaced it
+/// - Proofs in flight during a rotation still land, while a root revoked to
+///   correct allocations stops being claimable soon after
+/// - Value: 24 hours * 60 minutes * 60 seconds = 86,400 seconds
+pub const ROOT_GRACE_PERIOD: i64 = 24 * 60 * 60; // 24 hours in seconds
+
+/// Number of merkle root updates recorded on each distributor
+/// - Ring buffer of (root, operator, timestamp) entries, newest is the current root
+/// - Lets auditors find the root that was live at claim time directly from state
+/// - Must be greater than MAX_ROOT_HISTORY
+pub const ROOT_HISTORY_LEN: usize = 16;
+
+// ===== PDA SEED CONSTANTS =====
+
+/// Seed for program config PDA derivation
+/// - Used in: ["config"]
+/// - Singleton holding the global admin, pause switch and default parameters
+pub const CONFIG_SEED: &str = "config";
 
 /// Seed for owner nonce PDA derivation
 /// - Used in: ["owner_nonce", owner]
@@ -42,6 +247,58 @@ pub const DISTRIBUTOR_SEED: &str = "distributor";
 /// - Ensures the vault is controlled by the distributor PDA
 pub const VAULT_SEED: &str = "vault";
 
+/// Seed for distributor mint PDA derivation
+/// - Used in: ["distributor_mint", distributor_key, token_mint]
+/// - Tracks an extra mint of a multi-mint distributor
+/// - The extra mint's vault is derived from: ["vault", distributor_mint_key]
+pub const DISTRIBUTOR_MINT_SEED: &str = "distributor_mint";
+
+/// Seed for native SOL vault PDA derivation
+/// - Used in: ["sol_vault", distributor_key]
+/// - Lamport-only account holding the SOL of a native SOL distributor
+pub const SOL_VAULT_SEED: &str = "sol_vault";
+
+/// Seed for shared vault PDA derivation
+/// - Used in: ["shared_vault", token_mint, owner]
+/// - Creates one shared vault per (token, owner) pair
+/// - The shared vault's token account is derived from: ["vault", shared_vault_key]
+pub const SHARED_VAULT_SEED: &str = "shared_vault";
+
+/// Seed for rent escrow PDA derivation
+/// - Used in: ["rent_escrow", distributor_key, owner]
+/// - Lamport-only account funded by the owner to pay ClaimStatus rent for claimants
+pub const RENT_ESCROW_SEED: &str = "rent_escrow";
+
+/// Seed for per-mint registry PDA derivation
+/// - Used in: ["registry", token_mint]
+/// - Lists the scheduled distributors of a token and their claim windows
+pub const REGISTRY_SEED: &str = "registry";
+
+/// Seed for distributor metadata PDA derivation
+/// - Used in: ["metadata", distributor_key]
+/// - Holds the campaign's display name and metadata URI, set by the owner before start
+pub const METADATA_SEED: &str = "metadata";
+
+/// Seed for claim log page PDA derivation
+/// - Used in: ["claim_log", distributor_key, page_index (u64 little-endian)]
+/// - Pages are created lazily by claims once the distributor keeps a claim log
+pub const CLAIM_LOG_SEED: &str = "claim_log";
+
+/// Seed for claim escrow PDA derivation
+/// - Used in: ["claim_escrow", distributor_key, claimant_key]
+/// - Tracks the tokens escrowed for a claimant of an escrowed distributor and how much was released
+pub const CLAIM_ESCROW_SEED: &str = "claim_escrow";
+
+/// Seed for claim escrow token account PDA derivation
+/// - Used in: ["escrow_vault", distributor_key, claimant_key]
+/// - Holds a claimant's escrowed tokens, with the claim escrow PDA as token authority
+pub const ESCROW_VAULT_SEED: &str = "escrow_vault";
+
+/// Seed for attestation PDA derivation
+/// - Used in: ["attestation", attestor, subject]
+/// - Creates one attestation per (attestor, wallet) pair, shared by all distributors
+pub const ATTESTATION_SEED: &str = "attestation";
+
 /// Seed for claim status PDA derivation
 /// - Used in: ["claim", distributor_key, claimant_key]
 /// - Creates unique claim tracking for each (distributor, claimant) pair
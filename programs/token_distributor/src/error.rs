@@ -7,8 +7,26 @@ pub enum TokenDistributorError {
     OnlyOperator,
     #[msg("Only owner can perform this action")]
     OnlyOwner,
+    #[msg("Only owner or operator can perform this action")]
+    OnlyOwnerOrOperator,
     #[msg("Invalid operator account")]
     InvalidOperator,
+    #[msg("Operator is already listed")]
+    OperatorAlreadyExists,
+    #[msg("Operator is not listed")]
+    OperatorNotFound,
+    #[msg("Operator list is full")]
+    TooManyOperators,
+    #[msg("Invalid relayer account")]
+    InvalidRelayer,
+    #[msg("Relayer is already listed")]
+    RelayerAlreadyExists,
+    #[msg("Relayer is not listed")]
+    RelayerNotFound,
+    #[msg("Relayer list is full")]
+    TooManyRelayers,
+    #[msg("Relayer is not on the distributor's relayer allowlist")]
+    RelayerNotAllowed,
     
     // Time validation errors
     #[msg("Start time not set")]
@@ -19,6 +37,20 @@ pub enum TokenDistributorError {
     InvalidStartTime,
     #[msg("Start time cannot be more than 90 days in the future")]
     StartTimeTooFar,
+    #[msg("Duration is outside the configured bounds (48 hours to 180 days by default)")]
+    InvalidDuration,
+    #[msg("New end time must be later than the current end time")]
+    InvalidEndTime,
+    #[msg("Withdraw delay cannot be negative or more than 90 days")]
+    InvalidWithdrawDelay,
+    #[msg("Withdraw delay after the distribution end has not elapsed yet")]
+    WithdrawDelayActive,
+    #[msg("Claim windows must be non-empty, sorted and non-overlapping")]
+    InvalidClaimWindows,
+    #[msg("Too many claim windows")]
+    TooManyClaimWindows,
+    #[msg("Claims are only accepted inside one of the distribution's claim windows")]
+    OutsideClaimWindow,
     
     // Distribution state errors
     #[msg("Distribution not started")]
@@ -27,6 +59,46 @@ pub enum TokenDistributorError {
     DistributionEnded,
     #[msg("Distribution has not ended yet")]
     DistributionNotEnded,
+    #[msg("Distribution is live, partial withdrawals are only allowed before start or after end")]
+    DistributionActive,
+    #[msg("Clawback grace period has not elapsed yet")]
+    ClawbackGracePeriodActive,
+    #[msg("Claim status garbage collection grace period has not elapsed yet")]
+    GarbageCollectionGracePeriodActive,
+    #[msg("No clawback receiver configured")]
+    NoClawbackReceiver,
+    #[msg("Clawback receiver configured, use clawback instead of withdraw")]
+    ClawbackReceiverConfigured,
+    #[msg("Receiver token account is not owned by the clawback receiver or owner")]
+    ReceiverMismatch,
+    #[msg("Distribution is paused")]
+    DistributionPaused,
+    #[msg("Distribution is not paused")]
+    DistributionNotPaused,
+    #[msg("Distributor is finalized, merkle root and timing can no longer change")]
+    DistributorFinalized,
+    #[msg("Extra mint vaults must be withdrawn before the distributor")]
+    ExtraMintsOpen,
+    
+    // Claim freeze errors
+    #[msg("Claim is frozen by the claimant")]
+    ClaimFrozen,
+    #[msg("Claim is not frozen")]
+    ClaimNotFrozen,
+    #[msg("Unfreeze timelock has not elapsed yet")]
+    UnfreezeTimelockActive,
+    
+    // Frozen token account errors
+    #[msg("Claimant's token account is frozen, an operator can redirect the claim with redirect_frozen_claim")]
+    ClaimantAccountFrozen,
+    #[msg("Claimant's token account is not frozen")]
+    ClaimantAccountNotFrozen,
+    #[msg("Frozen claim grace period has not elapsed since the frozen account was reported")]
+    FrozenClaimGracePeriod,
+    
+    // Claim delegation errors
+    #[msg("Claim must be signed by the claimant or its delegate")]
+    NotClaimantOrDelegate,
     
     // Merkle proof errors
     #[msg("No merkle root set")]
@@ -35,12 +107,162 @@ pub enum TokenDistributorError {
     InvalidMerkleRoot,
     #[msg("Invalid proof")]
     InvalidProof,
+    #[msg("Merkle proof exceeds the maximum proof depth")]
+    ProofTooLong,
+    #[msg("Unsupported merkle tree version")]
+    InvalidTreeVersion,
+    #[msg("Unlock time is required for this merkle tree version")]
+    MissingUnlockTime,
+    #[msg("Number of extra amounts does not match the distributor's extra mints")]
+    ExtraAmountsMismatch,
+    #[msg("Recipient is required for this merkle tree version, use claim_to_recipient")]
+    MissingRecipient,
+    #[msg("Instruction requires a distributor with leaf-specified recipients")]
+    NotRecipientDistributor,
+    #[msg("Claim is not unlocked yet")]
+    ClaimLocked,
+    #[msg("Claimant does not hold enough of the gating token")]
+    ClaimGateNotMet,
+    #[msg("Claimant has no valid attestation with the required score from the distributor's attestor")]
+    AttestationRequired,
+    #[msg("Attestation expiry must be in the future")]
+    InvalidAttestationExpiry,
+    #[msg("Merkle root updated too recently")]
+    RootUpdateTooFrequent,
+    #[msg("Root update interval cannot exceed 7 days")]
+    InvalidRootUpdateInterval,
     
     // Amount validation errors
     #[msg("Invalid amount")]
     InvalidAmount,
+    #[msg("Allocation is below the amount already claimed, an operator can reconcile it with adjust_claim_status")]
+    AllocationReduced,
+    #[msg("Allocation is not below the amount already claimed and no excess is recorded")]
+    AllocationNotReduced,
     #[msg("Insufficient vault balance for this claim")]
     InsufficientVaultBalance,
+    #[msg("Withdrawal amount exceeds the vault balance")]
+    WithdrawAmountExceedsBalance,
+    #[msg("Vault must be empty to be closed")]
+    VaultNotEmpty,
+    #[msg("Claim exceeds the distributor's shared vault budget")]
+    BudgetExceeded,
+    #[msg("Claim exceeds the maximum amount per claimant")]
+    ClaimCapExceeded,
+    #[msg("Claims in this slot exceed the distributor's rate limit")]
+    SlotClaimLimitExceeded,
+    #[msg("Insufficient unallocated balance in the shared vault")]
+    InsufficientUnallocatedBalance,
+    #[msg("Shared vault does not match the distributor")]
+    SharedVaultMismatch,
+    #[msg("Vault does not match the distributor")]
+    VaultMismatch,
+    #[msg("Instruction requires a native SOL distributor")]
+    NotNativeDistributor,
+    #[msg("Instruction requires a multi-mint distributor")]
+    NotMultiMintDistributor,
+    #[msg("Distributor already has the maximum number of extra mints")]
+    TooManyExtraMints,
+    #[msg("Mints can only be added before the merkle root is set")]
+    MerkleRootAlreadySet,
+    #[msg("Extra mint accounts do not match the distributor's extra mints")]
+    InvalidExtraMintAccounts,
+    #[msg("Rent payer does not match the claim status")]
+    RentPayerMismatch,
+    #[msg("Claim fee exceeds the maximum")]
+    InvalidClaimFee,
+    #[msg("No claim fees to withdraw")]
+    NoClaimFees,
+    #[msg("Invalid number of claims in batch")]
+    InvalidBatchSize,
+    #[msg("Batch accounts do not match the claim entries")]
+    InvalidBatchAccounts,
+    
+    // Signature authorization errors
+    #[msg("Claim must be preceded by an ed25519 signature instruction")]
+    MissingSignatureInstruction,
+    #[msg("Invalid claim signature")]
+    InvalidClaimSignature,
+    #[msg("Claim voucher has expired")]
+    VoucherExpired,
+    #[msg("Distributor requires an operator co-signature, claim with claim and the co-signature")]
+    CosignRequired,
+    #[msg("Co-signature is not an operator's signature over this claim")]
+    InvalidCosign,
+    #[msg("Co-signature has expired")]
+    CosignExpired,
+    
+    // Rent escrow errors
+    #[msg("Insufficient rent escrow balance")]
+    InsufficientRentEscrow,
+    #[msg("Claim status account already exists")]
+    ClaimStatusAlreadyExists,
+    
+    // Claim log errors
+    #[msg("Distributor keeps a claim log, claim with claim and the current claim log page")]
+    ClaimLogRequired,
+    #[msg("Claim log is already enabled")]
+    ClaimLogAlreadyEnabled,
+    #[msg("Claim log and claim escrow can only be enabled before the first claim")]
+    ClaimsAlreadyMade,
+    #[msg("Claim log page is full")]
+    ClaimLogPageFull,
+    
+    // Claim escrow errors
+    #[msg("Distributor escrows claims, claim with claim and the claimant's claim escrow")]
+    ClaimEscrowRequired,
+    #[msg("Claim escrow is already enabled")]
+    ClaimEscrowAlreadyEnabled,
+    #[msg("Claims of this distributor cannot be escrowed")]
+    ClaimEscrowNotSupported,
+    #[msg("Invalid escrow release duration")]
+    InvalidReleaseDuration,
+    #[msg("No escrowed tokens are releasable yet")]
+    NothingToRelease,
+    
+    // Early bonus errors
+    #[msg("Invalid early bonus, the bonus needs a window within the distribution")]
+    InvalidEarlyBonus,
+    #[msg("Claims of this distributor cannot earn an early bonus")]
+    EarlyBonusNotSupported,
+    #[msg("Bonus pool cannot be reduced")]
+    BonusPoolReduced,
+    #[msg("Early bonus window is open, claim with claim to receive the bonus")]
+    EarlyBonusClaimRequired,
+    
+    // Metadata errors
+    #[msg("Metadata name or URI exceeds its maximum length")]
+    MetadataTooLong,
+    
+    // Registry errors
+    #[msg("Registry is full")]
+    RegistryFull,
+    #[msg("Distributors using slot-based activation cannot be listed in the registry")]
+    SlotActivationNotSupported,
+    #[msg("Only the mint authority or the config admin can list or evict live distributors")]
+    RegistryUnauthorized,
+    #[msg("Distributor is not listed in the registry")]
+    RegistryEntryNotFound,
+    
+    // Program config errors
+    #[msg("Only the program upgrade authority can perform this action")]
+    OnlyUpgradeAuthority,
+    #[msg("Only the program admin can perform this action")]
+    OnlyAdmin,
+    #[msg("Program is paused")]
+    ProgramPaused,
+    #[msg("Invalid program config")]
+    InvalidConfig,
+    #[msg("Protocol fee account is missing or not owned by the protocol fee receiver")]
+    ProtocolFeeAccountMismatch,
+    #[msg("Owner has reached the maximum number of active distributors")]
+    TooManyActiveDistributors,
+    #[msg("Owner nonce state already has the current layout")]
+    NonceStateUpToDate,
+    #[msg("Claim status already has the current layout")]
+    ClaimStatusUpToDate,
+    #[msg("Nonce must be greater than every nonce the owner has used")]
+    NonceAlreadyUsed,
     
     // System level errors
     #[msg("Arithmetic overflow")]
@@ -49,4 +271,8 @@ pub enum TokenDistributorError {
     DistributorNotOwnedByProgram,
     #[msg("Token mint does not match distributor's token mint")]
     TokenMintMismatch,
+    #[msg("Token mint decimals do not match the decimals cached at creation")]
+    MintDecimalsMismatch,
+    #[msg("Memo program is required for accounts that require incoming transfer memos")]
+    MemoProgramRequired,
 } 
\ No newline at end of file
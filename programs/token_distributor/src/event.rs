@@ -1,4 +1,41 @@
 use anchor_lang::prelude::*;
+use crate::state::{ActivationType, ClaimWindow};
+
+/// UI amount scaling of the distributed mint, reported alongside raw amounts
+/// - All amounts in events are raw base units; UI amounts are derived off-chain from these parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct AmountScaling {
+    /// Decimals of the mint
+    pub decimals: u8,
+    /// Token 2022 InterestBearingConfig parameters, if the mint accrues interest
+    pub interest_bearing: Option<InterestBearingScaling>,
+    /// Token 2022 ScaledUiAmount parameters, if the mint has a UI multiplier
+    pub scaled_ui_amount: Option<ScaledUiAmountScaling>,
+}
+
+/// Parameters of a Token 2022 interest-bearing mint
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct InterestBearingScaling {
+    /// Timestamp from which interest accrues
+    pub initialization_timestamp: i64,
+    /// Average rate in basis points from initialization until the last rate update
+    pub pre_update_average_rate: i16,
+    /// Timestamp of the last rate update
+    pub last_update_timestamp: i64,
+    /// Current rate in basis points
+    pub current_rate: i16,
+}
+
+/// Parameters of a Token 2022 scaled UI amount mint
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct ScaledUiAmountScaling {
+    /// Multiplier in effect before new_multiplier_effective_timestamp
+    pub multiplier: f64,
+    /// Timestamp from which new_multiplier applies
+    pub new_multiplier_effective_timestamp: i64,
+    /// Multiplier in effect from new_multiplier_effective_timestamp
+    pub new_multiplier: f64,
+}
 
 /// Event emitted when a new distributor is created
 #[event]
@@ -17,6 +54,250 @@ pub struct DistributorCreated {
     pub token_vault: Pubkey,
     /// Initial total amount of tokens deposited
     pub initial_total_amount: u64,
+    /// Length of the distribution window in seconds (slots in slot mode)
+    pub duration: i64,
+    /// Receiver of unclaimed tokens on clawback (Pubkey::default() if none)
+    pub clawback_receiver: Pubkey,
+    /// Delay after the end time before unclaimed tokens can be withdrawn, in seconds (slots in slot mode)
+    pub withdraw_delay: i64,
+    /// Clock the distribution window is measured on
+    pub activation_type: ActivationType,
+    /// Merkle tree hashing scheme version
+    pub tree_version: u8,
+    /// UI amount scaling of the token mint (amounts above are raw)
+    pub scaling: AmountScaling,
+}
+
+/// Event emitted when the owner sets a distributor's metadata
+#[event]
+pub struct DistributorMetadataSet {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// The metadata account public key
+    pub metadata: Pubkey,
+    /// Owner who set the metadata
+    pub owner: Pubkey,
+    /// Display name of the campaign
+    pub name: String,
+    /// URI of the campaign's off-chain metadata (recipient list, terms)
+    pub uri: String,
+}
+
+/// Event emitted when the owner closes a distributor's metadata
+#[event]
+pub struct DistributorMetadataClosed {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// The metadata account public key
+    pub metadata: Pubkey,
+    /// Owner who received the reclaimed rent
+    pub owner: Pubkey,
+}
+
+/// Event emitted when an extra mint is added to a multi-mint distributor
+#[event]
+pub struct DistributorMintAdded {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// The distributor mint account public key
+    pub distributor_mint: Pubkey,
+    /// Token mint address
+    pub token_mint: Pubkey,
+    /// Token vault address
+    pub token_vault: Pubkey,
+    /// Position of this mint's amount among the extra amounts of a merkle leaf
+    pub index: u8,
+    /// Initial amount of tokens deposited
+    pub initial_total_amount: u64,
+    /// UI amount scaling of the token mint (amounts above are raw)
+    pub scaling: AmountScaling,
+}
+
+/// Event emitted when a shared vault is created
+#[event]
+pub struct SharedVaultCreated {
+    /// The shared vault account public key
+    pub shared_vault: Pubkey,
+    /// Owner of the shared vault
+    pub owner: Pubkey,
+    /// Token mint address
+    pub token_mint: Pubkey,
+    /// Token vault address
+    pub token_vault: Pubkey,
+    /// Amount of tokens deposited
+    pub amount: u64,
+}
+
+/// Event emitted when a distributor drawing from a shared vault is created
+#[event]
+pub struct SharedDistributorCreated {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Nonce of the distributor
+    pub nonce: u32,
+    /// The shared vault account public key
+    pub shared_vault: Pubkey,
+    /// Owner of the distributor
+    pub owner: Pubkey,
+    /// Operator of the distributor
+    pub operator: Pubkey,
+    /// Token mint address
+    pub token_mint: Pubkey,
+    /// Maximum amount the distributor may pay out
+    pub budget: u64,
+    /// Length of the distribution window in seconds
+    pub duration: i64,
+    /// Delay after the end time before the distributor can be closed, in seconds
+    pub withdraw_delay: i64,
+    /// Merkle tree hashing scheme version
+    pub tree_version: u8,
+    /// UI amount scaling of the token mint (amounts above are raw)
+    pub scaling: AmountScaling,
+}
+
+/// Event emitted when a shared distributor is closed
+#[event]
+pub struct SharedDistributorClosed {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// The shared vault account public key
+    pub shared_vault: Pubkey,
+    /// Unclaimed budget released back to the shared vault
+    pub released_amount: u64,
+}
+
+/// Event emitted when unallocated tokens are withdrawn from a shared vault
+#[event]
+pub struct SharedVaultWithdrawn {
+    /// The shared vault account public key
+    pub shared_vault: Pubkey,
+    /// Owner who withdrew the tokens
+    pub owner: Pubkey,
+    /// Amount of tokens withdrawn
+    pub amount: u64,
+}
+
+/// Event emitted when the program config is initialized
+#[event]
+pub struct ProgramConfigInitialized {
+    /// The config account public key
+    pub config: Pubkey,
+    /// Global admin
+    pub admin: Pubkey,
+}
+
+/// Event emitted when the program config parameters are updated
+#[event]
+pub struct ProgramConfigUpdated {
+    /// The config account public key
+    pub config: Pubkey,
+    /// Global admin after the update
+    pub admin: Pubkey,
+    /// Minimum distribution duration in seconds
+    pub min_duration: i64,
+    /// Maximum distribution duration in seconds
+    pub max_duration: i64,
+    /// Default distribution duration in seconds
+    pub default_duration: i64,
+    /// Maximum lamport fee per claim
+    pub max_claim_fee: u64,
+    /// Protocol fee deducted from each claim, in basis points
+    pub protocol_fee_bps: u16,
+    /// Owner of the accounts receiving the protocol fee
+    pub protocol_fee_receiver: Pubkey,
+    /// Maximum number of distributors an owner may have open at once (0 = unlimited)
+    pub max_active_distributors: u32,
+}
+
+/// Event emitted when the admin sets an owner's active distributor limit
+#[event]
+pub struct OwnerDistributorLimitSet {
+    /// The owner the limit applies to
+    pub owner: Pubkey,
+    /// Global admin who set the limit
+    pub admin: Pubkey,
+    /// Owner-specific limit (0 = global limit applies)
+    pub max_active_distributors: u32,
+    /// Number of the owner's distributors currently open
+    pub active_distributors: u32,
+}
+
+/// Event emitted when an owner's nonce state is migrated to the current layout
+#[event]
+pub struct OwnerNonceMigrated {
+    /// The owner of the nonce state
+    pub owner: Pubkey,
+    /// Account that paid for the grown account
+    pub payer: Pubkey,
+    /// The owner's nonce counter, kept by the migration
+    pub nonce: u32,
+}
+
+/// Event emitted when a claim status is migrated to the current layout
+#[event]
+pub struct ClaimStatusMigrated {
+    /// The distributor the claim status belongs to
+    pub distributor: Pubkey,
+    /// The claimant the claim status belongs to
+    pub claimant: Pubkey,
+    /// Account that paid for the grown account
+    pub payer: Pubkey,
+    /// The claimed amount, kept by the migration
+    pub claimed_amount: u64,
+}
+
+#[event]
+pub struct GlobalPauseSet {
+    /// The config account public key
+    pub config: Pubkey,
+    /// Admin who toggled the pause
+    pub admin: Pubkey,
+    /// Whether the program is now paused
+    pub paused: bool,
+}
+
+/// Event emitted when an operator is added
+#[event]
+pub struct OperatorAdded {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Owner who added the operator
+    pub owner: Pubkey,
+    /// Operator that was added
+    pub operator: Pubkey,
+}
+
+/// Event emitted when an operator is removed
+#[event]
+pub struct OperatorRemoved {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Owner who removed the operator
+    pub owner: Pubkey,
+    /// Operator that was removed
+    pub operator: Pubkey,
+}
+
+/// Event emitted when a relayer is added to the relayer allowlist
+#[event]
+pub struct RelayerAdded {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Owner who added the relayer
+    pub owner: Pubkey,
+    /// Relayer that was added
+    pub relayer: Pubkey,
+}
+
+/// Event emitted when a relayer is removed from the relayer allowlist
+#[event]
+pub struct RelayerRemoved {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Owner who removed the relayer
+    pub owner: Pubkey,
+    /// Relayer that was removed
+    pub relayer: Pubkey,
 }
 
 /// Event emitted when the start time is set
@@ -26,10 +307,46 @@ pub struct StartTimeSet {
     pub distributor: Pubkey,
     /// Operator who set the start time
     pub operator: Pubkey,
+    /// Start time before the update (0 if it was not set)
+    pub previous_start_time: i64,
+    /// End time before the update (0 if it was not set)
+    pub previous_end_time: i64,
     /// Start time of the distribution
     pub start_time: i64,
     /// End time of the distribution
     pub end_time: i64,
+    /// Time after which unclaimed tokens can be withdrawn (end time + withdraw delay)
+    pub withdraw_unlocks_at: i64,
+    /// Number of timing updates on the distributor including this one
+    pub update_index: u64,
+}
+
+/// Event emitted when the end time of an active distribution is extended
+#[event]
+pub struct EndTimeExtended {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Operator who extended the end time
+    pub operator: Pubkey,
+    /// End time before the extension
+    pub previous_end_time: i64,
+    /// End time after the extension
+    pub end_time: i64,
+    /// Time after which unclaimed tokens can be withdrawn (end time + withdraw delay)
+    pub withdraw_unlocks_at: i64,
+    /// Number of timing updates on the distributor including this one
+    pub update_index: u64,
+}
+
+/// Event emitted when an operator sets the claim windows of a phased distribution
+#[event]
+pub struct ClaimWindowsSet {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Operator who set the claim windows
+    pub operator: Pubkey,
+    /// Claim windows in phase order (empty when the distribution is no longer phased)
+    pub claim_windows: Vec<ClaimWindow>,
 }
 
 /// Event emitted when the merkle root is set
@@ -39,8 +356,220 @@ pub struct MerkleRootSet {
     pub distributor: Pubkey,
     /// Operator who set the merkle root
     pub operator: Pubkey,
+    /// Merkle root before the update (all zeros if it was not set)
+    pub previous_merkle_root: [u8; 32],
     /// The merkle root hash
     pub merkle_root: [u8; 32],
+    /// Merkle tree hashing scheme version of the root
+    pub tree_version: u8,
+    /// Number of merkle root updates on the distributor including this one
+    pub update_index: u64,
+}
+
+/// Event emitted when a distributor's entry in its mint registry is synced
+#[event]
+pub struct RegistrySynced {
+    /// The registry account public key
+    pub registry: Pubkey,
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Token mint address
+    pub token_mint: Pubkey,
+    /// Start time of the distribution
+    pub start_time: i64,
+    /// End time of the distribution
+    pub end_time: i64,
+}
+
+/// Event emitted when a distributor's entry is removed from its mint registry
+/// - Emitted by withdraw, clawback and close_vault when given the registry, and by evict_registry_entry
+#[event]
+pub struct RegistryEntryRemoved {
+    /// The registry account public key
+    pub registry: Pubkey,
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Token mint address
+    pub token_mint: Pubkey,
+}
+
+/// Event emitted when the merkle root and timing are locked
+#[event]
+pub struct DistributorFinalized {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Owner who finalized the distributor
+    pub owner: Pubkey,
+    /// The locked merkle root
+    pub merkle_root: [u8; 32],
+    /// The locked start time
+    pub start_time: i64,
+    /// The locked end time
+    pub end_time: i64,
+}
+
+/// Event emitted when the distribution is paused
+#[event]
+pub struct DistributionPaused {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Owner or operator who paused the distribution
+    pub authority: Pubkey,
+}
+
+/// Event emitted when the distribution is unpaused
+#[event]
+pub struct DistributionUnpaused {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Owner or operator who unpaused the distribution
+    pub authority: Pubkey,
+}
+
+/// Event emitted when the merkle root update interval is changed
+#[event]
+pub struct RootUpdateIntervalSet {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Owner who changed the interval
+    pub owner: Pubkey,
+    /// Minimum interval between merkle root updates in seconds
+    pub root_update_interval: i64,
+}
+
+/// Event emitted when the claim fee is set
+#[event]
+pub struct ClaimFeeSet {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Owner who set the fee
+    pub owner: Pubkey,
+    /// Lamport fee charged per claim
+    pub claim_fee: u64,
+}
+
+/// Event emitted when the claim limits are set
+#[event]
+pub struct ClaimLimitsSet {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Owner who set the limits
+    pub owner: Pubkey,
+    /// Maximum cumulative amount per claimant (0 if uncapped)
+    pub max_claim_per_user: u64,
+    /// Maximum total amount claimed per slot (0 if unlimited)
+    pub max_total_per_slot: u64,
+}
+
+/// Event emitted when the owner turns the operator co-signature requirement on or off
+#[event]
+pub struct CosignRequirementSet {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Owner who changed the requirement
+    pub owner: Pubkey,
+    /// Whether claims now require an operator co-signature
+    pub require_cosign: bool,
+}
+
+/// Event emitted when the owner enables the claim log
+#[event]
+pub struct ClaimLogEnabled {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Owner who enabled the claim log
+    pub owner: Pubkey,
+}
+
+/// Event emitted when the owner enables the claim escrow
+#[event]
+pub struct ClaimEscrowEnabled {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Owner who enabled the claim escrow
+    pub owner: Pubkey,
+    /// Period over which each claimant's escrowed tokens are released, in seconds
+    pub release_duration: i64,
+}
+
+/// Event emitted when the owner sets the early bonus
+#[event]
+pub struct EarlyBonusSet {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Owner who set the early bonus
+    pub owner: Pubkey,
+    /// Bonus on claims made in the bonus window, in basis points (0 disables it)
+    pub early_bonus_bps: u16,
+    /// Period after the start during which claims earn the bonus, in seconds (slots in slot mode)
+    pub bonus_window: i64,
+    /// Tokens funding the bonuses
+    pub bonus_pool: u64,
+    /// Tokens deposited into the vault by this update
+    pub deposited: u64,
+}
+
+/// Event emitted when the claim gate is set or cleared
+#[event]
+pub struct ClaimGateSet {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Owner who set the gate
+    pub owner: Pubkey,
+    /// Mint claimants must hold (Pubkey::default() if claims are not gated)
+    pub gate_mint: Pubkey,
+    /// Minimum balance of the gate mint a claimant must hold
+    pub gate_min_amount: u64,
+}
+
+/// Event emitted when the claim attestation requirement is set
+#[event]
+pub struct ClaimAttestationSet {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Owner who set the requirement
+    pub owner: Pubkey,
+    /// Attestor claimants must hold an attestation from (Pubkey::default() if not required)
+    pub attestor: Pubkey,
+    /// Minimum attestation score a claimant must hold
+    pub min_attestation_score: u64,
+}
+
+/// Event emitted when an attestor issues or refreshes an attestation
+#[event]
+pub struct AttestationIssued {
+    /// Key that issued the attestation
+    pub attestor: Pubkey,
+    /// Wallet the attestation is about
+    pub subject: Pubkey,
+    /// Wallet-quality score assigned by the attestor
+    pub score: u64,
+    /// Time after which the attestation is no longer valid (0 if it does not expire)
+    pub expires_at: i64,
+}
+
+/// Event emitted when an attestor revokes an attestation
+#[event]
+pub struct AttestationRevoked {
+    /// Key that issued the attestation
+    pub attestor: Pubkey,
+    /// Wallet the attestation was about
+    pub subject: Pubkey,
+}
+
+/// Event emitted when accumulated claim fees are withdrawn
+#[event]
+pub struct ClaimFeesWithdrawn {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Owner who received the fees
+    pub owner: Pubkey,
+    /// Amount of lamports withdrawn
+    pub amount: u64,
+    /// Total claim fees collected so far
+    pub total_fees_collected: u64,
+    /// Total claim fees withdrawn so far
+    pub total_fees_withdrawn: u64,
 }
 
 /// Event emitted when tokens are claimed
@@ -56,6 +585,213 @@ pub struct TokensClaimed {
     pub user_max_amount: u64,
     /// Total amount claimed from the distributor by all users
     pub total_claimed: u64,
+    /// Protocol fee deducted from user_amount_claimed (the claimant received the rest)
+    pub protocol_fee: u64,
+    /// UI amount scaling of the token mint (amounts above are raw)
+    pub scaling: AmountScaling,
+    /// Index of the claim window the claim fell in (always 0 without claim windows)
+    pub phase: u8,
+    /// Early bonus paid out of the bonus pool on top of user_amount_claimed
+    pub bonus_amount: u64,
+}
+
+/// Event emitted when a claim empties the distributor's vault
+/// - The owner can withdraw from then on, without waiting for the end of the distribution
+#[event]
+pub struct DistributionCompleted {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Address of the claimant whose claim emptied the vault
+    pub claimant: Pubkey,
+    /// Total amount claimed from the distributor by all users
+    pub total_claimed: u64,
+    /// Number of claims made against the distributor
+    pub claims_count: u64,
+    /// Time at which the distribution completed
+    pub completed_at: i64,
+}
+
+/// Event emitted when a claim of an escrowed distributor is paid into the claimant's escrow
+/// - The claim itself is reported by TokensClaimed as for other claims
+#[event]
+pub struct TokensEscrowed {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Address of the claimant
+    pub claimant: Pubkey,
+    /// The claimant's claim escrow account
+    pub claim_escrow: Pubkey,
+    /// Amount of tokens escrowed by this claim (the claimed amount less any protocol fee)
+    pub amount: u64,
+    /// Total amount escrowed for the claimant
+    pub escrowed_amount: u64,
+    /// Time the release started (Unix timestamp)
+    pub release_start: i64,
+    /// Time the escrowed tokens are fully released (Unix timestamp)
+    pub release_end: i64,
+}
+
+/// Event emitted when a claimant releases unlocked tokens from their claim escrow
+#[event]
+pub struct TokensReleased {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Address of the claimant
+    pub claimant: Pubkey,
+    /// The claimant's claim escrow account
+    pub claim_escrow: Pubkey,
+    /// Amount of tokens released in this transaction
+    pub amount: u64,
+    /// Total amount released to the claimant
+    pub released_amount: u64,
+    /// Total amount escrowed for the claimant
+    pub escrowed_amount: u64,
+}
+
+/// Event emitted for each extra mint paid out by claim_multi_mint
+/// - The distributor's own mint is reported by TokensClaimed as for other claims
+#[event]
+pub struct ExtraMintClaimed {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Address of the claimant
+    pub claimant: Pubkey,
+    /// Extra token mint address
+    pub token_mint: Pubkey,
+    /// Amount of tokens claimed by user in this transaction
+    pub user_amount_claimed: u64,
+    /// Maximum amount of this mint the user is eligible to claim
+    pub user_max_amount: u64,
+    /// Total amount of this mint claimed from the distributor by all users
+    pub total_claimed: u64,
+    /// UI amount scaling of the token mint (amounts above are raw)
+    pub scaling: AmountScaling,
+}
+
+/// Event emitted when claim_to_recipient pays a claim to a leaf-specified recipient
+/// - The claim itself is reported by TokensClaimed as for other claims
+#[event]
+pub struct ClaimRedirected {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Address of the claimant that authorized the claim
+    pub claimant: Pubkey,
+    /// Address of the recipient the tokens were sent to
+    pub recipient: Pubkey,
+    /// Amount of tokens sent to the recipient, net of the protocol fee
+    pub amount: u64,
+}
+
+/// Event emitted when an operator reports the token account of a claimant frozen
+/// - The claim can be redirected once the grace period has elapsed
+#[event]
+pub struct FrozenClaimReported {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Address of the claimant whose token account is frozen
+    pub claimant: Pubkey,
+    /// Operator who reported the frozen token account
+    pub operator: Pubkey,
+    /// The claimant's frozen associated token account
+    pub frozen_token_account: Pubkey,
+    /// Time from which the claim can be redirected (Unix timestamp)
+    pub redirectable_at: i64,
+}
+
+/// Event emitted when an operator redirects the claim of a claimant whose token account is frozen
+/// - The claim itself is reported by TokensClaimed as for other claims
+#[event]
+pub struct FrozenClaimRedirected {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Address of the claimant whose allocation was redirected
+    pub claimant: Pubkey,
+    /// Operator who redirected the claim
+    pub operator: Pubkey,
+    /// The claimant's frozen associated token account
+    pub frozen_token_account: Pubkey,
+    /// Receiver of the redirected tokens (clawback receiver, or owner without one)
+    pub receiver: Pubkey,
+    /// Amount of tokens sent to the receiver, net of the protocol fee
+    pub amount: u64,
+}
+
+/// Event emitted when an operator reconciles a claim status with a reduced allocation
+#[event]
+pub struct ClaimStatusAdjusted {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Address of the claimant
+    pub claimant: Pubkey,
+    /// Operator who reconciled the claim status
+    pub operator: Pubkey,
+    /// Reduced allocation of the claimant, from the merkle tree
+    pub max_amount: u64,
+    /// Total amount claimed by the claimant, unchanged by the adjustment
+    pub claimed_amount: u64,
+    /// Amount the claimant claimed beyond the reduced allocation before the adjustment
+    pub previous_over_claimed_amount: u64,
+    /// Amount the claimant claimed beyond the reduced allocation
+    pub over_claimed_amount: u64,
+    /// Total amount claimed beyond reduced allocations across all claimants
+    pub total_over_claimed: u64,
+}
+
+/// Event emitted when a claimant freezes their claim
+#[event]
+pub struct ClaimFrozen {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Address of the claimant who froze the claim
+    pub claimant: Pubkey,
+    /// Earliest time the claim can be unfrozen
+    pub unfreeze_after: i64,
+}
+
+/// Event emitted when a claimant unfreezes their claim
+#[event]
+pub struct ClaimUnfrozen {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Address of the claimant who unfroze the claim
+    pub claimant: Pubkey,
+    /// Owner or operator who approved the unfreeze
+    pub authority: Pubkey,
+}
+
+/// Event emitted when a claimant sets or clears their claim delegate
+#[event]
+pub struct ClaimDelegateSet {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Address of the claimant
+    pub claimant: Pubkey,
+    /// Delegate allowed to claim on the claimant's behalf (Pubkey::default() if cleared)
+    pub delegate: Pubkey,
+}
+
+/// Event emitted when the rent escrow is funded
+#[event]
+pub struct RentEscrowFunded {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Owner who funded the escrow
+    pub owner: Pubkey,
+    /// Amount of lamports deposited
+    pub amount: u64,
+    /// Escrow balance after the deposit
+    pub balance: u64,
+}
+
+/// Event emitted when the rent escrow is withdrawn
+#[event]
+pub struct RentEscrowWithdrawn {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Owner who received the lamports
+    pub owner: Pubkey,
+    /// Amount of lamports withdrawn
+    pub amount: u64,
 }
 
 /// Event emitted when remaining tokens are withdrawn
@@ -69,6 +805,54 @@ pub struct TokensWithdrawn {
     pub amount_withdrawn: u64,
 }
 
+/// Event emitted when the emptied token vault is closed, keeping the distributor open
+#[event]
+pub struct VaultClosed {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// The closed token vault
+    pub token_vault: Pubkey,
+    /// Owner who closed the vault and received its rent
+    pub owner: Pubkey,
+}
+
+/// Event emitted when the vault of an extra mint is withdrawn and closed
+#[event]
+pub struct DistributorMintWithdrawn {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Extra token mint address
+    pub token_mint: Pubkey,
+    /// Owner of the token account that received the tokens (owner or clawback receiver)
+    pub receiver: Pubkey,
+    /// Amount of tokens withdrawn
+    pub amount_withdrawn: u64,
+}
+
+/// Event emitted when Token 2022 transfer fees withheld in a vault are moved to the mint before closing
+#[event]
+pub struct WithheldFeesHarvested {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Vault the withheld fees were harvested from
+    pub token_vault: Pubkey,
+    /// Mint now holding the fees, withdrawable by its withdraw withheld authority
+    pub token_mint: Pubkey,
+    /// Amount of withheld fees harvested
+    pub amount: u64,
+}
+
+/// Event emitted when unclaimed tokens are clawed back
+#[event]
+pub struct Clawback {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Receiver of the unclaimed tokens
+    pub clawback_receiver: Pubkey,
+    /// Amount of tokens clawed back
+    pub amount: u64,
+}
+
 /// Event emitted when a ClaimStatus account is closed
 #[event]
 pub struct ClaimStatusClosed {
@@ -78,4 +862,21 @@ pub struct ClaimStatusClosed {
     pub claimant: Pubkey,
     /// Total amount that was claimed by this user
     pub claimed_amount: u64,
+    /// Account that received the reclaimed rent
+    pub rent_payer: Pubkey,
+}
+
+/// Event emitted when a stale ClaimStatus account is closed by a crank
+#[event]
+pub struct ClaimStatusGarbageCollected {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+    /// Address of the claimant the account belonged to
+    pub claimant: Pubkey,
+    /// Total amount that was claimed by this user
+    pub claimed_amount: u64,
+    /// Account that received the reclaimed rent
+    pub rent_payer: Pubkey,
+    /// Account that submitted the garbage collection
+    pub cranker: Pubkey,
 }
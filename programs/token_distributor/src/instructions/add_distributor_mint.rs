@@ -0,0 +1,160 @@
+use crate::constants::*;
+use crate::error::*;
+use crate::event::*;
+use crate::state::*;
+use crate::utils::{transfer_token_with_hook, amount_scaling};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/**
+ * Account context for adding an extra mint to a multi-mint distributor
+ *
+ * This instruction lets a campaign distribute several tokens (e.g. a governance
+ * token plus a stablecoin rebate) to the same recipients under one distributor,
+ * one merkle tree and one claim per recipient:
+ * - Creates a distributor mint PDA recording the mint and its leaf position
+ * - Creates a token vault PDA for the mint, controlled by the distributor PDA
+ * - Transfers the initial token amount from owner to the vault
+ *
+ * Access Control: Only the owner can add mints
+ *
+ * Business Logic:
+ * - Only for distributors created with MULTI_MINT_TREE_VERSION
+ * - Only before the merkle root is set, since every leaf carries one amount per extra mint
+ * - Up to MAX_EXTRA_MINTS extra mints, each distinct from the distributor's own mint
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AddDistributorMint<'info> {
+    /// The multi-mint distributor to add the mint to
+    /// - Will be modified to count the extra mint
+    #[account(
+        mut,
+        constraint = token_mint.key() != distributor.load()?.token_mint @ TokenDistributorError::TokenMintMismatch
+    )]
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+
+    /// The distributor mint account (PDA)
+    /// - Derived from: ["distributor_mint", distributor_key, token_mint]
+    #[account(
+        init,
+        payer = owner,
+        space = DistributorMint::LEN,
+        seeds = [DISTRIBUTOR_MINT_SEED.as_bytes(), distributor.key().as_ref(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub distributor_mint: Account<'info, DistributorMint>,
+
+    /// Token vault account (PDA) that holds the extra mint's tokens
+    /// - Controlled by the distributor PDA as token authority
+    /// - Derived from: ["vault", distributor_mint_key]
+    #[account(
+        init,
+        token::mint = token_mint,
+        token::authority = distributor,
+        token::token_program = token_program,
+        seeds = [VAULT_SEED.as_bytes(), distributor_mint.key().as_ref()],
+        bump,
+        payer = owner,
+    )]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// The extra token mint
+    /// - Supports both SPL Token and Token 2022 programs
+    #[account(
+        token::token_program = token_program,
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    /// Owner's token account containing the tokens to be deposited
+    /// - Must be owned by the owner signer
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = owner,
+        token::token_program = token_program,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// The owner of the distributor
+    /// - Must match the owner stored in the distributor state
+    #[account(
+        mut,
+        constraint = owner.key() == distributor.load()?.owner @ TokenDistributorError::OnlyOwner
+    )]
+    pub owner: Signer<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Token program of the extra mint (supports both SPL Token and Token 2022)
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/**
+ * Adds an extra mint to a multi-mint distributor
+ *
+ * @param ctx - The account context, with transfer hook accounts in remaining accounts
+ * @param initial_total_amount - Total amount of the extra mint to be distributed
+ */
+pub fn handle_add_distributor_mint<'info>(
+    ctx: Context<'_, '_, 'info, 'info, AddDistributorMint<'info>>,
+    initial_total_amount: u64,
+) -> Result<()> {
+    require!(initial_total_amount > 0, TokenDistributorError::InvalidAmount);
+
+    let distributor_key = ctx.accounts.distributor.key();
+    let index = {
+        let distributor = &mut ctx.accounts.distributor.load_mut()?;
+
+        require!(distributor.is_multi_mint(), TokenDistributorError::NotMultiMintDistributor);
+
+        // Existing leaves would not carry an amount for the new mint
+        require!(distributor.merkle_root == [0; 32], TokenDistributorError::MerkleRootAlreadySet);
+
+        require!(
+            (distributor.extra_mint_count as usize) < MAX_EXTRA_MINTS,
+            TokenDistributorError::TooManyExtraMints
+        );
+
+        let index = distributor.extra_mint_count;
+        distributor.extra_mint_count += 1;
+        distributor.open_extra_mints += 1;
+        index
+    };
+
+    let distributor_mint = &mut ctx.accounts.distributor_mint;
+    distributor_mint.bump = ctx.bumps.distributor_mint;
+    distributor_mint.index = index;
+    distributor_mint.decimals = ctx.accounts.token_mint.decimals;
+    distributor_mint.distributor = distributor_key;
+    distributor_mint.token_mint = ctx.accounts.token_mint.key();
+    distributor_mint.token_vault = ctx.accounts.token_vault.key();
+    distributor_mint.initial_total_amount = initial_total_amount;
+
+    // Transfer tokens from owner to the extra mint's vault
+    transfer_token_with_hook(
+        ctx.accounts.owner.to_account_info(),
+        ctx.accounts.owner_token_account.to_account_info(),
+        ctx.accounts.token_vault.to_account_info(),
+        ctx.accounts.token_mint.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        initial_total_amount,
+        ctx.accounts.token_mint.decimals,
+        None, // No signer seeds needed for owner-signed transfer
+        ctx.remaining_accounts,
+    )?;
+
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(DistributorMintAdded {
+        distributor: distributor_key,
+        distributor_mint: ctx.accounts.distributor_mint.key(),
+        token_mint: ctx.accounts.token_mint.key(),
+        token_vault: ctx.accounts.token_vault.key(),
+        index,
+        initial_total_amount,
+        scaling: amount_scaling(&ctx.accounts.token_mint.to_account_info(), ctx.accounts.token_mint.decimals)?,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::event::*;
+
+/**
+ * Account context for adding an operator
+ * 
+ * This instruction allows the distributor owner to add an operator to the
+ * fixed-size operator list, so campaigns can be managed by several team members.
+ * 
+ * Access Control: Only the owner can add operators
+ * 
+ * Business Logic:
+ * - The operator is stored in the first empty slot of the operator list
+ * - An operator cannot be listed twice
+ * - At most MAX_OPERATORS operators can be listed at the same time
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AddOperator<'info> {
+    /// The distributor account to update
+    /// - Must be a valid existing distributor PDA
+    /// - Will be modified to add the operator
+    #[account(mut)]
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+    
+    /// The owner of the distributor
+    /// - Must match the owner stored in the distributor state
+    /// - Only this account can call this instruction
+    #[account(constraint = owner.key() == distributor.load()?.owner @ TokenDistributorError::OnlyOwner)]
+    pub owner: Signer<'info>,
+}
+
+/**
+ * Adds an operator to the distributor
+ * 
+ * @param ctx - The account context containing distributor and owner accounts
+ * @param operator - Public key of the operator to add
+ * 
+ */
+pub fn handle_add_operator(
+    ctx: Context<AddOperator>,
+    operator: Pubkey,
+) -> Result<()> {
+    let distributor_key = ctx.accounts.distributor.key();
+    let distributor = &mut ctx.accounts.distributor.load_mut()?;
+    
+    // Validate operator is not empty account
+    require!(operator != Pubkey::default(), TokenDistributorError::InvalidOperator);
+    
+    // Reject duplicates so removal always clears the operator completely
+    require!(!distributor.is_operator(&operator), TokenDistributorError::OperatorAlreadyExists);
+    
+    // Store the operator in the first empty slot
+    let slot = distributor
+        .operators
+        .iter_mut()
+        .find(|key| **key == Pubkey::default())
+        .ok_or(TokenDistributorError::TooManyOperators)?;
+    *slot = operator;
+    
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(OperatorAdded {
+        distributor: distributor_key,
+        owner: ctx.accounts.owner.key(),
+        operator,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::event::*;
+
+/**
+ * Account context for adding a relayer
+ * 
+ * This instruction allows the distributor owner to add a relayer to the
+ * fixed-size relayer allowlist, so sponsored claims are only executed by the
+ * owner's own relayer service.
+ * 
+ * Access Control: Only the owner can add relayers
+ * 
+ * Business Logic:
+ * - The relayer is stored in the first empty slot of the relayer list
+ * - A relayer cannot be listed twice
+ * - At most MAX_RELAYERS relayers can be listed at the same time
+ * - Once any relayer is listed, claim_with_signature rejects other relayers
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AddRelayer<'info> {
+    /// The distributor account to update
+    /// - Must be a valid existing distributor PDA
+    /// - Will be modified to add the relayer
+    #[account(mut)]
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+    
+    /// The owner of the distributor
+    /// - Must match the owner stored in the distributor state
+    /// - Only this account can call this instruction
+    #[account(constraint = owner.key() == distributor.load()?.owner @ TokenDistributorError::OnlyOwner)]
+    pub owner: Signer<'info>,
+}
+
+/**
+ * Adds a relayer to the distributor's relayer allowlist
+ * 
+ * @param ctx - The account context containing distributor and owner accounts
+ * @param relayer - Public key of the relayer to add
+ * 
+ */
+pub fn handle_add_relayer(
+    ctx: Context<AddRelayer>,
+    relayer: Pubkey,
+) -> Result<()> {
+    let distributor_key = ctx.accounts.distributor.key();
+    let distributor = &mut ctx.accounts.distributor.load_mut()?;
+    
+    // Validate relayer is not empty account
+    require!(relayer != Pubkey::default(), TokenDistributorError::InvalidRelayer);
+    
+    // Reject duplicates so removal always clears the relayer completely
+    require!(!distributor.is_relayer(&relayer), TokenDistributorError::RelayerAlreadyExists);
+    
+    // Store the relayer in the first empty slot
+    let slot = distributor
+        .relayers
+        .iter_mut()
+        .find(|key| **key == Pubkey::default())
+        .ok_or(TokenDistributorError::TooManyRelayers)?;
+    *slot = relayer;
+    
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(RelayerAdded {
+        distributor: distributor_key,
+        owner: ctx.accounts.owner.key(),
+        relayer,
+    });
+    
+    Ok(())
+}
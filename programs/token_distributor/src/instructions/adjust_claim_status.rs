@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::constants::*;
+use crate::event::*;
+use super::verify_allocation_proof;
+
+/**
+ * Account context for reconciling a claim status with a reduced allocation
+ *
+ * This instruction allows a listed operator to record that a new merkle root
+ * lowered a claimant's max_amount below what they already claimed, instead of
+ * leaving the claimant's claims to fail and the excess hidden in total_claimed.
+ *
+ * Access Control: Only a listed operator can adjust claim statuses
+ *
+ * Business Logic:
+ * - The reduced allocation must be proven against the current or a recent merkle root
+ * - The excess over the allocation is recorded on the claim status and totalled
+ *   on the distributor, claimed_amount and total_claimed keep the amounts paid out
+ * - Adjusting again after a further reduction replaces the recorded excess
+ * - Nothing is refunded or recovered: the claimant has nothing left to claim, so
+ *   their claims keep failing with AllocationReduced until a later root raises
+ *   max_amount above the claimed amount
+ * - The record is cleared by the claimant's next claim under such a root, or by
+ *   adjusting with an allocation of at least the claimed amount (e.g. a root that
+ *   restores exactly the claimed amount, under which no claim succeeds)
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AdjustClaimStatus<'info> {
+    /// The distributor the claim status belongs to
+    /// - Will be modified to update total_over_claimed
+    #[account(mut)]
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+
+    /// Claim status of the claimant whose allocation was reduced
+    /// - Derived from: ["claim", distributor_key, claimant_key]
+    /// - Will be modified to record over_claimed_amount
+    #[account(
+        mut,
+        seeds = [CLAIM_SEED.as_bytes(), distributor.key().as_ref(), claimant.key().as_ref()],
+        bump
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+
+    /// The claimant whose allocation was reduced
+    /// CHECK: Only bound into the merkle leaf and used for PDA derivation
+    pub claimant: UncheckedAccount<'info>,
+
+    /// An operator of the distributor
+    /// - Must be one of the operators listed in the distributor state
+    #[account(constraint = distributor.load()?.is_operator(&operator.key()) @ TokenDistributorError::OnlyOperator)]
+    pub operator: Signer<'info>,
+}
+
+/**
+ * Records the excess a claimant claimed over their reduced allocation
+ *
+ * @param ctx - The account context containing distributor, claim status, claimant and operator accounts
+ * @param max_amount - The claimant's reduced allocation (from merkle tree)
+ * @param proof - Array of 32-byte hashes forming the merkle proof path
+ * @param unlock_time - The claimant's unlock time from the merkle tree (tree version 2 only)
+ */
+pub fn handle_adjust_claim_status(
+    ctx: Context<AdjustClaimStatus>,
+    max_amount: u64,
+    proof: Vec<[u8; 32]>,
+    unlock_time: Option<i64>,
+) -> Result<()> {
+    let distributor_key = ctx.accounts.distributor.key();
+    let claimant_key = ctx.accounts.claimant.key();
+    let distributor = &mut ctx.accounts.distributor.load_mut()?;
+    let claim_status = &mut ctx.accounts.claim_status;
+
+    // Ensure merkle root has been set
+    require!(distributor.merkle_root != [0; 32], TokenDistributorError::NoMerkleRoot);
+
+    // Only an allocation from the merkle tree can be reconciled
+    verify_allocation_proof(
+        distributor,
+        distributor_key,
+        &claimant_key,
+        max_amount,
+        &proof,
+        unlock_time,
+        &[],
+        None,
+    )?;
+
+    // An allocation restored to at least the claimed amount settles an earlier record
+    let claimed_amount = claim_status.claimed_amount;
+    let previous_over_claimed_amount = claim_status.over_claimed_amount;
+    require!(
+        claimed_amount > max_amount || previous_over_claimed_amount > 0,
+        TokenDistributorError::AllocationNotReduced
+    );
+
+    // Record the excess, replacing any earlier adjustment in the distributor total
+    let over_claimed_amount = claimed_amount.saturating_sub(max_amount);
+    claim_status.over_claimed_amount = over_claimed_amount;
+    distributor.total_over_claimed = distributor.total_over_claimed
+        .saturating_sub(previous_over_claimed_amount)
+        .checked_add(over_claimed_amount)
+        .ok_or(TokenDistributorError::ArithmeticOverflow)?;
+    distributor.operator_last_action_ts = Clock::get()?.unix_timestamp;
+
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(ClaimStatusAdjusted {
+        distributor: distributor_key,
+        claimant: claimant_key,
+        operator: ctx.accounts.operator.key(),
+        max_amount,
+        claimed_amount,
+        previous_over_claimed_amount,
+        over_claimed_amount,
+        total_over_claimed: distributor.total_over_claimed,
+    });
+
+    Ok(())
+}
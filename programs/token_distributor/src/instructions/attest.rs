@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::constants::*;
+use crate::event::*;
+
+/**
+ * Account context for issuing an attestation
+ *
+ * This instruction lets any attestor publish a wallet-quality score for a
+ * wallet, for distributors that require attestations from this attestor.
+ *
+ * Access Control: Any key can attest, distributors choose which attestor they trust
+ *
+ * Business Logic:
+ * - Creates the attestation on first use, refreshes score and expiry afterwards
+ * - The attestor pays the rent and receives it back on revoke_attestation
+ */
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(subject: Pubkey)]
+pub struct Attest<'info> {
+    /// The attestation account (PDA)
+    /// - Derived from: ["attestation", attestor, subject]
+    #[account(
+        init_if_needed,
+        payer = attestor,
+        space = Attestation::LEN,
+        seeds = [ATTESTATION_SEED.as_bytes(), attestor.key().as_ref(), subject.as_ref()],
+        bump
+    )]
+    pub attestation: Account<'info, Attestation>,
+
+    /// The attestor issuing the attestation
+    /// - Pays the rent of the attestation account
+    #[account(mut)]
+    pub attestor: Signer<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/**
+ * Issues or refreshes an attestation
+ *
+ * @param ctx - The account context containing attestation and attestor accounts
+ * @param subject - Wallet the attestation is about
+ * @param score - Wallet-quality score, on a scale defined by the attestor
+ * @param expires_at - Time after which the attestation is no longer valid (0 if it does not expire)
+ */
+pub fn handle_attest(
+    ctx: Context<Attest>,
+    subject: Pubkey,
+    score: u64,
+    expires_at: i64,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(expires_at == 0 || expires_at > current_time, TokenDistributorError::InvalidAttestationExpiry);
+
+    let attestation = &mut ctx.accounts.attestation;
+    attestation.bump = ctx.bumps.attestation;
+    attestation.attestor = ctx.accounts.attestor.key();
+    attestation.subject = subject;
+    attestation.score = score;
+    attestation.issued_at = current_time;
+    attestation.expires_at = expires_at;
+
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(AttestationIssued {
+        attestor: attestation.attestor,
+        subject,
+        score,
+        expires_at,
+    });
+
+    Ok(())
+}
@@ -1,10 +1,17 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{TokenInterface, TokenAccount};
+use anchor_spl::memo::Memo;
+#[cfg(feature = "claim-fee")]
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::solana_program::sysvar;
+use anchor_spl::token_interface::{TokenInterface, TokenAccount, Mint};
 use crate::state::*;
 use crate::error::*;
-use crate::utils::verify;
+use crate::utils::{compute_root, compute_root_keccak, hash_leaf, hash_leaf_keccak, hash_leaf_with_unlock_time, hash_leaf_with_recipient, load_program_config};
+#[cfg(feature = "multi-mint")]
+use crate::utils::hash_leaf_multi_mint;
+use crate::utils::{cosign_message, load_ed25519_signature};
 use crate::constants::*;
-use crate::utils::transfer_token;
+use crate::utils::{transfer_token_with_hook, memo_if_required, amount_scaling};
 use crate::event::*;
 
 /**
@@ -14,7 +21,10 @@ use crate::event::*;
  * a valid merkle proof. The instruction verifies the proof, updates claim status,
  * and transfers tokens from the vault to the claimant.
  * 
- * Access Control: Any user with a valid merkle proof can claim their tokens
+ * Access Control: Any user with a valid merkle proof can claim their tokens,
+ * or their delegate set via set_claim_delegate
+ * - Distributors requiring a co-signature also need an operator's ed25519
+ *   signature in the instruction immediately before this one
  * 
  */
 #[event_cpi]
@@ -24,14 +34,14 @@ pub struct Claim<'info> {
     /// - Must be a valid existing distributor PDA
     /// - Will be modified to update total_claimed amount
     #[account(mut)]
-    pub distributor: Account<'info, TokenDistributor>,
+    pub distributor: AccountLoader<'info, TokenDistributor>,
     
     /// Individual claim status for this claimant
     /// - Tracks how much this user has already claimed
     /// - Derived from: ["claim", distributor_key, claimant_key]
     #[account(
         init_if_needed,
-        payer = claimant,
+        payer = payer,
         space = ClaimStatus::LEN,
         seeds = [CLAIM_SEED.as_bytes(), distributor.key().as_ref(), claimant.key().as_ref()],
         bump
@@ -39,45 +49,148 @@ pub struct Claim<'info> {
     pub claim_status: Account<'info, ClaimStatus>,
     
     /// Token vault holding the tokens to be distributed
-    /// - Controlled by the distributor PDA
-    /// - Derived from: ["vault", distributor_key]
+    /// - Controlled by the distributor PDA, or the shared vault PDA for shared distributors
+    /// - Must match the vault stored in the distributor state
     #[account(
         mut,
-        seeds = [VAULT_SEED.as_bytes(), distributor.key().as_ref()],
-        bump
+        address = distributor.load()?.token_vault
     )]
     pub token_vault: InterfaceAccount<'info, TokenAccount>,
     
+    /// Shared vault the distributor draws from
+    /// - Required only for shared distributors
+    /// - Will be modified to consume the allocated budget
+    #[account(
+        mut,
+        constraint = shared_vault.key() == distributor.load()?.shared_vault @ TokenDistributorError::SharedVaultMismatch
+    )]
+    pub shared_vault: Option<Account<'info, SharedVault>>,
+    
     /// Claimant's token account to receive the tokens
     /// - Must be owned by the claimant
     /// - Must be for the correct token mint
+    /// - Must not be frozen (ClaimantAccountFrozen)
     #[account(
         mut,
-        token::mint = distributor.token_mint,
+        token::mint = distributor.load()?.token_mint,
         token::authority = claimant,
         token::token_program = token_program,
+        constraint = !claimant_token_account.is_frozen() @ TokenDistributorError::ClaimantAccountFrozen,
     )]
     pub claimant_token_account: InterfaceAccount<'info, TokenAccount>,
     
     /// The token mint for verification
     /// - Must match the distributor's token mint
+    /// - Must still report the decimals cached at creation
     #[account(
         token::token_program = token_program,
-        constraint = token_mint.key() == distributor.token_mint @ TokenDistributorError::TokenMintMismatch
+        constraint = token_mint.key() == distributor.load()?.token_mint @ TokenDistributorError::TokenMintMismatch,
+        constraint = token_mint.decimals == distributor.load()?.decimals @ TokenDistributorError::MintDecimalsMismatch
     )]
-    pub token_mint: InterfaceAccount<'info, anchor_spl::token_interface::Mint>,
+    pub token_mint: InterfaceAccount<'info, Mint>,
     
-    /// The claimant attempting to claim tokens
-    /// - Must sign the transaction
+    /// The claimant whose allocation is claimed
+    /// - Must sign the transaction, unless the claimant's delegate signs
     /// - Must have a valid merkle proof for the claim
+    /// CHECK: Signature or delegation verified in the handler
+    pub claimant: UncheckedAccount<'info>,
+    
+    /// Delegate claiming on the claimant's behalf
+    /// - Required only when the claimant does not sign
+    /// - Must match the delegate stored in the claim status
+    pub delegate: Option<Signer<'info>>,
+    
+    /// Pays the rent if the ClaimStatus account is created, and the claim fee
+    /// - May be the claimant, or a relayer / the project sponsoring the claim
+    /// - Recorded as rent payer and refunded when the ClaimStatus is closed
     #[account(mut)]
-    pub claimant: Signer<'info>,
+    pub payer: Signer<'info>,
     
     /// System program for account creation
     pub system_program: Program<'info, System>,
     
     /// Token program (supports both SPL Token and Token 2022)
-    pub token_program: Interface<'info, TokenInterface>,
+    pub token_program: Interface<'info, TokenInterface>,    
+    /// SPL Memo program
+    /// - Required only when the claimant token account requires incoming transfer memos
+    ///   (Token 2022 MemoTransfer extension)
+    pub memo_program: Option<Program<'info, Memo>>,
+    
+    /// Token account receiving the protocol fee
+    /// - Required only while the protocol fee is enabled
+    /// - Must be owned by the protocol fee receiver set in the program config
+    #[cfg(feature = "protocol-fee")]
+    #[account(
+        mut,
+        constraint = protocol_fee_account.mint == token_mint.key() @ TokenDistributorError::TokenMintMismatch
+    )]
+    pub protocol_fee_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Claimant's token account of the distributor's gate mint
+    /// - Required only when the distributor gates claims on token holdings
+    #[cfg(feature = "claim-gates")]
+    pub gate_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Claimant's attestation from the distributor's attestor
+    /// - Required only when the distributor requires an attestation to claim
+    /// - Derived from: ["attestation", attestor, claimant]
+    #[cfg(feature = "claim-gates")]
+    pub attestation: Option<Account<'info, Attestation>>,
+    
+    /// Program config holding the global pause switch and default parameters
+    /// CHECK: Derived from ["config"], either uninitialized or a valid ProgramConfig
+    #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+    pub config: UncheckedAccount<'info>,
+    
+    /// Claim log page receiving the record of this claim
+    /// - Required only when the distributor keeps a claim log
+    /// - Derived from: ["claim_log", distributor_key, claim_log_page]
+    /// - Created by the payer when this claim writes the page's first record
+    #[cfg(feature = "claim-log")]
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ClaimLog::LEN,
+        seeds = [CLAIM_LOG_SEED.as_bytes(), distributor.key().as_ref(), &distributor.load()?.claim_log_page().to_le_bytes()],
+        bump
+    )]
+    pub claim_log: Option<AccountLoader<'info, ClaimLog>>,
+    
+    /// Instructions sysvar used to inspect the operator's ed25519 co-signature instruction
+    /// - Required only when the distributor requires a co-signature
+    /// CHECK: Address is checked against the instructions sysvar id
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+    
+    /// Claimant's claim escrow receiving the claim
+    /// - Required only when the distributor escrows claims
+    /// - Derived from: ["claim_escrow", distributor_key, claimant_key]
+    /// - Created by the payer on the claimant's first claim
+    #[cfg(feature = "claim-escrow")]
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ClaimEscrow::LEN,
+        seeds = [CLAIM_ESCROW_SEED.as_bytes(), distributor.key().as_ref(), claimant.key().as_ref()],
+        bump
+    )]
+    pub claim_escrow: Option<Account<'info, ClaimEscrow>>,
+    
+    /// Token account of the claim escrow receiving the claimed tokens instead of the claimant
+    /// - Required only when the distributor escrows claims
+    /// - Derived from: ["escrow_vault", distributor_key, claimant_key]
+    /// - Created by the payer on the claimant's first claim
+    #[cfg(feature = "claim-escrow")]
+    #[account(
+        init_if_needed,
+        payer = payer,
+        token::mint = token_mint,
+        token::authority = claim_escrow,
+        token::token_program = token_program,
+        seeds = [ESCROW_VAULT_SEED.as_bytes(), distributor.key().as_ref(), claimant.key().as_ref()],
+        bump
+    )]
+    pub escrow_vault: Option<InterfaceAccount<'info, TokenAccount>>,
 }
 
 /**
@@ -86,118 +199,846 @@ pub struct Claim<'info> {
  * @param ctx - The account context containing all required accounts
  * @param max_amount - Maximum amount this user is eligible to claim (from merkle tree)
  * @param proof - Array of 32-byte hashes forming the merkle proof path
+ * @param amount - Optional amount to claim now (defaults to the full pending amount)
+ * @param unlock_time - The claimant's unlock time from the merkle tree (tree version 2 only)
+ * @param cosign_expiry - Expiry of the operator co-signature (Unix timestamp, co-signed distributors only)
  * 
  * Validation Process:
- * 1. Verify merkle root is set and distribution is active
+ * 1. Verify the claimant or their delegate signed, that distribution is not paused,
+ *    merkle root is set and distribution is active,
+ *    that the claimant holds the gating token if the distributor is gated,
+ *    and that an operator co-signed the claim if the distributor requires it
  * 2. Check that current time is within distribution window
- * 3. Verify merkle proof for (claimant, max_amount) pair
- * 4. Calculate and transfer pending amount
+ * 3. Verify merkle proof for (claimant, max_amount[, unlock_time]) against the current or a historical root
+ *    and, for tree version 2, that the claimant's unlock time has passed
+ * 4. Enforce the per-claimant cap and per-slot limit, and the budget for
+ *    distributors drawing from a shared vault
+ * 5. Append the claim to the claim log, if the distributor keeps one
+ * 6. Charge the claim fee, if any, to the payer
+ * 7. Calculate and transfer the requested amount (up to the pending amount),
+ *    less the protocol fee, if any, which goes to the protocol fee account,
+ *    plus the early bonus, if the claim falls in the bonus window,
+ *    passing remaining accounts to the mint's transfer hook, if any
+ *    For escrowed distributors the amount goes to the claimant's claim escrow,
+ *    released to the claimant over time via release
  */
-pub fn handle_claim(
-    ctx: Context<Claim>,
+pub fn handle_claim<'info>(
+    ctx: Context<'_, '_, 'info, 'info, Claim<'info>>,
     max_amount: u64,
     proof: Vec<[u8; 32]>,
+    amount: Option<u64>,
+    unlock_time: Option<i64>,
+    cosign_expiry: Option<i64>,
 ) -> Result<()> {
-    let distributor = &mut ctx.accounts.distributor;
-    let claim_status = &mut ctx.accounts.claim_status;
+    // Reject all claims while the program is globally paused
+    let config = load_program_config(&ctx.accounts.config)?;
+    config.require_not_paused()?;
+    
+    let distributor_key = ctx.accounts.distributor.key();
+    let claimant_key = ctx.accounts.claimant.key();
+    let vault_balance = ctx.accounts.token_vault.amount;
+    
+    // Claims are authorized by the claimant, or by the delegate they set
+    let delegated = ctx.accounts.delegate.as_ref()
+        .is_some_and(|delegate| ctx.accounts.claim_status.is_delegate(delegate.key));
+    require!(
+        ctx.accounts.claimant.is_signer || delegated,
+        TokenDistributorError::NotClaimantOrDelegate
+    );
+    
+    // Record the rent payer when the claim status is first created
+    if ctx.accounts.claim_status.rent_payer == Pubkey::default() {
+        ctx.accounts.claim_status.rent_payer = ctx.accounts.payer.key();
+    }
+    
+    // Validate the claim and update claim status and distributor totals
+    let (pending_amount, phase, bonus_amount, new_total_claimed, completed) = {
+        let distributor = &mut ctx.accounts.distributor.load_mut()?;
+        #[cfg(feature = "claim-gates")]
+        check_claim_gate(
+            distributor,
+            &claimant_key,
+            ctx.accounts.gate_token_account.as_deref(),
+            ctx.accounts.attestation.as_deref(),
+        )?;
+        check_cosign(
+            distributor,
+            &distributor_key,
+            &claimant_key,
+            max_amount,
+            cosign_expiry,
+            ctx.accounts.instructions_sysvar.as_deref(),
+        )?;
+        let (pending_amount, phase) = process_claim(
+            distributor,
+            distributor_key,
+            &mut ctx.accounts.claim_status,
+            &claimant_key,
+            vault_balance,
+            max_amount,
+            proof,
+            amount,
+            unlock_time,
+            &[],
+            None,
+        )?;
+        #[cfg(feature = "claim-log")]
+        if distributor.has_claim_log() {
+            let claim_log = ctx.accounts.claim_log.as_ref()
+                .ok_or(TokenDistributorError::ClaimLogRequired)?;
+            append_claim_log(distributor, distributor_key, claim_log, &claimant_key, pending_amount)?;
+        }
+        // Early claims earn a bonus out of the bonus pool, which the vault holds alongside the allocations
+        let activation_now = distributor.activation_now(&Clock::get()?);
+        let bonus_amount = distributor.early_bonus(activation_now, pending_amount)
+            .min(vault_balance - pending_amount);
+        distributor.total_bonus_paid = distributor.total_bonus_paid
+            .checked_add(bonus_amount)
+            .ok_or(TokenDistributorError::ArithmeticOverflow)?;
+        let completed = complete_distribution(
+            distributor,
+            distributor_key,
+            &claimant_key,
+            vault_balance,
+            pending_amount + bonus_amount,
+        );
+        (pending_amount, phase, bonus_amount, distributor.total_claimed, completed)
+    };
+    
+    // Protocol fee is taken out of the claimed amount, the bonus is paid in full
+    #[cfg(feature = "protocol-fee")]
+    let (protocol_fee, protocol_fee_account) = (
+        protocol_fee_for(
+            &config,
+            ctx.accounts.protocol_fee_account.as_ref().map(|account| account.owner),
+            pending_amount,
+        )?,
+        ctx.accounts.protocol_fee_account.as_ref().map(|account| account.to_account_info()),
+    );
+    #[cfg(not(feature = "protocol-fee"))]
+    let (protocol_fee, protocol_fee_account) = (0, None);
+    
+    // Escrowed distributors pay the claim, less the protocol fee, into the claimant's escrow
+    let destination = ctx.accounts.claimant_token_account.to_account_info();
+    #[cfg(feature = "claim-escrow")]
+    let (destination, escrowed) = {
+        let release_duration = ctx.accounts.distributor.load()?.escrow_release_duration;
+        match (ctx.accounts.claim_escrow.as_mut(), ctx.accounts.escrow_vault.as_ref()) {
+            _ if release_duration == 0 => (destination, None),
+            (Some(claim_escrow), Some(escrow_vault)) => {
+                let escrowed = escrow_claim(
+                    claim_escrow,
+                    ctx.bumps.claim_escrow.unwrap_or_default(),
+                    distributor_key,
+                    &claimant_key,
+                    ctx.accounts.token_mint.key(),
+                    release_duration,
+                    pending_amount + bonus_amount - protocol_fee,
+                )?;
+                (escrow_vault.to_account_info(), Some(escrowed))
+            }
+            _ => return err!(TokenDistributorError::ClaimEscrowRequired),
+        }
+    };
+    
+    // ===== INTERACTIONS PHASE (Fee and Token Transfer) =====
     
+    #[cfg(feature = "claim-fee")]
+    collect_claim_fee(
+        &ctx.accounts.distributor,
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+    
+    pay_claim(
+        &ctx.accounts.distributor,
+        ctx.accounts.shared_vault.as_mut(),
+        ctx.accounts.token_vault.to_account_info(),
+        destination,
+        &ctx.accounts.token_mint,
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.memo_program.as_ref().map(|p| p.to_account_info()),
+        protocol_fee_account,
+        pending_amount + bonus_amount,
+        protocol_fee,
+        ctx.remaining_accounts,
+    )?;
+    
+    // Emit event for off-chain indexing and monitoring
+    let scaling = amount_scaling(&ctx.accounts.token_mint.to_account_info(), ctx.accounts.token_mint.decimals)?;
+    emit_cpi!(TokensClaimed {
+        distributor: distributor_key,
+        claimant: claimant_key,
+        user_amount_claimed: pending_amount,        // Amount claimed by user in this transaction
+        user_max_amount: max_amount,               // Maximum amount the user is eligible to claim
+        total_claimed: new_total_claimed,          // Total amount claimed from the distributor by all users
+        protocol_fee,                              // Protocol fee deducted from the amount claimed
+        scaling,                                   // UI amount scaling of the mint, amounts above are raw
+        phase,                                     // Index of the claim window the claim fell in
+        bonus_amount,                              // Early bonus paid on top of the amount claimed
+    });
+    #[cfg(feature = "claim-escrow")]
+    if let Some(escrowed) = escrowed {
+        emit_cpi!(escrowed);
+    }
+    if let Some(completed) = completed {
+        emit_cpi!(completed);
+    }
+    
+    Ok(())
+} 
+
+/**
+ * Validates a claim and applies its state updates
+ * 
+ * Shared by claim and claim_batch. Verifies the distribution window, the claim
+ * status and the merkle proof, then records the claimed amount on the claim
+ * status and the distributor. The caller performs the token transfer.
+ * 
+ * @param distributor - The distributor being claimed from
+ * @param distributor_key - Address of the distributor (bound into the leaf)
+ * @param claim_status - The claimant's claim status for this distributor
+ * @param claimant - The claimant address
+ * @param vault_balance - Current balance of the vault paying out the claim
+ * @param max_amount - Maximum amount this user is eligible to claim (from merkle tree)
+ * @param proof - Array of 32-byte hashes forming the merkle proof path, empty if the
+ *                allocation was verified by an earlier claim under the current root
+ * @param amount - Optional amount to claim now (defaults to the full pending amount)
+ * @param unlock_time - The claimant's unlock time from the merkle tree (tree version 2 only)
+ * @param extra_amounts - Extra mint amounts from the merkle tree (multi-mint distributors only)
+ * @param recipient - Recipient of the claim from the merkle tree (tree version 4 only)
+ * 
+ * @return The amount of the distributor's token mint to transfer to the claimant,
+ *         which may be 0 for multi-mint claims owing only extra mints, and the
+ *         phase of the claim (index of its claim window, 0 without claim windows)
+ */
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn process_claim(
+    distributor: &mut TokenDistributor,
+    distributor_key: Pubkey,
+    claim_status: &mut ClaimStatus,
+    claimant: &Pubkey,
+    vault_balance: u64,
+    max_amount: u64,
+    proof: Vec<[u8; 32]>,
+    amount: Option<u64>,
+    unlock_time: Option<i64>,
+    extra_amounts: &[u64],
+    recipient: Option<&Pubkey>,
+) -> Result<(u64, u8)> {
     // ===== VALIDATION PHASE =====
     
+    // Reject claims while the distribution is paused
+    require!(!distributor.is_paused(), TokenDistributorError::DistributionPaused);
+    
     // Ensure merkle root has been set (required for claim verification)
     require!(distributor.merkle_root != [0; 32], TokenDistributorError::NoMerkleRoot);
     
     // Verify distribution is active (within time window)
-    let current_time = Clock::get()?.unix_timestamp;
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
     // Check if start time has been set
     require!(distributor.start_time > 0, TokenDistributorError::StartTimeNotSet);
     
-    // Check if distribution time is within the window
-    require!(current_time >= distributor.start_time, TokenDistributorError::DistributionNotStarted);
-    require!(current_time <= distributor.end_time, TokenDistributorError::DistributionEnded);
+    // Check if distribution time is within the window, on the slot clock in slot mode
+    let activation_now = distributor.activation_now(&clock);
+    require!(activation_now >= distributor.start_time, TokenDistributorError::DistributionNotStarted);
+    require!(activation_now <= distributor.end_time, TokenDistributorError::DistributionEnded);
+    
+    // Phased distributions only accept claims inside one of their claim windows
+    let phase = distributor.claim_phase(activation_now)
+        .ok_or(TokenDistributorError::OutsideClaimWindow)?;
+    
+    // Reject claims the claimant has frozen
+    require!(!claim_status.frozen, TokenDistributorError::ClaimFrozen);
     
     // Check if user can still claim more tokens
+    // Multi-mint claims may owe only extra mints, the caller checks those
+    let multi_mint = !extra_amounts.is_empty();
     let claimed_amount = claim_status.claimed_amount;
-    require!(max_amount > claimed_amount, TokenDistributorError::InvalidAmount);
+    // Allocations lowered below the claimed amount are reconciled via adjust_claim_status
+    require!(max_amount >= claimed_amount || multi_mint, TokenDistributorError::AllocationReduced);
+    let remaining_amount = max_amount.saturating_sub(claimed_amount);
+    require!(remaining_amount > 0 || multi_mint, TokenDistributorError::InvalidAmount);
     
     // ===== MERKLE PROOF VERIFICATION =====
     
-    let claimant_account = &ctx.accounts.claimant;
-    
-    // Create the leaf node hash (claimant_pubkey + max_amount)
-    // This represents the user's entry in the merkle tree
-    let leaf: anchor_lang::solana_program::hash::Hash = anchor_lang::solana_program::hash::hashv(&[
-        &claimant_account.key().to_bytes(),
-        &max_amount.to_le_bytes(),
-    ]);
-    
-    // Verify the merkle proof
     // This ensures the user is eligible for the claimed amount
-    require!(
-        verify(proof, distributor.merkle_root, leaf.to_bytes()),
-        TokenDistributorError::InvalidProof
-    );
+    // Allocations already verified under the current root may omit the proof
+    let leaf = allocation_leaf(distributor, distributor_key, claimant, max_amount, unlock_time, extra_amounts, recipient)?;
+    if !(proof.is_empty() && claim_status.has_verified_leaf(&leaf, distributor.root_update_count)) {
+        let root = verify_leaf(distributor, &proof, leaf)?;
+        // Only the current root is cached, historical roots leave the history over time
+        if root == distributor.merkle_root {
+            claim_status.verified_leaf = leaf;
+            claim_status.verified_root_update = distributor.root_update_count;
+        }
+    }
+    
+    // Reject claims before the claimant's own unlock time, even if the distribution has started
+    if distributor.tree_version == UNLOCK_TIME_TREE_VERSION {
+        require!(activation_now >= unlock_time.unwrap_or_default(), TokenDistributorError::ClaimLocked);
+    }
     
     // ===== EFFECTS PHASE (State Updates) =====
     
     // Calculate the amount to transfer (incremental claiming)
-    let pending_amount = max_amount - claimed_amount;
+    // Partial claims pull any portion of the remaining allocation
+    let pending_amount = amount.unwrap_or(remaining_amount);
+    require!(
+        (pending_amount > 0 || multi_mint) && pending_amount <= remaining_amount,
+        TokenDistributorError::InvalidAmount
+    );
     
     // Check vault has sufficient balance before proceeding
     require!(
-        ctx.accounts.token_vault.amount >= pending_amount,
+        vault_balance >= pending_amount,
         TokenDistributorError::InsufficientVaultBalance
     );
     
-    // Prepare other immutable references
-    let nonce_bytes = distributor.nonce.to_le_bytes();
-    let token_mint_key = distributor.token_mint;
-    let owner_key = distributor.owner;
-    let distributor_bump = distributor.bump;
-    let distributor_key = distributor.key();
+    // Risk limits bound the damage of an over-allocating merkle tree
+    let new_claimed_amount = claimed_amount + pending_amount;  // Cumulative across partial claims
+    if distributor.max_claim_per_user > 0 {
+        require!(
+            new_claimed_amount <= distributor.max_claim_per_user,
+            TokenDistributorError::ClaimCapExceeded
+        );
+    }
+    require!(
+        distributor.consume_slot_allowance(clock.slot, pending_amount),
+        TokenDistributorError::SlotClaimLimitExceeded
+    );
     
     // Update claim status (CEI pattern - effects before interactions)
-    claim_status.claimed_amount = max_amount;  // Set to full amount (cumulative)
+    claim_status.claimed_amount = new_claimed_amount;
+    
+    // A claim settles any report of a frozen token account
+    claim_status.frozen_reported_at = 0;
+    
+    // A root allocating the claimed amount again settles any recorded over-claim
+    if claim_status.over_claimed_amount > 0 && max_amount >= claimed_amount {
+        distributor.total_over_claimed = distributor.total_over_claimed
+            .saturating_sub(claim_status.over_claimed_amount);
+        claim_status.over_claimed_amount = 0;
+    }
     
     // Calculate new total claimed amount with overflow protection
     let new_total_claimed = distributor.total_claimed
         .checked_add(pending_amount)
         .ok_or(TokenDistributorError::ArithmeticOverflow)?;
     
-    // Update distributor's total claimed amount
+    // Shared distributors are capped by their budget
+    if distributor.is_shared() {
+        require!(new_total_claimed <= distributor.budget, TokenDistributorError::BudgetExceeded);
+    }
+    
+    // Update distributor's total claimed amount and summary stats
     distributor.total_claimed = new_total_claimed;
+    distributor.record_claim(current_time);
     
-    // ===== INTERACTIONS PHASE (Token Transfer) =====
+    Ok((pending_amount, phase))
+}
+
+/**
+ * Verifies a claimant's allocation against the distributor's merkle roots
+ * 
+ * Shared by the claim instructions and verify_allocation. Multi-mint distributors
+ * with extra mints only verify with their extra amounts, so only claim_multi_mint
+ * can claim from them. Likewise tree version 4 distributors only verify with a
+ * recipient, so their tokens can only be claimed to it via claim_to_recipient.
+ * 
+ * @param distributor - The distributor the allocation belongs to
+ * @param distributor_key - Address of the distributor (bound into the leaf)
+ * @param claimant - The claimant address
+ * @param max_amount - Maximum amount this user is eligible to claim (from merkle tree)
+ * @param proof - Array of 32-byte hashes forming the merkle proof path
+ * @param unlock_time - The claimant's unlock time from the merkle tree (tree version 2 only)
+ * @param extra_amounts - Extra mint amounts from the merkle tree, one per extra mint
+ *                        (multi-mint distributors only, empty otherwise)
+ * @param recipient - Recipient of the claim from the merkle tree (tree version 4 only)
+ */
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn verify_allocation_proof(
+    distributor: &TokenDistributor,
+    distributor_key: Pubkey,
+    claimant: &Pubkey,
+    max_amount: u64,
+    proof: &[[u8; 32]],
+    unlock_time: Option<i64>,
+    extra_amounts: &[u64],
+    recipient: Option<&Pubkey>,
+) -> Result<()> {
+    let leaf = allocation_leaf(distributor, distributor_key, claimant, max_amount, unlock_time, extra_amounts, recipient)?;
+    verify_leaf(distributor, proof, leaf)?;
+    
+    Ok(())
+}
+
+/**
+ * Computes the merkle leaf of a claimant's allocation
+ * 
+ * @param distributor - The distributor the allocation belongs to
+ * @param distributor_key - Address of the distributor (bound into the leaf)
+ * @param claimant - The claimant address
+ * @param max_amount - Maximum amount this user is eligible to claim (from merkle tree)
+ * @param unlock_time - The claimant's unlock time from the merkle tree (tree version 2 only)
+ * @param extra_amounts - Extra mint amounts from the merkle tree (multi-mint distributors only)
+ * @param recipient - Recipient of the claim from the merkle tree (tree version 4 only)
+ */
+fn allocation_leaf(
+    distributor: &TokenDistributor,
+    distributor_key: Pubkey,
+    claimant: &Pubkey,
+    max_amount: u64,
+    unlock_time: Option<i64>,
+    extra_amounts: &[u64],
+    recipient: Option<&Pubkey>,
+) -> Result<[u8; 32]> {
+    // Leaves carry exactly one extra amount per extra mint
+    require!(
+        extra_amounts.len() == distributor.extra_mint_count as usize,
+        TokenDistributorError::ExtraAmountsMismatch
+    );
+    
+    // Create the leaf node hash (prefix + distributor_pubkey + claimant_pubkey + max_amount
+    // [+ unlock_time for tree version 2] [+ extra amounts for tree version 3]
+    // [+ recipient before max_amount for tree version 4], hashed with keccak256 for tree version 5)
+    // This represents the user's entry in this distributor's merkle tree
+    let leaf = match distributor.tree_version {
+        UNLOCK_TIME_TREE_VERSION => {
+            let unlock_time = unlock_time.ok_or(TokenDistributorError::MissingUnlockTime)?;
+            hash_leaf_with_unlock_time(&distributor_key, claimant, max_amount, unlock_time)
+        }
+        #[cfg(feature = "multi-mint")]
+        MULTI_MINT_TREE_VERSION => hash_leaf_multi_mint(&distributor_key, claimant, max_amount, extra_amounts),
+        RECIPIENT_TREE_VERSION => {
+            let recipient = recipient.ok_or(TokenDistributorError::MissingRecipient)?;
+            hash_leaf_with_recipient(&distributor_key, claimant, recipient, max_amount)
+        }
+        KECCAK_TREE_VERSION => hash_leaf_keccak(&distributor_key, claimant, max_amount),
+        _ => hash_leaf(&distributor_key, claimant, max_amount),
+    };
     
-    // Prepare PDA signing seeds for token transfer
-    let seeds = &[
+    Ok(leaf)
+}
+
+/**
+ * Verifies a merkle proof of a leaf against the distributor's known roots
+ * 
+ * @param distributor - The distributor the leaf belongs to
+ * @param proof - Array of 32-byte hashes forming the merkle proof path
+ * @param leaf - The allocation leaf, see allocation_leaf
+ * 
+ * @return The root the proof resolves to, the current or a historical root still in its grace period
+ */
+fn verify_leaf(distributor: &TokenDistributor, proof: &[[u8; 32]], leaf: [u8; 32]) -> Result<[u8; 32]> {
+    // Bound the hashing cost of the proof walk
+    require!(proof.len() <= MAX_PROOF_DEPTH, TokenDistributorError::ProofTooLong);
+    
+    // Verify the merkle proof against the current root or a historical root still in its grace period
+    // The proof is walked once and the resulting root compared to each known root
+    let computed_root = if distributor.tree_version == KECCAK_TREE_VERSION {
+        compute_root_keccak(proof, leaf)
+    } else {
+        compute_root(proof, leaf)
+    };
+    require!(
+        distributor.known_roots(Clock::get()?.unix_timestamp).any(|root| *root == computed_root),
+        TokenDistributorError::InvalidProof
+    );
+    
+    Ok(computed_root)
+}
+
+/**
+ * Checks that the claimant meets the distributor's claim gates
+ * 
+ * Shared by all claim instructions. Checks the gating token holdings and the
+ * attestation, each only when the distributor requires it.
+ * 
+ * @param distributor - The distributor being claimed from
+ * @param claimant - The claimant address
+ * @param gate_token_account - Claimant's token account of the gate mint, if provided
+ * @param attestation - Claimant's attestation from the distributor's attestor, if provided
+ */
+#[cfg(feature = "claim-gates")]
+pub(crate) fn check_claim_gate(
+    distributor: &TokenDistributor,
+    claimant: &Pubkey,
+    gate_token_account: Option<&TokenAccount>,
+    attestation: Option<&Attestation>,
+) -> Result<()> {
+    if distributor.is_gated() {
+        let gate_token_account = gate_token_account.ok_or(TokenDistributorError::ClaimGateNotMet)?;
+        require!(
+            gate_token_account.mint == distributor.gate_mint
+                && gate_token_account.owner == *claimant
+                && gate_token_account.amount >= distributor.gate_min_amount,
+            TokenDistributorError::ClaimGateNotMet
+        );
+    }
+    
+    if distributor.requires_attestation() {
+        let attestation = attestation.ok_or(TokenDistributorError::AttestationRequired)?;
+        require!(
+            attestation.attestor == distributor.attestor
+                && attestation.subject == *claimant
+                && attestation.score >= distributor.min_attestation_score
+                && attestation.is_valid_at(Clock::get()?.unix_timestamp),
+            TokenDistributorError::AttestationRequired
+        );
+    }
+    Ok(())
+}
+
+/**
+ * Checks the operator co-signature of a claim
+ * 
+ * Called by claim. Only distributors requiring a co-signature are checked: the
+ * instruction before the claim must verify an operator's ed25519 signature over
+ * (distributor, claimant, max_amount, expiry), and the expiry must not have passed.
+ * 
+ * @param distributor - The distributor being claimed from
+ * @param distributor_key - Address of the distributor
+ * @param claimant - The claimant address
+ * @param max_amount - Maximum amount the claimant is eligible to claim (from merkle tree)
+ * @param expiry - Expiry of the co-signature (Unix timestamp), if provided
+ * @param instructions_sysvar - Instructions sysvar, if provided
+ */
+pub(crate) fn check_cosign(
+    distributor: &TokenDistributor,
+    distributor_key: &Pubkey,
+    claimant: &Pubkey,
+    max_amount: u64,
+    expiry: Option<i64>,
+    instructions_sysvar: Option<&AccountInfo>,
+) -> Result<()> {
+    if !distributor.requires_cosign() {
+        return Ok(());
+    }
+    
+    let (Some(expiry), Some(instructions_sysvar)) = (expiry, instructions_sysvar) else {
+        return err!(TokenDistributorError::CosignRequired);
+    };
+    require!(Clock::get()?.unix_timestamp <= expiry, TokenDistributorError::CosignExpired);
+    
+    let (cosigner, message) = load_ed25519_signature(instructions_sysvar)?;
+    require!(
+        distributor.is_operator(&cosigner)
+            && message == cosign_message(distributor_key, claimant, max_amount, expiry),
+        TokenDistributorError::InvalidCosign
+    );
+    Ok(())
+}
+
+/**
+ * Appends a claim to the distributor's claim log
+ * 
+ * Called by claim after process_claim recorded the claim. The page is the one
+ * derived from claim_log_count, so the record lands at its next free slot.
+ * 
+ * @param distributor - The distributor being claimed from
+ * @param distributor_key - Address of the distributor
+ * @param claim_log - The claim log page derived from the distributor's claim_log_count
+ * @param claimant - The claimant address
+ * @param amount - Amount claimed, as returned by process_claim
+ */
+#[cfg(feature = "claim-log")]
+fn append_claim_log(
+    distributor: &mut TokenDistributor,
+    distributor_key: Pubkey,
+    claim_log: &AccountLoader<ClaimLog>,
+    claimant: &Pubkey,
+    amount: u64,
+) -> Result<()> {
+    // A page created by this claim has no discriminator until the instruction exits
+    let is_new = claim_log.as_ref().try_borrow_data()?[..8] == [0; 8];
+    let page = &mut if is_new { claim_log.load_init()? } else { claim_log.load_mut()? };
+    if is_new {
+        page.distributor = distributor_key;
+        page.page = distributor.claim_log_page();
+    }
+    
+    let appended = page.push(ClaimRecord {
+        claimant: *claimant,
+        amount,
+        claimed_at: distributor.last_claim_at,
+    });
+    require!(appended, TokenDistributorError::ClaimLogPageFull);
+    distributor.claim_log_count += 1;
+    
+    Ok(())
+}
+
+/**
+ * Records a claim paid into the claimant's claim escrow
+ * 
+ * Called by claim for escrowed distributors. The claimant's first claim starts
+ * the release with the distributor's release duration, later claims are added
+ * to the same schedule.
+ * 
+ * @param claim_escrow - The claimant's claim escrow, created by this claim if new
+ * @param bump - Bump of the claim escrow PDA
+ * @param distributor_key - Address of the distributor
+ * @param claimant - The claimant address
+ * @param token_mint - Mint of the escrowed tokens
+ * @param release_duration - The distributor's escrow release duration
+ * @param amount - Amount paid into the escrow
+ * 
+ * @return The TokensEscrowed event for the caller to emit
+ */
+#[cfg(feature = "claim-escrow")]
+fn escrow_claim(
+    claim_escrow: &mut Account<ClaimEscrow>,
+    bump: u8,
+    distributor_key: Pubkey,
+    claimant: &Pubkey,
+    token_mint: Pubkey,
+    release_duration: i64,
+    amount: u64,
+) -> Result<TokensEscrowed> {
+    if claim_escrow.distributor == Pubkey::default() {
+        claim_escrow.bump = bump;
+        claim_escrow.distributor = distributor_key;
+        claim_escrow.claimant = *claimant;
+        claim_escrow.token_mint = token_mint;
+        claim_escrow.release_start = Clock::get()?.unix_timestamp;
+        claim_escrow.release_duration = release_duration;
+    }
+    
+    claim_escrow.escrowed_amount = claim_escrow.escrowed_amount
+        .checked_add(amount)
+        .ok_or(TokenDistributorError::ArithmeticOverflow)?;
+    
+    Ok(TokensEscrowed {
+        distributor: distributor_key,
+        claimant: *claimant,
+        claim_escrow: claim_escrow.key(),
+        amount,
+        escrowed_amount: claim_escrow.escrowed_amount,
+        release_start: claim_escrow.release_start,
+        release_end: claim_escrow.release_end(),
+    })
+}
+
+/**
+ * Marks the distributor completed when a claim empties its vault
+ * 
+ * Called by the claim instructions after process_claim. Shared vaults are left
+ * out, as other distributors still draw from them, and so are multi-mint
+ * distributors with extra mints, whose extra vaults may still hold tokens.
+ * 
+ * @param distributor - The distributor being claimed from
+ * @param distributor_key - Address of the distributor
+ * @param claimant - The claimant address
+ * @param vault_balance - Balance of the vault before paying out the claim
+ * @param pending_amount - Amount paid out of the vault by the claim
+ * 
+ * @return The DistributionCompleted event for the caller to emit, if the claim emptied the vault
+ */
+pub(crate) fn complete_distribution(
+    distributor: &mut TokenDistributor,
+    distributor_key: Pubkey,
+    claimant: &Pubkey,
+    vault_balance: u64,
+    pending_amount: u64,
+) -> Option<DistributionCompleted> {
+    if pending_amount == 0
+        || pending_amount != vault_balance
+        || distributor.is_shared()
+        || distributor.extra_mint_count > 0
+    {
+        return None;
+    }
+    
+    // The claim was just recorded, so it is the last claim of the distributor
+    distributor.completed_at = distributor.last_claim_at;
+    
+    Some(DistributionCompleted {
+        distributor: distributor_key,
+        claimant: *claimant,
+        total_claimed: distributor.total_claimed,
+        claims_count: distributor.claims_count,
+        completed_at: distributor.completed_at,
+    })
+}
+
+/**
+ * Charges the distributor's claim fee to the payer
+ * 
+ * Shared by all claim instructions. The fee is collected onto the distributor
+ * account and tracked in total_fees_collected until the owner sweeps it.
+ * 
+ * @param distributor - The distributor being claimed from
+ * @param payer - Account paying the fee
+ * @param system_program - System program for the lamport transfer
+ */
+#[cfg(feature = "claim-fee")]
+pub(crate) fn collect_claim_fee<'info>(
+    distributor: &AccountLoader<'info, TokenDistributor>,
+    payer: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+) -> Result<()> {
+    let claim_fee = distributor.load()?.claim_fee;
+    if claim_fee == 0 {
+        return Ok(());
+    }
+    
+    transfer(
+        CpiContext::new(
+            system_program,
+            Transfer {
+                from: payer,
+                to: distributor.to_account_info(),
+            },
+        ),
+        claim_fee,
+    )?;
+    
+    let distributor = &mut distributor.load_mut()?;
+    distributor.total_fees_collected = distributor.total_fees_collected
+        .checked_add(claim_fee)
+        .ok_or(TokenDistributorError::ArithmeticOverflow)?;
+    
+    Ok(())
+}
+
+/**
+ * Computes the protocol fee on a claimed amount
+ * 
+ * Shared by all claim instructions. While the protocol fee is enabled, the
+ * account receiving it must be provided and belong to the protocol fee receiver.
+ * 
+ * @param config - The program config holding the protocol fee settings
+ * @param fee_receiver - Owner of the provided protocol fee account, if any
+ * @param amount - Amount claimed, as returned by process_claim
+ * 
+ * @return The fee to deduct from the amount claimed
+ */
+#[cfg(feature = "protocol-fee")]
+pub(crate) fn protocol_fee_for(
+    config: &ProgramConfig,
+    fee_receiver: Option<Pubkey>,
+    amount: u64,
+) -> Result<u64> {
+    let protocol_fee = config.protocol_fee(amount);
+    if protocol_fee > 0 {
+        require!(
+            fee_receiver == Some(config.protocol_fee_receiver),
+            TokenDistributorError::ProtocolFeeAccountMismatch
+        );
+    }
+    Ok(protocol_fee)
+}
+
+/**
+ * Transfers a processed claim from the vault to the claimant
+ * 
+ * Shared by claim and claim_with_signature. Signs with the distributor PDA, or
+ * with the shared vault PDA for shared distributors, whose allocation is
+ * reduced by the claimed amount.
+ * 
+ * @param distributor - The distributor being claimed from
+ * @param shared_vault - The shared vault, required for shared distributors
+ * @param token_vault - Vault paying out the claim
+ * @param claimant_token_account - Claimant's token account receiving the tokens
+ * @param token_mint - The distributor's token mint
+ * @param token_program - Token program (supports both SPL Token and Token 2022)
+ * @param memo_program - Memo program, required if the claimant token account requires memos
+ * @param protocol_fee_account - Token account receiving the protocol fee, required if the fee is non-zero
+ * @param amount - Amount claimed, as returned by process_claim
+ * @param protocol_fee - Part of the amount sent to the protocol fee account instead of the claimant
+ * @param hook_accounts - Extra accounts required by the mint's transfer hook, if any
+ */
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn pay_claim<'info>(
+    distributor: &AccountLoader<'info, TokenDistributor>,
+    shared_vault: Option<&mut Account<'info, SharedVault>>,
+    token_vault: AccountInfo<'info>,
+    claimant_token_account: AccountInfo<'info>,
+    token_mint: &InterfaceAccount<'info, Mint>,
+    token_program: AccountInfo<'info>,
+    memo_program: Option<AccountInfo<'info>>,
+    #[cfg_attr(not(feature = "protocol-fee"), allow(unused_variables))]
+    protocol_fee_account: Option<AccountInfo<'info>>,
+    amount: u64,
+    protocol_fee: u64,
+    hook_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    // Copy the signing data out so the account is not borrowed during the transfer
+    let (token_mint_key, owner, nonce, bump, is_shared) = {
+        let distributor = distributor.load()?;
+        (distributor.token_mint, distributor.owner, distributor.nonce, distributor.bump, distributor.is_shared())
+    };
+    let nonce_bytes = nonce.to_le_bytes();
+    let distributor_seeds: &[&[u8]] = &[
         DISTRIBUTOR_SEED.as_bytes(),
         token_mint_key.as_ref(),
-        owner_key.as_ref(),
+        owner.as_ref(),
         nonce_bytes.as_ref(),
-        &[distributor_bump],
+        &[bump],
     ];
-    let signer = &[&seeds[..]];
-
+    
+    // Shared distributors consume the shared vault allocation and pay from it
+    let shared_vault = match shared_vault {
+        Some(shared_vault) if is_shared => {
+            shared_vault.allocated_amount = shared_vault.allocated_amount
+                .checked_sub(amount)
+                .ok_or(TokenDistributorError::ArithmeticOverflow)?;
+            Some(shared_vault)
+        }
+        _ => {
+            require!(!is_shared, TokenDistributorError::SharedVaultMismatch);
+            None
+        }
+    };
+    let shared_vault_bump = shared_vault.as_ref().map_or(0, |v| v.bump);
+    let shared_vault_seeds: &[&[u8]] = &[
+        SHARED_VAULT_SEED.as_bytes(),
+        token_mint_key.as_ref(),
+        owner.as_ref(),
+        &[shared_vault_bump],
+    ];
+    
+    // Prepare PDA signing seeds and authority for token transfer
+    let (authority, seeds) = match shared_vault {
+        Some(shared_vault) => (shared_vault.to_account_info(), shared_vault_seeds),
+        None => (distributor.to_account_info(), distributor_seeds),
+    };
+    let signer = &[seeds];
+    
+    // Destinations with the MemoTransfer extension need a memo right before the transfer
+    memo_if_required(&claimant_token_account, memo_program.clone(), CLAIM_MEMO)?;
+    
     // Transfer tokens from vault to claimant using PDA authority
-    transfer_token(
-        ctx.accounts.distributor.to_account_info(),  // Delayed AccountInfo acquisition
-        ctx.accounts.token_vault.to_account_info(),
-        ctx.accounts.claimant_token_account.to_account_info(),
-        ctx.accounts.token_mint.to_account_info(),
-        ctx.accounts.token_program.to_account_info(),
-        pending_amount,
-        ctx.accounts.token_mint.decimals,
+    transfer_token_with_hook(
+        authority.clone(),
+        token_vault.clone(),
+        claimant_token_account,
+        token_mint.to_account_info(),
+        token_program.clone(),
+        amount - protocol_fee,
+        token_mint.decimals,
         Some(signer),  // PDA signing for secure transfer
+        hook_accounts,
     )?;
     
-    // Emit event for off-chain indexing and monitoring
-    emit_cpi!(TokensClaimed {
-        distributor: distributor_key,
-        claimant: ctx.accounts.claimant.key(),
-        user_amount_claimed: pending_amount,        // Amount claimed by user in this transaction
-        user_max_amount: max_amount,               // Maximum amount the user is eligible to claim
-        total_claimed: new_total_claimed,          // Total amount claimed from the distributor by all users
-    });
+    // Transfer the protocol fee, if any, from the same vault
+    #[cfg(feature = "protocol-fee")]
+    if let Some(protocol_fee_account) = protocol_fee_account.filter(|_| protocol_fee > 0) {
+        memo_if_required(&protocol_fee_account, memo_program, CLAIM_MEMO)?;
+        transfer_token_with_hook(
+            authority,
+            token_vault,
+            protocol_fee_account,
+            token_mint.to_account_info(),
+            token_program,
+            protocol_fee,
+            token_mint.decimals,
+            Some(signer),
+            hook_accounts,
+        )?;
+    }
     
     Ok(())
-} 
\ No newline at end of file
+}
@@ -0,0 +1,333 @@
+use anchor_lang::prelude::*;
+use anchor_spl::memo::Memo;
+use anchor_spl::token_interface::{TokenInterface, TokenAccount};
+use crate::state::*;
+use crate::error::*;
+use crate::constants::*;
+use crate::utils::{create_pda_account, transfer_token_with_hook, memo_if_required, load_program_config, amount_scaling};
+use crate::event::*;
+use super::{process_claim, complete_distribution};
+#[cfg(feature = "claim-fee")]
+use super::collect_claim_fee;
+#[cfg(feature = "protocol-fee")]
+use super::protocol_fee_for;
+#[cfg(feature = "claim-gates")]
+use super::check_claim_gate;
+
+/// A single claim within a batch
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct BatchClaimEntry {
+    /// Maximum amount the claimant is eligible to claim (from merkle tree)
+    pub max_amount: u64,
+    /// Array of 32-byte hashes forming the merkle proof path
+    pub proof: Vec<[u8; 32]>,
+    /// The claimant's unlock time from the merkle tree (tree version 2 only)
+    pub unlock_time: Option<i64>,
+}
+
+/**
+ * Account context for claiming from several distributors at once
+ * 
+ * This instruction lets a claimant eligible across several concurrent
+ * distributors of the same mint claim from all of them in one transaction.
+ * 
+ * Remaining accounts hold one (distributor, claim_status, token_vault) triple
+ * per entry, in the same order as the entries:
+ * - distributor: writable distributor account
+ * - claim_status: writable claim status PDA ["claim", distributor_key, claimant_key],
+ *   created if it does not exist yet
+ * - token_vault: writable vault of the distributor
+ * 
+ * Extra accounts required by the mint's transfer hook, if any, follow the triples.
+ * 
+ * Access Control: Any user with valid merkle proofs can claim their tokens
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimBatch<'info> {
+    /// Claimant's token account to receive the tokens
+    /// - Must be owned by the claimant
+    /// - Must be for the token mint of every distributor in the batch
+    /// - Must not be frozen (ClaimantAccountFrozen)
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = claimant,
+        token::token_program = token_program,
+        constraint = !claimant_token_account.is_frozen() @ TokenDistributorError::ClaimantAccountFrozen,
+    )]
+    pub claimant_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// The token mint shared by all distributors in the batch
+    #[account(
+        token::token_program = token_program,
+    )]
+    pub token_mint: InterfaceAccount<'info, anchor_spl::token_interface::Mint>,
+    
+    /// The claimant attempting to claim tokens
+    /// - Must sign the transaction
+    /// - Pays rent for claim status accounts created by the batch, and claim fees
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+    
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+    
+    /// Token program (supports both SPL Token and Token 2022)
+    pub token_program: Interface<'info, TokenInterface>,    
+    /// SPL Memo program
+    /// - Required only when the claimant or protocol fee token account requires incoming transfer memos
+    ///   (Token 2022 MemoTransfer extension)
+    pub memo_program: Option<Program<'info, Memo>>,
+    
+    /// Token account receiving the protocol fee
+    /// - Required only while the protocol fee is enabled
+    /// - Must be owned by the protocol fee receiver set in the program config
+    #[cfg(feature = "protocol-fee")]
+    #[account(
+        mut,
+        constraint = protocol_fee_account.mint == token_mint.key() @ TokenDistributorError::TokenMintMismatch
+    )]
+    pub protocol_fee_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Claimant's token account of the gate mint
+    /// - Required only when distributors in the batch gate claims on token holdings
+    /// - Checked against every gated distributor in the batch
+    #[cfg(feature = "claim-gates")]
+    pub gate_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Claimant's attestation from the distributor's attestor
+    /// - Required only when distributors in the batch require an attestation to claim
+    /// - Checked against every distributor requiring one, so they must share the attestor
+    /// - Derived from: ["attestation", attestor, claimant]
+    #[cfg(feature = "claim-gates")]
+    pub attestation: Option<Account<'info, Attestation>>,
+    
+    /// Program config holding the global pause switch and default parameters
+    /// CHECK: Derived from ["config"], either uninitialized or a valid ProgramConfig
+    #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+    pub config: UncheckedAccount<'info>,
+}
+
+/**
+ * Processes a batch of token claims with merkle proof verification
+ * 
+ * @param ctx - The account context, with one account triple per entry in remaining accounts,
+ *              followed by transfer hook accounts
+ * @param entries - One (max_amount, proof) entry per distributor, claiming the full pending amount
+ * 
+ * Validation Rules:
+ * - Between 1 and MAX_BATCH_CLAIMS entries
+ * - Each entry is validated exactly like a single claim
+ * - Distributors drawing from a shared vault are not supported
+ */
+pub fn handle_claim_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimBatch<'info>>,
+    entries: Vec<BatchClaimEntry>,
+) -> Result<()> {
+    // Reject all claims while the program is globally paused
+    let config = load_program_config(&ctx.accounts.config)?;
+    config.require_not_paused()?;
+    
+    require!(
+        !entries.is_empty() && entries.len() <= MAX_BATCH_CLAIMS,
+        TokenDistributorError::InvalidBatchSize
+    );
+    require!(
+        ctx.remaining_accounts.len() >= entries.len() * 3,
+        TokenDistributorError::InvalidBatchAccounts
+    );
+    let (batch_accounts, hook_accounts) = ctx.remaining_accounts.split_at(entries.len() * 3);
+    
+    let claimant_key = ctx.accounts.claimant.key();
+    let token_mint_key = ctx.accounts.token_mint.key();
+    let scaling = amount_scaling(&ctx.accounts.token_mint.to_account_info(), ctx.accounts.token_mint.decimals)?;
+    
+    for (entry, accounts) in entries.into_iter().zip(batch_accounts.chunks(3)) {
+        let distributor_info = &accounts[0];
+        let claim_status_info = &accounts[1];
+        let token_vault_info = &accounts[2];
+        
+        // ===== ACCOUNT VALIDATION =====
+        
+        let distributor_loader = AccountLoader::<TokenDistributor>::try_from(distributor_info)?;
+        let distributor_key = distributor_loader.key();
+        {
+            let distributor = distributor_loader.load()?;
+            require!(distributor.token_mint == token_mint_key, TokenDistributorError::TokenMintMismatch);
+            require!(
+                distributor.decimals == ctx.accounts.token_mint.decimals,
+                TokenDistributorError::MintDecimalsMismatch
+            );
+            require!(!distributor.is_shared(), TokenDistributorError::SharedVaultMismatch);
+            require!(
+                token_vault_info.key() == distributor.token_vault,
+                TokenDistributorError::InvalidBatchAccounts
+            );
+        }
+        let token_vault = InterfaceAccount::<TokenAccount>::try_from(token_vault_info)?;
+        
+        // Claim status must be the claimant's PDA for this distributor
+        let (claim_status_key, claim_status_bump) = Pubkey::find_program_address(
+            &[CLAIM_SEED.as_bytes(), distributor_key.as_ref(), claimant_key.as_ref()],
+            ctx.program_id,
+        );
+        require!(
+            claim_status_info.key() == claim_status_key,
+            TokenDistributorError::InvalidBatchAccounts
+        );
+        
+        // Create the claim status on first claim (equivalent of init_if_needed)
+        if claim_status_info.owner == &System::id() {
+            let claim_status_seeds: &[&[u8]] = &[
+                CLAIM_SEED.as_bytes(),
+                distributor_key.as_ref(),
+                claimant_key.as_ref(),
+                &[claim_status_bump],
+            ];
+            create_pda_account(
+                ctx.accounts.claimant.to_account_info(),
+                claim_status_info.clone(),
+                ctx.accounts.system_program.to_account_info(),
+                ClaimStatus::LEN,
+                &[claim_status_seeds],
+            )?;
+            let new_claim_status = ClaimStatus {
+                rent_payer: claimant_key,
+                ..Default::default()
+            };
+            new_claim_status.try_serialize(&mut &mut claim_status_info.try_borrow_mut_data()?[..])?;
+        }
+        let mut claim_status = Account::<ClaimStatus>::try_from(claim_status_info)?;
+        
+        // ===== CLAIM PROCESSING =====
+        
+        // Zero-copy updates are written to the distributor account directly
+        let (pending_amount, phase, new_total_claimed, owner, nonce, bump, completed) = {
+            let distributor = &mut distributor_loader.load_mut()?;
+            // Claims of logged, co-signed or escrowed distributors must go through claim
+            require!(!distributor.has_claim_log(), TokenDistributorError::ClaimLogRequired);
+            require!(!distributor.requires_cosign(), TokenDistributorError::CosignRequired);
+            require!(!distributor.is_escrowed(), TokenDistributorError::ClaimEscrowRequired);
+            // Claims in the early bonus window must go through claim to receive the bonus
+            require!(
+                !distributor.is_bonus_window_open(distributor.activation_now(&Clock::get()?)),
+                TokenDistributorError::EarlyBonusClaimRequired
+            );
+            #[cfg(feature = "claim-gates")]
+            check_claim_gate(
+                distributor,
+                &claimant_key,
+                ctx.accounts.gate_token_account.as_deref(),
+                ctx.accounts.attestation.as_deref(),
+            )?;
+            let (pending_amount, phase) = process_claim(
+                distributor,
+                distributor_key,
+                &mut claim_status,
+                &claimant_key,
+                token_vault.amount,
+                entry.max_amount,
+                entry.proof,
+                None,
+                entry.unlock_time,
+                &[],
+                None,
+            )?;
+            let completed = complete_distribution(distributor, distributor_key, &claimant_key, token_vault.amount, pending_amount);
+            (pending_amount, phase, distributor.total_claimed, distributor.owner, distributor.nonce, distributor.bump, completed)
+        };
+        
+        // Protocol fee is taken out of the claimed amount
+        #[cfg(feature = "protocol-fee")]
+        let protocol_fee = protocol_fee_for(
+            &config,
+            ctx.accounts.protocol_fee_account.as_ref().map(|account| account.owner),
+            pending_amount,
+        )?;
+        #[cfg(not(feature = "protocol-fee"))]
+        let protocol_fee = 0;
+        
+        // Charge the claim fee, if any, to the claimant
+        #[cfg(feature = "claim-fee")]
+        collect_claim_fee(
+            &distributor_loader,
+            ctx.accounts.claimant.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        )?;
+        
+        // Persist claim status updates before the transfer (CEI pattern)
+        claim_status.exit(ctx.program_id)?;
+        
+        // ===== INTERACTIONS PHASE (Token Transfer) =====
+        
+        // Prepare PDA signing seeds for token transfer
+        let nonce_bytes = nonce.to_le_bytes();
+        let seeds = &[
+            DISTRIBUTOR_SEED.as_bytes(),
+            token_mint_key.as_ref(),
+            owner.as_ref(),
+            nonce_bytes.as_ref(),
+            &[bump],
+        ];
+        let signer = &[&seeds[..]];
+        
+        // Destinations with the MemoTransfer extension need a memo right before each transfer
+        memo_if_required(
+            &ctx.accounts.claimant_token_account.to_account_info(),
+            ctx.accounts.memo_program.as_ref().map(|p| p.to_account_info()),
+            CLAIM_MEMO,
+        )?;
+        
+        transfer_token_with_hook(
+            distributor_info.clone(),
+            token_vault_info.clone(),
+            ctx.accounts.claimant_token_account.to_account_info(),
+            ctx.accounts.token_mint.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            pending_amount - protocol_fee,
+            ctx.accounts.token_mint.decimals,
+            Some(signer),  // PDA signing for secure transfer
+            hook_accounts,
+        )?;
+        
+        // Transfer the protocol fee, if any, from the same vault
+        #[cfg(feature = "protocol-fee")]
+        if let Some(protocol_fee_account) = ctx.accounts.protocol_fee_account.as_ref().filter(|_| protocol_fee > 0) {
+            memo_if_required(
+                &protocol_fee_account.to_account_info(),
+                ctx.accounts.memo_program.as_ref().map(|p| p.to_account_info()),
+                CLAIM_MEMO,
+            )?;
+            transfer_token_with_hook(
+                distributor_info.clone(),
+                token_vault_info.clone(),
+                protocol_fee_account.to_account_info(),
+                ctx.accounts.token_mint.to_account_info(),
+                ctx.accounts.token_program.to_account_info(),
+                protocol_fee,
+                ctx.accounts.token_mint.decimals,
+                Some(signer),
+                hook_accounts,
+            )?;
+        }
+        
+        // Emit event for off-chain indexing and monitoring
+        emit_cpi!(TokensClaimed {
+            distributor: distributor_key,
+            claimant: claimant_key,
+            user_amount_claimed: pending_amount,
+            user_max_amount: entry.max_amount,
+            total_claimed: new_total_claimed,
+            protocol_fee,
+            scaling,
+            phase,
+            bonus_amount: 0,
+        });
+        if let Some(completed) = completed {
+            emit_cpi!(completed);
+        }
+    }
+    
+    Ok(())
+}
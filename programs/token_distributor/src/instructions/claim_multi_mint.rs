@@ -0,0 +1,368 @@
+use anchor_lang::prelude::*;
+use anchor_spl::memo::Memo;
+use anchor_spl::token_interface::{TokenInterface, TokenAccount, Mint};
+use crate::state::*;
+use crate::error::*;
+use crate::constants::*;
+use crate::utils::{transfer_token_with_hook, memo_if_required, load_program_config, amount_scaling};
+use crate::event::*;
+use super::{process_claim, pay_claim};
+#[cfg(feature = "claim-fee")]
+use super::collect_claim_fee;
+#[cfg(feature = "protocol-fee")]
+use super::protocol_fee_for;
+#[cfg(feature = "claim-gates")]
+use super::check_claim_gate;
+
+/**
+ * Account context for claiming every mint of a multi-mint distributor
+ *
+ * This instruction lets a recipient of a multi-mint campaign claim all owed
+ * tokens in one transaction with a single merkle proof, instead of claiming
+ * from parallel campaigns with duplicated rent and proofs.
+ *
+ * Remaining accounts hold one group of five accounts per extra mint, in the
+ * order the mints were added:
+ * - distributor_mint: writable distributor mint PDA ["distributor_mint", distributor_key, token_mint]
+ * - token_vault: writable vault of the extra mint
+ * - token_mint: the extra mint
+ * - claimant_token_account: writable token account of the claimant for the extra mint
+ * - token_program: token program of the extra mint
+ *
+ * Extra accounts required by the transfer hooks of the mints, if any, follow the groups.
+ *
+ * Access Control: Any user with a valid merkle proof can claim their tokens
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimMultiMint<'info> {
+    /// The multi-mint distributor account
+    /// - Will be modified to update total_claimed amount
+    #[account(mut)]
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+
+    /// Individual claim status for this claimant
+    /// - Tracks how much of each mint this user has already claimed
+    /// - Derived from: ["claim", distributor_key, claimant_key]
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ClaimStatus::LEN,
+        seeds = [CLAIM_SEED.as_bytes(), distributor.key().as_ref(), claimant.key().as_ref()],
+        bump
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+
+    /// Token vault holding the distributor's own mint
+    /// - Must match the vault stored in the distributor state
+    #[account(
+        mut,
+        address = distributor.load()?.token_vault
+    )]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Claimant's token account for the distributor's own mint
+    /// - Must be owned by the claimant
+    /// - Must not be frozen (ClaimantAccountFrozen)
+    #[account(
+        mut,
+        token::mint = distributor.load()?.token_mint,
+        token::authority = claimant,
+        token::token_program = token_program,
+        constraint = !claimant_token_account.is_frozen() @ TokenDistributorError::ClaimantAccountFrozen,
+    )]
+    pub claimant_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// The distributor's own token mint
+    /// - Must still report the decimals cached at creation
+    #[account(
+        token::token_program = token_program,
+        constraint = token_mint.key() == distributor.load()?.token_mint @ TokenDistributorError::TokenMintMismatch,
+        constraint = token_mint.decimals == distributor.load()?.decimals @ TokenDistributorError::MintDecimalsMismatch
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    /// The claimant attempting to claim tokens
+    pub claimant: Signer<'info>,
+
+    /// Pays the rent if the ClaimStatus account is created, and the claim fee
+    /// - Recorded as rent payer and refunded when the ClaimStatus is closed
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Token program of the distributor's own mint (supports both SPL Token and Token 2022)
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// SPL Memo program
+    /// - Required only when a receiving token account requires incoming transfer memos
+    ///   (Token 2022 MemoTransfer extension)
+    pub memo_program: Option<Program<'info, Memo>>,
+
+    /// Token account receiving the protocol fee on the distributor's own mint
+    /// - Required only while the protocol fee is enabled
+    /// - Must be owned by the protocol fee receiver set in the program config
+    #[cfg(feature = "protocol-fee")]
+    #[account(
+        mut,
+        constraint = protocol_fee_account.mint == token_mint.key() @ TokenDistributorError::TokenMintMismatch
+    )]
+    pub protocol_fee_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Claimant's token account of the distributor's gate mint
+    /// - Required only when the distributor gates claims on token holdings
+    #[cfg(feature = "claim-gates")]
+    pub gate_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Claimant's attestation from the distributor's attestor
+    /// - Required only when the distributor requires an attestation to claim
+    /// - Derived from: ["attestation", attestor, claimant]
+    #[cfg(feature = "claim-gates")]
+    pub attestation: Option<Account<'info, Attestation>>,
+
+    /// Program config holding the global pause switch and default parameters
+    /// CHECK: Derived from ["config"], either uninitialized or a valid ProgramConfig
+    #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+    pub config: UncheckedAccount<'info>,
+}
+
+/// Validated accounts and amounts of one extra mint in a multi-mint claim
+struct ExtraMintPayout<'info> {
+    distributor_mint: Account<'info, DistributorMint>,
+    token_vault: AccountInfo<'info>,
+    token_mint: InterfaceAccount<'info, Mint>,
+    claimant_token_account: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    max_amount: u64,
+    amount: u64,
+}
+
+/**
+ * Claims every mint of a multi-mint distributor with one merkle proof
+ *
+ * @param ctx - The account context, with one account group per extra mint in remaining accounts,
+ *              followed by transfer hook accounts
+ * @param amounts - Amounts from the merkle leaf: the distributor's own mint first,
+ *                  then one per extra mint in the order the mints were added
+ * @param proof - Array of 32-byte hashes forming the merkle proof path
+ *
+ * Validation Rules:
+ * - Validated like a single claim of the distributor's own mint, except that the
+ *   claimant may owe nothing of it as long as some extra mint is owed
+ * - The full pending amount of every mint is claimed
+ * - Per-claimant caps, per-slot limits and the protocol fee apply to the
+ *   distributor's own mint only
+ */
+pub fn handle_claim_multi_mint<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimMultiMint<'info>>,
+    amounts: Vec<u64>,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    // Reject all claims while the program is globally paused
+    let config = load_program_config(&ctx.accounts.config)?;
+    config.require_not_paused()?;
+
+    let (max_amount, extra_amounts) = amounts
+        .split_first()
+        .ok_or(TokenDistributorError::ExtraAmountsMismatch)?;
+    require!(
+        ctx.remaining_accounts.len() >= extra_amounts.len() * 5,
+        TokenDistributorError::InvalidExtraMintAccounts
+    );
+    let (extra_mint_accounts, hook_accounts) = ctx.remaining_accounts.split_at(extra_amounts.len() * 5);
+
+    let distributor_key = ctx.accounts.distributor.key();
+    let claimant_key = ctx.accounts.claimant.key();
+    let vault_balance = ctx.accounts.token_vault.amount;
+
+    // Record the rent payer when the claim status is first created
+    if ctx.accounts.claim_status.rent_payer == Pubkey::default() {
+        ctx.accounts.claim_status.rent_payer = ctx.accounts.payer.key();
+    }
+
+    // Validate the claim, including the extra amounts bound into the leaf
+    let (pending_amount, phase, new_total_claimed) = {
+        let distributor = &mut ctx.accounts.distributor.load_mut()?;
+        // Claims of logged, co-signed or escrowed distributors must go through claim
+        require!(!distributor.has_claim_log(), TokenDistributorError::ClaimLogRequired);
+        require!(!distributor.requires_cosign(), TokenDistributorError::CosignRequired);
+        require!(!distributor.is_escrowed(), TokenDistributorError::ClaimEscrowRequired);
+        require!(distributor.is_multi_mint(), TokenDistributorError::NotMultiMintDistributor);
+        #[cfg(feature = "claim-gates")]
+        check_claim_gate(
+            distributor,
+            &claimant_key,
+            ctx.accounts.gate_token_account.as_deref(),
+            ctx.accounts.attestation.as_deref(),
+        )?;
+        let (pending_amount, phase) = process_claim(
+            distributor,
+            distributor_key,
+            &mut ctx.accounts.claim_status,
+            &claimant_key,
+            vault_balance,
+            *max_amount,
+            proof,
+            None,
+            None,
+            extra_amounts,
+            None,
+        )?;
+        (pending_amount, phase, distributor.total_claimed)
+    };
+
+    // ===== EXTRA MINT VALIDATION AND EFFECTS =====
+
+    let mut payouts = Vec::with_capacity(extra_amounts.len());
+    for (index, (max_amount, accounts)) in extra_amounts.iter().zip(extra_mint_accounts.chunks(5)).enumerate() {
+        let mut distributor_mint = Account::<DistributorMint>::try_from(&accounts[0])?;
+        require!(
+            distributor_mint.distributor == distributor_key
+                && distributor_mint.index as usize == index
+                && accounts[1].key() == distributor_mint.token_vault
+                && accounts[2].key() == distributor_mint.token_mint
+                && accounts[4].key() == *accounts[2].owner,
+            TokenDistributorError::InvalidExtraMintAccounts
+        );
+
+        let token_vault = InterfaceAccount::<TokenAccount>::try_from(&accounts[1])?;
+        let token_mint = InterfaceAccount::<Mint>::try_from(&accounts[2])?;
+        require!(
+            token_mint.decimals == distributor_mint.decimals,
+            TokenDistributorError::MintDecimalsMismatch
+        );
+        let claimant_token_account = InterfaceAccount::<TokenAccount>::try_from(&accounts[3])?;
+        require!(
+            claimant_token_account.mint == distributor_mint.token_mint && claimant_token_account.owner == claimant_key,
+            TokenDistributorError::InvalidExtraMintAccounts
+        );
+        require!(!claimant_token_account.is_frozen(), TokenDistributorError::ClaimantAccountFrozen);
+
+        // Claim the full pending amount of this mint
+        let claimed_amount = ctx.accounts.claim_status.extra_claimed_amounts[index];
+        let amount = max_amount.saturating_sub(claimed_amount);
+        require!(token_vault.amount >= amount, TokenDistributorError::InsufficientVaultBalance);
+
+        ctx.accounts.claim_status.extra_claimed_amounts[index] = claimed_amount + amount;
+        distributor_mint.total_claimed = distributor_mint.total_claimed
+            .checked_add(amount)
+            .ok_or(TokenDistributorError::ArithmeticOverflow)?;
+        distributor_mint.exit(ctx.program_id)?;
+
+        payouts.push(ExtraMintPayout {
+            distributor_mint,
+            token_vault: accounts[1].clone(),
+            token_mint,
+            claimant_token_account: accounts[3].clone(),
+            token_program: accounts[4].clone(),
+            max_amount: *max_amount,
+            amount,
+        });
+    }
+
+    // Something must be owed across all mints
+    require!(
+        pending_amount > 0 || payouts.iter().any(|payout| payout.amount > 0),
+        TokenDistributorError::InvalidAmount
+    );
+
+    // Protocol fee is taken out of the claimed amount of the distributor's own mint
+    #[cfg(feature = "protocol-fee")]
+    let (protocol_fee, protocol_fee_account) = (
+        protocol_fee_for(
+            &config,
+            ctx.accounts.protocol_fee_account.as_ref().map(|account| account.owner),
+            pending_amount,
+        )?,
+        ctx.accounts.protocol_fee_account.as_ref().map(|account| account.to_account_info()),
+    );
+    #[cfg(not(feature = "protocol-fee"))]
+    let (protocol_fee, protocol_fee_account) = (0, None);
+
+    // ===== INTERACTIONS PHASE (Fee and Token Transfers) =====
+
+    #[cfg(feature = "claim-fee")]
+    collect_claim_fee(
+        &ctx.accounts.distributor,
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    if pending_amount > 0 {
+        pay_claim(
+            &ctx.accounts.distributor,
+            None,
+            ctx.accounts.token_vault.to_account_info(),
+            ctx.accounts.claimant_token_account.to_account_info(),
+            &ctx.accounts.token_mint,
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.memo_program.as_ref().map(|p| p.to_account_info()),
+            protocol_fee_account,
+            pending_amount,
+            protocol_fee,
+            hook_accounts,
+        )?;
+
+        emit_cpi!(TokensClaimed {
+            distributor: distributor_key,
+            claimant: claimant_key,
+            user_amount_claimed: pending_amount,
+            user_max_amount: *max_amount,
+            total_claimed: new_total_claimed,
+            protocol_fee,
+            scaling: amount_scaling(&ctx.accounts.token_mint.to_account_info(), ctx.accounts.token_mint.decimals)?,
+            phase,
+            bonus_amount: 0,
+        });
+    }
+
+    // Extra mint vaults are controlled by the distributor PDA as well
+    let (token_mint_key, owner, nonce, bump) = {
+        let distributor = ctx.accounts.distributor.load()?;
+        (distributor.token_mint, distributor.owner, distributor.nonce, distributor.bump)
+    };
+    let nonce_bytes = nonce.to_le_bytes();
+    let seeds = &[
+        DISTRIBUTOR_SEED.as_bytes(),
+        token_mint_key.as_ref(),
+        owner.as_ref(),
+        nonce_bytes.as_ref(),
+        &[bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    for payout in payouts.into_iter().filter(|payout| payout.amount > 0) {
+        // Destinations with the MemoTransfer extension need a memo right before each transfer
+        memo_if_required(
+            &payout.claimant_token_account,
+            ctx.accounts.memo_program.as_ref().map(|p| p.to_account_info()),
+            CLAIM_MEMO,
+        )?;
+
+        transfer_token_with_hook(
+            ctx.accounts.distributor.to_account_info(),
+            payout.token_vault,
+            payout.claimant_token_account,
+            payout.token_mint.to_account_info(),
+            payout.token_program,
+            payout.amount,
+            payout.token_mint.decimals,
+            Some(signer),  // PDA signing for secure transfer
+            hook_accounts,
+        )?;
+
+        emit_cpi!(ExtraMintClaimed {
+            distributor: distributor_key,
+            claimant: claimant_key,
+            token_mint: payout.distributor_mint.token_mint,
+            user_amount_claimed: payout.amount,
+            user_max_amount: payout.max_amount,
+            total_claimed: payout.distributor_mint.total_claimed,
+            scaling: amount_scaling(&payout.token_mint.to_account_info(), payout.token_mint.decimals)?,
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,236 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token::spl_token::native_mint;
+#[cfg(feature = "claim-gates")]
+use anchor_spl::token_interface::TokenAccount;
+use crate::state::*;
+use crate::error::*;
+use crate::constants::*;
+use crate::event::*;
+use crate::utils::load_program_config;
+use super::{process_claim, complete_distribution};
+#[cfg(feature = "claim-fee")]
+use super::collect_claim_fee;
+#[cfg(feature = "protocol-fee")]
+use super::protocol_fee_for;
+#[cfg(feature = "claim-gates")]
+use super::check_claim_gate;
+
+/**
+ * Account context for claiming native SOL
+ * 
+ * This instruction allows eligible users to claim their allocated lamports
+ * from a native SOL distributor by providing a valid merkle proof. The
+ * lamports are sent directly to the claimant's wallet.
+ * 
+ * Access Control: Any user with a valid merkle proof can claim their lamports
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimSol<'info> {
+    /// The native SOL distributor account
+    /// - Will be modified to update total_claimed amount
+    #[account(
+        mut,
+        constraint = distributor.load()?.is_native() @ TokenDistributorError::NotNativeDistributor
+    )]
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+    
+    /// Individual claim status for this claimant
+    /// - Derived from: ["claim", distributor_key, claimant_key]
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ClaimStatus::LEN,
+        seeds = [CLAIM_SEED.as_bytes(), distributor.key().as_ref(), claimant.key().as_ref()],
+        bump
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+    
+    /// Lamport vault holding the SOL to be distributed
+    /// - Derived from: ["sol_vault", distributor_key]
+    #[account(
+        mut,
+        seeds = [SOL_VAULT_SEED.as_bytes(), distributor.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: SystemAccount<'info>,
+    
+    /// The claimant attempting to claim lamports
+    /// - Must sign the transaction
+    /// - Receives the claimed lamports
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+    
+    /// Pays the rent if the ClaimStatus account is created, and the claim fee
+    /// - May be the claimant, or a relayer / the project sponsoring the claim
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// System program for account creation and lamport transfers
+    pub system_program: Program<'info, System>,
+    
+    /// Account receiving the protocol fee
+    /// - Required only while the protocol fee is enabled
+    /// CHECK: Checked against the protocol fee receiver set in the program config
+    #[cfg(feature = "protocol-fee")]
+    #[account(mut)]
+    pub protocol_fee_receiver: Option<UncheckedAccount<'info>>,
+    
+    /// Claimant's token account of the distributor's gate mint
+    /// - Required only when the distributor gates claims on token holdings
+    #[cfg(feature = "claim-gates")]
+    pub gate_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Claimant's attestation from the distributor's attestor
+    /// - Required only when the distributor requires an attestation to claim
+    /// - Derived from: ["attestation", attestor, claimant]
+    #[cfg(feature = "claim-gates")]
+    pub attestation: Option<Account<'info, Attestation>>,
+    
+    /// Program config holding the global pause switch and default parameters
+    /// CHECK: Derived from ["config"], either uninitialized or a valid ProgramConfig
+    #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+    pub config: UncheckedAccount<'info>,
+}
+
+/**
+ * Processes a native SOL claim with merkle proof verification
+ * 
+ * @param ctx - The account context containing all required accounts
+ * @param max_amount - Maximum amount of lamports this user is eligible to claim (from merkle tree)
+ * @param proof - Array of 32-byte hashes forming the merkle proof path
+ * @param amount - Optional amount to claim now (defaults to the full pending amount)
+ * @param unlock_time - The claimant's unlock time from the merkle tree (tree version 2 only)
+ * 
+ * Note: Validation is identical to claim; the vault's rent reserve is never claimable
+ */
+pub fn handle_claim_sol(
+    ctx: Context<ClaimSol>,
+    max_amount: u64,
+    proof: Vec<[u8; 32]>,
+    amount: Option<u64>,
+    unlock_time: Option<i64>,
+) -> Result<()> {
+    // Reject all claims while the program is globally paused
+    let config = load_program_config(&ctx.accounts.config)?;
+    config.require_not_paused()?;
+    
+    let distributor_key = ctx.accounts.distributor.key();
+    let claimant_key = ctx.accounts.claimant.key();
+    
+    // Record the rent payer when the claim status is first created
+    if ctx.accounts.claim_status.rent_payer == Pubkey::default() {
+        ctx.accounts.claim_status.rent_payer = ctx.accounts.payer.key();
+    }
+    
+    // Only lamports above the vault's rent reserve are distributable
+    let vault_balance = ctx.accounts.sol_vault.lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(0));
+    
+    // Validate the claim and update claim status and distributor totals
+    let (pending_amount, phase, new_total_claimed, completed) = {
+        let distributor = &mut ctx.accounts.distributor.load_mut()?;
+        // Claims of logged, co-signed or escrowed distributors must go through claim
+        require!(!distributor.has_claim_log(), TokenDistributorError::ClaimLogRequired);
+        require!(!distributor.requires_cosign(), TokenDistributorError::CosignRequired);
+        require!(!distributor.is_escrowed(), TokenDistributorError::ClaimEscrowRequired);
+        #[cfg(feature = "claim-gates")]
+        check_claim_gate(
+            distributor,
+            &claimant_key,
+            ctx.accounts.gate_token_account.as_deref(),
+            ctx.accounts.attestation.as_deref(),
+        )?;
+        let (pending_amount, phase) = process_claim(
+            distributor,
+            distributor_key,
+            &mut ctx.accounts.claim_status,
+            &claimant_key,
+            vault_balance,
+            max_amount,
+            proof,
+            amount,
+            unlock_time,
+            &[],
+            None,
+        )?;
+        let completed = complete_distribution(distributor, distributor_key, &claimant_key, vault_balance, pending_amount);
+        (pending_amount, phase, distributor.total_claimed, completed)
+    };
+    
+    // Protocol fee is taken out of the claimed amount
+    #[cfg(feature = "protocol-fee")]
+    let protocol_fee = protocol_fee_for(
+        &config,
+        ctx.accounts.protocol_fee_receiver.as_ref().map(|receiver| receiver.key()),
+        pending_amount,
+    )?;
+    #[cfg(not(feature = "protocol-fee"))]
+    let protocol_fee = 0;
+    
+    // ===== INTERACTIONS PHASE (Fee and Lamport Transfer) =====
+    
+    #[cfg(feature = "claim-fee")]
+    collect_claim_fee(
+        &ctx.accounts.distributor,
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+    
+    // Prepare PDA signing seeds for the lamport transfer
+    let seeds: &[&[u8]] = &[
+        SOL_VAULT_SEED.as_bytes(),
+        distributor_key.as_ref(),
+        &[ctx.bumps.sol_vault],
+    ];
+    
+    transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.sol_vault.to_account_info(),
+                to: ctx.accounts.claimant.to_account_info(),
+            },
+            &[seeds],
+        ),
+        pending_amount - protocol_fee,
+    )?;
+    
+    // Transfer the protocol fee, if any, from the same vault
+    #[cfg(feature = "protocol-fee")]
+    if let Some(protocol_fee_receiver) = ctx.accounts.protocol_fee_receiver.as_ref().filter(|_| protocol_fee > 0) {
+        transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.sol_vault.to_account_info(),
+                    to: protocol_fee_receiver.to_account_info(),
+                },
+                &[seeds],
+            ),
+            protocol_fee,
+        )?;
+    }
+    
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(TokensClaimed {
+        distributor: distributor_key,
+        claimant: claimant_key,
+        user_amount_claimed: pending_amount,
+        user_max_amount: max_amount,
+        total_claimed: new_total_claimed,
+        protocol_fee,
+        scaling: AmountScaling {
+            decimals: native_mint::DECIMALS,
+            ..Default::default()
+        },
+        phase,
+        bonus_amount: 0,
+    });
+    if let Some(completed) = completed {
+        emit_cpi!(completed);
+    }
+    
+    Ok(())
+}
@@ -0,0 +1,258 @@
+use anchor_lang::prelude::*;
+use anchor_spl::memo::Memo;
+use anchor_spl::token_interface::{TokenInterface, TokenAccount, Mint};
+use crate::state::*;
+use crate::error::*;
+use crate::constants::*;
+use crate::utils::{amount_scaling, load_program_config};
+use crate::event::*;
+use super::claim::{pay_claim, process_claim, complete_distribution};
+#[cfg(feature = "claim-gates")]
+use super::claim::check_claim_gate;
+#[cfg(feature = "claim-fee")]
+use super::claim::collect_claim_fee;
+#[cfg(feature = "protocol-fee")]
+use super::claim::protocol_fee_for;
+
+/**
+ * Account context for claiming tokens to a leaf-specified recipient
+ *
+ * This instruction allows claimants of tree version 4 distributors to claim
+ * their allocation to the recipient bound into their merkle leaf, such as a
+ * vesting contract or DAO sub-account, instead of their own wallet.
+ *
+ * Access Control: Any claimant with a valid merkle proof
+ *
+ * Business Logic:
+ * - The claimant signs and is tracked by the claim status, as for claim
+ * - Tokens are sent to a token account owned by the recipient in the leaf
+ * - Claim gates and attestations apply to the claimant, not the recipient
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimToRecipient<'info> {
+    /// The distributor account containing distribution parameters
+    /// - Must use leaf-specified recipients (tree version 4)
+    /// - Will be modified to update total_claimed amount
+    #[account(mut)]
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+
+    /// Individual claim status for this claimant
+    /// - Tracks how much this user has already claimed
+    /// - Derived from: ["claim", distributor_key, claimant_key]
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ClaimStatus::LEN,
+        seeds = [CLAIM_SEED.as_bytes(), distributor.key().as_ref(), claimant.key().as_ref()],
+        bump
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+
+    /// Token vault holding the tokens to be distributed
+    /// - Must match the vault stored in the distributor state
+    #[account(
+        mut,
+        address = distributor.load()?.token_vault
+    )]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Recipient of the claim
+    /// CHECK: Verified against the merkle leaf by the proof
+    pub recipient: UncheckedAccount<'info>,
+
+    /// Recipient's token account to receive the tokens
+    /// - Must be owned by the recipient
+    /// - Must be for the correct token mint
+    /// - Must not be frozen (ClaimantAccountFrozen)
+    #[account(
+        mut,
+        token::mint = distributor.load()?.token_mint,
+        token::authority = recipient,
+        token::token_program = token_program,
+        constraint = !recipient_token_account.is_frozen() @ TokenDistributorError::ClaimantAccountFrozen,
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// The token mint for verification
+    /// - Must match the distributor's token mint
+    /// - Must still report the decimals cached at creation
+    #[account(
+        token::token_program = token_program,
+        constraint = token_mint.key() == distributor.load()?.token_mint @ TokenDistributorError::TokenMintMismatch,
+        constraint = token_mint.decimals == distributor.load()?.decimals @ TokenDistributorError::MintDecimalsMismatch
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    /// The claimant authorizing the claim
+    /// - Must sign the transaction
+    /// - Must have a valid merkle proof for the claim
+    pub claimant: Signer<'info>,
+
+    /// Pays the rent if the ClaimStatus account is created, and the claim fee
+    /// - May be the claimant, or a relayer / the project sponsoring the claim
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Token program (supports both SPL Token and Token 2022)
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// SPL Memo program
+    /// - Required only when the recipient token account requires incoming transfer memos
+    pub memo_program: Option<Program<'info, Memo>>,
+
+    /// Token account receiving the protocol fee
+    /// - Required only while the protocol fee is enabled
+    #[cfg(feature = "protocol-fee")]
+    #[account(
+        mut,
+        constraint = protocol_fee_account.mint == token_mint.key() @ TokenDistributorError::TokenMintMismatch
+    )]
+    pub protocol_fee_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Claimant's token account of the distributor's gate mint
+    /// - Required only when the distributor gates claims on token holdings
+    #[cfg(feature = "claim-gates")]
+    pub gate_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Claimant's attestation from the distributor's attestor
+    /// - Required only when the distributor requires an attestation to claim
+    #[cfg(feature = "claim-gates")]
+    pub attestation: Option<Account<'info, Attestation>>,
+
+    /// Program config holding the global pause switch and default parameters
+    /// CHECK: Derived from ["config"], either uninitialized or a valid ProgramConfig
+    #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+    pub config: UncheckedAccount<'info>,
+}
+
+/**
+ * Processes a claim paid to the recipient specified in the merkle leaf
+ *
+ * @param ctx - The account context, with transfer hook accounts in remaining accounts
+ * @param max_amount - Maximum amount this user is eligible to claim (from merkle tree)
+ * @param proof - Array of 32-byte hashes forming the merkle proof path
+ * @param amount - Optional amount to claim now (defaults to the full pending amount)
+ *
+ * Validation Process:
+ * 1. Verify the distributor uses leaf-specified recipients
+ * 2. Validate the claim as for claim, with the merkle proof for
+ *    (claimant, recipient, max_amount)
+ * 3. Transfer the claimed amount, less the protocol fee, to the recipient
+ */
+pub fn handle_claim_to_recipient<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimToRecipient<'info>>,
+    max_amount: u64,
+    proof: Vec<[u8; 32]>,
+    amount: Option<u64>,
+) -> Result<()> {
+    // Reject all claims while the program is globally paused
+    let config = load_program_config(&ctx.accounts.config)?;
+    config.require_not_paused()?;
+
+    let distributor_key = ctx.accounts.distributor.key();
+    let claimant_key = ctx.accounts.claimant.key();
+    let recipient_key = ctx.accounts.recipient.key();
+    let vault_balance = ctx.accounts.token_vault.amount;
+
+    // Record the rent payer when the claim status is first created
+    if ctx.accounts.claim_status.rent_payer == Pubkey::default() {
+        ctx.accounts.claim_status.rent_payer = ctx.accounts.payer.key();
+    }
+
+    // Validate the claim and update claim status and distributor totals
+    let (pending_amount, phase, new_total_claimed, completed) = {
+        let distributor = &mut ctx.accounts.distributor.load_mut()?;
+        // Claims of logged, co-signed or escrowed distributors must go through claim
+        require!(!distributor.has_claim_log(), TokenDistributorError::ClaimLogRequired);
+        require!(!distributor.requires_cosign(), TokenDistributorError::CosignRequired);
+        require!(!distributor.is_escrowed(), TokenDistributorError::ClaimEscrowRequired);
+        require!(distributor.has_recipients(), TokenDistributorError::NotRecipientDistributor);
+        #[cfg(feature = "claim-gates")]
+        check_claim_gate(
+            distributor,
+            &claimant_key,
+            ctx.accounts.gate_token_account.as_deref(),
+            ctx.accounts.attestation.as_deref(),
+        )?;
+        let (pending_amount, phase) = process_claim(
+            distributor,
+            distributor_key,
+            &mut ctx.accounts.claim_status,
+            &claimant_key,
+            vault_balance,
+            max_amount,
+            proof,
+            amount,
+            None,
+            &[],
+            Some(&recipient_key),
+        )?;
+        let completed = complete_distribution(distributor, distributor_key, &claimant_key, vault_balance, pending_amount);
+        (pending_amount, phase, distributor.total_claimed, completed)
+    };
+
+    // Protocol fee is taken out of the claimed amount
+    #[cfg(feature = "protocol-fee")]
+    let (protocol_fee, protocol_fee_account) = (
+        protocol_fee_for(
+            &config,
+            ctx.accounts.protocol_fee_account.as_ref().map(|account| account.owner),
+            pending_amount,
+        )?,
+        ctx.accounts.protocol_fee_account.as_ref().map(|account| account.to_account_info()),
+    );
+    #[cfg(not(feature = "protocol-fee"))]
+    let (protocol_fee, protocol_fee_account) = (0, None);
+
+    // ===== INTERACTIONS PHASE (Fee and Token Transfer) =====
+
+    #[cfg(feature = "claim-fee")]
+    collect_claim_fee(
+        &ctx.accounts.distributor,
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    pay_claim(
+        &ctx.accounts.distributor,
+        None,
+        ctx.accounts.token_vault.to_account_info(),
+        ctx.accounts.recipient_token_account.to_account_info(),
+        &ctx.accounts.token_mint,
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.memo_program.as_ref().map(|p| p.to_account_info()),
+        protocol_fee_account,
+        pending_amount,
+        protocol_fee,
+        ctx.remaining_accounts,
+    )?;
+
+    // Emit events for off-chain indexing and monitoring
+    let scaling = amount_scaling(&ctx.accounts.token_mint.to_account_info(), ctx.accounts.token_mint.decimals)?;
+    emit_cpi!(TokensClaimed {
+        distributor: distributor_key,
+        claimant: claimant_key,
+        user_amount_claimed: pending_amount,
+        user_max_amount: max_amount,
+        total_claimed: new_total_claimed,
+        protocol_fee,
+        scaling,
+        phase,
+        bonus_amount: 0,
+    });
+    emit_cpi!(ClaimRedirected {
+        distributor: distributor_key,
+        claimant: claimant_key,
+        recipient: recipient_key,
+        amount: pending_amount - protocol_fee,
+    });
+    if let Some(completed) = completed {
+        emit_cpi!(completed);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,289 @@
+use anchor_lang::prelude::*;
+use anchor_spl::memo::Memo;
+use anchor_lang::solana_program::sysvar;
+use anchor_spl::token_interface::{TokenInterface, TokenAccount, Mint};
+use crate::state::*;
+use crate::error::*;
+use crate::constants::*;
+use crate::utils::{claim_message, verify_ed25519_signature, load_program_config, amount_scaling};
+use crate::event::*;
+use super::{process_claim, complete_distribution, pay_claim};
+#[cfg(feature = "claim-fee")]
+use super::collect_claim_fee;
+#[cfg(feature = "protocol-fee")]
+use super::protocol_fee_for;
+#[cfg(feature = "claim-gates")]
+use super::check_claim_gate;
+
+/**
+ * Account context for claiming tokens on behalf of a claimant
+ * 
+ * This instruction lets a relayer submit and pay for a claim. Instead of
+ * signing the transaction, the claimant authorizes the claim with an
+ * off-chain ed25519 signature, verified by an ed25519 program instruction
+ * placed immediately before this one.
+ * 
+ * Access Control: Anyone holding a valid claimant signature and merkle proof,
+ * restricted to the distributor's listed relayers once any relayer is listed
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimWithSignature<'info> {
+    /// The distributor account containing distribution parameters
+    /// - Will be modified to update total_claimed amount
+    #[account(mut)]
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+    
+    /// Individual claim status for this claimant
+    /// - Derived from: ["claim", distributor_key, claimant_key]
+    /// - Rent paid by the relayer if created
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ClaimStatus::LEN,
+        seeds = [CLAIM_SEED.as_bytes(), distributor.key().as_ref(), claimant.key().as_ref()],
+        bump
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+    
+    /// Token vault holding the tokens to be distributed
+    /// - Must match the vault stored in the distributor state
+    #[account(
+        mut,
+        address = distributor.load()?.token_vault
+    )]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Shared vault the distributor draws from
+    /// - Required only for shared distributors
+    #[account(
+        mut,
+        constraint = shared_vault.key() == distributor.load()?.shared_vault @ TokenDistributorError::SharedVaultMismatch
+    )]
+    pub shared_vault: Option<Account<'info, SharedVault>>,
+    
+    /// Claimant's token account to receive the tokens
+    /// - Must be owned by the claimant
+    /// - Must not be frozen (ClaimantAccountFrozen)
+    #[account(
+        mut,
+        token::mint = distributor.load()?.token_mint,
+        token::authority = claimant,
+        token::token_program = token_program,
+        constraint = !claimant_token_account.is_frozen() @ TokenDistributorError::ClaimantAccountFrozen,
+    )]
+    pub claimant_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// The token mint for verification
+    /// - Must still report the decimals cached at creation
+    #[account(
+        token::token_program = token_program,
+        constraint = token_mint.key() == distributor.load()?.token_mint @ TokenDistributorError::TokenMintMismatch,
+        constraint = token_mint.decimals == distributor.load()?.decimals @ TokenDistributorError::MintDecimalsMismatch
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    
+    /// The claimant the tokens are claimed for
+    /// CHECK: Authorized through the ed25519 signature verified in the handler
+    pub claimant: UncheckedAccount<'info>,
+    
+    /// Relayer submitting the transaction
+    /// - Pays the rent if the ClaimStatus account is created, and the claim fee
+    /// - Must be on the distributor's relayer allowlist, if any relayer is listed
+    #[account(
+        mut,
+        constraint = distributor.load()?.is_allowed_relayer(&payer.key()) @ TokenDistributorError::RelayerNotAllowed
+    )]
+    pub payer: Signer<'info>,
+    
+    /// Instructions sysvar used to inspect the ed25519 signature instruction
+    /// CHECK: Address is checked against the instructions sysvar id
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+    
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+    
+    /// Token program (supports both SPL Token and Token 2022)
+    pub token_program: Interface<'info, TokenInterface>,    
+    /// SPL Memo program
+    /// - Required only when the claimant token account requires incoming transfer memos
+    ///   (Token 2022 MemoTransfer extension)
+    pub memo_program: Option<Program<'info, Memo>>,
+    
+    /// Token account receiving the protocol fee
+    /// - Required only while the protocol fee is enabled
+    /// - Must be owned by the protocol fee receiver set in the program config
+    #[cfg(feature = "protocol-fee")]
+    #[account(
+        mut,
+        constraint = protocol_fee_account.mint == token_mint.key() @ TokenDistributorError::TokenMintMismatch
+    )]
+    pub protocol_fee_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Claimant's token account of the distributor's gate mint
+    /// - Required only when the distributor gates claims on token holdings
+    #[cfg(feature = "claim-gates")]
+    pub gate_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Claimant's attestation from the distributor's attestor
+    /// - Required only when the distributor requires an attestation to claim
+    /// - Derived from: ["attestation", attestor, claimant]
+    #[cfg(feature = "claim-gates")]
+    pub attestation: Option<Account<'info, Attestation>>,
+    
+    /// Program config holding the global pause switch and default parameters
+    /// CHECK: Derived from ["config"], either uninitialized or a valid ProgramConfig
+    #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+    pub config: UncheckedAccount<'info>,
+}
+
+/**
+ * Processes a token claim authorized by an off-chain claimant signature
+ * 
+ * @param ctx - The account context, with transfer hook accounts in remaining accounts
+ * @param max_amount - Maximum amount this user is eligible to claim (from merkle tree)
+ * @param proof - Array of 32-byte hashes forming the merkle proof path
+ * @param amount - Optional amount to claim now (defaults to the full pending amount)
+ * @param unlock_time - The claimant's unlock time from the merkle tree (tree version 2 only)
+ * @param expiry - Unix timestamp after which the voucher is no longer valid
+ * 
+ * Validation Process:
+ * 1. Check the voucher has not expired
+ * 2. Verify the preceding ed25519 instruction checked the claimant's signature over
+ *    claim_message(distributor, claimant, max_amount, amount or 0, voucher_nonce, expiry)
+ * 3. Consume the voucher by incrementing the claimant's voucher nonce
+ * 4. Validate and pay out the claim exactly like claim
+ */
+pub fn handle_claim_with_signature<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimWithSignature<'info>>,
+    max_amount: u64,
+    proof: Vec<[u8; 32]>,
+    amount: Option<u64>,
+    unlock_time: Option<i64>,
+    expiry: i64,
+) -> Result<()> {
+    // Reject all claims while the program is globally paused
+    let config = load_program_config(&ctx.accounts.config)?;
+    config.require_not_paused()?;
+    
+    let distributor_key = ctx.accounts.distributor.key();
+    let claimant_key = ctx.accounts.claimant.key();
+    
+    // ===== SIGNATURE AUTHORIZATION =====
+    
+    // Reject vouchers past their expiry
+    require!(Clock::get()?.unix_timestamp <= expiry, TokenDistributorError::VoucherExpired);
+    
+    let voucher_nonce = ctx.accounts.claim_status.voucher_nonce;
+    let message = claim_message(
+        &distributor_key,
+        &claimant_key,
+        max_amount,
+        amount.unwrap_or(0),
+        voucher_nonce,
+        expiry,
+    );
+    verify_ed25519_signature(&ctx.accounts.instructions_sysvar, &claimant_key, &message)?;
+    
+    // Consume the voucher so it cannot be replayed
+    ctx.accounts.claim_status.voucher_nonce = voucher_nonce
+        .checked_add(1)
+        .ok_or(TokenDistributorError::ArithmeticOverflow)?;
+    
+    // Record the rent payer when the claim status is first created
+    if ctx.accounts.claim_status.rent_payer == Pubkey::default() {
+        ctx.accounts.claim_status.rent_payer = ctx.accounts.payer.key();
+    }
+    
+    // Validate the claim and update claim status and distributor totals
+    let vault_balance = ctx.accounts.token_vault.amount;
+    let (pending_amount, phase, new_total_claimed, completed) = {
+        let distributor = &mut ctx.accounts.distributor.load_mut()?;
+        // Claims of logged, co-signed or escrowed distributors must go through claim
+        require!(!distributor.has_claim_log(), TokenDistributorError::ClaimLogRequired);
+        require!(!distributor.requires_cosign(), TokenDistributorError::CosignRequired);
+        require!(!distributor.is_escrowed(), TokenDistributorError::ClaimEscrowRequired);
+        // Claims in the early bonus window must go through claim to receive the bonus
+        require!(
+            !distributor.is_bonus_window_open(distributor.activation_now(&Clock::get()?)),
+            TokenDistributorError::EarlyBonusClaimRequired
+        );
+        #[cfg(feature = "claim-gates")]
+        check_claim_gate(
+            distributor,
+            &claimant_key,
+            ctx.accounts.gate_token_account.as_deref(),
+            ctx.accounts.attestation.as_deref(),
+        )?;
+        let (pending_amount, phase) = process_claim(
+            distributor,
+            distributor_key,
+            &mut ctx.accounts.claim_status,
+            &claimant_key,
+            vault_balance,
+            max_amount,
+            proof,
+            amount,
+            unlock_time,
+            &[],
+            None,
+        )?;
+        let completed = complete_distribution(distributor, distributor_key, &claimant_key, vault_balance, pending_amount);
+        (pending_amount, phase, distributor.total_claimed, completed)
+    };
+    
+    // Protocol fee is taken out of the claimed amount
+    #[cfg(feature = "protocol-fee")]
+    let (protocol_fee, protocol_fee_account) = (
+        protocol_fee_for(
+            &config,
+            ctx.accounts.protocol_fee_account.as_ref().map(|account| account.owner),
+            pending_amount,
+        )?,
+        ctx.accounts.protocol_fee_account.as_ref().map(|account| account.to_account_info()),
+    );
+    #[cfg(not(feature = "protocol-fee"))]
+    let (protocol_fee, protocol_fee_account) = (0, None);
+    
+    // ===== INTERACTIONS PHASE (Fee and Token Transfer) =====
+    
+    #[cfg(feature = "claim-fee")]
+    collect_claim_fee(
+        &ctx.accounts.distributor,
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+    
+    pay_claim(
+        &ctx.accounts.distributor,
+        ctx.accounts.shared_vault.as_mut(),
+        ctx.accounts.token_vault.to_account_info(),
+        ctx.accounts.claimant_token_account.to_account_info(),
+        &ctx.accounts.token_mint,
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.memo_program.as_ref().map(|p| p.to_account_info()),
+        protocol_fee_account,
+        pending_amount,
+        protocol_fee,
+        ctx.remaining_accounts,
+    )?;
+    
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(TokensClaimed {
+        distributor: distributor_key,
+        claimant: claimant_key,
+        user_amount_claimed: pending_amount,
+        user_max_amount: max_amount,
+        total_claimed: new_total_claimed,
+        protocol_fee,
+        scaling: amount_scaling(&ctx.accounts.token_mint.to_account_info(), ctx.accounts.token_mint.decimals)?,
+        phase,
+        bonus_amount: 0,
+    });
+    if let Some(completed) = completed {
+        emit_cpi!(completed);
+    }
+    
+    Ok(())
+}
@@ -0,0 +1,232 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenInterface, TokenAccount};
+use crate::state::*;
+use crate::error::*;
+use crate::constants::*;
+use crate::utils::{transfer_token_with_hook, close_token_account_with_pda, harvest_withheld_fees};
+use crate::event::*;
+
+/**
+ * Account context for clawing back unclaimed tokens
+ * 
+ * This instruction transfers all unclaimed tokens to the clawback receiver
+ * configured at creation (e.g. a DAO treasury) instead of returning them to
+ * the owner wallet.
+ * 
+ * Access Control: Permissionless, only for scheduled distributions after
+ * end_time + withdraw_delay + CLAWBACK_GRACE_PERIOD
+ * 
+ * Business Logic:
+ * - Transfers all remaining tokens from the vault to the clawback receiver
+ * - Harvests withheld Token 2022 transfer fees to the mint so the vault can be closed
+ * - Closes the token vault account, returning rent to the owner
+ * - Closes the distributor account, returning rent to the owner
+ * - Removes the distributor from its mint registry when given
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClawbackVault<'info> {
+    /// The distributor account to claw back from and close
+    /// - Must be a valid existing distributor PDA
+    /// - Will be closed and rent returned to owner
+    #[account(
+        mut,
+        close = owner
+    )]
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+    
+    /// Token vault containing the unclaimed tokens
+    /// - Controlled by the distributor PDA
+    /// - Derived from: ["vault", distributor_key]
+    /// - Will be emptied and closed
+    #[account(
+        mut,
+        seeds = [VAULT_SEED.as_bytes(), distributor.key().as_ref()],
+        bump
+    )]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Clawback receiver's token account to receive the unclaimed tokens
+    /// - Must be owned by the configured clawback receiver
+    /// - Must be for the correct token mint
+    #[account(
+        mut,
+        token::mint = distributor.load()?.token_mint,
+        token::authority = distributor.load()?.clawback_receiver,
+        token::token_program = token_program,
+    )]
+    pub clawback_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// The token mint for verification
+    /// - Must match the distributor's token mint
+    /// - Used for transfer_checked validation
+    /// - Writable to receive transfer fees withheld in the vault (Token 2022)
+    #[account(
+        mut,
+        token::token_program = token_program,
+        constraint = token_mint.key() == distributor.load()?.token_mint @ TokenDistributorError::TokenMintMismatch
+    )]
+    pub token_mint: InterfaceAccount<'info, anchor_spl::token_interface::Mint>,
+    
+    /// The owner of the distributor
+    /// - Must match the owner stored in the distributor state
+    /// - Receives the reclaimed rent
+    #[account(
+        mut,
+        constraint = owner.key() == distributor.load()?.owner @ TokenDistributorError::OnlyOwner
+    )]
+    pub owner: SystemAccount<'info>,
+    
+    /// Nonce state account tracking the owner's active distributors
+    /// - Derived from: ["owner_nonce", owner]
+    /// - Active distributor count is decremented as the distributor closes
+    #[account(
+        mut,
+        seeds = [OWNER_NONCE_SEED.as_bytes(), owner.key().as_ref()],
+        bump
+    )]
+    pub owner_nonce: Account<'info, NonceState>,
+    
+    /// Token program (supports both SPL Token and Token 2022)
+    pub token_program: Interface<'info, TokenInterface>,
+    
+    /// Registry of the distributor's token mint
+    /// - Optional, the distributor's entry is removed from it when given
+    /// - Derived from: ["registry", token_mint]
+    #[account(
+        mut,
+        seeds = [REGISTRY_SEED.as_bytes(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub registry: Option<Account<'info, MintRegistry>>,
+}
+
+/**
+ * Claws back unclaimed tokens to the configured receiver
+ * 
+ * @param ctx - The account context, with transfer hook accounts in remaining accounts
+ * 
+ * Validation Rules:
+ * - A clawback receiver must be configured
+ * - The distribution must be scheduled (start and end time set)
+ * - Current time must be past end_time + withdraw_delay + CLAWBACK_GRACE_PERIOD
+ * - Every extra mint of a multi-mint distributor must have been withdrawn
+ */
+pub fn handle_clawback<'info>(ctx: Context<'_, '_, 'info, 'info, ClawbackVault<'info>>) -> Result<()> {
+    let distributor_key = ctx.accounts.distributor.key();
+    
+    // ===== VALIDATION PHASE =====
+    
+    // Copy the signing data out so the account is not borrowed during the transfers
+    let (token_mint_key, owner, nonce, bump, clawback_receiver) = {
+        let distributor = ctx.accounts.distributor.load()?;
+        
+        require!(
+            distributor.clawback_receiver != Pubkey::default(),
+            TokenDistributorError::NoClawbackReceiver
+        );
+        
+        // An unscheduled distribution has no end yet, its vault is still the owner's to withdraw
+        require!(
+            distributor.start_time > 0 && distributor.end_time > 0,
+            TokenDistributorError::StartTimeNotSet
+        );
+        
+        // Ensure the grace period after the distribution and withdraw delay has elapsed
+        let current_time = distributor.activation_now(&Clock::get()?);
+        let clawback_time = distributor.withdraw_unlocks_at()
+            .checked_add(distributor.activation_type().period(CLAWBACK_GRACE_PERIOD))
+            .ok_or(TokenDistributorError::ArithmeticOverflow)?;
+        require!(current_time > clawback_time, TokenDistributorError::ClawbackGracePeriodActive);
+        
+        // Extra mint vaults of multi-mint distributors are controlled by the distributor PDA
+        require!(distributor.open_extra_mints == 0, TokenDistributorError::ExtraMintsOpen);
+        
+        (
+            distributor.token_mint,
+            distributor.owner,
+            distributor.nonce,
+            distributor.bump,
+            distributor.clawback_receiver,
+        )
+    };
+    
+    let remaining_balance = ctx.accounts.token_vault.amount;
+    
+    // ===== INTERACTIONS PHASE (Token Transfer and Cleanup) =====
+    
+    // Prepare PDA signing seeds for token operations
+    let nonce_bytes = nonce.to_le_bytes();
+    let seeds = &[
+        DISTRIBUTOR_SEED.as_bytes(),
+        token_mint_key.as_ref(),
+        owner.as_ref(),
+        nonce_bytes.as_ref(),
+        &[bump],
+    ];
+    let signer = &[&seeds[..]];
+    
+    // Transfer remaining tokens only if there are any
+    if remaining_balance > 0 {
+        transfer_token_with_hook(
+            ctx.accounts.distributor.to_account_info(),
+            ctx.accounts.token_vault.to_account_info(),
+            ctx.accounts.clawback_token_account.to_account_info(),
+            ctx.accounts.token_mint.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            remaining_balance,
+            ctx.accounts.token_mint.decimals,
+            Some(signer),  // PDA signing for secure transfer
+            ctx.remaining_accounts,
+        )?;
+    }
+    
+    // Move withheld transfer fees to the mint, Token 2022 will not close a vault holding them
+    let withheld_fees = harvest_withheld_fees(
+        ctx.accounts.token_vault.to_account_info(),
+        ctx.accounts.token_mint.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+    )?;
+    if withheld_fees > 0 {
+        emit_cpi!(WithheldFeesHarvested {
+            distributor: distributor_key,
+            token_vault: ctx.accounts.token_vault.key(),
+            token_mint: token_mint_key,
+            amount: withheld_fees,
+        });
+    }
+    
+    // Close the token vault account and return rent to the owner
+    close_token_account_with_pda(
+        ctx.accounts.token_vault.to_account_info(),
+        ctx.accounts.owner.to_account_info(),
+        ctx.accounts.distributor.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        signer,  // PDA signing for secure closure
+    )?;
+    
+    // The distributor no longer counts against the owner's active distributor limit
+    ctx.accounts.owner_nonce.close_distributor();
+    
+    // Closed distributors leave their mint registry
+    if let Some(registry) = ctx.accounts.registry.as_mut() {
+        if registry.remove(&distributor_key) {
+            emit_cpi!(RegistryEntryRemoved {
+                registry: registry.key(),
+                distributor: distributor_key,
+                token_mint: ctx.accounts.token_mint.key(),
+            });
+        }
+    }
+    
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(Clawback {
+        distributor: distributor_key,
+        clawback_receiver,
+        amount: remaining_balance,
+    });
+    
+    // Note: The distributor account will be automatically closed due to the
+    // close = owner constraint in the account definition, returning rent to owner
+    Ok(())
+}
@@ -8,7 +8,8 @@ use crate::constants::*;
  * Account context for closing claim status accounts
  * 
  * This instruction allows users to close their ClaimStatus accounts after
- * the distribution has ended to reclaim the rent paid during account creation.
+ * the distribution has ended, returning the rent to whoever paid it during
+ * account creation.
  * 
  * Access Control: Only the original claimant can close their ClaimStatus account
  * 
@@ -16,41 +17,85 @@ use crate::constants::*;
 #[event_cpi]
 #[derive(Accounts)]
 pub struct CloseClaimStatus<'info> {
-    /// ClaimStatus account to be closed, rent returned to claimant
+    /// ClaimStatus account to be closed, rent returned to the rent payer
     /// - Must be a valid existing ClaimStatus account
     /// - Derived from: ["claim", distributor_key, claimant_key]
-    /// - Will be closed and rent returned to claimant
+    /// - Will be closed and rent returned to the original rent payer
     #[account(
         mut,
-        close = claimant,
+        close = rent_payer,
         seeds = [CLAIM_SEED.as_bytes(), distributor_key.key().as_ref(), claimant.key().as_ref()],
         bump 
     )]
     pub claim_status: Account<'info, ClaimStatus>,
     
-    /// The claimant who originally created the ClaimStatus account
-    /// - Must be the same claimant who paid for the account creation
-    /// - Will receive the reclaimed rent
+    /// The claimant the ClaimStatus account belongs to
     #[account(mut)]
     pub claimant: Signer<'info>,
     
+    /// The account that paid the rent for the ClaimStatus account
+    /// - Must match the rent payer recorded in the claim status
+    /// - Will receive the reclaimed rent
+    #[account(
+        mut,
+        constraint = rent_payer.key() == claim_status.rent_payer @ TokenDistributorError::RentPayerMismatch
+    )]
+    pub rent_payer: SystemAccount<'info>,
+    
     /// Distributor account used for PDA derivation and time validation
     /// CHECK: Either closed or valid TokenDistributor
     pub distributor_key: AccountInfo<'info>,
 }
 
 /**
- * Closes a ClaimStatus account and returns rent to the claimant
+ * Closes a ClaimStatus account and returns rent to the original rent payer
  *
  * @param ctx - The account context containing the ClaimStatus and claimant accounts
  * 
  * Validation Process:
- * 1. Check that distribution has ended using stored end_time
+ * 1. Check that distribution has ended using stored end_time (or the distributor is closed),
+ *    a distribution whose time is not set has not ended
  * 2. Anchor automatically transfers lamports and closes account
  */
 pub fn handle_close_claim_status(ctx: Context<CloseClaimStatus>) -> Result<()> {
-    let distributor_key = &ctx.accounts.distributor_key;
+    require_distribution_ended(&ctx.accounts.distributor_key)?;
+    
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(ClaimStatusClosed {
+        distributor: ctx.accounts.distributor_key.key(),
+        claimant: ctx.accounts.claimant.key(),
+        claimed_amount: ctx.accounts.claim_status.claimed_amount,
+        rent_payer: ctx.accounts.rent_payer.key(),
+    });
     
+    Ok(())
+}
+
+/**
+ * Checks that a distribution has ended, or that its distributor has been closed
+ * 
+ * A distribution whose time has not been set has not ended.
+ * 
+ * @param distributor_key - Distributor account, either closed or a valid TokenDistributor
+ */
+pub(crate) fn require_distribution_ended(distributor_key: &AccountInfo) -> Result<()> {
+    require_distribution_ended_for(distributor_key, 0, TokenDistributorError::DistributionNotEnded)
+}
+
+/**
+ * Checks that a distribution ended more than grace_period seconds ago, or that its distributor has been closed
+ * 
+ * A distribution whose time has not been set has not ended.
+ * 
+ * @param distributor_key - Distributor account, either closed or a valid TokenDistributor
+ * @param grace_period - Seconds that must have passed since end_time
+ * @param error - Error returned while the grace period is running
+ */
+pub(crate) fn require_distribution_ended_for(
+    distributor_key: &AccountInfo,
+    grace_period: i64,
+    error: TokenDistributorError,
+) -> Result<()> {
     // Only validate if distributor account still exists
     if distributor_key.data_len() != 0 {
         // Explicitly verify the distributor account is owned by this program
@@ -59,24 +104,26 @@ pub fn handle_close_claim_status(ctx: Context<CloseClaimStatus>) -> Result<()> {
             TokenDistributorError::DistributorNotOwnedByProgram
         );
         
-        // Deserialize distributor data to access end_time
+        // Read the distributor in place as AccountLoader::load does, it is too large to copy onto the stack
+        // (AccountLoader itself needs an account borrowed for 'info, which instruction accounts are not)
         let distributor_data = distributor_key.try_borrow_data()?;
-        let distributor = TokenDistributor::try_deserialize(&mut distributor_data.as_ref())?;
-        
-        // Check if distribution has ended
-        let current_time = Clock::get()?.unix_timestamp;
         require!(
-            current_time > distributor.end_time,
-            TokenDistributorError::DistributionNotEnded
+            distributor_data.len() >= TokenDistributor::LEN
+                && distributor_data[..TokenDistributor::DISCRIMINATOR.len()] == *TokenDistributor::DISCRIMINATOR,
+            ErrorCode::AccountDiscriminatorMismatch
         );
+        let distributor: &TokenDistributor =
+            bytemuck::from_bytes(&distributor_data[TokenDistributor::DISCRIMINATOR.len()..TokenDistributor::LEN]);
+        
+        // Check if distribution has ended, plus the grace period
+        // In slot mode the grace period is converted to slots
+        // An unscheduled distribution (end_time still 0) has not ended, its claim statuses may hold a freeze or delegate
+        let current_time = distributor.activation_now(&Clock::get()?);
+        let grace_period = distributor.activation_type().period(grace_period);
+        if distributor.end_time == 0 || current_time <= distributor.end_time.saturating_add(grace_period) {
+            return Err(error.into());
+        }
     }
     
-    // Emit event for off-chain indexing and monitoring
-    emit_cpi!(ClaimStatusClosed {
-        distributor: ctx.accounts.distributor_key.key(),
-        claimant: ctx.accounts.claimant.key(),
-        claimed_amount: ctx.accounts.claim_status.claimed_amount,
-    });
-    
     Ok(())
-}
\ No newline at end of file
+}
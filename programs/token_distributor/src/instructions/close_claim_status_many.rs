@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::event::*;
+use crate::constants::*;
+use super::require_distribution_ended;
+
+/**
+ * Account context for closing several claim status accounts at once
+ * 
+ * This instruction allows a claimant who took part in many distributions to
+ * close their ClaimStatus accounts across distributors in one transaction.
+ * 
+ * Remaining accounts hold one (claim_status, distributor, rent_payer) triple
+ * per account to close:
+ * - claim_status: writable claim status PDA ["claim", distributor_key, claimant_key]
+ * - distributor: distributor account, either closed or a valid TokenDistributor
+ * - rent_payer: writable rent payer recorded in the claim status, receives the rent
+ * 
+ * Access Control: Only the claimant can close their ClaimStatus accounts
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseClaimStatusMany<'info> {
+    /// The claimant the ClaimStatus accounts belong to
+    pub claimant: Signer<'info>,
+}
+
+/**
+ * Closes several ClaimStatus accounts, returning rent to their rent payers
+ * 
+ * @param ctx - The account context, with one account triple per ClaimStatus in remaining accounts
+ * 
+ * Validation Rules:
+ * - Between 1 and MAX_BATCH_CLOSES triples
+ * - Each triple is validated exactly like close_claim_status
+ */
+pub fn handle_close_claim_status_many<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CloseClaimStatusMany<'info>>,
+) -> Result<()> {
+    let triples = ctx.remaining_accounts.chunks_exact(3);
+    require!(
+        triples.len() > 0 && triples.len() <= MAX_BATCH_CLOSES && triples.remainder().is_empty(),
+        TokenDistributorError::InvalidBatchSize
+    );
+    
+    let claimant_key = ctx.accounts.claimant.key();
+    
+    for accounts in triples {
+        let claim_status_info = &accounts[0];
+        let distributor_info = &accounts[1];
+        let rent_payer_info = &accounts[2];
+        
+        // Claim status must be the claimant's PDA for this distributor
+        let (claim_status_key, _) = Pubkey::find_program_address(
+            &[CLAIM_SEED.as_bytes(), distributor_info.key().as_ref(), claimant_key.as_ref()],
+            ctx.program_id,
+        );
+        require!(
+            claim_status_info.key() == claim_status_key,
+            TokenDistributorError::InvalidBatchAccounts
+        );
+        let claim_status = Account::<ClaimStatus>::try_from(claim_status_info)?;
+        require!(
+            rent_payer_info.key() == claim_status.rent_payer,
+            TokenDistributorError::RentPayerMismatch
+        );
+        
+        require_distribution_ended(distributor_info)?;
+        
+        // Emit event for off-chain indexing and monitoring
+        emit_cpi!(ClaimStatusClosed {
+            distributor: distributor_info.key(),
+            claimant: claimant_key,
+            claimed_amount: claim_status.claimed_amount,
+            rent_payer: rent_payer_info.key(),
+        });
+        
+        // Return the rent to the rent payer and close the account
+        claim_status.close(rent_payer_info.clone())?;
+    }
+    
+    Ok(())
+}
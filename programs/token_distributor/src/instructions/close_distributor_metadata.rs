@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::constants::*;
+use crate::event::*;
+use super::require_distribution_ended;
+
+/**
+ * Account context for closing a distributor's metadata
+ *
+ * This instruction allows the owner who set a distributor's metadata to
+ * reclaim its rent once the distribution has ended.
+ *
+ * Access Control: Only the owner recorded in the metadata can close it
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseDistributorMetadata<'info> {
+    /// The distributor's metadata account, rent returned to the owner
+    /// - Derived from: ["metadata", distributor_key]
+    #[account(
+        mut,
+        close = owner,
+        seeds = [METADATA_SEED.as_bytes(), distributor_key.key().as_ref()],
+        bump = metadata.bump
+    )]
+    pub metadata: Account<'info, DistributorMetadata>,
+
+    /// The owner who set the metadata
+    /// - Must match the owner recorded in the metadata
+    /// - Will receive the reclaimed rent
+    #[account(
+        mut,
+        constraint = owner.key() == metadata.owner @ TokenDistributorError::OnlyOwner
+    )]
+    pub owner: Signer<'info>,
+
+    /// Distributor account used for PDA derivation and time validation
+    /// CHECK: Either closed or valid TokenDistributor
+    pub distributor_key: AccountInfo<'info>,
+}
+
+/**
+ * Closes a distributor's metadata account and returns rent to the owner
+ *
+ * @param ctx - The account context containing metadata, owner and distributor accounts
+ */
+pub fn handle_close_distributor_metadata(ctx: Context<CloseDistributorMetadata>) -> Result<()> {
+    require_distribution_ended(&ctx.accounts.distributor_key)?;
+
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(DistributorMetadataClosed {
+        distributor: ctx.accounts.distributor_key.key(),
+        metadata: ctx.accounts.metadata.key(),
+        owner: ctx.accounts.owner.key(),
+    });
+
+    Ok(())
+}
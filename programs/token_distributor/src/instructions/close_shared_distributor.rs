@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::constants::*;
+use crate::event::*;
+
+/**
+ * Account context for closing a shared distributor
+ * 
+ * This instruction closes a distributor that draws from a shared vault once
+ * its distribution has ended, releasing its unclaimed budget back to the
+ * shared vault. Tokens stay in the shared vault for the next round.
+ * 
+ * Access Control: Only the owner can close a shared distributor
+ * 
+ * Business Logic:
+ * - Can be called after the distribution period has ended or if it was never started
+ * - Releases budget - total_claimed back to the shared vault
+ * - Closes the distributor account to reclaim rent
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseSharedDistributor<'info> {
+    /// The shared distributor account to close
+    /// - Must draw from the given shared vault
+    /// - Will be closed and rent returned to owner
+    #[account(
+        mut,
+        close = owner,
+        constraint = distributor.load()?.shared_vault == shared_vault.key() @ TokenDistributorError::SharedVaultMismatch
+    )]
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+    
+    /// The shared vault the distributor draws from
+    /// - Will be modified to release the unclaimed budget
+    #[account(
+        mut,
+        seeds = [SHARED_VAULT_SEED.as_bytes(), shared_vault.token_mint.as_ref(), shared_vault.owner.as_ref()],
+        bump = shared_vault.bump
+    )]
+    pub shared_vault: Account<'info, SharedVault>,
+    
+    /// The owner of the distributor
+    /// - Must match the owner stored in the distributor state
+    /// - Receives the reclaimed rent
+    #[account(
+        mut,
+        constraint = owner.key() == distributor.load()?.owner @ TokenDistributorError::OnlyOwner
+    )]
+    pub owner: Signer<'info>,
+    
+    /// Nonce state account tracking the owner's active distributors
+    /// - Derived from: ["owner_nonce", owner]
+    /// - Active distributor count is decremented as the distributor closes
+    #[account(
+        mut,
+        seeds = [OWNER_NONCE_SEED.as_bytes(), owner.key().as_ref()],
+        bump
+    )]
+    pub owner_nonce: Account<'info, NonceState>,
+}
+
+/**
+ * Closes a shared distributor and releases its unclaimed budget
+ * 
+ * @param ctx - The account context containing distributor, shared vault and owner accounts
+ * 
+ * Validation Rules:
+ * - Distribution must have ended or never been started
+ * - Only the owner can call this function
+ */
+pub fn handle_close_shared_distributor(ctx: Context<CloseSharedDistributor>) -> Result<()> {
+    let distributor_key = ctx.accounts.distributor.key();
+    let distributor = &ctx.accounts.distributor.load()?;
+    let shared_vault = &mut ctx.accounts.shared_vault;
+    
+    // Ensure distribution has ended or was never started
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(current_time > distributor.end_time, TokenDistributorError::DistributionNotEnded);
+    require!(current_time > distributor.withdraw_unlocks_at(), TokenDistributorError::WithdrawDelayActive);
+    
+    // Release the unclaimed part of the budget
+    let released_amount = distributor.budget.saturating_sub(distributor.total_claimed);
+    shared_vault.allocated_amount = shared_vault
+        .allocated_amount
+        .checked_sub(released_amount)
+        .ok_or(TokenDistributorError::ArithmeticOverflow)?;
+    
+    // The distributor no longer counts against the owner's active distributor limit
+    ctx.accounts.owner_nonce.close_distributor();
+    
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(SharedDistributorClosed {
+        distributor: distributor_key,
+        shared_vault: shared_vault.key(),
+        released_amount,
+    });
+    
+    // Note: The distributor account will be automatically closed due to the
+    // close = owner constraint in the account definition, returning rent to owner
+    Ok(())
+}
@@ -0,0 +1,191 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenInterface, TokenAccount};
+use crate::state::*;
+use crate::error::*;
+use crate::constants::*;
+use crate::utils::{close_token_account_with_pda, harvest_withheld_fees};
+use crate::event::*;
+
+/**
+ * Account context for closing an emptied token vault
+ *
+ * This instruction allows the distributor owner to reclaim the rent of the
+ * token vault once every token has been paid out, without closing the
+ * distributor. Unlike withdraw, the distributor stays open for historical
+ * queries, and close_claim_status keeps validating the distribution's end
+ * against it instead of treating the distribution as closed.
+ *
+ * Access Control: Only the owner can close the vault
+ *
+ * Business Logic:
+ * - The vault must be empty
+ * - The distribution must have completed (a claim emptied the vault) or ended
+ * - Harvests withheld Token 2022 transfer fees to the mint so the vault can be closed
+ * - Closes the token vault account to reclaim rent
+ * - Marks the vault closed on the distributor, which no longer counts as active
+ * - Removes the distributor from its mint registry when given
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseVault<'info> {
+    /// The distributor whose vault is closed
+    /// - Must be a valid existing distributor PDA
+    /// - Stays open, vault_closed is set
+    #[account(mut)]
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+
+    /// Token vault of the distributor
+    /// - Controlled by the distributor PDA
+    /// - Derived from: ["vault", distributor_key]
+    /// - Must be empty, will be closed
+    #[account(
+        mut,
+        seeds = [VAULT_SEED.as_bytes(), distributor.key().as_ref()],
+        bump
+    )]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// The token mint for verification
+    /// - Must match the distributor's token mint
+    /// - Writable to receive transfer fees withheld in the vault (Token 2022)
+    #[account(
+        mut,
+        token::token_program = token_program,
+        constraint = token_mint.key() == distributor.load()?.token_mint @ TokenDistributorError::TokenMintMismatch
+    )]
+    pub token_mint: InterfaceAccount<'info, anchor_spl::token_interface::Mint>,
+
+    /// The owner of the distributor
+    /// - Must match the owner stored in the distributor state
+    /// - Receives the vault's rent
+    #[account(
+        mut,
+        constraint = owner.key() == distributor.load()?.owner @ TokenDistributorError::OnlyOwner
+    )]
+    pub owner: Signer<'info>,
+
+    /// Nonce state account tracking the owner's active distributors
+    /// - Derived from: ["owner_nonce", owner]
+    /// - Active distributor count is decremented as the distribution is over
+    #[account(
+        mut,
+        seeds = [OWNER_NONCE_SEED.as_bytes(), owner.key().as_ref()],
+        bump
+    )]
+    pub owner_nonce: Account<'info, NonceState>,
+
+    /// Token program (supports both SPL Token and Token 2022)
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Registry of the distributor's token mint
+    /// - Optional, the distributor's entry is removed from it when given
+    /// - Derived from: ["registry", token_mint]
+    #[account(
+        mut,
+        seeds = [REGISTRY_SEED.as_bytes(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub registry: Option<Account<'info, MintRegistry>>,
+}
+
+/**
+ * Closes the emptied token vault of a distributor
+ *
+ * @param ctx - The account context containing all required accounts
+ *
+ * @returns Result<()> - Success or error
+ *
+ * Validation Rules:
+ * - The vault must hold no tokens
+ * - Distribution must have completed, ended or never been started
+ * - Every extra mint of a multi-mint distributor must have been withdrawn
+ * - Only the owner can call this function
+ */
+pub fn handle_close_vault(ctx: Context<CloseVault>) -> Result<()> {
+    let distributor_key = ctx.accounts.distributor.key();
+
+    // ===== VALIDATION PHASE =====
+
+    // Only an empty vault is closed, withdraw recovers remaining tokens
+    require!(ctx.accounts.token_vault.amount == 0, TokenDistributorError::VaultNotEmpty);
+
+    let (token_mint_key, owner, nonce, bump) = {
+        let distributor = &mut ctx.accounts.distributor.load_mut()?;
+
+        // Ensure no more claims are expected from the vault
+        let current_time = distributor.activation_now(&Clock::get()?);
+        require!(
+            distributor.is_completed() || current_time > distributor.end_time,
+            TokenDistributorError::DistributionNotEnded
+        );
+
+        // Extra mint vaults are paid out alongside this vault by claim_multi_mint
+        require!(distributor.open_extra_mints == 0, TokenDistributorError::ExtraMintsOpen);
+
+        // ===== EFFECTS PHASE =====
+
+        distributor.vault_closed = 1;
+
+        (distributor.token_mint, distributor.owner, distributor.nonce, distributor.bump)
+    };
+
+    // The distributor can no longer be withdrawn, so it stops counting as active here
+    ctx.accounts.owner_nonce.close_distributor();
+
+    // ===== INTERACTIONS PHASE (Vault Cleanup) =====
+
+    // Prepare PDA signing seeds for token operations
+    let nonce_bytes = nonce.to_le_bytes();
+    let seeds = &[
+        DISTRIBUTOR_SEED.as_bytes(),
+        token_mint_key.as_ref(),
+        owner.as_ref(),
+        nonce_bytes.as_ref(),
+        &[bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    // Move withheld transfer fees to the mint, Token 2022 will not close a vault holding them
+    let withheld_fees = harvest_withheld_fees(
+        ctx.accounts.token_vault.to_account_info(),
+        ctx.accounts.token_mint.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+    )?;
+    if withheld_fees > 0 {
+        emit_cpi!(WithheldFeesHarvested {
+            distributor: distributor_key,
+            token_vault: ctx.accounts.token_vault.key(),
+            token_mint: token_mint_key,
+            amount: withheld_fees,
+        });
+    }
+
+    // Close the token vault account, returning its rent to the owner
+    close_token_account_with_pda(
+        ctx.accounts.token_vault.to_account_info(),
+        ctx.accounts.owner.to_account_info(),
+        ctx.accounts.distributor.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        signer,  // PDA signing for secure closure
+    )?;
+
+    // Distributors with a closed vault have nothing left to claim, they leave their mint registry
+    if let Some(registry) = ctx.accounts.registry.as_mut() {
+        if registry.remove(&distributor_key) {
+            emit_cpi!(RegistryEntryRemoved {
+                registry: registry.key(),
+                distributor: distributor_key,
+                token_mint: ctx.accounts.token_mint.key(),
+            });
+        }
+    }
+
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(VaultClosed {
+        distributor: distributor_key,
+        token_vault: ctx.accounts.token_vault.key(),
+        owner: ctx.accounts.owner.key(),
+    });
+
+    Ok(())
+}
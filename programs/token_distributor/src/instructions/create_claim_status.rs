@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::constants::*;
+use crate::utils::create_pda_account;
+
+/**
+ * Account context for creating a claim status paid from the rent escrow
+ * 
+ * This instruction creates the claimant's ClaimStatus account with rent paid
+ * from the distributor's rent escrow, so claimants without SOL for rent can
+ * claim. It is meant to precede claim in the same transaction.
+ * 
+ * Access Control: Only the claimant can create their own ClaimStatus account
+ */
+#[derive(Accounts)]
+pub struct CreateClaimStatus<'info> {
+    /// The distributor the claim status belongs to
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+    
+    /// Rent escrow paying for the ClaimStatus account
+    /// - Derived from: ["rent_escrow", distributor_key, owner]
+    /// - Recorded as rent payer, so rent returns here on close
+    #[account(
+        mut,
+        seeds = [RENT_ESCROW_SEED.as_bytes(), distributor.key().as_ref(), distributor.load()?.owner.as_ref()],
+        bump
+    )]
+    pub rent_escrow: SystemAccount<'info>,
+    
+    /// ClaimStatus account to create, derived from: ["claim", distributor_key, claimant_key]
+    /// CHECK: Validated by PDA seeds and created in the handler
+    #[account(
+        mut,
+        seeds = [CLAIM_SEED.as_bytes(), distributor.key().as_ref(), claimant.key().as_ref()],
+        bump
+    )]
+    pub claim_status: UncheckedAccount<'info>,
+    
+    /// The claimant the ClaimStatus account is created for
+    pub claimant: Signer<'info>,
+    
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/**
+ * Creates a ClaimStatus account funded by the rent escrow
+ * 
+ * @param ctx - The account context containing distributor, rent escrow, claim status and claimant accounts
+ * 
+ * Validation Rules:
+ * - ClaimStatus account must not exist yet
+ * - Rent escrow must stay rent exempt after paying the rent
+ */
+pub fn handle_create_claim_status(ctx: Context<CreateClaimStatus>) -> Result<()> {
+    let claim_status_info = ctx.accounts.claim_status.to_account_info();
+    require!(
+        claim_status_info.owner == &System::id(),
+        TokenDistributorError::ClaimStatusAlreadyExists
+    );
+    
+    // Escrow must cover the rent, less any lamports already at the address, while staying rent exempt itself
+    let rent = Rent::get()?;
+    let lamports = rent.minimum_balance(ClaimStatus::LEN).saturating_sub(claim_status_info.lamports());
+    let available = ctx.accounts.rent_escrow.lamports().saturating_sub(rent.minimum_balance(0));
+    require!(lamports <= available, TokenDistributorError::InsufficientRentEscrow);
+    
+    // Prepare PDA signing seeds for the escrow and the new account
+    let distributor_key = ctx.accounts.distributor.key();
+    let owner_key = ctx.accounts.distributor.load()?.owner;
+    let claimant_key = ctx.accounts.claimant.key();
+    let rent_escrow_seeds: &[&[u8]] = &[
+        RENT_ESCROW_SEED.as_bytes(),
+        distributor_key.as_ref(),
+        owner_key.as_ref(),
+        &[ctx.bumps.rent_escrow],
+    ];
+    let claim_status_seeds: &[&[u8]] = &[
+        CLAIM_SEED.as_bytes(),
+        distributor_key.as_ref(),
+        claimant_key.as_ref(),
+        &[ctx.bumps.claim_status],
+    ];
+    
+    create_pda_account(
+        ctx.accounts.rent_escrow.to_account_info(),
+        claim_status_info.clone(),
+        ctx.accounts.system_program.to_account_info(),
+        ClaimStatus::LEN,
+        &[rent_escrow_seeds, claim_status_seeds],
+    )?;
+    
+    // Record the escrow as rent payer so close_claim_status refunds it
+    let claim_status = ClaimStatus {
+        rent_payer: ctx.accounts.rent_escrow.key(),
+        ..Default::default()
+    };
+    claim_status.try_serialize(&mut &mut claim_status_info.try_borrow_mut_data()?[..])?;
+    
+    Ok(())
+}
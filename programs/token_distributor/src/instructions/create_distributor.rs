@@ -2,7 +2,7 @@ use crate::constants::*;
 use crate::error::*;
 use crate::event::*;
 use crate::state::*;
-use crate::utils::transfer_token;
+use crate::utils::{transfer_token_with_hook, load_program_config, amount_scaling};
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
@@ -15,6 +15,8 @@ use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
  * - Creates a token vault PDA to hold the tokens to be distributed
  * - Transfers the initial token amount from owner to the vault
  * - Sets up the operator who can manage the distribution
+ * - Records the distribution duration (defaults to the configured default duration)
+ * - Records the optional clawback receiver for unclaimed tokens
  *
  * Access Control: Only the owner can create a distributor
  */
@@ -49,7 +51,7 @@ pub struct CreateDistributor<'info> {
         ],
         bump
     )]
-    pub distributor: Account<'info, TokenDistributor>,
+    pub distributor: AccountLoader<'info, TokenDistributor>,
 
     /// Token vault account (PDA) that holds the tokens to be distributed
     /// - Controlled by the distributor PDA as token authority
@@ -88,8 +90,9 @@ pub struct CreateDistributor<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
 
-    /// The operator account that can manage the distribution
+    /// The initial operator account that can manage the distribution
     /// - Can set start time and update merkle root
+    /// - Stored as the first entry of the operator list
     /// CHECK: This account is validated by storing its key in the distributor state
     pub operator: AccountInfo<'info>,
 
@@ -101,6 +104,11 @@ pub struct CreateDistributor<'info> {
 
     /// Rent sysvar for rent exemption calculations
     pub rent: Sysvar<'info, Rent>,
+    
+    /// Program config holding the global pause switch and default parameters
+    /// CHECK: Derived from ["config"], either uninitialized or a valid ProgramConfig
+    #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+    pub config: UncheckedAccount<'info>,
 }
 
 /**
@@ -108,70 +116,190 @@ pub struct CreateDistributor<'info> {
  *
  * @param ctx - The account context containing all required accounts
  * @param initial_total_amount - Total amount of tokens to be distributed
+ * @param duration - Optional length of the distribution window in seconds, or slots in slot mode (defaults to the configured default duration)
+ * @param clawback_receiver - Optional receiver of unclaimed tokens after the distribution ends
+ * @param tree_version - Optional merkle tree hashing scheme version (defaults to TREE_VERSION,
+ *                       MULTI_MINT_TREE_VERSION to add extra mints via add_distributor_mint,
+ *                       RECIPIENT_TREE_VERSION for leaf-specified recipients)
+ * @param withdraw_delay - Optional delay in seconds (slots in slot mode) after the end time before withdrawal or clawback (defaults to 0)
+ * @param activation_type - Optional clock the distribution window is measured on (defaults to Timestamp)
  */
-pub fn handle_create_distributor(
-    ctx: Context<CreateDistributor>,
+pub fn handle_create_distributor<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateDistributor<'info>>,
     initial_total_amount: u64,
+    duration: Option<i64>,
+    clawback_receiver: Option<Pubkey>,
+    tree_version: Option<u8>,
+    withdraw_delay: Option<i64>,
+    activation_type: Option<ActivationType>,
 ) -> Result<()> {
+    // Calculate nonce number with overflow protection
+    let nonce = ctx.accounts.owner_nonce
+        .nonce
+        .checked_add(1)
+        .ok_or(TokenDistributorError::ArithmeticOverflow)?;
+
+    let event = initialize_distributor(
+        DistributorAccounts {
+            owner_nonce: &mut ctx.accounts.owner_nonce,
+            distributor: &ctx.accounts.distributor,
+            distributor_bump: ctx.bumps.distributor,
+            token_vault: &ctx.accounts.token_vault,
+            token_mint: &ctx.accounts.token_mint,
+            owner_token_account: &ctx.accounts.owner_token_account,
+            owner: &ctx.accounts.owner,
+            operator: &ctx.accounts.operator,
+            token_program: &ctx.accounts.token_program,
+            config: &ctx.accounts.config,
+            remaining_accounts: ctx.remaining_accounts,
+        },
+        nonce,
+        initial_total_amount,
+        duration,
+        clawback_receiver,
+        tree_version,
+        withdraw_delay,
+        activation_type,
+    )?;
+
+    // Emit event for off-chain indexing and monitoring
+    // Uses emit_cpi! for cross-program call compatibility
+    emit_cpi!(event);
+
+    Ok(())
+}
+
+/// Accounts shared by create_distributor and create_distributor_with_nonce
+pub(crate) struct DistributorAccounts<'a, 'info> {
+    pub owner_nonce: &'a mut Account<'info, NonceState>,
+    pub distributor: &'a AccountLoader<'info, TokenDistributor>,
+    pub distributor_bump: u8,
+    pub token_vault: &'a InterfaceAccount<'info, TokenAccount>,
+    pub token_mint: &'a InterfaceAccount<'info, Mint>,
+    pub owner_token_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub owner: &'a Signer<'info>,
+    pub operator: &'a AccountInfo<'info>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+    pub config: &'a UncheckedAccount<'info>,
+    pub remaining_accounts: &'info [AccountInfo<'info>],
+}
+
+/**
+ * Validates the creation parameters, initializes the distributor under the given nonce and funds its vault
+ *
+ * @param accounts - The accounts of the creating instruction
+ * @param nonce - Nonce the distributor PDA was derived with, recorded as the owner's latest nonce
+ *
+ * The remaining parameters are those of handle_create_distributor.
+ * Returns the DistributorCreated event for the caller to emit.
+ */
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn initialize_distributor<'info>(
+    accounts: DistributorAccounts<'_, 'info>,
+    nonce: u32,
+    initial_total_amount: u64,
+    duration: Option<i64>,
+    clawback_receiver: Option<Pubkey>,
+    tree_version: Option<u8>,
+    withdraw_delay: Option<i64>,
+    activation_type: Option<ActivationType>,
+) -> Result<DistributorCreated> {
     // Validate initial total amount
     require!(
         initial_total_amount > 0,
         TokenDistributorError::InvalidAmount
     );
 
+    // Reject all creations while the program is globally paused
+    let config = load_program_config(accounts.config)?;
+    config.require_not_paused()?;
+
+    // Validate distribution duration is within the configured bounds
+    // In slot mode durations are slots, the second-based bounds are converted
+    let activation_type = activation_type.unwrap_or_default();
+    let duration = duration.unwrap_or(activation_type.period(config.default_duration));
+    config.require_valid_duration(duration, activation_type)?;
+
+    // Validate the withdraw delay
+    let withdraw_delay = withdraw_delay.unwrap_or_default();
+    require!(
+        (0..=activation_type.period(MAX_WITHDRAW_DELAY)).contains(&withdraw_delay),
+        TokenDistributorError::InvalidWithdrawDelay
+    );
+
+    // Validate the merkle tree hashing scheme is supported
+    // Multi-mint trees are only claimable when the multi-mint instructions are built
+    let tree_version = tree_version.unwrap_or(TREE_VERSION);
+    require!(
+        tree_version == TREE_VERSION
+            || tree_version == UNLOCK_TIME_TREE_VERSION
+            || (cfg!(feature = "multi-mint") && tree_version == MULTI_MINT_TREE_VERSION)
+            || tree_version == RECIPIENT_TREE_VERSION
+            || tree_version == KECCAK_TREE_VERSION,
+        TokenDistributorError::InvalidTreeVersion
+    );
+
     // Validate operator is not empty account
     require!(
-        ctx.accounts.operator.key() != Pubkey::default(),
+        accounts.operator.key() != Pubkey::default(),
         TokenDistributorError::InvalidOperator
     );
 
-    let owner_nonce = &mut ctx.accounts.owner_nonce;
-    let distributor = &mut ctx.accounts.distributor;
+    let owner_nonce = accounts.owner_nonce;
+    let distributor_key = accounts.distributor.key();
+    let distributor = &mut accounts.distributor.load_init()?;
 
-    // Calculate nonce number with overflow protection
-    let current_nonce = owner_nonce
-        .nonce
-        .checked_add(1)
-        .ok_or(TokenDistributorError::ArithmeticOverflow)?;
+    // Update nonce state with the distributor's nonce
+    owner_nonce.nonce = nonce;
 
-    // Update nonce state with current nonce
-    owner_nonce.nonce = current_nonce;
+    // Enforce the owner's active distributor limit
+    owner_nonce.open_distributor(config.max_active_distributors)?;
 
-    // Initialize distributor state with auto-assigned nonce
-    distributor.bump = ctx.bumps.distributor;
-    distributor.nonce = current_nonce;
-    distributor.owner = ctx.accounts.owner.key();
-    distributor.operator = ctx.accounts.operator.key();
-    distributor.token_mint = ctx.accounts.token_mint.key();
-    distributor.token_vault = ctx.accounts.token_vault.key();
+    // Initialize distributor state
+    distributor.bump = accounts.distributor_bump;
+    distributor.nonce = nonce;
+    distributor.owner = accounts.owner.key();
+    distributor.operators[0] = accounts.operator.key();
+    distributor.token_mint = accounts.token_mint.key();
+    distributor.token_vault = accounts.token_vault.key();
     distributor.initial_total_amount = initial_total_amount;
+    distributor.duration = duration;
+    distributor.withdraw_delay = withdraw_delay;
+    distributor.activation_type = activation_type as u8;
+    distributor.tree_version = tree_version;
+    distributor.decimals = accounts.token_mint.decimals;
+    distributor.clawback_receiver = clawback_receiver.unwrap_or_default();
     // Note: total_claimed, start_time, end_time, merkle_root use default values (0)
 
     // Transfer tokens from owner to vault
     // This ensures the vault has the tokens available for distribution
     // Uses transfer_checked for compatibility with both SPL Token and Token 2022
-    transfer_token(
-        ctx.accounts.owner.to_account_info(),
-        ctx.accounts.owner_token_account.to_account_info(),
-        ctx.accounts.token_vault.to_account_info(),
-        ctx.accounts.token_mint.to_account_info(),
-        ctx.accounts.token_program.to_account_info(),
+    // Remaining accounts are passed to the mint's transfer hook, if any
+    transfer_token_with_hook(
+        accounts.owner.to_account_info(),
+        accounts.owner_token_account.to_account_info(),
+        accounts.token_vault.to_account_info(),
+        accounts.token_mint.to_account_info(),
+        accounts.token_program.to_account_info(),
         initial_total_amount,
-        ctx.accounts.token_mint.decimals,
+        accounts.token_mint.decimals,
         None, // No signer seeds needed for owner-signed transfer
+        accounts.remaining_accounts,
     )?;
 
-    // Emit event for off-chain indexing and monitoring
-    // Uses emit_cpi! for cross-program call compatibility
-    emit_cpi!(DistributorCreated {
-        distributor: distributor.key(),
-        nonce: current_nonce,
-        owner: ctx.accounts.owner.key(),
-        operator: ctx.accounts.operator.key(),
-        token_mint: ctx.accounts.token_mint.key(),
-        token_vault: ctx.accounts.token_vault.key(),
+    Ok(DistributorCreated {
+        distributor: distributor_key,
+        nonce,
+        owner: accounts.owner.key(),
+        operator: accounts.operator.key(),
+        token_mint: accounts.token_mint.key(),
+        token_vault: accounts.token_vault.key(),
         initial_total_amount,
-    });
-
-    Ok(())
+        duration,
+        clawback_receiver: distributor.clawback_receiver,
+        withdraw_delay,
+        activation_type,
+        tree_version,
+        scaling: amount_scaling(&accounts.token_mint.to_account_info(), accounts.token_mint.decimals)?,
+    })
 }
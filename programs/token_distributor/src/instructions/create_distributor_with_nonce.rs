@@ -0,0 +1,166 @@
+use crate::constants::*;
+use crate::error::*;
+use crate::state::*;
+use super::{initialize_distributor, DistributorAccounts};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/**
+ * Account context for creating a new token distributor under a caller-supplied nonce
+ *
+ * This instruction works like create_distributor, but the distributor PDA is
+ * derived from a nonce chosen by the owner, so the distributor and vault
+ * addresses can be computed before creation (e.g. for funding approvals):
+ * - Creates or updates a nonce state PDA to track nonce numbers
+ * - Creates a distributor PDA with the supplied nonce, which must be unused
+ * - Creates a token vault PDA to hold the tokens to be distributed
+ * - Transfers the initial token amount from owner to the vault
+ * - Sets up the operator who can manage the distribution
+ * - Records the distribution duration (defaults to the configured default duration)
+ * - Records the optional clawback receiver for unclaimed tokens
+ *
+ * Access Control: Only the owner can create a distributor
+ */
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(nonce: u32)]
+pub struct CreateDistributorWithNonce<'info> {
+    /// Nonce state account (PDA) that tracks nonce numbers for this owner
+    /// - Stores the current nonce counter for automatic nonce assignment
+    /// - Derived from: ["owner_nonce", owner]
+    /// - The supplied nonce must exceed the counter, which is then advanced to it
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = NonceState::LEN,
+        seeds = [OWNER_NONCE_SEED.as_bytes(), owner.key().as_ref()],
+        bump,
+        constraint = nonce > owner_nonce.nonce @ TokenDistributorError::NonceAlreadyUsed
+    )]
+    pub owner_nonce: Account<'info, NonceState>,
+
+    /// The main distributor account (PDA)
+    /// - Stores all distribution parameters and state
+    /// - Derived from: ["distributor", token_mint, owner, nonce]
+    #[account(
+        init,
+        payer = owner,
+        space = TokenDistributor::LEN,
+        seeds = [
+            DISTRIBUTOR_SEED.as_bytes(),
+            token_mint.key().as_ref(),
+            owner.key().as_ref(),
+            nonce.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+
+    /// Token vault account (PDA) that holds the tokens to be distributed
+    /// - Controlled by the distributor PDA as token authority
+    /// - Derived from: ["vault", distributor_key]
+    #[account(
+        init,
+        token::mint = token_mint,
+        token::authority = distributor,
+        token::token_program = token_program,
+        seeds = [VAULT_SEED.as_bytes(), distributor.key().as_ref()],
+        bump,
+        payer = owner,
+    )]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// The token mint for the tokens being distributed
+    /// - Supports both SPL Token and Token 2022 programs
+    #[account(
+        token::token_program = token_program,
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    /// Owner's token account containing the tokens to be deposited
+    /// - Must be owned by the owner signer
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = owner,
+        token::token_program = token_program,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// The owner of the distributor
+    /// - Has full control over the distributor
+    /// - Can withdraw remaining tokens after distribution ends
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// The initial operator account that can manage the distribution
+    /// - Can set start time and update merkle root
+    /// - Stored as the first entry of the operator list
+    /// CHECK: This account is validated by storing its key in the distributor state
+    pub operator: AccountInfo<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Token program (supports both SPL Token and Token 2022)
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Rent sysvar for rent exemption calculations
+    pub rent: Sysvar<'info, Rent>,
+    
+    /// Program config holding the global pause switch and default parameters
+    /// CHECK: Derived from ["config"], either uninitialized or a valid ProgramConfig
+    #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+    pub config: UncheckedAccount<'info>,
+}
+
+/**
+ * Creates a new token distributor under a caller-supplied nonce
+ *
+ * @param ctx - The account context containing all required accounts
+ * @param nonce - Nonce the distributor PDA is derived with, greater than any nonce the owner has used
+ *
+ * The remaining parameters are those of create_distributor.
+ *
+ * Nonces are only ever advanced, so a nonce is never reused even after its
+ * distributor has been closed, and create_distributor continues after it.
+ */
+#[allow(clippy::too_many_arguments)]
+pub fn handle_create_distributor_with_nonce<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateDistributorWithNonce<'info>>,
+    nonce: u32,
+    initial_total_amount: u64,
+    duration: Option<i64>,
+    clawback_receiver: Option<Pubkey>,
+    tree_version: Option<u8>,
+    withdraw_delay: Option<i64>,
+    activation_type: Option<ActivationType>,
+) -> Result<()> {
+    let event = initialize_distributor(
+        DistributorAccounts {
+            owner_nonce: &mut ctx.accounts.owner_nonce,
+            distributor: &ctx.accounts.distributor,
+            distributor_bump: ctx.bumps.distributor,
+            token_vault: &ctx.accounts.token_vault,
+            token_mint: &ctx.accounts.token_mint,
+            owner_token_account: &ctx.accounts.owner_token_account,
+            owner: &ctx.accounts.owner,
+            operator: &ctx.accounts.operator,
+            token_program: &ctx.accounts.token_program,
+            config: &ctx.accounts.config,
+            remaining_accounts: ctx.remaining_accounts,
+        },
+        nonce,
+        initial_total_amount,
+        duration,
+        clawback_receiver,
+        tree_version,
+        withdraw_delay,
+        activation_type,
+    )?;
+
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(event);
+
+    Ok(())
+}
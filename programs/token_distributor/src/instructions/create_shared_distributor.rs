@@ -0,0 +1,199 @@
+use crate::constants::*;
+use crate::error::*;
+use crate::event::*;
+use crate::state::*;
+use crate::utils::{load_program_config, amount_scaling};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/**
+ * Account context for creating a distributor that draws from a shared vault
+ *
+ * This instruction initializes a new distributor (round) without its own vault:
+ * - Creates or updates a nonce state PDA to track nonce numbers
+ * - Creates a distributor PDA with auto-incremented nonce number
+ * - Allocates a budget from the shared vault's unallocated balance
+ * - Sets up the operator who can manage the distribution
+ *
+ * Access Control: Only the shared vault owner can create a shared distributor
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateSharedDistributor<'info> {
+    /// Nonce state account (PDA) that tracks nonce numbers for this owner
+    /// - Derived from: ["owner_nonce", owner]
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = NonceState::LEN,
+        seeds = [OWNER_NONCE_SEED.as_bytes(), owner.key().as_ref()],
+        bump
+    )]
+    pub owner_nonce: Account<'info, NonceState>,
+
+    /// The main distributor account (PDA)
+    /// - Derived from: ["distributor", token_mint, owner, current_nonce]
+    /// - Nonce is automatically determined from owner_nonce.nonce + 1
+    #[account(
+        init,
+        payer = owner,
+        space = TokenDistributor::LEN,
+        seeds = [
+            DISTRIBUTOR_SEED.as_bytes(),
+            token_mint.key().as_ref(),
+            owner.key().as_ref(),
+            (owner_nonce.nonce + 1).to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+
+    /// The shared vault the distributor draws from
+    /// - Derived from: ["shared_vault", token_mint, owner]
+    /// - Will be modified to allocate the budget
+    #[account(
+        mut,
+        seeds = [SHARED_VAULT_SEED.as_bytes(), token_mint.key().as_ref(), owner.key().as_ref()],
+        bump = shared_vault.bump
+    )]
+    pub shared_vault: Account<'info, SharedVault>,
+
+    /// Token vault of the shared vault
+    /// - Used to check the unallocated balance
+    #[account(address = shared_vault.token_vault)]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// The token mint for the tokens being distributed
+    #[account(
+        token::token_program = token_program,
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    /// The owner of the shared vault and the new distributor
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// The initial operator account that can manage the distribution
+    /// CHECK: This account is validated by storing its key in the distributor state
+    pub operator: AccountInfo<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Token program (supports both SPL Token and Token 2022)
+    pub token_program: Interface<'info, TokenInterface>,
+    
+    /// Program config holding the global pause switch and default parameters
+    /// CHECK: Derived from ["config"], either uninitialized or a valid ProgramConfig
+    #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+    pub config: UncheckedAccount<'info>,
+}
+
+/**
+ * Creates a new distributor drawing from the owner's shared vault
+ *
+ * @param ctx - The account context containing all required accounts
+ * @param budget - Maximum amount the distributor may pay out from the shared vault
+ * @param duration - Optional length of the distribution window in seconds (defaults to the configured default duration)
+ * @param tree_version - Optional merkle tree hashing scheme version (defaults to TREE_VERSION)
+ * @param withdraw_delay - Optional delay in seconds after the end time before the distributor can be closed (defaults to 0)
+ */
+pub fn handle_create_shared_distributor(
+    ctx: Context<CreateSharedDistributor>,
+    budget: u64,
+    duration: Option<i64>,
+    tree_version: Option<u8>,
+    withdraw_delay: Option<i64>,
+) -> Result<()> {
+    require!(budget > 0, TokenDistributorError::InvalidAmount);
+
+    // Reject all creations while the program is globally paused
+    let config = load_program_config(&ctx.accounts.config)?;
+    config.require_not_paused()?;
+
+    // Validate distribution duration is within the configured bounds
+    let duration = duration.unwrap_or(config.default_duration);
+    config.require_valid_duration(duration, ActivationType::Timestamp)?;
+
+    // Validate the withdraw delay
+    let withdraw_delay = withdraw_delay.unwrap_or_default();
+    require!(
+        (0..=MAX_WITHDRAW_DELAY).contains(&withdraw_delay),
+        TokenDistributorError::InvalidWithdrawDelay
+    );
+
+    // Validate the merkle tree hashing scheme is supported
+    let tree_version = tree_version.unwrap_or(TREE_VERSION);
+    require!(
+        tree_version == TREE_VERSION
+            || tree_version == UNLOCK_TIME_TREE_VERSION
+            || tree_version == KECCAK_TREE_VERSION,
+        TokenDistributorError::InvalidTreeVersion
+    );
+
+    // Validate operator is not empty account
+    require!(
+        ctx.accounts.operator.key() != Pubkey::default(),
+        TokenDistributorError::InvalidOperator
+    );
+
+    // Allocate the budget from the unallocated vault balance
+    let shared_vault = &mut ctx.accounts.shared_vault;
+    let allocated_amount = shared_vault
+        .allocated_amount
+        .checked_add(budget)
+        .ok_or(TokenDistributorError::ArithmeticOverflow)?;
+    require!(
+        allocated_amount <= ctx.accounts.token_vault.amount,
+        TokenDistributorError::InsufficientUnallocatedBalance
+    );
+    shared_vault.allocated_amount = allocated_amount;
+
+    let owner_nonce = &mut ctx.accounts.owner_nonce;
+    let distributor_key = ctx.accounts.distributor.key();
+    let distributor = &mut ctx.accounts.distributor.load_init()?;
+
+    // Calculate nonce number with overflow protection
+    let current_nonce = owner_nonce
+        .nonce
+        .checked_add(1)
+        .ok_or(TokenDistributorError::ArithmeticOverflow)?;
+
+    // Update nonce state with current nonce
+    owner_nonce.nonce = current_nonce;
+
+    // Enforce the owner's active distributor limit
+    owner_nonce.open_distributor(config.max_active_distributors)?;
+
+    // Initialize distributor state pointing at the shared vault
+    distributor.bump = ctx.bumps.distributor;
+    distributor.nonce = current_nonce;
+    distributor.owner = ctx.accounts.owner.key();
+    distributor.operators[0] = ctx.accounts.operator.key();
+    distributor.token_mint = ctx.accounts.token_mint.key();
+    distributor.token_vault = ctx.accounts.token_vault.key();
+    distributor.shared_vault = shared_vault.key();
+    distributor.budget = budget;
+    distributor.initial_total_amount = budget;
+    distributor.duration = duration;
+    distributor.withdraw_delay = withdraw_delay;
+    distributor.tree_version = tree_version;
+    distributor.decimals = ctx.accounts.token_mint.decimals;
+
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(SharedDistributorCreated {
+        distributor: distributor_key,
+        nonce: current_nonce,
+        shared_vault: shared_vault.key(),
+        owner: ctx.accounts.owner.key(),
+        operator: ctx.accounts.operator.key(),
+        token_mint: ctx.accounts.token_mint.key(),
+        budget,
+        duration,
+        withdraw_delay,
+        tree_version,
+        scaling: amount_scaling(&ctx.accounts.token_mint.to_account_info(), ctx.accounts.token_mint.decimals)?,
+    });
+
+    Ok(())
+}
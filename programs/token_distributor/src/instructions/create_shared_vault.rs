@@ -0,0 +1,126 @@
+use crate::constants::*;
+use crate::error::*;
+use crate::event::*;
+use crate::state::*;
+use crate::utils::{transfer_token_with_hook, load_program_config};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/**
+ * Account context for creating a shared vault
+ *
+ * This instruction initializes a vault that several distributors of the same
+ * owner and mint can draw from:
+ * - Creates the shared vault PDA for the (token_mint, owner) pair
+ * - Creates a token vault PDA controlled by the shared vault
+ * - Transfers the initial token amount from owner to the vault
+ *
+ * Access Control: Only the owner can create a shared vault
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateSharedVault<'info> {
+    /// The shared vault account (PDA)
+    /// - Tracks budget allocated to shared distributors
+    /// - Derived from: ["shared_vault", token_mint, owner]
+    #[account(
+        init,
+        payer = owner,
+        space = SharedVault::LEN,
+        seeds = [SHARED_VAULT_SEED.as_bytes(), token_mint.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub shared_vault: Account<'info, SharedVault>,
+
+    /// Token vault account (PDA) that holds the shared tokens
+    /// - Controlled by the shared vault PDA as token authority
+    /// - Derived from: ["vault", shared_vault_key]
+    #[account(
+        init,
+        token::mint = token_mint,
+        token::authority = shared_vault,
+        token::token_program = token_program,
+        seeds = [VAULT_SEED.as_bytes(), shared_vault.key().as_ref()],
+        bump,
+        payer = owner,
+    )]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// The token mint for the tokens being distributed
+    /// - Supports both SPL Token and Token 2022 programs
+    #[account(
+        token::token_program = token_program,
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    /// Owner's token account containing the tokens to be deposited
+    /// - Must be owned by the owner signer
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = owner,
+        token::token_program = token_program,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// The owner of the shared vault
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Token program (supports both SPL Token and Token 2022)
+    pub token_program: Interface<'info, TokenInterface>,
+    
+    /// Program config holding the global pause switch and default parameters
+    /// CHECK: Derived from ["config"], either uninitialized or a valid ProgramConfig
+    #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+    pub config: UncheckedAccount<'info>,
+}
+
+/**
+ * Creates a shared vault and deposits the initial amount
+ *
+ * @param ctx - The account context, with transfer hook accounts in remaining accounts
+ * @param amount - Amount of tokens to deposit into the shared vault
+ *
+ * Note: Additional deposits can be made with plain token transfers to the vault
+ */
+pub fn handle_create_shared_vault<'info>(ctx: Context<'_, '_, 'info, 'info, CreateSharedVault<'info>>, amount: u64) -> Result<()> {
+    // Reject all creations while the program is globally paused
+    load_program_config(&ctx.accounts.config)?.require_not_paused()?;
+
+    require!(amount > 0, TokenDistributorError::InvalidAmount);
+
+    let shared_vault = &mut ctx.accounts.shared_vault;
+    shared_vault.bump = ctx.bumps.shared_vault;
+    shared_vault.owner = ctx.accounts.owner.key();
+    shared_vault.token_mint = ctx.accounts.token_mint.key();
+    shared_vault.token_vault = ctx.accounts.token_vault.key();
+    // Note: allocated_amount uses default value (0)
+
+    // Transfer tokens from owner to the shared vault
+    transfer_token_with_hook(
+        ctx.accounts.owner.to_account_info(),
+        ctx.accounts.owner_token_account.to_account_info(),
+        ctx.accounts.token_vault.to_account_info(),
+        ctx.accounts.token_mint.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        amount,
+        ctx.accounts.token_mint.decimals,
+        None, // No signer seeds needed for owner-signed transfer
+        ctx.remaining_accounts,
+    )?;
+
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(SharedVaultCreated {
+        shared_vault: shared_vault.key(),
+        owner: ctx.accounts.owner.key(),
+        token_mint: ctx.accounts.token_mint.key(),
+        token_vault: ctx.accounts.token_vault.key(),
+        amount,
+    });
+
+    Ok(())
+}
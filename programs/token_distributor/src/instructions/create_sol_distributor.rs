@@ -0,0 +1,202 @@
+use crate::constants::*;
+use crate::error::*;
+use crate::event::*;
+use crate::state::*;
+use crate::utils::load_program_config;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token::spl_token::native_mint;
+
+/**
+ * Account context for creating a native SOL distributor
+ *
+ * This instruction initializes a distributor that pays out native SOL, e.g.
+ * to airdrop gas money, with the same nonce management as create_distributor:
+ * - Creates or updates a nonce state PDA to track nonce numbers
+ * - Creates a distributor PDA keyed by the native mint
+ * - Transfers the initial lamport amount (plus the vault's rent reserve) into
+ *   a lamport vault PDA
+ * - Sets up the operator who can manage the distribution
+ *
+ * Access Control: Only the owner can create a distributor
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateSolDistributor<'info> {
+    /// Nonce state account (PDA) that tracks nonce numbers for this owner
+    /// - Derived from: ["owner_nonce", owner]
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = NonceState::LEN,
+        seeds = [OWNER_NONCE_SEED.as_bytes(), owner.key().as_ref()],
+        bump
+    )]
+    pub owner_nonce: Account<'info, NonceState>,
+
+    /// The main distributor account (PDA)
+    /// - Derived from: ["distributor", native_mint, owner, current_nonce]
+    /// - Nonce is automatically determined from owner_nonce.nonce + 1
+    #[account(
+        init,
+        payer = owner,
+        space = TokenDistributor::LEN,
+        seeds = [
+            DISTRIBUTOR_SEED.as_bytes(),
+            native_mint::ID.as_ref(),
+            owner.key().as_ref(),
+            (owner_nonce.nonce + 1).to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+
+    /// Lamport vault (PDA) that holds the SOL to be distributed
+    /// - System-owned account without data
+    /// - Derived from: ["sol_vault", distributor_key]
+    #[account(
+        mut,
+        seeds = [SOL_VAULT_SEED.as_bytes(), distributor.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
+    /// The owner of the distributor
+    /// - Funds the lamport vault
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// The initial operator account that can manage the distribution
+    /// CHECK: This account is validated by storing its key in the distributor state
+    pub operator: AccountInfo<'info>,
+
+    /// System program for account creation and lamport transfers
+    pub system_program: Program<'info, System>,
+    
+    /// Program config holding the global pause switch and default parameters
+    /// CHECK: Derived from ["config"], either uninitialized or a valid ProgramConfig
+    #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+    pub config: UncheckedAccount<'info>,
+}
+
+/**
+ * Creates a new native SOL distributor with automatic nonce management
+ *
+ * @param ctx - The account context containing all required accounts
+ * @param initial_total_amount - Total amount of lamports to be distributed
+ * @param duration - Optional length of the distribution window in seconds (defaults to the configured default duration)
+ * @param tree_version - Optional merkle tree hashing scheme version (defaults to TREE_VERSION)
+ * @param withdraw_delay - Optional delay in seconds after the end time before withdrawal (defaults to 0)
+ */
+pub fn handle_create_sol_distributor(
+    ctx: Context<CreateSolDistributor>,
+    initial_total_amount: u64,
+    duration: Option<i64>,
+    tree_version: Option<u8>,
+    withdraw_delay: Option<i64>,
+) -> Result<()> {
+    // Validate initial total amount
+    require!(
+        initial_total_amount > 0,
+        TokenDistributorError::InvalidAmount
+    );
+
+    // Reject all creations while the program is globally paused
+    let config = load_program_config(&ctx.accounts.config)?;
+    config.require_not_paused()?;
+
+    // Validate distribution duration is within the configured bounds
+    let duration = duration.unwrap_or(config.default_duration);
+    config.require_valid_duration(duration, ActivationType::Timestamp)?;
+
+    // Validate the withdraw delay
+    let withdraw_delay = withdraw_delay.unwrap_or_default();
+    require!(
+        (0..=MAX_WITHDRAW_DELAY).contains(&withdraw_delay),
+        TokenDistributorError::InvalidWithdrawDelay
+    );
+
+    // Validate the merkle tree hashing scheme is supported
+    let tree_version = tree_version.unwrap_or(TREE_VERSION);
+    require!(
+        tree_version == TREE_VERSION
+            || tree_version == UNLOCK_TIME_TREE_VERSION
+            || tree_version == KECCAK_TREE_VERSION,
+        TokenDistributorError::InvalidTreeVersion
+    );
+
+    // Validate operator is not empty account
+    require!(
+        ctx.accounts.operator.key() != Pubkey::default(),
+        TokenDistributorError::InvalidOperator
+    );
+
+    let owner_nonce = &mut ctx.accounts.owner_nonce;
+    let distributor_key = ctx.accounts.distributor.key();
+    let distributor = &mut ctx.accounts.distributor.load_init()?;
+
+    // Calculate nonce number with overflow protection
+    let current_nonce = owner_nonce
+        .nonce
+        .checked_add(1)
+        .ok_or(TokenDistributorError::ArithmeticOverflow)?;
+
+    // Update nonce state with current nonce
+    owner_nonce.nonce = current_nonce;
+
+    // Enforce the owner's active distributor limit
+    owner_nonce.open_distributor(config.max_active_distributors)?;
+
+    // Initialize distributor state
+    distributor.bump = ctx.bumps.distributor;
+    distributor.nonce = current_nonce;
+    distributor.owner = ctx.accounts.owner.key();
+    distributor.operators[0] = ctx.accounts.operator.key();
+    distributor.token_mint = native_mint::ID;
+    distributor.token_vault = ctx.accounts.sol_vault.key();
+    distributor.native = 1;
+    distributor.initial_total_amount = initial_total_amount;
+    distributor.duration = duration;
+    distributor.withdraw_delay = withdraw_delay;
+    distributor.tree_version = tree_version;
+    distributor.decimals = native_mint::DECIMALS;
+
+    // Fund the vault with the distributable amount plus its own rent reserve,
+    // so the vault stays rent exempt until it is emptied by withdraw_sol
+    let rent_reserve = Rent::get()?.minimum_balance(0);
+    let deposit = initial_total_amount
+        .checked_add(rent_reserve)
+        .ok_or(TokenDistributorError::ArithmeticOverflow)?;
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.owner.to_account_info(),
+                to: ctx.accounts.sol_vault.to_account_info(),
+            },
+        ),
+        deposit,
+    )?;
+
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(DistributorCreated {
+        distributor: distributor_key,
+        nonce: current_nonce,
+        owner: ctx.accounts.owner.key(),
+        operator: ctx.accounts.operator.key(),
+        token_mint: native_mint::ID,
+        token_vault: ctx.accounts.sol_vault.key(),
+        initial_total_amount,
+        duration,
+        withdraw_delay,
+        activation_type: ActivationType::Timestamp,
+        clawback_receiver: Pubkey::default(),
+        tree_version,
+        scaling: AmountScaling {
+            decimals: native_mint::DECIMALS,
+            ..Default::default()
+        },
+    });
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::constants::*;
+use crate::event::*;
+
+/**
+ * Account context for enabling the claim escrow
+ * 
+ * This instruction allows the distributor owner to lock claimed tokens in a
+ * per-claimant escrow that releases them linearly, instead of building
+ * vesting into the distribution itself.
+ * 
+ * Access Control: Only the owner can enable the claim escrow
+ * 
+ * Business Logic:
+ * - Only allowed before the first claim, so every claimant is escrowed alike
+ * - Irreversible, the escrow cannot be disabled afterwards
+ * - Claims then pass the claimant's claim escrow and its token account,
+ *   created by the payer on the claimant's first claim
+ * - Only claim supports escrows, so native SOL, multi-mint and recipient
+ *   distributors are rejected
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct EnableClaimEscrow<'info> {
+    /// The distributor account to update
+    /// - Must not have any claims yet
+    /// - Will be modified to set escrow_release_duration
+    #[account(mut)]
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+    
+    /// The owner of the distributor
+    /// - Must match the owner stored in the distributor state
+    #[account(constraint = owner.key() == distributor.load()?.owner @ TokenDistributorError::OnlyOwner)]
+    pub owner: Signer<'info>,
+}
+
+/**
+ * Enables the claim escrow of a distributor
+ * 
+ * @param ctx - The account context containing distributor and owner accounts
+ * @param release_duration - Period over which each claimant's escrowed tokens are released, in seconds
+ */
+pub fn handle_enable_claim_escrow(ctx: Context<EnableClaimEscrow>, release_duration: i64) -> Result<()> {
+    let distributor_key = ctx.accounts.distributor.key();
+    let distributor = &mut ctx.accounts.distributor.load_mut()?;
+    
+    require!(!distributor.is_escrowed(), TokenDistributorError::ClaimEscrowAlreadyEnabled);
+    require!(distributor.claims_count == 0, TokenDistributorError::ClaimsAlreadyMade);
+    require!(
+        !distributor.is_native()
+            && distributor.tree_version != MULTI_MINT_TREE_VERSION
+            && distributor.tree_version != RECIPIENT_TREE_VERSION,
+        TokenDistributorError::ClaimEscrowNotSupported
+    );
+    require!(
+        release_duration > 0 && release_duration <= MAX_ESCROW_RELEASE_DURATION,
+        TokenDistributorError::InvalidReleaseDuration
+    );
+    
+    distributor.escrow_release_duration = release_duration;
+    
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(ClaimEscrowEnabled {
+        distributor: distributor_key,
+        owner: ctx.accounts.owner.key(),
+        release_duration,
+    });
+    
+    Ok(())
+}
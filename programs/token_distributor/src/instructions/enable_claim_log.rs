@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::event::*;
+
+/**
+ * Account context for enabling the claim log
+ * 
+ * This instruction allows the distributor owner to record every claim in
+ * paginated ClaimLog accounts, giving claimants verifiable on-chain proof of
+ * claim that does not depend on RPCs retaining events.
+ * 
+ * Access Control: Only the owner can enable the claim log
+ * 
+ * Business Logic:
+ * - Only allowed before the first claim, so the log covers the full claim history
+ * - Irreversible, the log cannot be disabled afterwards
+ * - Claims then pass the current claim log page, created by the payer when needed
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct EnableClaimLog<'info> {
+    /// The distributor account to update
+    /// - Must not have any claims yet
+    /// - Will be modified to set claim_log
+    #[account(mut)]
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+    
+    /// The owner of the distributor
+    /// - Must match the owner stored in the distributor state
+    #[account(constraint = owner.key() == distributor.load()?.owner @ TokenDistributorError::OnlyOwner)]
+    pub owner: Signer<'info>,
+}
+
+/**
+ * Enables the claim log of a distributor
+ * 
+ * @param ctx - The account context containing distributor and owner accounts
+ */
+pub fn handle_enable_claim_log(ctx: Context<EnableClaimLog>) -> Result<()> {
+    let distributor_key = ctx.accounts.distributor.key();
+    let distributor = &mut ctx.accounts.distributor.load_mut()?;
+    
+    require!(!distributor.has_claim_log(), TokenDistributorError::ClaimLogAlreadyEnabled);
+    require!(distributor.claims_count == 0, TokenDistributorError::ClaimsAlreadyMade);
+    
+    distributor.claim_log = 1;
+    
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(ClaimLogEnabled {
+        distributor: distributor_key,
+        owner: ctx.accounts.owner.key(),
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use crate::state::*;
+use crate::error::*;
+use crate::constants::*;
+use crate::utils::load_program_config;
+use crate::event::*;
+use super::sync_registry::is_registry_authority;
+
+/**
+ * Account context for evicting a distributor from its mint registry
+ *
+ * This instruction removes a registry entry that no longer lists a claimable
+ * airdrop, e.g. of a distributor closed without passing its registry, and lets
+ * the token's issuer take down listings they do not endorse.
+ *
+ * Access Control: Permissionless for distributors that are closed, completed,
+ * ended or whose vault is closed; the mint authority or the program config
+ * admin can evict any entry
+ *
+ * Business Logic:
+ * - The distributor must be listed in the registry
+ * - The distributor account may already be closed
+ * - Frees the entry's slot for a new listing
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct EvictRegistryEntry<'info> {
+    /// The registry to evict the distributor from
+    /// - Derived from: ["registry", token_mint]
+    #[account(
+        mut,
+        seeds = [REGISTRY_SEED.as_bytes(), token_mint.key().as_ref()],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, MintRegistry>,
+
+    /// The listed distributor to evict
+    /// CHECK: Only read when it is still a distributor, a closed account counts as closed
+    pub distributor: UncheckedAccount<'info>,
+
+    /// The token mint of the registry
+    /// - Its mint authority may evict any entry
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    /// The mint authority of the token mint, or the program config admin
+    /// - Required only to evict a distributor that is still live
+    pub authority: Option<Signer<'info>>,
+
+    /// Program config holding the admin
+    /// CHECK: Derived from ["config"], either uninitialized or a valid ProgramConfig
+    #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+    pub config: UncheckedAccount<'info>,
+}
+
+/**
+ * Removes a distributor from its mint registry
+ *
+ * @param ctx - The account context containing registry, distributor, mint and authority accounts
+ *
+ * Process:
+ * 1. Verify the distributor is listed
+ * 2. Require the authority unless the distributor is closed, completed, ended or its vault closed
+ * 3. Clear the distributor's entry
+ */
+pub fn handle_evict_registry_entry(ctx: Context<EvictRegistryEntry>) -> Result<()> {
+    let distributor_key = ctx.accounts.distributor.key();
+    require!(ctx.accounts.registry.is_listed(&distributor_key), TokenDistributorError::RegistryEntryNotFound);
+
+    // Listed keys were distributors, one that is no longer a distributor account has been closed
+    let distributor_info = ctx.accounts.distributor.to_account_info();
+    let live = distributor_info.owner == &crate::ID && {
+        // Read the distributor in place as close_claim_status does, it is too large to copy onto the stack
+        let distributor_data = distributor_info.try_borrow_data()?;
+        distributor_data.len() >= TokenDistributor::LEN
+            && distributor_data[..TokenDistributor::DISCRIMINATOR.len()] == *TokenDistributor::DISCRIMINATOR
+            && {
+                let distributor: &TokenDistributor =
+                    bytemuck::from_bytes(&distributor_data[TokenDistributor::DISCRIMINATOR.len()..TokenDistributor::LEN]);
+                !distributor.is_completed()
+                    && !distributor.is_vault_closed()
+                    && distributor.end_time > distributor.activation_now(&Clock::get()?)
+            }
+    };
+
+    // Only the token's issuer or the protocol take down live listings
+    if live {
+        let config = load_program_config(&ctx.accounts.config)?;
+        let authorized = ctx.accounts.authority.as_ref().is_some_and(|authority| {
+            is_registry_authority(&ctx.accounts.token_mint, &config, &authority.key())
+        });
+        require!(authorized, TokenDistributorError::RegistryUnauthorized);
+    }
+
+    ctx.accounts.registry.remove(&distributor_key);
+
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(RegistryEntryRemoved {
+        registry: ctx.accounts.registry.key(),
+        distributor: distributor_key,
+        token_mint: ctx.accounts.token_mint.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::constants::*;
+use crate::event::*;
+use crate::utils::load_program_config;
+
+/**
+ * Account context for extending the distribution end time
+ * 
+ * This instruction allows a listed operator to push the end time of a live
+ * distribution further out when claims are slower than expected, keeping all
+ * existing ClaimStatus state instead of launching a new distributor.
+ * 
+ * Access Control: Only a listed operator can extend the end time
+ * 
+ * Business Logic:
+ * - Only allowed while the distribution is live (start_time <= now <= end_time)
+ * - End time can only move later, never earlier
+ * - Total duration (end_time - start_time) is capped at the configured maximum duration
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExtendTime<'info> {
+    /// The distributor account to update
+    /// - Must be a valid existing distributor PDA
+    /// - Will be modified to set end_time and duration
+    #[account(mut)]
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+    
+    /// An operator who can extend the end time
+    /// - Must be one of the operators listed in the distributor state
+    /// - Only listed operators can call this instruction
+    #[account(constraint = distributor.load()?.is_operator(&operator.key()) @ TokenDistributorError::OnlyOperator)]
+    pub operator: Signer<'info>,
+    
+    /// Program config holding the global pause switch and default parameters
+    /// CHECK: Derived from ["config"], either uninitialized or a valid ProgramConfig
+    #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+    pub config: UncheckedAccount<'info>,
+}
+
+/**
+ * Extends the end time of an active distribution
+ * 
+ * @param ctx - The account context containing distributor and operator accounts
+ * @param new_end_time - Unix timestamp (slot in slot mode) when the distribution should now end
+ * 
+ */
+pub fn handle_extend_time(
+    ctx: Context<ExtendTime>,
+    new_end_time: i64,
+) -> Result<()> {
+    let distributor_key = ctx.accounts.distributor.key();
+    let distributor = &mut ctx.accounts.distributor.load_mut()?;
+    
+    // Finalized distributors keep their terms for good
+    require!(!distributor.is_finalized(), TokenDistributorError::DistributorFinalized);
+    
+    // Validate the distribution is live
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+    let activation_now = distributor.activation_now(&clock);
+    require!(distributor.start_time > 0, TokenDistributorError::StartTimeNotSet);
+    require!(activation_now >= distributor.start_time, TokenDistributorError::DistributionNotStarted);
+    require!(activation_now <= distributor.end_time, TokenDistributorError::DistributionEnded);
+    
+    // End time can only be pushed later
+    require!(new_end_time > distributor.end_time, TokenDistributorError::InvalidEndTime);
+    
+    // Total duration cannot exceed the configured maximum
+    let new_duration = new_end_time
+        .checked_sub(distributor.start_time)
+        .ok_or(TokenDistributorError::ArithmeticOverflow)?;
+    let config = load_program_config(&ctx.accounts.config)?;
+    require!(
+        new_duration <= distributor.activation_type().period(config.max_duration),
+        TokenDistributorError::InvalidDuration
+    );
+    
+    let previous_end_time = distributor.end_time;
+    distributor.end_time = new_end_time;
+    distributor.duration = new_duration;
+    distributor.operator_last_action_ts = current_time;
+    distributor.time_update_count = distributor.time_update_count
+        .checked_add(1)
+        .ok_or(TokenDistributorError::ArithmeticOverflow)?;
+    
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(EndTimeExtended {
+        distributor: distributor_key,
+        operator: ctx.accounts.operator.key(),
+        previous_end_time,
+        end_time: new_end_time,
+        withdraw_unlocks_at: distributor.withdraw_unlocks_at(),
+        update_index: distributor.time_update_count,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::event::*;
+
+/**
+ * Account context for finalizing a distributor
+ * 
+ * This instruction allows the distributor owner to permanently lock the
+ * merkle root, timing and claim fee, guaranteeing the airdrop terms cannot
+ * be altered mid-flight.
+ * 
+ * Access Control: Only the owner can finalize the distributor
+ * 
+ * Business Logic:
+ * - The merkle root and start time must be set
+ * - Finalizing is irreversible, set_merkle_root, set_time, extend_time and set_claim_fee are rejected afterwards
+ * - Pausing, claims and withdrawals are unaffected
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Finalize<'info> {
+    /// The distributor account to finalize
+    /// - Will be modified to set the finalized flag
+    #[account(mut)]
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+    
+    /// The owner of the distributor
+    /// - Must match the owner stored in the distributor state
+    #[account(constraint = owner.key() == distributor.load()?.owner @ TokenDistributorError::OnlyOwner)]
+    pub owner: Signer<'info>,
+}
+
+/**
+ * Permanently locks the merkle root and timing of the distributor
+ * 
+ * @param ctx - The account context containing distributor and owner accounts
+ */
+pub fn handle_finalize(ctx: Context<Finalize>) -> Result<()> {
+    let distributor_key = ctx.accounts.distributor.key();
+    let distributor = &mut ctx.accounts.distributor.load_mut()?;
+    
+    require!(!distributor.is_finalized(), TokenDistributorError::DistributorFinalized);
+    
+    // Only complete terms can be locked
+    require!(distributor.merkle_root != [0; 32], TokenDistributorError::NoMerkleRoot);
+    require!(distributor.start_time > 0, TokenDistributorError::StartTimeNotSet);
+    
+    distributor.finalized = 1;
+    
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(DistributorFinalized {
+        distributor: distributor_key,
+        owner: ctx.accounts.owner.key(),
+        merkle_root: distributor.merkle_root,
+        start_time: distributor.start_time,
+        end_time: distributor.end_time,
+    });
+    
+    Ok(())
+}
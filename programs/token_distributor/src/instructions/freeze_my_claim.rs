@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::event::*;
+use crate::constants::*;
+
+/**
+ * Account context for freezing a claim
+ * 
+ * This instruction allows a claimant to block further claims for their address,
+ * e.g. after leaking their private key before claiming. The claim stays frozen
+ * until the claimant unfreezes it after the UNFREEZE_TIMELOCK has elapsed, with
+ * the owner or an operator co-signing.
+ * 
+ * Access Control: Claimant only (enforced by PDA seeds)
+ * 
+ * Business Logic:
+ * - Creates the ClaimStatus account if the claimant has not claimed yet
+ * - Freezing an already frozen claim restarts the timelock
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FreezeMyClaim<'info> {
+    /// The distributor account the claim belongs to
+    /// - Must be a valid existing distributor PDA
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+    
+    /// Individual claim status for this claimant
+    /// - Derived from: ["claim", distributor_key, claimant_key]
+    /// - Will be modified to set the frozen flag
+    #[account(
+        init_if_needed,
+        payer = claimant,
+        space = ClaimStatus::LEN,
+        seeds = [CLAIM_SEED.as_bytes(), distributor.key().as_ref(), claimant.key().as_ref()],
+        bump
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+    
+    /// The claimant freezing their claim
+    /// - Must sign the transaction
+    /// - Pays for ClaimStatus creation if needed
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+    
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/**
+ * Freezes the claimant's claim
+ * 
+ * @param ctx - The account context containing distributor, claim status and claimant accounts
+ * 
+ */
+pub fn handle_freeze_my_claim(ctx: Context<FreezeMyClaim>) -> Result<()> {
+    let claim_status = &mut ctx.accounts.claim_status;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    // Record the rent payer when the claim status is first created
+    if claim_status.rent_payer == Pubkey::default() {
+        claim_status.rent_payer = ctx.accounts.claimant.key();
+    }
+    
+    // Block further claims and (re)start the unfreeze timelock
+    claim_status.frozen = true;
+    claim_status.frozen_at = current_time;
+    
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(ClaimFrozen {
+        distributor: ctx.accounts.distributor.key(),
+        claimant: ctx.accounts.claimant.key(),
+        unfreeze_after: current_time + UNFREEZE_TIMELOCK,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::*;
+use crate::error::*;
+use crate::constants::*;
+use crate::event::*;
+
+/**
+ * Account context for funding the rent escrow
+ * 
+ * This instruction allows the owner to top up the distributor's rent escrow,
+ * a lamport-only PDA from which ClaimStatus rent is paid on behalf of claimants.
+ * 
+ * Access Control: Only the owner can fund the rent escrow
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FundRentEscrow<'info> {
+    /// The distributor the rent escrow belongs to
+    #[account(
+        constraint = distributor.load()?.owner == owner.key() @ TokenDistributorError::OnlyOwner
+    )]
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+    
+    /// Rent escrow holding lamports for ClaimStatus rent
+    /// - System-owned PDA without data
+    /// - Derived from: ["rent_escrow", distributor_key, owner]
+    #[account(
+        mut,
+        seeds = [RENT_ESCROW_SEED.as_bytes(), distributor.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub rent_escrow: SystemAccount<'info>,
+    
+    /// The owner of the distributor
+    /// - Pays the lamports deposited into the escrow
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    /// System program for the lamport transfer
+    pub system_program: Program<'info, System>,
+}
+
+/**
+ * Deposits lamports into the rent escrow
+ * 
+ * @param ctx - The account context containing distributor, rent escrow and owner accounts
+ * @param amount - Amount of lamports to deposit
+ */
+pub fn handle_fund_rent_escrow(ctx: Context<FundRentEscrow>, amount: u64) -> Result<()> {
+    require!(amount > 0, TokenDistributorError::InvalidAmount);
+    
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.owner.to_account_info(),
+                to: ctx.accounts.rent_escrow.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(RentEscrowFunded {
+        distributor: ctx.accounts.distributor.key(),
+        owner: ctx.accounts.owner.key(),
+        amount,
+        balance: ctx.accounts.rent_escrow.lamports(),
+    });
+    
+    Ok(())
+}
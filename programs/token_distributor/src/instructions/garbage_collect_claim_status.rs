@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::event::*;
+use crate::constants::*;
+use super::require_distribution_ended_for;
+
+/**
+ * Account context for garbage collecting a stale claim status account
+ *
+ * This instruction lets anyone close a ClaimStatus account that its claimant
+ * never closed, once the distribution ended long enough ago. The rent goes to
+ * whoever paid it during account creation, never to the caller.
+ *
+ * The rent payer is the claimant whenever the claimant paid for the account
+ * (claiming with themselves as payer, freeze_my_claim, set_claim_delegate).
+ * When the owner, a relayer or the rent escrow paid instead, the rent goes back
+ * to them rather than to the claimant, who never spent it, as with close_claim_status.
+ *
+ * Access Control: Permissionless after end_time + CLAIM_STATUS_GC_GRACE_PERIOD
+ * (or immediately once the distributor has been closed)
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct GarbageCollectClaimStatus<'info> {
+    /// ClaimStatus account to be closed, rent returned to the rent payer
+    /// - Must be a valid existing ClaimStatus account
+    /// - Derived from: ["claim", distributor_key, claimant_key]
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [CLAIM_SEED.as_bytes(), distributor_key.key().as_ref(), claimant.key().as_ref()],
+        bump
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+
+    /// The claimant the ClaimStatus account belongs to
+    /// CHECK: Only used for PDA derivation
+    pub claimant: UncheckedAccount<'info>,
+
+    /// The account that paid the rent for the ClaimStatus account
+    /// - Must match the rent payer recorded in the claim status
+    /// - Will receive the reclaimed rent
+    #[account(
+        mut,
+        constraint = rent_payer.key() == claim_status.rent_payer @ TokenDistributorError::RentPayerMismatch
+    )]
+    pub rent_payer: SystemAccount<'info>,
+
+    /// Distributor account used for PDA derivation and time validation
+    /// CHECK: Either closed or valid TokenDistributor
+    pub distributor_key: AccountInfo<'info>,
+
+    /// Anyone may submit the garbage collection
+    pub cranker: Signer<'info>,
+}
+
+/**
+ * Closes a stale ClaimStatus account and returns rent to the original rent payer
+ *
+ * @param ctx - The account context containing the ClaimStatus, claimant and rent payer accounts
+ *
+ * Validation Process:
+ * 1. Check that end_time + CLAIM_STATUS_GC_GRACE_PERIOD has passed (or the distributor is closed)
+ * 2. Anchor automatically transfers lamports to the rent payer and closes account
+ */
+pub fn handle_garbage_collect_claim_status(ctx: Context<GarbageCollectClaimStatus>) -> Result<()> {
+    require_distribution_ended_for(
+        &ctx.accounts.distributor_key,
+        CLAIM_STATUS_GC_GRACE_PERIOD,
+        TokenDistributorError::GarbageCollectionGracePeriodActive,
+    )?;
+
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(ClaimStatusGarbageCollected {
+        distributor: ctx.accounts.distributor_key.key(),
+        claimant: ctx.accounts.claimant.key(),
+        claimed_amount: ctx.accounts.claim_status.claimed_amount,
+        rent_payer: ctx.accounts.rent_payer.key(),
+        cranker: ctx.accounts.cranker.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::constants::*;
+use crate::event::*;
+
+/**
+ * Account context for initializing the program config
+ * 
+ * This instruction creates the singleton ProgramConfig PDA with the default
+ * parameters and sets the global admin.
+ * 
+ * Access Control: Only the program upgrade authority can initialize the config
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    /// The program config account (PDA)
+    /// - Derived from: ["config"]
+    #[account(
+        init,
+        payer = authority,
+        space = ProgramConfig::LEN,
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    
+    /// The program upgrade authority
+    /// - Must match the upgrade authority recorded in the program data account
+    /// - Pays for the config account creation
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// This program, used to locate its program data account
+    #[account(constraint = token_distributor_program.programdata_address()? == Some(program_data.key()) @ TokenDistributorError::OnlyUpgradeAuthority)]
+    pub token_distributor_program: Program<'info, crate::program::TokenDistributor>,
+    
+    /// Program data account of this program, holding the upgrade authority
+    #[account(constraint = program_data.upgrade_authority_address == Some(authority.key()) @ TokenDistributorError::OnlyUpgradeAuthority)]
+    pub program_data: Account<'info, ProgramData>,
+    
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/**
+ * Initializes the program config with the default parameters
+ * 
+ * @param ctx - The account context containing config, authority and program accounts
+ * @param admin - Global admin who can update the config and toggle the global pause
+ */
+pub fn handle_initialize_config(ctx: Context<InitializeConfig>, admin: Pubkey) -> Result<()> {
+    require!(admin != Pubkey::default(), TokenDistributorError::InvalidConfig);
+    
+    let config = &mut ctx.accounts.config;
+    config.set_inner(ProgramConfig {
+        bump: ctx.bumps.config,
+        admin,
+        ..ProgramConfig::with_defaults()
+    });
+    
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(ProgramConfigInitialized {
+        config: config.key(),
+        admin,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::Discriminator;
+use crate::state::*;
+use crate::error::*;
+use crate::constants::*;
+use crate::event::*;
+
+/**
+ * Account context for migrating a claim status to the current layout
+ *
+ * Claim statuses created before freezing, delegation and the other per-claimant
+ * fields were added hold only the claimed amount (ClaimStatus::LEGACY_LEN bytes)
+ * and no longer deserialize, so the claimant can neither claim the rest of their
+ * allocation nor close the account and get the rent back until it is migrated.
+ *
+ * Access Control: Anyone can migrate a claim status, the payer funds the extra rent
+ *
+ * Business Logic:
+ * - Grows the account to ClaimStatus::LEN, keeping the claimed amount
+ * - The new fields start zeroed: not frozen, no delegate, no voucher used,
+ *   nothing over-claimed and no verified leaf
+ * - The claimant paid for legacy accounts, so it is recorded as the rent payer
+ *   and gets the whole rent, including the top-up, back on close
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateClaimStatus<'info> {
+    /// Claim status account of the claimant, in a previous layout
+    /// - Derived from: ["claim", distributor_key, claimant_key]
+    /// CHECK: Program ownership, discriminator and length are checked in the handler
+    #[account(
+        mut,
+        seeds = [CLAIM_SEED.as_bytes(), distributor_key.key().as_ref(), claimant.key().as_ref()],
+        bump
+    )]
+    pub claim_status: UncheckedAccount<'info>,
+
+    /// Distributor account used for PDA derivation
+    /// CHECK: Only used for PDA derivation, may already be closed
+    pub distributor_key: UncheckedAccount<'info>,
+
+    /// The claimant the claim status belongs to
+    /// CHECK: Only used for PDA derivation
+    pub claimant: UncheckedAccount<'info>,
+
+    /// Pays the rent for the grown account
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program for the rent top-up
+    pub system_program: Program<'info, System>,
+}
+
+/**
+ * Migrates a claim status to the current layout
+ *
+ * @param ctx - The account context containing claim status, distributor, claimant and payer accounts
+ *
+ * Validation Rules:
+ * - The account must be a claim status owned by this program
+ * - The account must be smaller than the current layout
+ */
+pub fn handle_migrate_claim_status(ctx: Context<MigrateClaimStatus>) -> Result<()> {
+    let claim_status_info = ctx.accounts.claim_status.to_account_info();
+
+    require_keys_eq!(*claim_status_info.owner, crate::ID, ErrorCode::AccountOwnedByWrongProgram);
+    require!(
+        claim_status_info.data_len() >= ClaimStatus::LEGACY_LEN
+            && claim_status_info.try_borrow_data()?.starts_with(ClaimStatus::DISCRIMINATOR),
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+    require!(
+        claim_status_info.data_len() < ClaimStatus::LEN,
+        TokenDistributorError::ClaimStatusUpToDate
+    );
+
+    // Top up the rent for the new size, then grow the account with zeroed fields
+    let rent_due = Rent::get()?
+        .minimum_balance(ClaimStatus::LEN)
+        .saturating_sub(claim_status_info.lamports());
+    if rent_due > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: claim_status_info.clone(),
+                },
+            ),
+            rent_due,
+        )?;
+    }
+    claim_status_info.realloc(ClaimStatus::LEN, true)?;
+
+    // Legacy accounts were always paid for by the claimant
+    let mut claim_status = ClaimStatus::try_deserialize(&mut &claim_status_info.try_borrow_data()?[..])?;
+    if claim_status.rent_payer == Pubkey::default() {
+        claim_status.rent_payer = ctx.accounts.claimant.key();
+    }
+    claim_status.try_serialize(&mut &mut claim_status_info.try_borrow_mut_data()?[..])?;
+
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(ClaimStatusMigrated {
+        distributor: ctx.accounts.distributor_key.key(),
+        claimant: ctx.accounts.claimant.key(),
+        payer: ctx.accounts.payer.key(),
+        claimed_amount: claim_status.claimed_amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::Discriminator;
+use crate::state::*;
+use crate::error::*;
+use crate::constants::*;
+use crate::event::*;
+
+/**
+ * Account context for migrating an owner's nonce state to the current layout
+ *
+ * Nonce states created before active distributor limits only hold the nonce
+ * counter (NonceState::LEGACY_LEN bytes) and no longer deserialize, so the
+ * owner cannot create, withdraw or claw back distributors until it is migrated.
+ *
+ * Access Control: Anyone can migrate a nonce state, the payer funds the extra rent
+ *
+ * Business Logic:
+ * - Grows the account to NonceState::LEN, keeping the nonce counter
+ * - The active distributor count starts at 0, distributors created before the
+ *   migration are not counted against the limit
+ * - No owner-specific limit is set, the global limit applies
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateOwnerNonce<'info> {
+    /// Nonce state account of the owner, in the legacy layout
+    /// - Derived from: ["owner_nonce", owner]
+    /// CHECK: Program ownership, discriminator and length are checked in the handler
+    #[account(
+        mut,
+        seeds = [OWNER_NONCE_SEED.as_bytes(), owner.key().as_ref()],
+        bump
+    )]
+    pub owner_nonce: UncheckedAccount<'info>,
+
+    /// The owner of the nonce state
+    /// CHECK: Only used for PDA derivation
+    pub owner: UncheckedAccount<'info>,
+
+    /// Pays the rent for the grown account
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program for the rent top-up
+    pub system_program: Program<'info, System>,
+}
+
+/**
+ * Migrates an owner's nonce state to the current layout
+ *
+ * @param ctx - The account context containing owner nonce, owner and payer accounts
+ *
+ * Validation Rules:
+ * - The account must be a nonce state owned by this program
+ * - The account must still be in the legacy layout
+ */
+pub fn handle_migrate_owner_nonce(ctx: Context<MigrateOwnerNonce>) -> Result<()> {
+    let owner_nonce = ctx.accounts.owner_nonce.to_account_info();
+
+    require_keys_eq!(*owner_nonce.owner, crate::ID, ErrorCode::AccountOwnedByWrongProgram);
+    require!(
+        owner_nonce.data_len() >= NonceState::LEGACY_LEN
+            && owner_nonce.try_borrow_data()?.starts_with(NonceState::DISCRIMINATOR),
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+    require!(
+        owner_nonce.data_len() < NonceState::LEN,
+        TokenDistributorError::NonceStateUpToDate
+    );
+
+    // Top up the rent for the new size, then grow the account with zeroed fields
+    let rent_due = Rent::get()?
+        .minimum_balance(NonceState::LEN)
+        .saturating_sub(owner_nonce.lamports());
+    if rent_due > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: owner_nonce.clone(),
+                },
+            ),
+            rent_due,
+        )?;
+    }
+    owner_nonce.realloc(NonceState::LEN, true)?;
+
+    let nonce_state = NonceState::try_deserialize(&mut &owner_nonce.try_borrow_data()?[..])?;
+
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(OwnerNonceMigrated {
+        owner: ctx.accounts.owner.key(),
+        payer: ctx.accounts.payer.key(),
+        nonce: nonce_state.nonce,
+    });
+
+    Ok(())
+}
@@ -1,13 +1,165 @@
+pub mod initialize_config;
+pub mod update_config;
+pub mod set_global_pause;
+pub mod set_owner_distributor_limit;
+pub mod migrate_owner_nonce;
+pub mod migrate_claim_status;
 pub mod create_distributor;
+pub mod create_distributor_with_nonce;
+pub mod create_sol_distributor;
+pub mod create_shared_vault;
+pub mod create_shared_distributor;
+#[cfg(feature = "multi-mint")]
+pub mod add_distributor_mint;
 pub mod set_merkle_root;
+pub mod set_root_update_interval;
+#[cfg(feature = "claim-fee")]
+pub mod set_claim_fee;
+#[cfg(feature = "claim-gates")]
+pub mod set_claim_gate;
+#[cfg(feature = "claim-gates")]
+pub mod set_claim_attestation;
+#[cfg(feature = "claim-gates")]
+pub mod attest;
+#[cfg(feature = "claim-gates")]
+pub mod revoke_attestation;
+pub mod set_claim_limits;
+#[cfg(feature = "claim-log")]
+pub mod enable_claim_log;
+#[cfg(feature = "claim-escrow")]
+pub mod enable_claim_escrow;
+pub mod set_early_bonus;
+pub mod set_require_cosign;
+pub mod set_distributor_metadata;
+pub mod finalize;
 pub mod set_time;
+pub mod extend_time;
+pub mod set_claim_windows;
+pub mod sync_registry;
+pub mod evict_registry_entry;
 pub mod claim;
+pub mod claim_batch;
+#[cfg(feature = "claim-with-signature")]
+pub mod claim_with_signature;
+pub mod claim_sol;
+#[cfg(feature = "multi-mint")]
+pub mod claim_multi_mint;
+pub mod claim_to_recipient;
+pub mod redirect_frozen_claim;
+#[cfg(feature = "claim-escrow")]
+pub mod release;
+pub mod verify_allocation;
+pub mod quote_claim;
+pub mod adjust_claim_status;
 pub mod withdraw;
+pub mod withdraw_partial;
+pub mod withdraw_sol;
+pub mod close_vault;
+#[cfg(feature = "multi-mint")]
+pub mod withdraw_distributor_mint;
+#[cfg(feature = "claim-fee")]
+pub mod withdraw_claim_fees;
+pub mod clawback;
 pub mod close_claim_status;
+pub mod close_claim_status_many;
+pub mod garbage_collect_claim_status;
+pub mod fund_rent_escrow;
+pub mod create_claim_status;
+pub mod withdraw_rent_escrow;
+pub mod close_shared_distributor;
+pub mod close_distributor_metadata;
+pub mod withdraw_shared_vault;
+pub mod add_operator;
+pub mod remove_operator;
+#[cfg(feature = "claim-with-signature")]
+pub mod add_relayer;
+#[cfg(feature = "claim-with-signature")]
+pub mod remove_relayer;
+pub mod pause;
+pub mod unpause;
+pub mod freeze_my_claim;
+pub mod unfreeze_my_claim;
+pub mod set_claim_delegate;
 
+pub use initialize_config::*;
+pub use update_config::*;
+pub use set_global_pause::*;
+pub use set_owner_distributor_limit::*;
+pub use migrate_owner_nonce::*;
+pub use migrate_claim_status::*;
 pub use create_distributor::*;
+pub use create_distributor_with_nonce::*;
+pub use create_sol_distributor::*;
+pub use create_shared_vault::*;
+pub use create_shared_distributor::*;
+#[cfg(feature = "multi-mint")]
+pub use add_distributor_mint::*;
 pub use set_merkle_root::*;
+pub use set_root_update_interval::*;
+#[cfg(feature = "claim-fee")]
+pub use set_claim_fee::*;
+#[cfg(feature = "claim-gates")]
+pub use set_claim_gate::*;
+#[cfg(feature = "claim-gates")]
+pub use set_claim_attestation::*;
+#[cfg(feature = "claim-gates")]
+pub use attest::*;
+#[cfg(feature = "claim-gates")]
+pub use revoke_attestation::*;
+pub use set_claim_limits::*;
+#[cfg(feature = "claim-log")]
+pub use enable_claim_log::*;
+#[cfg(feature = "claim-escrow")]
+pub use enable_claim_escrow::*;
+pub use set_early_bonus::*;
+pub use set_require_cosign::*;
+pub use set_distributor_metadata::*;
+pub use finalize::*;
 pub use set_time::*;
+pub use extend_time::*;
+pub use set_claim_windows::*;
+pub use sync_registry::*;
+pub use evict_registry_entry::*;
 pub use claim::*;
+pub use claim_batch::*;
+#[cfg(feature = "claim-with-signature")]
+pub use claim_with_signature::*;
+pub use claim_sol::*;
+#[cfg(feature = "multi-mint")]
+pub use claim_multi_mint::*;
+pub use claim_to_recipient::*;
+pub use redirect_frozen_claim::*;
+#[cfg(feature = "claim-escrow")]
+pub use release::*;
+pub use verify_allocation::*;
+pub use quote_claim::*;
+pub use adjust_claim_status::*;
 pub use withdraw::*;
-pub use close_claim_status::*;
\ No newline at end of file
+pub use withdraw_partial::*;
+pub use withdraw_sol::*;
+pub use close_vault::*;
+#[cfg(feature = "multi-mint")]
+pub use withdraw_distributor_mint::*;
+#[cfg(feature = "claim-fee")]
+pub use withdraw_claim_fees::*;
+pub use clawback::*;
+pub use close_claim_status::*;
+pub use close_claim_status_many::*;
+pub use garbage_collect_claim_status::*;
+pub use fund_rent_escrow::*;
+pub use create_claim_status::*;
+pub use withdraw_rent_escrow::*;
+pub use close_shared_distributor::*;
+pub use close_distributor_metadata::*;
+pub use withdraw_shared_vault::*;
+pub use add_operator::*;
+pub use remove_operator::*;
+#[cfg(feature = "claim-with-signature")]
+pub use add_relayer::*;
+#[cfg(feature = "claim-with-signature")]
+pub use remove_relayer::*;
+pub use pause::*;
+pub use unpause::*;
+pub use freeze_my_claim::*;
+pub use unfreeze_my_claim::*;
+pub use set_claim_delegate::*;
\ No newline at end of file
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::event::*;
+
+/**
+ * Account context for pausing the distribution
+ * 
+ * This instruction allows the owner or a listed operator to pause claims, e.g. when
+ * a bug in the off-chain merkle root generation is discovered. While paused, all
+ * claims are rejected until the distribution is unpaused.
+ * 
+ * Access Control: Owner or any listed operator
+ * 
+ * Business Logic:
+ * - Claims are rejected while paused
+ * - Time and merkle root can still be updated while paused
+ * - Pausing does not extend the distribution window
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Pause<'info> {
+    /// The distributor account to update
+    /// - Must be a valid existing distributor PDA
+    /// - Will be modified to set the paused flag
+    #[account(mut)]
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+    
+    /// The owner or operator pausing the distribution
+    /// - Must be the owner or one of the listed operators
+    #[account(
+        constraint = authority.key() == distributor.load()?.owner
+            || distributor.load()?.is_operator(&authority.key()) @ TokenDistributorError::OnlyOwnerOrOperator
+    )]
+    pub authority: Signer<'info>,
+}
+
+/**
+ * Pauses the token distribution
+ * 
+ * @param ctx - The account context containing distributor and authority accounts
+ * 
+ */
+pub fn handle_pause(ctx: Context<Pause>) -> Result<()> {
+    let distributor_key = ctx.accounts.distributor.key();
+    let distributor = &mut ctx.accounts.distributor.load_mut()?;
+    
+    // Pausing twice is a no-op that would only emit a misleading event
+    require!(!distributor.is_paused(), TokenDistributorError::DistributionPaused);
+    
+    distributor.paused = 1;
+    
+    // Only actions taken by an operator, not the owner, count as operator activity
+    if distributor.is_operator(&ctx.accounts.authority.key()) {
+        distributor.operator_last_action_ts = Clock::get()?.unix_timestamp;
+    }
+    
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(DistributionPaused {
+        distributor: distributor_key,
+        authority: ctx.accounts.authority.key(),
+    });
+    
+    Ok(())
+}
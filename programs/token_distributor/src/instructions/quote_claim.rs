@@ -0,0 +1,163 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::state::*;
+use crate::error::*;
+use crate::constants::*;
+use crate::utils::load_program_config;
+use super::process_claim;
+#[cfg(feature = "claim-gates")]
+use super::check_claim_gate;
+
+/**
+ * Account context for quoting a claim
+ *
+ * This read-only instruction runs the same validation as claim and reports
+ * what a claim would pay out right now. No tokens are moved and no account
+ * is written, the state updates are applied to copies and discarded.
+ *
+ * Access Control: Permissionless
+ *
+ * Business Logic:
+ * - Fails with the error claim would fail with (paused, window, proof, gates, limits)
+ * - Returns the claimable amount, early bonus and fees through the instruction return data
+ * - The claimant does not need to sign, any allocation can be quoted
+ */
+#[derive(Accounts)]
+pub struct QuoteClaim<'info> {
+    /// The distributor the allocation belongs to
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+
+    /// Individual claim status for this claimant
+    /// - Optional, the claimant is quoted as not having claimed yet without it
+    /// - Derived from: ["claim", distributor_key, claimant_key]
+    #[account(
+        seeds = [CLAIM_SEED.as_bytes(), distributor.key().as_ref(), claimant.key().as_ref()],
+        bump
+    )]
+    pub claim_status: Option<Account<'info, ClaimStatus>>,
+
+    /// Token vault paying out claims
+    /// - Required for token distributors, including shared distributors
+    #[account(address = distributor.load()?.token_vault @ TokenDistributorError::VaultMismatch)]
+    pub token_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Lamport vault paying out claims
+    /// - Required for native SOL distributors
+    /// - Derived from: ["sol_vault", distributor_key]
+    #[account(
+        seeds = [SOL_VAULT_SEED.as_bytes(), distributor.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: Option<SystemAccount<'info>>,
+
+    /// The claimant whose claim is quoted
+    /// CHECK: Only bound into the merkle leaf and used for PDA derivation
+    pub claimant: UncheckedAccount<'info>,
+
+    /// Claimant's token account of the distributor's gate mint
+    /// - Required only when the distributor gates claims on token holdings
+    #[cfg(feature = "claim-gates")]
+    pub gate_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Claimant's attestation from the distributor's attestor
+    /// - Required only when the distributor requires an attestation to claim
+    #[cfg(feature = "claim-gates")]
+    pub attestation: Option<Account<'info, Attestation>>,
+
+    /// Program config holding the global pause switch and protocol fee
+    /// CHECK: Derived from ["config"], either uninitialized or a valid ProgramConfig
+    #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+    pub config: UncheckedAccount<'info>,
+}
+
+/// Quote returned by quote_claim
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct ClaimQuote {
+    /// Amount a claim of the full pending amount would pay out now, before the protocol fee
+    pub claimable_amount: u64,
+    /// Part of the claimable amount that would go to the protocol fee receiver
+    pub protocol_fee: u64,
+    /// Lamport fee the claim would charge the payer
+    pub claim_fee: u64,
+    /// Amount the claimant has already claimed
+    pub claimed_amount: u64,
+    /// Early bonus the claim would pay on top of the claimable amount
+    pub bonus_amount: u64,
+}
+
+/**
+ * Quotes a claim without transferring tokens
+ *
+ * @param ctx - The account context containing distributor, claim status, vault and claimant accounts
+ * @param max_amount - Maximum amount this user is eligible to claim (from merkle tree)
+ * @param proof - Array of 32-byte hashes forming the merkle proof path
+ * @param unlock_time - The claimant's unlock time from the merkle tree (tree version 2 only)
+ *
+ * @returns Result<ClaimQuote> - The quote, also set as return data
+ */
+pub fn handle_quote_claim(
+    ctx: Context<QuoteClaim>,
+    max_amount: u64,
+    proof: Vec<[u8; 32]>,
+    unlock_time: Option<i64>,
+) -> Result<ClaimQuote> {
+    // Claims are rejected while the program is globally paused
+    let config = load_program_config(&ctx.accounts.config)?;
+    config.require_not_paused()?;
+
+    let distributor_key = ctx.accounts.distributor.key();
+    let claimant_key = ctx.accounts.claimant.key();
+
+    // Work on copies so the validation's state updates are never written
+    let mut distributor = *ctx.accounts.distributor.load()?;
+    let mut claim_status = ctx.accounts.claim_status.as_deref().cloned().unwrap_or_default();
+    let claimed_amount = claim_status.claimed_amount;
+
+    // Balance of the vault the claim would be paid from, as computed by claim and claim_sol
+    let vault_balance = if distributor.is_native() {
+        let sol_vault = ctx.accounts.sol_vault.as_ref().ok_or(TokenDistributorError::VaultMismatch)?;
+        sol_vault.lamports().saturating_sub(Rent::get()?.minimum_balance(0))
+    } else {
+        ctx.accounts.token_vault.as_ref().ok_or(TokenDistributorError::VaultMismatch)?.amount
+    };
+
+    #[cfg(feature = "claim-gates")]
+    check_claim_gate(
+        &distributor,
+        &claimant_key,
+        ctx.accounts.gate_token_account.as_deref(),
+        ctx.accounts.attestation.as_deref(),
+    )?;
+    let (claimable_amount, _) = process_claim(
+        &mut distributor,
+        distributor_key,
+        &mut claim_status,
+        &claimant_key,
+        vault_balance,
+        max_amount,
+        proof,
+        None,
+        unlock_time,
+        &[],
+        None,
+    )?;
+
+    // Claims in the bonus window earn the early bonus, capped as claim caps it
+    let activation_now = distributor.activation_now(&Clock::get()?);
+    let bonus_amount = distributor.early_bonus(activation_now, claimable_amount)
+        .min(vault_balance - claimable_amount);
+
+    // Fees compiled out of this build are quoted as 0
+    #[cfg(feature = "protocol-fee")]
+    let protocol_fee = config.protocol_fee(claimable_amount);
+    #[cfg(not(feature = "protocol-fee"))]
+    let protocol_fee = 0;
+
+    Ok(ClaimQuote {
+        claimable_amount,
+        protocol_fee,
+        claim_fee: distributor.claim_fee,
+        claimed_amount,
+        bonus_amount,
+    })
+}
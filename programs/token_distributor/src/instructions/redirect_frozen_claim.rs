@@ -0,0 +1,280 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::memo::Memo;
+use anchor_spl::token_interface::{TokenInterface, TokenAccount, Mint};
+use crate::state::*;
+use crate::error::*;
+use crate::constants::*;
+use crate::utils::{amount_scaling, load_program_config};
+use crate::event::*;
+use super::claim::{pay_claim, process_claim, complete_distribution};
+#[cfg(feature = "protocol-fee")]
+use super::claim::protocol_fee_for;
+
+/**
+ * Account context for redirecting the claim of a claimant whose token account is frozen
+ *
+ * Mints with a freeze authority, e.g. Token 2022 mints whose default account
+ * state is frozen, can leave a claimant unable to receive their allocation.
+ * This instruction allows a listed operator to settle such an allocation per
+ * the distributor's unclaimed token policy instead of leaving it stuck.
+ *
+ * Access Control: Only a listed operator can redirect a claim
+ *
+ * Business Logic:
+ * - The claimant's associated token account must exist and be frozen
+ * - Without the claimant's signature, the first call only reports the frozen
+ *   account, and the claim can be redirected FROZEN_CLAIM_GRACE_PERIOD later
+ * - A claimant signing the redirect consents to it, skipping the grace period
+ * - Rejected once the distributor is finalized and while the bonus window is open
+ * - The claim is validated as for claim, with the claimant's merkle proof
+ * - The full pending amount, less the protocol fee, goes to the clawback
+ *   receiver when one is configured, to the owner otherwise
+ * - The claim status records the amount as claimed, so it cannot be claimed again
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RedirectFrozenClaim<'info> {
+    /// The distributor account containing distribution parameters
+    /// - Will be modified to update total_claimed amount
+    #[account(mut)]
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+
+    /// Individual claim status for this claimant
+    /// - Tracks how much this user has already claimed
+    /// - Derived from: ["claim", distributor_key, claimant_key]
+    #[account(
+        init_if_needed,
+        payer = operator,
+        space = ClaimStatus::LEN,
+        seeds = [CLAIM_SEED.as_bytes(), distributor.key().as_ref(), claimant.key().as_ref()],
+        bump
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+
+    /// Token vault holding the tokens to be distributed
+    /// - Must match the vault stored in the distributor state
+    #[account(
+        mut,
+        address = distributor.load()?.token_vault
+    )]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// The claimant whose claim is redirected
+    /// - May sign to consent to the redirect, skipping the grace period
+    /// CHECK: Verified against the merkle leaf by the proof
+    pub claimant: UncheckedAccount<'info>,
+
+    /// The claimant's associated token account
+    /// - Must be frozen, proving the claimant cannot receive the tokens
+    #[account(
+        associated_token::mint = token_mint,
+        associated_token::authority = claimant,
+        associated_token::token_program = token_program,
+        constraint = claimant_token_account.is_frozen() @ TokenDistributorError::ClaimantAccountNotFrozen
+    )]
+    pub claimant_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Token account receiving the redirected tokens
+    /// - Must be owned by the clawback receiver if one is configured, by the owner otherwise
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::token_program = token_program,
+        constraint = receiver_token_account.owner == distributor.load()?.unclaimed_receiver() @ TokenDistributorError::ReceiverMismatch
+    )]
+    pub receiver_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// The token mint for verification
+    /// - Must match the distributor's token mint
+    /// - Must still report the decimals cached at creation
+    #[account(
+        token::token_program = token_program,
+        constraint = token_mint.key() == distributor.load()?.token_mint @ TokenDistributorError::TokenMintMismatch,
+        constraint = token_mint.decimals == distributor.load()?.decimals @ TokenDistributorError::MintDecimalsMismatch
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    /// An operator redirecting the claim
+    /// - Must be one of the operators listed in the distributor state
+    /// - Pays the rent if the ClaimStatus account is created
+    #[account(
+        mut,
+        constraint = distributor.load()?.is_operator(&operator.key()) @ TokenDistributorError::OnlyOperator
+    )]
+    pub operator: Signer<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Token program (supports both SPL Token and Token 2022)
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Associated token program, for the derivation of the claimant's token account
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// SPL Memo program
+    /// - Required only when the receiver token account requires incoming transfer memos
+    pub memo_program: Option<Program<'info, Memo>>,
+
+    /// Token account receiving the protocol fee
+    /// - Required only while the protocol fee is enabled
+    #[cfg(feature = "protocol-fee")]
+    #[account(
+        mut,
+        constraint = protocol_fee_account.mint == token_mint.key() @ TokenDistributorError::TokenMintMismatch
+    )]
+    pub protocol_fee_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Program config holding the global pause switch and default parameters
+    /// CHECK: Derived from ["config"], either uninitialized or a valid ProgramConfig
+    #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+    pub config: UncheckedAccount<'info>,
+}
+
+/**
+ * Redirects the pending allocation of a claimant whose token account is frozen
+ *
+ * @param ctx - The account context, with transfer hook accounts in remaining accounts
+ * @param max_amount - Maximum amount the claimant is eligible to claim (from merkle tree)
+ * @param proof - Array of 32-byte hashes forming the claimant's merkle proof path
+ * @param unlock_time - The claimant's unlock time from the merkle tree (tree version 2 only)
+ *
+ * Validation Process:
+ * 1. Verify the claimant's associated token account is frozen
+ * 2. Without the claimant's consent, report the frozen account on the first call
+ *    and require the grace period to have elapsed on later ones
+ * 3. Validate the claim as for claim, with the claimant's merkle proof
+ * 4. Transfer the pending amount, less the protocol fee, to the receiver
+ */
+pub fn handle_redirect_frozen_claim<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RedirectFrozenClaim<'info>>,
+    max_amount: u64,
+    proof: Vec<[u8; 32]>,
+    unlock_time: Option<i64>,
+) -> Result<()> {
+    // Reject all claims while the program is globally paused
+    let config = load_program_config(&ctx.accounts.config)?;
+    config.require_not_paused()?;
+
+    let distributor_key = ctx.accounts.distributor.key();
+    let claimant_key = ctx.accounts.claimant.key();
+    let vault_balance = ctx.accounts.token_vault.amount;
+
+    // Record the rent payer when the claim status is first created
+    if ctx.accounts.claim_status.rent_payer == Pubkey::default() {
+        ctx.accounts.claim_status.rent_payer = ctx.accounts.operator.key();
+    }
+
+    let clock = Clock::get()?;
+    {
+        let distributor = ctx.accounts.distributor.load()?;
+        // Finalized distributors keep their allocations for good
+        require!(!distributor.is_finalized(), TokenDistributorError::DistributorFinalized);
+        // Only claim pays the early bonus, so claims inside its window must go through it
+        require!(
+            !distributor.is_bonus_window_open(distributor.activation_now(&clock)),
+            TokenDistributorError::EarlyBonusClaimRequired
+        );
+    }
+
+    // Without the claimant's consent, the frozen account is reported first and the
+    // claim redirected after the grace period, giving the claimant time to get it thawed
+    if !ctx.accounts.claimant.is_signer {
+        let reported_at = ctx.accounts.claim_status.frozen_reported_at;
+        if reported_at == 0 {
+            ctx.accounts.claim_status.frozen_reported_at = clock.unix_timestamp;
+            emit_cpi!(FrozenClaimReported {
+                distributor: distributor_key,
+                claimant: claimant_key,
+                operator: ctx.accounts.operator.key(),
+                frozen_token_account: ctx.accounts.claimant_token_account.key(),
+                redirectable_at: clock.unix_timestamp.saturating_add(FROZEN_CLAIM_GRACE_PERIOD),
+            });
+            return Ok(());
+        }
+        require!(
+            clock.unix_timestamp >= reported_at.saturating_add(FROZEN_CLAIM_GRACE_PERIOD),
+            TokenDistributorError::FrozenClaimGracePeriod
+        );
+    }
+
+    // Validate the claim and update claim status and distributor totals
+    let (pending_amount, phase, new_total_claimed, completed) = {
+        let distributor = &mut ctx.accounts.distributor.load_mut()?;
+        // Claims of logged or escrowed distributors must go through claim
+        require!(!distributor.has_claim_log(), TokenDistributorError::ClaimLogRequired);
+        require!(!distributor.is_escrowed(), TokenDistributorError::ClaimEscrowRequired);
+        let (pending_amount, phase) = process_claim(
+            distributor,
+            distributor_key,
+            &mut ctx.accounts.claim_status,
+            &claimant_key,
+            vault_balance,
+            max_amount,
+            proof,
+            None,
+            unlock_time,
+            &[],
+            None,
+        )?;
+        let completed = complete_distribution(distributor, distributor_key, &claimant_key, vault_balance, pending_amount);
+        (pending_amount, phase, distributor.total_claimed, completed)
+    };
+
+    // Protocol fee is taken out of the claimed amount
+    #[cfg(feature = "protocol-fee")]
+    let (protocol_fee, protocol_fee_account) = (
+        protocol_fee_for(
+            &config,
+            ctx.accounts.protocol_fee_account.as_ref().map(|account| account.owner),
+            pending_amount,
+        )?,
+        ctx.accounts.protocol_fee_account.as_ref().map(|account| account.to_account_info()),
+    );
+    #[cfg(not(feature = "protocol-fee"))]
+    let (protocol_fee, protocol_fee_account) = (0, None);
+
+    // ===== INTERACTIONS PHASE (Token Transfer) =====
+
+    pay_claim(
+        &ctx.accounts.distributor,
+        None,
+        ctx.accounts.token_vault.to_account_info(),
+        ctx.accounts.receiver_token_account.to_account_info(),
+        &ctx.accounts.token_mint,
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.memo_program.as_ref().map(|p| p.to_account_info()),
+        protocol_fee_account,
+        pending_amount,
+        protocol_fee,
+        ctx.remaining_accounts,
+    )?;
+
+    // Emit events for off-chain indexing and monitoring
+    let scaling = amount_scaling(&ctx.accounts.token_mint.to_account_info(), ctx.accounts.token_mint.decimals)?;
+    emit_cpi!(TokensClaimed {
+        distributor: distributor_key,
+        claimant: claimant_key,
+        user_amount_claimed: pending_amount,
+        user_max_amount: max_amount,
+        total_claimed: new_total_claimed,
+        protocol_fee,
+        scaling,
+        phase,
+        bonus_amount: 0,
+    });
+    emit_cpi!(FrozenClaimRedirected {
+        distributor: distributor_key,
+        claimant: claimant_key,
+        operator: ctx.accounts.operator.key(),
+        frozen_token_account: ctx.accounts.claimant_token_account.key(),
+        receiver: ctx.accounts.receiver_token_account.owner,
+        amount: pending_amount - protocol_fee,
+    });
+    if let Some(completed) = completed {
+        emit_cpi!(completed);
+    }
+
+    Ok(())
+}
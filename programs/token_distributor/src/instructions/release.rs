@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+use anchor_spl::memo::Memo;
+use anchor_spl::token_interface::{TokenInterface, TokenAccount, Mint};
+use crate::state::*;
+use crate::error::*;
+use crate::constants::*;
+use crate::event::*;
+use crate::utils::{transfer_token_with_hook, memo_if_required};
+
+/**
+ * Account context for releasing escrowed tokens
+ * 
+ * This instruction allows a claimant of an escrowed distributor to pull the
+ * tokens of their claim escrow that have unlocked so far.
+ * 
+ * Access Control: Only the claimant can release their escrowed tokens
+ * 
+ * Business Logic:
+ * - Escrowed tokens unlock linearly over the release duration, starting at
+ *   the claimant's first claim
+ * - Works regardless of the distributor's pause state, window or closure,
+ *   the escrowed tokens already belong to the claimant
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Release<'info> {
+    /// The claimant's claim escrow
+    /// - Derived from: ["claim_escrow", distributor_key, claimant_key]
+    /// - Will be modified to update released_amount
+    #[account(
+        mut,
+        seeds = [CLAIM_ESCROW_SEED.as_bytes(), claim_escrow.distributor.as_ref(), claimant.key().as_ref()],
+        bump = claim_escrow.bump
+    )]
+    pub claim_escrow: Account<'info, ClaimEscrow>,
+    
+    /// Token account holding the escrowed tokens
+    /// - Derived from: ["escrow_vault", distributor_key, claimant_key]
+    #[account(
+        mut,
+        seeds = [ESCROW_VAULT_SEED.as_bytes(), claim_escrow.distributor.as_ref(), claimant.key().as_ref()],
+        bump
+    )]
+    pub escrow_vault: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Claimant's token account to receive the released tokens
+    /// - Must be owned by the claimant
+    /// - Must not be frozen (ClaimantAccountFrozen)
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = claimant,
+        token::token_program = token_program,
+        constraint = !claimant_token_account.is_frozen() @ TokenDistributorError::ClaimantAccountFrozen,
+    )]
+    pub claimant_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// The mint of the escrowed tokens
+    #[account(
+        token::token_program = token_program,
+        address = claim_escrow.token_mint @ TokenDistributorError::TokenMintMismatch
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    
+    /// The claimant the escrow belongs to
+    pub claimant: Signer<'info>,
+    
+    /// Token program (supports both SPL Token and Token 2022)
+    pub token_program: Interface<'info, TokenInterface>,
+    
+    /// SPL Memo program
+    /// - Required only when the claimant token account requires incoming transfer memos
+    ///   (Token 2022 MemoTransfer extension)
+    pub memo_program: Option<Program<'info, Memo>>,
+}
+
+/**
+ * Releases the unlocked tokens of a claim escrow to the claimant
+ * 
+ * @param ctx - The account context containing claim escrow, token and claimant accounts,
+ *              with transfer hook accounts in remaining accounts
+ */
+pub fn handle_release<'info>(ctx: Context<'_, '_, 'info, 'info, Release<'info>>) -> Result<()> {
+    let claim_escrow = &mut ctx.accounts.claim_escrow;
+    
+    let amount = claim_escrow.releasable_amount(Clock::get()?.unix_timestamp);
+    require!(amount > 0, TokenDistributorError::NothingToRelease);
+    
+    // Update the released amount (CEI pattern - effects before interactions)
+    claim_escrow.released_amount = claim_escrow.released_amount
+        .checked_add(amount)
+        .ok_or(TokenDistributorError::ArithmeticOverflow)?;
+    
+    let (distributor_key, claimant_key, bump) = (claim_escrow.distributor, claim_escrow.claimant, claim_escrow.bump);
+    let escrow_seeds: &[&[u8]] = &[
+        CLAIM_ESCROW_SEED.as_bytes(),
+        distributor_key.as_ref(),
+        claimant_key.as_ref(),
+        &[bump],
+    ];
+    
+    // Destinations with the MemoTransfer extension need a memo right before the transfer
+    memo_if_required(
+        &ctx.accounts.claimant_token_account.to_account_info(),
+        ctx.accounts.memo_program.as_ref().map(|p| p.to_account_info()),
+        CLAIM_MEMO,
+    )?;
+    
+    // Transfer the unlocked tokens with the claim escrow PDA as authority
+    transfer_token_with_hook(
+        ctx.accounts.claim_escrow.to_account_info(),
+        ctx.accounts.escrow_vault.to_account_info(),
+        ctx.accounts.claimant_token_account.to_account_info(),
+        ctx.accounts.token_mint.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        amount,
+        ctx.accounts.token_mint.decimals,
+        Some(&[escrow_seeds]),
+        ctx.remaining_accounts,
+    )?;
+    
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(TokensReleased {
+        distributor: distributor_key,
+        claimant: claimant_key,
+        claim_escrow: ctx.accounts.claim_escrow.key(),
+        amount,
+        released_amount: ctx.accounts.claim_escrow.released_amount,
+        escrowed_amount: ctx.accounts.claim_escrow.escrowed_amount,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::event::*;
+
+/**
+ * Account context for removing an operator
+ * 
+ * This instruction allows the distributor owner to remove an operator from the
+ * operator list, e.g. to rotate a compromised operator key.
+ * 
+ * Access Control: Only the owner can remove operators
+ * 
+ * Business Logic:
+ * - The operator slot is reset to Pubkey::default()
+ * - The removed operator immediately loses access to operator instructions
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RemoveOperator<'info> {
+    /// The distributor account to update
+    /// - Must be a valid existing distributor PDA
+    /// - Will be modified to remove the operator
+    #[account(mut)]
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+    
+    /// The owner of the distributor
+    /// - Must match the owner stored in the distributor state
+    /// - Only this account can call this instruction
+    #[account(constraint = owner.key() == distributor.load()?.owner @ TokenDistributorError::OnlyOwner)]
+    pub owner: Signer<'info>,
+}
+
+/**
+ * Removes an operator from the distributor
+ * 
+ * @param ctx - The account context containing distributor and owner accounts
+ * @param operator - Public key of the operator to remove
+ * 
+ */
+pub fn handle_remove_operator(
+    ctx: Context<RemoveOperator>,
+    operator: Pubkey,
+) -> Result<()> {
+    let distributor_key = ctx.accounts.distributor.key();
+    let distributor = &mut ctx.accounts.distributor.load_mut()?;
+    
+    // Locate the operator in the list
+    require!(distributor.is_operator(&operator), TokenDistributorError::OperatorNotFound);
+    
+    // Clear the operator slot
+    for key in distributor.operators.iter_mut() {
+        if *key == operator {
+            *key = Pubkey::default();
+        }
+    }
+    
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(OperatorRemoved {
+        distributor: distributor_key,
+        owner: ctx.accounts.owner.key(),
+        operator,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::event::*;
+
+/**
+ * Account context for removing a relayer
+ * 
+ * This instruction allows the distributor owner to remove a relayer from the
+ * relayer allowlist, e.g. to rotate a compromised relayer key.
+ * 
+ * Access Control: Only the owner can remove relayers
+ * 
+ * Business Logic:
+ * - The relayer slot is reset to Pubkey::default()
+ * - The removed relayer can no longer submit claim_with_signature
+ * - Removing the last relayer lets any relayer submit claim_with_signature again
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RemoveRelayer<'info> {
+    /// The distributor account to update
+    /// - Must be a valid existing distributor PDA
+    /// - Will be modified to remove the relayer
+    #[account(mut)]
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+    
+    /// The owner of the distributor
+    /// - Must match the owner stored in the distributor state
+    /// - Only this account can call this instruction
+    #[account(constraint = owner.key() == distributor.load()?.owner @ TokenDistributorError::OnlyOwner)]
+    pub owner: Signer<'info>,
+}
+
+/**
+ * Removes a relayer from the distributor's relayer allowlist
+ * 
+ * @param ctx - The account context containing distributor and owner accounts
+ * @param relayer - Public key of the relayer to remove
+ * 
+ */
+pub fn handle_remove_relayer(
+    ctx: Context<RemoveRelayer>,
+    relayer: Pubkey,
+) -> Result<()> {
+    let distributor_key = ctx.accounts.distributor.key();
+    let distributor = &mut ctx.accounts.distributor.load_mut()?;
+    
+    // Locate the relayer in the list
+    require!(distributor.is_relayer(&relayer), TokenDistributorError::RelayerNotFound);
+    
+    // Clear the relayer slot
+    for key in distributor.relayers.iter_mut() {
+        if *key == relayer {
+            *key = Pubkey::default();
+        }
+    }
+    
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(RelayerRemoved {
+        distributor: distributor_key,
+        owner: ctx.accounts.owner.key(),
+        relayer,
+    });
+    
+    Ok(())
+}
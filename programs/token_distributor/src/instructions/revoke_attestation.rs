@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::event::*;
+
+/**
+ * Account context for revoking an attestation
+ *
+ * This instruction lets an attestor withdraw an attestation it issued, e.g.
+ * after detecting a sybil wallet, so gated claims from the wallet fail.
+ *
+ * Access Control: Only the attestor that issued the attestation
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RevokeAttestation<'info> {
+    /// The attestation account to close
+    /// - Derived from: ["attestation", attestor, subject]
+    /// - Will be closed and rent returned to the attestor
+    #[account(
+        mut,
+        close = attestor,
+        seeds = [ATTESTATION_SEED.as_bytes(), attestor.key().as_ref(), attestation.subject.as_ref()],
+        bump = attestation.bump
+    )]
+    pub attestation: Account<'info, Attestation>,
+
+    /// The attestor that issued the attestation
+    /// - Receives the reclaimed rent
+    #[account(mut)]
+    pub attestor: Signer<'info>,
+}
+
+/**
+ * Revokes an attestation
+ *
+ * @param ctx - The account context containing attestation and attestor accounts
+ */
+pub fn handle_revoke_attestation(ctx: Context<RevokeAttestation>) -> Result<()> {
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(AttestationRevoked {
+        attestor: ctx.accounts.attestor.key(),
+        subject: ctx.accounts.attestation.subject,
+    });
+
+    // Note: The attestation account is closed by the close = attestor constraint
+    Ok(())
+}
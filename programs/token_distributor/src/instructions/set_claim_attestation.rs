@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::event::*;
+
+/**
+ * Account context for setting the claim attestation requirement
+ *
+ * This instruction allows the distributor owner to require claimants to hold
+ * an attestation from a chosen attestor, such as a provider scoring wallet age
+ * or activity, reducing farm-and-dump claims by sybil wallets.
+ *
+ * Access Control: Only the owner can set the attestation requirement
+ *
+ * Business Logic:
+ * - Attestations are checked at claim time, including their expiry
+ * - Passing no attestor clears the requirement
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetClaimAttestation<'info> {
+    /// The distributor account to update
+    /// - Will be modified to set attestor and min_attestation_score
+    #[account(mut)]
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+
+    /// The owner of the distributor
+    /// - Must match the owner stored in the distributor state
+    #[account(constraint = owner.key() == distributor.load()?.owner @ TokenDistributorError::OnlyOwner)]
+    pub owner: Signer<'info>,
+}
+
+/**
+ * Sets or clears the attestation required to claim
+ *
+ * @param ctx - The account context containing distributor and owner accounts
+ * @param attestor - Attestor claimants must hold an attestation from (None clears the requirement)
+ * @param min_attestation_score - Minimum attestation score a claimant must hold
+ */
+pub fn handle_set_claim_attestation(
+    ctx: Context<SetClaimAttestation>,
+    attestor: Option<Pubkey>,
+    min_attestation_score: u64,
+) -> Result<()> {
+    let distributor_key = ctx.accounts.distributor.key();
+    let distributor = &mut ctx.accounts.distributor.load_mut()?;
+
+    match attestor {
+        Some(attestor) if attestor != Pubkey::default() => {
+            distributor.attestor = attestor;
+            distributor.min_attestation_score = min_attestation_score;
+        }
+        _ => {
+            distributor.attestor = Pubkey::default();
+            distributor.min_attestation_score = 0;
+        }
+    }
+
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(ClaimAttestationSet {
+        distributor: distributor_key,
+        owner: ctx.accounts.owner.key(),
+        attestor: distributor.attestor,
+        min_attestation_score: distributor.min_attestation_score,
+    });
+
+    Ok(())
+}
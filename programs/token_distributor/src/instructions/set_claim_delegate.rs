@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::event::*;
+use crate::constants::*;
+
+/**
+ * Account context for setting a claim delegate
+ * 
+ * This instruction allows a claimant to authorize another key, such as an
+ * exchange acting as custodian, to execute claim on their behalf. Delegated
+ * claims still pay out to the claimant's token account.
+ * 
+ * Access Control: Claimant only (enforced by PDA seeds)
+ * 
+ * Business Logic:
+ * - Creates the ClaimStatus account if the claimant has not claimed yet
+ * - Replaces any previous delegate, passing no delegate revokes it
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetClaimDelegate<'info> {
+    /// The distributor account the claim belongs to
+    /// - Must be a valid existing distributor PDA
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+    
+    /// Individual claim status for this claimant
+    /// - Derived from: ["claim", distributor_key, claimant_key]
+    /// - Will be modified to set the delegate
+    #[account(
+        init_if_needed,
+        payer = claimant,
+        space = ClaimStatus::LEN,
+        seeds = [CLAIM_SEED.as_bytes(), distributor.key().as_ref(), claimant.key().as_ref()],
+        bump
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+    
+    /// The claimant delegating their claim
+    /// - Must sign the transaction
+    /// - Pays for ClaimStatus creation if needed
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+    
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/**
+ * Sets or clears the claimant's claim delegate
+ * 
+ * @param ctx - The account context containing distributor, claim status and claimant accounts
+ * @param delegate - Key allowed to claim for the claimant (None clears the delegate)
+ * 
+ */
+pub fn handle_set_claim_delegate(ctx: Context<SetClaimDelegate>, delegate: Option<Pubkey>) -> Result<()> {
+    let claim_status = &mut ctx.accounts.claim_status;
+    
+    // Record the rent payer when the claim status is first created
+    if claim_status.rent_payer == Pubkey::default() {
+        claim_status.rent_payer = ctx.accounts.claimant.key();
+    }
+    
+    claim_status.delegate = delegate.unwrap_or_default();
+    
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(ClaimDelegateSet {
+        distributor: ctx.accounts.distributor.key(),
+        claimant: ctx.accounts.claimant.key(),
+        delegate: claim_status.delegate,
+    });
+    
+    Ok(())
+}
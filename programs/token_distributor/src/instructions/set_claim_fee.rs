@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::constants::*;
+use crate::event::*;
+use crate::utils::load_program_config;
+
+/**
+ * Account context for setting the claim fee
+ * 
+ * This instruction allows the distributor owner to charge a lamport fee on
+ * each claim, paid by the claim's rent payer into the distributor account.
+ * 
+ * Access Control: Only the owner can set the claim fee
+ * 
+ * Business Logic:
+ * - A fee of 0 disables claim fees
+ * - The fee is capped at the configured maximum claim fee
+ * - The fee is part of the airdrop terms, so it is locked once finalized
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetClaimFee<'info> {
+    /// The distributor account to update
+    /// - Will be modified to set claim_fee
+    #[account(mut)]
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+    
+    /// The owner of the distributor
+    /// - Must match the owner stored in the distributor state
+    #[account(constraint = owner.key() == distributor.load()?.owner @ TokenDistributorError::OnlyOwner)]
+    pub owner: Signer<'info>,
+    
+    /// Program config holding the global pause switch and default parameters
+    /// CHECK: Derived from ["config"], either uninitialized or a valid ProgramConfig
+    #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+    pub config: UncheckedAccount<'info>,
+}
+
+/**
+ * Sets the lamport fee charged per claim
+ * 
+ * @param ctx - The account context containing distributor and owner accounts
+ * @param claim_fee - Fee in lamports (0 disables fees)
+ */
+pub fn handle_set_claim_fee(ctx: Context<SetClaimFee>, claim_fee: u64) -> Result<()> {
+    let distributor_key = ctx.accounts.distributor.key();
+    let distributor = &mut ctx.accounts.distributor.load_mut()?;
+    
+    // Finalized distributors keep their terms for good
+    require!(!distributor.is_finalized(), TokenDistributorError::DistributorFinalized);
+    
+    let config = load_program_config(&ctx.accounts.config)?;
+    require!(claim_fee <= config.max_claim_fee, TokenDistributorError::InvalidClaimFee);
+    
+    distributor.claim_fee = claim_fee;
+    
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(ClaimFeeSet {
+        distributor: distributor_key,
+        owner: ctx.accounts.owner.key(),
+        claim_fee,
+    });
+    
+    Ok(())
+}
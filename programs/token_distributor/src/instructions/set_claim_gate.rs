@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::event::*;
+
+/**
+ * Account context for setting the claim gate
+ * 
+ * This instruction allows the distributor owner to restrict claims to
+ * claimants holding a gating token, such as a membership NFT, without
+ * regenerating the merkle tree whenever holdings change.
+ * 
+ * Access Control: Only the owner can set the claim gate
+ * 
+ * Business Logic:
+ * - Holdings are checked at claim time from a token account of the claimant
+ * - A minimum amount of 0 is rejected while a gate mint is set
+ * - Passing no gate mint clears the gate
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetClaimGate<'info> {
+    /// The distributor account to update
+    /// - Will be modified to set gate_mint and gate_min_amount
+    #[account(mut)]
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+    
+    /// The owner of the distributor
+    /// - Must match the owner stored in the distributor state
+    #[account(constraint = owner.key() == distributor.load()?.owner @ TokenDistributorError::OnlyOwner)]
+    pub owner: Signer<'info>,
+}
+
+/**
+ * Sets or clears the gating token required to claim
+ * 
+ * @param ctx - The account context containing distributor and owner accounts
+ * @param gate_mint - Mint claimants must hold (None clears the gate)
+ * @param gate_min_amount - Minimum balance of the gate mint a claimant must hold
+ */
+pub fn handle_set_claim_gate(
+    ctx: Context<SetClaimGate>,
+    gate_mint: Option<Pubkey>,
+    gate_min_amount: u64,
+) -> Result<()> {
+    let distributor_key = ctx.accounts.distributor.key();
+    let distributor = &mut ctx.accounts.distributor.load_mut()?;
+    
+    match gate_mint {
+        Some(gate_mint) if gate_mint != Pubkey::default() => {
+            require!(gate_min_amount > 0, TokenDistributorError::InvalidAmount);
+            distributor.gate_mint = gate_mint;
+            distributor.gate_min_amount = gate_min_amount;
+        }
+        _ => {
+            distributor.gate_mint = Pubkey::default();
+            distributor.gate_min_amount = 0;
+        }
+    }
+    
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(ClaimGateSet {
+        distributor: distributor_key,
+        owner: ctx.accounts.owner.key(),
+        gate_mint: distributor.gate_mint,
+        gate_min_amount: distributor.gate_min_amount,
+    });
+    
+    Ok(())
+}
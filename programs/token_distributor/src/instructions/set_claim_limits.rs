@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::event::*;
+
+/**
+ * Account context for setting the claim limits
+ * 
+ * This instruction allows the distributor owner to cap what a single claimant
+ * can claim and how much can be claimed within one slot, so an off-chain tree
+ * bug that over-allocates someone cannot drain the vault before the
+ * distribution is paused or the root fixed.
+ * 
+ * Access Control: Only the owner can set the claim limits
+ * 
+ * Business Logic:
+ * - A limit of 0 disables it
+ * - The per-claimant cap applies to the cumulative claimed amount
+ * - The per-slot limit applies to the sum of all claims landing in a slot
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetClaimLimits<'info> {
+    /// The distributor account to update
+    /// - Will be modified to set max_claim_per_user and max_total_per_slot
+    #[account(mut)]
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+    
+    /// The owner of the distributor
+    /// - Must match the owner stored in the distributor state
+    #[account(constraint = owner.key() == distributor.load()?.owner @ TokenDistributorError::OnlyOwner)]
+    pub owner: Signer<'info>,
+}
+
+/**
+ * Sets the per-claimant cap and per-slot limit on claims
+ * 
+ * @param ctx - The account context containing distributor and owner accounts
+ * @param max_claim_per_user - Maximum cumulative amount per claimant (0 disables the cap)
+ * @param max_total_per_slot - Maximum total amount claimed per slot (0 disables the limit)
+ */
+pub fn handle_set_claim_limits(
+    ctx: Context<SetClaimLimits>,
+    max_claim_per_user: u64,
+    max_total_per_slot: u64,
+) -> Result<()> {
+    let distributor_key = ctx.accounts.distributor.key();
+    let distributor = &mut ctx.accounts.distributor.load_mut()?;
+    
+    distributor.max_claim_per_user = max_claim_per_user;
+    distributor.max_total_per_slot = max_total_per_slot;
+    
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(ClaimLimitsSet {
+        distributor: distributor_key,
+        owner: ctx.accounts.owner.key(),
+        max_claim_per_user,
+        max_total_per_slot,
+    });
+    
+    Ok(())
+}
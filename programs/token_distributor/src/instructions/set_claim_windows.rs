@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::constants::*;
+use crate::event::*;
+
+/**
+ * Account context for setting the claim windows of a phased distribution
+ *
+ * This instruction allows a listed operator to split the distribution into
+ * phases, e.g. a window for early users, a pause, then a window for everyone.
+ *
+ * Access Control: Only a listed operator can set the claim windows
+ *
+ * Business Logic:
+ * - Windows can be modified multiple times before distribution starts
+ * - Once distribution starts, windows cannot be modified anymore
+ * - Up to MAX_CLAIM_WINDOWS windows, sorted and non-overlapping
+ * - An empty list clears the windows, claims then follow start_time / end_time alone
+ * - Windows restrict claims further, they do not extend the distribution past end_time
+ * - In slot mode window bounds are slots, compared against Clock::slot
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetClaimWindows<'info> {
+    /// The distributor account to update
+    /// - Must be a valid existing distributor PDA
+    /// - Will be modified to set claim_windows
+    #[account(mut)]
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+
+    /// An operator who can set the claim windows
+    /// - Must be one of the operators listed in the distributor state
+    #[account(constraint = distributor.load()?.is_operator(&operator.key()) @ TokenDistributorError::OnlyOperator)]
+    pub operator: Signer<'info>,
+}
+
+/**
+ * Sets the claim windows of the distribution
+ *
+ * @param ctx - The account context containing distributor and operator accounts
+ * @param claim_windows - Claim windows in phase order, inclusive bounds in Unix
+ *                        timestamps (slots in slot mode)
+ *
+ */
+pub fn handle_set_claim_windows(
+    ctx: Context<SetClaimWindows>,
+    claim_windows: Vec<ClaimWindow>,
+) -> Result<()> {
+    let distributor_key = ctx.accounts.distributor.key();
+    let distributor = &mut ctx.accounts.distributor.load_mut()?;
+
+    // Finalized distributors keep their terms for good
+    require!(!distributor.is_finalized(), TokenDistributorError::DistributorFinalized);
+
+    // Claim windows are part of the terms claimants see before the start
+    let clock = Clock::get()?;
+    let activation_now = distributor.activation_now(&clock);
+    if distributor.start_time > 0 && activation_now >= distributor.start_time {
+        return err!(TokenDistributorError::DistributionAlreadyStarted);
+    }
+
+    // Each window must be non-empty and start after the previous one ended
+    require!(claim_windows.len() <= MAX_CLAIM_WINDOWS, TokenDistributorError::TooManyClaimWindows);
+    let mut previous_end = -1;
+    for window in claim_windows.iter() {
+        require!(
+            window.start > previous_end && window.end > window.start,
+            TokenDistributorError::InvalidClaimWindows
+        );
+        previous_end = window.end;
+    }
+
+    distributor.claim_windows = [ClaimWindow::default(); MAX_CLAIM_WINDOWS];
+    distributor.claim_windows[..claim_windows.len()].copy_from_slice(&claim_windows);
+    distributor.operator_last_action_ts = clock.unix_timestamp;
+
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(ClaimWindowsSet {
+        distributor: distributor_key,
+        operator: ctx.accounts.operator.key(),
+        claim_windows,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::constants::*;
+use crate::event::*;
+
+/**
+ * Account context for setting a distributor's metadata
+ *
+ * This instruction allows the distributor owner to give the campaign a display
+ * name and a URI to its off-chain metadata, which wallets and explorers render
+ * instead of the bare distributor PDA.
+ *
+ * Access Control: Only the owner can set the metadata
+ *
+ * Business Logic:
+ * - The metadata account is created on first use, usually in the same
+ *   transaction as create_distributor
+ * - Metadata can be changed until the distribution starts, so claimants
+ *   see the same campaign terms for its whole duration
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetDistributorMetadata<'info> {
+    /// The distributor the metadata describes
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+
+    /// The distributor's metadata account
+    /// - Derived from: ["metadata", distributor_key]
+    /// - Created on first use, paid by the owner
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = DistributorMetadata::LEN,
+        seeds = [METADATA_SEED.as_bytes(), distributor.key().as_ref()],
+        bump
+    )]
+    pub metadata: Account<'info, DistributorMetadata>,
+
+    /// The owner of the distributor
+    /// - Must match the owner stored in the distributor state
+    /// - Pays for the metadata account creation
+    #[account(
+        mut,
+        constraint = owner.key() == distributor.load()?.owner @ TokenDistributorError::OnlyOwner
+    )]
+    pub owner: Signer<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/**
+ * Sets the display name and metadata URI of a distributor
+ *
+ * @param ctx - The account context containing distributor, metadata and owner accounts
+ * @param name - Display name of the campaign (at most MAX_METADATA_NAME_LEN bytes)
+ * @param uri - URI of the campaign's off-chain metadata (at most MAX_METADATA_URI_LEN bytes)
+ */
+pub fn handle_set_distributor_metadata(
+    ctx: Context<SetDistributorMetadata>,
+    name: String,
+    uri: String,
+) -> Result<()> {
+    let distributor_key = ctx.accounts.distributor.key();
+    let distributor = &ctx.accounts.distributor.load()?;
+
+    require!(
+        name.len() <= MAX_METADATA_NAME_LEN && uri.len() <= MAX_METADATA_URI_LEN,
+        TokenDistributorError::MetadataTooLong
+    );
+
+    // Metadata is fixed once claimants can see the campaign running
+    let activation_now = distributor.activation_now(&Clock::get()?);
+    if distributor.start_time > 0 && activation_now >= distributor.start_time {
+        return err!(TokenDistributorError::DistributionAlreadyStarted);
+    }
+
+    let metadata = &mut ctx.accounts.metadata;
+    metadata.bump = ctx.bumps.metadata;
+    metadata.distributor = distributor_key;
+    metadata.owner = ctx.accounts.owner.key();
+    metadata.name = name;
+    metadata.uri = uri;
+
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(DistributorMetadataSet {
+        distributor: distributor_key,
+        metadata: metadata.key(),
+        owner: metadata.owner,
+        name: metadata.name.clone(),
+        uri: metadata.uri.clone(),
+    });
+
+    Ok(())
+}
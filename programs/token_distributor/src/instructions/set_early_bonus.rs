@@ -0,0 +1,147 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::*;
+use crate::error::*;
+use crate::constants::*;
+use crate::event::*;
+use crate::utils::transfer_token_with_hook;
+
+/**
+ * Account context for setting the early bonus
+ *
+ * This instruction allows the distributor owner to reward users who claim
+ * soon after the start, e.g. within the first 24 hours, with a bonus on top
+ * of their allocation, paid out of a bonus pool deposited into the vault.
+ *
+ * Access Control: Only the owner can set the early bonus
+ *
+ * Business Logic:
+ * - Can be modified multiple times before distribution starts
+ * - The bonus pool can only grow, raising it deposits the difference from the owner
+ * - Bonuses stop once the pool is spent, what is left of it is withdrawn with the vault
+ * - Only claim pays the bonus, other claim instructions are rejected while the window is open
+ * - Native SOL, shared, multi-mint and recipient distributors are rejected
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetEarlyBonus<'info> {
+    /// The distributor account to update
+    /// - Will be modified to set the early bonus and bonus pool
+    #[account(mut)]
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+
+    /// Token vault receiving the bonus pool deposit
+    /// - Must match the vault stored in the distributor state
+    #[account(
+        mut,
+        address = distributor.load()?.token_vault
+    )]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// The token mint for verification
+    /// - Must match the distributor's token mint
+    #[account(
+        token::token_program = token_program,
+        constraint = token_mint.key() == distributor.load()?.token_mint @ TokenDistributorError::TokenMintMismatch
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    /// Owner's token account funding the bonus pool
+    /// - Must be owned by the owner signer
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = owner,
+        token::token_program = token_program,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// The owner of the distributor
+    /// - Must match the owner stored in the distributor state
+    #[account(constraint = owner.key() == distributor.load()?.owner @ TokenDistributorError::OnlyOwner)]
+    pub owner: Signer<'info>,
+
+    /// Token program (supports both SPL Token and Token 2022)
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/**
+ * Sets the early bonus and funds its bonus pool
+ *
+ * @param ctx - The account context, with transfer hook accounts in remaining accounts
+ * @param early_bonus_bps - Bonus in basis points of the claimed amount (0 disables it)
+ * @param bonus_window - Period after the start during which claims earn the bonus,
+ *                       in seconds (slots in slot mode)
+ * @param bonus_pool - Tokens funding the bonuses, never lower than the current pool
+ */
+pub fn handle_set_early_bonus<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SetEarlyBonus<'info>>,
+    early_bonus_bps: u16,
+    bonus_window: i64,
+    bonus_pool: u64,
+) -> Result<()> {
+    let distributor_key = ctx.accounts.distributor.key();
+    let deposited = {
+        let distributor = &mut ctx.accounts.distributor.load_mut()?;
+
+        // Finalized distributors keep their terms for good
+        require!(!distributor.is_finalized(), TokenDistributorError::DistributorFinalized);
+
+        // The bonus is part of the terms claimants see before the start
+        let activation_now = distributor.activation_now(&Clock::get()?);
+        if distributor.start_time > 0 && activation_now >= distributor.start_time {
+            return err!(TokenDistributorError::DistributionAlreadyStarted);
+        }
+
+        // Only claim pays the bonus, out of the distributor's own vault
+        require!(
+            !distributor.is_native()
+                && !distributor.is_shared()
+                && distributor.tree_version != MULTI_MINT_TREE_VERSION
+                && distributor.tree_version != RECIPIENT_TREE_VERSION,
+            TokenDistributorError::EarlyBonusNotSupported
+        );
+        require!(
+            early_bonus_bps <= MAX_EARLY_BONUS_BPS
+                && bonus_window >= 0
+                && bonus_window <= distributor.duration
+                && (early_bonus_bps == 0 || bonus_window > 0),
+            TokenDistributorError::InvalidEarlyBonus
+        );
+        require!(bonus_pool >= distributor.bonus_pool, TokenDistributorError::BonusPoolReduced);
+
+        let deposited = bonus_pool - distributor.bonus_pool;
+        distributor.early_bonus_bps = early_bonus_bps as u64;
+        distributor.bonus_window = bonus_window;
+        distributor.bonus_pool = bonus_pool;
+        deposited
+    };
+
+    // ===== INTERACTIONS PHASE (Token Transfer) =====
+
+    if deposited > 0 {
+        transfer_token_with_hook(
+            ctx.accounts.owner.to_account_info(),
+            ctx.accounts.owner_token_account.to_account_info(),
+            ctx.accounts.token_vault.to_account_info(),
+            ctx.accounts.token_mint.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            deposited,
+            ctx.accounts.token_mint.decimals,
+            None, // No signer seeds needed for owner-signed transfer
+            ctx.remaining_accounts,
+        )?;
+    }
+
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(EarlyBonusSet {
+        distributor: distributor_key,
+        owner: ctx.accounts.owner.key(),
+        early_bonus_bps,
+        bonus_window,
+        bonus_pool,
+        deposited,
+    });
+
+    Ok(())
+}
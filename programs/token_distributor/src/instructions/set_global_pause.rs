@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::constants::*;
+use crate::event::*;
+
+/**
+ * Account context for toggling the global pause
+ * 
+ * This instruction gives the global admin an emergency brake across all
+ * campaigns: while paused, every claim and distributor creation is rejected.
+ * 
+ * Access Control: Only the global admin can toggle the global pause
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetGlobalPause<'info> {
+    /// The program config account
+    /// - Derived from: ["config"]
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    
+    /// The global admin
+    #[account(constraint = admin.key() == config.admin @ TokenDistributorError::OnlyAdmin)]
+    pub admin: Signer<'info>,
+}
+
+/**
+ * Pauses or resumes all claims and distributor creations
+ * 
+ * @param ctx - The account context containing config and admin accounts
+ * @param paused - Whether the program should be paused
+ */
+pub fn handle_set_global_pause(ctx: Context<SetGlobalPause>, paused: bool) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.paused = paused;
+    
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(GlobalPauseSet {
+        config: config.key(),
+        admin: ctx.accounts.admin.key(),
+        paused,
+    });
+    
+    Ok(())
+}
@@ -6,17 +6,18 @@ use crate::event::*;
 /**
  * Account context for setting the merkle root
  * 
- * This instruction allows the designated operator to set the merkle root hash
+ * This instruction allows a listed operator to set the merkle root hash
  * that will be used to verify token claims. The merkle root represents the
  * root of a merkle tree containing all eligible claimants and their allocations.
  * 
- * Access Control: Only the operator can set the merkle root
+ * Access Control: Only a listed operator can set the merkle root
  * 
  * Business Logic:
  * - The merkle root defines who can claim tokens and how much
  * - Each leaf in the merkle tree represents a (claimant, amount) pair
  * - Claimants must provide a valid merkle proof to claim their tokens
  * - The merkle root can be updated by the operator if needed
+ * - Successive updates must respect the owner-configured root_update_interval
  */
 #[event_cpi]
 #[derive(Accounts)]
@@ -25,12 +26,12 @@ pub struct SetMerkleRoot<'info> {
     /// - Must be a valid existing distributor PDA
     /// - Will be modified to set the merkle_root
     #[account(mut)]
-    pub distributor: Account<'info, TokenDistributor>,
+    pub distributor: AccountLoader<'info, TokenDistributor>,
     
-    /// The operator who can set the merkle root
-    /// - Must match the operator stored in the distributor state
-    /// - Only this account can call this instruction
-    #[account(constraint = operator.key() == distributor.operator @ TokenDistributorError::OnlyOperator)]
+    /// An operator who can set the merkle root
+    /// - Must be one of the operators listed in the distributor state
+    /// - Only listed operators can call this instruction
+    #[account(constraint = distributor.load()?.is_operator(&operator.key()) @ TokenDistributorError::OnlyOperator)]
     pub operator: Signer<'info>,
 }
 
@@ -42,15 +43,16 @@ pub struct SetMerkleRoot<'info> {
  * @param ctx - The account context containing distributor and operator accounts
  * @param merkle_root - 32-byte hash representing the root of the merkle tree
  * 
- * Merkle Tree Structure:
- * - Each leaf: hash(claimant_pubkey + max_amount)
- * - Intermediate nodes: hash(left_child + right_child) with lexicographic ordering
+ * Merkle Tree Structure (tree version 1):
+ * - Each leaf: hash(LEAF_PREFIX + distributor_pubkey + claimant_pubkey + max_amount)
+ * - Intermediate nodes: hash(NODE_PREFIX + left_child + right_child) with lexicographic ordering
  * - Root: The final hash at the top of the tree
  * 
  * Validation Rules:
  * - Merkle root cannot be all zeros (empty hash)
  * - Only the designated operator can set the merkle root
  * - The merkle root can be updated multiple times if needed
+ * - Updates closer together than root_update_interval are rejected
  * 
  * Usage Notes:
  * - The merkle root should be generated off-chain from the complete list of eligible claimants
@@ -61,20 +63,45 @@ pub fn handle_set_merkle_root(
     ctx: Context<SetMerkleRoot>,
     merkle_root: [u8; 32],
 ) -> Result<()> {
-    let distributor = &mut ctx.accounts.distributor;
+    let distributor_key = ctx.accounts.distributor.key();
+    let distributor = &mut ctx.accounts.distributor.load_mut()?;
+    
+    // Finalized distributors keep their terms for good
+    require!(!distributor.is_finalized(), TokenDistributorError::DistributorFinalized);
     
     // Validate that the merkle root is not empty
     // An empty merkle root would allow no valid claims
     require!(merkle_root != [0; 32], TokenDistributorError::InvalidMerkleRoot);
     
-    // Set the merkle root for claim verification
-    distributor.merkle_root = merkle_root;
+    // Enforce the minimum interval between successive root updates
+    // The first root set is never rate limited
+    let current_time = Clock::get()?.unix_timestamp;
+    if distributor.last_root_update_at > 0 && distributor.root_update_interval > 0 {
+        let next_allowed = distributor.last_root_update_at
+            .checked_add(distributor.root_update_interval)
+            .ok_or(TokenDistributorError::ArithmeticOverflow)?;
+        if current_time < next_allowed {
+            msg!(
+                "Warning: merkle root update rejected, next update allowed at {}",
+                next_allowed
+            );
+            return err!(TokenDistributorError::RootUpdateTooFrequent);
+        }
+    }
+    
+    // Set the merkle root for claim verification, recording the update in the root history
+    let previous_merkle_root = distributor.merkle_root;
+    distributor.rotate_merkle_root(merkle_root, ctx.accounts.operator.key(), current_time);
+    distributor.operator_last_action_ts = current_time;
     
     // Emit event for off-chain indexing and monitoring
     emit_cpi!(MerkleRootSet {
-        distributor: distributor.key(),
+        distributor: distributor_key,
         operator: ctx.accounts.operator.key(),
+        previous_merkle_root,
         merkle_root,
+        tree_version: distributor.tree_version,
+        update_index: distributor.root_update_count,
     });
     
     Ok(())
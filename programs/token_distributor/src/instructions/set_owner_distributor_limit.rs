@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::constants::*;
+use crate::event::*;
+
+/**
+ * Account context for setting an owner's active distributor limit
+ *
+ * This instruction lets the global admin override the global limit on
+ * simultaneously active distributors for a single owner.
+ *
+ * Access Control: Only the global admin can set owner limits
+ *
+ * Business Logic:
+ * - Creates the owner's nonce state if the owner never created a distributor
+ * - A limit of 0 removes the override so the global limit applies again
+ * - Lowering the limit below the current count only blocks new creations
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetOwnerDistributorLimit<'info> {
+    /// The program config account
+    /// - Derived from: ["config"]
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// Nonce state account of the owner the limit applies to
+    /// - Derived from: ["owner_nonce", owner]
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = NonceState::LEN,
+        seeds = [OWNER_NONCE_SEED.as_bytes(), owner.key().as_ref()],
+        bump
+    )]
+    pub owner_nonce: Account<'info, NonceState>,
+
+    /// The owner the limit applies to
+    /// CHECK: Only used for PDA derivation
+    pub owner: UncheckedAccount<'info>,
+
+    /// The global admin
+    /// - Pays for the nonce state if it does not exist yet
+    #[account(
+        mut,
+        constraint = admin.key() == config.admin @ TokenDistributorError::OnlyAdmin
+    )]
+    pub admin: Signer<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/**
+ * Sets an owner's limit on active distributors
+ *
+ * @param ctx - The account context containing config, owner nonce, owner and admin accounts
+ * @param max_active_distributors - Owner-specific limit (0 = global limit applies)
+ */
+pub fn handle_set_owner_distributor_limit(
+    ctx: Context<SetOwnerDistributorLimit>,
+    max_active_distributors: u32,
+) -> Result<()> {
+    let owner_nonce = &mut ctx.accounts.owner_nonce;
+    owner_nonce.max_active_distributors = max_active_distributors;
+
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(OwnerDistributorLimitSet {
+        owner: ctx.accounts.owner.key(),
+        admin: ctx.accounts.admin.key(),
+        max_active_distributors,
+        active_distributors: owner_nonce.active_distributors,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::event::*;
+
+/**
+ * Account context for setting the co-signature requirement
+ * 
+ * This instruction allows the distributor owner to require that every claim is
+ * also approved off-chain, e.g. after KYC, by an ed25519 signature from one of
+ * the distributor's operators.
+ * 
+ * Access Control: Only the owner can set the requirement
+ * 
+ * Business Logic:
+ * - While required, claim must be preceded by an operator's ed25519 signature
+ *   over (distributor, claimant, max_amount, expiry)
+ * - Other claim instructions are rejected while required
+ * - Can be turned on or off at any time
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetRequireCosign<'info> {
+    /// The distributor account to update
+    /// - Will be modified to set require_cosign
+    #[account(mut)]
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+    
+    /// The owner of the distributor
+    /// - Must match the owner stored in the distributor state
+    #[account(constraint = owner.key() == distributor.load()?.owner @ TokenDistributorError::OnlyOwner)]
+    pub owner: Signer<'info>,
+}
+
+/**
+ * Turns the operator co-signature requirement on claims on or off
+ * 
+ * @param ctx - The account context containing distributor and owner accounts
+ * @param require_cosign - Whether claims must be co-signed by an operator
+ */
+pub fn handle_set_require_cosign(
+    ctx: Context<SetRequireCosign>,
+    require_cosign: bool,
+) -> Result<()> {
+    let distributor_key = ctx.accounts.distributor.key();
+    let distributor = &mut ctx.accounts.distributor.load_mut()?;
+    
+    distributor.require_cosign = require_cosign as u8;
+    
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(CosignRequirementSet {
+        distributor: distributor_key,
+        owner: ctx.accounts.owner.key(),
+        require_cosign,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::constants::*;
+use crate::event::*;
+
+/**
+ * Account context for setting the merkle root update interval
+ * 
+ * This instruction allows the distributor owner to configure the minimum
+ * interval between successive set_merkle_root calls, limiting how fast a
+ * compromised operator key can cycle roots.
+ * 
+ * Access Control: Only the owner can set the interval
+ * 
+ * Business Logic:
+ * - An interval of 0 disables rate limiting
+ * - The interval is capped at MAX_ROOT_UPDATE_INTERVAL
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetRootUpdateInterval<'info> {
+    /// The distributor account to update
+    /// - Must be a valid existing distributor PDA
+    /// - Will be modified to set root_update_interval
+    #[account(mut)]
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+    
+    /// The owner of the distributor
+    /// - Must match the owner stored in the distributor state
+    /// - Only this account can call this instruction
+    #[account(constraint = owner.key() == distributor.load()?.owner @ TokenDistributorError::OnlyOwner)]
+    pub owner: Signer<'info>,
+}
+
+/**
+ * Sets the minimum interval between merkle root updates
+ * 
+ * @param ctx - The account context containing distributor and owner accounts
+ * @param root_update_interval - Minimum interval in seconds (0 disables rate limiting)
+ * 
+ */
+pub fn handle_set_root_update_interval(
+    ctx: Context<SetRootUpdateInterval>,
+    root_update_interval: i64,
+) -> Result<()> {
+    let distributor_key = ctx.accounts.distributor.key();
+    let distributor = &mut ctx.accounts.distributor.load_mut()?;
+    
+    require!(
+        (0..=MAX_ROOT_UPDATE_INTERVAL).contains(&root_update_interval),
+        TokenDistributorError::InvalidRootUpdateInterval
+    );
+    
+    distributor.root_update_interval = root_update_interval;
+    
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(RootUpdateIntervalSet {
+        distributor: distributor_key,
+        owner: ctx.accounts.owner.key(),
+        root_update_interval,
+    });
+    
+    Ok(())
+}
@@ -7,17 +7,18 @@ use crate::event::*;
 /**
  * Account context for setting the distribution time
  * 
- * This instruction allows the designated operator to set when the token distribution
- * will begin and automatically calculates the end time based on the DURATION constant.
+ * This instruction allows a listed operator to set when the token distribution
+ * will begin and automatically calculates the end time based on the distributor's duration.
  * 
- * Access Control: Only the operator can set the time
+ * Access Control: Only a listed operator can set the time
  * 
  * Business Logic:
  * - Time can be modified multiple times before distribution starts
  * - Once distribution starts, time cannot be modified anymore
  * - Time must be in the future (prevents backdating)
  * - Each modification limited to max 90 days from current time (prevents setting times too far ahead in single operation)
- * - End time is automatically calculated as time + DURATION
+ * - End time is automatically calculated as time + duration
+ * - In slot mode times are slots, compared against Clock::slot
  */
 #[event_cpi]
 #[derive(Accounts)]
@@ -26,12 +27,12 @@ pub struct SetTime<'info> {
     /// - Must be a valid existing distributor PDA
     /// - Will be modified to set start_time and end_time
     #[account(mut)]
-    pub distributor: Account<'info, TokenDistributor>,
+    pub distributor: AccountLoader<'info, TokenDistributor>,
     
-    /// The operator who can set the time
-    /// - Must match the operator stored in the distributor state
-    /// - Only this account can call this instruction
-    #[account(constraint = operator.key() == distributor.operator @ TokenDistributorError::OnlyOperator)]
+    /// An operator who can set the time
+    /// - Must be one of the operators listed in the distributor state
+    /// - Only listed operators can call this instruction
+    #[account(constraint = distributor.load()?.is_operator(&operator.key()) @ TokenDistributorError::OnlyOperator)]
     pub operator: Signer<'info>,
 }
 
@@ -39,39 +40,57 @@ pub struct SetTime<'info> {
  * Sets the time for the token distribution
  * 
  * @param ctx - The account context containing distributor and operator accounts
- * @param start_time - Unix timestamp when distribution should begin
+ * @param start_time - Unix timestamp (slot in slot mode) when distribution should begin
  * 
  */
 pub fn handle_set_time(
     ctx: Context<SetTime>,
     start_time: i64,
 ) -> Result<()> {
-    let distributor = &mut ctx.accounts.distributor;
+    let distributor_key = ctx.accounts.distributor.key();
+    let distributor = &mut ctx.accounts.distributor.load_mut()?;
     
-    // Validate timing constraints
-    let current_time = Clock::get()?.unix_timestamp;
+    // Finalized distributors keep their terms for good
+    require!(!distributor.is_finalized(), TokenDistributorError::DistributorFinalized);
+    
+    // Validate timing constraints, on the slot clock in slot mode
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+    let activation_now = distributor.activation_now(&clock);
     
     // Check if distribution has already started - if so, cannot modify time
-    if distributor.start_time > 0 && current_time >= distributor.start_time {
+    if distributor.start_time > 0 && activation_now >= distributor.start_time {
         return err!(TokenDistributorError::DistributionAlreadyStarted);
     }
     
     // Time must be in the future to prevent backdating
-    require!(start_time > current_time, TokenDistributorError::InvalidStartTime);
+    require!(start_time > activation_now, TokenDistributorError::InvalidStartTime);
     
     // Time cannot be too far in the future (MAX_START_TIME = 90 days)
-    require!(start_time <= current_time + MAX_START_TIME, TokenDistributorError::StartTimeTooFar);
+    let max_start_time = activation_now.saturating_add(distributor.activation_type().period(MAX_START_TIME));
+    require!(start_time <= max_start_time, TokenDistributorError::StartTimeTooFar);
     
     // Set the distribution period
+    let (previous_start_time, previous_end_time) = (distributor.start_time, distributor.end_time);
     distributor.start_time = start_time;
-    distributor.end_time = start_time + DURATION;  // DURATION = 14 days
+    distributor.end_time = start_time
+        .checked_add(distributor.duration)
+        .ok_or(TokenDistributorError::ArithmeticOverflow)?;
+    distributor.operator_last_action_ts = current_time;
+    distributor.time_update_count = distributor.time_update_count
+        .checked_add(1)
+        .ok_or(TokenDistributorError::ArithmeticOverflow)?;
     
     // Emit event for off-chain indexing and monitoring
     emit_cpi!(StartTimeSet {
-        distributor: distributor.key(),
+        distributor: distributor_key,
         operator: ctx.accounts.operator.key(),
+        previous_start_time,
+        previous_end_time,
         start_time,
         end_time: distributor.end_time,
+        withdraw_unlocks_at: distributor.withdraw_unlocks_at(),
+        update_index: distributor.time_update_count,
     });
 
     Ok(())
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token_interface::Mint;
+use crate::state::*;
+use crate::error::*;
+use crate::constants::*;
+use crate::utils::load_program_config;
+use crate::event::*;
+
+/**
+ * Account context for syncing a distributor into its mint registry
+ * 
+ * This instruction lists a scheduled distributor in the per-mint registry, or
+ * refreshes its window after set_time / extend_time, so explorers and wallets
+ * can read the claimable airdrops of a token directly from chain state.
+ * 
+ * Access Control: The token's mint authority or the program config admin,
+ * so the registry only lists campaigns the token's issuer or the protocol endorse
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SyncRegistry<'info> {
+    /// The distributor to list in the registry
+    /// - Must have a start time set
+    /// - Must use timestamp-based activation
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+    
+    /// The registry of the distributor's token mint
+    /// - Derived from: ["registry", token_mint]
+    /// - Created on first use
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = MintRegistry::LEN,
+        seeds = [REGISTRY_SEED.as_bytes(), distributor.load()?.token_mint.as_ref()],
+        bump
+    )]
+    pub registry: Account<'info, MintRegistry>,
+    
+    /// The distributor's token mint
+    /// - Its mint authority may list distributors of the mint
+    #[account(address = distributor.load()?.token_mint @ TokenDistributorError::TokenMintMismatch)]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    
+    /// The mint authority of the token mint, or the program config admin
+    pub authority: Signer<'info>,
+    
+    /// The account paying for the registry account creation
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Program config holding the admin
+    /// CHECK: Derived from ["config"], either uninitialized or a valid ProgramConfig
+    #[account(seeds = [CONFIG_SEED.as_bytes()], bump)]
+    pub config: UncheckedAccount<'info>,
+    
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Check whether a key may list and evict the live distributors of a mint's registry
+/// - The mint authority of the token, or the program config admin
+pub(crate) fn is_registry_authority(token_mint: &Mint, config: &ProgramConfig, key: &Pubkey) -> bool {
+    token_mint.mint_authority == COption::Some(*key) || *key == config.admin
+}
+
+/**
+ * Lists or refreshes a distributor in its mint registry
+ * 
+ * @param ctx - The account context containing distributor, registry, mint, authority and payer accounts
+ * 
+ * Process:
+ * 1. Verify the authority is the mint authority or the config admin
+ * 2. Prune every entry whose window has ended
+ * 3. Insert or refresh the distributor's entry if its window has not ended
+ */
+pub fn handle_sync_registry(ctx: Context<SyncRegistry>) -> Result<()> {
+    let config = load_program_config(&ctx.accounts.config)?;
+    require!(
+        is_registry_authority(&ctx.accounts.token_mint, &config, &ctx.accounts.authority.key()),
+        TokenDistributorError::RegistryUnauthorized
+    );
+    
+    let distributor_key = ctx.accounts.distributor.key();
+    let distributor = &ctx.accounts.distributor.load()?;
+    let registry = &mut ctx.accounts.registry;
+    
+    require!(distributor.start_time > 0, TokenDistributorError::StartTimeNotSet);
+    
+    // Registry windows are unix timestamps, slot-based windows cannot be listed
+    require!(
+        distributor.activation_type() == ActivationType::Timestamp,
+        TokenDistributorError::SlotActivationNotSupported
+    );
+    
+    registry.bump = ctx.bumps.registry;
+    registry.token_mint = distributor.token_mint;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    registry.prune_ended(current_time);
+    
+    if distributor.end_time > current_time {
+        let listed = registry.upsert(RegistryEntry {
+            distributor: distributor_key,
+            start_time: distributor.start_time,
+            end_time: distributor.end_time,
+        });
+        require!(listed, TokenDistributorError::RegistryFull);
+    }
+    
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(RegistrySynced {
+        registry: registry.key(),
+        distributor: distributor_key,
+        token_mint: distributor.token_mint,
+        start_time: distributor.start_time,
+        end_time: distributor.end_time,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::event::*;
+use crate::constants::*;
+
+/**
+ * Account context for unfreezing a claim
+ * 
+ * This instruction allows a claimant to lift a freeze they previously placed
+ * on their claim, once the UNFREEZE_TIMELOCK has elapsed.
+ * 
+ * Access Control: Claimant (enforced by PDA seeds) co-signed by the owner or an operator
+ * 
+ * Business Logic:
+ * - The claimant's key alone cannot unfreeze, so whoever holds a leaked key
+ *   cannot wait out the timelock and claim
+ * - The owner or operator co-signs once they have confirmed the claimant
+ *   controls the key again, e.g. through the campaign's support process
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UnfreezeMyClaim<'info> {
+    /// The distributor account the claim belongs to
+    /// - Must be a valid existing distributor PDA
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+    
+    /// Individual claim status for this claimant
+    /// - Must be a valid existing ClaimStatus account
+    /// - Derived from: ["claim", distributor_key, claimant_key]
+    /// - Will be modified to clear the frozen flag
+    #[account(
+        mut,
+        seeds = [CLAIM_SEED.as_bytes(), distributor.key().as_ref(), claimant.key().as_ref()],
+        bump
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+    
+    /// The claimant unfreezing their claim
+    /// - Must sign the transaction
+    pub claimant: Signer<'info>,
+    
+    /// The owner or operator approving the unfreeze
+    /// - Must be the owner or one of the listed operators
+    #[account(
+        constraint = authority.key() == distributor.load()?.owner
+            || distributor.load()?.is_operator(&authority.key()) @ TokenDistributorError::OnlyOwnerOrOperator
+    )]
+    pub authority: Signer<'info>,
+}
+
+/**
+ * Unfreezes the claimant's claim
+ * 
+ * @param ctx - The account context containing distributor, claim status, claimant and authority accounts
+ * 
+ * Validation Rules:
+ * - Authority must be the owner or an operator
+ * - Claim must currently be frozen
+ * - UNFREEZE_TIMELOCK must have elapsed since the claim was frozen
+ */
+pub fn handle_unfreeze_my_claim(ctx: Context<UnfreezeMyClaim>) -> Result<()> {
+    let claim_status = &mut ctx.accounts.claim_status;
+    
+    require!(claim_status.frozen, TokenDistributorError::ClaimNotFrozen);
+    
+    // Enforce the timelock so a leaked key cannot immediately undo the freeze
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(
+        current_time >= claim_status.frozen_at + UNFREEZE_TIMELOCK,
+        TokenDistributorError::UnfreezeTimelockActive
+    );
+    
+    claim_status.frozen = false;
+    claim_status.frozen_at = 0;
+    
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(ClaimUnfrozen {
+        distributor: ctx.accounts.distributor.key(),
+        claimant: ctx.accounts.claimant.key(),
+        authority: ctx.accounts.authority.key(),
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::event::*;
+
+/**
+ * Account context for unpausing the distribution
+ * 
+ * This instruction allows the owner or a listed operator to resume claims
+ * after the distribution has been paused.
+ * 
+ * Access Control: Owner or any listed operator
+ * 
+ * Business Logic:
+ * - Claims are accepted again once unpaused
+ * - The distribution window is not adjusted for the paused period
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Unpause<'info> {
+    /// The distributor account to update
+    /// - Must be a valid existing distributor PDA
+    /// - Will be modified to set the paused flag
+    #[account(mut)]
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+    
+    /// The owner or operator unpausing the distribution
+    /// - Must be the owner or one of the listed operators
+    #[account(
+        constraint = authority.key() == distributor.load()?.owner
+            || distributor.load()?.is_operator(&authority.key()) @ TokenDistributorError::OnlyOwnerOrOperator
+    )]
+    pub authority: Signer<'info>,
+}
+
+/**
+ * Unpauses the token distribution
+ * 
+ * @param ctx - The account context containing distributor and authority accounts
+ * 
+ */
+pub fn handle_unpause(ctx: Context<Unpause>) -> Result<()> {
+    let distributor_key = ctx.accounts.distributor.key();
+    let distributor = &mut ctx.accounts.distributor.load_mut()?;
+    
+    // Only a paused distribution can be unpaused
+    require!(distributor.is_paused(), TokenDistributorError::DistributionNotPaused);
+    
+    distributor.paused = 0;
+    
+    // Only actions taken by an operator, not the owner, count as operator activity
+    if distributor.is_operator(&ctx.accounts.authority.key()) {
+        distributor.operator_last_action_ts = Clock::get()?.unix_timestamp;
+    }
+    
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(DistributionUnpaused {
+        distributor: distributor_key,
+        authority: ctx.accounts.authority.key(),
+    });
+    
+    Ok(())
+}
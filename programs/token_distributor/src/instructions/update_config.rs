@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::constants::*;
+use crate::event::*;
+
+/**
+ * Account context for updating the program config
+ * 
+ * This instruction allows the global admin to hand over the admin role, to
+ * change the default parameters applied to all distributors and to set the
+ * protocol fee taken on claims.
+ * 
+ * Access Control: Only the global admin can update the config
+ * 
+ * Business Logic:
+ * - Parameters left as None keep their current value
+ * - Minimum duration must be positive and min <= default <= max
+ * - Protocol fee is capped at MAX_PROTOCOL_FEE_BPS and needs a receiver when non-zero
+ * - Active distributor limit of 0 disables the limit, it only applies to later creations
+ * - New bounds only apply to later creations, extensions and fee changes
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    /// The program config account
+    /// - Derived from: ["config"]
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    
+    /// The global admin
+    #[account(constraint = admin.key() == config.admin @ TokenDistributorError::OnlyAdmin)]
+    pub admin: Signer<'info>,
+}
+
+/**
+ * Updates the program config
+ * 
+ * @param ctx - The account context containing config and admin accounts
+ * @param new_admin - Optional new global admin
+ * @param min_duration - Optional minimum distribution duration in seconds
+ * @param max_duration - Optional maximum distribution duration in seconds
+ * @param default_duration - Optional default distribution duration in seconds
+ * @param max_claim_fee - Optional maximum lamport fee per claim
+ * @param protocol_fee_bps - Optional protocol fee deducted from each claim, in basis points
+ * @param protocol_fee_receiver - Optional owner of the accounts receiving the protocol fee
+ * @param max_active_distributors - Optional maximum number of distributors an owner may have open at once
+ */
+#[allow(clippy::too_many_arguments)]
+pub fn handle_update_config(
+    ctx: Context<UpdateConfig>,
+    new_admin: Option<Pubkey>,
+    min_duration: Option<i64>,
+    max_duration: Option<i64>,
+    default_duration: Option<i64>,
+    max_claim_fee: Option<u64>,
+    protocol_fee_bps: Option<u16>,
+    protocol_fee_receiver: Option<Pubkey>,
+    max_active_distributors: Option<u32>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    
+    if let Some(new_admin) = new_admin {
+        require!(new_admin != Pubkey::default(), TokenDistributorError::InvalidConfig);
+        config.admin = new_admin;
+    }
+    config.min_duration = min_duration.unwrap_or(config.min_duration);
+    config.max_duration = max_duration.unwrap_or(config.max_duration);
+    config.default_duration = default_duration.unwrap_or(config.default_duration);
+    config.max_claim_fee = max_claim_fee.unwrap_or(config.max_claim_fee);
+    config.protocol_fee_bps = protocol_fee_bps.unwrap_or(config.protocol_fee_bps);
+    config.protocol_fee_receiver = protocol_fee_receiver.unwrap_or(config.protocol_fee_receiver);
+    config.max_active_distributors = max_active_distributors.unwrap_or(config.max_active_distributors);
+    config.validate()?;
+    
+    // Builds without the protocol-fee feature never take a protocol fee
+    #[cfg(not(feature = "protocol-fee"))]
+    require!(config.protocol_fee_bps == 0, TokenDistributorError::InvalidConfig);
+    
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(ProgramConfigUpdated {
+        config: config.key(),
+        admin: config.admin,
+        min_duration: config.min_duration,
+        max_duration: config.max_duration,
+        default_duration: config.default_duration,
+        max_claim_fee: config.max_claim_fee,
+        protocol_fee_bps: config.protocol_fee_bps,
+        protocol_fee_receiver: config.protocol_fee_receiver,
+        max_active_distributors: config.max_active_distributors,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::constants::*;
+use super::verify_allocation_proof;
+
+/**
+ * Account context for verifying a claimant's allocation
+ *
+ * This read-only instruction lets other programs check a user's allocation
+ * via CPI, e.g. to gate features on allocation size. No tokens are moved and
+ * no account is written.
+ *
+ * Access Control: Permissionless
+ *
+ * Business Logic:
+ * - Verifies the merkle proof against the current or a recent merkle root
+ * - Returns the verified allocation through the instruction return data
+ * - The claimant does not need to sign, any allocation can be checked
+ */
+#[derive(Accounts)]
+pub struct VerifyAllocation<'info> {
+    /// The distributor the allocation belongs to
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+
+    /// Individual claim status for this claimant
+    /// - Optional, the claimed amount is reported as 0 without it
+    /// - Derived from: ["claim", distributor_key, claimant_key]
+    #[account(
+        seeds = [CLAIM_SEED.as_bytes(), distributor.key().as_ref(), claimant.key().as_ref()],
+        bump
+    )]
+    pub claim_status: Option<Account<'info, ClaimStatus>>,
+
+    /// The claimant whose allocation is verified
+    /// CHECK: Only bound into the merkle leaf and used for PDA derivation
+    pub claimant: UncheckedAccount<'info>,
+}
+
+/// Allocation returned by verify_allocation
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct VerifiedAllocation {
+    /// Total amount allocated to the claimant by the merkle tree
+    pub max_amount: u64,
+    /// Amount the claimant has already claimed
+    pub claimed_amount: u64,
+    /// The claimant's unlock time (0 for tree version 1)
+    pub unlock_time: i64,
+}
+
+/**
+ * Verifies a claimant's allocation without transferring tokens
+ *
+ * @param ctx - The account context containing distributor, claim status and claimant accounts
+ * @param max_amount - Maximum amount this user is eligible to claim (from merkle tree)
+ * @param proof - Array of 32-byte hashes forming the merkle proof path
+ * @param unlock_time - The claimant's unlock time from the merkle tree (tree version 2 only)
+ *
+ * @returns Result<VerifiedAllocation> - The verified allocation, also set as return data
+ */
+pub fn handle_verify_allocation(
+    ctx: Context<VerifyAllocation>,
+    max_amount: u64,
+    proof: Vec<[u8; 32]>,
+    unlock_time: Option<i64>,
+) -> Result<VerifiedAllocation> {
+    let distributor_key = ctx.accounts.distributor.key();
+    let distributor = &ctx.accounts.distributor.load()?;
+
+    // Ensure merkle root has been set
+    require!(distributor.merkle_root != [0; 32], TokenDistributorError::NoMerkleRoot);
+
+    verify_allocation_proof(
+        distributor,
+        distributor_key,
+        &ctx.accounts.claimant.key(),
+        max_amount,
+        &proof,
+        unlock_time,
+        &[],
+        None,
+    )?;
+
+    Ok(VerifiedAllocation {
+        max_amount,
+        claimed_amount: ctx.accounts.claim_status.as_ref().map_or(0, |status| status.claimed_amount),
+        unlock_time: if distributor.tree_version == UNLOCK_TIME_TREE_VERSION { unlock_time.unwrap_or_default() } else { 0 },
+    })
+}
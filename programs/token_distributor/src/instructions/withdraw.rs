@@ -3,7 +3,7 @@ use anchor_spl::token_interface::{TokenInterface, TokenAccount};
 use crate::state::*;
 use crate::error::*;
 use crate::constants::*;
-use crate::utils::{transfer_token, close_token_account_with_pda};
+use crate::utils::{transfer_token_with_hook, close_token_account_with_pda, harvest_withheld_fees};
 use crate::event::*;
 
 /**
@@ -15,12 +15,16 @@ use crate::event::*;
  * Access Control: Only the owner can withdraw remaining tokens
  * 
  * Business Logic:
- * - Can be called in two scenarios:
- *   1. After the distribution period has ended (current_time > end_time)
+ * - Can be called in three scenarios:
+ *   1. After the distribution period and withdraw delay have ended (current_time > end_time + withdraw_delay)
  *   2. If distribution time was never set (start_time = 0, end_time = 0)
+ *   3. Once a claim has emptied the vault and it is still empty (distribution completed)
+ * - Not available when a clawback receiver is configured (use clawback instead)
  * - Withdraws all remaining tokens from the vault
+ * - Harvests withheld Token 2022 transfer fees to the mint so the vault can be closed
  * - Closes the token vault account to reclaim rent
  * - Closes the distributor account to reclaim rent
+ * - Removes the distributor from its mint registry when given
  */
 #[event_cpi]
 #[derive(Accounts)]
@@ -32,7 +36,7 @@ pub struct Withdraw<'info> {
         mut,
         close = owner
     )]
-    pub distributor: Account<'info, TokenDistributor>,
+    pub distributor: AccountLoader<'info, TokenDistributor>,
     
     /// Token vault containing the remaining tokens
     /// - Controlled by the distributor PDA
@@ -51,7 +55,7 @@ pub struct Withdraw<'info> {
     /// - Will be credited with all remaining tokens
     #[account(
         mut,
-        token::mint = distributor.token_mint,
+        token::mint = distributor.load()?.token_mint,
         token::authority = owner,
         token::token_program = token_program,
     )]
@@ -60,9 +64,11 @@ pub struct Withdraw<'info> {
     /// The token mint for verification
     /// - Must match the distributor's token mint
     /// - Used for transfer_checked validation
+    /// - Writable to receive transfer fees withheld in the vault (Token 2022)
     #[account(
+        mut,
         token::token_program = token_program,
-        constraint = token_mint.key() == distributor.token_mint @ TokenDistributorError::TokenMintMismatch
+        constraint = token_mint.key() == distributor.load()?.token_mint @ TokenDistributorError::TokenMintMismatch
     )]
     pub token_mint: InterfaceAccount<'info, anchor_spl::token_interface::Mint>,
     
@@ -72,12 +78,32 @@ pub struct Withdraw<'info> {
     /// - Receives the remaining tokens and reclaimed rent
     #[account(
         mut,
-        constraint = owner.key() == distributor.owner @ TokenDistributorError::OnlyOwner
+        constraint = owner.key() == distributor.load()?.owner @ TokenDistributorError::OnlyOwner
     )]
     pub owner: Signer<'info>,
     
+    /// Nonce state account tracking the owner's active distributors
+    /// - Derived from: ["owner_nonce", owner]
+    /// - Active distributor count is decremented as the distributor closes
+    #[account(
+        mut,
+        seeds = [OWNER_NONCE_SEED.as_bytes(), owner.key().as_ref()],
+        bump
+    )]
+    pub owner_nonce: Account<'info, NonceState>,
+    
     /// Token program (supports both SPL Token and Token 2022)
     pub token_program: Interface<'info, TokenInterface>,
+    
+    /// Registry of the distributor's token mint
+    /// - Optional, the distributor's entry is removed from it when given
+    /// - Derived from: ["registry", token_mint]
+    #[account(
+        mut,
+        seeds = [REGISTRY_SEED.as_bytes(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub registry: Option<Account<'info, MintRegistry>>,
 }
 
 /**
@@ -88,17 +114,39 @@ pub struct Withdraw<'info> {
  * @returns Result<()> - Success or error
  * 
  * Validation Rules:
- * - Distribution must have ended or never been started
+ * - Distribution must have ended, never been started, or completed with an empty vault
+ * - No clawback receiver may be configured
+ * - Every extra mint of a multi-mint distributor must have been withdrawn
  * - Only the owner can call this function
  */
-pub fn handle_withdraw(ctx: Context<Withdraw>) -> Result<()> {
-    let distributor = &ctx.accounts.distributor;
+pub fn handle_withdraw<'info>(ctx: Context<'_, '_, 'info, 'info, Withdraw<'info>>) -> Result<()> {
+    let distributor_key = ctx.accounts.distributor.key();
     
     // ===== VALIDATION PHASE =====
     
-    // Ensure distribution has ended or was never started before allowing withdrawal
-    let current_time = Clock::get()?.unix_timestamp;
-    require!(current_time > distributor.end_time, TokenDistributorError::DistributionNotEnded);
+    // Copy the signing data out so the account is not borrowed during the transfers
+    let (token_mint_key, owner, nonce, bump) = {
+        let distributor = ctx.accounts.distributor.load()?;
+        
+        // Unclaimed tokens belong to the clawback receiver when one is configured
+        require!(
+            distributor.clawback_receiver == Pubkey::default(),
+            TokenDistributorError::ClawbackReceiverConfigured
+        );
+        
+        // Ensure distribution has ended or was never started before allowing withdrawal
+        // A completed distribution is withdrawable right away, unless the vault was topped up since
+        if !(distributor.is_completed() && ctx.accounts.token_vault.amount == 0) {
+            let current_time = distributor.activation_now(&Clock::get()?);
+            require!(current_time > distributor.end_time, TokenDistributorError::DistributionNotEnded);
+            require!(current_time > distributor.withdraw_unlocks_at(), TokenDistributorError::WithdrawDelayActive);
+        }
+        
+        // Extra mint vaults of multi-mint distributors are controlled by the distributor PDA
+        require!(distributor.open_extra_mints == 0, TokenDistributorError::ExtraMintsOpen);
+        
+        (distributor.token_mint, distributor.owner, distributor.nonce, distributor.bump)
+    };
     
     // Get remaining balance for potential transfer and event emission
     let remaining_balance = ctx.accounts.token_vault.amount;
@@ -106,20 +154,21 @@ pub fn handle_withdraw(ctx: Context<Withdraw>) -> Result<()> {
     // ===== INTERACTIONS PHASE (Token Transfer and Cleanup) =====
     
     // Prepare PDA signing seeds for token operations
-    let nonce_bytes = distributor.nonce.to_le_bytes();
+    let nonce_bytes = nonce.to_le_bytes();
     let seeds = &[
         DISTRIBUTOR_SEED.as_bytes(),
-        distributor.token_mint.as_ref(),
-        distributor.owner.as_ref(),
+        token_mint_key.as_ref(),
+        owner.as_ref(),
         nonce_bytes.as_ref(),
-        &[distributor.bump],
+        &[bump],
     ];
     let signer = &[&seeds[..]];
     
     // Transfer remaining tokens only if there are any
     if remaining_balance > 0 {
         // Compatibility with both SPL Token and Token 2022
-        transfer_token(
+        // Remaining accounts are passed to the mint's transfer hook, if any
+        transfer_token_with_hook(
             ctx.accounts.distributor.to_account_info(),
             ctx.accounts.token_vault.to_account_info(),
             ctx.accounts.owner_token_account.to_account_info(),
@@ -128,9 +177,25 @@ pub fn handle_withdraw(ctx: Context<Withdraw>) -> Result<()> {
             remaining_balance,
             ctx.accounts.token_mint.decimals,
             Some(signer),  // PDA signing for secure transfer
+            ctx.remaining_accounts,
         )?;
     }
     
+    // Move withheld transfer fees to the mint, Token 2022 will not close a vault holding them
+    let withheld_fees = harvest_withheld_fees(
+        ctx.accounts.token_vault.to_account_info(),
+        ctx.accounts.token_mint.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+    )?;
+    if withheld_fees > 0 {
+        emit_cpi!(WithheldFeesHarvested {
+            distributor: distributor_key,
+            token_vault: ctx.accounts.token_vault.key(),
+            token_mint: token_mint_key,
+            amount: withheld_fees,
+        });
+    }
+    
     // Close the token vault account to reclaim rent
     // This returns the rent to the owner and cleans up the account
     close_token_account_with_pda(
@@ -141,9 +206,23 @@ pub fn handle_withdraw(ctx: Context<Withdraw>) -> Result<()> {
         signer,  // PDA signing for secure closure
     )?;
     
+    // The distributor no longer counts against the owner's active distributor limit
+    ctx.accounts.owner_nonce.close_distributor();
+    
+    // Closed distributors leave their mint registry
+    if let Some(registry) = ctx.accounts.registry.as_mut() {
+        if registry.remove(&distributor_key) {
+            emit_cpi!(RegistryEntryRemoved {
+                registry: registry.key(),
+                distributor: distributor_key,
+                token_mint: ctx.accounts.token_mint.key(),
+            });
+        }
+    }
+    
     // Emit event for off-chain indexing and monitoring
     emit_cpi!(TokensWithdrawn {
-        distributor: distributor.key(),
+        distributor: distributor_key,
         owner: ctx.accounts.owner.key(),
         amount_withdrawn: remaining_balance,
     });
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::event::*;
+
+/**
+ * Account context for withdrawing accumulated claim fees
+ * 
+ * This instruction allows the distributor owner to sweep the claim fees
+ * collected on the distributor account. The rent of the distributor account
+ * itself is never touched.
+ * 
+ * Access Control: Only the owner can withdraw claim fees
+ * 
+ * Business Logic:
+ * - Can be called at any time, including during the distribution
+ * - Withdraws total_fees_collected - total_fees_withdrawn
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawClaimFees<'info> {
+    /// The distributor account holding the fees
+    /// - Will be modified to update total_fees_withdrawn
+    #[account(mut)]
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+    
+    /// The owner of the distributor
+    /// - Must match the owner stored in the distributor state
+    /// - Receives the fees
+    #[account(
+        mut,
+        constraint = owner.key() == distributor.load()?.owner @ TokenDistributorError::OnlyOwner
+    )]
+    pub owner: Signer<'info>,
+}
+
+/**
+ * Withdraws the accumulated claim fees to the owner
+ * 
+ * @param ctx - The account context containing distributor and owner accounts
+ */
+pub fn handle_withdraw_claim_fees(ctx: Context<WithdrawClaimFees>) -> Result<()> {
+    let distributor_key = ctx.accounts.distributor.key();
+    let distributor = &mut ctx.accounts.distributor.load_mut()?;
+    
+    let amount = distributor.total_fees_collected
+        .checked_sub(distributor.total_fees_withdrawn)
+        .ok_or(TokenDistributorError::ArithmeticOverflow)?;
+    require!(amount > 0, TokenDistributorError::NoClaimFees);
+    
+    distributor.total_fees_withdrawn = distributor.total_fees_collected;
+    
+    // Move lamports directly, the distributor account is owned by this program
+    ctx.accounts.distributor.sub_lamports(amount)?;
+    ctx.accounts.owner.add_lamports(amount)?;
+    
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(ClaimFeesWithdrawn {
+        distributor: distributor_key,
+        owner: ctx.accounts.owner.key(),
+        amount,
+        total_fees_collected: distributor.total_fees_collected,
+        total_fees_withdrawn: distributor.total_fees_withdrawn,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,193 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenInterface, TokenAccount, Mint};
+use crate::state::*;
+use crate::error::*;
+use crate::constants::*;
+use crate::utils::{transfer_token_with_hook, close_token_account_with_pda, harvest_withheld_fees};
+use crate::event::*;
+
+/**
+ * Account context for withdrawing the vault of an extra mint
+ *
+ * This instruction empties and closes the vault of an extra mint of a
+ * multi-mint distributor once the distribution is over. Every extra mint must
+ * be withdrawn before the distributor itself can be withdrawn or clawed back.
+ *
+ * Access Control:
+ * - Without a clawback receiver: only the owner, after end_time + withdraw_delay
+ * - With a clawback receiver: anyone, after end_time + withdraw_delay + CLAWBACK_GRACE_PERIOD
+ *
+ * Business Logic:
+ * - Transfers the remaining tokens to the owner, or to the clawback receiver when one is configured
+ * - Harvests withheld Token 2022 transfer fees to the mint so the vault can be closed
+ * - Closes the vault and the distributor mint account, returning rent to the owner
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawDistributorMint<'info> {
+    /// The multi-mint distributor the mint belongs to
+    /// - Will be modified to count the closed extra mint
+    #[account(mut)]
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+
+    /// The distributor mint account to close
+    /// - Derived from: ["distributor_mint", distributor_key, token_mint]
+    /// - Will be closed and rent returned to owner
+    #[account(
+        mut,
+        close = owner,
+        seeds = [DISTRIBUTOR_MINT_SEED.as_bytes(), distributor.key().as_ref(), token_mint.key().as_ref()],
+        bump = distributor_mint.bump
+    )]
+    pub distributor_mint: Account<'info, DistributorMint>,
+
+    /// Token vault of the extra mint
+    /// - Derived from: ["vault", distributor_mint_key]
+    /// - Will be emptied and closed
+    #[account(
+        mut,
+        address = distributor_mint.token_vault
+    )]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Token account receiving the remaining tokens
+    /// - Must be owned by the clawback receiver if one is configured, by the owner otherwise
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::token_program = token_program,
+    )]
+    pub receiver_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// The extra token mint
+    /// - Writable to receive transfer fees withheld in the vault (Token 2022)
+    #[account(
+        mut,
+        token::token_program = token_program,
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    /// The owner of the distributor
+    /// - Receives the reclaimed rent
+    #[account(
+        mut,
+        constraint = owner.key() == distributor.load()?.owner @ TokenDistributorError::OnlyOwner
+    )]
+    pub owner: SystemAccount<'info>,
+
+    /// Account submitting the withdrawal
+    /// - Must be the owner unless a clawback receiver is configured
+    pub authority: Signer<'info>,
+
+    /// Token program of the extra mint (supports both SPL Token and Token 2022)
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/**
+ * Withdraws and closes the vault of an extra mint
+ *
+ * @param ctx - The account context, with transfer hook accounts in remaining accounts
+ *
+ * Validation Rules:
+ * - Distribution must have ended (plus the clawback grace period when a clawback receiver is configured)
+ * - Tokens go to the clawback receiver when one is configured, to the owner otherwise
+ */
+pub fn handle_withdraw_distributor_mint<'info>(ctx: Context<'_, '_, 'info, 'info, WithdrawDistributorMint<'info>>) -> Result<()> {
+    let distributor_key = ctx.accounts.distributor.key();
+
+    // ===== VALIDATION PHASE =====
+
+    let (token_mint_key, owner, nonce, bump, receiver) = {
+        let distributor = &mut ctx.accounts.distributor.load_mut()?;
+        let current_time = distributor.activation_now(&Clock::get()?);
+
+        // Unclaimed tokens belong to the clawback receiver when one is configured
+        let receiver = if distributor.clawback_receiver == Pubkey::default() {
+            require!(
+                ctx.accounts.authority.key() == distributor.owner,
+                TokenDistributorError::OnlyOwner
+            );
+            require!(current_time > distributor.end_time, TokenDistributorError::DistributionNotEnded);
+            require!(current_time > distributor.withdraw_unlocks_at(), TokenDistributorError::WithdrawDelayActive);
+            distributor.owner
+        } else {
+            let clawback_after = distributor.withdraw_unlocks_at()
+                .checked_add(distributor.activation_type().period(CLAWBACK_GRACE_PERIOD))
+                .ok_or(TokenDistributorError::ArithmeticOverflow)?;
+            require!(current_time > clawback_after, TokenDistributorError::ClawbackGracePeriodActive);
+            distributor.clawback_receiver
+        };
+        require!(
+            ctx.accounts.receiver_token_account.owner == receiver,
+            TokenDistributorError::ReceiverMismatch
+        );
+
+        // The distributor can be closed once every extra mint has been withdrawn
+        distributor.open_extra_mints = distributor.open_extra_mints.saturating_sub(1);
+
+        (distributor.token_mint, distributor.owner, distributor.nonce, distributor.bump, receiver)
+    };
+
+    let remaining_balance = ctx.accounts.token_vault.amount;
+
+    // ===== INTERACTIONS PHASE (Token Transfer and Cleanup) =====
+
+    // Extra mint vaults are controlled by the distributor PDA
+    let nonce_bytes = nonce.to_le_bytes();
+    let seeds = &[
+        DISTRIBUTOR_SEED.as_bytes(),
+        token_mint_key.as_ref(),
+        owner.as_ref(),
+        nonce_bytes.as_ref(),
+        &[bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    if remaining_balance > 0 {
+        transfer_token_with_hook(
+            ctx.accounts.distributor.to_account_info(),
+            ctx.accounts.token_vault.to_account_info(),
+            ctx.accounts.receiver_token_account.to_account_info(),
+            ctx.accounts.token_mint.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            remaining_balance,
+            ctx.accounts.token_mint.decimals,
+            Some(signer),  // PDA signing for secure transfer
+            ctx.remaining_accounts,
+        )?;
+    }
+
+    // Move withheld transfer fees to the mint, Token 2022 will not close a vault holding them
+    let withheld_fees = harvest_withheld_fees(
+        ctx.accounts.token_vault.to_account_info(),
+        ctx.accounts.token_mint.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+    )?;
+    if withheld_fees > 0 {
+        emit_cpi!(WithheldFeesHarvested {
+            distributor: distributor_key,
+            token_vault: ctx.accounts.token_vault.key(),
+            token_mint: ctx.accounts.token_mint.key(),
+            amount: withheld_fees,
+        });
+    }
+
+    close_token_account_with_pda(
+        ctx.accounts.token_vault.to_account_info(),
+        ctx.accounts.owner.to_account_info(),
+        ctx.accounts.distributor.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        signer,  // PDA signing for secure closure
+    )?;
+
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(DistributorMintWithdrawn {
+        distributor: distributor_key,
+        token_mint: ctx.accounts.token_mint.key(),
+        receiver,
+        amount_withdrawn: remaining_balance,
+    });
+
+    // Note: The distributor mint account is closed by the close = owner constraint
+    Ok(())
+}
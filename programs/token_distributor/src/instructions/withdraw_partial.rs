@@ -0,0 +1,160 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenInterface, TokenAccount};
+use crate::state::*;
+use crate::error::*;
+use crate::constants::*;
+use crate::utils::transfer_token_with_hook;
+use crate::event::*;
+
+/**
+ * Account context for withdrawing part of the vault balance
+ *
+ * This instruction allows the distributor owner to sweep a chosen amount of
+ * tokens out of the vault (e.g. an over-deposit) while keeping the distributor
+ * and vault open for a later round.
+ *
+ * Access Control: Only the owner can withdraw tokens
+ *
+ * Business Logic:
+ * - Can be called in two scenarios:
+ *   1. After the distribution period and withdraw delay have ended (current_time > end_time + withdraw_delay)
+ *   2. Before anything is claimable (start_time not set or not reached yet)
+ * - Not available after end_time when a clawback receiver is configured
+ * - The unpaid bonus pool can only be withdrawn once the distribution has ended
+ * - Withdraws only the requested amount, no accounts are closed
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawPartial<'info> {
+    /// The distributor account to withdraw from
+    /// - Must be a valid existing distributor PDA
+    /// - Stays open after the withdrawal
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+
+    /// Token vault containing the distributor's tokens
+    /// - Controlled by the distributor PDA
+    /// - Derived from: ["vault", distributor_key]
+    #[account(
+        mut,
+        seeds = [VAULT_SEED.as_bytes(), distributor.key().as_ref()],
+        bump
+    )]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Owner's token account to receive the withdrawn tokens
+    /// - Must be owned by the owner
+    /// - Must be for the correct token mint
+    #[account(
+        mut,
+        token::mint = distributor.load()?.token_mint,
+        token::authority = owner,
+        token::token_program = token_program,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// The token mint for verification
+    /// - Must match the distributor's token mint
+    /// - Used for transfer_checked validation
+    #[account(
+        token::token_program = token_program,
+        constraint = token_mint.key() == distributor.load()?.token_mint @ TokenDistributorError::TokenMintMismatch
+    )]
+    pub token_mint: InterfaceAccount<'info, anchor_spl::token_interface::Mint>,
+
+    /// The owner of the distributor
+    /// - Must match the owner stored in the distributor state
+    /// - Only this account can call this instruction
+    #[account(
+        constraint = owner.key() == distributor.load()?.owner @ TokenDistributorError::OnlyOwner
+    )]
+    pub owner: Signer<'info>,
+
+    /// Token program (supports both SPL Token and Token 2022)
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/**
+ * Withdraws part of the vault balance to the owner
+ *
+ * @param ctx - The account context, with transfer hook accounts in remaining accounts
+ * @param amount - Amount of tokens to withdraw
+ *
+ * @returns Result<()> - Success or error
+ *
+ * Validation Rules:
+ * - Distribution must not be live (before start_time or after end_time)
+ * - No clawback receiver may be configured once the distribution has ended
+ * - Amount must be non-zero and not exceed the vault balance,
+ *   less the unpaid bonus pool before the distribution has ended
+ * - Only the owner can call this function
+ */
+pub fn handle_withdraw_partial<'info>(ctx: Context<'_, '_, 'info, 'info, WithdrawPartial<'info>>, amount: u64) -> Result<()> {
+    let distributor_key = ctx.accounts.distributor.key();
+
+    // ===== VALIDATION PHASE =====
+
+    require!(amount > 0, TokenDistributorError::InvalidAmount);
+
+    // Copy the signing data out so the account is not borrowed during the transfer
+    let (token_mint_key, owner, nonce, bump) = {
+        let distributor = ctx.accounts.distributor.load()?;
+
+        // Nothing may be swept while claims are possible or during the withdraw delay
+        // (an unscheduled distributor has not ended, whatever its zero end_time says)
+        let current_time = distributor.activation_now(&Clock::get()?);
+        let ended = distributor.start_time > 0 && current_time > distributor.withdraw_unlocks_at();
+        require!(
+            ended || distributor.start_time == 0 || current_time < distributor.start_time,
+            TokenDistributorError::DistributionActive
+        );
+
+        // Unclaimed tokens belong to the clawback receiver once the distribution has ended
+        require!(
+            !ended || distributor.clawback_receiver == Pubkey::default(),
+            TokenDistributorError::ClawbackReceiverConfigured
+        );
+
+        // The unpaid bonus pool stays in the vault for the early claims it was deposited for
+        let reserved = if ended { 0 } else { distributor.remaining_bonus_pool() };
+        require!(
+            amount <= ctx.accounts.token_vault.amount.saturating_sub(reserved),
+            TokenDistributorError::WithdrawAmountExceedsBalance
+        );
+
+        (distributor.token_mint, distributor.owner, distributor.nonce, distributor.bump)
+    };
+
+    // ===== INTERACTIONS PHASE (Token Transfer) =====
+
+    // Prepare PDA signing seeds for the transfer
+    let nonce_bytes = nonce.to_le_bytes();
+    let seeds = &[
+        DISTRIBUTOR_SEED.as_bytes(),
+        token_mint_key.as_ref(),
+        owner.as_ref(),
+        nonce_bytes.as_ref(),
+        &[bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    transfer_token_with_hook(
+        ctx.accounts.distributor.to_account_info(),
+        ctx.accounts.token_vault.to_account_info(),
+        ctx.accounts.owner_token_account.to_account_info(),
+        ctx.accounts.token_mint.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        amount,
+        ctx.accounts.token_mint.decimals,
+        Some(signer),  // PDA signing for secure transfer
+        ctx.remaining_accounts,
+    )?;
+
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(TokensWithdrawn {
+        distributor: distributor_key,
+        owner: ctx.accounts.owner.key(),
+        amount_withdrawn: amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::error::*;
+use crate::constants::*;
+use crate::event::*;
+use super::require_distribution_ended;
+
+/**
+ * Account context for withdrawing the rent escrow
+ * 
+ * This instruction allows the owner to recover the lamports left in the rent
+ * escrow, including rent refunded by closed ClaimStatus accounts.
+ * 
+ * Access Control: Only the owner can withdraw the rent escrow (enforced by PDA seeds)
+ * 
+ * Business Logic:
+ * - Can be called after the distribution period has ended or once the distributor is closed
+ * - Can be called repeatedly as ClaimStatus accounts are closed
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawRentEscrow<'info> {
+    /// Rent escrow to empty
+    /// - Derived from: ["rent_escrow", distributor_key, owner]
+    #[account(
+        mut,
+        seeds = [RENT_ESCROW_SEED.as_bytes(), distributor_key.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub rent_escrow: SystemAccount<'info>,
+    
+    /// The owner of the distributor
+    /// - Receives the escrow lamports
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    /// Distributor account used for PDA derivation and time validation
+    /// CHECK: Either closed or valid TokenDistributor
+    pub distributor_key: AccountInfo<'info>,
+    
+    /// System program for the lamport transfer
+    pub system_program: Program<'info, System>,
+}
+
+/**
+ * Withdraws all lamports from the rent escrow to the owner
+ * 
+ * @param ctx - The account context containing rent escrow, owner and distributor accounts
+ */
+pub fn handle_withdraw_rent_escrow(ctx: Context<WithdrawRentEscrow>) -> Result<()> {
+    let distributor_key = &ctx.accounts.distributor_key;
+    
+    require_distribution_ended(distributor_key)?;
+    
+    let amount = ctx.accounts.rent_escrow.lamports();
+    require!(amount > 0, TokenDistributorError::InsufficientRentEscrow);
+    
+    // Prepare PDA signing seeds for the lamport transfer
+    let distributor_key = distributor_key.key();
+    let owner_key = ctx.accounts.owner.key();
+    let seeds: &[&[u8]] = &[
+        RENT_ESCROW_SEED.as_bytes(),
+        distributor_key.as_ref(),
+        owner_key.as_ref(),
+        &[ctx.bumps.rent_escrow],
+    ];
+    
+    transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.rent_escrow.to_account_info(),
+                to: ctx.accounts.owner.to_account_info(),
+            },
+            &[seeds],
+        ),
+        amount,
+    )?;
+    
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(RentEscrowWithdrawn {
+        distributor: distributor_key,
+        owner: owner_key,
+        amount,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenInterface, TokenAccount};
+use crate::state::*;
+use crate::error::*;
+use crate::constants::*;
+use crate::utils::transfer_token_with_hook;
+use crate::event::*;
+
+/**
+ * Account context for withdrawing from a shared vault
+ * 
+ * This instruction allows the owner to withdraw tokens from the shared vault
+ * that are not allocated to any shared distributor's remaining budget.
+ * 
+ * Access Control: Only the owner can withdraw from the shared vault
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawSharedVault<'info> {
+    /// The shared vault to withdraw from
+    /// - Derived from: ["shared_vault", token_mint, owner]
+    #[account(
+        seeds = [SHARED_VAULT_SEED.as_bytes(), token_mint.key().as_ref(), owner.key().as_ref()],
+        bump = shared_vault.bump
+    )]
+    pub shared_vault: Account<'info, SharedVault>,
+    
+    /// Token vault of the shared vault
+    /// - Derived from: ["vault", shared_vault_key]
+    #[account(
+        mut,
+        seeds = [VAULT_SEED.as_bytes(), shared_vault.key().as_ref()],
+        bump
+    )]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Owner's token account to receive the tokens
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = owner,
+        token::token_program = token_program,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// The token mint for verification
+    #[account(
+        token::token_program = token_program,
+    )]
+    pub token_mint: InterfaceAccount<'info, anchor_spl::token_interface::Mint>,
+    
+    /// The owner of the shared vault
+    /// - Enforced by the shared vault PDA seeds
+    pub owner: Signer<'info>,
+    
+    /// Token program (supports both SPL Token and Token 2022)
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/**
+ * Withdraws unallocated tokens from the shared vault
+ * 
+ * @param ctx - The account context, with transfer hook accounts in remaining accounts
+ * @param amount - Amount of tokens to withdraw
+ * 
+ * Validation Rules:
+ * - Amount must not exceed vault balance minus allocated budget
+ */
+pub fn handle_withdraw_shared_vault<'info>(ctx: Context<'_, '_, 'info, 'info, WithdrawSharedVault<'info>>, amount: u64) -> Result<()> {
+    let shared_vault = &ctx.accounts.shared_vault;
+    
+    require!(amount > 0, TokenDistributorError::InvalidAmount);
+    
+    // Only tokens not reserved for shared distributors can be withdrawn
+    let unallocated = ctx.accounts.token_vault.amount.saturating_sub(shared_vault.allocated_amount);
+    require!(amount <= unallocated, TokenDistributorError::InsufficientUnallocatedBalance);
+    
+    // Prepare PDA signing seeds for token transfer
+    let seeds = &[
+        SHARED_VAULT_SEED.as_bytes(),
+        shared_vault.token_mint.as_ref(),
+        shared_vault.owner.as_ref(),
+        &[shared_vault.bump],
+    ];
+    let signer = &[&seeds[..]];
+    
+    transfer_token_with_hook(
+        ctx.accounts.shared_vault.to_account_info(),
+        ctx.accounts.token_vault.to_account_info(),
+        ctx.accounts.owner_token_account.to_account_info(),
+        ctx.accounts.token_mint.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        amount,
+        ctx.accounts.token_mint.decimals,
+        Some(signer),  // PDA signing for secure transfer
+        ctx.remaining_accounts,
+    )?;
+    
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(SharedVaultWithdrawn {
+        shared_vault: shared_vault.key(),
+        owner: ctx.accounts.owner.key(),
+        amount,
+    });
+    
+    Ok(())
+}
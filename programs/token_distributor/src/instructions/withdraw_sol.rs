@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::*;
+use crate::error::*;
+use crate::constants::*;
+use crate::event::*;
+
+/**
+ * Account context for withdrawing remaining SOL
+ * 
+ * This instruction allows the owner of a native SOL distributor to withdraw
+ * the lamports left in the vault, including its rent reserve, and close the
+ * distributor.
+ * 
+ * Access Control: Only the owner can withdraw remaining lamports
+ * 
+ * Business Logic:
+ * - Can be called after the distribution period has ended or if it was never started
+ * - Can be called right away once a claim has emptied the vault down to its rent reserve
+ * - Empties the lamport vault
+ * - Closes the distributor account to reclaim rent
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawSol<'info> {
+    /// The native SOL distributor to withdraw from and close
+    /// - Will be closed and rent returned to owner
+    #[account(
+        mut,
+        close = owner,
+        constraint = distributor.load()?.is_native() @ TokenDistributorError::NotNativeDistributor
+    )]
+    pub distributor: AccountLoader<'info, TokenDistributor>,
+    
+    /// Lamport vault holding the remaining SOL
+    /// - Derived from: ["sol_vault", distributor_key]
+    #[account(
+        mut,
+        seeds = [SOL_VAULT_SEED.as_bytes(), distributor.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: SystemAccount<'info>,
+    
+    /// The owner of the distributor
+    /// - Receives the remaining lamports and reclaimed rent
+    #[account(
+        mut,
+        constraint = owner.key() == distributor.load()?.owner @ TokenDistributorError::OnlyOwner
+    )]
+    pub owner: Signer<'info>,
+    
+    /// Nonce state account tracking the owner's active distributors
+    /// - Derived from: ["owner_nonce", owner]
+    /// - Active distributor count is decremented as the distributor closes
+    #[account(
+        mut,
+        seeds = [OWNER_NONCE_SEED.as_bytes(), owner.key().as_ref()],
+        bump
+    )]
+    pub owner_nonce: Account<'info, NonceState>,
+    
+    /// System program for the lamport transfer
+    pub system_program: Program<'info, System>,
+}
+
+/**
+ * Withdraws remaining lamports from a native SOL distributor
+ * 
+ * @param ctx - The account context containing all required accounts
+ * 
+ * Validation Rules:
+ * - Distribution must have ended or never been started, and the withdraw delay elapsed,
+ *   unless a claim completed it and the vault holds no more than its rent reserve
+ * - Only the owner can call this function
+ */
+pub fn handle_withdraw_sol(ctx: Context<WithdrawSol>) -> Result<()> {
+    let (end_time, withdraw_unlocks_at, completed) = {
+        let distributor = ctx.accounts.distributor.load()?;
+        (distributor.end_time, distributor.withdraw_unlocks_at(), distributor.is_completed())
+    };
+    
+    // Ensure distribution has ended or was never started before allowing withdrawal
+    // A completed distribution is withdrawable right away, unless the vault was topped up since
+    let distributable = ctx.accounts.sol_vault.lamports().saturating_sub(Rent::get()?.minimum_balance(0));
+    if !(completed && distributable == 0) {
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(current_time > end_time, TokenDistributorError::DistributionNotEnded);
+        require!(current_time > withdraw_unlocks_at, TokenDistributorError::WithdrawDelayActive);
+    }
+    
+    // Withdraw everything, including the vault's rent reserve
+    let remaining_balance = ctx.accounts.sol_vault.lamports();
+    let distributor_key = ctx.accounts.distributor.key();
+    let seeds: &[&[u8]] = &[
+        SOL_VAULT_SEED.as_bytes(),
+        distributor_key.as_ref(),
+        &[ctx.bumps.sol_vault],
+    ];
+    
+    if remaining_balance > 0 {
+        transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.sol_vault.to_account_info(),
+                    to: ctx.accounts.owner.to_account_info(),
+                },
+                &[seeds],
+            ),
+            remaining_balance,
+        )?;
+    }
+    
+    // The distributor no longer counts against the owner's active distributor limit
+    ctx.accounts.owner_nonce.close_distributor();
+    
+    // Emit event for off-chain indexing and monitoring
+    emit_cpi!(TokensWithdrawn {
+        distributor: distributor_key,
+        owner: ctx.accounts.owner.key(),
+        amount_withdrawn: remaining_balance,
+    });
+    
+    // Note: The distributor account will be automatically closed due to the
+    // close = owner constraint in the account definition, returning rent to owner
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-declare_id!("2Ab8No85xrnnd2rFamKjQqGAK6Qu9EQ4eEh59oaZEPBL");
+declare_id!("Evnn4x4QMBgBR2dBZwheSm59g3NxGVyH8MZXwiCFGvs3");
 
 pub mod constants;
 pub mod error;
@@ -13,6 +13,7 @@ pub mod utils;
 pub mod test;
 
 use instructions::*;
+use state::{ActivationType, ClaimWindow};
 
 /**
  * Token Distributor Program
@@ -23,9 +24,10 @@ use instructions::*;
  * Key Features:
  * - Merkle tree-based claim verification
  * - Supports single claim (when root set once) or incremental distributions by adjusting max_amount without resetting previous claims
- * - Flexible merkle root updates (operator can update root anytime without time restrictions)
- * - Time-bounded distributions (configurable start and end times)
- * - Operator delegation (separate owner and operator roles)
+ * - Flexible merkle root updates (operator can update root anytime, optionally rate limited by the owner)
+ * - Time-bounded distributions (configurable start time and duration)
+ * - Emergency pause / unpause of claims
+ * - Operator delegation (separate owner and operator roles, up to 5 operators)
  * - Cross-program call event emission for composability
  * - Support for both SPL Token and Token 2022
  *
@@ -34,18 +36,141 @@ use instructions::*;
  * - Distributor PDA: Stores distribution parameters and state
  * - Token Vault PDA: Holds tokens to be distributed
  * - Claim Status PDAs: Track how much each user has claimed
+ * - Mint Registry PDAs: List the scheduled distributors of each token
  *
  * Workflow:
  * 1. Owner creates distributor and deposits tokens
  * 2. Operator sets start time and merkle root
  * 3. Users claim tokens with valid merkle proofs
- * 4. Owner withdraws remaining tokens after distribution ends
+ * 4. Owner withdraws remaining tokens after distribution ends (or anyone triggers clawback to the configured receiver)
  * 5. Users can optionally close ClaimStatus accounts to reclaim rent
  */
 #[program]
 pub mod token_distributor {
     use super::*;
 
+    /**
+     * Initializes the program config
+     *
+     * Creates the singleton config PDA with the default parameters and sets
+     * the global admin.
+     *
+     * @param ctx - Account context containing config, authority and program data accounts
+     * @param admin - Global admin who can update the config and toggle the global pause
+     *
+     * Access Control: Program upgrade authority only
+     */
+    pub fn initialize_config(ctx: Context<InitializeConfig>, admin: Pubkey) -> Result<()> {
+        handle_initialize_config(ctx, admin)
+    }
+
+    /**
+     * Updates the program config
+     *
+     * Hands over the admin role, changes the duration bounds, default
+     * duration and maximum claim fee applied to all distributors, sets
+     * the protocol fee deducted from each claim and limits how many
+     * distributors an owner may have open at once.
+     *
+     * @param ctx - Account context containing config and admin accounts
+     * @param new_admin - Optional new global admin
+     * @param min_duration - Optional minimum distribution duration in seconds
+     * @param max_duration - Optional maximum distribution duration in seconds
+     * @param default_duration - Optional default distribution duration in seconds
+     * @param max_claim_fee - Optional maximum lamport fee per claim
+     * @param protocol_fee_bps - Optional protocol fee deducted from each claim, in basis points
+     * @param protocol_fee_receiver - Optional owner of the accounts receiving the protocol fee
+     * @param max_active_distributors - Optional maximum number of distributors an owner may have open at once (0 = unlimited)
+     *
+     * Access Control: Global admin only
+     */
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        new_admin: Option<Pubkey>,
+        min_duration: Option<i64>,
+        max_duration: Option<i64>,
+        default_duration: Option<i64>,
+        max_claim_fee: Option<u64>,
+        protocol_fee_bps: Option<u16>,
+        protocol_fee_receiver: Option<Pubkey>,
+        max_active_distributors: Option<u32>,
+    ) -> Result<()> {
+        handle_update_config(
+            ctx,
+            new_admin,
+            min_duration,
+            max_duration,
+            default_duration,
+            max_claim_fee,
+            protocol_fee_bps,
+            protocol_fee_receiver,
+            max_active_distributors,
+        )
+    }
+
+    /**
+     * Pauses or resumes the whole program
+     *
+     * Emergency brake across all campaigns: while paused, every claim and
+     * distributor creation is rejected.
+     *
+     * @param ctx - Account context containing config and admin accounts
+     * @param paused - Whether the program should be paused
+     *
+     * Access Control: Global admin only
+     */
+    pub fn set_global_pause(ctx: Context<SetGlobalPause>, paused: bool) -> Result<()> {
+        handle_set_global_pause(ctx, paused)
+    }
+
+    /**
+     * Sets an owner's limit on active distributors
+     *
+     * Overrides the global limit for one owner, e.g. to let a trusted
+     * integrator run more campaigns at once.
+     *
+     * @param ctx - Account context containing config, admin, owner and owner nonce accounts
+     * @param max_active_distributors - Owner-specific limit (0 = global limit applies)
+     *
+     * Access Control: Global admin only
+     */
+    pub fn set_owner_distributor_limit(
+        ctx: Context<SetOwnerDistributorLimit>,
+        max_active_distributors: u32,
+    ) -> Result<()> {
+        handle_set_owner_distributor_limit(ctx, max_active_distributors)
+    }
+
+    /**
+     * Migrates an owner's nonce state to the current layout
+     *
+     * Grows a nonce state created before active distributor limits so the
+     * owner can create and close distributors again. The nonce is kept.
+     *
+     * @param ctx - Account context containing owner nonce, owner and payer accounts
+     *
+     * Access Control: Anyone (the payer funds the extra rent)
+     */
+    pub fn migrate_owner_nonce(ctx: Context<MigrateOwnerNonce>) -> Result<()> {
+        handle_migrate_owner_nonce(ctx)
+    }
+
+    /**
+     * Migrates a claim status to the current layout
+     *
+     * Grows a claim status created before the per-claimant fields were added so
+     * its claimant can claim and close it again. The claimed amount is kept and
+     * the claimant is recorded as the rent payer.
+     *
+     * @param ctx - Account context containing claim status, distributor, claimant and payer accounts
+     *
+     * Access Control: Anyone (the payer funds the extra rent)
+     */
+    pub fn migrate_claim_status(ctx: Context<MigrateClaimStatus>) -> Result<()> {
+        handle_migrate_claim_status(ctx)
+    }
+
     /**
      * Creates a new token distributor
      *
@@ -53,20 +178,146 @@ pub mod token_distributor {
      * The owner deposits tokens into a vault controlled by the distributor PDA.
      * Nonce numbers are automatically assigned using an owner-specific counter.
      *
-     * @param ctx - Account context containing distributor, vault, counter, and owner accounts
+     * @param ctx - Account context containing distributor, vault, counter, and owner accounts, with transfer hook accounts in remaining accounts
      * @param initial_total_amount - Total amount of tokens to distribute
+     * @param duration - Optional distribution window in seconds, or slots in slot mode (defaults to 14 days)
+     * @param clawback_receiver - Optional receiver of unclaimed tokens (replaces owner withdrawal)
+     * @param tree_version - Optional merkle tree version (1 by default, 2 adds per-user unlock times, 3 for multi-mint, 4 adds recipients, 5 hashes with keccak256)
+     * @param withdraw_delay - Optional delay in seconds (slots in slot mode) after the end time before withdrawal or clawback (0 by default, at most 90 days)
+     * @param activation_type - Optional clock for the distribution window (Timestamp by default, Slot compares against Clock::slot)
+     *
+     * Access Control: Owner only
+     */
+    pub fn create_distributor<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateDistributor<'info>>,
+        initial_total_amount: u64,
+        duration: Option<i64>,
+        clawback_receiver: Option<Pubkey>,
+        tree_version: Option<u8>,
+        withdraw_delay: Option<i64>,
+        activation_type: Option<ActivationType>,
+    ) -> Result<()> {
+        handle_create_distributor(ctx, initial_total_amount, duration, clawback_receiver, tree_version, withdraw_delay, activation_type)
+    }
+
+    /**
+     * Creates a new token distributor under a caller-supplied nonce
+     *
+     * Works like create_distributor, but the distributor is derived from the
+     * given nonce instead of the owner's next one, so its distributor and
+     * vault addresses are known before creation. The nonce must be greater
+     * than every nonce the owner has used and becomes the owner's latest nonce.
+     *
+     * @param ctx - Account context containing distributor, vault, counter, and owner accounts, with transfer hook accounts in remaining accounts
+     * @param nonce - Nonce the distributor is derived with
+     * @param initial_total_amount - Total amount of tokens to distribute
+     * @param duration - Optional distribution window in seconds, or slots in slot mode (defaults to 14 days)
+     * @param clawback_receiver - Optional receiver of unclaimed tokens (replaces owner withdrawal)
+     * @param tree_version - Optional merkle tree version, as for create_distributor
+     * @param withdraw_delay - Optional delay in seconds (slots in slot mode) after the end time before withdrawal or clawback (0 by default, at most 90 days)
+     * @param activation_type - Optional clock for the distribution window (Timestamp by default, Slot compares against Clock::slot)
+     *
+     * Access Control: Owner only
+     */
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_distributor_with_nonce<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateDistributorWithNonce<'info>>,
+        nonce: u32,
+        initial_total_amount: u64,
+        duration: Option<i64>,
+        clawback_receiver: Option<Pubkey>,
+        tree_version: Option<u8>,
+        withdraw_delay: Option<i64>,
+        activation_type: Option<ActivationType>,
+    ) -> Result<()> {
+        handle_create_distributor_with_nonce(ctx, nonce, initial_total_amount, duration, clawback_receiver, tree_version, withdraw_delay, activation_type)
+    }
+
+    /**
+     * Creates a new native SOL distributor
+     *
+     * Works like create_distributor, but the owner deposits lamports into a
+     * lamport vault PDA and claims pay out native SOL, e.g. to airdrop gas money.
+     *
+     * @param ctx - Account context containing distributor, lamport vault, counter, and owner accounts
+     * @param initial_total_amount - Total amount of lamports to distribute
+     * @param duration - Optional distribution window in seconds (defaults to 14 days)
+     * @param tree_version - Optional merkle tree version (1 by default, 2 adds per-user unlock times, 5 hashes with keccak256)
+     * @param withdraw_delay - Optional delay in seconds after the end time before withdrawal (0 by default, at most 90 days)
      *
      * Access Control: Owner only
      */
-    pub fn create_distributor(ctx: Context<CreateDistributor>, initial_total_amount: u64) -> Result<()> {
-        handle_create_distributor(ctx, initial_total_amount)
+    pub fn create_sol_distributor(
+        ctx: Context<CreateSolDistributor>,
+        initial_total_amount: u64,
+        duration: Option<i64>,
+        tree_version: Option<u8>,
+        withdraw_delay: Option<i64>,
+    ) -> Result<()> {
+        handle_create_sol_distributor(ctx, initial_total_amount, duration, tree_version, withdraw_delay)
+    }
+
+    /**
+     * Creates a shared vault
+     *
+     * Initializes a vault for the owner and mint that several distributors can
+     * draw from, and deposits the initial amount of tokens.
+     *
+     * @param ctx - Account context containing shared vault, token vault and owner accounts, with transfer hook accounts in remaining accounts
+     * @param amount - Amount of tokens to deposit
+     *
+     * Access Control: Owner only
+     */
+    pub fn create_shared_vault<'info>(ctx: Context<'_, '_, 'info, 'info, CreateSharedVault<'info>>, amount: u64) -> Result<()> {
+        handle_create_shared_vault(ctx, amount)
+    }
+
+    /**
+     * Creates a new distributor drawing from a shared vault
+     *
+     * Works like create_distributor, but instead of depositing tokens into its own
+     * vault the distributor is allocated a budget from the owner's shared vault.
+     *
+     * @param ctx - Account context containing distributor, shared vault, counter, and owner accounts
+     * @param budget - Maximum amount of tokens the distributor may pay out
+     * @param duration - Optional distribution window in seconds (defaults to 14 days)
+     * @param tree_version - Optional merkle tree version (1 by default, 2 adds per-user unlock times, 5 hashes with keccak256)
+     * @param withdraw_delay - Optional delay in seconds after the end time before the distributor can be closed (0 by default, at most 90 days)
+     *
+     * Access Control: Owner only
+     */
+    pub fn create_shared_distributor(
+        ctx: Context<CreateSharedDistributor>,
+        budget: u64,
+        duration: Option<i64>,
+        tree_version: Option<u8>,
+        withdraw_delay: Option<i64>,
+    ) -> Result<()> {
+        handle_create_shared_distributor(ctx, budget, duration, tree_version, withdraw_delay)
+    }
+
+    /**
+     * Adds an extra mint to a multi-mint distributor
+     *
+     * Creates a vault for the mint controlled by the distributor PDA and deposits
+     * the initial amount, so one campaign can distribute several tokens with a
+     * single merkle tree. Must be called before the merkle root is set.
+     *
+     * @param ctx - Account context containing distributor, distributor mint, vault and owner accounts, with transfer hook accounts in remaining accounts
+     * @param initial_total_amount - Total amount of the extra mint to distribute
+     *
+     * Access Control: Owner only
+     */
+    #[cfg(feature = "multi-mint")]
+    pub fn add_distributor_mint<'info>(ctx: Context<'_, '_, 'info, 'info, AddDistributorMint<'info>>, initial_total_amount: u64) -> Result<()> {
+        handle_add_distributor_mint(ctx, initial_total_amount)
     }
 
     /**
      * Sets the time for the distribution
      *
      * Configures when the token distribution will begin and automatically
-     * calculates the end time(start_time + 14 days).
+     * calculates the end time(start_time + duration).
      *
      * @param ctx - Account context containing distributor and operator accounts
      * @param start_time - Unix timestamp when distribution should begin
@@ -77,6 +328,66 @@ pub mod token_distributor {
         handle_set_time(ctx, start_time)
     }
 
+    /**
+     * Extends the end time of an active distribution
+     *
+     * Pushes end_time later (never earlier) while the distribution is live.
+     * The total duration stays capped at 180 days.
+     *
+     * @param ctx - Account context containing distributor and operator accounts
+     * @param new_end_time - Unix timestamp when the distribution should now end
+     *
+     * Access Control: Operator only
+     */
+    pub fn extend_time(ctx: Context<ExtendTime>, new_end_time: i64) -> Result<()> {
+        handle_extend_time(ctx, new_end_time)
+    }
+
+    /**
+     * Sets the claim windows of a phased distribution
+     *
+     * Restricts claims to up to MAX_CLAIM_WINDOWS sorted, non-overlapping
+     * windows within the distribution period. Claims report the index of
+     * their window as their phase. An empty list clears the windows.
+     *
+     * @param ctx - Account context containing distributor and operator accounts
+     * @param claim_windows - Claim windows in phase order, inclusive Unix timestamps (slots in slot mode)
+     *
+     * Access Control: Operator only, before the distribution starts
+     */
+    pub fn set_claim_windows(ctx: Context<SetClaimWindows>, claim_windows: Vec<ClaimWindow>) -> Result<()> {
+        handle_set_claim_windows(ctx, claim_windows)
+    }
+
+    /**
+     * Lists or refreshes a distributor in its mint registry
+     *
+     * Records the distributor's claim window in the per-mint registry and
+     * prunes entries whose window has ended.
+     *
+     * @param ctx - Account context containing distributor, registry, mint, authority and payer accounts
+     *
+     * Access Control: Mint authority of the token or program config admin
+     */
+    pub fn sync_registry(ctx: Context<SyncRegistry>) -> Result<()> {
+        handle_sync_registry(ctx)
+    }
+
+    /**
+     * Evicts a distributor from its mint registry
+     *
+     * Frees the slot of a distributor that was closed without passing its
+     * registry, or takes down a listing the token's issuer does not endorse.
+     *
+     * @param ctx - Account context containing registry, distributor, mint and optional authority accounts
+     *
+     * Access Control: Permissionless for closed, completed or ended distributors,
+     * mint authority of the token or program config admin otherwise
+     */
+    pub fn evict_registry_entry(ctx: Context<EvictRegistryEntry>) -> Result<()> {
+        handle_evict_registry_entry(ctx)
+    }
+
     /**
      * Sets the merkle root for claim verification
      *
@@ -87,24 +398,490 @@ pub mod token_distributor {
      * @param merkle_root - 32-byte hash representing the merkle tree root
      *
      * Access Control: Operator only
-     * Note: The merkle root can be updated multiple times if needed
+     * Note: The merkle root can be updated multiple times if needed, subject to
+     * the owner-configured minimum interval between updates
      */
     pub fn set_merkle_root(ctx: Context<SetMerkleRoot>, merkle_root: [u8; 32]) -> Result<()> {
         handle_set_merkle_root(ctx, merkle_root)
     }
 
+    /**
+     * Sets the minimum interval between merkle root updates
+     *
+     * Rate limits set_merkle_root to bound the damage a compromised operator
+     * key can do by rapidly cycling roots.
+     *
+     * @param ctx - Account context containing distributor and owner accounts
+     * @param root_update_interval - Minimum interval in seconds (0 disables, max 7 days)
+     *
+     * Access Control: Owner only
+     */
+    pub fn set_root_update_interval(
+        ctx: Context<SetRootUpdateInterval>,
+        root_update_interval: i64,
+    ) -> Result<()> {
+        handle_set_root_update_interval(ctx, root_update_interval)
+    }
+
+    /**
+     * Sets the lamport fee charged per claim
+     *
+     * The fee is paid by the claim's rent payer and collected onto the
+     * distributor account until the owner withdraws it. It can no longer be
+     * changed once the distributor is finalized.
+     *
+     * @param ctx - Account context containing distributor and owner accounts
+     * @param claim_fee - Fee in lamports (0 disables fees, max 0.01 SOL)
+     *
+     * Access Control: Owner only
+     */
+    #[cfg(feature = "claim-fee")]
+    pub fn set_claim_fee(ctx: Context<SetClaimFee>, claim_fee: u64) -> Result<()> {
+        handle_set_claim_fee(ctx, claim_fee)
+    }
+
+    /**
+     * Restricts claims to holders of a gating token
+     *
+     * While set, every claim must include a token account of the claimant
+     * holding at least gate_min_amount of the gate mint (e.g. a membership NFT).
+     *
+     * @param ctx - Account context containing distributor and owner accounts
+     * @param gate_mint - Mint claimants must hold (None clears the gate)
+     * @param gate_min_amount - Minimum balance of the gate mint, must be positive when a mint is set
+     *
+     * Access Control: Owner only
+     */
+    #[cfg(feature = "claim-gates")]
+    pub fn set_claim_gate(
+        ctx: Context<SetClaimGate>,
+        gate_mint: Option<Pubkey>,
+        gate_min_amount: u64,
+    ) -> Result<()> {
+        handle_set_claim_gate(ctx, gate_mint, gate_min_amount)
+    }
+
+    /**
+     * Requires claimants to hold an attestation from an attestor
+     *
+     * While set, every claim must include the claimant's unexpired attestation
+     * from the attestor with at least min_attestation_score (anti-sybil gate).
+     *
+     * @param ctx - Account context containing distributor and owner accounts
+     * @param attestor - Attestor claimants must hold an attestation from (None clears the requirement)
+     * @param min_attestation_score - Minimum attestation score a claimant must hold
+     *
+     * Access Control: Owner only
+     */
+    #[cfg(feature = "claim-gates")]
+    pub fn set_claim_attestation(
+        ctx: Context<SetClaimAttestation>,
+        attestor: Option<Pubkey>,
+        min_attestation_score: u64,
+    ) -> Result<()> {
+        handle_set_claim_attestation(ctx, attestor, min_attestation_score)
+    }
+
+    /**
+     * Issues or refreshes a wallet attestation
+     *
+     * Publishes the attestor's wallet-quality score (e.g. from account age or
+     * activity) for a wallet, checked by distributors trusting this attestor.
+     *
+     * @param ctx - Account context containing attestation and attestor accounts
+     * @param subject - Wallet the attestation is about
+     * @param score - Wallet-quality score, on a scale defined by the attestor
+     * @param expires_at - Time after which the attestation is no longer valid (0 if it does not expire)
+     *
+     * Access Control: Any attestor, for its own attestations
+     */
+    #[cfg(feature = "claim-gates")]
+    pub fn attest(ctx: Context<Attest>, subject: Pubkey, score: u64, expires_at: i64) -> Result<()> {
+        handle_attest(ctx, subject, score, expires_at)
+    }
+
+    /**
+     * Revokes a wallet attestation
+     *
+     * @param ctx - Account context containing attestation and attestor accounts
+     *
+     * Access Control: The attestor that issued the attestation
+     */
+    #[cfg(feature = "claim-gates")]
+    pub fn revoke_attestation(ctx: Context<RevokeAttestation>) -> Result<()> {
+        handle_revoke_attestation(ctx)
+    }
+
+    /**
+     * Sets risk limits on claims
+     *
+     * Caps bound the damage of an over-allocating merkle tree until the
+     * distribution can be paused or the root fixed.
+     *
+     * @param ctx - Account context containing distributor and owner accounts
+     * @param max_claim_per_user - Maximum cumulative amount per claimant (0 disables the cap)
+     * @param max_total_per_slot - Maximum total amount claimed per slot (0 disables the limit)
+     *
+     * Access Control: Owner only
+     */
+    pub fn set_claim_limits(
+        ctx: Context<SetClaimLimits>,
+        max_claim_per_user: u64,
+        max_total_per_slot: u64,
+    ) -> Result<()> {
+        handle_set_claim_limits(ctx, max_claim_per_user, max_total_per_slot)
+    }
+
+    /**
+     * Enables the on-chain claim log
+     *
+     * Every later claim appends (claimant, amount, timestamp) to a paginated
+     * ClaimLog account, so proof of claim survives RPC event pruning.
+     *
+     * @param ctx - Account context containing distributor and owner accounts
+     *
+     * Access Control: Owner only, before the first claim
+     */
+    #[cfg(feature = "claim-log")]
+    pub fn enable_claim_log(ctx: Context<EnableClaimLog>) -> Result<()> {
+        handle_enable_claim_log(ctx)
+    }
+
+    /**
+     * Escrows every claim of a distributor, releasing it linearly
+     *
+     * Claims then pay into a ClaimEscrow PDA of the claimant, from which the
+     * claimant pulls the unlocked portion with release. Only allowed before the
+     * first claim and irreversible.
+     *
+     * @param ctx - Account context containing distributor and owner accounts
+     * @param release_duration - Period over which each claimant's escrowed tokens are released, in seconds (at most 4 years)
+     *
+     * Access Control: Owner only, before the first claim
+     */
+    #[cfg(feature = "claim-escrow")]
+    pub fn enable_claim_escrow(ctx: Context<EnableClaimEscrow>, release_duration: i64) -> Result<()> {
+        handle_enable_claim_escrow(ctx, release_duration)
+    }
+
+    /**
+     * Pays a bonus on claims made early, funded by a bonus pool
+     *
+     * Claims made with claim within bonus_window of the start receive
+     * early_bonus_bps of the claimed amount on top, until the bonus pool runs
+     * out. Raising the pool deposits the difference from the owner's token account.
+     *
+     * @param ctx - Account context containing distributor, vault, mint and owner accounts
+     * @param early_bonus_bps - Bonus in basis points of the claimed amount (0 disables it, at most 100%)
+     * @param bonus_window - Period after the start during which claims earn the bonus, in seconds (slots in slot mode)
+     * @param bonus_pool - Tokens funding the bonuses, never lower than the current pool
+     *
+     * Access Control: Owner only, before the distribution starts
+     */
+    pub fn set_early_bonus<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SetEarlyBonus<'info>>,
+        early_bonus_bps: u16,
+        bonus_window: i64,
+        bonus_pool: u64,
+    ) -> Result<()> {
+        handle_set_early_bonus(ctx, early_bonus_bps, bonus_window, bonus_pool)
+    }
+
+    /**
+     * Requires claims to be co-signed by an operator
+     *
+     * While required, claim must be preceded by an ed25519 signature from one
+     * of the operators, so only users cleared off-chain (e.g. KYC) can claim
+     * even with a valid merkle proof.
+     *
+     * @param ctx - Account context containing distributor and owner accounts
+     * @param require_cosign - Whether claims must be co-signed by an operator
+     *
+     * Access Control: Owner only
+     */
+    pub fn set_require_cosign(ctx: Context<SetRequireCosign>, require_cosign: bool) -> Result<()> {
+        handle_set_require_cosign(ctx, require_cosign)
+    }
+
+    /**
+     * Sets the display name and metadata URI of a distributor
+     *
+     * Wallets and explorers read the metadata account to show the campaign
+     * name and link its recipient list and terms. Usually sent in the same
+     * transaction as create_distributor.
+     *
+     * @param ctx - Account context containing distributor, metadata and owner accounts
+     * @param name - Display name of the campaign
+     * @param uri - URI of the campaign's off-chain metadata
+     *
+     * Access Control: Owner only, before the distribution starts
+     */
+    pub fn set_distributor_metadata(
+        ctx: Context<SetDistributorMetadata>,
+        name: String,
+        uri: String,
+    ) -> Result<()> {
+        handle_set_distributor_metadata(ctx, name, uri)
+    }
+
+    /**
+     * Permanently locks the merkle root, timing and claim fee
+     *
+     * Once finalized, set_merkle_root, set_time, extend_time and set_claim_fee
+     * are rejected, so the airdrop terms can no longer be altered.
+     *
+     * @param ctx - Account context containing distributor and owner accounts
+     *
+     * Access Control: Owner only
+     */
+    pub fn finalize(ctx: Context<Finalize>) -> Result<()> {
+        handle_finalize(ctx)
+    }
+
     /**
      * Claims tokens with merkle proof verification
      *
      * Allows eligible users to claim their allocated tokens by providing a valid merkle proof
-     * @param ctx - Account context containing distributor, claim status, and token accounts
+     * @param ctx - Account context containing distributor, claim status, token and payer accounts, with transfer hook accounts in remaining accounts
      * @param max_amount - Maximum amount this user is eligible to claim
+     * @param proof - Array of 32-byte hashes forming the merkle proof (empty if an earlier claim verified the allocation under the current root)
+     * @param amount - Optional amount to claim now, up to max_amount - claimed_amount (defaults to all of it)
+     * @param unlock_time - The claimant's unlock time from the merkle tree (tree version 2 only)
+     * @param cosign_expiry - Expiry of the operator co-signature (required only when the distributor requires one)
+     *
+     * Access Control: Any user with valid merkle proof, co-signed by an operator if required
+     */
+    pub fn claim<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Claim<'info>>,
+        max_amount: u64,
+        proof: Vec<[u8; 32]>,
+        amount: Option<u64>,
+        unlock_time: Option<i64>,
+        cosign_expiry: Option<i64>,
+    ) -> Result<()> {
+        handle_claim(ctx, max_amount, proof, amount, unlock_time, cosign_expiry)
+    }
+
+    /**
+     * Claims tokens from several distributors in one transaction
+     *
+     * Lets a claimant eligible across concurrent distributors of the same mint
+     * bundle their claims. Each entry claims the full pending amount.
+     *
+     * @param ctx - Account context with one (distributor, claim status, vault) triple per entry in remaining accounts, followed by transfer hook accounts
+     * @param entries - One (max_amount, proof) entry per distributor
+     *
+     * Access Control: Any user with valid merkle proofs
+     */
+    pub fn claim_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimBatch<'info>>,
+        entries: Vec<BatchClaimEntry>,
+    ) -> Result<()> {
+        handle_claim_batch(ctx, entries)
+    }
+
+    /**
+     * Claims tokens on behalf of a claimant authorized by an ed25519 signature
+     *
+     * Lets a relayer submit and pay for the claim, so claimants without SOL can
+     * claim. Must be preceded by an ed25519 program instruction verifying the
+     * claimant's signature over the claim message.
+     *
+     * @param ctx - Account context containing distributor, claim status, token, payer and instructions sysvar accounts, with transfer hook accounts in remaining accounts
+     * @param max_amount - Maximum amount this user is eligible to claim
+     * @param proof - Array of 32-byte hashes forming the merkle proof
+     * @param amount - Optional amount to claim now (defaults to the full pending amount)
+     * @param unlock_time - The claimant's unlock time from the merkle tree (tree version 2 only)
+     * @param expiry - Unix timestamp after which the signed voucher is no longer valid
+     *
+     * Access Control: Anyone holding a valid claimant signature and merkle proof (listed relayers only once any is listed)
+     * Note: Each voucher is bound to the claimant's voucher nonce and can only be used once
+     */
+    #[cfg(feature = "claim-with-signature")]
+    pub fn claim_with_signature<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimWithSignature<'info>>,
+        max_amount: u64,
+        proof: Vec<[u8; 32]>,
+        amount: Option<u64>,
+        unlock_time: Option<i64>,
+        expiry: i64,
+    ) -> Result<()> {
+        handle_claim_with_signature(ctx, max_amount, proof, amount, unlock_time, expiry)
+    }
+
+    /**
+     * Claims native SOL with merkle proof verification
+     *
+     * @param ctx - Account context containing distributor, claim status, lamport vault and payer accounts
+     * @param max_amount - Maximum amount of lamports this user is eligible to claim
      * @param proof - Array of 32-byte hashes forming the merkle proof
+     * @param amount - Optional amount to claim now (defaults to the full pending amount)
+     * @param unlock_time - The claimant's unlock time from the merkle tree (tree version 2 only)
      *
      * Access Control: Any user with valid merkle proof
      */
-    pub fn claim(ctx: Context<Claim>, max_amount: u64, proof: Vec<[u8; 32]>) -> Result<()> {
-        handle_claim(ctx, max_amount, proof)
+    pub fn claim_sol(
+        ctx: Context<ClaimSol>,
+        max_amount: u64,
+        proof: Vec<[u8; 32]>,
+        amount: Option<u64>,
+        unlock_time: Option<i64>,
+    ) -> Result<()> {
+        handle_claim_sol(ctx, max_amount, proof, amount, unlock_time)
+    }
+
+    /**
+     * Claims every mint of a multi-mint distributor in one transaction
+     *
+     * Verifies a single merkle proof over all of the claimant's amounts and
+     * transfers the full pending amount of each mint.
+     *
+     * @param ctx - Account context as for claim, with five accounts per extra mint in remaining accounts, followed by transfer hook accounts
+     * @param amounts - Amounts from the merkle leaf, the distributor's own mint first
+     * @param proof - Array of 32-byte hashes forming the merkle proof
+     *
+     * Access Control: Any user with valid merkle proof
+     */
+    #[cfg(feature = "multi-mint")]
+    pub fn claim_multi_mint<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimMultiMint<'info>>,
+        amounts: Vec<u64>,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        handle_claim_multi_mint(ctx, amounts, proof)
+    }
+
+    /**
+     * Claims tokens to the recipient specified in the claimant's merkle leaf
+     *
+     * For tree version 4 distributors: the claimant signs, and tokens go to the
+     * recipient bound into the leaf, such as a vesting contract or DAO sub-account.
+     *
+     * @param ctx - Account context as for claim, with the recipient and its token account, and transfer hook accounts in remaining accounts
+     * @param max_amount - Maximum amount this user is eligible to claim
+     * @param proof - Array of 32-byte hashes forming the merkle proof
+     * @param amount - Optional amount to claim now (defaults to the full pending amount)
+     *
+     * Access Control: Any claimant with valid merkle proof
+     */
+    pub fn claim_to_recipient<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimToRecipient<'info>>,
+        max_amount: u64,
+        proof: Vec<[u8; 32]>,
+        amount: Option<u64>,
+    ) -> Result<()> {
+        handle_claim_to_recipient(ctx, max_amount, proof, amount)
+    }
+
+    /**
+     * Redirects the claim of a claimant whose token account is frozen
+     *
+     * Mints with a freeze authority can leave a claimant's associated token account
+     * frozen, failing their claims with ClaimantAccountFrozen. The operator settles
+     * the pending allocation to the clawback receiver, or the owner without one.
+     * Without the claimant's signature, the first call only reports the frozen
+     * account and the redirect waits for FROZEN_CLAIM_GRACE_PERIOD after it.
+     *
+     * @param ctx - Account context containing distributor, claim status, the claimant (optionally signing its consent), the claimant's frozen token account, receiver token account and operator accounts, with transfer hook accounts in remaining accounts
+     * @param max_amount - Maximum amount the claimant is eligible to claim
+     * @param proof - Array of 32-byte hashes forming the claimant's merkle proof
+     * @param unlock_time - The claimant's unlock time from the merkle tree (tree version 2 only)
+     *
+     * Access Control: Operator only
+     */
+    pub fn redirect_frozen_claim<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RedirectFrozenClaim<'info>>,
+        max_amount: u64,
+        proof: Vec<[u8; 32]>,
+        unlock_time: Option<i64>,
+    ) -> Result<()> {
+        handle_redirect_frozen_claim(ctx, max_amount, proof, unlock_time)
+    }
+
+    /**
+     * Releases the unlocked tokens of a claim escrow
+     *
+     * Transfers the part of the claimant's escrowed tokens unlocked so far and
+     * not yet released, also after the distribution has ended or been closed.
+     *
+     * @param ctx - Account context containing claim escrow, token and claimant accounts, with transfer hook accounts in remaining accounts
+     *
+     * Access Control: Claimant only
+     */
+    #[cfg(feature = "claim-escrow")]
+    pub fn release<'info>(ctx: Context<'_, '_, 'info, 'info, Release<'info>>) -> Result<()> {
+        handle_release(ctx)
+    }
+
+    /**
+     * Verifies a claimant's allocation without transferring tokens
+     *
+     * Read-only check for other programs: validates the merkle proof against
+     * the distributor's roots and returns the allocation as return data, so
+     * callers can gate features on allocation size via CPI.
+     *
+     * @param ctx - Account context containing distributor, optional claim status and claimant accounts
+     * @param max_amount - Maximum amount this user is eligible to claim
+     * @param proof - Array of 32-byte hashes forming the merkle proof
+     * @param unlock_time - The claimant's unlock time from the merkle tree (tree version 2 only)
+     *
+     * Access Control: Permissionless
+     */
+    pub fn verify_allocation(
+        ctx: Context<VerifyAllocation>,
+        max_amount: u64,
+        proof: Vec<[u8; 32]>,
+        unlock_time: Option<i64>,
+    ) -> Result<VerifiedAllocation> {
+        handle_verify_allocation(ctx, max_amount, proof, unlock_time)
+    }
+
+    /**
+     * Quotes the amount a claim would pay out now without claiming
+     *
+     * Runs every check of claim and returns the claimable amount and fees as
+     * return data, without writing any account or transferring tokens, so
+     * frontends can show "you can claim X now" from a simulation.
+     *
+     * @param ctx - Account context containing distributor, optional claim status, vault and claimant accounts
+     * @param max_amount - Maximum amount this user is eligible to claim
+     * @param proof - Array of 32-byte hashes forming the merkle proof
+     * @param unlock_time - The claimant's unlock time from the merkle tree (tree version 2 only)
+     *
+     * Access Control: Permissionless
+     */
+    pub fn quote_claim(
+        ctx: Context<QuoteClaim>,
+        max_amount: u64,
+        proof: Vec<[u8; 32]>,
+        unlock_time: Option<i64>,
+    ) -> Result<ClaimQuote> {
+        handle_quote_claim(ctx, max_amount, proof, unlock_time)
+    }
+
+    /**
+     * Reconciles a claim status with a reduced allocation
+     *
+     * Records how much a claimant claimed beyond the allocation a later merkle
+     * root gave them, so downgraded allocations are accounted for on the claim
+     * status and the distributor instead of only failing the claimant's claims.
+     * The claims stay rejected until a later root raises the allocation above the
+     * claimed amount; adjusting with such an allocation clears the record.
+     *
+     * @param ctx - Account context containing distributor, claim status, claimant and operator accounts
+     * @param max_amount - The claimant's reduced allocation from the merkle tree
+     * @param proof - Array of 32-byte hashes forming the merkle proof
+     * @param unlock_time - The claimant's unlock time from the merkle tree (tree version 2 only)
+     *
+     * Access Control: Operator only
+     */
+    pub fn adjust_claim_status(
+        ctx: Context<AdjustClaimStatus>,
+        max_amount: u64,
+        proof: Vec<[u8; 32]>,
+        unlock_time: Option<i64>,
+    ) -> Result<()> {
+        handle_adjust_claim_status(ctx, max_amount, proof, unlock_time)
     }
 
     /**
@@ -113,22 +890,114 @@ pub mod token_distributor {
      * Allows the owner to reclaim any undistributed tokens after the distribution
      * period has ended. This also closes the distributor and vault accounts.
      *
-     * @param ctx - Account context containing distributor, vault, and owner accounts
+     * @param ctx - Account context containing distributor, vault, and owner accounts, with transfer hook accounts in remaining accounts
      *
      * Access Control: Owner only
      * Note: This provides complete cleanup and rent recovery
      */
-    pub fn withdraw(ctx: Context<Withdraw>) -> Result<()> {
+    pub fn withdraw<'info>(ctx: Context<'_, '_, 'info, 'info, Withdraw<'info>>) -> Result<()> {
         handle_withdraw(ctx)
     }
 
+    /**
+     * Closes the emptied token vault while keeping the distributor open
+     *
+     * Returns the vault's rent to the owner once the distribution has completed
+     * or ended, leaving the distributor for historical queries and for
+     * close_claim_status, which keeps checking the distribution's end.
+     *
+     * @param ctx - Account context containing distributor, vault, and owner accounts
+     *
+     * Access Control: Owner only
+     * Note: The distributor can no longer be withdrawn or clawed back afterwards
+     */
+    pub fn close_vault(ctx: Context<CloseVault>) -> Result<()> {
+        handle_close_vault(ctx)
+    }
+
+    /**
+     * Withdraws part of the vault balance without closing any accounts
+     *
+     * Lets the owner sweep excess tokens (e.g. an over-deposit) while keeping
+     * the distributor and vault open for a later round.
+     *
+     * @param ctx - Account context containing distributor, vault, and owner accounts, with transfer hook accounts in remaining accounts
+     * @param amount - Amount of tokens to withdraw
+     *
+     * Access Control: Owner only
+     * Note: Only callable before start_time or after end_time
+     */
+    pub fn withdraw_partial<'info>(ctx: Context<'_, '_, 'info, 'info, WithdrawPartial<'info>>, amount: u64) -> Result<()> {
+        handle_withdraw_partial(ctx, amount)
+    }
+
+    /**
+     * Withdraws remaining SOL after distribution ends
+     *
+     * Empties the lamport vault of a native SOL distributor to the owner and
+     * closes the distributor.
+     *
+     * @param ctx - Account context containing distributor, lamport vault and owner accounts
+     *
+     * Access Control: Owner only
+     * Note: Only callable after distribution ends or if never started
+     */
+    pub fn withdraw_sol(ctx: Context<WithdrawSol>) -> Result<()> {
+        handle_withdraw_sol(ctx)
+    }
+
+    /**
+     * Withdraws the vault of an extra mint after distribution ends
+     *
+     * Empties the extra mint's vault to the owner, or to the clawback receiver
+     * when one is configured, and closes it. Every extra mint must be withdrawn
+     * before withdraw or clawback can close the distributor.
+     *
+     * @param ctx - Account context containing distributor, distributor mint, vault and receiver accounts, with transfer hook accounts in remaining accounts
+     *
+     * Access Control: Owner only, or permissionless after end_time + 7 days when a clawback receiver is set
+     */
+    #[cfg(feature = "multi-mint")]
+    pub fn withdraw_distributor_mint<'info>(ctx: Context<'_, '_, 'info, 'info, WithdrawDistributorMint<'info>>) -> Result<()> {
+        handle_withdraw_distributor_mint(ctx)
+    }
+
+    /**
+     * Withdraws accumulated claim fees
+     *
+     * Sweeps the claim fees collected on the distributor account to the owner.
+     *
+     * @param ctx - Account context containing distributor and owner accounts
+     *
+     * Access Control: Owner only
+     * Note: Can be called at any time
+     */
+    #[cfg(feature = "claim-fee")]
+    pub fn withdraw_claim_fees(ctx: Context<WithdrawClaimFees>) -> Result<()> {
+        handle_withdraw_claim_fees(ctx)
+    }
+
+    /**
+     * Claws back unclaimed tokens to the configured receiver
+     *
+     * Transfers the remaining vault balance to the clawback receiver set at
+     * creation, then closes the vault and distributor accounts.
+     *
+     * @param ctx - Account context containing distributor, vault and receiver accounts, with transfer hook accounts in remaining accounts
+     *
+     * Access Control: Permissionless once scheduled, after end_time + 7 days grace period
+     */
+    pub fn clawback<'info>(ctx: Context<'_, '_, 'info, 'info, ClawbackVault<'info>>) -> Result<()> {
+        handle_clawback(ctx)
+    }
+
     /**
      * Closes a ClaimStatus account and reclaims rent
      *
      * Allows users to close their ClaimStatus accounts after the distribution
-     * has ended to reclaim the rent they paid during account creation.
+     * has ended. The rent goes back to whoever paid it during account creation.
      *
-     * @param ctx - Account context containing claim status and claimant accounts
+     * @param ctx - Account context containing claim status, claimant and rent payer accounts
      *
      * Access Control: Claimant only (enforced by PDA seeds)
      *
@@ -137,4 +1006,246 @@ pub mod token_distributor {
     pub fn close_claim_status(ctx: Context<CloseClaimStatus>) -> Result<()> {
         handle_close_claim_status(ctx)
     }
+
+    /**
+     * Closes several ClaimStatus accounts in one transaction
+     *
+     * Lets a claimant reclaim rent across many ended distributions at once.
+     * Rent for each account goes back to whoever paid it.
+     *
+     * @param ctx - Account context with one (claim status, distributor, rent payer) triple per account in remaining accounts
+     *
+     * Access Control: Claimant only (enforced by PDA derivation)
+     */
+    pub fn close_claim_status_many<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseClaimStatusMany<'info>>,
+    ) -> Result<()> {
+        handle_close_claim_status_many(ctx)
+    }
+
+    /**
+     * Closes a stale ClaimStatus account left behind by its claimant
+     *
+     * Cleans up ClaimStatus accounts long after a distribution ended. The rent
+     * goes back to whoever paid it during account creation, not to the caller
+     * (nor to a claimant whose account was sponsored).
+     *
+     * @param ctx - Account context containing claim status, claimant, rent payer and distributor accounts
+     *
+     * Access Control: Permissionless after end_time + 180 days (or once the distributor is closed)
+     */
+    pub fn garbage_collect_claim_status(ctx: Context<GarbageCollectClaimStatus>) -> Result<()> {
+        handle_garbage_collect_claim_status(ctx)
+    }
+
+    /**
+     * Funds the distributor's rent escrow
+     *
+     * Deposits lamports into the escrow from which ClaimStatus rent is paid on
+     * behalf of claimants.
+     *
+     * @param ctx - Account context containing distributor, rent escrow and owner accounts
+     * @param amount - Amount of lamports to deposit
+     *
+     * Access Control: Owner only
+     */
+    pub fn fund_rent_escrow(ctx: Context<FundRentEscrow>, amount: u64) -> Result<()> {
+        handle_fund_rent_escrow(ctx, amount)
+    }
+
+    /**
+     * Creates a ClaimStatus account paid from the rent escrow
+     *
+     * Lets claimants without SOL for rent create their ClaimStatus ahead of claim
+     * in the same transaction. The rent returns to the escrow on close.
+     *
+     * @param ctx - Account context containing distributor, rent escrow, claim status and claimant accounts
+     *
+     * Access Control: Claimant only (enforced by PDA seeds)
+     */
+    pub fn create_claim_status(ctx: Context<CreateClaimStatus>) -> Result<()> {
+        handle_create_claim_status(ctx)
+    }
+
+    /**
+     * Withdraws the rent escrow
+     *
+     * Returns the remaining escrow lamports, including rent refunded by closed
+     * ClaimStatus accounts, to the owner after the distribution has ended.
+     *
+     * @param ctx - Account context containing rent escrow, owner and distributor accounts
+     *
+     * Access Control: Owner only (enforced by PDA seeds)
+     */
+    pub fn withdraw_rent_escrow(ctx: Context<WithdrawRentEscrow>) -> Result<()> {
+        handle_withdraw_rent_escrow(ctx)
+    }
+
+    /**
+     * Closes a shared distributor
+     *
+     * Releases the distributor's unclaimed budget back to the shared vault and
+     * closes the distributor account once the distribution has ended.
+     *
+     * @param ctx - Account context containing distributor, shared vault and owner accounts
+     *
+     * Access Control: Owner only
+     */
+    pub fn close_shared_distributor(ctx: Context<CloseSharedDistributor>) -> Result<()> {
+        handle_close_shared_distributor(ctx)
+    }
+
+    /**
+     * Closes a distributor's metadata account
+     *
+     * Returns the metadata rent to the owner once the distribution has ended
+     * or the distributor has been closed.
+     *
+     * @param ctx - Account context containing metadata, owner and distributor accounts
+     *
+     * Access Control: Owner recorded in the metadata only
+     */
+    pub fn close_distributor_metadata(ctx: Context<CloseDistributorMetadata>) -> Result<()> {
+        handle_close_distributor_metadata(ctx)
+    }
+
+    /**
+     * Withdraws unallocated tokens from a shared vault
+     *
+     * @param ctx - Account context containing shared vault, token vault and owner accounts, with transfer hook accounts in remaining accounts
+     * @param amount - Amount of tokens to withdraw
+     *
+     * Access Control: Owner only
+     * Note: Budget allocated to open shared distributors cannot be withdrawn
+     */
+    pub fn withdraw_shared_vault<'info>(ctx: Context<'_, '_, 'info, 'info, WithdrawSharedVault<'info>>, amount: u64) -> Result<()> {
+        handle_withdraw_shared_vault(ctx, amount)
+    }
+
+    /**
+     * Adds an operator to the distributor
+     *
+     * Stores the operator in the first free slot of the operator list.
+     * Any listed operator can set the time and merkle root.
+     *
+     * @param ctx - Account context containing distributor and owner accounts
+     * @param operator - Public key of the operator to add
+     *
+     * Access Control: Owner only
+     */
+    pub fn add_operator(ctx: Context<AddOperator>, operator: Pubkey) -> Result<()> {
+        handle_add_operator(ctx, operator)
+    }
+
+    /**
+     * Removes an operator from the distributor
+     *
+     * Clears the operator slot so the key can no longer manage the distribution.
+     * Used to rotate compromised or retired operator keys.
+     *
+     * @param ctx - Account context containing distributor and owner accounts
+     * @param operator - Public key of the operator to remove
+     *
+     * Access Control: Owner only
+     */
+    pub fn remove_operator(ctx: Context<RemoveOperator>, operator: Pubkey) -> Result<()> {
+        handle_remove_operator(ctx, operator)
+    }
+
+    /**
+     * Adds a relayer to the distributor's relayer allowlist
+     *
+     * Once any relayer is listed, only listed relayers can submit
+     * claim_with_signature for the distributor's claimants.
+     *
+     * @param ctx - Account context containing distributor and owner accounts
+     * @param relayer - Public key of the relayer to add
+     *
+     * Access Control: Owner only
+     */
+    #[cfg(feature = "claim-with-signature")]
+    pub fn add_relayer(ctx: Context<AddRelayer>, relayer: Pubkey) -> Result<()> {
+        handle_add_relayer(ctx, relayer)
+    }
+
+    /**
+     * Removes a relayer from the distributor's relayer allowlist
+     *
+     * Removing the last relayer opens claim_with_signature to any relayer again.
+     *
+     * @param ctx - Account context containing distributor and owner accounts
+     * @param relayer - Public key of the relayer to remove
+     *
+     * Access Control: Owner only
+     */
+    #[cfg(feature = "claim-with-signature")]
+    pub fn remove_relayer(ctx: Context<RemoveRelayer>, relayer: Pubkey) -> Result<()> {
+        handle_remove_relayer(ctx, relayer)
+    }
+
+    /**
+     * Pauses claims for the distribution
+     *
+     * Stops all claims until the distribution is unpaused, e.g. when a bad
+     * merkle root has been published.
+     *
+     * @param ctx - Account context containing distributor and authority accounts
+     *
+     * Access Control: Owner or operator
+     */
+    pub fn pause(ctx: Context<Pause>) -> Result<()> {
+        handle_pause(ctx)
+    }
+
+    /**
+     * Unpauses claims for the distribution
+     *
+     * @param ctx - Account context containing distributor and authority accounts
+     *
+     * Access Control: Owner or operator
+     */
+    pub fn unpause(ctx: Context<Unpause>) -> Result<()> {
+        handle_unpause(ctx)
+    }
+
+    /**
+     * Freezes the caller's claim
+     *
+     * Blocks further claims for the claimant's address, e.g. after a key leak,
+     * until the claimant unfreezes it after a 7 day timelock.
+     *
+     * @param ctx - Account context containing distributor, claim status and claimant accounts
+     *
+     * Access Control: Claimant only (enforced by PDA seeds)
+     */
+    pub fn freeze_my_claim(ctx: Context<FreezeMyClaim>) -> Result<()> {
+        handle_freeze_my_claim(ctx)
+    }
+
+    /**
+     * Unfreezes the caller's claim
+     *
+     * @param ctx - Account context containing distributor, claim status, claimant and authority accounts
+     *
+     * Access Control: Claimant (enforced by PDA seeds) co-signed by the owner or an operator
+     * Note: Only allowed once the unfreeze timelock has elapsed
+     */
+    pub fn unfreeze_my_claim(ctx: Context<UnfreezeMyClaim>) -> Result<()> {
+        handle_unfreeze_my_claim(ctx)
+    }
+
+    /**
+     * Sets or clears the delegate allowed to claim on the caller's behalf
+     *
+     * Lets custodians such as exchanges execute claim for their users without
+     * holding their keys, the tokens still go to the claimant's token account.
+     *
+     * @param ctx - Account context containing distributor, claim status and claimant accounts
+     * @param delegate - Key allowed to claim for the claimant (None clears the delegate)
+     *
+     * Access Control: Claimant only (enforced by PDA seeds)
+     */
+    pub fn set_claim_delegate(ctx: Context<SetClaimDelegate>, delegate: Option<Pubkey>) -> Result<()> {
+        handle_set_claim_delegate(ctx, delegate)
+    }
 }
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+/**
+ * Wallet attestation account
+ *
+ * This struct records an attestor's assessment of a wallet (e.g. account age
+ * or on-chain activity) as a score. Distributors can require claimants to hold
+ * an attestation from an attestor of their choice with a minimum score, an
+ * anti-sybil gate that does not hardcode any single provider.
+ *
+ * Derivation: ["attestation", attestor, subject]
+ *
+ * Lifecycle:
+ * 1. Created or refreshed by the attestor via attest
+ * 2. Checked by claims of distributors configured with this attestor
+ * 3. Closed by the attestor via revoke_attestation, returning rent to the attestor
+ *
+ * Design Notes:
+ * - One Attestation account per (attestor, subject) pair, shared by all distributors
+ * - The meaning and scale of the score are defined by the attestor
+ */
+#[account]
+#[derive(Default, Debug)]
+pub struct Attestation {
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+
+    /// Key that issued the attestation
+    pub attestor: Pubkey,
+
+    /// Wallet the attestation is about
+    pub subject: Pubkey,
+
+    /// Wallet-quality score assigned by the attestor
+    pub score: u64,
+
+    /// Time the attestation was last issued (Unix timestamp)
+    pub issued_at: i64,
+
+    /// Time after which the attestation is no longer valid (Unix timestamp)
+    /// - 0 if it does not expire
+    pub expires_at: i64,
+}
+
+impl Attestation {
+    /// Calculate the space required for this account
+    /// - Includes 8-byte discriminator + struct size
+    pub const LEN: usize = 8 + std::mem::size_of::<Attestation>();
+
+    /// Check whether the attestation is still valid at the given time
+    pub fn is_valid_at(&self, timestamp: i64) -> bool {
+        self.expires_at == 0 || timestamp <= self.expires_at
+    }
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+/**
+ * Claim escrow account
+ *
+ * This struct tracks the tokens a claimant of an escrowed distributor has
+ * claimed into their escrow token account, and how much of them they have
+ * released. Escrowed tokens unlock linearly over the distributor's release
+ * duration, starting at the claimant's first claim.
+ *
+ * Derivation: ["claim_escrow", distributor, claimant]
+ * Token account: ["escrow_vault", distributor, claimant], owned by this PDA
+ *
+ * Lifecycle:
+ * 1. Created with its token account by the claimant's first claim
+ * 2. Topped up by later claims, which follow the same release schedule
+ * 3. Drawn down by the claimant via release
+ *
+ * Design Notes:
+ * - Holds everything release needs, so tokens stay releasable after the
+ *   distributor is withdrawn and closed
+ * - Release times are unix timestamps, also for slot-mode distributors
+ */
+#[account]
+#[derive(Default, Debug)]
+pub struct ClaimEscrow {
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+
+    /// Distributor the escrowed tokens were claimed from
+    pub distributor: Pubkey,
+
+    /// Claimant the escrowed tokens are released to
+    pub claimant: Pubkey,
+
+    /// Mint of the escrowed tokens
+    pub token_mint: Pubkey,
+
+    /// Total amount claimed into the escrow
+    pub escrowed_amount: u64,
+
+    /// Total amount released to the claimant
+    pub released_amount: u64,
+
+    /// Time the release started, the claimant's first claim (Unix timestamp)
+    pub release_start: i64,
+
+    /// Period over which escrowed tokens are released, in seconds
+    /// - Copied from the distributor at the first claim
+    pub release_duration: i64,
+}
+
+impl ClaimEscrow {
+    /// Calculate the space required for this account
+    /// - Includes 8-byte discriminator + struct size
+    pub const LEN: usize = 8 + std::mem::size_of::<ClaimEscrow>();
+
+    /// Time the escrowed tokens are fully released (Unix timestamp)
+    pub fn release_end(&self) -> i64 {
+        self.release_start.saturating_add(self.release_duration)
+    }
+
+    /// Amount of the escrowed tokens unlocked at the given time
+    /// - Unlocks linearly from release_start, tokens escrowed later unlock
+    ///   as if they had been escrowed at release_start
+    pub fn unlocked_amount(&self, timestamp: i64) -> u64 {
+        if timestamp >= self.release_end() || self.release_duration <= 0 {
+            return self.escrowed_amount;
+        }
+        let elapsed = timestamp.saturating_sub(self.release_start).max(0);
+        (self.escrowed_amount as u128 * elapsed as u128 / self.release_duration as u128) as u64
+    }
+
+    /// Amount the claimant can release at the given time
+    pub fn releasable_amount(&self, timestamp: i64) -> u64 {
+        self.unlocked_amount(timestamp).saturating_sub(self.released_amount)
+    }
+}
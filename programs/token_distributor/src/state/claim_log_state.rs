@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+/**
+ * Page of a distributor's claim log
+ *
+ * This struct keeps a compact on-chain record of every claim of a distributor
+ * that enabled its claim log, so proof of claim survives RPC event pruning
+ * without relying on event indexing.
+ *
+ * Derivation: ["claim_log", distributor_key, page_index]
+ *
+ * Lifecycle:
+ * 1. Created by the claim that writes the page's first record (using init_if_needed)
+ * 2. Appended to by every claim until CLAIM_LOG_PAGE_LEN records are written
+ * 3. Never closed, the log outlives the distributor
+ *
+ * Design Notes:
+ * - Zero-copy account, so appending a record does not deserialize the page
+ * - Record n of the log is records[n % CLAIM_LOG_PAGE_LEN] of page n / CLAIM_LOG_PAGE_LEN
+ */
+#[account(zero_copy)]
+#[derive(Debug)]
+pub struct ClaimLog {
+    /// Distributor whose claims are logged
+    pub distributor: Pubkey,
+
+    /// Index of this page in the log
+    pub page: u64,
+
+    /// Number of records written to this page
+    pub len: u64,
+
+    /// Claim records in claim order, only the first len are written
+    pub records: [ClaimRecord; CLAIM_LOG_PAGE_LEN],
+}
+
+/// A single claim recorded in a ClaimLog
+#[zero_copy]
+#[derive(Default, Debug, PartialEq)]
+pub struct ClaimRecord {
+    /// The claimant whose allocation was claimed
+    pub claimant: Pubkey,
+
+    /// Amount claimed, including any protocol fee
+    pub amount: u64,
+
+    /// Time of the claim (Unix timestamp)
+    pub claimed_at: i64,
+}
+
+impl ClaimLog {
+    /// Calculate the space required for this account
+    /// - Includes 8-byte discriminator + struct size
+    pub const LEN: usize = 8 + std::mem::size_of::<ClaimLog>();
+
+    /// Append a record, returning false if the page is full
+    pub fn push(&mut self, record: ClaimRecord) -> bool {
+        let Some(slot) = self.records.get_mut(self.len as usize) else {
+            return false;
+        };
+        *slot = record;
+        self.len += 1;
+        true
+    }
+
+    /// The records written to this page
+    pub fn records(&self) -> &[ClaimRecord] {
+        &self.records[..self.len as usize]
+    }
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::constants::*;
 
 /**
  * Individual claim status account
@@ -10,24 +11,93 @@ use anchor_lang::prelude::*;
  * Derivation: ["claim", distributor_key, claimant_key]
  * 
  * Lifecycle:
- * 1. Created on first claim (using init_if_needed)
- * 2. Updated with each subsequent claim
- * 3. Can be closed after distribution ends for rent reclamation
+ * 1. Created on first claim, freeze or delegation (using init_if_needed)
+ * 2. Updated with each subsequent claim, freeze / unfreeze, delegate change,
+ *    frozen account report or reconciliation of a reduced allocation by an operator
+ * 3. Can be closed after distribution ends, returning rent to the original payer
+ * 4. Can be garbage collected by anyone 180 days after distribution ends,
+ *    still returning rent to the original payer
  * 
  * Design Notes:
  * - One ClaimStatus account per (distributor, claimant) pair
  * - Enables efficient tracking of individual claim progress
  * - Prevents double-claiming when operator updates merkle root
+ * 
+ * Layout:
+ * - Accounts created before the per-claimant fields hold only claimed_amount
+ *   (LEGACY_LEN bytes) and must be grown with migrate_claim_status before use
+ * - Every later field was appended, so migrated accounts keep their claimed amount,
+ *   start with the new fields zeroed and record the claimant as rent payer
  */
 #[account]
 #[derive(Default, Debug)]
 pub struct ClaimStatus {
     /// Total amount claimed by this user (cumulative)
     pub claimed_amount: u64,
+    
+    /// Whether further claims are blocked by the claimant
+    /// - Set via freeze_my_claim, cleared via unfreeze_my_claim
+    pub frozen: bool,
+    
+    /// Time the claim was last frozen (Unix timestamp)
+    /// - Unfreezing is only allowed after frozen_at + UNFREEZE_TIMELOCK
+    pub frozen_at: i64,
+    
+    /// Account that paid the rent for this claim status
+    /// - Set when the account is created
+    /// - Receives the rent back when the account is closed
+    pub rent_payer: Pubkey,
+    
+    /// Nonce of the next claim_with_signature voucher
+    /// - Signed into each voucher and incremented when one is used
+    /// - Prevents relayed claim authorizations from being replayed
+    pub voucher_nonce: u64,
+    
+    /// Amounts claimed of each extra mint of a multi-mint distributor (cumulative)
+    /// - Indexed like the extra amounts of the merkle leaf, unused for other distributors
+    pub extra_claimed_amounts: [u64; MAX_EXTRA_MINTS],
+    
+    /// Key allowed to execute claim on the claimant's behalf
+    /// - Set via set_claim_delegate, Pubkey::default() if none
+    /// - Tokens still go to the claimant's token account
+    pub delegate: Pubkey,
+    
+    /// Amount claimed beyond the claimant's current allocation
+    /// - Recorded by adjust_claim_status after a root lowered max_amount below claimed_amount
+    /// - Cleared by the next claim once a later root allocates at least claimed_amount again
+    pub over_claimed_amount: u64,
+    
+    /// Leaf of the allocation last verified against the distributor's current merkle root
+    /// - Recorded by claims whose proof resolved to the current root
+    /// - Lets later claims of the same allocation under that root omit the proof
+    pub verified_leaf: [u8; 32],
+    
+    /// Distributor's root_update_count when verified_leaf was verified
+    /// - verified_leaf is ignored once a root update changes the count
+    pub verified_root_update: u64,
+    
+    /// Time an operator reported the claimant's token account frozen (Unix timestamp)
+    /// - Set by the first redirect_frozen_claim without the claimant's signature
+    /// - The claim can only be redirected FROZEN_CLAIM_GRACE_PERIOD after it
+    /// - Cleared by any claim, including the redirect itself
+    pub frozen_reported_at: i64,
 }
 
 impl ClaimStatus {
     /// Calculate the space required for this account
     /// - Includes 8-byte discriminator + struct size
     pub const LEN: usize = 8 + std::mem::size_of::<ClaimStatus>();
+    
+    /// Size of accounts created before the per-claimant fields (discriminator + claimed_amount)
+    pub const LEGACY_LEN: usize = 8 + 8;
+    
+    /// Check whether the leaf was verified against the root of the given root update
+    pub fn has_verified_leaf(&self, leaf: &[u8; 32], root_update_count: u64) -> bool {
+        self.verified_root_update == root_update_count && self.verified_leaf == *leaf
+    }
+    
+    /// Check whether a key is the claimant's delegate
+    pub fn is_delegate(&self, key: &Pubkey) -> bool {
+        self.delegate != Pubkey::default() && *key == self.delegate
+    }
 } 
\ No newline at end of file
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::*;
+use super::ActivationType;
+
+/**
+ * Program-wide configuration account
+ *
+ * This struct holds the global admin, an emergency pause switch covering every
+ * campaign, and the default parameters applied when distributors are created
+ * and managed.
+ *
+ * Derivation: ["config"]
+ *
+ * Lifecycle:
+ * 1. Created once by the program upgrade authority via initialize_config
+ * 2. Parameters and admin updated by the admin via update_config
+ * 3. Global pause toggled by the admin via set_global_pause
+ * 4. Per-owner active distributor limits set by the admin via set_owner_distributor_limit
+ *
+ * Design Notes:
+ * - Singleton account, one per program deployment
+ * - Until it is initialized, instructions fall back to the constant defaults
+ * - The protocol fee is taken out of the claimed amount, so a fee of 0 leaves
+ *   claims unchanged
+ */
+#[account]
+#[derive(Default, Debug)]
+pub struct ProgramConfig {
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+
+    /// Global admin
+    /// - Can update the parameters and toggle the global pause
+    pub admin: Pubkey,
+
+    /// Whether all claims and distributor creations are paused
+    pub paused: bool,
+
+    /// Minimum distribution duration in seconds
+    /// - Defaults to MIN_DURATION
+    pub min_duration: i64,
+
+    /// Maximum distribution duration in seconds
+    /// - Defaults to MAX_DURATION
+    pub max_duration: i64,
+
+    /// Duration used when none is provided at creation, in seconds
+    /// - Defaults to DURATION
+    pub default_duration: i64,
+
+    /// Maximum lamport fee per claim
+    /// - Defaults to MAX_CLAIM_FEE
+    pub max_claim_fee: u64,
+
+    /// Protocol fee deducted from each claim, in basis points
+    /// - 0 disables the protocol fee, capped at MAX_PROTOCOL_FEE_BPS
+    pub protocol_fee_bps: u16,
+
+    /// Owner of the accounts receiving the protocol fee
+    /// - Token claims pay into a token account owned by this address,
+    ///   native SOL claims pay this address directly
+    pub protocol_fee_receiver: Pubkey,
+
+    /// Maximum number of distributors an owner may have open at once
+    /// - 0 disables the limit, owners can be given their own limit via set_owner_distributor_limit
+    /// - Bounds state growth by a single integrator on shared deployments
+    pub max_active_distributors: u32,
+}
+
+impl ProgramConfig {
+    /// Calculate the space required for this account
+    /// - Includes 8-byte discriminator + struct size
+    pub const LEN: usize = 8 + std::mem::size_of::<ProgramConfig>();
+
+    /// Configuration in effect before the config account is initialized
+    pub fn with_defaults() -> Self {
+        Self {
+            min_duration: MIN_DURATION,
+            max_duration: MAX_DURATION,
+            default_duration: DURATION,
+            max_claim_fee: MAX_CLAIM_FEE,
+            ..Default::default()
+        }
+    }
+
+    /// Reject the operation while the global pause is on
+    pub fn require_not_paused(&self) -> Result<()> {
+        require!(!self.paused, TokenDistributorError::ProgramPaused);
+        Ok(())
+    }
+
+    /// Check that a distribution duration is within the configured bounds
+    /// - The duration is in the units of the given activation clock
+    pub fn require_valid_duration(&self, duration: i64, activation_type: ActivationType) -> Result<()> {
+        require!(
+            (activation_type.period(self.min_duration)..=activation_type.period(self.max_duration)).contains(&duration),
+            TokenDistributorError::InvalidDuration
+        );
+        Ok(())
+    }
+
+    /// Protocol fee deducted from a claimed amount, rounded down
+    pub fn protocol_fee(&self, amount: u64) -> u64 {
+        (amount as u128 * self.protocol_fee_bps as u128 / BASIS_POINTS_DENOMINATOR as u128) as u64
+    }
+
+    /// Check that the parameters are consistent
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.min_duration > 0
+                && self.min_duration <= self.default_duration
+                && self.default_duration <= self.max_duration,
+            TokenDistributorError::InvalidConfig
+        );
+        require!(
+            self.protocol_fee_bps <= MAX_PROTOCOL_FEE_BPS
+                && (self.protocol_fee_bps == 0 || self.protocol_fee_receiver != Pubkey::default()),
+            TokenDistributorError::InvalidConfig
+        );
+        Ok(())
+    }
+}
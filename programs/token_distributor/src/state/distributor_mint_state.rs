@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+/**
+ * Distributor mint state account
+ *
+ * This struct tracks an extra mint of a multi-mint distributor, letting one
+ * campaign airdrop several tokens to the same recipients with a single merkle
+ * tree and a single claim per recipient.
+ *
+ * Derivation: ["distributor_mint", distributor_key, token_mint]
+ *
+ * Lifecycle:
+ * 1. Created with an initial deposit via add_distributor_mint, before the merkle root is set
+ * 2. Updated during claim_multi_mint (total_claimed)
+ * 3. Closed with its vault via withdraw_distributor_mint after the distribution ends
+ *
+ * Design Notes:
+ * - Tokens are held in a vault token account controlled by the distributor PDA,
+ *   so claims sign with the same seeds for every mint
+ * - index is the position of this mint's amount among the extra amounts of a leaf
+ */
+#[account]
+#[derive(Default, Debug)]
+pub struct DistributorMint {
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+
+    /// Position of this mint's amount among the extra amounts of a merkle leaf
+    /// - 0 for the first extra mint added, in the order of add_distributor_mint calls
+    pub index: u8,
+
+    /// Decimals of the token mint, cached when the mint is added
+    pub decimals: u8,
+
+    /// The multi-mint distributor this mint belongs to
+    pub distributor: Pubkey,
+
+    /// Token mint address
+    pub token_mint: Pubkey,
+
+    /// Token vault account address
+    /// - Derived from: ["vault", distributor_mint_key]
+    /// - Controlled by the distributor PDA
+    pub token_vault: Pubkey,
+
+    /// Initial amount of tokens deposited
+    pub initial_total_amount: u64,
+
+    /// Total amount of this mint claimed by all users
+    pub total_claimed: u64,
+}
+
+impl DistributorMint {
+    /// Calculate the space required for this account
+    /// - Includes 8-byte discriminator + struct size
+    pub const LEN: usize = 8 + std::mem::size_of::<DistributorMint>();
+}
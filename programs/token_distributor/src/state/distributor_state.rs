@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::constants::*;
 
 /**
  * Main distributor state account
@@ -12,27 +13,28 @@ use anchor_lang::prelude::*;
  * Lifecycle:
  * 1. Created during create_distributor instruction
  * 2. Updated when start_time and merkle_root are set
- * 3. Updated during claims (total_claimed increments)
- * 4. Closed during withdraw instruction
+ * 3. Updated during claims (total_claimed, claims_count and claim timestamps)
+ * 4. Paused / unpaused by owner or operator if needed
+ *    Optionally finalized by the owner, locking merkle root and timing
+ * 5. Closed during withdraw or clawback instruction
+ *
+ * Design Notes:
+ * - Zero-copy account loaded through AccountLoader, so the root history is
+ *   not deserialized on every instruction
+ * - Flags are stored as u8 and read through is_native() / is_paused()
  */
-#[account]
+#[account(zero_copy)]
 #[derive(Default, Debug)]
 pub struct TokenDistributor {
-    /// Bump seed for PDA derivation
-    /// - Saved to avoid recomputation during claim operations
-    pub bump: u8,
-    
-    /// Nonce number for this distributor
-    /// - Allows multiple distribution campaigns for the same token/owner pair
-    pub nonce: u32,
-    
     /// Owner of the distributor
-    /// - Can withdraw remaining tokens after distribution ends
+    /// - Can withdraw remaining tokens after distribution ends (unless a clawback receiver is set)
     pub owner: Pubkey,
     
-    /// Operator who can manage the distribution
-    /// - Can set start time and update merkle root
-    pub operator: Pubkey,
+    /// Operators who can manage the distribution
+    /// - Any listed operator can set start time and update merkle root
+    /// - Empty slots are filled with Pubkey::default()
+    /// - Managed by the owner via add_operator / remove_operator
+    pub operators: [Pubkey; MAX_OPERATORS],
     
     /// Token mint address
     /// - Specifies which token is being distributed
@@ -42,8 +44,41 @@ pub struct TokenDistributor {
     /// - PDA that holds the tokens to be distributed
     /// - Controlled by the distributor PDA
     /// - Derived from: ["vault", distributor_key]
+    /// - For shared distributors, the shared vault's token account instead
+    /// - For native SOL distributors, the lamport vault ["sol_vault", distributor_key]
     pub token_vault: Pubkey,
     
+    /// Shared vault this distributor draws from
+    /// - Pubkey::default() for distributors with their own vault
+    pub shared_vault: Pubkey,
+    
+    /// Receiver of unclaimed tokens on clawback
+    /// - Set during distributor creation, Pubkey::default() when not configured
+    /// - When set, unclaimed tokens go to this address via clawback instead of withdraw
+    pub clawback_receiver: Pubkey,
+    
+    /// Merkle root for claim verification
+    /// - 32-byte hash representing the root of the merkle tree
+    /// - Used to verify user claims with merkle proofs
+    /// - Can be updated by operator, subject to root_update_interval
+    pub merkle_root: [u8; 32],
+    
+    /// Ring buffer of the last ROOT_HISTORY_LEN merkle root updates
+    /// - Written by set_merkle_root, the newest entry is the current root
+    /// - Records which operator set each root and when, so the root live at
+    ///   any claim can be read from state
+    /// - Claims may still be proven against the MAX_ROOT_HISTORY roots preceding the current one
+    pub root_history: [RootUpdate; ROOT_HISTORY_LEN],
+    
+    /// Total number of merkle root updates
+    /// - The next update is written to root_history[root_update_count % ROOT_HISTORY_LEN]
+    /// - Reported as update_index in MerkleRootSet
+    pub root_update_count: u64,
+    
+    /// Maximum amount this distributor may pay out from the shared vault
+    /// - Only enforced for shared distributors
+    pub budget: u64,
+    
     /// Initial total amount of tokens deposited
     /// - Set during distributor creation
     pub initial_total_amount: u64,
@@ -53,26 +88,512 @@ pub struct TokenDistributor {
     /// - Used to track distribution progress
     pub total_claimed: u64,
     
-    /// Start time of distribution (Unix timestamp)
+    /// Start time of distribution (Unix timestamp, or slot in slot mode)
     /// - Set by operator before distribution begins
     /// - Claims are only allowed after this time
     pub start_time: i64,
     
-    /// End time of distribution (Unix timestamp)
-    /// - Automatically calculated as start_time + DURATION
+    /// Length of the distribution window in seconds (slots in slot mode)
+    /// - Set during distributor creation (defaults to the configured default duration)
+    /// - Bounded by the configured minimum and maximum durations
+    /// - Updated when the operator extends the end time
+    pub duration: i64,
+    
+    /// End time of distribution (Unix timestamp, or slot in slot mode)
+    /// - Automatically calculated as start_time + duration
+    /// - Can be pushed later by the operator while the distribution is live
     /// - Claims are only allowed before this time
-    /// - Withdrawal is only allowed after this time
+    /// - Withdrawal is only allowed after this time plus withdraw_delay
     pub end_time: i64,
     
-    /// Merkle root for claim verification
-    /// - 32-byte hash representing the root of the merkle tree
-    /// - Used to verify user claims with merkle proofs
-    /// - Can be updated by operator at any time
+    /// Minimum interval between successive merkle root updates in seconds
+    /// - Configured by the owner, 0 disables rate limiting
+    /// - Limits the blast radius of a compromised operator key
+    pub root_update_interval: i64,
+    
+    /// Time of the last merkle root update (Unix timestamp)
+    /// - 0 until the merkle root is set for the first time
+    pub last_root_update_at: i64,
+    
+    /// Lamport fee charged to the rent payer on each claim
+    /// - Set by the owner via set_claim_fee, 0 disables fees
+    /// - Collected onto the distributor account itself
+    pub claim_fee: u64,
+    
+    /// Total claim fees collected (lamports)
+    pub total_fees_collected: u64,
+    
+    /// Total claim fees swept by the owner (lamports)
+    /// - total_fees_collected - total_fees_withdrawn is the current fee balance
+    pub total_fees_withdrawn: u64,
+    
+    /// Nonce number for this distributor
+    /// - Allows multiple distribution campaigns for the same token/owner pair
+    pub nonce: u32,
+    
+    /// Bump seed for PDA derivation
+    /// - Saved to avoid recomputation during claim operations
+    pub bump: u8,
+    
+    /// Merkle tree hashing scheme version used by merkle_root
+    /// - Set to TREE_VERSION during distributor creation
+    /// - Lets indexers tell which leaf/node hashing scheme a root uses
+    pub tree_version: u8,
+    
+    /// Whether the distributor pays out native SOL from a lamport vault (0 or 1)
+    /// - token_mint is the native mint for identification only
+    /// - Claimed with claim_sol and withdrawn with withdraw_sol
+    pub native: u8,
+    
+    /// Whether claims are currently paused (0 or 1)
+    /// - Set by owner or operator via pause / unpause
+    /// - Claims are rejected while paused
+    pub paused: u8,
+    
+    /// Time of the last operator action (Unix timestamp)
+    /// - Updated by set_time, extend_time, set_merkle_root, adjust_claim_status and operator pause / unpause
+    /// - 0 until an operator acts, lets monitoring spot funded campaigns left idle
+    pub operator_last_action_ts: i64,
+    
+    /// Whether the merkle root and timing are permanently locked (0 or 1)
+    /// - Set once by the owner via finalize, never cleared
+    /// - set_merkle_root, set_time and extend_time are rejected once set
+    pub finalized: u8,
+    
+    /// Decimals of the token mint, cached at creation
+    /// - Claims are rejected if the mint passed in no longer reports these decimals
+    pub decimals: u8,
+    
+    /// Number of extra mints added via add_distributor_mint (multi-mint distributors only)
+    /// - Fixes how many extra amounts each merkle leaf carries
+    pub extra_mint_count: u8,
+    
+    /// Number of extra mint vaults not yet withdrawn
+    /// - The distributor cannot be withdrawn or clawed back while any remain
+    pub open_extra_mints: u8,
+    
+    /// Clock the distribution window is measured on (ActivationType as u8)
+    /// - Set at creation, read through activation_type()
+    /// - In slot mode start_time, end_time, duration and withdraw_delay are slots
+    pub activation_type: u8,
+    
+    /// Whether every claim is recorded in the claim log (0 or 1)
+    /// - Enabled by the owner via enable_claim_log before the first claim, never cleared
+    /// - Only claim writes the log, other claim instructions are rejected
+    pub claim_log: u8,
+    
+    /// Whether claims must be co-signed by an operator (0 or 1)
+    /// - Set by the owner via set_require_cosign, for distributions requiring off-chain approval such as KYC
+    /// - Only claim accepts co-signatures, other claim instructions are rejected while set
+    pub require_cosign: u8,
+    
+    /// Whether the token vault has been closed via close_vault (0 or 1)
+    /// - Set once by the owner after the vault was emptied, never cleared
+    /// - The distributor stays open for queries and claim status closing, but
+    ///   nothing can be claimed, withdrawn or clawed back anymore
+    pub vault_closed: u8,
+    
+    /// Mint claimants must hold to claim
+    /// - Set by the owner via set_claim_gate, Pubkey::default() when claims are not gated
+    /// - Enables holders-only drops without regenerating the merkle tree as holdings change
+    pub gate_mint: Pubkey,
+    
+    /// Minimum balance of gate_mint a claimant must hold to claim
+    pub gate_min_amount: u64,
+    
+    /// Maximum cumulative amount a single claimant may claim
+    /// - Set by the owner via set_claim_limits, 0 disables the cap
+    /// - Bounds the damage of a merkle tree that over-allocates a claimant
+    pub max_claim_per_user: u64,
+    
+    /// Maximum total amount claimed across all claimants within one slot
+    /// - Set by the owner via set_claim_limits, 0 disables the limit
+    /// - Slows a drain until the distribution can be paused or the root fixed
+    pub max_total_per_slot: u64,
+    
+    /// Slot of the most recent claim counted against max_total_per_slot
+    pub rate_limit_slot: u64,
+    
+    /// Total amount claimed during rate_limit_slot
+    pub claimed_in_slot: u64,
+    
+    /// Total number of timing updates (set_time and extend_time)
+    /// - Reported as update_index in StartTimeSet and EndTimeExtended so indexers
+    ///   can detect missed or out-of-order events
+    pub time_update_count: u64,
+    
+    /// Attestor claimants must hold an attestation from
+    /// - Set by the owner via set_claim_attestation, Pubkey::default() when not required
+    /// - Anti-sybil gate, the attestor is any key publishing wallet-quality scores via attest
+    pub attestor: Pubkey,
+    
+    /// Minimum attestation score a claimant must hold
+    pub min_attestation_score: u64,
+    
+    /// Number of successful claims
+    /// - Every claim counts, including repeated partial claims by the same claimant
+    pub claims_count: u64,
+    
+    /// Time of the first claim (Unix timestamp)
+    /// - 0 until the first claim
+    pub first_claim_at: i64,
+    
+    /// Time of the most recent claim (Unix timestamp)
+    /// - 0 until the first claim
+    pub last_claim_at: i64,
+    
+    /// Delay after end_time before the owner can withdraw, in seconds (slots in slot mode)
+    /// - Set at creation, 0 allows withdrawal as soon as the distribution ends
+    /// - Also delays clawback, whose grace period starts after it
+    pub withdraw_delay: i64,
+    
+    /// Number of records written to the claim log
+    /// - The next record goes to page claim_log_count / CLAIM_LOG_PAGE_LEN
+    pub claim_log_count: u64,
+    
+    /// Total amount claimed beyond reduced allocations
+    /// - Sum of over_claimed_amount over the claim statuses reconciled by adjust_claim_status
+    /// - total_claimed - total_over_claimed is the amount claimed within current allocations
+    pub total_over_claimed: u64,
+    
+    /// Period over which escrowed claims are released linearly, in seconds
+    /// - Set by the owner via enable_claim_escrow before the first claim, never cleared
+    /// - 0 pays claims out directly, otherwise claim pays into the claimant's
+    ///   ClaimEscrow and other claim instructions are rejected
+    pub escrow_release_duration: i64,
+    
+    /// Time at which a claim emptied the vault (0 while tokens remain to be claimed)
+    /// - Set by the claim paying out the last tokens of the vault
+    /// - Lets the owner withdraw before end_time while the vault stays empty
+    pub completed_at: i64,
+    
+    /// Relayers allowed to submit claim_with_signature
+    /// - Empty slots are filled with Pubkey::default(), an empty list allows any relayer
+    /// - Managed by the owner via add_relayer / remove_relayer
+    pub relayers: [Pubkey; MAX_RELAYERS],
+    
+    /// Claim windows of a phased distribution, in activation units
+    /// - Set by an operator via set_claim_windows before the distribution starts
+    /// - Sorted and non-overlapping, unused slots are zeroed
+    /// - When any is set, claims must also fall inside one of them, whose index is the claim's phase
+    pub claim_windows: [ClaimWindow; MAX_CLAIM_WINDOWS],
+    
+    /// Bonus paid on top of claims made early, in basis points of the claimed amount
+    /// - Set by the owner via set_early_bonus before the distribution starts, 0 disables it
+    /// - Stored as u64 to keep the zero-copy layout free of padding
+    pub early_bonus_bps: u64,
+    
+    /// Period after start_time during which claims earn the early bonus, in seconds (slots in slot mode)
+    pub bonus_window: i64,
+    
+    /// Tokens deposited into the vault to fund early bonuses
+    /// - Bonuses stop once total_bonus_paid reaches it, unpaid bonuses are withdrawn with the vault
+    pub bonus_pool: u64,
+    
+    /// Total early bonuses paid out of bonus_pool
+    /// - Not included in total_claimed, which only counts merkle allocations
+    pub total_bonus_paid: u64,
+    
+    /// Reserved space for future fields
+    /// - Zero-initialized, new fields are carved out of it so the account size stays the same
+    pub reserved: [u64; 16],
+}
+
+/// Clock a distributor's window is measured on
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ActivationType {
+    /// Window bounds are unix timestamps from Clock::unix_timestamp
+    #[default]
+    Timestamp,
+    /// Window bounds are slots from Clock::slot, immune to validator clock drift
+    Slot,
+}
+
+impl ActivationType {
+    /// Current point on this clock
+    pub fn now(self, clock: &Clock) -> i64 {
+        match self {
+            ActivationType::Timestamp => clock.unix_timestamp,
+            ActivationType::Slot => clock.slot as i64,
+        }
+    }
+
+    /// Convert a period in seconds to this clock's units, assuming MS_PER_SLOT per slot
+    pub fn period(self, seconds: i64) -> i64 {
+        match self {
+            ActivationType::Timestamp => seconds,
+            ActivationType::Slot => seconds.saturating_mul(1000) / MS_PER_SLOT,
+        }
+    }
+}
+
+/// A merkle root update recorded in TokenDistributor::root_history
+#[zero_copy]
+#[derive(Default, Debug, PartialEq)]
+pub struct RootUpdate {
+    /// The merkle root that was set
     pub merkle_root: [u8; 32],
+    
+    /// Operator who set the root
+    pub operator: Pubkey,
+    
+    /// Time the root was set (Unix timestamp)
+    pub updated_at: i64,
+}
+
+/// A claim window of a phased distribution, stored in TokenDistributor::claim_windows
+/// - Also an instruction argument and event field, so Borsh and Pod alike
+///   (two i64s, the layouts are identical)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, PartialEq, Eq, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+pub struct ClaimWindow {
+    /// Start of the window, inclusive (Unix timestamp, slot in slot mode)
+    pub start: i64,
+    
+    /// End of the window, inclusive (Unix timestamp, slot in slot mode)
+    pub end: i64,
+}
+
+impl ClaimWindow {
+    /// Check whether this slot of the claim window list is unused
+    pub fn is_empty(&self) -> bool {
+        self.end == 0
+    }
+    
+    /// Check whether the given point lies inside the window
+    pub fn contains(&self, now: i64) -> bool {
+        !self.is_empty() && (self.start..=self.end).contains(&now)
+    }
 }
 
 impl TokenDistributor {
     /// Calculate the space required for this account
     /// - Includes 8-byte discriminator + struct size
     pub const LEN: usize = 8 + std::mem::size_of::<TokenDistributor>();
-} 
\ No newline at end of file
+
+    /// Check whether this distributor draws from a shared vault
+    pub fn is_shared(&self) -> bool {
+        self.shared_vault != Pubkey::default()
+    }
+
+    /// Check whether this distributor pays out native SOL
+    pub fn is_native(&self) -> bool {
+        self.native != 0
+    }
+
+    /// Check whether claims are paused
+    pub fn is_paused(&self) -> bool {
+        self.paused != 0
+    }
+
+    /// Check whether the merkle root and timing are locked
+    pub fn is_finalized(&self) -> bool {
+        self.finalized != 0
+    }
+
+    /// Check whether claims require an attestation
+    pub fn requires_attestation(&self) -> bool {
+        self.attestor != Pubkey::default()
+    }
+
+    /// Check whether the distributor pays out several mints per claim
+    pub fn is_multi_mint(&self) -> bool {
+        self.tree_version == MULTI_MINT_TREE_VERSION
+    }
+
+    /// Check whether leaves specify the recipient of each claim
+    pub fn has_recipients(&self) -> bool {
+        self.tree_version == RECIPIENT_TREE_VERSION
+    }
+
+    /// Check whether claims must be co-signed by an operator
+    pub fn requires_cosign(&self) -> bool {
+        self.require_cosign != 0
+    }
+
+    /// Check whether claims are recorded in the claim log
+    pub fn has_claim_log(&self) -> bool {
+        self.claim_log != 0
+    }
+
+    /// Check whether claims are paid into per-claimant escrows
+    pub fn is_escrowed(&self) -> bool {
+        self.escrow_release_duration != 0
+    }
+
+    /// Check whether the token vault has been closed, leaving the distributor open
+    pub fn is_vault_closed(&self) -> bool {
+        self.vault_closed != 0
+    }
+
+    /// Check whether a claim has emptied the vault
+    pub fn is_completed(&self) -> bool {
+        self.completed_at != 0
+    }
+
+    /// Receiver of unclaimed tokens, the clawback receiver when one is configured and the owner otherwise
+    pub fn unclaimed_receiver(&self) -> Pubkey {
+        if self.clawback_receiver == Pubkey::default() {
+            self.owner
+        } else {
+            self.clawback_receiver
+        }
+    }
+    
+    /// Check whether claims are restricted to claim windows
+    pub fn is_phased(&self) -> bool {
+        self.claim_windows.iter().any(|window| !window.is_empty())
+    }
+    
+    /// Phase of a claim at the given point, the index of the claim window containing it
+    /// - Always phase 0 without claim windows, None outside every window of a phased distribution
+    pub fn claim_phase(&self, now: i64) -> Option<u8> {
+        if !self.is_phased() {
+            return Some(0);
+        }
+        self.claim_windows.iter().position(|window| window.contains(now)).map(|index| index as u8)
+    }
+
+    /// Check whether claims made early earn a bonus
+    pub fn has_early_bonus(&self) -> bool {
+        self.early_bonus_bps != 0
+    }
+
+    /// Check whether a claim at the given point falls in the early bonus window
+    /// - The window opens at start_time and lasts bonus_window
+    pub fn is_bonus_window_open(&self, now: i64) -> bool {
+        self.has_early_bonus()
+            && self.start_time > 0
+            && now >= self.start_time
+            && now < self.start_time.saturating_add(self.bonus_window)
+    }
+
+    /// Early bonus earned by a claim of the given amount at the given point
+    /// - 0 outside the bonus window, capped by what is left of the bonus pool
+    pub fn early_bonus(&self, now: i64, amount: u64) -> u64 {
+        if !self.is_bonus_window_open(now) {
+            return 0;
+        }
+        let bonus = (amount as u128 * self.early_bonus_bps as u128 / BASIS_POINTS_DENOMINATOR as u128) as u64;
+        bonus.min(self.remaining_bonus_pool())
+    }
+
+    /// Part of the bonus pool not paid out as early bonuses yet
+    pub fn remaining_bonus_pool(&self) -> u64 {
+        self.bonus_pool.saturating_sub(self.total_bonus_paid)
+    }
+
+    /// Index of the claim log page receiving the next record
+    pub fn claim_log_page(&self) -> u64 {
+        self.claim_log_count / CLAIM_LOG_PAGE_LEN as u64
+    }
+
+    /// Check whether claims require holding the gate mint
+    pub fn is_gated(&self) -> bool {
+        self.gate_mint != Pubkey::default()
+    }
+
+    /// Set a new merkle root, recording the update in the root history
+    pub fn rotate_merkle_root(&mut self, merkle_root: [u8; 32], operator: Pubkey, updated_at: i64) {
+        let index = (self.root_update_count % ROOT_HISTORY_LEN as u64) as usize;
+        self.root_history[index] = RootUpdate {
+            merkle_root,
+            operator,
+            updated_at,
+        };
+        self.root_update_count += 1;
+        self.merkle_root = merkle_root;
+        self.last_root_update_at = updated_at;
+    }
+
+    /// Iterate over the recorded root updates, most recent first
+    pub fn root_updates(&self) -> impl Iterator<Item = &RootUpdate> {
+        let count = self.root_update_count;
+        let recorded = count.min(ROOT_HISTORY_LEN as u64);
+        (1..=recorded).map(move |back| &self.root_history[((count - back) % ROOT_HISTORY_LEN as u64) as usize])
+    }
+
+    /// Iterate over the merkle roots claims may be proven against at the given time (Unix timestamp)
+    /// - The current root, and those of the MAX_ROOT_HISTORY roots preceding it
+    ///   replaced less than ROOT_GRACE_PERIOD ago
+    pub fn known_roots(&self, now: i64) -> impl Iterator<Item = &[u8; 32]> {
+        // Each historical root was replaced by the update following it
+        let replaced_at = std::iter::once(None).chain(self.root_updates().map(|update| Some(update.updated_at)));
+        self.root_updates()
+            .zip(replaced_at)
+            .take(MAX_ROOT_HISTORY + 1)
+            .filter(move |(_, replaced_at)| {
+                !replaced_at.is_some_and(|replaced_at| now > replaced_at.saturating_add(ROOT_GRACE_PERIOD))
+            })
+            .map(|(update, _)| &update.merkle_root)
+            .filter(|root| **root != [0; 32])
+    }
+
+    /// Find the root update that was live at the given time
+    /// - None if no recorded update is that old (no root was set yet, or it left the history)
+    pub fn root_live_at(&self, timestamp: i64) -> Option<&RootUpdate> {
+        self.root_updates().find(|update| update.updated_at <= timestamp)
+    }
+
+    /// Count a claim against the per-slot limit
+    /// - Returns false, leaving the tally untouched, if the claim would exceed the limit
+    pub fn consume_slot_allowance(&mut self, slot: u64, amount: u64) -> bool {
+        if self.max_total_per_slot == 0 {
+            return true;
+        }
+        
+        let claimed_in_slot = if slot == self.rate_limit_slot { self.claimed_in_slot } else { 0 };
+        match claimed_in_slot.checked_add(amount) {
+            Some(total) if total <= self.max_total_per_slot => {
+                self.rate_limit_slot = slot;
+                self.claimed_in_slot = total;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Clock the distribution window is measured on
+    pub fn activation_type(&self) -> ActivationType {
+        if self.activation_type == ActivationType::Slot as u8 {
+            ActivationType::Slot
+        } else {
+            ActivationType::Timestamp
+        }
+    }
+
+    /// Current point on the distributor's activation clock, to compare against the window
+    pub fn activation_now(&self, clock: &Clock) -> i64 {
+        self.activation_type().now(clock)
+    }
+
+    /// Time after which unclaimed tokens can be withdrawn (end_time + withdraw_delay)
+    /// - Clawback additionally waits CLAWBACK_GRACE_PERIOD after this time
+    pub fn withdraw_unlocks_at(&self) -> i64 {
+        self.end_time.saturating_add(self.withdraw_delay)
+    }
+
+    /// Update the claim summary stats with a claim made at the given time
+    pub fn record_claim(&mut self, claimed_at: i64) {
+        self.claims_count += 1;
+        if self.first_claim_at == 0 {
+            self.first_claim_at = claimed_at;
+        }
+        self.last_claim_at = claimed_at;
+    }
+
+    /// Check whether the given key is one of the listed relayers
+    pub fn is_relayer(&self, key: &Pubkey) -> bool {
+        *key != Pubkey::default() && self.relayers.contains(key)
+    }
+
+    /// Check whether the given key may relay claims, any key may while no relayer is listed
+    pub fn is_allowed_relayer(&self, key: &Pubkey) -> bool {
+        self.relayers.iter().all(|relayer| *relayer == Pubkey::default()) || self.is_relayer(key)
+    }
+
+    /// Check whether the given key is one of the listed operators
+    pub fn is_operator(&self, key: &Pubkey) -> bool {
+        *key != Pubkey::default() && self.operators.contains(key)
+    }
+}
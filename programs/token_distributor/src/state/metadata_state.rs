@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+/**
+ * Distributor metadata account
+ *
+ * This struct holds the human-readable side of a campaign, a display name and
+ * a URI to its off-chain metadata (e.g. an IPFS link to the full recipient
+ * list and terms), so wallets and explorers can render "OKX Boost Airdrop #3"
+ * instead of a bare distributor PDA.
+ *
+ * Derivation: ["metadata", distributor]
+ *
+ * Lifecycle:
+ * 1. Created by the owner via set_distributor_metadata, usually in the
+ *    create_distributor transaction
+ * 2. Updated by the owner via set_distributor_metadata until the distribution starts
+ * 3. Closed by the owner via close_distributor_metadata once the distribution
+ *    has ended, returning rent to the owner
+ *
+ * Design Notes:
+ * - Kept in a companion PDA so distributors without metadata pay no extra rent
+ * - Allocated at the maximum name and URI lengths so updates never reallocate
+ */
+#[account]
+#[derive(Default, Debug)]
+pub struct DistributorMetadata {
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+
+    /// Distributor this metadata describes
+    pub distributor: Pubkey,
+
+    /// Owner who last set the metadata
+    /// - Can close the metadata once the distribution has ended, even after
+    ///   the distributor itself has been closed
+    pub owner: Pubkey,
+
+    /// Display name of the campaign
+    /// - At most MAX_METADATA_NAME_LEN bytes
+    pub name: String,
+
+    /// URI of the campaign's off-chain metadata
+    /// - At most MAX_METADATA_URI_LEN bytes
+    pub uri: String,
+}
+
+impl DistributorMetadata {
+    /// Calculate the space required for this account
+    /// - Includes 8-byte discriminator, bump, distributor, owner and both strings at their maximum length
+    pub const LEN: usize = 8 + 1 + 32 + 32 + (4 + MAX_METADATA_NAME_LEN) + (4 + MAX_METADATA_URI_LEN);
+}
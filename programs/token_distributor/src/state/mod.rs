@@ -1,7 +1,23 @@
 pub mod distributor_state;
 pub mod claim_state;
 pub mod nonce_state;
+pub mod shared_vault_state;
+pub mod distributor_mint_state;
+pub mod registry_state;
+pub mod config_state;
+pub mod attestation_state;
+pub mod claim_log_state;
+pub mod metadata_state;
+pub mod claim_escrow_state;
 
 pub use distributor_state::*;
 pub use claim_state::*; 
-pub use nonce_state::*; 
\ No newline at end of file
+pub use nonce_state::*;
+pub use shared_vault_state::*;
+pub use distributor_mint_state::*;
+pub use registry_state::*;
+pub use config_state::*;
+pub use attestation_state::*;
+pub use claim_log_state::*;
+pub use metadata_state::*;
+pub use claim_escrow_state::*; 
\ No newline at end of file
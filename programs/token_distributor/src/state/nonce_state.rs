@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::error::*;
 
 /**
  * Nonce state account
@@ -10,23 +11,73 @@ use anchor_lang::prelude::*;
  *
  * Lifecycle:
  * 1. Created on first distributor creation (using init_if_needed)
- * 2. Updated with each new distributor creation (nonce incremented)
- * 3. Persistent across multiple distributor campaigns
+ * 2. Updated with each new distributor creation (nonce incremented, or advanced
+ *    to the nonce supplied to create_distributor_with_nonce)
+ * 3. Active distributor count decremented when a distributor is closed
+ * 4. Persistent across multiple distributor campaigns
  *
  * Design Notes:
  * - One NonceState account per owner
  * - Enables automatic nonce assignment
+ * - Bounds how many distributors an owner may have open at once
+ *
+ * Layout:
+ * - Accounts created before active distributor limits hold only the nonce
+ *   (LEGACY_LEN bytes) and must be grown with migrate_owner_nonce before use
+ * - active_distributors and max_active_distributors were appended after the nonce,
+ *   so migrated accounts keep their nonce and start with both at 0
  */
 #[account]
 #[derive(Default, Debug)]
 pub struct NonceState {
-    /// Increments with each distributor creation
+    /// Latest nonce used by the owner, incremented with each distributor creation
     /// - Ensures unique nonces for each owner's distributors
+    /// - Only ever increases, so nonces of closed distributors are never reused
     pub nonce: u32,
+    
+    /// Number of this owner's distributors that have not been closed yet
+    /// - Incremented on creation, decremented on withdraw, withdraw_sol,
+    ///   clawback and close_shared_distributor
+    pub active_distributors: u32,
+    
+    /// Owner-specific limit on active distributors set by the global admin
+    /// - 0 applies the global limit from ProgramConfig
+    pub max_active_distributors: u32,
 }
 
 impl NonceState {
     /// Calculate the space required for this account
     /// - Includes 8-byte discriminator + struct size
     pub const LEN: usize = 8 + std::mem::size_of::<NonceState>();
+
+    /// Size of accounts created before active distributor limits (discriminator + nonce)
+    pub const LEGACY_LEN: usize = 8 + 4;
+
+    /// Active distributor limit in effect for this owner
+    /// - The owner override when set, otherwise the global limit (0 means unlimited)
+    pub fn active_limit(&self, global_limit: u32) -> u32 {
+        if self.max_active_distributors != 0 {
+            self.max_active_distributors
+        } else {
+            global_limit
+        }
+    }
+
+    /// Count a newly created distributor, enforcing the active distributor limit
+    pub fn open_distributor(&mut self, global_limit: u32) -> Result<()> {
+        let limit = self.active_limit(global_limit);
+        require!(
+            limit == 0 || self.active_distributors < limit,
+            TokenDistributorError::TooManyActiveDistributors
+        );
+        self.active_distributors = self.active_distributors
+            .checked_add(1)
+            .ok_or(TokenDistributorError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Count a closed distributor
+    pub fn close_distributor(&mut self) {
+        self.active_distributors = self.active_distributors.saturating_sub(1);
+    }
 }
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+/**
+ * Per-mint campaign registry account
+ *
+ * This struct lists the scheduled distributors of a token together with their
+ * claim windows, so explorers and wallets can render the claimable airdrops
+ * of a token directly from chain state.
+ *
+ * Derivation: ["registry", token_mint]
+ *
+ * Lifecycle:
+ * 1. Created on the first sync_registry call for a mint (using init_if_needed)
+ * 2. Entries added or refreshed by sync_registry once a start time is set,
+ *    by the token's mint authority or the program config admin
+ * 3. Entries whose window has ended are pruned on every sync
+ * 4. Entries of closed distributors are removed by withdraw, clawback and
+ *    close_vault when given the registry, or by evict_registry_entry
+ *
+ * Design Notes:
+ * - One MintRegistry account per token mint
+ * - Fixed capacity of MAX_REGISTRY_ENTRIES, empty slots have distributor == Pubkey::default()
+ */
+#[account]
+#[derive(Default, Debug)]
+pub struct MintRegistry {
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+
+    /// Token mint the listed distributors distribute
+    pub token_mint: Pubkey,
+
+    /// Listed distributors and their claim windows
+    pub entries: [RegistryEntry; MAX_REGISTRY_ENTRIES],
+}
+
+/// A distributor listed in a MintRegistry
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, PartialEq)]
+pub struct RegistryEntry {
+    /// The distributor account public key
+    pub distributor: Pubkey,
+
+    /// Start time of the distribution (Unix timestamp)
+    pub start_time: i64,
+
+    /// End time of the distribution (Unix timestamp)
+    pub end_time: i64,
+}
+
+impl MintRegistry {
+    /// Calculate the space required for this account
+    /// - Includes 8-byte discriminator + struct size
+    pub const LEN: usize = 8 + std::mem::size_of::<MintRegistry>();
+
+    /// Clear all entries whose window ended before the given time
+    pub fn prune_ended(&mut self, current_time: i64) {
+        for entry in self.entries.iter_mut() {
+            if entry.distributor != Pubkey::default() && entry.end_time <= current_time {
+                *entry = RegistryEntry::default();
+            }
+        }
+    }
+
+    /// Clear the entry of a distributor
+    /// - Returns false if the distributor is not listed
+    pub fn remove(&mut self, distributor: &Pubkey) -> bool {
+        match self.entries.iter_mut().find(|e| e.distributor == *distributor) {
+            Some(entry) => {
+                *entry = RegistryEntry::default();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Check whether a distributor is listed
+    pub fn is_listed(&self, distributor: &Pubkey) -> bool {
+        *distributor != Pubkey::default() && self.entries.iter().any(|e| e.distributor == *distributor)
+    }
+
+    /// Insert or refresh the entry of a distributor
+    /// - Returns false if the distributor is not listed and the registry is full
+    pub fn upsert(&mut self, entry: RegistryEntry) -> bool {
+        let slot = self
+            .entries
+            .iter()
+            .position(|e| e.distributor == entry.distributor)
+            .or_else(|| self.entries.iter().position(|e| e.distributor == Pubkey::default()));
+        match slot {
+            Some(index) => {
+                self.entries[index] = entry;
+                true
+            }
+            None => false,
+        }
+    }
+}
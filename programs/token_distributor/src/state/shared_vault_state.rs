@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+/**
+ * Shared vault state account
+ *
+ * This struct tracks a token vault that several distributors (rounds) of the
+ * same owner and mint draw from, each capped by its own budget. It avoids
+ * repeated large deposits and fragmented treasury accounting across rounds.
+ *
+ * Derivation: ["shared_vault", token_mint, owner]
+ *
+ * Lifecycle:
+ * 1. Created with an initial deposit via create_shared_vault
+ * 2. Budget allocated when a shared distributor is created
+ * 3. Budget consumed by claims and released when a shared distributor is closed
+ * 4. Unallocated tokens withdrawn by the owner at any time
+ *
+ * Design Notes:
+ * - One SharedVault account per (token_mint, owner) pair
+ * - Tokens are held in a vault token account controlled by this PDA
+ */
+#[account]
+#[derive(Default, Debug)]
+pub struct SharedVault {
+    /// Bump seed for PDA derivation
+    /// - Saved to avoid recomputation during claim operations
+    pub bump: u8,
+
+    /// Owner of the shared vault
+    /// - Can create shared distributors and withdraw unallocated tokens
+    pub owner: Pubkey,
+
+    /// Token mint address
+    pub token_mint: Pubkey,
+
+    /// Token vault account address
+    /// - Derived from: ["vault", shared_vault_key]
+    /// - Controlled by the shared vault PDA
+    pub token_vault: Pubkey,
+
+    /// Budget allocated to attached distributors that has not been claimed yet
+    /// - Increased when a shared distributor is created
+    /// - Decreased by claims and when a shared distributor is closed
+    /// - Tokens above this amount can be withdrawn by the owner
+    pub allocated_amount: u64,
+}
+
+impl SharedVault {
+    /// Calculate the space required for this account
+    /// - Includes 8-byte discriminator + struct size
+    pub const LEN: usize = 8 + std::mem::size_of::<SharedVault>();
+}
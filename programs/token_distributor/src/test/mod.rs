@@ -1 +1,12 @@
-pub mod test_merkle; 
\ No newline at end of file
+pub mod test_claim_escrow;
+pub mod test_claim_limits;
+pub mod test_claim_log;
+pub mod test_claim_windows;
+pub mod test_config;
+pub mod test_early_bonus;
+pub mod test_features;
+pub mod test_merkle;
+pub mod test_registry;
+pub mod test_scaling;
+pub mod test_signature;
+pub mod test_vectors;
\ No newline at end of file
@@ -0,0 +1,49 @@
+#[cfg(test)]
+mod tests {
+    use crate::state::ClaimEscrow;
+
+    fn escrow(escrowed_amount: u64) -> ClaimEscrow {
+        ClaimEscrow {
+            escrowed_amount,
+            release_start: 1_000,
+            release_duration: 300,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_unlocked_amount_is_linear() {
+        let escrow = escrow(900);
+        assert_eq!(escrow.unlocked_amount(0), 0);
+        assert_eq!(escrow.unlocked_amount(1_000), 0);
+        assert_eq!(escrow.unlocked_amount(1_100), 300);
+        assert_eq!(escrow.unlocked_amount(1_300), 900);
+        assert_eq!(escrow.unlocked_amount(i64::MAX), 900);
+    }
+
+    #[test]
+    fn test_unlocked_amount_rounds_down() {
+        let escrow = escrow(10);
+        assert_eq!(escrow.unlocked_amount(1_001), 0);
+        assert_eq!(escrow.unlocked_amount(1_299), 9);
+        assert_eq!(escrow.unlocked_amount(1_300), 10);
+    }
+
+    #[test]
+    fn test_releasable_amount_after_top_up() {
+        let mut escrow = escrow(600);
+        escrow.released_amount = escrow.releasable_amount(1_150);
+        assert_eq!(escrow.released_amount, 300);
+
+        // A later claim follows the same schedule, its unlocked share is releasable at once
+        escrow.escrowed_amount += 300;
+        assert_eq!(escrow.releasable_amount(1_150), 150);
+        assert_eq!(escrow.releasable_amount(1_300), 600);
+    }
+
+    #[test]
+    fn test_escrow_amounts_do_not_overflow() {
+        let escrow = escrow(u64::MAX);
+        assert_eq!(escrow.unlocked_amount(1_150), u64::MAX / 2);
+    }
+}
@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod tests {
+    use crate::state::{ActivationType, TokenDistributor};
+    use anchor_lang::prelude::Clock;
+
+    #[test]
+    fn test_slot_allowance_disabled() {
+        let mut distributor = TokenDistributor::default();
+        assert!(distributor.consume_slot_allowance(1, u64::MAX));
+        assert_eq!(distributor.claimed_in_slot, 0);
+    }
+
+    #[test]
+    fn test_slot_allowance_resets_each_slot() {
+        let mut distributor = TokenDistributor {
+            max_total_per_slot: 100,
+            ..Default::default()
+        };
+
+        assert!(distributor.consume_slot_allowance(10, 60));
+        assert!(distributor.consume_slot_allowance(10, 40));
+        // Limit reached for slot 10, tally untouched by the rejected claim
+        assert!(!distributor.consume_slot_allowance(10, 1));
+        assert_eq!(distributor.claimed_in_slot, 100);
+
+        // A new slot starts from zero
+        assert!(distributor.consume_slot_allowance(11, 100));
+        assert!(!distributor.consume_slot_allowance(12, 101));
+        assert_eq!((distributor.rate_limit_slot, distributor.claimed_in_slot), (11, 100));
+    }
+
+    #[test]
+    fn test_record_claim_stats() {
+        let mut distributor = TokenDistributor::default();
+        distributor.record_claim(1_000);
+        distributor.record_claim(1_500);
+        distributor.record_claim(2_000);
+        assert_eq!(distributor.claims_count, 3);
+        assert_eq!((distributor.first_claim_at, distributor.last_claim_at), (1_000, 2_000));
+    }
+
+    #[test]
+    fn test_activation_clock() {
+        let clock = Clock {
+            slot: 250_000_000,
+            unix_timestamp: 1_700_000_000,
+            ..Default::default()
+        };
+        let mut distributor = TokenDistributor::default();
+        assert_eq!(distributor.activation_type(), ActivationType::Timestamp);
+        assert_eq!(distributor.activation_now(&clock), 1_700_000_000);
+
+        distributor.activation_type = ActivationType::Slot as u8;
+        assert_eq!(distributor.activation_type(), ActivationType::Slot);
+        assert_eq!(distributor.activation_now(&clock), 250_000_000);
+    }
+}
@@ -0,0 +1,39 @@
+#[cfg(test)]
+mod tests {
+    use crate::constants::CLAIM_LOG_PAGE_LEN;
+    use crate::state::{ClaimLog, ClaimRecord, TokenDistributor};
+    use anchor_lang::prelude::Pubkey;
+    use anchor_lang::Discriminator;
+
+    #[test]
+    fn test_page_fits_cpi_allocation() {
+        // Pages are created by claims through a system program CPI
+        assert_eq!(ClaimLog::LEN, 6_200);
+        const { assert!(ClaimLog::LEN <= 10_240) };
+        assert!(ClaimLog::DISCRIMINATOR.iter().any(|byte| *byte != 0));
+    }
+
+    #[test]
+    fn test_push_until_full() {
+        let mut page: ClaimLog = bytemuck::Zeroable::zeroed();
+        let claimant = Pubkey::new_unique();
+        for i in 0..CLAIM_LOG_PAGE_LEN as u64 {
+            assert!(page.push(ClaimRecord { claimant, amount: i, claimed_at: 1_000 + i as i64 }));
+        }
+        assert!(!page.push(ClaimRecord::default()));
+        assert_eq!(page.records().len(), CLAIM_LOG_PAGE_LEN);
+        assert_eq!(page.records()[5], ClaimRecord { claimant, amount: 5, claimed_at: 1_005 });
+    }
+
+    #[test]
+    fn test_claim_log_page_index() {
+        let mut distributor = TokenDistributor::default();
+        assert!(!distributor.has_claim_log());
+        assert_eq!(distributor.claim_log_page(), 0);
+
+        distributor.claim_log_count = CLAIM_LOG_PAGE_LEN as u64 - 1;
+        assert_eq!(distributor.claim_log_page(), 0);
+        distributor.claim_log_count += 1;
+        assert_eq!(distributor.claim_log_page(), 1);
+    }
+}
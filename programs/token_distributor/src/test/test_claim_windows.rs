@@ -0,0 +1,40 @@
+#[cfg(test)]
+mod tests {
+    use crate::state::{ClaimWindow, TokenDistributor};
+
+    fn phased(windows: &[(i64, i64)]) -> TokenDistributor {
+        let mut distributor = TokenDistributor::default();
+        for (slot, (start, end)) in distributor.claim_windows.iter_mut().zip(windows) {
+            *slot = ClaimWindow { start: *start, end: *end };
+        }
+        distributor
+    }
+
+    #[test]
+    fn test_unphased_claims_are_phase_zero() {
+        let distributor = TokenDistributor::default();
+        assert!(!distributor.is_phased());
+        assert_eq!(distributor.claim_phase(0), Some(0));
+        assert_eq!(distributor.claim_phase(i64::MAX), Some(0));
+    }
+
+    #[test]
+    fn test_claim_phase_is_window_index() {
+        let distributor = phased(&[(100, 200), (300, 400)]);
+        assert!(distributor.is_phased());
+        assert_eq!(distributor.claim_phase(100), Some(0));
+        assert_eq!(distributor.claim_phase(200), Some(0));
+        assert_eq!(distributor.claim_phase(300), Some(1));
+        assert_eq!(distributor.claim_phase(400), Some(1));
+    }
+
+    #[test]
+    fn test_no_phase_between_windows() {
+        let distributor = phased(&[(100, 200), (300, 400)]);
+        assert_eq!(distributor.claim_phase(99), None);
+        assert_eq!(distributor.claim_phase(250), None);
+        assert_eq!(distributor.claim_phase(401), None);
+        // Unused slots never contain a point, even 0
+        assert_eq!(distributor.claim_phase(0), None);
+    }
+}
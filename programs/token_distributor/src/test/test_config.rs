@@ -0,0 +1,128 @@
+#[cfg(test)]
+mod tests {
+    use crate::constants::{DURATION, MAX_DURATION, MAX_PROTOCOL_FEE_BPS, MIN_DURATION};
+    use crate::state::{ActivationType, NonceState, ProgramConfig};
+    use anchor_lang::solana_program::pubkey::Pubkey;
+    use anchor_lang::{AccountDeserialize, AccountSerialize};
+
+    #[test]
+    fn test_defaults_are_valid() {
+        let config = ProgramConfig::with_defaults();
+        assert!(config.validate().is_ok());
+        assert!(config.require_not_paused().is_ok());
+        assert!(config.require_valid_duration(DURATION, ActivationType::Timestamp).is_ok());
+        assert!(config.require_valid_duration(MIN_DURATION - 1, ActivationType::Timestamp).is_err());
+        assert!(config.require_valid_duration(MAX_DURATION + 1, ActivationType::Timestamp).is_err());
+    }
+
+    #[test]
+    fn test_duration_bounds_in_slots() {
+        let config = ProgramConfig::with_defaults();
+        // 400ms slots: the 180 day maximum is 38,880,000 slots
+        let max_slots = ActivationType::Slot.period(MAX_DURATION);
+        assert_eq!(max_slots, 38_880_000);
+        assert!(config.require_valid_duration(max_slots, ActivationType::Slot).is_ok());
+        assert!(config.require_valid_duration(max_slots + 1, ActivationType::Slot).is_err());
+        // A minimum duration in seconds is too short a window in slots
+        assert!(config.require_valid_duration(MIN_DURATION, ActivationType::Slot).is_err());
+    }
+
+    #[test]
+    fn test_validate_rejects_inconsistent_bounds() {
+        let config = ProgramConfig {
+            default_duration: MAX_DURATION + 1,
+            ..ProgramConfig::with_defaults()
+        };
+        assert!(config.validate().is_err());
+
+        let config = ProgramConfig {
+            min_duration: 0,
+            ..ProgramConfig::with_defaults()
+        };
+        assert!(config.validate().is_err());
+
+        let config = ProgramConfig {
+            paused: true,
+            ..ProgramConfig::with_defaults()
+        };
+        assert!(config.require_not_paused().is_err());
+    }
+
+    #[test]
+    fn test_protocol_fee() {
+        // Disabled by default
+        let config = ProgramConfig::with_defaults();
+        assert_eq!(config.protocol_fee(1_000_000), 0);
+
+        let config = ProgramConfig {
+            protocol_fee_bps: 250,
+            protocol_fee_receiver: Pubkey::new_unique(),
+            ..ProgramConfig::with_defaults()
+        };
+        assert!(config.validate().is_ok());
+        assert_eq!(config.protocol_fee(1_000_000), 25_000);
+        // Rounded down
+        assert_eq!(config.protocol_fee(39), 0);
+
+        // A non-zero fee needs a receiver
+        let config = ProgramConfig {
+            protocol_fee_bps: 250,
+            ..ProgramConfig::with_defaults()
+        };
+        assert!(config.validate().is_err());
+
+        let config = ProgramConfig {
+            protocol_fee_bps: MAX_PROTOCOL_FEE_BPS + 1,
+            protocol_fee_receiver: Pubkey::new_unique(),
+            ..ProgramConfig::with_defaults()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_active_distributor_limit() {
+        // Unlimited by default
+        let mut owner_nonce = NonceState::default();
+        for _ in 0..10 {
+            assert!(owner_nonce.open_distributor(0).is_ok());
+        }
+        assert_eq!(owner_nonce.active_distributors, 10);
+
+        // Global limit
+        let mut owner_nonce = NonceState::default();
+        assert!(owner_nonce.open_distributor(2).is_ok());
+        assert!(owner_nonce.open_distributor(2).is_ok());
+        assert!(owner_nonce.open_distributor(2).is_err());
+        assert_eq!(owner_nonce.active_distributors, 2);
+
+        // Closing frees a slot
+        owner_nonce.close_distributor();
+        assert!(owner_nonce.open_distributor(2).is_ok());
+
+        // Owner override takes precedence over the global limit
+        owner_nonce.max_active_distributors = 3;
+        assert!(owner_nonce.open_distributor(2).is_ok());
+        assert!(owner_nonce.open_distributor(2).is_err());
+
+        // Distributors created before counting started never underflow
+        let mut owner_nonce = NonceState::default();
+        owner_nonce.close_distributor();
+        assert_eq!(owner_nonce.active_distributors, 0);
+    }
+
+    #[test]
+    fn test_legacy_nonce_state_migration() {
+        // A nonce state from before active distributor limits: discriminator and nonce only
+        let mut data = Vec::new();
+        NonceState { nonce: 42, ..Default::default() }.try_serialize(&mut data).unwrap();
+        data.truncate(NonceState::LEGACY_LEN);
+        assert!(NonceState::try_deserialize(&mut data.as_slice()).is_err());
+
+        // migrate_owner_nonce zero-extends it to the current size
+        data.resize(NonceState::LEN, 0);
+        let owner_nonce = NonceState::try_deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(owner_nonce.nonce, 42);
+        assert_eq!(owner_nonce.active_distributors, 0);
+        assert_eq!(owner_nonce.max_active_distributors, 0);
+    }
+}
@@ -0,0 +1,48 @@
+#[cfg(test)]
+mod tests {
+    use crate::state::TokenDistributor;
+
+    fn with_bonus(early_bonus_bps: u64, bonus_pool: u64) -> TokenDistributor {
+        TokenDistributor {
+            start_time: 1_000,
+            early_bonus_bps,
+            bonus_window: 100,
+            bonus_pool,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_bonus_window_bounds() {
+        let distributor = with_bonus(5_000, 1_000_000);
+        assert!(!distributor.is_bonus_window_open(999));
+        assert!(distributor.is_bonus_window_open(1_000));
+        assert!(distributor.is_bonus_window_open(1_099));
+        assert!(!distributor.is_bonus_window_open(1_100));
+        // Without a start time there is no window yet
+        let unstarted = TokenDistributor { start_time: 0, ..distributor };
+        assert!(!unstarted.is_bonus_window_open(0));
+    }
+
+    #[test]
+    fn test_early_bonus_amount() {
+        let distributor = with_bonus(2_500, 1_000_000);
+        assert_eq!(distributor.early_bonus(1_000, 1_000), 250);
+        assert_eq!(distributor.early_bonus(1_100, 1_000), 0);
+        // Disabled bonuses pay nothing even inside the window
+        assert!(!with_bonus(0, 1_000_000).has_early_bonus());
+        assert_eq!(with_bonus(0, 1_000_000).early_bonus(1_000, 1_000), 0);
+    }
+
+    #[test]
+    fn test_early_bonus_capped_by_pool() {
+        let mut distributor = with_bonus(10_000, 1_000);
+        distributor.total_bonus_paid = 900;
+        assert_eq!(distributor.early_bonus(1_000, 500), 100);
+        distributor.total_bonus_paid = 1_000;
+        assert_eq!(distributor.early_bonus(1_000, 500), 0);
+        // No overflow on the largest amounts
+        let distributor = with_bonus(10_000, u64::MAX);
+        assert_eq!(distributor.early_bonus(1_000, u64::MAX), u64::MAX);
+    }
+}
@@ -0,0 +1,51 @@
+#[cfg(test)]
+mod tests {
+    // Run with `cargo test -p token_distributor --no-default-features`: the struct
+    // literals below only compile once the optional subsystems' accounts are gone
+    #[test]
+    #[cfg(not(any(
+        feature = "protocol-fee",
+        feature = "claim-gates",
+        feature = "claim-log",
+        feature = "claim-escrow"
+    )))]
+    fn test_minimal_claim_accounts() {
+        use anchor_lang::prelude::Pubkey;
+        use anchor_lang::ToAccountMetas;
+
+        // No protocol_fee_account, gate_token_account, attestation, claim_log or claim escrow accounts
+        let claim = crate::accounts::Claim {
+            distributor: Pubkey::new_unique(),
+            claim_status: Pubkey::new_unique(),
+            token_vault: Pubkey::new_unique(),
+            shared_vault: None,
+            claimant_token_account: Pubkey::new_unique(),
+            token_mint: Pubkey::new_unique(),
+            claimant: Pubkey::new_unique(),
+            delegate: None,
+            payer: Pubkey::new_unique(),
+            system_program: Pubkey::new_unique(),
+            token_program: Pubkey::new_unique(),
+            memo_program: None,
+            config: Pubkey::new_unique(),
+            instructions_sysvar: None,
+            event_authority: Pubkey::new_unique(),
+            program: crate::ID,
+        };
+        assert_eq!(claim.to_account_metas(None).len(), 16);
+
+        // No protocol_fee_account, gate_token_account or attestation
+        let claim_batch = crate::accounts::ClaimBatch {
+            claimant_token_account: Pubkey::new_unique(),
+            token_mint: Pubkey::new_unique(),
+            claimant: Pubkey::new_unique(),
+            system_program: Pubkey::new_unique(),
+            token_program: Pubkey::new_unique(),
+            memo_program: None,
+            config: Pubkey::new_unique(),
+            event_authority: Pubkey::new_unique(),
+            program: crate::ID,
+        };
+        assert_eq!(claim_batch.to_account_metas(None).len(), 9);
+    }
+}
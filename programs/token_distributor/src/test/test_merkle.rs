@@ -8,25 +8,26 @@ struct TreeNode {
     amount: u64,
 }
 
-struct SimpleMerkleTree {
+pub(super) struct SimpleMerkleTree {
     nodes: Vec<[u8; 32]>,
     leaf_count: usize,
 }
 
 impl SimpleMerkleTree {
-    fn new(tree_nodes: Vec<TreeNode>) -> Self {
-        let leaf_count = tree_nodes.len();
-        let mut nodes = Vec::new();
-
+    fn new(tree_nodes: Vec<TreeNode>, distributor: &Pubkey) -> Self {
         // Generate leaf hashes
-        for node in tree_nodes {
-            let leaf_hash = Self::hash_leaf(&node.claimant, node.amount);
-            nodes.push(leaf_hash.to_bytes());
-        }
+        let leaves = tree_nodes
+            .iter()
+            .map(|node| Self::hash_leaf(distributor, &node.claimant, node.amount).to_bytes())
+            .collect();
+        Self::from_leaves(leaves)
+    }
 
+    /// Build a tree from precomputed leaf hashes (any tree version)
+    pub(super) fn from_leaves(leaves: Vec<[u8; 32]>) -> Self {
         let mut tree = SimpleMerkleTree {
-            nodes,
-            leaf_count,
+            leaf_count: leaves.len(),
+            nodes: leaves,
         };
 
         // Build the tree
@@ -34,20 +35,22 @@ impl SimpleMerkleTree {
         tree
     }
 
-    fn hash_leaf(claimant: &Pubkey, amount: u64) -> anchor_lang::solana_program::hash::Hash {
-        // Hash leaf without prefix
+    fn hash_leaf(distributor: &Pubkey, claimant: &Pubkey, amount: u64) -> anchor_lang::solana_program::hash::Hash {
+        // Hash leaf with leaf prefix (0x00) and distributor binding
         hashv(&[
+            &[0u8],
+            &distributor.to_bytes(),
             &claimant.to_bytes(),
             &amount.to_le_bytes(),
         ])
     }
 
     fn hash_intermediate(left: &[u8; 32], right: &[u8; 32]) -> anchor_lang::solana_program::hash::Hash {
-        // Hash intermediate nodes without prefix, using the same ordering as verify function
+        // Hash intermediate nodes with node prefix (0x01), using the same ordering as verify function
         if left <= right {
-            hashv(&[left, right])
+            hashv(&[&[1u8], left, right])
         } else {
-            hashv(&[right, left])
+            hashv(&[&[1u8], right, left])
         }
     }
 
@@ -84,11 +87,11 @@ impl SimpleMerkleTree {
         if level_len == 1 {
             0
         } else {
-            (level_len + 1) / 2
+            level_len.div_ceil(2)
         }
     }
 
-    fn get_root(&self) -> Option<&[u8; 32]> {
+    pub(super) fn get_root(&self) -> Option<&[u8; 32]> {
         if self.nodes.is_empty() {
             None
         } else {
@@ -104,7 +107,7 @@ impl SimpleMerkleTree {
     }
 
     /// Generate merkle proof for a leaf at given index
-    fn get_proof(&self, index: usize) -> Result<Vec<[u8; 32]>, &'static str> {
+    pub(super) fn get_proof(&self, index: usize) -> Result<Vec<[u8; 32]>, &'static str> {
         if index >= self.leaf_count {
             return Err("Index out of bounds");
         }
@@ -116,7 +119,7 @@ impl SimpleMerkleTree {
 
         while level_len > 1 {
             // Find sibling index
-            let sibling_index = if current_index % 2 == 0 {
+            let sibling_index = if current_index.is_multiple_of(2) {
                 // Current node is left child, sibling is right
                 if current_index + 1 < level_len {
                     current_index + 1
@@ -145,7 +148,14 @@ impl SimpleMerkleTree {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::utils::verify;
+    use crate::utils::{verify, compute_root, hash_leaf, hash_leaf_with_unlock_time, hash_leaf_multi_mint, hash_leaf_with_recipient, hash_node,
+        hash_leaf_keccak, hash_node_keccak, compute_root_keccak};
+    use crate::state::TokenDistributor;
+    use crate::constants::{MAX_ROOT_HISTORY, ROOT_GRACE_PERIOD, ROOT_HISTORY_LEN};
+
+    fn test_distributor() -> Pubkey {
+        Pubkey::from_str("9xQeWvG816bUx9EPjHmaT23yvVM2ZWbrrpZb9PusVFin").unwrap()
+    }
 
     fn get_test_data() -> Vec<TreeNode> {
         // Data from test_fixtures.csv
@@ -179,12 +189,12 @@ mod tests {
         
         // Print individual leaf hashes for debugging
         for (i, node) in tree_nodes.iter().enumerate() {
-            let leaf_hash = SimpleMerkleTree::hash_leaf(&node.claimant, node.amount);
+            let leaf_hash = SimpleMerkleTree::hash_leaf(&test_distributor(), &node.claimant, node.amount);
             println!("Leaf {}: claimant={}, amount={}, hash={:?}", 
                 i, node.claimant, node.amount, leaf_hash.to_bytes());
         }
 
-        let merkle_tree = SimpleMerkleTree::new(tree_nodes);
+        let merkle_tree = SimpleMerkleTree::new(tree_nodes, &test_distributor());
         let root = merkle_tree.get_merkle_root().unwrap();
         
         println!("Rust Merkle Root: {:?}", root);
@@ -194,12 +204,12 @@ mod tests {
         let root_array: Vec<u8> = root.into_iter().collect();
         println!("Rust Merkle Root as array: {:?}", root_array);
         
-        // Expected TypeScript result (after removing prefixes and using lexicographic ordering)
+        // Expected TypeScript result (tree version 1: prefixed, distributor-bound leaves and lexicographic ordering)
         let expected_ts_root = vec![
-            51, 122, 158, 29, 92, 151, 242, 153,
-            236, 252, 41, 211, 22, 50, 250, 139,
-            218, 189, 37, 163, 61, 102, 114, 92,
-            184, 219, 198, 184, 3, 245, 63, 91
+            243, 152, 164, 112, 130, 81, 148, 108,
+            250, 197, 110, 192, 207, 95, 163, 2,
+            181, 250, 196, 208, 254, 195, 137, 9,
+            32, 21, 36, 184, 246, 50, 181, 28
         ];
         
         println!("Expected TypeScript root: {:?}", expected_ts_root);
@@ -223,7 +233,7 @@ mod tests {
         println!("=== Testing get_proof and verify ===");
         
         let tree_nodes = get_test_data();
-        let merkle_tree = SimpleMerkleTree::new(tree_nodes.clone());
+        let merkle_tree = SimpleMerkleTree::new(tree_nodes.clone(), &test_distributor());
         let root = merkle_tree.get_root().unwrap();
         
         println!("Merkle root: {:?}", root);
@@ -235,7 +245,7 @@ mod tests {
             println!("Amount: {}", node.amount);
             
             // Generate leaf hash
-            let leaf_hash = SimpleMerkleTree::hash_leaf(&node.claimant, node.amount);
+            let leaf_hash = SimpleMerkleTree::hash_leaf(&test_distributor(), &node.claimant, node.amount);
             println!("Leaf hash: {:?}", leaf_hash.to_bytes());
             
             // Get proof
@@ -244,7 +254,7 @@ mod tests {
             println!("Proof: {:?}", proof);
             
             // Verify proof
-            let is_valid = verify(proof.clone(), *root, leaf_hash.to_bytes());
+            let is_valid = verify(&proof, *root, leaf_hash.to_bytes());
             println!("Proof verification: {}", if is_valid { "✅ VALID" } else { "❌ INVALID" });
             
             assert!(is_valid, "Proof verification failed for index {}", index);
@@ -258,29 +268,30 @@ mod tests {
         println!("=== Testing invalid proof ===");
         
         let tree_nodes = get_test_data();
-        let merkle_tree = SimpleMerkleTree::new(tree_nodes.clone());
+        let merkle_tree = SimpleMerkleTree::new(tree_nodes.clone(), &test_distributor());
         let root = merkle_tree.get_root().unwrap();
         
         // Test with wrong leaf
         let wrong_leaf = SimpleMerkleTree::hash_leaf(
+            &test_distributor(),
             &Pubkey::from_str("11111111111111111111111111111112").unwrap(), 
             9999
         );
         let proof = merkle_tree.get_proof(0).expect("Failed to get proof");
         
-        let is_valid = verify(proof, *root, wrong_leaf.to_bytes());
+        let is_valid = verify(&proof, *root, wrong_leaf.to_bytes());
         println!("Invalid proof verification: {}", if is_valid { "❌ UNEXPECTEDLY VALID" } else { "✅ CORRECTLY INVALID" });
         
         assert!(!is_valid, "Invalid proof should not verify");
         
         // Test with tampered proof
-        let correct_leaf = SimpleMerkleTree::hash_leaf(&tree_nodes[0].claimant, tree_nodes[0].amount);
+        let correct_leaf = SimpleMerkleTree::hash_leaf(&test_distributor(), &tree_nodes[0].claimant, tree_nodes[0].amount);
         let mut tampered_proof = merkle_tree.get_proof(0).expect("Failed to get proof");
         if !tampered_proof.is_empty() {
             tampered_proof[0][0] = tampered_proof[0][0].wrapping_add(1); // Tamper with first byte
         }
         
-        let is_valid_tampered = verify(tampered_proof, *root, correct_leaf.to_bytes());
+        let is_valid_tampered = verify(&tampered_proof, *root, correct_leaf.to_bytes());
         println!("Tampered proof verification: {}", if is_valid_tampered { "❌ UNEXPECTEDLY VALID" } else { "✅ CORRECTLY INVALID" });
         
         assert!(!is_valid_tampered, "Tampered proof should not verify");
@@ -298,24 +309,244 @@ mod tests {
             amount: 1000,
         }];
         
-        let single_tree = SimpleMerkleTree::new(single_node.clone());
+        let single_tree = SimpleMerkleTree::new(single_node.clone(), &test_distributor());
         let single_root = single_tree.get_root().unwrap();
         let single_proof = single_tree.get_proof(0).expect("Failed to get proof for single node");
         
         println!("Single node proof length: {}", single_proof.len());
         assert_eq!(single_proof.len(), 0, "Single node should have empty proof");
         
-        let single_leaf = SimpleMerkleTree::hash_leaf(&single_node[0].claimant, single_node[0].amount);
-        let single_valid = verify(single_proof, *single_root, single_leaf.to_bytes());
+        let single_leaf = SimpleMerkleTree::hash_leaf(&test_distributor(), &single_node[0].claimant, single_node[0].amount);
+        let single_valid = verify(&single_proof, *single_root, single_leaf.to_bytes());
         println!("Single node verification: {}", if single_valid { "✅ VALID" } else { "❌ INVALID" });
         assert!(single_valid, "Single node proof should be valid");
         
         // Test out of bounds
         let tree_nodes = get_test_data();
-        let merkle_tree = SimpleMerkleTree::new(tree_nodes);
+        let merkle_tree = SimpleMerkleTree::new(tree_nodes, &test_distributor());
         let out_of_bounds_result = merkle_tree.get_proof(10);
         assert!(out_of_bounds_result.is_err(), "Out of bounds should return error");
         
         println!("✅ Edge case tests passed!");
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn test_leaf_matches_program_hash() {
+        // The off-chain leaf must be byte-identical to the leaf computed by the program
+        for node in get_test_data() {
+            let off_chain = SimpleMerkleTree::hash_leaf(&test_distributor(), &node.claimant, node.amount);
+            let on_chain = hash_leaf(&test_distributor(), &node.claimant, node.amount);
+            assert_eq!(off_chain.to_bytes(), on_chain);
+        }
+    }
+
+    #[test]
+    fn test_compute_root_deep_proof() {
+        // 24-level proof, hashed level by level with hash_node
+        let leaf = hash_leaf(&test_distributor(), &Pubkey::new_unique(), 1_000);
+        let proof: Vec<[u8; 32]> = (0..24u8).map(|level| [level; 32]).collect();
+        let expected = proof.iter().fold(leaf, |node, sibling| hash_node(&node, sibling));
+
+        assert_eq!(compute_root(&proof, leaf), expected);
+        assert!(verify(&proof, expected, leaf));
+        assert!(!verify(&proof[..23], expected, leaf));
+        assert_eq!(compute_root(&[], leaf), leaf);
+    }
+
+    #[test]
+    fn test_proof_not_replayable_across_distributors() {
+        let tree_nodes = get_test_data();
+        let merkle_tree = SimpleMerkleTree::new(tree_nodes.clone(), &test_distributor());
+        let proof = merkle_tree.get_proof(0).expect("Failed to get proof");
+
+        // Same tree contents built for another distributor yields a different root
+        let other_distributor = Pubkey::from_str("11111111111111111111111111111112").unwrap();
+        let other_tree = SimpleMerkleTree::new(tree_nodes.clone(), &other_distributor);
+        assert_ne!(merkle_tree.get_root(), other_tree.get_root());
+
+        // A proof for one distributor must not verify against the other's root
+        let leaf = hash_leaf(&test_distributor(), &tree_nodes[0].claimant, tree_nodes[0].amount);
+        assert!(verify(&proof, *merkle_tree.get_root().unwrap(), leaf));
+        assert!(!verify(&proof, *other_tree.get_root().unwrap(), leaf));
+    }
+
+    #[test]
+    fn test_proof_valid_against_historical_root() {
+        let tree_nodes = get_test_data();
+        let old_tree = SimpleMerkleTree::new(tree_nodes.clone(), &test_distributor());
+        let proof = old_tree.get_proof(0).expect("Failed to get proof");
+        let leaf = hash_leaf(&test_distributor(), &tree_nodes[0].claimant, tree_nodes[0].amount);
+
+        // Rotate to a new root built from a different allocation list
+        let mut new_nodes = tree_nodes.clone();
+        new_nodes[0].amount += 500;
+        let new_tree = SimpleMerkleTree::new(new_nodes, &test_distributor());
+
+        let operator = Pubkey::new_unique();
+        let mut distributor = TokenDistributor::default();
+        distributor.rotate_merkle_root(*old_tree.get_root().unwrap(), operator, 100);
+        distributor.rotate_merkle_root(*new_tree.get_root().unwrap(), operator, 200);
+
+        // The proof for the old root still verifies while it is in the history
+        assert!(!verify(&proof, distributor.merkle_root, leaf));
+        assert!(distributor.known_roots(200).any(|root| verify(&proof, *root, leaf)));
+
+        // Once the old root falls out of the history window the proof is rejected
+        for i in 0..MAX_ROOT_HISTORY {
+            distributor.rotate_merkle_root([i as u8 + 1; 32], operator, 300 + i as i64);
+        }
+        assert!(!distributor.known_roots(300).any(|root| verify(&proof, *root, leaf)));
+    }
+
+    #[test]
+    fn test_historical_root_expires_after_grace_period() {
+        let operator = Pubkey::new_unique();
+        let mut distributor = TokenDistributor::default();
+        distributor.rotate_merkle_root([1; 32], operator, 100);
+        distributor.rotate_merkle_root([2; 32], operator, 200);
+        distributor.rotate_merkle_root([3; 32], operator, 300);
+
+        // Each replaced root counts from the update that replaced it
+        let known = |now| distributor.known_roots(now).copied().collect::<Vec<_>>();
+        assert_eq!(known(200 + ROOT_GRACE_PERIOD), vec![[3; 32], [2; 32], [1; 32]]);
+        assert_eq!(known(200 + ROOT_GRACE_PERIOD + 1), vec![[3; 32], [2; 32]]);
+        assert_eq!(known(300 + ROOT_GRACE_PERIOD + 1), vec![[3; 32]]);
+
+        // The current root never expires
+        assert_eq!(known(i64::MAX), vec![[3; 32]]);
+    }
+
+    #[test]
+    fn test_root_live_at() {
+        let mut distributor = TokenDistributor::default();
+        assert!(distributor.root_live_at(1_000).is_none());
+
+        // Record more updates than the ring buffer holds
+        let operators: Vec<Pubkey> = (0..ROOT_HISTORY_LEN + 2).map(|_| Pubkey::new_unique()).collect();
+        for (i, operator) in operators.iter().enumerate() {
+            distributor.rotate_merkle_root([i as u8 + 1; 32], *operator, 100 * (i as i64 + 1));
+        }
+        assert_eq!(distributor.root_update_count, ROOT_HISTORY_LEN as u64 + 2);
+        assert_eq!(distributor.root_updates().count(), ROOT_HISTORY_LEN);
+        assert_eq!(distributor.root_updates().next().unwrap().merkle_root, distributor.merkle_root);
+
+        // A claim between two updates was checked against the earlier root
+        let live = distributor.root_live_at(1_050).unwrap();
+        assert_eq!(live.merkle_root, [10; 32]);
+        assert_eq!(live.operator, operators[9]);
+        assert_eq!(live.updated_at, 1_000);
+
+        // Updates that left the ring buffer cannot be resolved
+        assert!(distributor.root_live_at(250).is_none());
+        assert_eq!(distributor.root_live_at(300).unwrap().merkle_root, [3; 32]);
+    }
+
+    #[test]
+    fn test_unlock_time_leaf() {
+        let node = &get_test_data()[0];
+        let unlock_time: i64 = 1_700_000_000;
+
+        // Version 2 leaf appends the little-endian unlock time to the version 1 preimage
+        let expected = hashv(&[
+            &[0u8],
+            test_distributor().as_ref(),
+            node.claimant.as_ref(),
+            &node.amount.to_le_bytes(),
+            &unlock_time.to_le_bytes(),
+        ]);
+        let leaf = hash_leaf_with_unlock_time(&test_distributor(), &node.claimant, node.amount, unlock_time);
+        assert_eq!(leaf, expected.to_bytes());
+
+        // The unlock time is bound into the leaf
+        assert_ne!(leaf, hash_leaf(&test_distributor(), &node.claimant, node.amount));
+        assert_ne!(
+            leaf,
+            hash_leaf_with_unlock_time(&test_distributor(), &node.claimant, node.amount, unlock_time - 1)
+        );
+    }
+
+    #[test]
+    fn test_multi_mint_leaf() {
+        let node = &get_test_data()[0];
+        let extra_amounts = [250u64, 0];
+
+        // Version 3 leaf appends one little-endian amount per extra mint to the version 1 preimage
+        let expected = hashv(&[
+            &[0u8],
+            test_distributor().as_ref(),
+            node.claimant.as_ref(),
+            &node.amount.to_le_bytes(),
+            &extra_amounts[0].to_le_bytes(),
+            &extra_amounts[1].to_le_bytes(),
+        ]);
+        let leaf = hash_leaf_multi_mint(&test_distributor(), &node.claimant, node.amount, &extra_amounts);
+        assert_eq!(leaf, expected.to_bytes());
+
+        // Without extra mints the leaf is the version 1 leaf
+        assert_eq!(
+            hash_leaf_multi_mint(&test_distributor(), &node.claimant, node.amount, &[]),
+            hash_leaf(&test_distributor(), &node.claimant, node.amount)
+        );
+
+        // Extra amounts are bound into the leaf, including their order
+        assert_ne!(
+            leaf,
+            hash_leaf_multi_mint(&test_distributor(), &node.claimant, node.amount, &[0, 250])
+        );
+        assert_ne!(
+            leaf,
+            hash_leaf_multi_mint(&test_distributor(), &node.claimant, node.amount, &[250])
+        );
+    }
+
+    #[test]
+    fn test_recipient_leaf() {
+        let node = &get_test_data()[0];
+        let recipient = Pubkey::new_unique();
+
+        // Version 4 leaf inserts the recipient between the claimant and the amount
+        let expected = hashv(&[
+            &[0u8],
+            test_distributor().as_ref(),
+            node.claimant.as_ref(),
+            recipient.as_ref(),
+            &node.amount.to_le_bytes(),
+        ]);
+        let leaf = hash_leaf_with_recipient(&test_distributor(), &node.claimant, &recipient, node.amount);
+        assert_eq!(leaf, expected.to_bytes());
+
+        // The recipient is bound into the leaf, a claimant cannot redirect to another wallet
+        assert_ne!(
+            leaf,
+            hash_leaf_with_recipient(&test_distributor(), &node.claimant, &Pubkey::new_unique(), node.amount)
+        );
+        assert_ne!(leaf, hash_leaf(&test_distributor(), &node.claimant, node.amount));
+    }
+
+    #[test]
+    fn test_keccak_tree() {
+        let leaves: Vec<[u8; 32]> = get_test_data()
+            .iter()
+            .map(|node| hash_leaf_keccak(&test_distributor(), &node.claimant, node.amount))
+            .collect();
+        let left = hash_node_keccak(&leaves[0], &leaves[1]);
+        let right = hash_node_keccak(&leaves[2], &leaves[3]);
+        let root = hash_node_keccak(&left, &right);
+
+        // Every leaf proves against the keccak root
+        let proofs = [
+            vec![leaves[1], right],
+            vec![leaves[0], right],
+            vec![leaves[3], left],
+            vec![leaves[2], left],
+        ];
+        for (leaf, proof) in leaves.iter().zip(proofs.iter()) {
+            assert_eq!(compute_root_keccak(proof, *leaf), root);
+        }
+
+        // Keccak and sha256 trees over the same entries never share leaves or roots
+        let node = &get_test_data()[0];
+        assert_ne!(leaves[0], hash_leaf(&test_distributor(), &node.claimant, node.amount));
+        assert_ne!(compute_root(&proofs[0], leaves[0]), root);
+    }
+}
@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod tests {
+    use crate::constants::MAX_REGISTRY_ENTRIES;
+    use crate::state::{MintRegistry, RegistryEntry};
+    use anchor_lang::solana_program::pubkey::Pubkey;
+
+    fn entry(start_time: i64, end_time: i64) -> RegistryEntry {
+        RegistryEntry {
+            distributor: Pubkey::new_unique(),
+            start_time,
+            end_time,
+        }
+    }
+
+    #[test]
+    fn test_upsert_refreshes_existing_entry() {
+        let mut registry = MintRegistry::default();
+        let first = entry(100, 200);
+        assert!(registry.upsert(first));
+
+        let extended = RegistryEntry { end_time: 300, ..first };
+        assert!(registry.upsert(extended));
+
+        let listed: Vec<_> = registry
+            .entries
+            .iter()
+            .filter(|e| e.distributor != Pubkey::default())
+            .collect();
+        assert_eq!(listed, vec![&extended]);
+    }
+
+    #[test]
+    fn test_prune_frees_slots_of_ended_entries() {
+        let mut registry = MintRegistry::default();
+        for i in 0..MAX_REGISTRY_ENTRIES as i64 {
+            assert!(registry.upsert(entry(100, 200 + i)));
+        }
+        assert!(!registry.upsert(entry(100, 500)), "Full registry should reject new entries");
+
+        registry.prune_ended(201);
+        assert!(registry.upsert(entry(100, 500)));
+        assert!(registry.upsert(entry(100, 500)));
+        assert!(!registry.upsert(entry(100, 500)));
+    }
+
+    #[test]
+    fn test_remove_frees_the_slot_of_a_listed_entry() {
+        let mut registry = MintRegistry::default();
+        let listed = entry(100, 200);
+        assert!(registry.upsert(listed));
+        assert!(registry.is_listed(&listed.distributor));
+
+        assert!(registry.remove(&listed.distributor));
+        assert!(!registry.is_listed(&listed.distributor));
+        assert!(!registry.remove(&listed.distributor), "Unlisted distributors should not be removed");
+        assert!(!registry.is_listed(&Pubkey::default()), "Empty slots should not count as listed");
+    }
+}
@@ -0,0 +1,41 @@
+#[cfg(test)]
+mod tests {
+    use crate::event::ScaledUiAmountScaling;
+    use crate::utils::find_scaled_ui_amount;
+
+    fn tlv_entry(extension_type: u16, value: &[u8]) -> Vec<u8> {
+        let mut entry = extension_type.to_le_bytes().to_vec();
+        entry.extend_from_slice(&(value.len() as u16).to_le_bytes());
+        entry.extend_from_slice(value);
+        entry
+    }
+
+    #[test]
+    fn test_find_scaled_ui_amount() {
+        let mut config = vec![0u8; 32];
+        config.extend_from_slice(&1.5f64.to_le_bytes());
+        config.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+        config.extend_from_slice(&2.0f64.to_le_bytes());
+
+        // Preceded by another extension (MintCloseAuthority)
+        let mut tlv_data = tlv_entry(3, &[7u8; 32]);
+        tlv_data.extend(tlv_entry(25, &config));
+
+        assert_eq!(
+            find_scaled_ui_amount(&tlv_data),
+            Some(ScaledUiAmountScaling {
+                multiplier: 1.5,
+                new_multiplier_effective_timestamp: 1_700_000_000,
+                new_multiplier: 2.0,
+            })
+        );
+    }
+
+    #[test]
+    fn test_find_scaled_ui_amount_absent() {
+        assert_eq!(find_scaled_ui_amount(&[]), None);
+        assert_eq!(find_scaled_ui_amount(&tlv_entry(3, &[7u8; 32])), None);
+        // Truncated entry
+        assert_eq!(find_scaled_ui_amount(&[25, 0, 56, 0, 1, 2]), None);
+    }
+}
@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod tests {
+    use crate::utils::{claim_message, cosign_message, parse_ed25519_instruction};
+    use anchor_lang::solana_program::pubkey::Pubkey;
+    use std::str::FromStr;
+
+    fn test_claimant() -> Pubkey {
+        Pubkey::from_str("3gmBN8LBomg3sZEjTgp2YsECMYgJpjcT7xUfpnDB4gSs").unwrap()
+    }
+
+    fn test_distributor() -> Pubkey {
+        Pubkey::from_str("9xQeWvG816bUx9EPjHmaT23yvVM2ZWbrrpZb9PusVFin").unwrap()
+    }
+
+    // Builds ed25519 program instruction data in the layout produced by the
+    // ed25519 program client: header, offsets, public key, signature, message
+    fn ed25519_instruction_data(public_key: &Pubkey, message: &[u8], instruction_index: u16) -> Vec<u8> {
+        let public_key_offset: u16 = 16;
+        let signature_offset: u16 = public_key_offset + 32;
+        let message_offset: u16 = signature_offset + 64;
+
+        let mut data = vec![1u8, 0u8];
+        for field in [
+            signature_offset,
+            instruction_index,
+            public_key_offset,
+            instruction_index,
+            message_offset,
+            message.len() as u16,
+            instruction_index,
+        ] {
+            data.extend_from_slice(&field.to_le_bytes());
+        }
+        data.extend_from_slice(public_key.as_ref());
+        data.extend_from_slice(&[7u8; 64]);
+        data.extend_from_slice(message);
+        data
+    }
+
+    #[test]
+    fn test_claim_message_layout() {
+        let message = claim_message(&test_distributor(), &test_claimant(), 1000, 0, 0, 1_700_000_000);
+
+        assert_eq!(message.len(), 23 + 32 + 32 + 8 + 8 + 8 + 8);
+        assert!(message.starts_with(b"token_distributor:claim"));
+        assert_eq!(&message[23..55], test_distributor().as_ref());
+        assert_eq!(&message[55..87], test_claimant().as_ref());
+        assert_eq!(&message[87..95], &1000u64.to_le_bytes());
+
+        assert_eq!(&message[103..111], &0u64.to_le_bytes());
+        assert_eq!(&message[111..119], &1_700_000_000i64.to_le_bytes());
+
+        // Partial and full claims must produce different authorizations
+        assert_ne!(message, claim_message(&test_distributor(), &test_claimant(), 1000, 400, 0, 1_700_000_000));
+
+        // A used voucher cannot be replayed once the nonce advances
+        assert_ne!(message, claim_message(&test_distributor(), &test_claimant(), 1000, 0, 1, 1_700_000_000));
+    }
+
+    #[test]
+    fn test_cosign_message_layout() {
+        let message = cosign_message(&test_distributor(), &test_claimant(), 1000, 1_700_000_000);
+
+        assert_eq!(message.len(), 24 + 32 + 32 + 8 + 8);
+        assert!(message.starts_with(b"token_distributor:cosign"));
+        assert_eq!(&message[24..56], test_distributor().as_ref());
+        assert_eq!(&message[56..88], test_claimant().as_ref());
+        assert_eq!(&message[88..96], &1000u64.to_le_bytes());
+        assert_eq!(&message[96..104], &1_700_000_000i64.to_le_bytes());
+
+        // A co-signature never doubles as a claimant voucher
+        assert!(!message.starts_with(b"token_distributor:claim"));
+    }
+
+    #[test]
+    fn test_parse_ed25519_instruction() {
+        let message = claim_message(&test_distributor(), &test_claimant(), 1000, 0, 0, 1_700_000_000);
+        let data = ed25519_instruction_data(&test_claimant(), &message, u16::MAX);
+
+        let (public_key, signed_message) = parse_ed25519_instruction(&data).expect("Failed to parse");
+        assert_eq!(public_key, test_claimant());
+        assert_eq!(signed_message, message.as_slice());
+    }
+
+    #[test]
+    fn test_parse_ed25519_instruction_rejects_external_data() {
+        let message = claim_message(&test_distributor(), &test_claimant(), 1000, 0, 0, 1_700_000_000);
+
+        // Public key or message referenced from another instruction is rejected
+        let data = ed25519_instruction_data(&test_claimant(), &message, 0);
+        assert!(parse_ed25519_instruction(&data).is_none());
+
+        // Truncated data is rejected
+        let data = ed25519_instruction_data(&test_claimant(), &message, u16::MAX);
+        assert!(parse_ed25519_instruction(&data[..data.len() - 1]).is_none());
+        assert!(parse_ed25519_instruction(&data[..10]).is_none());
+    }
+}
@@ -0,0 +1,144 @@
+//! Canonical merkle tree test vectors shared with the TypeScript tooling
+//!
+//! The vectors live in tests/vectors/merkle_tree_vectors.json at the repository
+//! root. Regenerate them with `UPDATE_TEST_VECTORS=1 cargo test test_vectors`.
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_merkle::SimpleMerkleTree;
+    use crate::constants::{TREE_VERSION, UNLOCK_TIME_TREE_VERSION};
+    use crate::utils::{hash_leaf, hash_leaf_with_unlock_time, verify};
+    use anchor_lang::solana_program::pubkey::Pubkey;
+    use serde_json::{json, Value};
+    use std::str::FromStr;
+
+    const VECTORS_PATH: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../tests/vectors/merkle_tree_vectors.json"
+    );
+
+    struct Entry {
+        claimant: Pubkey,
+        amount: u64,
+        unlock_time: Option<i64>,
+    }
+
+    fn to_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    fn from_hex(hex: &str) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+        }
+        bytes
+    }
+
+    fn hash_entry(distributor: &Pubkey, entry: &Entry) -> [u8; 32] {
+        match entry.unlock_time {
+            Some(unlock_time) => hash_leaf_with_unlock_time(distributor, &entry.claimant, entry.amount, unlock_time),
+            None => hash_leaf(distributor, &entry.claimant, entry.amount),
+        }
+    }
+
+    fn entries(count: u8, unlock_times: Option<&[i64]>) -> Vec<Entry> {
+        (0..count)
+            .map(|i| Entry {
+                claimant: Pubkey::new_from_array([i + 1; 32]),
+                amount: match i {
+                    0 => u64::MAX,
+                    _ => 1_000 * i as u64,
+                },
+                unlock_time: unlock_times.map(|times| times[i as usize % times.len()]),
+            })
+            .collect()
+    }
+
+    fn case(name: &str, tree_version: u8, distributor: &Pubkey, entries: Vec<Entry>) -> Value {
+        let leaves: Vec<[u8; 32]> = entries.iter().map(|entry| hash_entry(distributor, entry)).collect();
+        let tree = SimpleMerkleTree::from_leaves(leaves.clone());
+        let proofs: Vec<Vec<String>> = (0..leaves.len())
+            .map(|i| tree.get_proof(i).unwrap().iter().map(|node| to_hex(node)).collect())
+            .collect();
+
+        json!({
+            "name": name,
+            "tree_version": tree_version,
+            "distributor": distributor.to_string(),
+            "entries": entries.iter().map(|entry| json!({
+                "claimant": entry.claimant.to_string(),
+                // u64 / i64 as strings so JavaScript readers keep full precision
+                "amount": entry.amount.to_string(),
+                "unlock_time": entry.unlock_time.map(|t| t.to_string()),
+            })).collect::<Vec<_>>(),
+            "leaves": leaves.iter().map(|leaf| to_hex(leaf)).collect::<Vec<_>>(),
+            "root": to_hex(tree.get_root().unwrap()),
+            "proofs": proofs,
+        })
+    }
+
+    fn generate() -> Value {
+        let distributor = Pubkey::from_str("9xQeWvG816bUx9EPjHmaT23yvVM2ZWbrrpZb9PusVFin").unwrap();
+        let unlock_times: &[i64] = &[0, 1_700_000_000, -1, i64::MAX];
+
+        json!({
+            "description": "Merkle tree test vectors: hex-encoded leaves, root and per-leaf proofs. \
+                Leaves are sha256(0x00 || distributor || claimant || amount_le [|| unlock_time_le]), \
+                nodes are sha256(0x01 || sorted(left, right)), odd levels duplicate their last node.",
+            "cases": [
+                case("v1_single_leaf", TREE_VERSION, &distributor, entries(1, None)),
+                case("v1_two_leaves", TREE_VERSION, &distributor, entries(2, None)),
+                case("v1_odd_leaves", TREE_VERSION, &distributor, entries(5, None)),
+                case("v1_eight_leaves", TREE_VERSION, &distributor, entries(8, None)),
+                case("v2_unlock_times", UNLOCK_TIME_TREE_VERSION, &distributor, entries(7, Some(unlock_times))),
+            ],
+        })
+    }
+
+    fn read_vectors() -> Value {
+        let contents = std::fs::read_to_string(VECTORS_PATH).expect("Failed to read test vectors");
+        serde_json::from_str(&contents).expect("Failed to parse test vectors")
+    }
+
+    #[test]
+    fn test_vectors_up_to_date() {
+        let expected = generate();
+        if std::env::var_os("UPDATE_TEST_VECTORS").is_some() {
+            let contents = serde_json::to_string_pretty(&expected).unwrap() + "\n";
+            std::fs::write(VECTORS_PATH, contents).expect("Failed to write test vectors");
+        }
+
+        assert_eq!(
+            read_vectors(),
+            expected,
+            "Test vectors are stale, regenerate with UPDATE_TEST_VECTORS=1"
+        );
+    }
+
+    #[test]
+    fn test_vectors_verify_on_chain() {
+        for case in read_vectors()["cases"].as_array().unwrap() {
+            let distributor = Pubkey::from_str(case["distributor"].as_str().unwrap()).unwrap();
+            let root = from_hex(case["root"].as_str().unwrap());
+
+            for (i, entry) in case["entries"].as_array().unwrap().iter().enumerate() {
+                let entry = Entry {
+                    claimant: Pubkey::from_str(entry["claimant"].as_str().unwrap()).unwrap(),
+                    amount: entry["amount"].as_str().unwrap().parse().unwrap(),
+                    unlock_time: entry["unlock_time"].as_str().map(|t| t.parse().unwrap()),
+                };
+                let leaf = hash_entry(&distributor, &entry);
+                assert_eq!(leaf, from_hex(case["leaves"][i].as_str().unwrap()));
+
+                let proof: Vec<[u8; 32]> = case["proofs"][i]
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .map(|node| from_hex(node.as_str().unwrap()))
+                    .collect();
+                assert!(verify(&proof, root, leaf), "{} leaf {} does not verify", case["name"], i);
+            }
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer};
+
+/// Create a rent-exempt program account at a PDA, the way init_if_needed does
+/// - An address already holding lamports cannot be created with create_account, so anyone could
+///   block it by sending lamports there first: it is topped up to rent exemption, then allocated
+///   and assigned instead
+/// - signer_seeds must sign for the new account, and for the payer if it is a PDA
+pub fn create_pda_account<'info>(
+    payer: AccountInfo<'info>,
+    account: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let rent_exempt_lamports = Rent::get()?.minimum_balance(space).max(1);
+    let current_lamports = account.lamports();
+    if current_lamports == 0 {
+        return create_account(
+            CpiContext::new_with_signer(system_program, CreateAccount { from: payer, to: account }, signer_seeds),
+            rent_exempt_lamports,
+            space as u64,
+            &crate::ID,
+        );
+    }
+    
+    let required_lamports = rent_exempt_lamports.saturating_sub(current_lamports);
+    if required_lamports > 0 {
+        transfer(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                Transfer { from: payer, to: account.clone() },
+                signer_seeds,
+            ),
+            required_lamports,
+        )?;
+    }
+    allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate { account_to_allocate: account.clone() },
+            signer_seeds,
+        ),
+        space as u64,
+    )?;
+    assign(
+        CpiContext::new_with_signer(system_program, Assign { account_to_assign: account }, signer_seeds),
+        &crate::ID,
+    )
+}
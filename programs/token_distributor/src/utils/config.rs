@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+use crate::error::*;
+use crate::state::ProgramConfig;
+
+/// Load the program config, falling back to the defaults while it is not initialized
+/// - The account address is checked by the caller's seeds constraint
+pub fn load_program_config(config: &AccountInfo) -> Result<ProgramConfig> {
+    if config.data_len() == 0 {
+        return Ok(ProgramConfig::with_defaults());
+    }
+    
+    require!(config.owner == &crate::ID, TokenDistributorError::InvalidConfig);
+    let data = config.try_borrow_data()?;
+    ProgramConfig::try_deserialize(&mut data.as_ref())
+}
@@ -1,5 +1,11 @@
+pub mod account;
+pub mod config;
+pub mod signature;
 pub mod token;
 pub mod verify;
 
+pub use account::*;
+pub use config::*;
+pub use signature::*;
 pub use token::*;
 pub use verify::*; 
\ No newline at end of file
@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+use crate::constants::*;
+use crate::error::*;
+
+/// Size of the ed25519 instruction header (signature count + padding)
+const ED25519_HEADER_LEN: usize = 2;
+
+/// Size of one ed25519 signature offsets entry (7 little-endian u16 fields)
+const ED25519_OFFSETS_LEN: usize = 14;
+
+/// Builds the voucher message a claimant signs to authorize a claim_with_signature
+/// - Prefixed with CLAIM_MESSAGE_DOMAIN so the signature cannot authorize anything else
+/// - Binds the distributor, claimant, eligible amount and the amount to claim (0 = full pending)
+/// - Binds the claimant's current voucher nonce and an expiry so vouchers are single use
+///   and cannot be hoarded
+pub fn claim_message(
+    distributor: &Pubkey,
+    claimant: &Pubkey,
+    max_amount: u64,
+    amount: u64,
+    voucher_nonce: u64,
+    expiry: i64,
+) -> Vec<u8> {
+    [
+        CLAIM_MESSAGE_DOMAIN,
+        distributor.as_ref(),
+        claimant.as_ref(),
+        &max_amount.to_le_bytes(),
+        &amount.to_le_bytes(),
+        &voucher_nonce.to_le_bytes(),
+        &expiry.to_le_bytes(),
+    ]
+    .concat()
+}
+
+/// Builds the message an operator signs to co-sign claims of a claimant
+/// - Prefixed with COSIGN_MESSAGE_DOMAIN so the signature cannot authorize anything else
+/// - Binds the distributor, claimant and eligible amount, valid for any number of claims
+///   until the expiry (Unix timestamp)
+pub fn cosign_message(
+    distributor: &Pubkey,
+    claimant: &Pubkey,
+    max_amount: u64,
+    expiry: i64,
+) -> Vec<u8> {
+    [
+        COSIGN_MESSAGE_DOMAIN,
+        distributor.as_ref(),
+        claimant.as_ref(),
+        &max_amount.to_le_bytes(),
+        &expiry.to_le_bytes(),
+    ]
+    .concat()
+}
+
+/// Parses ed25519 program instruction data holding a single signature
+/// - Only accepts signatures whose public key and message live in the same instruction
+/// - Returns the signing public key and the signed message
+pub fn parse_ed25519_instruction(data: &[u8]) -> Option<(Pubkey, &[u8])> {
+    if data.len() < ED25519_HEADER_LEN + ED25519_OFFSETS_LEN || data[0] != 1 {
+        return None;
+    }
+    let offsets = &data[ED25519_HEADER_LEN..ED25519_HEADER_LEN + ED25519_OFFSETS_LEN];
+    let read_u16 = |i: usize| u16::from_le_bytes([offsets[i * 2], offsets[i * 2 + 1]]);
+    let signature_instruction_index = read_u16(1);
+    let public_key_offset = read_u16(2) as usize;
+    let public_key_instruction_index = read_u16(3);
+    let message_data_offset = read_u16(4) as usize;
+    let message_data_size = read_u16(5) as usize;
+    let message_instruction_index = read_u16(6);
+
+    // Data referenced from other instructions could differ from what was verified here
+    if signature_instruction_index != u16::MAX
+        || public_key_instruction_index != u16::MAX
+        || message_instruction_index != u16::MAX
+    {
+        return None;
+    }
+
+    let public_key = data.get(public_key_offset..public_key_offset + 32)?;
+    let message = data.get(message_data_offset..message_data_offset + message_data_size)?;
+    Some((Pubkey::try_from(public_key).ok()?, message))
+}
+
+/// Loads the ed25519 signature verified by the instruction preceding the current one
+/// - Returns the signing public key and the signed message
+/// - The ed25519 program fails the transaction if the signature itself is invalid
+pub fn load_ed25519_signature(instructions_sysvar: &AccountInfo) -> Result<(Pubkey, Vec<u8>)> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    require!(current_index > 0, TokenDistributorError::MissingSignatureInstruction);
+    let instruction = load_instruction_at_checked((current_index - 1) as usize, instructions_sysvar)?;
+    require!(
+        instruction.program_id == ed25519_program::ID && instruction.accounts.is_empty(),
+        TokenDistributorError::MissingSignatureInstruction
+    );
+
+    let (public_key, signed_message) = parse_ed25519_instruction(&instruction.data)
+        .ok_or(TokenDistributorError::InvalidClaimSignature)?;
+    Ok((public_key, signed_message.to_vec()))
+}
+
+/// Checks that the instruction preceding the current one is an ed25519 signature
+/// verification of `message` by `signer`
+pub fn verify_ed25519_signature(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<()> {
+    let (public_key, signed_message) = load_ed25519_signature(instructions_sysvar)?;
+    require!(
+        public_key == *signer && signed_message == message,
+        TokenDistributorError::InvalidClaimSignature
+    );
+    Ok(())
+}
@@ -1,7 +1,24 @@
 use anchor_lang::prelude::*;
+use anchor_spl::memo::{build_memo, BuildMemo};
+use anchor_spl::token_2022::spl_token_2022;
+use anchor_spl::token_2022::spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
+use anchor_spl::token_2022::spl_token_2022::extension::interest_bearing_mint::InterestBearingConfig;
+use anchor_spl::token_2022::spl_token_2022::extension::memo_transfer::memo_required;
+use anchor_spl::token_2022::spl_token_2022::extension::transfer_fee::TransferFeeAmount;
+use anchor_spl::token_2022_extensions::transfer_fee::{harvest_withheld_tokens_to_mint, HarvestWithheldTokensToMint};
 use anchor_spl::token_interface::{transfer_checked, TransferChecked, close_account, CloseAccount};
+use crate::error::TokenDistributorError;
+use crate::event::{AmountScaling, InterestBearingScaling, ScaledUiAmountScaling};
+
+/// Token 2022 extension type of ScaledUiAmountConfig
+/// - Not known to the pinned spl-token-2022 version, so read from the raw TLV data
+const SCALED_UI_AMOUNT_EXTENSION_TYPE: u16 = 25;
+
+/// Length of ScaledUiAmountConfig: authority, multiplier, effective timestamp, new multiplier
+const SCALED_UI_AMOUNT_CONFIG_LEN: usize = 32 + 8 + 8 + 8;
 
 /// Universal token transfer function that supports both SPL Token and Token 2022
+#[allow(clippy::too_many_arguments)]
 pub fn transfer_token<'a>(
     authority: AccountInfo<'a>,
     from: AccountInfo<'a>,
@@ -30,6 +47,44 @@ pub fn transfer_token<'a>(
     transfer_checked(cpi_ctx, amount, decimals)
 }
 
+/// Token transfer that also supports Token 2022 mints with the TransferHook extension
+/// - hook_accounts are the extra accounts the mint's transfer hook needs: the hook program,
+///   its extra account metas account and the accounts those resolve to, usually remaining accounts
+/// - Same as transfer_token when no hook accounts are given, or when built without the
+///   transfer-hooks feature, in which case transfers of hooked mints fail in the token program
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_token_with_hook<'a>(
+    authority: AccountInfo<'a>,
+    from: AccountInfo<'a>,
+    to: AccountInfo<'a>,
+    mint: AccountInfo<'a>,
+    token_program: AccountInfo<'a>,
+    amount: u64,
+    decimals: u8,
+    signer_seeds: Option<&[&[&[u8]]]>,
+    #[cfg_attr(not(feature = "transfer-hooks"), allow(unused_variables))]
+    hook_accounts: &[AccountInfo<'a>],
+) -> Result<()> {
+    // Resolves the hook's extra accounts from hook_accounts and appends them to the CPI
+    #[cfg(feature = "transfer-hooks")]
+    if !hook_accounts.is_empty() {
+        spl_token_2022::onchain::invoke_transfer_checked(
+            token_program.key,
+            from,
+            mint,
+            to,
+            authority,
+            hook_accounts,
+            amount,
+            decimals,
+            signer_seeds.unwrap_or_default(),
+        )?;
+        return Ok(());
+    }
+    
+    transfer_token(authority, from, to, mint, token_program, amount, decimals, signer_seeds)
+}
+
 /// Close token account with PDA authority for both SPL Token and Token 2022
 pub fn close_token_account_with_pda<'a>(
     token_account: AccountInfo<'a>,
@@ -54,3 +109,117 @@ pub fn close_token_account_with_pda<'a>(
         signer_seeds,
     ))
 }
+
+/// Move transfer fees withheld in a token account to its mint
+/// - Token 2022 refuses to close accounts holding withheld fees, so this must run before closing a vault
+/// - Harvested fees can then be withdrawn from the mint by its withdraw withheld authority
+/// - Returns the amount harvested, 0 for accounts without withheld fees
+pub fn harvest_withheld_fees<'a>(
+    token_account: AccountInfo<'a>,
+    mint: AccountInfo<'a>,
+    token_program: AccountInfo<'a>,
+) -> Result<u64> {
+    if token_account.owner != &spl_token_2022::ID {
+        return Ok(0);
+    }
+    
+    let withheld_amount: u64 = {
+        let data = token_account.try_borrow_data()?;
+        let account = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&data)?;
+        match account.get_extension::<TransferFeeAmount>() {
+            Ok(fee_amount) => fee_amount.withheld_amount.into(),
+            Err(_) => 0,
+        }
+    };
+    if withheld_amount == 0 {
+        return Ok(0);
+    }
+    
+    // Harvesting to the mint is permissionless, no signer required
+    harvest_withheld_tokens_to_mint(
+        CpiContext::new(
+            token_program.clone(),
+            HarvestWithheldTokensToMint {
+                token_program_id: token_program,
+                mint,
+            },
+        ),
+        vec![token_account],
+    )?;
+    
+    Ok(withheld_amount)
+}
+
+/// Check whether a token account requires a memo on incoming transfers
+/// - Only Token 2022 accounts with the MemoTransfer extension enabled do
+pub fn requires_incoming_memo(token_account: &AccountInfo) -> Result<bool> {
+    if token_account.owner != &spl_token_2022::ID {
+        return Ok(false);
+    }
+    
+    let data = token_account.try_borrow_data()?;
+    let account = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&data)?;
+    Ok(memo_required(&account))
+}
+
+/// Log the memo required by a MemoTransfer destination, if any
+/// - Must be called immediately before the transfer into the destination
+pub fn memo_if_required<'a>(
+    destination: &AccountInfo<'a>,
+    memo_program: Option<AccountInfo<'a>>,
+    memo: &[u8],
+) -> Result<()> {
+    if !requires_incoming_memo(destination)? {
+        return Ok(());
+    }
+    
+    let memo_program = memo_program.ok_or(TokenDistributorError::MemoProgramRequired)?;
+    build_memo(CpiContext::new(memo_program, BuildMemo {}), memo)
+}
+
+/// Read the UI amount scaling parameters of a mint
+/// - SPL Token mints and Token 2022 mints without scaling extensions only report decimals
+pub fn amount_scaling(mint: &AccountInfo, decimals: u8) -> Result<AmountScaling> {
+    let mut scaling = AmountScaling {
+        decimals,
+        ..Default::default()
+    };
+    if mint.owner != &spl_token_2022::ID {
+        return Ok(scaling);
+    }
+    
+    let data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+    
+    if let Ok(config) = mint.get_extension::<InterestBearingConfig>() {
+        scaling.interest_bearing = Some(InterestBearingScaling {
+            initialization_timestamp: config.initialization_timestamp.into(),
+            pre_update_average_rate: config.pre_update_average_rate.into(),
+            last_update_timestamp: config.last_update_timestamp.into(),
+            current_rate: config.current_rate.into(),
+        });
+    }
+    
+    scaling.scaled_ui_amount = find_scaled_ui_amount(mint.get_tlv_data());
+    Ok(scaling)
+}
+
+/// Find the ScaledUiAmountConfig entry in a mint's TLV extension data
+pub fn find_scaled_ui_amount(mut tlv_data: &[u8]) -> Option<ScaledUiAmountScaling> {
+    while tlv_data.len() >= 4 {
+        let extension_type = u16::from_le_bytes([tlv_data[0], tlv_data[1]]);
+        let length = u16::from_le_bytes([tlv_data[2], tlv_data[3]]) as usize;
+        let value = tlv_data.get(4..4 + length)?;
+        
+        if extension_type == SCALED_UI_AMOUNT_EXTENSION_TYPE && length == SCALED_UI_AMOUNT_CONFIG_LEN {
+            let read = |offset: usize| value[offset..offset + 8].try_into().ok();
+            return Some(ScaledUiAmountScaling {
+                multiplier: f64::from_le_bytes(read(32)?),
+                new_multiplier_effective_timestamp: i64::from_le_bytes(read(40)?),
+                new_multiplier: f64::from_le_bytes(read(48)?),
+            });
+        }
+        tlv_data = &tlv_data[4 + length..];
+    }
+    None
+}
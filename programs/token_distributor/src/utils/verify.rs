@@ -1,3 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use solana_keccak_hasher::hashv as keccak_hashv;
+use crate::constants::*;
+
+/// Computes the merkle leaf for a (distributor, claimant, amount) entry
+/// - Prefixed with LEAF_PREFIX so a leaf can never be confused with an intermediate node
+/// - Binds the leaf to the distributor so proofs cannot be replayed across distributors
+pub fn hash_leaf(distributor: &Pubkey, claimant: &Pubkey, amount: u64) -> [u8; 32] {
+    hashv(&[
+        &[LEAF_PREFIX],
+        distributor.as_ref(),
+        claimant.as_ref(),
+        &amount.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// Computes the merkle leaf for a (distributor, claimant, amount, unlock_time) entry
+/// - Used by UNLOCK_TIME_TREE_VERSION trees
+/// - Same prefix and distributor binding as hash_leaf
+pub fn hash_leaf_with_unlock_time(
+    distributor: &Pubkey,
+    claimant: &Pubkey,
+    amount: u64,
+    unlock_time: i64,
+) -> [u8; 32] {
+    hashv(&[
+        &[LEAF_PREFIX],
+        distributor.as_ref(),
+        claimant.as_ref(),
+        &amount.to_le_bytes(),
+        &unlock_time.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// Computes the merkle leaf for a (distributor, claimant, amount, extra_amounts) entry
+/// - Used by MULTI_MINT_TREE_VERSION trees, one extra amount per extra mint in the order added
+/// - Same prefix and distributor binding as hash_leaf, equal to it when there are no extra mints
+pub fn hash_leaf_multi_mint(
+    distributor: &Pubkey,
+    claimant: &Pubkey,
+    amount: u64,
+    extra_amounts: &[u64],
+) -> [u8; 32] {
+    let extra_amounts: Vec<[u8; 8]> = extra_amounts.iter().map(|amount| amount.to_le_bytes()).collect();
+    let mut data: Vec<&[u8]> = vec![&[LEAF_PREFIX], distributor.as_ref(), claimant.as_ref()];
+    let amount = amount.to_le_bytes();
+    data.push(&amount);
+    data.extend(extra_amounts.iter().map(|amount| amount.as_slice()));
+    hashv(&data).to_bytes()
+}
+
+/// Computes the merkle leaf for a (distributor, claimant, recipient, amount) entry
+/// - Used by RECIPIENT_TREE_VERSION trees, the claimant authorizes claims paid to the recipient
+/// - Same prefix and distributor binding as hash_leaf
+pub fn hash_leaf_with_recipient(
+    distributor: &Pubkey,
+    claimant: &Pubkey,
+    recipient: &Pubkey,
+    amount: u64,
+) -> [u8; 32] {
+    hashv(&[
+        &[LEAF_PREFIX],
+        distributor.as_ref(),
+        claimant.as_ref(),
+        recipient.as_ref(),
+        &amount.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// Computes an intermediate merkle node from two children
+/// - Prefixed with NODE_PREFIX for domain separation from leaves
+/// - Children are sorted so proofs do not need to carry left/right flags
+pub fn hash_node(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    if a <= b {
+        hashv(&[&[NODE_PREFIX], a, b]).to_bytes()
+    } else {
+        hashv(&[&[NODE_PREFIX], b, a]).to_bytes()
+    }
+}
+
 /// Merkle proof verification
 /// Modified version of https://github.com/saber-hq/merkle-distributor/blob/ac937d1901033ecb7fa3b0db22f7b39569c8e052/programs/merkle-distributor/src/merkle_proof.rs#L8
 /// This function deals with verification of Merkle trees (hash trees).
@@ -6,17 +90,49 @@
 /// defined by `root`. For this, a `proof` must be provided, containing
 /// sibling hashes on the branch from the leaf to the root of the tree. Each
 /// pair of leaves and each pair of pre-images are assumed to be sorted.
-pub fn verify(proof: Vec<[u8; 32]>, root: [u8; 32], leaf: [u8; 32]) -> bool {
-    let mut computed_hash = leaf;
-    for proof_element in proof.into_iter() {
-        if computed_hash <= proof_element {
-            // Hash(current computed hash + current element of the proof)
-            computed_hash = anchor_lang::solana_program::hash::hashv(&[&computed_hash, &proof_element]).to_bytes();
-        } else {
-            // Hash(current element of the proof + current computed hash)
-            computed_hash = anchor_lang::solana_program::hash::hashv(&[&proof_element, &computed_hash]).to_bytes();
-        }
-    }
+/// Intermediate nodes are hashed with NODE_PREFIX (see `hash_node`).
+pub fn verify(proof: &[[u8; 32]], root: [u8; 32], leaf: [u8; 32]) -> bool {
     // Check if the computed hash (root) is equal to the provided root
-    computed_hash == root
-} 
\ No newline at end of file
+    compute_root(proof, leaf) == root
+}
+
+/// Computes the merkle root a proof leads to from a leaf
+/// - Walks the proof once, borrowing each sibling instead of copying the proof
+/// - Lets callers check the result against several candidate roots without rehashing
+pub fn compute_root(proof: &[[u8; 32]], leaf: [u8; 32]) -> [u8; 32] {
+    proof.iter().fold(leaf, |computed_hash, proof_element| {
+        // Hash(prefix + sorted pair of current computed hash and proof element)
+        hash_node(&computed_hash, proof_element)
+    })
+}
+
+/// Computes the keccak256 merkle leaf for a (distributor, claimant, amount) entry
+/// - Used by KECCAK_TREE_VERSION trees, same preimage as hash_leaf
+pub fn hash_leaf_keccak(distributor: &Pubkey, claimant: &Pubkey, amount: u64) -> [u8; 32] {
+    keccak_hashv(&[
+        &[LEAF_PREFIX],
+        distributor.as_ref(),
+        claimant.as_ref(),
+        &amount.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// Computes a keccak256 intermediate merkle node from two children
+/// - Used by KECCAK_TREE_VERSION trees, same preimage as hash_node
+pub fn hash_node_keccak(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    // The prefix and both children go to a single syscall, no preimage is copied
+    if a <= b {
+        keccak_hashv(&[&[NODE_PREFIX], a, b]).to_bytes()
+    } else {
+        keccak_hashv(&[&[NODE_PREFIX], b, a]).to_bytes()
+    }
+}
+
+/// Computes the merkle root a keccak256 proof leads to from a leaf
+/// - Keccak counterpart of compute_root for KECCAK_TREE_VERSION trees
+pub fn compute_root_keccak(proof: &[[u8; 32]], leaf: [u8; 32]) -> [u8; 32] {
+    proof.iter().fold(leaf, |computed_hash, proof_element| {
+        hash_node_keccak(&computed_hash, proof_element)
+    })
+}
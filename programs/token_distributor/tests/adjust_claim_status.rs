@@ -0,0 +1,99 @@
+//! Reconciliation of claims made against a later-reduced allocation
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use common::*;
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+use token_distributor::error::TokenDistributorError;
+use token_distributor::utils::verify::hash_leaf;
+use token_distributor::{accounts, instruction, ID};
+
+const DURATION: i64 = 7 * DAY;
+const AMOUNT: u64 = 1_000_000;
+
+/// Reconciles the claimant's claim status with their single-leaf allocation of `max_amount`, signed by the owner as operator
+async fn adjust_claim_status(test: &mut TestDistributor, claimant: &Pubkey, max_amount: u64) -> Result<u64, BanksClientError> {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::AdjustClaimStatus {
+            distributor: test.distributor,
+            claim_status: claim_status_address(&test.distributor, claimant),
+            claimant: *claimant,
+            operator: test.owner.pubkey(),
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::AdjustClaimStatus { max_amount, proof: vec![], unlock_time: None }.data(),
+    };
+    test.send(&[ix], &[]).await
+}
+
+/// A root lowering a claimed allocation is reported, reconciled, and settled once a later root raises it again
+#[tokio::test]
+async fn test_adjust_claim_status() {
+    let mut test = TestDistributor::new(false, 2 * AMOUNT, DURATION, None).await;
+    let claimant = Keypair::new();
+    let leaf = |test: &TestDistributor, max_amount| hash_leaf(&test.distributor, &claimant.pubkey(), max_amount);
+    test.set_merkle_root(leaf(&test, AMOUNT)).await.unwrap();
+    test.set_time(GENESIS + DAY).await.unwrap();
+    test.warp_to(GENESIS + DAY).await;
+    let token_account = test.create_token_account(&claimant.pubkey()).await;
+    test.claim(&claimant, &token_account, AMOUNT, vec![]).await.unwrap();
+
+    // The operator halves the allocation after it was claimed
+    test.set_merkle_root(leaf(&test, AMOUNT / 2)).await.unwrap();
+    assert_error(test.claim(&claimant, &token_account, AMOUNT / 2, vec![]).await, TokenDistributorError::AllocationReduced);
+    assert_error(adjust_claim_status(&mut test, &claimant.pubkey(), AMOUNT).await, TokenDistributorError::AllocationNotReduced);
+    adjust_claim_status(&mut test, &claimant.pubkey(), AMOUNT / 2).await.unwrap();
+
+    let status = test.claim_status(&claimant.pubkey()).await.unwrap();
+    assert_eq!((status.claimed_amount, status.over_claimed_amount), (AMOUNT, AMOUNT / 2));
+    let distributor = test.distributor().await;
+    assert_eq!((distributor.total_claimed, distributor.total_over_claimed), (AMOUNT, AMOUNT / 2));
+
+    // The reconciliation pays nothing, the claimant's claims stay blocked
+    assert_error(test.claim(&claimant, &token_account, AMOUNT / 2, vec![]).await, TokenDistributorError::AllocationReduced);
+    assert_eq!(test.token_balance(&token_account).await, AMOUNT);
+
+    // A later root allocating more than was claimed pays the difference and settles the record
+    test.set_merkle_root(leaf(&test, 2 * AMOUNT)).await.unwrap();
+    test.claim(&claimant, &token_account, 2 * AMOUNT, vec![]).await.unwrap();
+    assert_eq!(test.token_balance(&token_account).await, 2 * AMOUNT);
+    let status = test.claim_status(&claimant.pubkey()).await.unwrap();
+    assert_eq!((status.claimed_amount, status.over_claimed_amount), (2 * AMOUNT, 0));
+    let distributor = test.distributor().await;
+    assert_eq!((distributor.total_claimed, distributor.total_over_claimed), (2 * AMOUNT, 0));
+}
+
+/// A root restoring exactly the claimed amount leaves nothing to claim, adjusting with it clears the record
+#[tokio::test]
+async fn test_adjust_claim_status_restored_allocation() {
+    let mut test = TestDistributor::new(false, AMOUNT, DURATION, None).await;
+    let claimant = Keypair::new();
+    let leaf = |test: &TestDistributor, max_amount| hash_leaf(&test.distributor, &claimant.pubkey(), max_amount);
+    test.set_merkle_root(leaf(&test, AMOUNT)).await.unwrap();
+    test.set_time(GENESIS + DAY).await.unwrap();
+    test.warp_to(GENESIS + DAY).await;
+    let token_account = test.create_token_account(&claimant.pubkey()).await;
+    test.claim(&claimant, &token_account, AMOUNT, vec![]).await.unwrap();
+
+    test.set_merkle_root(leaf(&test, AMOUNT / 4)).await.unwrap();
+    adjust_claim_status(&mut test, &claimant.pubkey(), AMOUNT / 4).await.unwrap();
+    assert_eq!(test.distributor().await.total_over_claimed, AMOUNT * 3 / 4);
+
+    // The allocation comes back to what was claimed, no claim can settle the record
+    test.warp_to(GENESIS + DAY + 60).await;
+    test.set_merkle_root(leaf(&test, AMOUNT)).await.unwrap();
+    assert_error(test.claim(&claimant, &token_account, AMOUNT, vec![]).await, TokenDistributorError::InvalidAmount);
+    adjust_claim_status(&mut test, &claimant.pubkey(), AMOUNT).await.unwrap();
+    assert_eq!(test.claim_status(&claimant.pubkey()).await.unwrap().over_claimed_amount, 0);
+    assert_eq!(test.distributor().await.total_over_claimed, 0);
+
+    // With nothing recorded, an allocation that is not reduced has nothing to reconcile
+    assert_error(adjust_claim_status(&mut test, &claimant.pubkey(), AMOUNT).await, TokenDistributorError::AllocationNotReduced);
+}
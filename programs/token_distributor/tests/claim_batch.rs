@@ -0,0 +1,86 @@
+//! Batched claims across distributors
+
+mod common;
+
+use anchor_lang::prelude::{AccountMeta, Pubkey};
+use anchor_lang::solana_program::{instruction::Instruction, system_instruction, system_program};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use common::*;
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+use token_distributor::instructions::BatchClaimEntry;
+use token_distributor::state::ClaimStatus;
+use token_distributor::utils::verify::hash_leaf;
+use token_distributor::{accounts, instruction, ID};
+
+const DURATION: i64 = 7 * DAY;
+const AMOUNT: u64 = 1_000_000;
+
+/// Claims the claimant's single-leaf allocation of the test distributor through claim_batch
+async fn claim_batch(test: &mut TestDistributor, claimant: &Keypair, token_account: Pubkey) -> Result<u64, BanksClientError> {
+    let mut accounts = accounts::ClaimBatch {
+        claimant_token_account: token_account,
+        token_mint: test.mint,
+        claimant: claimant.pubkey(),
+        system_program: system_program::ID,
+        token_program: test.token_program,
+        memo_program: None,
+        #[cfg(feature = "protocol-fee")]
+        protocol_fee_account: None,
+        #[cfg(feature = "claim-gates")]
+        gate_token_account: None,
+        #[cfg(feature = "claim-gates")]
+        attestation: None,
+        config: config(),
+        event_authority: event_authority(),
+        program: ID,
+    }
+    .to_account_metas(None);
+    accounts.extend([
+        AccountMeta::new(test.distributor, false),
+        AccountMeta::new(claim_status_address(&test.distributor, &claimant.pubkey()), false),
+        AccountMeta::new(test.vault, false),
+    ]);
+    let entries = vec![BatchClaimEntry { max_amount: AMOUNT, proof: vec![], unlock_time: None }];
+    let ix = Instruction { program_id: ID, accounts, data: instruction::ClaimBatch { entries }.data() };
+    test.send(&[ix], &[claimant]).await
+}
+
+/// Lamports sent to a claim status address ahead of the claim do not block its creation
+#[tokio::test]
+async fn test_claim_batch_prefunded_claim_status() {
+    let mut test = TestDistributor::new(false, AMOUNT, DURATION, None).await;
+    let claimant = Keypair::new();
+    test.set_merkle_root(hash_leaf(&test.distributor, &claimant.pubkey(), AMOUNT)).await.unwrap();
+    test.set_time(GENESIS + DAY).await.unwrap();
+    test.warp_to(GENESIS + DAY).await;
+    let token_account = test.create_token_account(&claimant.pubkey()).await;
+
+    // The claimant pays the claim status rent, anyone can fund its address beforehand
+    let owner = test.owner.pubkey();
+    let claim_status = claim_status_address(&test.distributor, &claimant.pubkey());
+    let prefunded = test.minimum_balance(0).await;
+    let rent = test.minimum_balance(ClaimStatus::LEN).await;
+    test.send(
+        &[
+            system_instruction::transfer(&owner, &claimant.pubkey(), rent),
+            system_instruction::transfer(&owner, &claim_status, prefunded),
+        ],
+        &[],
+    )
+    .await
+    .unwrap();
+
+    claim_batch(&mut test, &claimant, token_account).await.unwrap();
+    assert_eq!(test.token_balance(&token_account).await, AMOUNT);
+    let status = test.claim_status(&claimant.pubkey()).await.unwrap();
+    assert_eq!(status.claimed_amount, AMOUNT);
+    assert_eq!(status.rent_payer, claimant.pubkey());
+
+    // Only the shortfall was charged, the account is rent exempt and owned by the program
+    let account = test.account(&claim_status).await.unwrap();
+    assert_eq!(account.owner, ID);
+    assert_eq!(account.lamports, rent);
+    assert_eq!(account.data.len(), ClaimStatus::LEN);
+    assert_eq!(test.account(&claimant.pubkey()).await.unwrap().lamports, prefunded);
+}
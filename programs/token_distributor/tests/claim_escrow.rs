@@ -0,0 +1,142 @@
+//! Claims paid into per-claimant escrows and released linearly
+#![cfg(feature = "claim-escrow")]
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::system_program;
+use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
+use common::*;
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+use token_distributor::constants::*;
+use token_distributor::error::TokenDistributorError;
+use token_distributor::state::ClaimEscrow;
+use token_distributor::utils::verify::hash_leaf;
+use token_distributor::{accounts, instruction, ID};
+
+const DURATION: i64 = 7 * DAY;
+const RELEASE_DURATION: i64 = 10 * DAY;
+const AMOUNT: u64 = 1_000_000;
+
+fn claim_escrow_address(test: &TestDistributor, claimant: &Pubkey) -> Pubkey {
+    pda(&[CLAIM_ESCROW_SEED.as_bytes(), test.distributor.as_ref(), claimant.as_ref()])
+}
+
+fn escrow_vault_address(test: &TestDistributor, claimant: &Pubkey) -> Pubkey {
+    pda(&[ESCROW_VAULT_SEED.as_bytes(), test.distributor.as_ref(), claimant.as_ref()])
+}
+
+async fn enable_claim_escrow(test: &mut TestDistributor) -> Result<u64, BanksClientError> {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::EnableClaimEscrow {
+            distributor: test.distributor,
+            owner: test.owner.pubkey(),
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::EnableClaimEscrow { release_duration: RELEASE_DURATION }.data(),
+    };
+    test.send(&[ix], &[]).await
+}
+
+/// Claims the claimant's single-leaf allocation into their claim escrow
+async fn claim_into_escrow(test: &mut TestDistributor, claimant: &Keypair, token_account: &Pubkey) -> Result<u64, BanksClientError> {
+    let mut accounts = accounts::Claim {
+        distributor: test.distributor,
+        claim_status: claim_status_address(&test.distributor, &claimant.pubkey()),
+        token_vault: test.vault,
+        shared_vault: None,
+        claimant_token_account: *token_account,
+        token_mint: test.mint,
+        claimant: claimant.pubkey(),
+        delegate: None,
+        payer: test.owner.pubkey(),
+        system_program: system_program::ID,
+        token_program: test.token_program,
+        memo_program: None,
+        #[cfg(feature = "protocol-fee")]
+        protocol_fee_account: None,
+        #[cfg(feature = "claim-gates")]
+        gate_token_account: None,
+        #[cfg(feature = "claim-gates")]
+        attestation: None,
+        config: config(),
+        #[cfg(feature = "claim-log")]
+        claim_log: None,
+        instructions_sysvar: None,
+        claim_escrow: Some(claim_escrow_address(test, &claimant.pubkey())),
+        escrow_vault: Some(escrow_vault_address(test, &claimant.pubkey())),
+        event_authority: event_authority(),
+        program: ID,
+    }
+    .to_account_metas(None);
+    accounts.iter_mut().find(|meta| meta.pubkey == claimant.pubkey()).unwrap().is_signer = true;
+    let ix = Instruction {
+        program_id: ID,
+        accounts,
+        data: instruction::Claim { max_amount: AMOUNT, proof: vec![], amount: None, unlock_time: None, cosign_expiry: None }
+            .data(),
+    };
+    test.send(&[ix], &[claimant]).await
+}
+
+async fn release(test: &mut TestDistributor, claimant: &Keypair, token_account: &Pubkey) -> Result<u64, BanksClientError> {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::Release {
+            claim_escrow: claim_escrow_address(test, &claimant.pubkey()),
+            escrow_vault: escrow_vault_address(test, &claimant.pubkey()),
+            claimant_token_account: *token_account,
+            token_mint: test.mint,
+            claimant: claimant.pubkey(),
+            token_program: test.token_program,
+            memo_program: None,
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::Release {}.data(),
+    };
+    test.send(&[ix], &[claimant]).await
+}
+
+/// An escrowed claim lands in the claimant's escrow and is released linearly from the claim
+#[tokio::test]
+async fn test_claim_escrow() {
+    let mut test = TestDistributor::new(false, AMOUNT, DURATION, None).await;
+    let claimant = Keypair::new();
+    enable_claim_escrow(&mut test).await.unwrap();
+    assert_error(enable_claim_escrow(&mut test).await, TokenDistributorError::ClaimEscrowAlreadyEnabled);
+    test.set_merkle_root(hash_leaf(&test.distributor, &claimant.pubkey(), AMOUNT)).await.unwrap();
+    test.set_time(GENESIS + DAY).await.unwrap();
+    test.warp_to(GENESIS + DAY).await;
+    let token_account = test.create_token_account(&claimant.pubkey()).await;
+
+    // Claims must go into the escrow
+    assert_error(test.claim(&claimant, &token_account, AMOUNT, vec![]).await, TokenDistributorError::ClaimEscrowRequired);
+    claim_into_escrow(&mut test, &claimant, &token_account).await.unwrap();
+    assert_eq!(test.token_balance(&token_account).await, 0);
+    assert_eq!(test.token_balance(&escrow_vault_address(&test, &claimant.pubkey())).await, AMOUNT);
+    assert_error(release(&mut test, &claimant, &token_account).await, TokenDistributorError::NothingToRelease);
+
+    // Half of the release duration unlocks half of the escrow
+    test.warp_to(GENESIS + DAY + RELEASE_DURATION / 2).await;
+    release(&mut test, &claimant, &token_account).await.unwrap();
+    assert_eq!(test.token_balance(&token_account).await, AMOUNT / 2);
+
+    // The rest is releasable at the end, also after the distribution has ended
+    test.warp_to(GENESIS + DAY + RELEASE_DURATION).await;
+    release(&mut test, &claimant, &token_account).await.unwrap();
+    assert_eq!(test.token_balance(&token_account).await, AMOUNT);
+    assert_error(release(&mut test, &claimant, &token_account).await, TokenDistributorError::NothingToRelease);
+
+    let address = claim_escrow_address(&test, &claimant.pubkey());
+    let data = test.account(&address).await.unwrap().data;
+    let escrow = ClaimEscrow::try_deserialize(&mut data.as_slice()).unwrap();
+    assert_eq!((escrow.escrowed_amount, escrow.released_amount), (AMOUNT, AMOUNT));
+    assert_eq!((escrow.release_start, escrow.release_end()), (GENESIS + DAY, GENESIS + DAY + RELEASE_DURATION));
+}
@@ -0,0 +1,148 @@
+//! Lamport claim fees collected on the distributor account and swept by the owner
+#![cfg(feature = "claim-fee")]
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::system_instruction;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use common::*;
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+use token_distributor::constants::MAX_CLAIM_FEE;
+use token_distributor::error::TokenDistributorError;
+use token_distributor::state::TokenDistributor;
+use token_distributor::utils::verify::{hash_leaf, hash_node};
+use token_distributor::{accounts, instruction, ID};
+
+const DURATION: i64 = 7 * DAY;
+const AMOUNT: u64 = 1_000_000;
+const CLAIM_FEE: u64 = 1_000_000;
+
+/// Sets the claim fee, signed by `owner` along with the test owner paying the fee
+async fn set_claim_fee(test: &mut TestDistributor, owner: &Keypair, claim_fee: u64) -> Result<u64, BanksClientError> {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::SetClaimFee {
+            distributor: test.distributor,
+            owner: owner.pubkey(),
+            config: config(),
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::SetClaimFee { claim_fee }.data(),
+    };
+    test.send(&[ix], &[owner]).await
+}
+
+async fn withdraw_claim_fees(test: &mut TestDistributor, owner: &Keypair) -> Result<u64, BanksClientError> {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::WithdrawClaimFees {
+            distributor: test.distributor,
+            owner: owner.pubkey(),
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::WithdrawClaimFees {}.data(),
+    };
+    test.send(&[ix], &[owner]).await
+}
+
+async fn lamports(test: &mut TestDistributor, address: &Pubkey) -> u64 {
+    test.account(address).await.map_or(0, |account| account.lamports)
+}
+
+/// Each claim pays the fee into the distributor, sweeps take exactly the fees and leave the rent-exempt minimum
+#[tokio::test]
+async fn test_claim_fee() {
+    let mut test = TestDistributor::new(false, 3 * AMOUNT, DURATION, None).await;
+    let (owner, distributor) = (test.owner.insecure_clone(), test.distributor);
+    let rent = test.minimum_balance(TokenDistributor::LEN).await;
+    assert_eq!(lamports(&mut test, &distributor).await, rent);
+
+    // Only the owner sets the fee, up to the configured maximum
+    let stranger = Keypair::new();
+    assert_error(set_claim_fee(&mut test, &stranger, CLAIM_FEE).await, TokenDistributorError::OnlyOwner);
+    assert_error(set_claim_fee(&mut test, &owner, MAX_CLAIM_FEE + 1).await, TokenDistributorError::InvalidClaimFee);
+    set_claim_fee(&mut test, &owner, CLAIM_FEE).await.unwrap();
+    assert_eq!(test.distributor().await.claim_fee, CLAIM_FEE);
+    assert_error(withdraw_claim_fees(&mut test, &owner).await, TokenDistributorError::NoClaimFees);
+
+    let claimants = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let leaves: Vec<_> = claimants.iter().map(|claimant| hash_leaf(&distributor, &claimant.pubkey(), AMOUNT)).collect();
+    let root = hash_node(&hash_node(&leaves[0], &leaves[1]), &leaves[2]);
+    let proofs = [vec![leaves[1], leaves[2]], vec![leaves[0], leaves[2]], vec![hash_node(&leaves[0], &leaves[1])]];
+    test.set_merkle_root(root).await.unwrap();
+    test.set_time(GENESIS + DAY).await.unwrap();
+    test.warp_to(GENESIS + DAY).await;
+
+    // The payer of each claim pays the fee, here the owner
+    for (claimant, proof) in claimants[..2].iter().zip(&proofs) {
+        let token_account = test.create_token_account(&claimant.pubkey()).await;
+        test.claim(claimant, &token_account, AMOUNT, proof.clone()).await.unwrap();
+    }
+    let state = test.distributor().await;
+    assert_eq!((state.total_fees_collected, state.total_fees_withdrawn), (2 * CLAIM_FEE, 0));
+    assert_eq!(lamports(&mut test, &distributor).await, rent + 2 * CLAIM_FEE);
+
+    // Only the owner sweeps, down to the rent-exempt minimum
+    assert_error(withdraw_claim_fees(&mut test, &stranger).await, TokenDistributorError::OnlyOwner);
+    let balance = lamports(&mut test, &owner.pubkey()).await;
+    withdraw_claim_fees(&mut test, &owner).await.unwrap();
+    // The owner pays the transaction fee of 5,000 lamports
+    assert_eq!(lamports(&mut test, &owner.pubkey()).await, balance + 2 * CLAIM_FEE - 5_000);
+    assert_eq!(lamports(&mut test, &distributor).await, rent);
+    let state = test.distributor().await;
+    assert_eq!((state.total_fees_collected, state.total_fees_withdrawn), (2 * CLAIM_FEE, 2 * CLAIM_FEE));
+    assert_error(withdraw_claim_fees(&mut test, &owner).await, TokenDistributorError::NoClaimFees);
+
+    // A disabled fee collects nothing
+    set_claim_fee(&mut test, &owner, 0).await.unwrap();
+    let token_account = test.create_token_account(&claimants[2].pubkey()).await;
+    test.claim(&claimants[2], &token_account, AMOUNT, proofs[2].clone()).await.unwrap();
+    assert_eq!(test.distributor().await.total_fees_collected, 2 * CLAIM_FEE);
+    assert_error(withdraw_claim_fees(&mut test, &owner).await, TokenDistributorError::NoClaimFees);
+
+    // Lamports sent to the distributor directly are not fees
+    let gift = system_instruction::transfer(&owner.pubkey(), &distributor, CLAIM_FEE);
+    test.send(&[gift], &[]).await.unwrap();
+    assert_error(withdraw_claim_fees(&mut test, &owner).await, TokenDistributorError::NoClaimFees);
+    assert_eq!(lamports(&mut test, &distributor).await, rent + CLAIM_FEE);
+}
+
+/// Fees collected after a sweep are withdrawn on their own, the totals keep adding up
+#[tokio::test]
+async fn test_withdraw_claim_fees_repeatedly() {
+    let mut test = TestDistributor::new(false, 2 * AMOUNT, DURATION, None).await;
+    let (owner, distributor) = (test.owner.insecure_clone(), test.distributor);
+    let rent = test.minimum_balance(TokenDistributor::LEN).await;
+    set_claim_fee(&mut test, &owner, CLAIM_FEE).await.unwrap();
+
+    let (alice, bob) = (Keypair::new(), Keypair::new());
+    let alice_leaf = hash_leaf(&distributor, &alice.pubkey(), AMOUNT);
+    let bob_leaf = hash_leaf(&distributor, &bob.pubkey(), AMOUNT);
+    test.set_merkle_root(hash_node(&alice_leaf, &bob_leaf)).await.unwrap();
+    test.set_time(GENESIS + DAY).await.unwrap();
+    test.warp_to(GENESIS + DAY).await;
+
+    let token_account = test.create_token_account(&alice.pubkey()).await;
+    test.claim(&alice, &token_account, AMOUNT, vec![bob_leaf]).await.unwrap();
+    withdraw_claim_fees(&mut test, &owner).await.unwrap();
+
+    // A raised fee applies to later claims
+    set_claim_fee(&mut test, &owner, 2 * CLAIM_FEE).await.unwrap();
+    let token_account = test.create_token_account(&bob.pubkey()).await;
+    test.claim(&bob, &token_account, AMOUNT, vec![alice_leaf]).await.unwrap();
+    assert_eq!(lamports(&mut test, &distributor).await, rent + 2 * CLAIM_FEE);
+
+    let balance = lamports(&mut test, &owner.pubkey()).await;
+    withdraw_claim_fees(&mut test, &owner).await.unwrap();
+    assert_eq!(lamports(&mut test, &owner.pubkey()).await, balance + 2 * CLAIM_FEE - 5_000);
+    assert_eq!(lamports(&mut test, &distributor).await, rent);
+    let state = test.distributor().await;
+    assert_eq!((state.total_fees_collected, state.total_fees_withdrawn), (3 * CLAIM_FEE, 3 * CLAIM_FEE));
+}
@@ -0,0 +1,96 @@
+//! Phased distributions claimable only inside their claim windows
+
+mod common;
+
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use common::*;
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+use token_distributor::error::TokenDistributorError;
+use token_distributor::state::ClaimWindow;
+use token_distributor::utils::verify::hash_leaf;
+use token_distributor::{accounts, instruction, ID};
+
+const DURATION: i64 = 7 * DAY;
+const AMOUNT: u64 = 1_000_000;
+
+/// Sets the claim windows, `(start, end)` offsets from genesis, signed by the operator
+async fn set_claim_windows(test: &mut TestDistributor, windows: &[(i64, i64)]) -> Result<u64, BanksClientError> {
+    let claim_windows = windows
+        .iter()
+        .map(|(start, end)| ClaimWindow { start: GENESIS + start, end: GENESIS + end })
+        .collect();
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::SetClaimWindows {
+            distributor: test.distributor,
+            operator: test.owner.pubkey(),
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::SetClaimWindows { claim_windows }.data(),
+    };
+    test.send(&[ix], &[]).await
+}
+
+/// Claims inside either window succeed, claims in the pause between them fail
+#[tokio::test]
+async fn test_claim_windows() {
+    let mut test = TestDistributor::new(false, AMOUNT, DURATION, None).await;
+    let claimant = Keypair::new();
+    test.set_merkle_root(hash_leaf(&test.distributor, &claimant.pubkey(), AMOUNT)).await.unwrap();
+    test.set_time(GENESIS + DAY).await.unwrap();
+    let token_account = test.create_token_account(&claimant.pubkey()).await;
+
+    // Windows must be sorted, non-overlapping and non-empty
+    for invalid in [vec![(2 * DAY, DAY)], vec![(DAY, 3 * DAY), (3 * DAY, 4 * DAY)], vec![(3 * DAY, 4 * DAY), (DAY, 2 * DAY)]] {
+        assert_error(set_claim_windows(&mut test, &invalid).await, TokenDistributorError::InvalidClaimWindows);
+    }
+    assert_error(
+        set_claim_windows(&mut test, &[(DAY, DAY + 1), (DAY + 2, DAY + 3), (DAY + 4, DAY + 5), (DAY + 6, DAY + 7), (DAY + 8, DAY + 9)]).await,
+        TokenDistributorError::TooManyClaimWindows,
+    );
+
+    // Phase 1 for early users, a pause, then phase 2 for everyone
+    set_claim_windows(&mut test, &[(DAY, 2 * DAY), (3 * DAY, 5 * DAY)]).await.unwrap();
+    let distributor = test.distributor().await;
+    assert!(distributor.is_phased());
+    assert_eq!(distributor.claim_windows[1], ClaimWindow { start: GENESIS + 3 * DAY, end: GENESIS + 5 * DAY });
+
+    test.warp_to(GENESIS + DAY).await;
+    test.claim_partial(&claimant, &token_account, AMOUNT, vec![], Some(AMOUNT / 2)).await.unwrap();
+    assert_eq!(test.token_balance(&token_account).await, AMOUNT / 2);
+
+    // Windows are locked once the distribution started
+    assert_error(set_claim_windows(&mut test, &[]).await, TokenDistributorError::DistributionAlreadyStarted);
+
+    test.warp_to(GENESIS + 2 * DAY + 1).await;
+    assert_error(
+        test.claim(&claimant, &token_account, AMOUNT, vec![]).await,
+        TokenDistributorError::OutsideClaimWindow,
+    );
+
+    test.warp_to(GENESIS + 3 * DAY).await;
+    test.claim(&claimant, &token_account, AMOUNT, vec![]).await.unwrap();
+    assert_eq!(test.token_balance(&token_account).await, AMOUNT);
+}
+
+/// Clearing the windows restores claims over the whole distribution period
+#[tokio::test]
+async fn test_clear_claim_windows() {
+    let mut test = TestDistributor::new(false, AMOUNT, DURATION, None).await;
+    let claimant = Keypair::new();
+    test.set_merkle_root(hash_leaf(&test.distributor, &claimant.pubkey(), AMOUNT)).await.unwrap();
+    test.set_time(GENESIS + DAY).await.unwrap();
+    let token_account = test.create_token_account(&claimant.pubkey()).await;
+
+    set_claim_windows(&mut test, &[(3 * DAY, 5 * DAY)]).await.unwrap();
+    set_claim_windows(&mut test, &[]).await.unwrap();
+    assert!(!test.distributor().await.is_phased());
+
+    test.warp_to(GENESIS + DAY).await;
+    test.claim(&claimant, &token_account, AMOUNT, vec![]).await.unwrap();
+    assert_eq!(test.token_balance(&token_account).await, AMOUNT);
+}
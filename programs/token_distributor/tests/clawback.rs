@@ -0,0 +1,102 @@
+//! Clawing back unclaimed tokens to the clawback receiver
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
+use common::*;
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+use token_distributor::constants::{CLAWBACK_GRACE_PERIOD, OWNER_NONCE_SEED};
+use token_distributor::error::TokenDistributorError;
+use token_distributor::state::NonceState;
+use token_distributor::utils::verify::hash_leaf;
+use token_distributor::{accounts, instruction, ID};
+
+const DURATION: i64 = 7 * DAY;
+const AMOUNT: u64 = 1_000_000;
+
+fn owner_nonce(test: &TestDistributor) -> Pubkey {
+    pda(&[OWNER_NONCE_SEED.as_bytes(), test.owner.pubkey().as_ref()])
+}
+
+/// Claws the vault back into `clawback_token_account`
+async fn clawback(test: &mut TestDistributor, clawback_token_account: &Pubkey) -> Result<u64, BanksClientError> {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::ClawbackVault {
+            distributor: test.distributor,
+            token_vault: test.vault,
+            clawback_token_account: *clawback_token_account,
+            token_mint: test.mint,
+            owner: test.owner.pubkey(),
+            owner_nonce: owner_nonce(test),
+            token_program: test.token_program,
+            registry: None,
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::Clawback {}.data(),
+    };
+    test.send(&[ix], &[]).await
+}
+
+async fn active_distributors(test: &mut TestDistributor) -> u64 {
+    let address = owner_nonce(test);
+    let data = test.account(&address).await.unwrap().data;
+    NonceState::try_deserialize(&mut data.as_slice()).unwrap().active_distributors as u64
+}
+
+/// Anyone claws back what is left once the grace period after the end has passed, closing the distributor
+#[tokio::test]
+async fn test_clawback() {
+    let mut test = TestDistributor::new(false, AMOUNT, DURATION, None).await;
+    let receiver = Pubkey::new_unique();
+    test.use_distributor(2, 2 * AMOUNT, DURATION, Some(receiver)).await;
+    let (distributor, vault) = (test.distributor, test.vault);
+    let receiver_account = test.create_token_account(&receiver).await;
+    let claimant = Keypair::new();
+    test.set_merkle_root(hash_leaf(&distributor, &claimant.pubkey(), AMOUNT)).await.unwrap();
+    let start_time = GENESIS + DAY;
+    test.set_time(start_time).await.unwrap();
+    test.warp_to(start_time).await;
+    let claimant_account = test.create_token_account(&claimant.pubkey()).await;
+    test.claim(&claimant, &claimant_account, AMOUNT, vec![]).await.unwrap();
+    let active = active_distributors(&mut test).await;
+
+    // Ended, but the grace period is still running
+    let clawback_time = start_time + DURATION + CLAWBACK_GRACE_PERIOD;
+    test.warp_to(start_time + DURATION + 1).await;
+    assert_error(clawback(&mut test, &receiver_account).await, TokenDistributorError::ClawbackGracePeriodActive);
+    test.warp_to(clawback_time).await;
+    assert_error(clawback(&mut test, &receiver_account).await, TokenDistributorError::ClawbackGracePeriodActive);
+
+    // After it the unclaimed rest goes to the receiver and the distributor is closed
+    test.warp_to(clawback_time + 1).await;
+    clawback(&mut test, &receiver_account).await.unwrap();
+    assert_eq!(test.token_balance(&receiver_account).await, AMOUNT);
+    assert!(test.account(&vault).await.is_none());
+    assert!(test.account(&distributor).await.is_none());
+    assert_eq!(active_distributors(&mut test).await, active - 1);
+}
+
+/// A distributor whose time was never set has not ended, however late it is
+#[tokio::test]
+async fn test_clawback_unscheduled() {
+    let mut test = TestDistributor::new(false, AMOUNT, DURATION, None).await;
+    let receiver = Pubkey::new_unique();
+    test.use_distributor(2, AMOUNT, DURATION, Some(receiver)).await;
+    let (distributor, vault) = (test.distributor, test.vault);
+    let receiver_account = test.create_token_account(&receiver).await;
+
+    test.warp_to(GENESIS + DURATION + CLAWBACK_GRACE_PERIOD + DAY).await;
+    assert_error(clawback(&mut test, &receiver_account).await, TokenDistributorError::StartTimeNotSet);
+    assert_eq!(test.token_balance(&vault).await, AMOUNT);
+    assert!(test.account(&distributor).await.is_some());
+
+    // The owner keeps control of the deposit
+    test.withdraw_partial(AMOUNT).await.unwrap();
+    assert_eq!(test.token_balance(&receiver_account).await, 0);
+}
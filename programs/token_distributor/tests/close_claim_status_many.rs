@@ -0,0 +1,128 @@
+//! Closing a claimant's claim statuses across distributors in one transaction
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::{system_instruction, system_program};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use common::*;
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+use token_distributor::error::TokenDistributorError;
+use token_distributor::utils::verify::hash_leaf;
+use token_distributor::{accounts, instruction, ID};
+
+const DURATION: i64 = 7 * DAY;
+const AMOUNT: u64 = 1_000_000;
+
+/// Closes the claimant's claim statuses given as (claim_status, distributor, rent_payer) triples
+async fn close_claim_status_many(
+    test: &mut TestDistributor,
+    claimant: &Keypair,
+    triples: &[(Pubkey, Pubkey, Pubkey)],
+) -> Result<u64, BanksClientError> {
+    let mut accounts = accounts::CloseClaimStatusMany {
+        claimant: claimant.pubkey(),
+        event_authority: event_authority(),
+        program: ID,
+    }
+    .to_account_metas(None);
+    for (claim_status, distributor, rent_payer) in triples {
+        accounts.push(AccountMeta::new(*claim_status, false));
+        accounts.push(AccountMeta::new_readonly(*distributor, false));
+        accounts.push(AccountMeta::new(*rent_payer, false));
+    }
+    let ix = Instruction { program_id: ID, accounts, data: instruction::CloseClaimStatusMany {}.data() };
+    test.send(&[ix], &[claimant]).await
+}
+
+/// Freezes the claimant's claim, the claimant paying for the new claim status
+async fn freeze_my_claim(test: &mut TestDistributor, claimant: &Keypair) {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::FreezeMyClaim {
+            distributor: test.distributor,
+            claim_status: claim_status_address(&test.distributor, &claimant.pubkey()),
+            claimant: claimant.pubkey(),
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::FreezeMyClaim {}.data(),
+    };
+    test.send(&[ix], &[claimant]).await.unwrap();
+}
+
+async fn lamports(test: &mut TestDistributor, address: &Pubkey) -> u64 {
+    test.account(address).await.map_or(0, |account| account.lamports)
+}
+
+/// One transaction closes claim statuses of several ended distributors, each refunding its own rent payer
+#[tokio::test]
+async fn test_close_claim_status_many() {
+    let mut test = TestDistributor::new(false, AMOUNT, DURATION, None).await;
+    let claimant = Keypair::new();
+    let fund = system_instruction::transfer(&test.owner.pubkey(), &claimant.pubkey(), 1_000_000_000);
+    test.send(&[fund], &[]).await.unwrap();
+    let start_time = GENESIS + DAY;
+
+    // The owner pays for the claim status of the first distributor's claim
+    let claimed = (test.distributor, test.vault);
+    test.set_merkle_root(hash_leaf(&claimed.0, &claimant.pubkey(), AMOUNT)).await.unwrap();
+    test.set_time(start_time).await.unwrap();
+    test.warp_to(start_time).await;
+    let token_account = test.create_token_account(&claimant.pubkey()).await;
+    test.claim(&claimant, &token_account, AMOUNT, vec![]).await.unwrap();
+
+    // The claimant pays for the one of a second distributor, a third is never scheduled
+    test.use_distributor(2, AMOUNT, DURATION, None).await;
+    let frozen = test.distributor;
+    freeze_my_claim(&mut test, &claimant).await;
+    test.set_time(start_time + DAY).await.unwrap();
+    test.use_distributor(3, AMOUNT, DURATION, None).await;
+    let unscheduled = test.distributor;
+    freeze_my_claim(&mut test, &claimant).await;
+
+    let owner = test.owner.pubkey();
+    let claimed_triple = (claim_status_address(&claimed.0, &claimant.pubkey()), claimed.0, owner);
+    let frozen_triple = (claim_status_address(&frozen, &claimant.pubkey()), frozen, claimant.pubkey());
+    let unscheduled_triple = (claim_status_address(&unscheduled, &claimant.pubkey()), unscheduled, claimant.pubkey());
+
+    // Every distribution must have ended, the first one alone is not enough
+    test.warp_to(start_time + DURATION + 1).await;
+    assert_error(close_claim_status_many(&mut test, &claimant, &[]).await, TokenDistributorError::InvalidBatchSize);
+    assert_error(
+        close_claim_status_many(&mut test, &claimant, &[claimed_triple, frozen_triple]).await,
+        TokenDistributorError::DistributionNotEnded,
+    );
+    test.warp_to(start_time + DAY + DURATION + 1).await;
+    assert_error(
+        close_claim_status_many(&mut test, &claimant, &[claimed_triple, unscheduled_triple]).await,
+        TokenDistributorError::DistributionNotEnded,
+    );
+
+    // Rent only goes back to the recorded payer, and each claim status must be the claimant's PDA of its distributor
+    assert_error(
+        close_claim_status_many(&mut test, &claimant, &[(claimed_triple.0, claimed.0, claimant.pubkey())]).await,
+        TokenDistributorError::RentPayerMismatch,
+    );
+    assert_error(
+        close_claim_status_many(&mut test, &claimant, &[(claimed_triple.0, frozen, owner)]).await,
+        TokenDistributorError::InvalidBatchAccounts,
+    );
+    let stranger_status = claim_status_address(&claimed.0, &Pubkey::new_unique());
+    assert_error(
+        close_claim_status_many(&mut test, &claimant, &[(stranger_status, claimed.0, owner)]).await,
+        TokenDistributorError::InvalidBatchAccounts,
+    );
+
+    let frozen_rent = lamports(&mut test, &frozen_triple.0).await;
+    let balance = lamports(&mut test, &claimant.pubkey()).await;
+    close_claim_status_many(&mut test, &claimant, &[claimed_triple, frozen_triple]).await.unwrap();
+    assert!(test.account(&claimed_triple.0).await.is_none());
+    assert!(test.account(&frozen_triple.0).await.is_none());
+    assert_eq!(lamports(&mut test, &claimant.pubkey()).await, balance + frozen_rent);
+    assert!(test.account(&unscheduled_triple.0).await.is_some());
+}
@@ -0,0 +1,98 @@
+//! Closing the emptied vault while keeping the distributor open
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
+use common::*;
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+use token_distributor::constants::OWNER_NONCE_SEED;
+use token_distributor::error::TokenDistributorError;
+use token_distributor::state::NonceState;
+use token_distributor::utils::verify::hash_leaf;
+use token_distributor::{accounts, instruction, ID};
+
+const DURATION: i64 = 7 * DAY;
+const AMOUNT: u64 = 1_000_000;
+
+fn owner_nonce(test: &TestDistributor) -> Pubkey {
+    pda(&[OWNER_NONCE_SEED.as_bytes(), test.owner.pubkey().as_ref()])
+}
+
+/// Closes the vault of the distributor, signed by the owner
+async fn close_vault(test: &mut TestDistributor) -> Result<u64, BanksClientError> {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::CloseVault {
+            distributor: test.distributor,
+            token_vault: test.vault,
+            token_mint: test.mint,
+            owner: test.owner.pubkey(),
+            owner_nonce: owner_nonce(test),
+            token_program: test.token_program,
+            event_authority: event_authority(),
+            program: ID,
+            registry: None,
+        }
+        .to_account_metas(None),
+        data: instruction::CloseVault {}.data(),
+    };
+    test.send(&[ix], &[]).await
+}
+
+/// Closes the claimant's claim status, returning its rent to whoever paid it
+async fn close_claim_status(test: &mut TestDistributor, claimant: &Keypair) -> Result<u64, BanksClientError> {
+    let rent_payer = test.claim_status(&claimant.pubkey()).await.unwrap().rent_payer;
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::CloseClaimStatus {
+            claim_status: claim_status_address(&test.distributor, &claimant.pubkey()),
+            claimant: claimant.pubkey(),
+            rent_payer,
+            distributor_key: test.distributor,
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::CloseClaimStatus {}.data(),
+    };
+    test.send(&[ix], &[claimant]).await
+}
+
+/// The emptied vault closes once the distribution completed, the distributor stays and still checks the end time
+#[tokio::test]
+async fn test_close_vault() {
+    for token_2022 in [false, true] {
+        let mut test = TestDistributor::new(token_2022, AMOUNT, DURATION, None).await;
+        let claimant = Keypair::new();
+        let leaf = |test: &TestDistributor, max_amount| hash_leaf(&test.distributor, &claimant.pubkey(), max_amount);
+        test.set_merkle_root(leaf(&test, AMOUNT / 2)).await.unwrap();
+        test.set_time(GENESIS + DAY).await.unwrap();
+        test.warp_to(GENESIS + DAY).await;
+        let token_account = test.create_token_account(&claimant.pubkey()).await;
+
+        // The vault only closes once it is empty
+        test.claim(&claimant, &token_account, AMOUNT / 2, vec![]).await.unwrap();
+        assert_error(close_vault(&mut test).await, TokenDistributorError::VaultNotEmpty);
+
+        // The claim emptying it completes the distribution, so it closes before the end
+        test.set_merkle_root(leaf(&test, AMOUNT)).await.unwrap();
+        test.claim(&claimant, &token_account, AMOUNT, vec![]).await.unwrap();
+        close_vault(&mut test).await.unwrap();
+        let (vault, nonce_address) = (test.vault, owner_nonce(&test));
+        assert!(test.account(&vault).await.is_none());
+        let distributor = test.distributor().await;
+        assert!(distributor.is_vault_closed());
+        assert_eq!(distributor.total_claimed, AMOUNT);
+        let nonce_data = test.account(&nonce_address).await.unwrap().data;
+        assert_eq!(NonceState::try_deserialize(&mut nonce_data.as_slice()).unwrap().active_distributors, 0);
+
+        // Claim statuses still wait for the end of the distribution
+        assert_error(close_claim_status(&mut test, &claimant).await, TokenDistributorError::DistributionNotEnded);
+        test.warp_to(GENESIS + DAY + DURATION + 1).await;
+        close_claim_status(&mut test, &claimant).await.unwrap();
+        assert!(test.claim_status(&claimant.pubkey()).await.is_none());
+    }
+}
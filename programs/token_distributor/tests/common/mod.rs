@@ -0,0 +1,445 @@
+//! solana-program-test setup shared by the instruction tests
+//!
+//! The program runs natively, or as its SBF build when `SBF_OUT_DIR` points at
+//! it (`cargo build-sbf --sbf-out-dir target/deploy`). Compute units are only
+//! meaningful for the SBF build, see [`runs_sbf`].
+
+#![allow(dead_code, deprecated)]
+
+use std::collections::HashSet;
+
+use anchor_lang::prelude::{AccountInfo, Clock, Pubkey};
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::solana_program::{system_instruction, system_program, sysvar};
+use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
+use anchor_spl::token_2022::spl_token_2022;
+use anchor_spl::token_2022::spl_token_2022::extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions};
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::account::Account;
+use solana_sdk::instruction::{AccountMeta, Instruction, InstructionError};
+use solana_sdk::signature::{Keypair, Signature, Signer};
+use solana_sdk::transaction::{Transaction, TransactionError};
+use token_distributor::constants::*;
+use token_distributor::error::TokenDistributorError;
+use token_distributor::state::{ClaimStatus, TokenDistributor};
+use token_distributor::{accounts, instruction, ID};
+
+pub mod transfer_hook;
+
+/// Mint decimals used by every test
+pub const DECIMALS: u8 = 6;
+
+/// Clock start of every test (Unix timestamp)
+pub const GENESIS: i64 = 1_700_000_000;
+
+pub const DAY: i64 = 24 * 60 * 60;
+
+/// Nonce of the distributor created by [`TestDistributor::new`] (first distributor of the owner)
+const NONCE: u32 = 1;
+
+/// Whether the program runs as its SBF build rather than natively
+pub fn runs_sbf() -> bool {
+    std::env::var_os("SBF_OUT_DIR").is_some() || std::env::var_os("BPF_OUT_DIR").is_some()
+}
+
+/// Native entrypoint of the program
+fn process_instruction(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    // Anchor's entrypoint ties the account slice to the account lifetimes
+    let accounts = Box::leak(accounts.to_vec().into_boxed_slice());
+    token_distributor::entry(program_id, accounts, data)
+}
+
+/// Custom program error of a failed transaction, if any
+pub fn error_code(error: &BanksClientError) -> Option<u32> {
+    match error {
+        BanksClientError::TransactionError(TransactionError::InstructionError(_, InstructionError::Custom(code)))
+        | BanksClientError::SimulationError {
+            err: TransactionError::InstructionError(_, InstructionError::Custom(code)),
+            ..
+        } => Some(*code),
+        _ => None,
+    }
+}
+
+/// Asserts a transaction failed with the given program error
+#[track_caller]
+pub fn assert_error<T: std::fmt::Debug>(result: Result<T, BanksClientError>, error: TokenDistributorError) {
+    match result {
+        Ok(value) => panic!("expected {:?}, transaction succeeded: {:?}", error, value),
+        Err(e) => assert_eq!(error_code(&e), Some(u32::from(error)), "expected {:?}, got {:?}", error, e),
+    }
+}
+
+/// PDA of the program
+pub fn pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &ID).0
+}
+
+pub fn event_authority() -> Pubkey {
+    pda(&[b"__event_authority"])
+}
+
+pub fn config() -> Pubkey {
+    pda(&[CONFIG_SEED.as_bytes()])
+}
+
+pub fn claim_status_address(distributor: &Pubkey, claimant: &Pubkey) -> Pubkey {
+    pda(&[CLAIM_SEED.as_bytes(), distributor.as_ref(), claimant.as_ref()])
+}
+
+/// A distributor holding a deposit, on a fresh bank
+pub struct TestDistributor {
+    pub context: ProgramTestContext,
+    /// Owner and operator of the distributor
+    pub owner: Keypair,
+    pub token_program: Pubkey,
+    pub mint: Pubkey,
+    pub distributor: Pubkey,
+    pub vault: Pubkey,
+    /// Owner's token account that funded the distributor
+    pub owner_token_account: Pubkey,
+    /// Shared vault the distributor draws from, passed to claims when set
+    pub shared_vault: Option<Pubkey>,
+    /// Accounts appended to claims and distributor creation, such as transfer hook accounts
+    pub remaining_accounts: Vec<AccountMeta>,
+    /// Signatures of the transactions sent so far, to avoid resending one
+    sent: HashSet<Signature>,
+}
+
+impl TestDistributor {
+    /// Sets up a mint and a distributor holding `deposit` tokens, open for `duration` seconds
+    ///
+    /// Time and root are not set yet.
+    pub async fn new(token_2022: bool, deposit: u64, duration: i64, tree_version: Option<u8>) -> Self {
+        let mut program_test = ProgramTest::default();
+        // Transaction errors carry what a failing test needs, RUST_LOG still overrides
+        solana_logger::setup_with_default("error");
+        program_test.add_program("token_distributor", ID, processor!(process_instruction));
+        program_test.add_program(
+            "test_transfer_hook",
+            transfer_hook::TRANSFER_HOOK_PROGRAM_ID,
+            processor!(transfer_hook::process_transfer_hook),
+        );
+        let owner = Keypair::new();
+        program_test.add_account(owner.pubkey(), Account::new(1_000_000_000_000, 0, &system_program::ID));
+        let context = program_test.start_with_context().await;
+        let token_program = if token_2022 { spl_token_2022::ID } else { anchor_spl::token::ID };
+
+        let mut test = TestDistributor {
+            context,
+            owner,
+            token_program,
+            mint: Pubkey::default(),
+            distributor: Pubkey::default(),
+            vault: Pubkey::default(),
+            owner_token_account: Pubkey::default(),
+            shared_vault: None,
+            remaining_accounts: Vec::new(),
+            sent: HashSet::new(),
+        };
+        test.warp_to(GENESIS).await;
+
+        // Mint the deposit to the owner and fund the distributor with it
+        let mint = Keypair::new();
+        let owner_key = test.owner.pubkey();
+        let rent = test.minimum_balance(spl_token_2022::state::Mint::LEN).await;
+        test.send(
+            &[
+                system_instruction::create_account(
+                    &owner_key,
+                    &mint.pubkey(),
+                    rent,
+                    spl_token_2022::state::Mint::LEN as u64,
+                    &token_program,
+                ),
+                spl_token_2022::instruction::initialize_mint2(&token_program, &mint.pubkey(), &owner_key, Some(&owner_key), DECIMALS)
+                    .unwrap(),
+            ],
+            &[&mint],
+        )
+        .await
+        .unwrap();
+        test.mint = mint.pubkey();
+        test.owner_token_account = test.create_token_account(&owner_key).await;
+        test.send(
+            &[spl_token_2022::instruction::mint_to(&token_program, &test.mint, &test.owner_token_account, &owner_key, &[], deposit)
+                .unwrap()],
+            &[],
+        )
+        .await
+        .unwrap();
+
+        test.distributor = pda(&[DISTRIBUTOR_SEED.as_bytes(), test.mint.as_ref(), owner_key.as_ref(), &NONCE.to_le_bytes()]);
+        test.vault = pda(&[VAULT_SEED.as_bytes(), test.distributor.as_ref()]);
+        let create = Instruction {
+            program_id: ID,
+            accounts: accounts::CreateDistributor {
+                owner_nonce: pda(&[OWNER_NONCE_SEED.as_bytes(), owner_key.as_ref()]),
+                distributor: test.distributor,
+                token_vault: test.vault,
+                token_mint: test.mint,
+                owner_token_account: test.owner_token_account,
+                owner: owner_key,
+                operator: owner_key,
+                system_program: system_program::ID,
+                token_program,
+                rent: sysvar::rent::ID,
+                config: config(),
+                event_authority: event_authority(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: instruction::CreateDistributor {
+                initial_total_amount: deposit,
+                duration: Some(duration),
+                clawback_receiver: None,
+                tree_version,
+                withdraw_delay: None,
+                activation_type: None,
+            }
+            .data(),
+        };
+        test.send(&[create], &[]).await.unwrap();
+        test
+    }
+
+    /// Switches the test to another distributor of the owner, created with `nonce` and funded with `deposit` new tokens
+    ///
+    /// Unclaimed tokens of the distributor go to `clawback_receiver` when given. Time and root are not set yet.
+    pub async fn use_distributor(&mut self, nonce: u32, deposit: u64, duration: i64, clawback_receiver: Option<Pubkey>) {
+        let owner = self.owner.pubkey();
+        let distributor = pda(&[DISTRIBUTOR_SEED.as_bytes(), self.mint.as_ref(), owner.as_ref(), &nonce.to_le_bytes()]);
+        let vault = pda(&[VAULT_SEED.as_bytes(), distributor.as_ref()]);
+        let mint_to =
+            spl_token_2022::instruction::mint_to(&self.token_program, &self.mint, &self.owner_token_account, &owner, &[], deposit)
+                .unwrap();
+        let mut create = Instruction {
+            program_id: ID,
+            accounts: accounts::CreateDistributorWithNonce {
+                owner_nonce: pda(&[OWNER_NONCE_SEED.as_bytes(), owner.as_ref()]),
+                distributor,
+                token_vault: vault,
+                token_mint: self.mint,
+                owner_token_account: self.owner_token_account,
+                owner,
+                operator: owner,
+                system_program: system_program::ID,
+                token_program: self.token_program,
+                rent: sysvar::rent::ID,
+                config: config(),
+                event_authority: event_authority(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: instruction::CreateDistributorWithNonce {
+                nonce,
+                initial_total_amount: deposit,
+                duration: Some(duration),
+                clawback_receiver,
+                tree_version: None,
+                withdraw_delay: None,
+                activation_type: None,
+            }
+            .data(),
+        };
+        create.accounts.extend_from_slice(&self.remaining_accounts);
+        self.send(&[mint_to, create], &[]).await.unwrap();
+        self.distributor = distributor;
+        self.vault = vault;
+    }
+
+    /// Signs with the owner and the given signers and processes the instructions
+    ///
+    /// Returns the compute units consumed by the transaction.
+    pub async fn send(&mut self, instructions: &[Instruction], signers: &[&Keypair]) -> Result<u64, BanksClientError> {
+        let mut all_signers = vec![&self.owner];
+        all_signers.extend_from_slice(signers);
+        let mut tx = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.owner.pubkey()),
+            &all_signers,
+            self.context.last_blockhash,
+        );
+        // An identical transaction would be rejected as already processed
+        if !self.sent.insert(tx.signatures[0]) {
+            let blockhash = self.context.get_new_latest_blockhash().await.unwrap();
+            tx.sign(&all_signers, blockhash);
+            self.sent.insert(tx.signatures[0]);
+        }
+        let result = self.context.banks_client.process_transaction_with_metadata(tx).await?;
+        result.result?;
+        Ok(result.metadata.map_or(0, |metadata| metadata.compute_units_consumed))
+    }
+
+    /// Moves the clock to the given Unix timestamp
+    pub async fn warp_to(&mut self, unix_timestamp: i64) {
+        let mut clock: Clock = self.context.banks_client.get_sysvar().await.unwrap();
+        clock.unix_timestamp = unix_timestamp;
+        self.context.set_sysvar(&clock);
+    }
+
+    pub async fn minimum_balance(&mut self, len: usize) -> u64 {
+        self.context.banks_client.get_rent().await.unwrap().minimum_balance(len)
+    }
+
+    /// Creates a token account of the mint owned by `authority`, at a fresh address
+    ///
+    /// The account has room for the extensions the mint requires, such as TransferHookAccount.
+    pub async fn create_token_account(&mut self, authority: &Pubkey) -> Pubkey {
+        let account = Keypair::new();
+        let mint = self.mint;
+        let mint_data = self.account(&mint).await.expect("mint exists").data;
+        let mint_extensions = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)
+            .unwrap()
+            .get_extension_types()
+            .unwrap();
+        let len = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Account>(
+            &ExtensionType::get_required_init_account_extensions(&mint_extensions),
+        )
+        .unwrap();
+        let rent = self.minimum_balance(len).await;
+        let instructions = [
+            system_instruction::create_account(&self.owner.pubkey(), &account.pubkey(), rent, len as u64, &self.token_program),
+            spl_token_2022::instruction::initialize_account3(&self.token_program, &account.pubkey(), &self.mint, authority).unwrap(),
+        ];
+        self.send(&instructions, &[&account]).await.unwrap();
+        account.pubkey()
+    }
+
+    pub async fn set_time(&mut self, start_time: i64) -> Result<u64, BanksClientError> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetTime {
+                distributor: self.distributor,
+                operator: self.owner.pubkey(),
+                event_authority: event_authority(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: instruction::SetTime { start_time }.data(),
+        };
+        self.send(&[ix], &[]).await
+    }
+
+    pub async fn set_merkle_root(&mut self, merkle_root: [u8; 32]) -> Result<u64, BanksClientError> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetMerkleRoot {
+                distributor: self.distributor,
+                operator: self.owner.pubkey(),
+                event_authority: event_authority(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: instruction::SetMerkleRoot { merkle_root }.data(),
+        };
+        self.send(&[ix], &[]).await
+    }
+
+    /// Claims `max_amount` with `proof` for the claimant into `token_account`
+    ///
+    /// The owner pays the fees and rent, the claimant signs.
+    pub async fn claim(
+        &mut self,
+        claimant: &Keypair,
+        token_account: &Pubkey,
+        max_amount: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<u64, BanksClientError> {
+        self.claim_partial(claimant, token_account, max_amount, proof, None).await
+    }
+
+    /// Claims `amount` (all that is pending if `None`) of `max_amount` with `proof`, as [`Self::claim`]
+    pub async fn claim_partial(
+        &mut self,
+        claimant: &Keypair,
+        token_account: &Pubkey,
+        max_amount: u64,
+        proof: Vec<[u8; 32]>,
+        amount: Option<u64>,
+    ) -> Result<u64, BanksClientError> {
+        let mut accounts = accounts::Claim {
+            distributor: self.distributor,
+            claim_status: claim_status_address(&self.distributor, &claimant.pubkey()),
+            token_vault: self.vault,
+            shared_vault: self.shared_vault,
+            claimant_token_account: *token_account,
+            token_mint: self.mint,
+            claimant: claimant.pubkey(),
+            delegate: None,
+            payer: self.owner.pubkey(),
+            system_program: system_program::ID,
+            token_program: self.token_program,
+            memo_program: None,
+            #[cfg(feature = "protocol-fee")]
+            protocol_fee_account: None,
+            #[cfg(feature = "claim-gates")]
+            gate_token_account: None,
+            #[cfg(feature = "claim-gates")]
+            attestation: None,
+            config: config(),
+            #[cfg(feature = "claim-log")]
+            claim_log: None,
+            instructions_sysvar: None,
+            #[cfg(feature = "claim-escrow")]
+            claim_escrow: None,
+            #[cfg(feature = "claim-escrow")]
+            escrow_vault: None,
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None);
+        // The claimant is only optionally a signer, as claims may be signed by a delegate
+        accounts.iter_mut().find(|meta| meta.pubkey == claimant.pubkey()).unwrap().is_signer = true;
+        accounts.extend_from_slice(&self.remaining_accounts);
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: instruction::Claim { max_amount, proof, amount, unlock_time: None, cosign_expiry: None }.data(),
+        };
+        self.send(&[ix], &[claimant]).await
+    }
+
+    /// Withdraws `amount` from the vault back to the owner, keeping the distributor open
+    pub async fn withdraw_partial(&mut self, amount: u64) -> Result<u64, BanksClientError> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::WithdrawPartial {
+                distributor: self.distributor,
+                token_vault: self.vault,
+                owner_token_account: self.owner_token_account,
+                token_mint: self.mint,
+                owner: self.owner.pubkey(),
+                token_program: self.token_program,
+                event_authority: event_authority(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: instruction::WithdrawPartial { amount }.data(),
+        };
+        self.send(&[ix], &[]).await
+    }
+
+    pub async fn account(&mut self, address: &Pubkey) -> Option<Account> {
+        self.context.banks_client.get_account(*address).await.unwrap()
+    }
+
+    pub async fn distributor(&mut self) -> TokenDistributor {
+        let address = self.distributor;
+        let data = self.account(&address).await.expect("distributor exists").data;
+        TokenDistributor::try_deserialize(&mut data.as_slice()).unwrap()
+    }
+
+    pub async fn claim_status(&mut self, claimant: &Pubkey) -> Option<ClaimStatus> {
+        let address = claim_status_address(&self.distributor, claimant);
+        let data = self.account(&address).await?.data;
+        Some(ClaimStatus::try_deserialize(&mut data.as_slice()).unwrap())
+    }
+
+    /// Token balance of an account of either token program
+    pub async fn token_balance(&mut self, account: &Pubkey) -> u64 {
+        let data = self.account(account).await.expect("token account exists").data;
+        StateWithExtensions::<spl_token_2022::state::Account>::unpack(&data).unwrap().base.amount
+    }
+}
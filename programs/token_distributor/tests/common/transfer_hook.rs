@@ -0,0 +1,60 @@
+//! Transfer hook program counting the transfers of the mints using it
+//!
+//! Its extra account metas list one account, a counter PDA derived from the
+//! mint, so transfers only go through when the caller resolved and passed it.
+
+use anchor_lang::prelude::{AccountInfo, Pubkey};
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::solana_program::program_error::ProgramError;
+use spl_tlv_account_resolution::account::ExtraAccountMeta;
+use spl_tlv_account_resolution::seeds::Seed;
+use spl_tlv_account_resolution::state::ExtraAccountMetaList;
+use spl_transfer_hook_interface::instruction::{ExecuteInstruction, TransferHookInstruction};
+
+pub const TRANSFER_HOOK_PROGRAM_ID: Pubkey = Pubkey::new_from_array([7; 32]);
+
+const COUNTER_SEED: &[u8] = b"counter";
+
+/// Length of the counter account: transfer count and total amount transferred
+pub const COUNTER_LEN: usize = 16;
+
+/// Counter PDA of the mint, the only extra account of its transfers
+pub fn counter_address(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[COUNTER_SEED, mint.as_ref()], &TRANSFER_HOOK_PROGRAM_ID).0
+}
+
+/// Data of the mint's extra account metas account, to be stored at
+/// `spl_transfer_hook_interface::get_extra_account_metas_address`
+pub fn extra_account_metas_data() -> Vec<u8> {
+    let counter = ExtraAccountMeta::new_with_seeds(
+        &[Seed::Literal { bytes: COUNTER_SEED.to_vec() }, Seed::AccountKey { index: 1 }],
+        false,
+        true,
+    )
+    .unwrap();
+    let mut data = vec![0; ExtraAccountMetaList::size_of(1).unwrap()];
+    ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, &[counter]).unwrap();
+    data
+}
+
+/// Native entrypoint of the hook, handling Execute only
+pub fn process_transfer_hook(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let TransferHookInstruction::Execute { amount } = TransferHookInstruction::unpack(data)? else {
+        return Err(ProgramError::InvalidInstructionData);
+    };
+    // source, mint, destination, authority, extra account metas, then the extra accounts
+    let extra_account_metas = accounts.get(4).ok_or(ProgramError::NotEnoughAccountKeys)?;
+    ExtraAccountMetaList::check_account_infos::<ExecuteInstruction>(
+        accounts,
+        data,
+        program_id,
+        &extra_account_metas.try_borrow_data()?,
+    )?;
+
+    let mut counter = accounts[5].try_borrow_mut_data()?;
+    let count = u64::from_le_bytes(counter[..8].try_into().unwrap()) + 1;
+    let total = u64::from_le_bytes(counter[8..].try_into().unwrap()) + amount;
+    counter[..8].copy_from_slice(&count.to_le_bytes());
+    counter[8..].copy_from_slice(&total.to_le_bytes());
+    Ok(())
+}
@@ -0,0 +1,129 @@
+//! Compute units consumed per instruction, checked against upper bounds
+//!
+//! Only the SBF build is metered, so these tests are ignored by default. Run them
+//! against the compiled program and print the measurements with
+//! `SBF_OUT_DIR=target/deploy cargo test -p token_distributor --test compute_units -- --ignored --nocapture`
+//! after `anchor build`.
+
+mod common;
+
+use common::*;
+use solana_sdk::signature::{Keypair, Signer};
+use token_distributor::constants::{KECCAK_TREE_VERSION, MAX_PROOF_DEPTH, MAX_ROOT_HISTORY, TREE_VERSION};
+use token_distributor::error::TokenDistributorError;
+use token_distributor::utils::verify::{compute_root, compute_root_keccak, hash_leaf, hash_leaf_keccak};
+
+const AMOUNT: u64 = 1_000_000;
+
+/// Upper bounds per instruction, kept in sync with the table in the README
+const SET_MERKLE_ROOT_UNITS: u64 = 30_000;
+const SET_TIME_UNITS: u64 = 30_000;
+/// First claim of a claimant with a proof of MAX_PROOF_DEPTH levels, including ClaimStatus creation
+const CLAIM_UNITS: u64 = 80_000;
+const WITHDRAW_PARTIAL_UNITS: u64 = 40_000;
+
+/// Deterministic sibling hashes for proofs
+fn sibling(level: usize) -> [u8; 32] {
+    let mut hash = [0u8; 32];
+    hash[..8].copy_from_slice(&(level as u64 + 1).to_le_bytes());
+    hash
+}
+
+/// Proof of `depth` siblings above the claimant's leaf, and the root it leads to
+///
+/// A single path is all a claim reads, so no full tree of 2^depth leaves is built.
+fn single_path(distributor: &TestDistributor, claimant: &Keypair, tree_version: u8, depth: usize) -> ([u8; 32], Vec<[u8; 32]>) {
+    let proof: Vec<[u8; 32]> = (0..depth).map(sibling).collect();
+    let root = if tree_version == KECCAK_TREE_VERSION {
+        compute_root_keccak(&proof, hash_leaf_keccak(&distributor.distributor, &claimant.pubkey(), AMOUNT))
+    } else {
+        compute_root(&proof, hash_leaf(&distributor.distributor, &claimant.pubkey(), AMOUNT))
+    };
+    (root, proof)
+}
+
+#[track_caller]
+fn assert_within(name: &str, units: u64, bound: u64) {
+    println!("  {:<32} {:>6} CU (bound {})", name, units, bound);
+    assert!(units <= bound, "{}: {} CU exceeds the bound of {}", name, units, bound);
+}
+
+/// Measures the operator instructions, a first claim per proof depth for sha256 and keccak256 trees, and withdraw_partial
+#[tokio::test]
+#[ignore = "compute units are only metered for the SBF build, set SBF_OUT_DIR"]
+async fn test_compute_units() {
+    assert!(runs_sbf(), "SBF_OUT_DIR must point at the compiled program");
+    println!("Compute units (SPL Token):");
+    for (name, tree_version) in [("sha256", TREE_VERSION), ("keccak256", KECCAK_TREE_VERSION)] {
+        let mut costs = Vec::new();
+        for depth in [1, 10, 14, 17, 20, MAX_PROOF_DEPTH] {
+            let mut test = TestDistributor::new(false, 2 * AMOUNT, 7 * DAY, Some(tree_version)).await;
+            let claimant = Keypair::new();
+            let (root, proof) = single_path(&test, &claimant, tree_version, depth);
+            let set_root = test.set_merkle_root(root).await.unwrap();
+            let set_time = test.set_time(GENESIS + DAY).await.unwrap();
+            test.warp_to(GENESIS + DAY).await;
+            let token_account = test.create_token_account(&claimant.pubkey()).await;
+            let claim = test.claim(&claimant, &token_account, AMOUNT, proof).await.unwrap();
+            assert_within(&format!("claim, {} depth {}", name, depth), claim, CLAIM_UNITS);
+            costs.push(claim);
+
+            if depth == MAX_PROOF_DEPTH && tree_version == TREE_VERSION {
+                assert_within("set_merkle_root", set_root, SET_MERKLE_ROOT_UNITS);
+                assert_within("set_time", set_time, SET_TIME_UNITS);
+                test.warp_to(GENESIS + 8 * DAY + 1).await;
+                assert_within("withdraw_partial", test.withdraw_partial(1).await.unwrap(), WITHDRAW_PARTIAL_UNITS);
+            }
+        }
+        // Every proof level adds hashing cost
+        assert!(costs.windows(2).all(|pair| pair[0] < pair[1]), "{}: {:?}", name, costs);
+
+        // Proofs beyond the depth limit are rejected before any hashing
+        let mut test = TestDistributor::new(false, AMOUNT, 7 * DAY, Some(tree_version)).await;
+        let claimant = Keypair::new();
+        let (root, proof) = single_path(&test, &claimant, tree_version, MAX_PROOF_DEPTH + 1);
+        test.set_merkle_root(root).await.unwrap();
+        test.set_time(GENESIS + DAY).await.unwrap();
+        test.warp_to(GENESIS + DAY).await;
+        let token_account = test.create_token_account(&claimant.pubkey()).await;
+        assert_error(test.claim(&claimant, &token_account, AMOUNT, proof).await, TokenDistributorError::ProofTooLong);
+    }
+}
+
+/// Compares a claim against the current root with one against the oldest root still accepted
+///
+/// The proof is walked once and the result compared with every known root, so
+/// an old root costs a few comparisons more, not another proof walk per root.
+#[tokio::test]
+#[ignore = "compute units are only metered for the SBF build, set SBF_OUT_DIR"]
+async fn test_history_root_compute_units() {
+    const DEPTH: usize = 20;
+    assert!(runs_sbf(), "SBF_OUT_DIR must point at the compiled program");
+    let mut test = TestDistributor::new(false, 2 * AMOUNT, 7 * DAY, Some(TREE_VERSION)).await;
+    let (old_claimant, claimant) = (Keypair::new(), Keypair::new());
+    let (old_root, old_proof) = single_path(&test, &old_claimant, TREE_VERSION, DEPTH);
+    let (root, proof) = single_path(&test, &claimant, TREE_VERSION, DEPTH);
+
+    // The first claimant's root ends up as the oldest of the MAX_ROOT_HISTORY previous roots
+    test.set_merkle_root(old_root).await.unwrap();
+    test.set_time(GENESIS + DAY).await.unwrap();
+    let mut now = GENESIS + DAY;
+    test.warp_to(now).await;
+    for level in 1..MAX_ROOT_HISTORY {
+        now += 60;
+        test.warp_to(now).await;
+        test.set_merkle_root(sibling(DEPTH + level)).await.unwrap();
+    }
+    now += 60;
+    test.warp_to(now).await;
+    test.set_merkle_root(root).await.unwrap();
+
+    let token_account = test.create_token_account(&claimant.pubkey()).await;
+    let current = test.claim(&claimant, &token_account, AMOUNT, proof).await.unwrap();
+    let old_token_account = test.create_token_account(&old_claimant.pubkey()).await;
+    let oldest = test.claim(&old_claimant, &old_token_account, AMOUNT, old_proof).await.unwrap();
+    println!("Claim cost at depth {} (compute units): current root {}, oldest accepted root {}", DEPTH, current, oldest);
+
+    // Rehashing the proof per root would add about MAX_ROOT_HISTORY * DEPTH hash syscalls (over 7,000 CU)
+    assert!(oldest.abs_diff(current) < 1_000, "current root {} CU, oldest root {} CU", current, oldest);
+}
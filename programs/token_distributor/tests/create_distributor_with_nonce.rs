@@ -0,0 +1,92 @@
+//! Distributors created under a caller-supplied nonce
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::{system_program, sysvar};
+use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
+use anchor_spl::token_2022::spl_token_2022;
+use common::*;
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::Signer;
+use token_distributor::constants::*;
+use token_distributor::error::TokenDistributorError;
+use token_distributor::state::NonceState;
+use token_distributor::{accounts, instruction, ID};
+
+const DURATION: i64 = 7 * DAY;
+const AMOUNT: u64 = 1_000_000;
+
+fn distributor_address(test: &TestDistributor, nonce: u32) -> Pubkey {
+    pda(&[DISTRIBUTOR_SEED.as_bytes(), test.mint.as_ref(), test.owner.pubkey().as_ref(), &nonce.to_le_bytes()])
+}
+
+fn owner_nonce_address(test: &TestDistributor) -> Pubkey {
+    pda(&[OWNER_NONCE_SEED.as_bytes(), test.owner.pubkey().as_ref()])
+}
+
+/// Mints AMOUNT to the owner and creates a distributor with it under `nonce`
+async fn create_with_nonce(test: &mut TestDistributor, nonce: u32) -> Result<u64, BanksClientError> {
+    let owner = test.owner.pubkey();
+    let distributor = distributor_address(test, nonce);
+    let mint_to =
+        spl_token_2022::instruction::mint_to(&test.token_program, &test.mint, &test.owner_token_account, &owner, &[], AMOUNT)
+            .unwrap();
+    let create = Instruction {
+        program_id: ID,
+        accounts: accounts::CreateDistributorWithNonce {
+            owner_nonce: owner_nonce_address(test),
+            distributor,
+            token_vault: pda(&[VAULT_SEED.as_bytes(), distributor.as_ref()]),
+            token_mint: test.mint,
+            owner_token_account: test.owner_token_account,
+            owner,
+            operator: owner,
+            system_program: system_program::ID,
+            token_program: test.token_program,
+            rent: sysvar::rent::ID,
+            config: config(),
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::CreateDistributorWithNonce {
+            nonce,
+            initial_total_amount: AMOUNT,
+            duration: Some(DURATION),
+            clawback_receiver: None,
+            tree_version: None,
+            withdraw_delay: None,
+            activation_type: None,
+        }
+        .data(),
+    };
+    test.send(&[mint_to, create], &[]).await
+}
+
+async fn owner_nonce(test: &mut TestDistributor) -> u32 {
+    let address = owner_nonce_address(test);
+    let data = test.account(&address).await.unwrap().data;
+    NonceState::try_deserialize(&mut data.as_slice()).unwrap().nonce
+}
+
+/// A supplied nonce creates the precomputed addresses and only ever advances the owner's counter
+#[tokio::test]
+async fn test_create_distributor_with_nonce() {
+    // The owner's first distributor takes nonce 1
+    let mut test = TestDistributor::new(false, AMOUNT, DURATION, None).await;
+    assert_error(create_with_nonce(&mut test, 1).await, TokenDistributorError::NonceAlreadyUsed);
+
+    create_with_nonce(&mut test, 10).await.unwrap();
+    let distributor = distributor_address(&test, 10);
+    let vault = pda(&[VAULT_SEED.as_bytes(), distributor.as_ref()]);
+    assert_eq!(test.token_balance(&vault).await, AMOUNT);
+    assert_eq!(owner_nonce(&mut test).await, 10);
+
+    // Nonces below the counter stay unusable, even those that were never taken
+    assert_error(create_with_nonce(&mut test, 5).await, TokenDistributorError::NonceAlreadyUsed);
+    assert_error(create_with_nonce(&mut test, 10).await, TokenDistributorError::NonceAlreadyUsed);
+    create_with_nonce(&mut test, 11).await.unwrap();
+    assert_eq!(owner_nonce(&mut test).await, 11);
+}
@@ -0,0 +1,78 @@
+//! Completion of a distribution by the claim that empties its vault
+
+mod common;
+
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::token_2022::spl_token_2022;
+use common::*;
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+use token_distributor::constants::OWNER_NONCE_SEED;
+use token_distributor::error::TokenDistributorError;
+use token_distributor::utils::verify::hash_leaf;
+use token_distributor::{accounts, instruction, ID};
+
+const DURATION: i64 = 7 * DAY;
+const AMOUNT: u64 = 1_000_000;
+
+/// Withdraws the vault back to the owner and closes the distributor
+async fn withdraw(test: &mut TestDistributor) -> Result<u64, BanksClientError> {
+    let owner = test.owner.pubkey();
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::Withdraw {
+            distributor: test.distributor,
+            token_vault: test.vault,
+            owner_token_account: test.owner_token_account,
+            token_mint: test.mint,
+            owner,
+            owner_nonce: pda(&[OWNER_NONCE_SEED.as_bytes(), owner.as_ref()]),
+            token_program: test.token_program,
+            event_authority: event_authority(),
+            program: ID,
+            registry: None,
+        }
+        .to_account_metas(None),
+        data: instruction::Withdraw {}.data(),
+    };
+    test.send(&[ix], &[]).await
+}
+
+/// The claim emptying the vault completes the distribution, which can then be withdrawn before its end
+#[tokio::test]
+async fn test_distribution_completed() {
+    let mut test = TestDistributor::new(false, AMOUNT, DURATION, None).await;
+    let claimant = Keypair::new();
+    let leaf = |test: &TestDistributor, max_amount| hash_leaf(&test.distributor, &claimant.pubkey(), max_amount);
+    test.set_merkle_root(leaf(&test, AMOUNT / 2)).await.unwrap();
+    test.set_time(GENESIS + DAY).await.unwrap();
+    test.warp_to(GENESIS + DAY).await;
+    let token_account = test.create_token_account(&claimant.pubkey()).await;
+
+    // A claim leaving tokens in the vault does not complete the distribution
+    test.claim(&claimant, &token_account, AMOUNT / 2, vec![]).await.unwrap();
+    assert_eq!(test.distributor().await.completed_at, 0);
+    assert_error(withdraw(&mut test).await, TokenDistributorError::DistributionNotEnded);
+
+    // The claim paying out the rest of the vault does
+    test.set_merkle_root(leaf(&test, AMOUNT)).await.unwrap();
+    test.claim(&claimant, &token_account, AMOUNT, vec![]).await.unwrap();
+    let distributor = test.distributor().await;
+    assert_eq!(distributor.completed_at, GENESIS + DAY);
+    assert_eq!((distributor.total_claimed, distributor.claims_count), (AMOUNT, 2));
+
+    // A top-up restores the usual timing until the vault is emptied again
+    let top_up = spl_token_2022::instruction::mint_to(&test.token_program, &test.mint, &test.vault, &test.owner.pubkey(), &[], 1).unwrap();
+    test.send(&[top_up], &[]).await.unwrap();
+    assert_error(withdraw(&mut test).await, TokenDistributorError::DistributionNotEnded);
+    test.set_merkle_root(leaf(&test, AMOUNT + 1)).await.unwrap();
+    test.claim(&claimant, &token_account, AMOUNT + 1, vec![]).await.unwrap();
+
+    // The empty vault and the distributor close before the end of the distribution
+    withdraw(&mut test).await.unwrap();
+    let (distributor, vault) = (test.distributor, test.vault);
+    assert!(test.account(&distributor).await.is_none());
+    assert!(test.account(&vault).await.is_none());
+    assert_eq!(test.token_balance(&token_account).await, AMOUNT + 1);
+}
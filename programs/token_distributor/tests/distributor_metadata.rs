@@ -0,0 +1,88 @@
+//! Campaign name and URI kept in the distributor's metadata account
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::system_program;
+use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
+use common::*;
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::Signer;
+use token_distributor::constants::*;
+use token_distributor::error::TokenDistributorError;
+use token_distributor::state::DistributorMetadata;
+use token_distributor::{accounts, instruction, ID};
+
+const DURATION: i64 = 7 * DAY;
+const NAME: &str = "OKX Boost Airdrop #3";
+const URI: &str = "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi";
+
+fn metadata_address(distributor: &Pubkey) -> Pubkey {
+    pda(&[METADATA_SEED.as_bytes(), distributor.as_ref()])
+}
+
+async fn set_metadata(test: &mut TestDistributor, name: &str, uri: &str) -> Result<u64, BanksClientError> {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::SetDistributorMetadata {
+            distributor: test.distributor,
+            metadata: metadata_address(&test.distributor),
+            owner: test.owner.pubkey(),
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::SetDistributorMetadata { name: name.to_string(), uri: uri.to_string() }.data(),
+    };
+    test.send(&[ix], &[]).await
+}
+
+async fn close_metadata(test: &mut TestDistributor) -> Result<u64, BanksClientError> {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::CloseDistributorMetadata {
+            metadata: metadata_address(&test.distributor),
+            owner: test.owner.pubkey(),
+            distributor_key: test.distributor,
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::CloseDistributorMetadata {}.data(),
+    };
+    test.send(&[ix], &[]).await
+}
+
+async fn metadata(test: &mut TestDistributor) -> Option<DistributorMetadata> {
+    let address = metadata_address(&test.distributor);
+    let data = test.account(&address).await?.data;
+    Some(DistributorMetadata::try_deserialize(&mut data.as_slice()).unwrap())
+}
+
+/// Metadata is editable until the start, then fixed until the owner closes it after the end
+#[tokio::test]
+async fn test_distributor_metadata() {
+    let mut test = TestDistributor::new(false, 1_000_000, DURATION, None).await;
+
+    let too_long = "x".repeat(MAX_METADATA_NAME_LEN + 1);
+    assert_error(set_metadata(&mut test, &too_long, URI).await, TokenDistributorError::MetadataTooLong);
+
+    set_metadata(&mut test, "draft", URI).await.unwrap();
+    set_metadata(&mut test, NAME, URI).await.unwrap();
+    let stored = metadata(&mut test).await.unwrap();
+    assert_eq!((stored.distributor, stored.owner), (test.distributor, test.owner.pubkey()));
+    assert_eq!((stored.name.as_str(), stored.uri.as_str()), (NAME, URI));
+
+    // Fixed once the distribution has started
+    test.set_time(GENESIS + DAY).await.unwrap();
+    test.warp_to(GENESIS + DAY).await;
+    assert_error(set_metadata(&mut test, "renamed", URI).await, TokenDistributorError::DistributionAlreadyStarted);
+
+    // Closable only after the end
+    assert_error(close_metadata(&mut test).await, TokenDistributorError::DistributionNotEnded);
+    test.warp_to(GENESIS + DAY + DURATION + 1).await;
+    close_metadata(&mut test).await.unwrap();
+    assert!(metadata(&mut test).await.is_none());
+}
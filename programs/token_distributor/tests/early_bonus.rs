@@ -0,0 +1,145 @@
+//! Early claim bonus funded by a bonus pool
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{AnchorDeserialize, InstructionData, ToAccountMetas};
+use anchor_spl::token_2022::spl_token_2022;
+use common::*;
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::Transaction;
+use token_distributor::error::TokenDistributorError;
+use token_distributor::instructions::ClaimQuote;
+use token_distributor::utils::verify::{hash_leaf, hash_node};
+use token_distributor::{accounts, instruction, ID};
+
+const DURATION: i64 = 7 * DAY;
+const AMOUNT: u64 = 1_000_000;
+
+/// Sets the early bonus, depositing any raise of the bonus pool from the owner's token account
+async fn set_early_bonus(
+    test: &mut TestDistributor,
+    early_bonus_bps: u16,
+    bonus_window: i64,
+    bonus_pool: u64,
+) -> Result<u64, BanksClientError> {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::SetEarlyBonus {
+            distributor: test.distributor,
+            token_vault: test.vault,
+            token_mint: test.mint,
+            owner_token_account: test.owner_token_account,
+            owner: test.owner.pubkey(),
+            token_program: test.token_program,
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::SetEarlyBonus { early_bonus_bps, bonus_window, bonus_pool }.data(),
+    };
+    test.send(&[ix], &[]).await
+}
+
+/// Simulates quote_claim for a claimant who has not claimed yet and returns its quote
+async fn quote_claim(test: &mut TestDistributor, claimant: &Pubkey, max_amount: u64, proof: Vec<[u8; 32]>) -> ClaimQuote {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::QuoteClaim {
+            distributor: test.distributor,
+            claim_status: None,
+            token_vault: Some(test.vault),
+            sol_vault: None,
+            claimant: *claimant,
+            #[cfg(feature = "claim-gates")]
+            gate_token_account: None,
+            #[cfg(feature = "claim-gates")]
+            attestation: None,
+            config: config(),
+        }
+        .to_account_metas(None),
+        data: instruction::QuoteClaim { max_amount, proof, unlock_time: None }.data(),
+    };
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&test.owner.pubkey()), &[&test.owner], test.context.last_blockhash);
+    let simulation = test.context.banks_client.simulate_transaction(tx).await.unwrap();
+    let return_data = simulation.simulation_details.unwrap().return_data.unwrap();
+    ClaimQuote::deserialize(&mut return_data.data.as_slice()).unwrap()
+}
+
+/// Claims in the first day earn 50% on top until the pool runs out, later claims earn nothing
+#[tokio::test]
+async fn test_early_bonus() {
+    let mut test = TestDistributor::new(true, 2 * AMOUNT, DURATION, None).await;
+    let (early, late) = (Keypair::new(), Keypair::new());
+    let early_leaf = hash_leaf(&test.distributor, &early.pubkey(), AMOUNT);
+    let late_leaf = hash_leaf(&test.distributor, &late.pubkey(), AMOUNT);
+    test.set_merkle_root(hash_node(&early_leaf, &late_leaf)).await.unwrap();
+    test.set_time(GENESIS + DAY).await.unwrap();
+    let (vault, owner_token_account) = (test.vault, test.owner_token_account);
+    let early_account = test.create_token_account(&early.pubkey()).await;
+    let late_account = test.create_token_account(&late.pubkey()).await;
+    let mint_pool = spl_token_2022::instruction::mint_to(
+        &test.token_program,
+        &test.mint,
+        &test.owner_token_account,
+        &test.owner.pubkey(),
+        &[],
+        AMOUNT,
+    )
+    .unwrap();
+    test.send(&[mint_pool], &[]).await.unwrap();
+
+    // The bonus needs a window within the distribution, and the pool never shrinks
+    assert_error(set_early_bonus(&mut test, 10_001, DAY, 0).await, TokenDistributorError::InvalidEarlyBonus);
+    assert_error(set_early_bonus(&mut test, 5_000, 0, 0).await, TokenDistributorError::InvalidEarlyBonus);
+    assert_error(set_early_bonus(&mut test, 5_000, DURATION + 1, 0).await, TokenDistributorError::InvalidEarlyBonus);
+    set_early_bonus(&mut test, 5_000, DAY, AMOUNT / 2).await.unwrap();
+    assert_error(set_early_bonus(&mut test, 5_000, DAY, AMOUNT / 4).await, TokenDistributorError::BonusPoolReduced);
+    set_early_bonus(&mut test, 5_000, DAY, AMOUNT * 3 / 4).await.unwrap();
+    assert_eq!(test.token_balance(&vault).await, 2 * AMOUNT + AMOUNT * 3 / 4);
+    assert_eq!(test.token_balance(&owner_token_account).await, AMOUNT / 4);
+
+    // The unpaid pool stays in the vault, only the rest can be withdrawn before the start
+    assert_error(test.withdraw_partial(2 * AMOUNT + 1).await, TokenDistributorError::WithdrawAmountExceedsBalance);
+
+    // A claim at the start earns the full bonus, as quoted
+    test.warp_to(GENESIS + DAY).await;
+    let quote = quote_claim(&mut test, &early.pubkey(), AMOUNT, vec![late_leaf]).await;
+    assert_eq!((quote.claimable_amount, quote.bonus_amount), (AMOUNT, AMOUNT / 2));
+    test.claim(&early, &early_account, AMOUNT, vec![late_leaf]).await.unwrap();
+    assert_eq!(test.token_balance(&early_account).await, AMOUNT + AMOUNT / 2);
+    assert_error(set_early_bonus(&mut test, 0, 0, AMOUNT).await, TokenDistributorError::DistributionAlreadyStarted);
+
+    // The last second of the window earns what is left of the pool
+    test.warp_to(GENESIS + 2 * DAY - 1).await;
+    test.claim_partial(&late, &late_account, AMOUNT, vec![early_leaf], Some(AMOUNT * 3 / 4)).await.unwrap();
+    assert_eq!(test.token_balance(&late_account).await, AMOUNT * 3 / 4 + AMOUNT / 4);
+
+    // Claims after the window earn nothing, bonuses are tracked apart from allocations
+    test.warp_to(GENESIS + 2 * DAY).await;
+    test.claim(&late, &late_account, AMOUNT, vec![early_leaf]).await.unwrap();
+    assert_eq!(test.token_balance(&late_account).await, AMOUNT + AMOUNT / 4);
+    let distributor = test.distributor().await;
+    assert_eq!(distributor.total_claimed, 2 * AMOUNT);
+    assert_eq!(distributor.total_bonus_paid, AMOUNT * 3 / 4);
+    assert_eq!(distributor.completed_at, GENESIS + 2 * DAY);
+    assert_eq!(test.token_balance(&vault).await, 0);
+}
+
+/// The bonus pays out of the bonus pool only, never out of other claimants' allocations
+#[tokio::test]
+async fn test_early_bonus_without_pool() {
+    let mut test = TestDistributor::new(false, AMOUNT, DURATION, None).await;
+    let claimant = Keypair::new();
+    test.set_merkle_root(hash_leaf(&test.distributor, &claimant.pubkey(), AMOUNT / 2)).await.unwrap();
+    test.set_time(GENESIS + DAY).await.unwrap();
+    let token_account = test.create_token_account(&claimant.pubkey()).await;
+    set_early_bonus(&mut test, 10_000, DAY, 0).await.unwrap();
+
+    test.warp_to(GENESIS + DAY).await;
+    test.claim(&claimant, &token_account, AMOUNT / 2, vec![]).await.unwrap();
+    assert_eq!(test.token_balance(&token_account).await, AMOUNT / 2);
+    assert_eq!(test.distributor().await.total_bonus_paid, 0);
+}
@@ -0,0 +1,115 @@
+//! Extending the end time of a live distribution
+
+mod common;
+
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use common::*;
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+use token_distributor::constants::MAX_DURATION;
+use token_distributor::error::TokenDistributorError;
+use token_distributor::utils::verify::{hash_leaf, hash_node};
+use token_distributor::{accounts, instruction, ID};
+
+const DURATION: i64 = 7 * DAY;
+const AMOUNT: u64 = 1_000_000;
+const START_TIME: i64 = GENESIS + DAY;
+
+/// Extends the end time, signed by `operator` along with the owner paying the fee
+async fn extend_time(test: &mut TestDistributor, operator: &Keypair, new_end_time: i64) -> Result<u64, BanksClientError> {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::ExtendTime {
+            distributor: test.distributor,
+            operator: operator.pubkey(),
+            config: config(),
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::ExtendTime { new_end_time }.data(),
+    };
+    test.send(&[ix], &[operator]).await
+}
+
+async fn finalize(test: &mut TestDistributor) -> Result<u64, BanksClientError> {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::Finalize {
+            distributor: test.distributor,
+            owner: test.owner.pubkey(),
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::Finalize {}.data(),
+    };
+    test.send(&[ix], &[]).await
+}
+
+/// An extension keeps the window live past its original end, claims included
+#[tokio::test]
+async fn test_extend_time() {
+    let mut test = TestDistributor::new(false, 2 * AMOUNT, DURATION, None).await;
+    let operator = test.owner.insecure_clone();
+    let (alice, bob) = (Keypair::new(), Keypair::new());
+    let alice_leaf = hash_leaf(&test.distributor, &alice.pubkey(), AMOUNT);
+    let bob_leaf = hash_leaf(&test.distributor, &bob.pubkey(), AMOUNT);
+    test.set_merkle_root(hash_node(&alice_leaf, &bob_leaf)).await.unwrap();
+
+    // Only a live distribution is extended
+    assert_error(extend_time(&mut test, &operator, START_TIME + 2 * DURATION).await, TokenDistributorError::StartTimeNotSet);
+    test.set_time(START_TIME).await.unwrap();
+    assert_error(
+        extend_time(&mut test, &operator, START_TIME + 2 * DURATION).await,
+        TokenDistributorError::DistributionNotStarted,
+    );
+    test.warp_to(START_TIME + DURATION).await;
+    assert_error(extend_time(&mut test, &alice, START_TIME + 2 * DURATION).await, TokenDistributorError::OnlyOperator);
+
+    let time_update_count = test.distributor().await.time_update_count;
+    extend_time(&mut test, &operator, START_TIME + 2 * DURATION).await.unwrap();
+    let distributor = test.distributor().await;
+    assert_eq!((distributor.start_time, distributor.end_time), (START_TIME, START_TIME + 2 * DURATION));
+    assert_eq!(distributor.duration, 2 * DURATION);
+    assert_eq!(distributor.time_update_count, time_update_count + 1);
+
+    // Claims go on past the original end, up to the new one
+    test.warp_to(START_TIME + DURATION + 1).await;
+    let alice_account = test.create_token_account(&alice.pubkey()).await;
+    test.claim(&alice, &alice_account, AMOUNT, vec![bob_leaf]).await.unwrap();
+    test.warp_to(START_TIME + 2 * DURATION + 1).await;
+    let bob_account = test.create_token_account(&bob.pubkey()).await;
+    assert_error(test.claim(&bob, &bob_account, AMOUNT, vec![alice_leaf]).await, TokenDistributorError::DistributionEnded);
+    assert_error(
+        extend_time(&mut test, &operator, START_TIME + 3 * DURATION).await,
+        TokenDistributorError::DistributionEnded,
+    );
+}
+
+/// The end time only moves later, within the maximum duration and never once finalized
+#[tokio::test]
+async fn test_extend_time_rejections() {
+    let mut test = TestDistributor::new(false, AMOUNT, DURATION, None).await;
+    let operator = test.owner.insecure_clone();
+    test.set_merkle_root(hash_leaf(&test.distributor, &Keypair::new().pubkey(), AMOUNT)).await.unwrap();
+    test.set_time(START_TIME).await.unwrap();
+    test.warp_to(START_TIME).await;
+    let end_time = START_TIME + DURATION;
+
+    assert_error(extend_time(&mut test, &operator, end_time - 1).await, TokenDistributorError::InvalidEndTime);
+    assert_error(extend_time(&mut test, &operator, end_time).await, TokenDistributorError::InvalidEndTime);
+    assert_error(
+        extend_time(&mut test, &operator, START_TIME + MAX_DURATION + 1).await,
+        TokenDistributorError::InvalidDuration,
+    );
+    extend_time(&mut test, &operator, START_TIME + MAX_DURATION).await.unwrap();
+
+    finalize(&mut test).await.unwrap();
+    assert_error(
+        extend_time(&mut test, &operator, START_TIME + MAX_DURATION + 1).await,
+        TokenDistributorError::DistributorFinalized,
+    );
+    assert_eq!(test.distributor().await.end_time, START_TIME + MAX_DURATION);
+}
@@ -0,0 +1,112 @@
+//! Finalizing a distributor, locking its root, timing and claim fee
+
+mod common;
+
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use common::*;
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+use token_distributor::error::TokenDistributorError;
+use token_distributor::utils::verify::hash_leaf;
+use token_distributor::{accounts, instruction, ID};
+
+const DURATION: i64 = 7 * DAY;
+const AMOUNT: u64 = 1_000_000;
+const START_TIME: i64 = GENESIS + DAY;
+
+/// Finalizes the distributor, signed by `owner` along with the test owner paying the fee
+async fn finalize(test: &mut TestDistributor, owner: &Keypair) -> Result<u64, BanksClientError> {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::Finalize {
+            distributor: test.distributor,
+            owner: owner.pubkey(),
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::Finalize {}.data(),
+    };
+    test.send(&[ix], &[owner]).await
+}
+
+async fn extend_time(test: &mut TestDistributor, new_end_time: i64) -> Result<u64, BanksClientError> {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::ExtendTime {
+            distributor: test.distributor,
+            operator: test.owner.pubkey(),
+            config: config(),
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::ExtendTime { new_end_time }.data(),
+    };
+    test.send(&[ix], &[]).await
+}
+
+#[cfg(feature = "claim-fee")]
+async fn set_claim_fee(test: &mut TestDistributor, claim_fee: u64) -> Result<u64, BanksClientError> {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::SetClaimFee {
+            distributor: test.distributor,
+            owner: test.owner.pubkey(),
+            config: config(),
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::SetClaimFee { claim_fee }.data(),
+    };
+    test.send(&[ix], &[]).await
+}
+
+/// Only the owner finalizes, and only complete terms
+#[tokio::test]
+async fn test_finalize_requires_terms() {
+    let mut test = TestDistributor::new(false, AMOUNT, DURATION, None).await;
+    let owner = test.owner.insecure_clone();
+    assert_error(finalize(&mut test, &owner).await, TokenDistributorError::NoMerkleRoot);
+    test.set_merkle_root([1; 32]).await.unwrap();
+    assert_error(finalize(&mut test, &owner).await, TokenDistributorError::StartTimeNotSet);
+    test.set_time(START_TIME).await.unwrap();
+    assert_error(finalize(&mut test, &Keypair::new()).await, TokenDistributorError::OnlyOwner);
+    finalize(&mut test, &owner).await.unwrap();
+    assert!(test.distributor().await.is_finalized());
+    assert_error(finalize(&mut test, &owner).await, TokenDistributorError::DistributorFinalized);
+}
+
+/// A finalized distributor refuses root, time and fee changes, claims go on
+#[tokio::test]
+async fn test_finalized_terms_are_locked() {
+    let mut test = TestDistributor::new(false, AMOUNT, DURATION, None).await;
+    let owner = test.owner.insecure_clone();
+    let claimant = Keypair::new();
+    test.set_merkle_root(hash_leaf(&test.distributor, &claimant.pubkey(), AMOUNT)).await.unwrap();
+    test.set_time(START_TIME).await.unwrap();
+    #[cfg(feature = "claim-fee")]
+    set_claim_fee(&mut test, 1_000).await.unwrap();
+    finalize(&mut test, &owner).await.unwrap();
+    let terms = test.distributor().await;
+
+    test.warp_to(START_TIME).await;
+    assert_error(test.set_merkle_root([2; 32]).await, TokenDistributorError::DistributorFinalized);
+    assert_error(test.set_time(START_TIME + DAY).await, TokenDistributorError::DistributorFinalized);
+    assert_error(extend_time(&mut test, START_TIME + 2 * DURATION).await, TokenDistributorError::DistributorFinalized);
+    #[cfg(feature = "claim-fee")]
+    {
+        assert_error(set_claim_fee(&mut test, 0).await, TokenDistributorError::DistributorFinalized);
+        assert_error(set_claim_fee(&mut test, 2_000).await, TokenDistributorError::DistributorFinalized);
+    }
+    let distributor = test.distributor().await;
+    assert_eq!(distributor.merkle_root, terms.merkle_root);
+    assert_eq!((distributor.start_time, distributor.end_time), (terms.start_time, terms.end_time));
+    assert_eq!(distributor.claim_fee, terms.claim_fee);
+
+    let token_account = test.create_token_account(&claimant.pubkey()).await;
+    test.claim(&claimant, &token_account, AMOUNT, vec![]).await.unwrap();
+    assert_eq!(test.token_balance(&token_account).await, AMOUNT);
+}
@@ -0,0 +1,172 @@
+//! Claimants freezing their own claim and unfreezing it with a co-signature
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::{system_instruction, system_program};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use common::*;
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+use token_distributor::constants::UNFREEZE_TIMELOCK;
+use token_distributor::error::TokenDistributorError;
+use token_distributor::utils::verify::hash_leaf;
+use token_distributor::{accounts, instruction, ID};
+
+const DURATION: i64 = 14 * DAY;
+const AMOUNT: u64 = 1_000_000;
+
+async fn freeze_my_claim(test: &mut TestDistributor, claimant: &Keypair) -> Result<u64, BanksClientError> {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::FreezeMyClaim {
+            distributor: test.distributor,
+            claim_status: claim_status_address(&test.distributor, &claimant.pubkey()),
+            claimant: claimant.pubkey(),
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::FreezeMyClaim {}.data(),
+    };
+    test.send(&[ix], &[claimant]).await
+}
+
+/// Unfreezes the claim, co-signed by `authority` (the owner if none)
+async fn unfreeze_my_claim(
+    test: &mut TestDistributor,
+    claimant: &Keypair,
+    authority: Option<&Keypair>,
+) -> Result<u64, BanksClientError> {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::UnfreezeMyClaim {
+            distributor: test.distributor,
+            claim_status: claim_status_address(&test.distributor, &claimant.pubkey()),
+            claimant: claimant.pubkey(),
+            authority: authority.map_or(test.owner.pubkey(), |authority| authority.pubkey()),
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::UnfreezeMyClaim {}.data(),
+    };
+    let mut signers = vec![claimant];
+    signers.extend(authority);
+    test.send(&[ix], &signers).await
+}
+
+/// Closes the claimant's claim status, returning the rent to the claimant who paid it
+async fn close_claim_status(test: &mut TestDistributor, claimant: &Keypair) -> Result<u64, BanksClientError> {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::CloseClaimStatus {
+            claim_status: claim_status_address(&test.distributor, &claimant.pubkey()),
+            claimant: claimant.pubkey(),
+            rent_payer: claimant.pubkey(),
+            distributor_key: test.distributor,
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::CloseClaimStatus {}.data(),
+    };
+    test.send(&[ix], &[claimant]).await
+}
+
+/// Gives the claimant lamports to pay for its claim status
+async fn fund(test: &mut TestDistributor, claimant: &Pubkey) {
+    let fund = system_instruction::transfer(&test.owner.pubkey(), claimant, 1_000_000_000);
+    test.send(&[fund], &[]).await.unwrap();
+}
+
+/// A frozen claim blocks claims until the timelock has passed and the owner or an operator co-signs the unfreeze
+#[tokio::test]
+async fn test_freeze_my_claim() {
+    let mut test = TestDistributor::new(false, AMOUNT, DURATION, None).await;
+    let claimant = Keypair::new();
+    fund(&mut test, &claimant.pubkey()).await;
+
+    // Freezing before the campaign is scheduled creates the claim status
+    let frozen_at = GENESIS;
+    freeze_my_claim(&mut test, &claimant).await.unwrap();
+    let claim_status = test.claim_status(&claimant.pubkey()).await.unwrap();
+    assert!(claim_status.frozen);
+    assert_eq!(claim_status.frozen_at, frozen_at);
+    assert_eq!(claim_status.rent_payer, claimant.pubkey());
+
+    // The unscheduled distribution has not ended, so the freeze cannot be shed by closing the claim status
+    assert_error(close_claim_status(&mut test, &claimant).await, TokenDistributorError::DistributionNotEnded);
+
+    test.set_merkle_root(hash_leaf(&test.distributor, &claimant.pubkey(), AMOUNT)).await.unwrap();
+    test.set_time(GENESIS + DAY).await.unwrap();
+    test.warp_to(GENESIS + DAY).await;
+    let token_account = test.create_token_account(&claimant.pubkey()).await;
+    assert_error(test.claim(&claimant, &token_account, AMOUNT, vec![]).await, TokenDistributorError::ClaimFrozen);
+    assert_error(close_claim_status(&mut test, &claimant).await, TokenDistributorError::DistributionNotEnded);
+
+    // The timelock holds even with the owner co-signing
+    assert_error(unfreeze_my_claim(&mut test, &claimant, None).await, TokenDistributorError::UnfreezeTimelockActive);
+    test.warp_to(frozen_at + UNFREEZE_TIMELOCK - 1).await;
+    assert_error(unfreeze_my_claim(&mut test, &claimant, None).await, TokenDistributorError::UnfreezeTimelockActive);
+
+    // After it the claimant's key alone, or with anyone else's, still cannot unfreeze
+    test.warp_to(frozen_at + UNFREEZE_TIMELOCK).await;
+    let stranger = Keypair::new();
+    assert_error(
+        unfreeze_my_claim(&mut test, &claimant, Some(&stranger)).await,
+        TokenDistributorError::OnlyOwnerOrOperator,
+    );
+    assert_error(
+        unfreeze_my_claim(&mut test, &claimant, Some(&claimant)).await,
+        TokenDistributorError::OnlyOwnerOrOperator,
+    );
+    assert_error(test.claim(&claimant, &token_account, AMOUNT, vec![]).await, TokenDistributorError::ClaimFrozen);
+
+    // The owner's co-signature lifts the freeze
+    unfreeze_my_claim(&mut test, &claimant, None).await.unwrap();
+    let claim_status = test.claim_status(&claimant.pubkey()).await.unwrap();
+    assert!(!claim_status.frozen);
+    assert_eq!(claim_status.frozen_at, 0);
+    assert_error(unfreeze_my_claim(&mut test, &claimant, None).await, TokenDistributorError::ClaimNotFrozen);
+    test.claim(&claimant, &token_account, AMOUNT, vec![]).await.unwrap();
+    assert_eq!(test.token_balance(&token_account).await, AMOUNT);
+}
+
+/// Freezing again restarts the timelock
+#[tokio::test]
+async fn test_refreeze_restarts_timelock() {
+    let mut test = TestDistributor::new(false, AMOUNT, DURATION, None).await;
+    let claimant = Keypair::new();
+    fund(&mut test, &claimant.pubkey()).await;
+
+    freeze_my_claim(&mut test, &claimant).await.unwrap();
+    test.warp_to(GENESIS + DAY).await;
+    freeze_my_claim(&mut test, &claimant).await.unwrap();
+    test.warp_to(GENESIS + UNFREEZE_TIMELOCK).await;
+    assert_error(unfreeze_my_claim(&mut test, &claimant, None).await, TokenDistributorError::UnfreezeTimelockActive);
+    test.warp_to(GENESIS + DAY + UNFREEZE_TIMELOCK).await;
+    unfreeze_my_claim(&mut test, &claimant, None).await.unwrap();
+}
+
+/// Once the distribution has ended the claimant closes the claim status and gets the rent back
+#[tokio::test]
+async fn test_close_claim_status_after_end() {
+    let mut test = TestDistributor::new(false, AMOUNT, DURATION, None).await;
+    let claimant = Keypair::new();
+    fund(&mut test, &claimant.pubkey()).await;
+    freeze_my_claim(&mut test, &claimant).await.unwrap();
+    test.set_time(GENESIS + DAY).await.unwrap();
+    let address = claim_status_address(&test.distributor, &claimant.pubkey());
+    let rent = test.account(&address).await.unwrap().lamports;
+
+    test.warp_to(GENESIS + DAY + DURATION).await;
+    assert_error(close_claim_status(&mut test, &claimant).await, TokenDistributorError::DistributionNotEnded);
+    test.warp_to(GENESIS + DAY + DURATION + 1).await;
+    let balance = test.account(&claimant.pubkey()).await.unwrap().lamports;
+    close_claim_status(&mut test, &claimant).await.unwrap();
+    assert!(test.account(&address).await.is_none());
+    assert_eq!(test.account(&claimant.pubkey()).await.unwrap().lamports, balance + rent);
+}
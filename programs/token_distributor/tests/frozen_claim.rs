@@ -0,0 +1,183 @@
+//! Claims of claimants whose token account is frozen
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::system_program;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::spl_associated_token_account;
+use anchor_spl::token_2022::spl_token_2022;
+use common::*;
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+use token_distributor::constants::FROZEN_CLAIM_GRACE_PERIOD;
+use token_distributor::error::TokenDistributorError;
+use token_distributor::utils::verify::hash_leaf;
+use token_distributor::{accounts, instruction, ID};
+
+const DURATION: i64 = 7 * DAY;
+const AMOUNT: u64 = 1_000_000;
+
+/// Redirects the claimant's pending allocation to `receiver_token_account`, signed by the operator
+/// and, when given, by the consenting claimant
+async fn redirect_frozen_claim(
+    test: &mut TestDistributor,
+    claimant: &Pubkey,
+    receiver_token_account: &Pubkey,
+    consent: Option<&Keypair>,
+) -> Result<u64, BanksClientError> {
+    let mut ix = Instruction {
+        program_id: ID,
+        accounts: accounts::RedirectFrozenClaim {
+            distributor: test.distributor,
+            claim_status: claim_status_address(&test.distributor, claimant),
+            token_vault: test.vault,
+            claimant: *claimant,
+            claimant_token_account: spl_associated_token_account::get_associated_token_address_with_program_id(
+                claimant,
+                &test.mint,
+                &test.token_program,
+            ),
+            receiver_token_account: *receiver_token_account,
+            token_mint: test.mint,
+            operator: test.owner.pubkey(),
+            system_program: system_program::ID,
+            token_program: test.token_program,
+            associated_token_program: spl_associated_token_account::ID,
+            memo_program: None,
+            #[cfg(feature = "protocol-fee")]
+            protocol_fee_account: None,
+            config: config(),
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::RedirectFrozenClaim { max_amount: AMOUNT, proof: vec![], unlock_time: None }.data(),
+    };
+    match consent {
+        Some(claimant) => {
+            ix.accounts[3].is_signer = true;
+            test.send(&[ix], &[claimant]).await
+        }
+        None => test.send(&[ix], &[]).await,
+    }
+}
+
+/// Creates the claimant's associated token account and freezes it with the mint's freeze authority
+async fn freeze_associated_account(test: &mut TestDistributor, claimant: &Pubkey) -> Pubkey {
+    let (mint, token_program, owner) = (test.mint, test.token_program, test.owner.pubkey());
+    let create_ata = spl_associated_token_account::instruction::create_associated_token_account(
+        &owner,
+        claimant,
+        &mint,
+        &token_program,
+    );
+    let ata = spl_associated_token_account::get_associated_token_address_with_program_id(claimant, &mint, &token_program);
+    let freeze = spl_token_2022::instruction::freeze_account(&token_program, &ata, &mint, &owner, &[]).unwrap();
+    test.send(&[create_ata, freeze], &[]).await.unwrap();
+    ata
+}
+
+/// Claims into a frozen account fail up front, the operator reports it and redirects the
+/// claim to the owner once the grace period has elapsed
+#[tokio::test]
+async fn test_redirect_frozen_claim() {
+    for token_2022 in [false, true] {
+        let mut test = TestDistributor::new(token_2022, AMOUNT, DURATION, None).await;
+        let claimant = Keypair::new();
+        test.set_merkle_root(hash_leaf(&test.distributor, &claimant.pubkey(), AMOUNT)).await.unwrap();
+        test.set_time(GENESIS + DAY).await.unwrap();
+        test.warp_to(GENESIS + DAY).await;
+        let ata = freeze_associated_account(&mut test, &claimant.pubkey()).await;
+        let other_account = test.create_token_account(&claimant.pubkey()).await;
+        let owner_token_account = test.owner_token_account;
+        assert_error(
+            test.claim(&claimant, &ata, AMOUNT, vec![]).await,
+            TokenDistributorError::ClaimantAccountFrozen,
+        );
+
+        // The allocation only goes to the owner, as there is no clawback receiver
+        assert_error(
+            redirect_frozen_claim(&mut test, &claimant.pubkey(), &other_account, None).await,
+            TokenDistributorError::ReceiverMismatch,
+        );
+
+        // The first redirect only reports the frozen account, the claimant keeps their allocation
+        let owner_balance = test.token_balance(&owner_token_account).await;
+        redirect_frozen_claim(&mut test, &claimant.pubkey(), &owner_token_account, None).await.unwrap();
+        let claim_status = test.claim_status(&claimant.pubkey()).await.unwrap();
+        assert_eq!(claim_status.frozen_reported_at, GENESIS + DAY);
+        assert_eq!(claim_status.claimed_amount, 0);
+        assert_eq!(test.token_balance(&owner_token_account).await, owner_balance);
+
+        // The claim is redirected once the grace period has elapsed
+        test.warp_to(GENESIS + DAY + FROZEN_CLAIM_GRACE_PERIOD - 1).await;
+        assert_error(
+            redirect_frozen_claim(&mut test, &claimant.pubkey(), &owner_token_account, None).await,
+            TokenDistributorError::FrozenClaimGracePeriod,
+        );
+        test.warp_to(GENESIS + DAY + FROZEN_CLAIM_GRACE_PERIOD).await;
+        redirect_frozen_claim(&mut test, &claimant.pubkey(), &owner_token_account, None).await.unwrap();
+        assert_eq!(test.token_balance(&owner_token_account).await, owner_balance + AMOUNT);
+        let claim_status = test.claim_status(&claimant.pubkey()).await.unwrap();
+        assert_eq!(claim_status.claimed_amount, AMOUNT);
+        assert_eq!(claim_status.frozen_reported_at, 0);
+        assert_eq!(test.distributor().await.total_claimed, AMOUNT);
+
+        // The redirected allocation is settled, the claimant cannot claim it again
+        assert_error(
+            test.claim(&claimant, &other_account, AMOUNT, vec![]).await,
+            TokenDistributorError::InvalidAmount,
+        );
+    }
+}
+
+/// A claimant consenting to the redirect skips the grace period
+#[tokio::test]
+async fn test_redirect_frozen_claim_with_consent() {
+    let mut test = TestDistributor::new(true, AMOUNT, DURATION, None).await;
+    let claimant = Keypair::new();
+    test.set_merkle_root(hash_leaf(&test.distributor, &claimant.pubkey(), AMOUNT)).await.unwrap();
+    test.set_time(GENESIS + DAY).await.unwrap();
+    test.warp_to(GENESIS + DAY).await;
+    freeze_associated_account(&mut test, &claimant.pubkey()).await;
+    let owner_token_account = test.owner_token_account;
+
+    let owner_balance = test.token_balance(&owner_token_account).await;
+    redirect_frozen_claim(&mut test, &claimant.pubkey(), &owner_token_account, Some(&claimant)).await.unwrap();
+    assert_eq!(test.token_balance(&owner_token_account).await, owner_balance + AMOUNT);
+    assert_eq!(test.claim_status(&claimant.pubkey()).await.unwrap().claimed_amount, AMOUNT);
+}
+
+/// Claimants whose account is not frozen claim themselves, their claim is never redirected
+#[tokio::test]
+async fn test_redirect_frozen_claim_not_frozen() {
+    let mut test = TestDistributor::new(true, AMOUNT, DURATION, None).await;
+    let claimant = Keypair::new();
+    test.set_merkle_root(hash_leaf(&test.distributor, &claimant.pubkey(), AMOUNT)).await.unwrap();
+    test.set_time(GENESIS + DAY).await.unwrap();
+    test.warp_to(GENESIS + DAY).await;
+    let (mint, token_program, owner) = (test.mint, test.token_program, test.owner.pubkey());
+    let create_ata = spl_associated_token_account::instruction::create_associated_token_account(
+        &owner,
+        &claimant.pubkey(),
+        &mint,
+        &token_program,
+    );
+    test.send(&[create_ata], &[]).await.unwrap();
+    let owner_token_account = test.owner_token_account;
+
+    // Neither the report nor a redirect past the grace period go through
+    assert_error(
+        redirect_frozen_claim(&mut test, &claimant.pubkey(), &owner_token_account, None).await,
+        TokenDistributorError::ClaimantAccountNotFrozen,
+    );
+    test.warp_to(GENESIS + DAY + FROZEN_CLAIM_GRACE_PERIOD).await;
+    assert_error(
+        redirect_frozen_claim(&mut test, &claimant.pubkey(), &owner_token_account, None).await,
+        TokenDistributorError::ClaimantAccountNotFrozen,
+    );
+    assert!(test.claim_status(&claimant.pubkey()).await.is_none());
+    assert_eq!(test.distributor().await.total_claimed, 0);
+}
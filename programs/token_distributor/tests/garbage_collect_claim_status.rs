@@ -0,0 +1,138 @@
+//! Permissionless cleanup of claim statuses long after the distribution ended
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::{system_instruction, system_program};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use common::*;
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+use token_distributor::constants::CLAIM_STATUS_GC_GRACE_PERIOD;
+use token_distributor::error::TokenDistributorError;
+use token_distributor::utils::verify::{hash_leaf, hash_node};
+use token_distributor::{accounts, instruction, ID};
+
+const DURATION: i64 = 7 * DAY;
+const AMOUNT: u64 = 1_000_000;
+
+/// Freezes the claimant's claim, the claimant paying for the new claim status
+async fn freeze_my_claim(test: &mut TestDistributor, claimant: &Keypair) {
+    let fund = system_instruction::transfer(&test.owner.pubkey(), &claimant.pubkey(), 1_000_000_000);
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::FreezeMyClaim {
+            distributor: test.distributor,
+            claim_status: claim_status_address(&test.distributor, &claimant.pubkey()),
+            claimant: claimant.pubkey(),
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::FreezeMyClaim {}.data(),
+    };
+    test.send(&[fund, ix], &[claimant]).await.unwrap();
+}
+
+/// Garbage collects the claimant's claim status, refunding `rent_payer`, cranked by `cranker`
+async fn garbage_collect(
+    test: &mut TestDistributor,
+    claimant: &Pubkey,
+    rent_payer: &Pubkey,
+    cranker: &Keypair,
+) -> Result<u64, BanksClientError> {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::GarbageCollectClaimStatus {
+            claim_status: claim_status_address(&test.distributor, claimant),
+            claimant: *claimant,
+            rent_payer: *rent_payer,
+            distributor_key: test.distributor,
+            cranker: cranker.pubkey(),
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::GarbageCollectClaimStatus {}.data(),
+    };
+    test.send(&[ix], &[cranker]).await
+}
+
+async fn lamports(test: &mut TestDistributor, address: &Pubkey) -> u64 {
+    test.account(address).await.map_or(0, |account| account.lamports)
+}
+
+/// Anyone closes a claim status once the grace period after the end has passed, the rent goes to whoever paid it
+#[tokio::test]
+async fn test_garbage_collect_claim_status() {
+    let mut test = TestDistributor::new(false, 2 * AMOUNT, DURATION, None).await;
+    let (claimant, sponsored) = (Keypair::new(), Keypair::new());
+    let leaf = hash_leaf(&test.distributor, &claimant.pubkey(), AMOUNT);
+    let sponsored_leaf = hash_leaf(&test.distributor, &sponsored.pubkey(), AMOUNT);
+    test.set_merkle_root(hash_node(&leaf, &sponsored_leaf)).await.unwrap();
+    let cranker = Keypair::new();
+
+    // The claimant pays for its own claim status, the owner pays for the sponsored claimant's
+    freeze_my_claim(&mut test, &claimant).await;
+    let start_time = GENESIS + DAY;
+    test.set_time(start_time).await.unwrap();
+    test.warp_to(start_time).await;
+    let token_account = test.create_token_account(&sponsored.pubkey()).await;
+    test.claim(&sponsored, &token_account, AMOUNT, vec![leaf]).await.unwrap();
+    let (address, sponsored_address) = (
+        claim_status_address(&test.distributor, &claimant.pubkey()),
+        claim_status_address(&test.distributor, &sponsored.pubkey()),
+    );
+    let rent = lamports(&mut test, &address).await;
+
+    // Neither while the distribution runs nor during the grace period after it
+    let collectable_at = start_time + DURATION + CLAIM_STATUS_GC_GRACE_PERIOD;
+    assert_error(
+        garbage_collect(&mut test, &claimant.pubkey(), &claimant.pubkey(), &cranker).await,
+        TokenDistributorError::GarbageCollectionGracePeriodActive,
+    );
+    test.warp_to(collectable_at).await;
+    assert_error(
+        garbage_collect(&mut test, &claimant.pubkey(), &claimant.pubkey(), &cranker).await,
+        TokenDistributorError::GarbageCollectionGracePeriodActive,
+    );
+
+    // The refund only goes to the rent payer, never to the cranker
+    test.warp_to(collectable_at + 1).await;
+    assert_error(
+        garbage_collect(&mut test, &claimant.pubkey(), &cranker.pubkey(), &cranker).await,
+        TokenDistributorError::RentPayerMismatch,
+    );
+    let balance = lamports(&mut test, &claimant.pubkey()).await;
+    garbage_collect(&mut test, &claimant.pubkey(), &claimant.pubkey(), &cranker).await.unwrap();
+    assert!(test.account(&address).await.is_none());
+    assert_eq!(lamports(&mut test, &claimant.pubkey()).await, balance + rent);
+    assert_eq!(lamports(&mut test, &cranker.pubkey()).await, 0);
+
+    // The sponsored claimant's rent goes back to the owner who paid it, not to the claimant
+    assert_error(
+        garbage_collect(&mut test, &sponsored.pubkey(), &sponsored.pubkey(), &cranker).await,
+        TokenDistributorError::RentPayerMismatch,
+    );
+    let owner = test.owner.pubkey();
+    garbage_collect(&mut test, &sponsored.pubkey(), &owner, &cranker).await.unwrap();
+    assert!(test.account(&sponsored_address).await.is_none());
+    assert_eq!(lamports(&mut test, &sponsored.pubkey()).await, 0);
+}
+
+/// A claim status created before the distribution is scheduled keeps its freeze, however late it is
+#[tokio::test]
+async fn test_garbage_collect_unscheduled() {
+    let mut test = TestDistributor::new(false, AMOUNT, DURATION, None).await;
+    let claimant = Keypair::new();
+    freeze_my_claim(&mut test, &claimant).await;
+
+    test.warp_to(GENESIS + DURATION + CLAIM_STATUS_GC_GRACE_PERIOD + DAY).await;
+    assert_error(
+        garbage_collect(&mut test, &claimant.pubkey(), &claimant.pubkey(), &Keypair::new()).await,
+        TokenDistributorError::GarbageCollectionGracePeriodActive,
+    );
+    assert!(test.claim_status(&claimant.pubkey()).await.unwrap().frozen);
+}
@@ -0,0 +1,117 @@
+//! Migrating claim statuses created before the per-claimant fields were added
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::system_program;
+use anchor_lang::{Discriminator, InstructionData, ToAccountMetas};
+use common::*;
+use solana_program_test::BanksClientError;
+use solana_sdk::account::Account;
+use solana_sdk::signature::{Keypair, Signer};
+use token_distributor::error::TokenDistributorError;
+use token_distributor::state::ClaimStatus;
+use token_distributor::utils::verify::hash_leaf;
+use token_distributor::{accounts, instruction, ID};
+
+const DURATION: i64 = 7 * DAY;
+const AMOUNT: u64 = 1_000_000;
+
+/// Writes a claim status of the legacy layout, as the claimant's first claim of `claimed_amount` created it
+async fn set_legacy_claim_status(test: &mut TestDistributor, claimant: &Pubkey, claimed_amount: u64) -> u64 {
+    let mut data = ClaimStatus::DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&claimed_amount.to_le_bytes());
+    assert_eq!(data.len(), ClaimStatus::LEGACY_LEN);
+    let rent = test.minimum_balance(ClaimStatus::LEGACY_LEN).await;
+    let account = Account { lamports: rent, data, owner: ID, executable: false, rent_epoch: 0 };
+    test.context.set_account(&claim_status_address(&test.distributor, claimant), &account.into());
+    rent
+}
+
+/// Migrates the claimant's claim status, the owner paying the extra rent
+async fn migrate_claim_status(test: &mut TestDistributor, claimant: &Pubkey) -> Result<u64, BanksClientError> {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::MigrateClaimStatus {
+            claim_status: claim_status_address(&test.distributor, claimant),
+            distributor_key: test.distributor,
+            claimant: *claimant,
+            payer: test.owner.pubkey(),
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::MigrateClaimStatus {}.data(),
+    };
+    test.send(&[ix], &[]).await
+}
+
+async fn close_claim_status(test: &mut TestDistributor, claimant: &Keypair) -> Result<u64, BanksClientError> {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::CloseClaimStatus {
+            claim_status: claim_status_address(&test.distributor, &claimant.pubkey()),
+            claimant: claimant.pubkey(),
+            rent_payer: claimant.pubkey(),
+            distributor_key: test.distributor,
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::CloseClaimStatus {}.data(),
+    };
+    test.send(&[ix], &[claimant]).await
+}
+
+/// A legacy claim status blocks claims until migrated, then keeps its claimed amount and refunds the claimant
+#[tokio::test]
+async fn test_migrate_claim_status() {
+    let mut test = TestDistributor::new(false, AMOUNT, DURATION, None).await;
+    let claimant = Keypair::new();
+    test.set_merkle_root(hash_leaf(&test.distributor, &claimant.pubkey(), AMOUNT)).await.unwrap();
+    test.set_time(GENESIS + DAY).await.unwrap();
+    test.warp_to(GENESIS + DAY).await;
+    let token_account = test.create_token_account(&claimant.pubkey()).await;
+    let legacy_rent = set_legacy_claim_status(&mut test, &claimant.pubkey(), AMOUNT / 4).await;
+
+    // The legacy layout no longer deserializes
+    assert!(test.claim(&claimant, &token_account, AMOUNT, vec![]).await.is_err());
+
+    migrate_claim_status(&mut test, &claimant.pubkey()).await.unwrap();
+    let address = claim_status_address(&test.distributor, &claimant.pubkey());
+    let account = test.account(&address).await.unwrap();
+    assert_eq!(account.data.len(), ClaimStatus::LEN);
+    assert_eq!(account.lamports, test.minimum_balance(ClaimStatus::LEN).await);
+    assert!(account.lamports > legacy_rent);
+    let claim_status = test.claim_status(&claimant.pubkey()).await.unwrap();
+    assert_eq!(claim_status.claimed_amount, AMOUNT / 4);
+    assert_eq!(claim_status.rent_payer, claimant.pubkey());
+    assert!(!claim_status.frozen);
+    assert_eq!(claim_status.delegate, Pubkey::default());
+    assert_error(migrate_claim_status(&mut test, &claimant.pubkey()).await, TokenDistributorError::ClaimStatusUpToDate);
+
+    // Only the rest of the allocation is paid out
+    test.claim(&claimant, &token_account, AMOUNT, vec![]).await.unwrap();
+    assert_eq!(test.token_balance(&token_account).await, AMOUNT - AMOUNT / 4);
+    assert_eq!(test.claim_status(&claimant.pubkey()).await.unwrap().claimed_amount, AMOUNT);
+
+    // The claimant gets the whole rent back on close
+    test.warp_to(GENESIS + DAY + DURATION + 1).await;
+    close_claim_status(&mut test, &claimant).await.unwrap();
+    assert_eq!(test.account(&claimant.pubkey()).await.unwrap().lamports, account.lamports);
+}
+
+/// Accounts of other programs or types are not migrated
+#[tokio::test]
+async fn test_migrate_claim_status_rejects_other_accounts() {
+    let mut test = TestDistributor::new(false, AMOUNT, DURATION, None).await;
+    let claimant = Pubkey::new_unique();
+    let address = claim_status_address(&test.distributor, &claimant);
+    let rent = test.minimum_balance(ClaimStatus::LEGACY_LEN).await;
+    let account = Account { lamports: rent, data: vec![1; ClaimStatus::LEGACY_LEN], owner: ID, executable: false, rent_epoch: 0 };
+    test.context.set_account(&address, &account.into());
+    assert!(migrate_claim_status(&mut test, &claimant).await.is_err());
+    assert_eq!(test.account(&address).await.unwrap().data.len(), ClaimStatus::LEGACY_LEN);
+}
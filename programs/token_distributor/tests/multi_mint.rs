@@ -0,0 +1,299 @@
+//! Multi-mint distributors paying every registered mint with one proof
+#![cfg(feature = "multi-mint")]
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::solana_program::{system_instruction, system_program};
+use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
+use anchor_spl::token_2022::spl_token_2022;
+use common::*;
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+use token_distributor::constants::{DISTRIBUTOR_MINT_SEED, MULTI_MINT_TREE_VERSION, OWNER_NONCE_SEED, VAULT_SEED};
+use token_distributor::error::TokenDistributorError;
+use token_distributor::state::DistributorMint;
+use token_distributor::utils::verify::{hash_leaf_multi_mint, hash_node};
+use token_distributor::{accounts, instruction, ID};
+
+const DURATION: i64 = 7 * DAY;
+const AMOUNT: u64 = 1_000_000;
+
+/// An extra mint of the distributor, with its accounts and the owner's token account of it
+struct ExtraMint {
+    mint: Pubkey,
+    token_program: Pubkey,
+    distributor_mint: Pubkey,
+    vault: Pubkey,
+    owner_token_account: Pubkey,
+}
+
+/// Creates a token account of `mint` owned by `authority`, at a fresh address
+async fn create_token_account(test: &mut TestDistributor, mint: &Pubkey, token_program: &Pubkey, authority: &Pubkey) -> Pubkey {
+    let account = Keypair::new();
+    let len = spl_token_2022::state::Account::LEN;
+    let rent = test.minimum_balance(len).await;
+    let instructions = [
+        system_instruction::create_account(&test.owner.pubkey(), &account.pubkey(), rent, len as u64, token_program),
+        spl_token_2022::instruction::initialize_account3(token_program, &account.pubkey(), mint, authority).unwrap(),
+    ];
+    test.send(&instructions, &[&account]).await.unwrap();
+    account.pubkey()
+}
+
+/// Creates a new mint of `token_program` and mints `amount` of it to the owner
+async fn create_extra_mint(test: &mut TestDistributor, token_program: Pubkey, amount: u64) -> ExtraMint {
+    let (mint, owner) = (Keypair::new(), test.owner.pubkey());
+    let len = spl_token_2022::state::Mint::LEN;
+    let rent = test.minimum_balance(len).await;
+    let instructions = [
+        system_instruction::create_account(&owner, &mint.pubkey(), rent, len as u64, &token_program),
+        spl_token_2022::instruction::initialize_mint2(&token_program, &mint.pubkey(), &owner, None, DECIMALS).unwrap(),
+    ];
+    test.send(&instructions, &[&mint]).await.unwrap();
+    let mint = mint.pubkey();
+    let owner_token_account = create_token_account(test, &mint, &token_program, &owner).await;
+    let mint_to = spl_token_2022::instruction::mint_to(&token_program, &mint, &owner_token_account, &owner, &[], amount).unwrap();
+    test.send(&[mint_to], &[]).await.unwrap();
+    let distributor_mint = pda(&[DISTRIBUTOR_MINT_SEED.as_bytes(), test.distributor.as_ref(), mint.as_ref()]);
+    let vault = pda(&[VAULT_SEED.as_bytes(), distributor_mint.as_ref()]);
+    ExtraMint { mint, token_program, distributor_mint, vault, owner_token_account }
+}
+
+async fn add_distributor_mint(test: &mut TestDistributor, extra: &ExtraMint, amount: u64) -> Result<u64, BanksClientError> {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::AddDistributorMint {
+            distributor: test.distributor,
+            distributor_mint: extra.distributor_mint,
+            token_vault: extra.vault,
+            token_mint: extra.mint,
+            owner_token_account: extra.owner_token_account,
+            owner: test.owner.pubkey(),
+            system_program: system_program::ID,
+            token_program: extra.token_program,
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::AddDistributorMint { initial_total_amount: amount }.data(),
+    };
+    test.send(&[ix], &[]).await
+}
+
+/// Claims every mint with the leaf `amounts`, paying extra mints into the given (mint, token account) pairs in order
+async fn claim_multi_mint(
+    test: &mut TestDistributor,
+    claimant: &Keypair,
+    token_account: &Pubkey,
+    extras: &[(&ExtraMint, Pubkey)],
+    amounts: Vec<u64>,
+    proof: Vec<[u8; 32]>,
+) -> Result<u64, BanksClientError> {
+    let mut accounts = accounts::ClaimMultiMint {
+        distributor: test.distributor,
+        claim_status: claim_status_address(&test.distributor, &claimant.pubkey()),
+        token_vault: test.vault,
+        claimant_token_account: *token_account,
+        token_mint: test.mint,
+        claimant: claimant.pubkey(),
+        payer: test.owner.pubkey(),
+        system_program: system_program::ID,
+        token_program: test.token_program,
+        memo_program: None,
+        #[cfg(feature = "protocol-fee")]
+        protocol_fee_account: None,
+        #[cfg(feature = "claim-gates")]
+        gate_token_account: None,
+        #[cfg(feature = "claim-gates")]
+        attestation: None,
+        config: config(),
+        event_authority: event_authority(),
+        program: ID,
+    }
+    .to_account_metas(None);
+    for (extra, token_account) in extras {
+        accounts.push(AccountMeta::new(extra.distributor_mint, false));
+        accounts.push(AccountMeta::new(extra.vault, false));
+        accounts.push(AccountMeta::new_readonly(extra.mint, false));
+        accounts.push(AccountMeta::new(*token_account, false));
+        accounts.push(AccountMeta::new_readonly(extra.token_program, false));
+    }
+    let ix = Instruction { program_id: ID, accounts, data: instruction::ClaimMultiMint { amounts, proof }.data() };
+    test.send(&[ix], &[claimant]).await
+}
+
+/// Withdraws what is left of an extra mint to the owner
+async fn withdraw_distributor_mint(test: &mut TestDistributor, extra: &ExtraMint) -> Result<u64, BanksClientError> {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::WithdrawDistributorMint {
+            distributor: test.distributor,
+            distributor_mint: extra.distributor_mint,
+            token_vault: extra.vault,
+            receiver_token_account: extra.owner_token_account,
+            token_mint: extra.mint,
+            owner: test.owner.pubkey(),
+            authority: test.owner.pubkey(),
+            token_program: extra.token_program,
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::WithdrawDistributorMint {}.data(),
+    };
+    test.send(&[ix], &[]).await
+}
+
+async fn withdraw(test: &mut TestDistributor) -> Result<u64, BanksClientError> {
+    let owner = test.owner.pubkey();
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::Withdraw {
+            distributor: test.distributor,
+            token_vault: test.vault,
+            owner_token_account: test.owner_token_account,
+            token_mint: test.mint,
+            owner,
+            owner_nonce: pda(&[OWNER_NONCE_SEED.as_bytes(), owner.as_ref()]),
+            token_program: test.token_program,
+            event_authority: event_authority(),
+            program: ID,
+            registry: None,
+        }
+        .to_account_metas(None),
+        data: instruction::Withdraw {}.data(),
+    };
+    test.send(&[ix], &[]).await
+}
+
+async fn distributor_mint(test: &mut TestDistributor, extra: &ExtraMint) -> DistributorMint {
+    let data = test.account(&extra.distributor_mint).await.expect("distributor mint exists").data;
+    DistributorMint::try_deserialize(&mut data.as_slice()).unwrap()
+}
+
+/// One proof pays the own mint and every extra mint, tracked per mint, and extra mints are withdrawn after the end
+#[tokio::test]
+async fn test_multi_mint() {
+    let mut test = TestDistributor::new(false, 2 * AMOUNT, DURATION, Some(MULTI_MINT_TREE_VERSION)).await;
+    let distributor = test.distributor;
+
+    // Extra mints of either token program, never the distributor's own mint
+    let spl = create_extra_mint(&mut test, anchor_spl::token::ID, 2 * AMOUNT).await;
+    let token_2022 = create_extra_mint(&mut test, spl_token_2022::ID, 2 * AMOUNT).await;
+    let own_distributor_mint = pda(&[DISTRIBUTOR_MINT_SEED.as_bytes(), distributor.as_ref(), test.mint.as_ref()]);
+    let own = ExtraMint {
+        mint: test.mint,
+        token_program: test.token_program,
+        distributor_mint: own_distributor_mint,
+        vault: pda(&[VAULT_SEED.as_bytes(), own_distributor_mint.as_ref()]),
+        owner_token_account: test.owner_token_account,
+    };
+    assert_error(add_distributor_mint(&mut test, &own, AMOUNT).await, TokenDistributorError::TokenMintMismatch);
+    assert_error(add_distributor_mint(&mut test, &spl, 0).await, TokenDistributorError::InvalidAmount);
+    add_distributor_mint(&mut test, &spl, 2 * AMOUNT).await.unwrap();
+    add_distributor_mint(&mut test, &token_2022, 2 * AMOUNT).await.unwrap();
+    assert_eq!(test.token_balance(&spl.vault).await, 2 * AMOUNT);
+    assert_eq!(test.token_balance(&token_2022.vault).await, 2 * AMOUNT);
+    assert_eq!(distributor_mint(&mut test, &token_2022).await.index, 1);
+
+    // Alice is owed some of each mint but the last, Bob only extra mints
+    let (alice, bob) = (Keypair::new(), Keypair::new());
+    let alice_amounts = vec![AMOUNT, AMOUNT / 2, 0];
+    let bob_amounts = vec![0, AMOUNT, AMOUNT];
+    let alice_leaf = hash_leaf_multi_mint(&distributor, &alice.pubkey(), alice_amounts[0], &alice_amounts[1..]);
+    let bob_leaf = hash_leaf_multi_mint(&distributor, &bob.pubkey(), bob_amounts[0], &bob_amounts[1..]);
+    test.set_merkle_root(hash_node(&alice_leaf, &bob_leaf)).await.unwrap();
+    let start_time = GENESIS + DAY;
+    test.set_time(start_time).await.unwrap();
+    test.warp_to(start_time).await;
+
+    // Leaves carry one amount per mint, so no mint can be added once the root is set
+    let late = create_extra_mint(&mut test, anchor_spl::token::ID, AMOUNT).await;
+    assert_error(add_distributor_mint(&mut test, &late, AMOUNT).await, TokenDistributorError::MerkleRootAlreadySet);
+
+    let mut accounts = Vec::new();
+    for claimant in [&alice, &bob] {
+        let own_account = test.create_token_account(&claimant.pubkey()).await;
+        let spl_account = create_token_account(&mut test, &spl.mint, &spl.token_program, &claimant.pubkey()).await;
+        let token_2022_account =
+            create_token_account(&mut test, &token_2022.mint, &token_2022.token_program, &claimant.pubkey()).await;
+        accounts.push((own_account, spl_account, token_2022_account));
+    }
+    let (alice_own, alice_spl, alice_2022) = accounts[0];
+    let (bob_own, bob_spl, bob_2022) = accounts[1];
+
+    // Extra amounts are bound into the leaf and mint groups must come in the order the mints were added
+    let mut tampered = alice_amounts.clone();
+    tampered[2] = AMOUNT;
+    assert_error(
+        claim_multi_mint(&mut test, &alice, &alice_own, &[(&spl, alice_spl), (&token_2022, alice_2022)], tampered, vec![bob_leaf])
+            .await,
+        TokenDistributorError::InvalidProof,
+    );
+    assert_error(
+        claim_multi_mint(
+            &mut test,
+            &alice,
+            &alice_own,
+            &[(&token_2022, alice_2022), (&spl, alice_spl)],
+            alice_amounts.clone(),
+            vec![bob_leaf],
+        )
+        .await,
+        TokenDistributorError::InvalidExtraMintAccounts,
+    );
+
+    // One proof pays every mint
+    claim_multi_mint(
+        &mut test,
+        &alice,
+        &alice_own,
+        &[(&spl, alice_spl), (&token_2022, alice_2022)],
+        alice_amounts.clone(),
+        vec![bob_leaf],
+    )
+    .await
+    .unwrap();
+    assert_eq!(test.token_balance(&alice_own).await, AMOUNT);
+    assert_eq!(test.token_balance(&alice_spl).await, AMOUNT / 2);
+    assert_eq!(test.token_balance(&alice_2022).await, 0);
+    let claim_status = test.claim_status(&alice.pubkey()).await.unwrap();
+    assert_eq!(claim_status.claimed_amount, AMOUNT);
+    assert_eq!(claim_status.extra_claimed_amounts, [AMOUNT / 2, 0, 0]);
+    assert_error(
+        claim_multi_mint(&mut test, &alice, &alice_own, &[(&spl, alice_spl), (&token_2022, alice_2022)], alice_amounts, vec![bob_leaf])
+            .await,
+        TokenDistributorError::InvalidAmount,
+    );
+
+    // Nothing of the own mint is needed as long as an extra mint is owed
+    claim_multi_mint(&mut test, &bob, &bob_own, &[(&spl, bob_spl), (&token_2022, bob_2022)], bob_amounts, vec![alice_leaf])
+        .await
+        .unwrap();
+    assert_eq!(test.token_balance(&bob_own).await, 0);
+    assert_eq!(test.token_balance(&bob_spl).await, AMOUNT);
+    assert_eq!(test.token_balance(&bob_2022).await, AMOUNT);
+    assert_eq!(test.claim_status(&bob.pubkey()).await.unwrap().extra_claimed_amounts, [AMOUNT, AMOUNT, 0]);
+    assert_eq!(test.distributor().await.total_claimed, AMOUNT);
+    assert_eq!(distributor_mint(&mut test, &spl).await.total_claimed, AMOUNT + AMOUNT / 2);
+    assert_eq!(distributor_mint(&mut test, &token_2022).await.total_claimed, AMOUNT);
+
+    // Extra mints are withdrawn after the end, before the distributor itself
+    assert_error(withdraw_distributor_mint(&mut test, &spl).await, TokenDistributorError::DistributionNotEnded);
+    test.warp_to(start_time + DURATION + 1).await;
+    assert_error(withdraw(&mut test).await, TokenDistributorError::ExtraMintsOpen);
+    for extra in [&spl, &token_2022] {
+        withdraw_distributor_mint(&mut test, extra).await.unwrap();
+        assert!(test.account(&extra.vault).await.is_none());
+        assert!(test.account(&extra.distributor_mint).await.is_none());
+    }
+    assert_eq!(test.token_balance(&spl.owner_token_account).await, AMOUNT / 2);
+    assert_eq!(test.token_balance(&token_2022.owner_token_account).await, AMOUNT);
+    assert_eq!(test.distributor().await.open_extra_mints, 0);
+    withdraw(&mut test).await.unwrap();
+    let owner_token_account = test.owner_token_account;
+    assert_eq!(test.token_balance(&owner_token_account).await, AMOUNT);
+}
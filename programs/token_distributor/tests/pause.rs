@@ -0,0 +1,103 @@
+//! Pausing and unpausing the claims of a distribution
+
+mod common;
+
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use common::*;
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+use token_distributor::error::TokenDistributorError;
+use token_distributor::utils::verify::{hash_leaf, hash_node};
+use token_distributor::{accounts, instruction, ID};
+
+const DURATION: i64 = 7 * DAY;
+const AMOUNT: u64 = 1_000_000;
+
+/// Pauses the distribution, signed by `authority` along with the owner paying the fee
+async fn pause(test: &mut TestDistributor, authority: &Keypair) -> Result<u64, BanksClientError> {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::Pause {
+            distributor: test.distributor,
+            authority: authority.pubkey(),
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::Pause {}.data(),
+    };
+    test.send(&[ix], &[authority]).await
+}
+
+async fn unpause(test: &mut TestDistributor, authority: &Keypair) -> Result<u64, BanksClientError> {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::Unpause {
+            distributor: test.distributor,
+            authority: authority.pubkey(),
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::Unpause {}.data(),
+    };
+    test.send(&[ix], &[authority]).await
+}
+
+/// An open distributor whose root allocates `AMOUNT` to each claimant, with their proofs
+async fn open_distributor(claimants: &[&Keypair; 2]) -> (TestDistributor, [Vec<[u8; 32]>; 2]) {
+    let mut test = TestDistributor::new(false, 2 * AMOUNT, DURATION, None).await;
+    let leaves = claimants.map(|claimant| hash_leaf(&test.distributor, &claimant.pubkey(), AMOUNT));
+    test.set_merkle_root(hash_node(&leaves[0], &leaves[1])).await.unwrap();
+    test.set_time(GENESIS + DAY).await.unwrap();
+    test.warp_to(GENESIS + DAY).await;
+    (test, [vec![leaves[1]], vec![leaves[0]]])
+}
+
+/// Claims are rejected while paused, pausing being limited to the owner and operators
+#[tokio::test]
+async fn test_claim_rejected_while_paused() {
+    let (alice, bob) = (Keypair::new(), Keypair::new());
+    let (mut test, [alice_proof, bob_proof]) = open_distributor(&[&alice, &bob]).await;
+    let owner = test.owner.insecure_clone();
+    let alice_account = test.create_token_account(&alice.pubkey()).await;
+    test.claim_partial(&alice, &alice_account, AMOUNT, alice_proof.clone(), Some(AMOUNT / 2)).await.unwrap();
+
+    assert_error(pause(&mut test, &alice).await, TokenDistributorError::OnlyOwnerOrOperator);
+    pause(&mut test, &owner).await.unwrap();
+    assert!(test.distributor().await.is_paused());
+    assert_error(pause(&mut test, &owner).await, TokenDistributorError::DistributionPaused);
+
+    // Neither a first claim nor the rest of a partial one goes through
+    let bob_account = test.create_token_account(&bob.pubkey()).await;
+    assert_error(test.claim(&bob, &bob_account, AMOUNT, bob_proof).await, TokenDistributorError::DistributionPaused);
+    assert_error(test.claim(&alice, &alice_account, AMOUNT, alice_proof).await, TokenDistributorError::DistributionPaused);
+    assert!(test.claim_status(&bob.pubkey()).await.is_none());
+    assert_eq!(test.token_balance(&alice_account).await, AMOUNT / 2);
+    assert_eq!(test.distributor().await.total_claimed, AMOUNT / 2);
+}
+
+/// Claims go through again once unpaused, within the unchanged window
+#[tokio::test]
+async fn test_claim_after_unpause() {
+    let (alice, bob) = (Keypair::new(), Keypair::new());
+    let (mut test, [alice_proof, bob_proof]) = open_distributor(&[&alice, &bob]).await;
+    let owner = test.owner.insecure_clone();
+    assert_error(unpause(&mut test, &owner).await, TokenDistributorError::DistributionNotPaused);
+    pause(&mut test, &owner).await.unwrap();
+    let end_time = test.distributor().await.end_time;
+
+    assert_error(unpause(&mut test, &bob).await, TokenDistributorError::OnlyOwnerOrOperator);
+    unpause(&mut test, &owner).await.unwrap();
+    assert!(!test.distributor().await.is_paused());
+    let alice_account = test.create_token_account(&alice.pubkey()).await;
+    test.claim(&alice, &alice_account, AMOUNT, alice_proof).await.unwrap();
+    assert_eq!(test.token_balance(&alice_account).await, AMOUNT);
+
+    // The pause did not extend the window
+    assert_eq!(test.distributor().await.end_time, end_time);
+    test.warp_to(end_time + 1).await;
+    let bob_account = test.create_token_account(&bob.pubkey()).await;
+    assert_error(test.claim(&bob, &bob_account, AMOUNT, bob_proof).await, TokenDistributorError::DistributionEnded);
+}
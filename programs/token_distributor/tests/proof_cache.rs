@@ -0,0 +1,54 @@
+//! Claims omitting the proof of an allocation verified under the current root
+
+mod common;
+
+use common::*;
+use solana_sdk::signature::{Keypair, Signer};
+use token_distributor::error::TokenDistributorError;
+use token_distributor::utils::verify::{hash_leaf, hash_node};
+
+const DURATION: i64 = 7 * DAY;
+const AMOUNT: u64 = 1_000_000;
+
+/// Sibling leaf of the claimant's leaf in the two-leaf trees below
+const SIBLING: [u8; 32] = [7; 32];
+
+/// Partial claims after the first pass an empty proof until the root changes
+#[tokio::test]
+async fn test_proof_cache() {
+    let mut test = TestDistributor::new(false, 4 * AMOUNT, DURATION, None).await;
+    let claimant = Keypair::new();
+    let leaf = |test: &TestDistributor, max_amount| hash_leaf(&test.distributor, &claimant.pubkey(), max_amount);
+    let first_leaf = leaf(&test, 2 * AMOUNT);
+    test.set_merkle_root(hash_node(&first_leaf, &SIBLING)).await.unwrap();
+    test.set_time(GENESIS + DAY).await.unwrap();
+    test.warp_to(GENESIS + DAY).await;
+    let token_account = test.create_token_account(&claimant.pubkey()).await;
+
+    // The first claim proves the allocation and caches its leaf
+    assert_error(
+        test.claim_partial(&claimant, &token_account, 2 * AMOUNT, vec![], Some(AMOUNT)).await,
+        TokenDistributorError::InvalidProof,
+    );
+    test.claim_partial(&claimant, &token_account, 2 * AMOUNT, vec![SIBLING], Some(AMOUNT)).await.unwrap();
+    let status = test.claim_status(&claimant.pubkey()).await.unwrap();
+    assert_eq!(status.verified_leaf, first_leaf);
+    assert!(status.has_verified_leaf(&first_leaf, test.distributor().await.root_update_count));
+
+    // Later claims of the same allocation omit the proof, other allocations still need one
+    assert_error(test.claim(&claimant, &token_account, 3 * AMOUNT, vec![]).await, TokenDistributorError::InvalidProof);
+    test.claim_partial(&claimant, &token_account, 2 * AMOUNT, vec![], Some(AMOUNT / 2)).await.unwrap();
+    test.claim(&claimant, &token_account, 2 * AMOUNT, vec![]).await.unwrap();
+    assert_eq!(test.token_balance(&token_account).await, 2 * AMOUNT);
+
+    // A root update invalidates the cache, the new allocation is proven once again
+    let second_leaf = leaf(&test, 4 * AMOUNT);
+    test.set_merkle_root(hash_node(&second_leaf, &SIBLING)).await.unwrap();
+    assert_error(
+        test.claim_partial(&claimant, &token_account, 4 * AMOUNT, vec![], Some(AMOUNT)).await,
+        TokenDistributorError::InvalidProof,
+    );
+    test.claim_partial(&claimant, &token_account, 4 * AMOUNT, vec![SIBLING], Some(AMOUNT)).await.unwrap();
+    test.claim(&claimant, &token_account, 4 * AMOUNT, vec![]).await.unwrap();
+    assert_eq!(test.token_balance(&token_account).await, 4 * AMOUNT);
+}
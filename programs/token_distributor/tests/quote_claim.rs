@@ -0,0 +1,308 @@
+//! Quotes of quote_claim matching what claim pays, with unlock times, early bonus and fees
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::system_program;
+use anchor_lang::{AnchorDeserialize, InstructionData, ToAccountMetas};
+use anchor_spl::token_2022::spl_token_2022;
+use common::*;
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::Transaction;
+use token_distributor::constants::UNLOCK_TIME_TREE_VERSION;
+use token_distributor::error::TokenDistributorError;
+use token_distributor::instructions::ClaimQuote;
+use token_distributor::utils::verify::{hash_leaf_with_unlock_time, hash_node};
+use token_distributor::{accounts, instruction, ID};
+
+const DURATION: i64 = 7 * DAY;
+const AMOUNT: u64 = 1_000_000;
+const START_TIME: i64 = GENESIS + DAY;
+const BONUS_POOL: u64 = AMOUNT * 7 / 8;
+#[cfg(feature = "claim-fee")]
+const CLAIM_FEE: u64 = 10_000;
+#[cfg(feature = "protocol-fee")]
+const PROTOCOL_FEE_BPS: u16 = 100;
+
+/// A claimant's allocation in the unlock time tree, with their proof
+struct Allocation {
+    claimant: Keypair,
+    token_account: Pubkey,
+    unlock_time: i64,
+    proof: Vec<[u8; 32]>,
+}
+
+async fn set_early_bonus(test: &mut TestDistributor, early_bonus_bps: u16, bonus_window: i64, bonus_pool: u64) {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::SetEarlyBonus {
+            distributor: test.distributor,
+            token_vault: test.vault,
+            token_mint: test.mint,
+            owner_token_account: test.owner_token_account,
+            owner: test.owner.pubkey(),
+            token_program: test.token_program,
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::SetEarlyBonus { early_bonus_bps, bonus_window, bonus_pool }.data(),
+    };
+    test.send(&[ix], &[]).await.unwrap();
+}
+
+#[cfg(feature = "claim-fee")]
+async fn set_claim_fee(test: &mut TestDistributor, claim_fee: u64) {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::SetClaimFee {
+            distributor: test.distributor,
+            owner: test.owner.pubkey(),
+            config: config(),
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::SetClaimFee { claim_fee }.data(),
+    };
+    test.send(&[ix], &[]).await.unwrap();
+}
+
+/// Writes a program config taking `protocol_fee_bps` of each claim for `protocol_fee_receiver`
+#[cfg(feature = "protocol-fee")]
+async fn set_protocol_fee(test: &mut TestDistributor, protocol_fee_bps: u16, protocol_fee_receiver: Pubkey) {
+    use anchor_lang::AccountSerialize;
+    use solana_sdk::account::Account;
+    use token_distributor::constants::CONFIG_SEED;
+    use token_distributor::state::ProgramConfig;
+
+    let (_, bump) = Pubkey::find_program_address(&[CONFIG_SEED.as_bytes()], &ID);
+    let program_config = ProgramConfig {
+        bump,
+        admin: test.owner.pubkey(),
+        protocol_fee_bps,
+        protocol_fee_receiver,
+        ..ProgramConfig::with_defaults()
+    };
+    let mut data = Vec::with_capacity(ProgramConfig::LEN);
+    program_config.try_serialize(&mut data).unwrap();
+    data.resize(ProgramConfig::LEN, 0);
+    let lamports = test.minimum_balance(ProgramConfig::LEN).await;
+    let account = Account { lamports, data, owner: ID, executable: false, rent_epoch: 0 };
+    test.context.set_account(&config(), &account.into());
+}
+
+/// Simulates quote_claim for the allocation, as the claim would see it now
+async fn quote_claim(test: &mut TestDistributor, allocation: &Allocation) -> Result<ClaimQuote, BanksClientError> {
+    let claimant = allocation.claimant.pubkey();
+    let claim_status = claim_status_address(&test.distributor, &claimant);
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::QuoteClaim {
+            distributor: test.distributor,
+            claim_status: test.account(&claim_status).await.map(|_| claim_status),
+            token_vault: Some(test.vault),
+            sol_vault: None,
+            claimant,
+            #[cfg(feature = "claim-gates")]
+            gate_token_account: None,
+            #[cfg(feature = "claim-gates")]
+            attestation: None,
+            config: config(),
+        }
+        .to_account_metas(None),
+        data: instruction::QuoteClaim {
+            max_amount: AMOUNT,
+            proof: allocation.proof.clone(),
+            unlock_time: Some(allocation.unlock_time),
+        }
+        .data(),
+    };
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&test.owner.pubkey()), &[&test.owner], test.context.last_blockhash);
+    let simulation = test.context.banks_client.simulate_transaction(tx).await?;
+    simulation.result.unwrap().map_err(BanksClientError::TransactionError)?;
+    let return_data = simulation.simulation_details.unwrap().return_data.unwrap();
+    Ok(ClaimQuote::deserialize(&mut return_data.data.as_slice()).unwrap())
+}
+
+/// Claims `amount` (all that is pending if `None`) of the allocation, paying any protocol fee into `protocol_fee_account`
+async fn claim(
+    test: &mut TestDistributor,
+    allocation: &Allocation,
+    amount: Option<u64>,
+    protocol_fee_account: Option<Pubkey>,
+) -> Result<u64, BanksClientError> {
+    let claimant = allocation.claimant.pubkey();
+    let mut accounts = accounts::Claim {
+        distributor: test.distributor,
+        claim_status: claim_status_address(&test.distributor, &claimant),
+        token_vault: test.vault,
+        shared_vault: None,
+        claimant_token_account: allocation.token_account,
+        token_mint: test.mint,
+        claimant,
+        delegate: None,
+        payer: test.owner.pubkey(),
+        system_program: system_program::ID,
+        token_program: test.token_program,
+        memo_program: None,
+        #[cfg(feature = "protocol-fee")]
+        protocol_fee_account,
+        #[cfg(feature = "claim-gates")]
+        gate_token_account: None,
+        #[cfg(feature = "claim-gates")]
+        attestation: None,
+        config: config(),
+        #[cfg(feature = "claim-log")]
+        claim_log: None,
+        instructions_sysvar: None,
+        #[cfg(feature = "claim-escrow")]
+        claim_escrow: None,
+        #[cfg(feature = "claim-escrow")]
+        escrow_vault: None,
+        event_authority: event_authority(),
+        program: ID,
+    }
+    .to_account_metas(None);
+    #[cfg(not(feature = "protocol-fee"))]
+    let _ = protocol_fee_account;
+    accounts.iter_mut().find(|meta| meta.pubkey == claimant).unwrap().is_signer = true;
+    let ix = Instruction {
+        program_id: ID,
+        accounts,
+        data: instruction::Claim {
+            max_amount: AMOUNT,
+            proof: allocation.proof.clone(),
+            amount,
+            unlock_time: Some(allocation.unlock_time),
+            cosign_expiry: None,
+        }
+        .data(),
+    };
+    test.send(&[ix], &[&allocation.claimant]).await
+}
+
+async fn lamports(test: &mut TestDistributor, address: &Pubkey) -> u64 {
+    test.account(address).await.map_or(0, |account| account.lamports)
+}
+
+/// Quotes the pending claim of the allocation, claims it and checks the claim paid out exactly the quote
+async fn claim_as_quoted(test: &mut TestDistributor, allocation: &Allocation, protocol_fee_account: &Pubkey) -> ClaimQuote {
+    let quote = quote_claim(test, allocation).await.unwrap();
+    let distributor = test.distributor;
+    let claimed_before = test.claim_status(&allocation.claimant.pubkey()).await.map_or(0, |status| status.claimed_amount);
+    let balance = test.token_balance(&allocation.token_account).await;
+    let fee_balance = test.token_balance(protocol_fee_account).await;
+    let distributor_lamports = lamports(test, &distributor).await;
+    let bonus_paid = test.distributor().await.total_bonus_paid;
+    assert_eq!(quote.claimed_amount, claimed_before);
+
+    claim(test, allocation, None, Some(*protocol_fee_account)).await.unwrap();
+    let received = test.token_balance(&allocation.token_account).await - balance;
+    assert_eq!(received, quote.claimable_amount + quote.bonus_amount - quote.protocol_fee);
+    assert_eq!(test.token_balance(protocol_fee_account).await - fee_balance, quote.protocol_fee);
+    assert_eq!(lamports(test, &distributor).await - distributor_lamports, quote.claim_fee);
+    assert_eq!(test.distributor().await.total_bonus_paid - bonus_paid, quote.bonus_amount);
+    assert_eq!(
+        test.claim_status(&allocation.claimant.pubkey()).await.unwrap().claimed_amount,
+        claimed_before + quote.claimable_amount
+    );
+    quote
+}
+
+/// Each quote matches the claim that follows it, with the unlock time, early bonus and fees in play
+#[tokio::test]
+async fn test_quote_matches_claim() {
+    let mut test = TestDistributor::new(false, 2 * AMOUNT, DURATION, Some(UNLOCK_TIME_TREE_VERSION)).await;
+    let (alice, bob) = (Keypair::new(), Keypair::new());
+    let (alice_unlock, bob_unlock) = (START_TIME, START_TIME + DAY / 2);
+    let alice_leaf = hash_leaf_with_unlock_time(&test.distributor, &alice.pubkey(), AMOUNT, alice_unlock);
+    let bob_leaf = hash_leaf_with_unlock_time(&test.distributor, &bob.pubkey(), AMOUNT, bob_unlock);
+    test.set_merkle_root(hash_node(&alice_leaf, &bob_leaf)).await.unwrap();
+    test.set_time(START_TIME).await.unwrap();
+    let alice = Allocation {
+        token_account: test.create_token_account(&alice.pubkey()).await,
+        claimant: alice,
+        unlock_time: alice_unlock,
+        proof: vec![bob_leaf],
+    };
+    let bob = Allocation {
+        token_account: test.create_token_account(&bob.pubkey()).await,
+        claimant: bob,
+        unlock_time: bob_unlock,
+        proof: vec![alice_leaf],
+    };
+
+    // Claims in the first day earn 50% on top, out of a pool too small to pay both in full
+    let mint_pool = spl_token_2022::instruction::mint_to(
+        &test.token_program,
+        &test.mint,
+        &test.owner_token_account,
+        &test.owner.pubkey(),
+        &[],
+        BONUS_POOL,
+    )
+    .unwrap();
+    test.send(&[mint_pool], &[]).await.unwrap();
+    set_early_bonus(&mut test, 5_000, DAY, BONUS_POOL).await;
+    #[cfg(feature = "claim-fee")]
+    set_claim_fee(&mut test, CLAIM_FEE).await;
+    let protocol_fee_receiver = Keypair::new().pubkey();
+    let protocol_fee_account = test.create_token_account(&protocol_fee_receiver).await;
+    #[cfg(feature = "protocol-fee")]
+    set_protocol_fee(&mut test, PROTOCOL_FEE_BPS, protocol_fee_receiver).await;
+    test.warp_to(START_TIME).await;
+
+    // A locked allocation is refused by the quote as by the claim
+    assert_error(quote_claim(&mut test, &bob).await, TokenDistributorError::ClaimLocked);
+    assert_error(claim(&mut test, &bob, None, Some(protocol_fee_account)).await, TokenDistributorError::ClaimLocked);
+
+    // The first claim earns the full bonus
+    let quote = claim_as_quoted(&mut test, &alice, &protocol_fee_account).await;
+    assert_eq!((quote.claimable_amount, quote.bonus_amount), (AMOUNT, AMOUNT / 2));
+    #[cfg(feature = "claim-fee")]
+    assert_eq!(quote.claim_fee, CLAIM_FEE);
+    #[cfg(feature = "protocol-fee")]
+    assert_eq!(quote.protocol_fee, AMOUNT * PROTOCOL_FEE_BPS as u64 / 10_000);
+
+    // Once unlocked, a partial claim is quoted for the rest of the allocation and the rest of the pool
+    test.warp_to(bob_unlock).await;
+    claim(&mut test, &bob, Some(AMOUNT / 2), Some(protocol_fee_account)).await.unwrap();
+    let quote = claim_as_quoted(&mut test, &bob, &protocol_fee_account).await;
+    assert_eq!((quote.claimed_amount, quote.claimable_amount), (AMOUNT / 2, AMOUNT / 2));
+    assert_eq!(quote.bonus_amount, BONUS_POOL - AMOUNT / 2 - AMOUNT / 4);
+    assert_eq!(test.distributor().await.total_bonus_paid, BONUS_POOL);
+}
+
+/// Past the bonus window the quote drops the bonus, as the claim does
+#[tokio::test]
+async fn test_quote_matches_claim_after_bonus_window() {
+    let mut test = TestDistributor::new(false, AMOUNT, DURATION, Some(UNLOCK_TIME_TREE_VERSION)).await;
+    let claimant = Keypair::new();
+    let unlock_time = START_TIME + 2 * DAY;
+    test.set_merkle_root(hash_leaf_with_unlock_time(&test.distributor, &claimant.pubkey(), AMOUNT, unlock_time))
+        .await
+        .unwrap();
+    test.set_time(START_TIME).await.unwrap();
+    let allocation = Allocation {
+        token_account: test.create_token_account(&claimant.pubkey()).await,
+        claimant,
+        unlock_time,
+        proof: vec![],
+    };
+    set_early_bonus(&mut test, 5_000, DAY, 0).await;
+    #[cfg(feature = "claim-fee")]
+    set_claim_fee(&mut test, CLAIM_FEE).await;
+    let protocol_fee_receiver = Keypair::new().pubkey();
+    let protocol_fee_account = test.create_token_account(&protocol_fee_receiver).await;
+    #[cfg(feature = "protocol-fee")]
+    set_protocol_fee(&mut test, PROTOCOL_FEE_BPS, protocol_fee_receiver).await;
+
+    test.warp_to(unlock_time - 1).await;
+    assert_error(quote_claim(&mut test, &allocation).await, TokenDistributorError::ClaimLocked);
+    test.warp_to(unlock_time).await;
+    let quote = claim_as_quoted(&mut test, &allocation, &protocol_fee_account).await;
+    assert_eq!((quote.claimable_amount, quote.bonus_amount), (AMOUNT, 0));
+}
@@ -0,0 +1,136 @@
+//! Listing distributors in their mint registry and removing them once closed
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::system_program;
+use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
+use common::*;
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+use token_distributor::constants::{OWNER_NONCE_SEED, REGISTRY_SEED};
+use token_distributor::error::TokenDistributorError;
+use token_distributor::state::MintRegistry;
+use token_distributor::utils::verify::hash_leaf;
+use token_distributor::{accounts, instruction, ID};
+
+const DURATION: i64 = 7 * DAY;
+const AMOUNT: u64 = 1_000_000;
+
+fn registry(test: &TestDistributor) -> Pubkey {
+    pda(&[REGISTRY_SEED.as_bytes(), test.mint.as_ref()])
+}
+
+/// Syncs the distributor into its registry, authorized by `authority` (the owner, who is the mint authority, if none)
+async fn sync_registry(test: &mut TestDistributor, authority: Option<&Keypair>) -> Result<u64, BanksClientError> {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::SyncRegistry {
+            distributor: test.distributor,
+            registry: registry(test),
+            token_mint: test.mint,
+            authority: authority.map_or(test.owner.pubkey(), |authority| authority.pubkey()),
+            payer: test.owner.pubkey(),
+            config: config(),
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::SyncRegistry {}.data(),
+    };
+    test.send(&[ix], authority.as_slice()).await
+}
+
+/// Evicts the distributor from its registry, signed by `authority` when given
+async fn evict_registry_entry(test: &mut TestDistributor, authority: Option<&Keypair>) -> Result<u64, BanksClientError> {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::EvictRegistryEntry {
+            registry: registry(test),
+            distributor: test.distributor,
+            token_mint: test.mint,
+            authority: authority.map(|authority| authority.pubkey()),
+            config: config(),
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::EvictRegistryEntry {}.data(),
+    };
+    test.send(&[ix], authority.as_slice()).await
+}
+
+/// Withdraws the vault back to the owner and closes the distributor, passing its registry
+async fn withdraw(test: &mut TestDistributor) -> Result<u64, BanksClientError> {
+    let owner = test.owner.pubkey();
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::Withdraw {
+            distributor: test.distributor,
+            token_vault: test.vault,
+            owner_token_account: test.owner_token_account,
+            token_mint: test.mint,
+            owner,
+            owner_nonce: pda(&[OWNER_NONCE_SEED.as_bytes(), owner.as_ref()]),
+            token_program: test.token_program,
+            event_authority: event_authority(),
+            program: ID,
+            registry: Some(registry(test)),
+        }
+        .to_account_metas(None),
+        data: instruction::Withdraw {}.data(),
+    };
+    test.send(&[ix], &[]).await
+}
+
+async fn is_listed(test: &mut TestDistributor) -> bool {
+    let address = registry(test);
+    let data = test.account(&address).await.unwrap().data;
+    MintRegistry::try_deserialize(&mut data.as_slice()).unwrap().is_listed(&test.distributor)
+}
+
+/// Only the mint authority lists and takes down live distributors, withdraw removes the closed one
+#[tokio::test]
+async fn test_registry_listing() {
+    let mut test = TestDistributor::new(true, AMOUNT, DURATION, None).await;
+    test.set_time(GENESIS + DAY).await.unwrap();
+    let stranger = Keypair::new();
+
+    // Anyone else's listing is rejected
+    assert_error(sync_registry(&mut test, Some(&stranger)).await, TokenDistributorError::RegistryUnauthorized);
+    sync_registry(&mut test, None).await.unwrap();
+    assert!(is_listed(&mut test).await);
+
+    // A live distributor is only evicted by the mint authority
+    assert_error(evict_registry_entry(&mut test, None).await, TokenDistributorError::RegistryUnauthorized);
+    assert_error(evict_registry_entry(&mut test, Some(&stranger)).await, TokenDistributorError::RegistryUnauthorized);
+    let owner = test.owner.insecure_clone();
+    evict_registry_entry(&mut test, Some(&owner)).await.unwrap();
+    assert!(!is_listed(&mut test).await);
+    assert_error(evict_registry_entry(&mut test, Some(&owner)).await, TokenDistributorError::RegistryEntryNotFound);
+
+    // Withdrawing closes the distributor and removes it from the registry
+    sync_registry(&mut test, None).await.unwrap();
+    test.warp_to(GENESIS + DAY + DURATION + 1).await;
+    withdraw(&mut test).await.unwrap();
+    assert!(!is_listed(&mut test).await);
+}
+
+/// Anyone evicts a distributor that has nothing left to claim
+#[tokio::test]
+async fn test_evict_completed_distributor() {
+    let mut test = TestDistributor::new(false, AMOUNT, DURATION, None).await;
+    let claimant = Keypair::new();
+    test.set_merkle_root(hash_leaf(&test.distributor, &claimant.pubkey(), AMOUNT)).await.unwrap();
+    test.set_time(GENESIS + DAY).await.unwrap();
+    sync_registry(&mut test, None).await.unwrap();
+    test.warp_to(GENESIS + DAY).await;
+    let token_account = test.create_token_account(&claimant.pubkey()).await;
+
+    // The claim emptying the vault completes the distribution
+    test.claim(&claimant, &token_account, AMOUNT, vec![]).await.unwrap();
+    evict_registry_entry(&mut test, None).await.unwrap();
+    assert!(!is_listed(&mut test).await);
+}
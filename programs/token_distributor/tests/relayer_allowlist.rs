@@ -0,0 +1,122 @@
+//! Relayer allowlist of claims submitted with a claimant signature
+#![cfg(feature = "claim-with-signature")]
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::{system_instruction, system_program, sysvar};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use common::*;
+use solana_program_test::BanksClientError;
+use solana_sdk::ed25519_instruction::new_ed25519_instruction_with_signature;
+use solana_sdk::signature::{Keypair, Signer};
+use token_distributor::error::TokenDistributorError;
+use token_distributor::utils::claim_message;
+use token_distributor::utils::verify::hash_leaf;
+use token_distributor::{accounts, instruction, ID};
+
+const DURATION: i64 = 7 * DAY;
+const AMOUNT: u64 = 1_000_000;
+
+/// Adds (`add`) or removes the relayer from the distributor's relayer allowlist, signed by the owner
+async fn set_relayer(test: &mut TestDistributor, relayer: Pubkey, add: bool) -> Result<u64, BanksClientError> {
+    let (distributor, owner) = (test.distributor, test.owner.pubkey());
+    let ix = if add {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::AddRelayer { distributor, owner, event_authority: event_authority(), program: ID }
+                .to_account_metas(None),
+            data: instruction::AddRelayer { relayer }.data(),
+        }
+    } else {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::RemoveRelayer { distributor, owner, event_authority: event_authority(), program: ID }
+                .to_account_metas(None),
+            data: instruction::RemoveRelayer { relayer }.data(),
+        }
+    };
+    test.send(&[ix], &[]).await
+}
+
+/// Claims `amount` of the claimant's single-leaf allocation, signed by the claimant and submitted by the relayer
+async fn claim_with_signature(
+    test: &mut TestDistributor,
+    claimant: &Keypair,
+    token_account: &Pubkey,
+    relayer: &Keypair,
+    amount: Option<u64>,
+) -> Result<u64, BanksClientError> {
+    let voucher_nonce = test.claim_status(&claimant.pubkey()).await.map_or(0, |status| status.voucher_nonce);
+    let expiry = GENESIS + DURATION;
+    let message = claim_message(&test.distributor, &claimant.pubkey(), AMOUNT, amount.unwrap_or(0), voucher_nonce, expiry);
+    let signature: [u8; 64] = claimant.sign_message(&message).as_ref().try_into().unwrap();
+    let verify = new_ed25519_instruction_with_signature(&message, &signature, &claimant.pubkey().to_bytes());
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::ClaimWithSignature {
+            distributor: test.distributor,
+            claim_status: claim_status_address(&test.distributor, &claimant.pubkey()),
+            token_vault: test.vault,
+            shared_vault: None,
+            claimant_token_account: *token_account,
+            token_mint: test.mint,
+            claimant: claimant.pubkey(),
+            payer: relayer.pubkey(),
+            instructions_sysvar: sysvar::instructions::ID,
+            system_program: system_program::ID,
+            token_program: test.token_program,
+            memo_program: None,
+            #[cfg(feature = "protocol-fee")]
+            protocol_fee_account: None,
+            #[cfg(feature = "claim-gates")]
+            gate_token_account: None,
+            #[cfg(feature = "claim-gates")]
+            attestation: None,
+            config: config(),
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::ClaimWithSignature { max_amount: AMOUNT, proof: vec![], amount, unlock_time: None, expiry }.data(),
+    };
+    test.send(&[verify, ix], &[relayer]).await
+}
+
+/// Listed relayers alone submit signed claims, an empty allowlist lets any relayer submit them
+#[tokio::test]
+async fn test_relayer_allowlist() {
+    let mut test = TestDistributor::new(false, AMOUNT, DURATION, None).await;
+    let (claimant, relayer, other_relayer) = (Keypair::new(), Keypair::new(), Keypair::new());
+    for key in [relayer.pubkey(), other_relayer.pubkey()] {
+        let fund = system_instruction::transfer(&test.owner.pubkey(), &key, 1_000_000_000);
+        test.send(&[fund], &[]).await.unwrap();
+    }
+    test.set_merkle_root(hash_leaf(&test.distributor, &claimant.pubkey(), AMOUNT)).await.unwrap();
+    test.set_time(GENESIS + DAY).await.unwrap();
+    test.warp_to(GENESIS + DAY).await;
+    let token_account = test.create_token_account(&claimant.pubkey()).await;
+
+    // The owner lists the sponsoring relayer, once
+    assert_error(set_relayer(&mut test, Pubkey::default(), true).await, TokenDistributorError::InvalidRelayer);
+    set_relayer(&mut test, relayer.pubkey(), true).await.unwrap();
+    assert_error(set_relayer(&mut test, relayer.pubkey(), true).await, TokenDistributorError::RelayerAlreadyExists);
+    let distributor = test.distributor().await;
+    assert!(distributor.is_relayer(&relayer.pubkey()));
+    assert!(!distributor.is_allowed_relayer(&other_relayer.pubkey()));
+
+    // Only the listed relayer can submit the claimant's signed claims
+    assert_error(
+        claim_with_signature(&mut test, &claimant, &token_account, &other_relayer, Some(AMOUNT / 2)).await,
+        TokenDistributorError::RelayerNotAllowed,
+    );
+    claim_with_signature(&mut test, &claimant, &token_account, &relayer, Some(AMOUNT / 2)).await.unwrap();
+    assert_eq!(test.token_balance(&token_account).await, AMOUNT / 2);
+
+    // Removing the last relayer opens signed claims to any relayer again
+    set_relayer(&mut test, relayer.pubkey(), false).await.unwrap();
+    assert_error(set_relayer(&mut test, relayer.pubkey(), false).await, TokenDistributorError::RelayerNotFound);
+    claim_with_signature(&mut test, &claimant, &token_account, &other_relayer, None).await.unwrap();
+    assert_eq!(test.token_balance(&token_account).await, AMOUNT);
+}
@@ -0,0 +1,167 @@
+//! Rent escrow paying for claim statuses on behalf of claimants without SOL
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::system_program;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use common::*;
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+use token_distributor::constants::RENT_ESCROW_SEED;
+use token_distributor::error::TokenDistributorError;
+use token_distributor::state::ClaimStatus;
+use token_distributor::utils::verify::hash_leaf;
+use token_distributor::{accounts, instruction, ID};
+
+const DURATION: i64 = 7 * DAY;
+const AMOUNT: u64 = 1_000_000;
+
+fn rent_escrow(test: &TestDistributor, owner: &Pubkey) -> Pubkey {
+    pda(&[RENT_ESCROW_SEED.as_bytes(), test.distributor.as_ref(), owner.as_ref()])
+}
+
+async fn fund_rent_escrow(test: &mut TestDistributor, amount: u64) -> Result<u64, BanksClientError> {
+    let owner = test.owner.pubkey();
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::FundRentEscrow {
+            distributor: test.distributor,
+            rent_escrow: rent_escrow(test, &owner),
+            owner,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::FundRentEscrow { amount }.data(),
+    };
+    test.send(&[ix], &[]).await
+}
+
+async fn create_claim_status(test: &mut TestDistributor, claimant: &Keypair) -> Result<u64, BanksClientError> {
+    let owner = test.owner.pubkey();
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::CreateClaimStatus {
+            distributor: test.distributor,
+            rent_escrow: rent_escrow(test, &owner),
+            claim_status: claim_status_address(&test.distributor, &claimant.pubkey()),
+            claimant: claimant.pubkey(),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: instruction::CreateClaimStatus {}.data(),
+    };
+    test.send(&[ix], &[claimant]).await
+}
+
+async fn close_claim_status(test: &mut TestDistributor, claimant: &Keypair, rent_payer: &Pubkey) -> Result<u64, BanksClientError> {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::CloseClaimStatus {
+            claim_status: claim_status_address(&test.distributor, &claimant.pubkey()),
+            claimant: claimant.pubkey(),
+            rent_payer: *rent_payer,
+            distributor_key: test.distributor,
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::CloseClaimStatus {}.data(),
+    };
+    test.send(&[ix], &[claimant]).await
+}
+
+/// Withdraws the escrow derived from `owner`, signed by `owner` along with the test owner paying the fee
+async fn withdraw_rent_escrow(test: &mut TestDistributor, owner: &Keypair) -> Result<u64, BanksClientError> {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::WithdrawRentEscrow {
+            rent_escrow: rent_escrow(test, &owner.pubkey()),
+            owner: owner.pubkey(),
+            distributor_key: test.distributor,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::WithdrawRentEscrow {}.data(),
+    };
+    test.send(&[ix], &[owner]).await
+}
+
+async fn lamports(test: &mut TestDistributor, address: &Pubkey) -> u64 {
+    test.account(address).await.map_or(0, |account| account.lamports)
+}
+
+/// The escrow pays claim status rent while staying rent exempt, gets it back on close and is withdrawn by the owner
+#[tokio::test]
+async fn test_rent_escrow() {
+    let mut test = TestDistributor::new(false, AMOUNT, DURATION, None).await;
+    let owner = test.owner.pubkey();
+    let escrow = rent_escrow(&test, &owner);
+    let claim_rent = test.minimum_balance(ClaimStatus::LEN).await;
+    let escrow_reserve = test.minimum_balance(0).await;
+
+    // A claimant holding no SOL at all
+    let claimant = Keypair::new();
+    test.set_merkle_root(hash_leaf(&test.distributor, &claimant.pubkey(), AMOUNT)).await.unwrap();
+    let start_time = GENESIS + DAY;
+    test.set_time(start_time).await.unwrap();
+    test.warp_to(start_time).await;
+
+    // The escrow must keep its own rent exemption after paying
+    assert_error(fund_rent_escrow(&mut test, 0).await, TokenDistributorError::InvalidAmount);
+    fund_rent_escrow(&mut test, claim_rent).await.unwrap();
+    assert_eq!(lamports(&mut test, &escrow).await, claim_rent);
+    assert_error(create_claim_status(&mut test, &claimant).await, TokenDistributorError::InsufficientRentEscrow);
+    fund_rent_escrow(&mut test, escrow_reserve).await.unwrap();
+    create_claim_status(&mut test, &claimant).await.unwrap();
+    assert_eq!(lamports(&mut test, &escrow).await, escrow_reserve);
+    assert_eq!(test.claim_status(&claimant.pubkey()).await.unwrap().rent_payer, escrow);
+    assert_error(create_claim_status(&mut test, &claimant).await, TokenDistributorError::ClaimStatusAlreadyExists);
+
+    // The claim goes through on the existing account
+    let token_account = test.create_token_account(&claimant.pubkey()).await;
+    test.claim(&claimant, &token_account, AMOUNT, vec![]).await.unwrap();
+    assert_eq!(test.token_balance(&token_account).await, AMOUNT);
+    assert_eq!(lamports(&mut test, &claimant.pubkey()).await, 0);
+
+    // Closing refunds the escrow, not the claimant or the owner
+    test.warp_to(start_time + DURATION + 1).await;
+    assert_error(close_claim_status(&mut test, &claimant, &owner).await, TokenDistributorError::RentPayerMismatch);
+    assert_error(
+        close_claim_status(&mut test, &claimant, &claimant.pubkey()).await,
+        TokenDistributorError::RentPayerMismatch,
+    );
+    close_claim_status(&mut test, &claimant, &escrow).await.unwrap();
+    assert_eq!(lamports(&mut test, &escrow).await, claim_rent + escrow_reserve);
+    assert_eq!(lamports(&mut test, &claimant.pubkey()).await, 0);
+
+    // Only the owner withdraws the escrow, a stranger derives an empty one of their own
+    let stranger = Keypair::new();
+    assert_error(withdraw_rent_escrow(&mut test, &stranger).await, TokenDistributorError::InsufficientRentEscrow);
+    let owner_keypair = test.owner.insecure_clone();
+    let balance = lamports(&mut test, &owner).await;
+    withdraw_rent_escrow(&mut test, &owner_keypair).await.unwrap();
+    assert!(test.account(&escrow).await.is_none());
+    // The owner pays the transaction fee of 5,000 lamports
+    assert_eq!(lamports(&mut test, &owner).await, balance + claim_rent + escrow_reserve - 5_000);
+    assert_error(withdraw_rent_escrow(&mut test, &owner_keypair).await, TokenDistributorError::InsufficientRentEscrow);
+}
+
+/// The escrow stays locked until the distribution has ended
+#[tokio::test]
+async fn test_withdraw_rent_escrow_before_end() {
+    let mut test = TestDistributor::new(false, AMOUNT, DURATION, None).await;
+    fund_rent_escrow(&mut test, 1_000_000).await.unwrap();
+    let owner = test.owner.insecure_clone();
+    assert_error(withdraw_rent_escrow(&mut test, &owner).await, TokenDistributorError::DistributionNotEnded);
+    test.set_time(GENESIS + DAY).await.unwrap();
+    test.warp_to(GENESIS + DAY + DURATION).await;
+    assert_error(withdraw_rent_escrow(&mut test, &owner).await, TokenDistributorError::DistributionNotEnded);
+    test.warp_to(GENESIS + DAY + DURATION + 1).await;
+    withdraw_rent_escrow(&mut test, &owner).await.unwrap();
+}
@@ -0,0 +1,44 @@
+//! Claims proven against merkle roots replaced by a later root update
+
+mod common;
+
+use common::*;
+use solana_sdk::signature::{Keypair, Signer};
+use token_distributor::constants::ROOT_GRACE_PERIOD;
+use token_distributor::error::TokenDistributorError;
+use token_distributor::utils::verify::{hash_leaf, hash_node};
+
+const DURATION: i64 = 7 * DAY;
+const AMOUNT: u64 = 1_000_000;
+
+/// Sibling leaf of the claimants' leaves in the two-leaf trees below
+const SIBLING: [u8; 32] = [7; 32];
+
+/// A replaced root stays claimable for the grace period after the update replacing it, not beyond
+#[tokio::test]
+async fn test_historical_root_grace_period() {
+    let mut test = TestDistributor::new(false, 2 * AMOUNT, DURATION, None).await;
+    let (early, late) = (Keypair::new(), Keypair::new());
+    let early_leaf = hash_leaf(&test.distributor, &early.pubkey(), AMOUNT);
+    let late_leaf = hash_leaf(&test.distributor, &late.pubkey(), AMOUNT);
+    test.set_merkle_root(hash_node(&early_leaf, &late_leaf)).await.unwrap();
+    test.set_time(GENESIS + DAY).await.unwrap();
+    test.warp_to(GENESIS + DAY).await;
+    let early_account = test.create_token_account(&early.pubkey()).await;
+    let late_account = test.create_token_account(&late.pubkey()).await;
+
+    // The operator revokes both allocations with a new root
+    test.set_merkle_root(hash_node(&SIBLING, &SIBLING)).await.unwrap();
+
+    // Proofs of the replaced root still land within the grace period
+    test.warp_to(GENESIS + DAY + ROOT_GRACE_PERIOD).await;
+    test.claim(&early, &early_account, AMOUNT, vec![late_leaf]).await.unwrap();
+    assert_eq!(test.token_balance(&early_account).await, AMOUNT);
+
+    // After it the replaced root is no longer claimable
+    test.warp_to(GENESIS + DAY + ROOT_GRACE_PERIOD + 1).await;
+    assert_error(
+        test.claim(&late, &late_account, AMOUNT, vec![early_leaf]).await,
+        TokenDistributorError::InvalidProof,
+    );
+}
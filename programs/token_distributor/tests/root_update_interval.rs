@@ -0,0 +1,80 @@
+//! Rate limiting of merkle root updates by the owner-configured interval
+
+mod common;
+
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use common::*;
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+use token_distributor::constants::MAX_ROOT_UPDATE_INTERVAL;
+use token_distributor::error::TokenDistributorError;
+use token_distributor::{accounts, instruction, ID};
+
+const DURATION: i64 = 7 * DAY;
+const AMOUNT: u64 = 1_000_000;
+const INTERVAL: i64 = 60 * 60;
+
+/// Sets the interval, signed by `owner` along with the test owner paying the fee
+async fn set_root_update_interval(test: &mut TestDistributor, owner: &Keypair, interval: i64) -> Result<u64, BanksClientError> {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::SetRootUpdateInterval {
+            distributor: test.distributor,
+            owner: owner.pubkey(),
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::SetRootUpdateInterval { root_update_interval: interval }.data(),
+    };
+    test.send(&[ix], &[owner]).await
+}
+
+/// Updates within the interval of the last one are rejected, the first root and later updates are not
+#[tokio::test]
+async fn test_root_update_interval() {
+    let mut test = TestDistributor::new(false, AMOUNT, DURATION, None).await;
+    let owner = test.owner.insecure_clone();
+    assert_error(set_root_update_interval(&mut test, &Keypair::new(), INTERVAL).await, TokenDistributorError::OnlyOwner);
+    assert_error(set_root_update_interval(&mut test, &owner, -1).await, TokenDistributorError::InvalidRootUpdateInterval);
+    assert_error(
+        set_root_update_interval(&mut test, &owner, MAX_ROOT_UPDATE_INTERVAL + 1).await,
+        TokenDistributorError::InvalidRootUpdateInterval,
+    );
+    set_root_update_interval(&mut test, &owner, INTERVAL).await.unwrap();
+
+    // The first root is never rate limited
+    test.set_merkle_root([1; 32]).await.unwrap();
+    assert_eq!(test.distributor().await.last_root_update_at, GENESIS);
+
+    test.warp_to(GENESIS + INTERVAL - 1).await;
+    assert_error(test.set_merkle_root([2; 32]).await, TokenDistributorError::RootUpdateTooFrequent);
+    assert_eq!(test.distributor().await.merkle_root, [1; 32]);
+
+    // The window restarts with each accepted update
+    test.warp_to(GENESIS + INTERVAL).await;
+    test.set_merkle_root([2; 32]).await.unwrap();
+    let distributor = test.distributor().await;
+    assert_eq!((distributor.merkle_root, distributor.last_root_update_at), ([2; 32], GENESIS + INTERVAL));
+    test.warp_to(GENESIS + 2 * INTERVAL - 1).await;
+    assert_error(test.set_merkle_root([3; 32]).await, TokenDistributorError::RootUpdateTooFrequent);
+    test.warp_to(GENESIS + 2 * INTERVAL).await;
+    test.set_merkle_root([3; 32]).await.unwrap();
+    assert_eq!(test.distributor().await.merkle_root, [3; 32]);
+}
+
+/// An interval of 0 lifts the limit right away
+#[tokio::test]
+async fn test_root_update_interval_disabled() {
+    let mut test = TestDistributor::new(false, AMOUNT, DURATION, None).await;
+    let owner = test.owner.insecure_clone();
+    set_root_update_interval(&mut test, &owner, INTERVAL).await.unwrap();
+    test.set_merkle_root([1; 32]).await.unwrap();
+    assert_error(test.set_merkle_root([2; 32]).await, TokenDistributorError::RootUpdateTooFrequent);
+
+    set_root_update_interval(&mut test, &owner, 0).await.unwrap();
+    test.set_merkle_root([2; 32]).await.unwrap();
+    test.set_merkle_root([3; 32]).await.unwrap();
+    assert_eq!(test.distributor().await.merkle_root, [3; 32]);
+}
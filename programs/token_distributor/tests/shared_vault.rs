@@ -0,0 +1,193 @@
+//! Distributors drawing budgets from a shared vault
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::system_program;
+use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
+use anchor_spl::token_2022::spl_token_2022;
+use common::*;
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+use token_distributor::constants::{DISTRIBUTOR_SEED, OWNER_NONCE_SEED, SHARED_VAULT_SEED, VAULT_SEED};
+use token_distributor::error::TokenDistributorError;
+use token_distributor::state::SharedVault;
+use token_distributor::utils::verify::{hash_leaf, hash_node};
+use token_distributor::{accounts, instruction, ID};
+
+const DURATION: i64 = 7 * DAY;
+const AMOUNT: u64 = 1_000_000;
+
+fn owner_nonce(test: &TestDistributor) -> Pubkey {
+    pda(&[OWNER_NONCE_SEED.as_bytes(), test.owner.pubkey().as_ref()])
+}
+
+fn shared_vault(test: &TestDistributor) -> Pubkey {
+    pda(&[SHARED_VAULT_SEED.as_bytes(), test.mint.as_ref(), test.owner.pubkey().as_ref()])
+}
+
+fn shared_token_vault(test: &TestDistributor) -> Pubkey {
+    pda(&[VAULT_SEED.as_bytes(), shared_vault(test).as_ref()])
+}
+
+/// Mints `amount` new tokens to the owner and deposits them into a new shared vault
+async fn create_shared_vault(test: &mut TestDistributor, amount: u64) {
+    let owner = test.owner.pubkey();
+    let mint_to =
+        spl_token_2022::instruction::mint_to(&test.token_program, &test.mint, &test.owner_token_account, &owner, &[], amount)
+            .unwrap();
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::CreateSharedVault {
+            shared_vault: shared_vault(test),
+            token_vault: shared_token_vault(test),
+            token_mint: test.mint,
+            owner_token_account: test.owner_token_account,
+            owner,
+            system_program: system_program::ID,
+            token_program: test.token_program,
+            config: config(),
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::CreateSharedVault { amount }.data(),
+    };
+    test.send(&[mint_to, ix], &[]).await.unwrap();
+}
+
+/// Creates the owner's distributor of the given nonce with a budget from the shared vault, and switches the test to it
+async fn create_shared_distributor(test: &mut TestDistributor, nonce: u32, budget: u64) -> Result<u64, BanksClientError> {
+    let owner = test.owner.pubkey();
+    let distributor = pda(&[DISTRIBUTOR_SEED.as_bytes(), test.mint.as_ref(), owner.as_ref(), &nonce.to_le_bytes()]);
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::CreateSharedDistributor {
+            owner_nonce: owner_nonce(test),
+            distributor,
+            shared_vault: shared_vault(test),
+            token_vault: shared_token_vault(test),
+            token_mint: test.mint,
+            owner,
+            operator: owner,
+            system_program: system_program::ID,
+            token_program: test.token_program,
+            config: config(),
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::CreateSharedDistributor {
+            budget,
+            duration: Some(DURATION),
+            tree_version: None,
+            withdraw_delay: None,
+        }
+        .data(),
+    };
+    let result = test.send(&[ix], &[]).await?;
+    test.distributor = distributor;
+    test.vault = shared_token_vault(test);
+    test.shared_vault = Some(shared_vault(test));
+    Ok(result)
+}
+
+async fn close_shared_distributor(test: &mut TestDistributor, distributor: &Pubkey) -> Result<u64, BanksClientError> {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::CloseSharedDistributor {
+            distributor: *distributor,
+            shared_vault: shared_vault(test),
+            owner: test.owner.pubkey(),
+            owner_nonce: owner_nonce(test),
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::CloseSharedDistributor {}.data(),
+    };
+    test.send(&[ix], &[]).await
+}
+
+async fn withdraw_shared_vault(test: &mut TestDistributor, amount: u64) -> Result<u64, BanksClientError> {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::WithdrawSharedVault {
+            shared_vault: shared_vault(test),
+            token_vault: shared_token_vault(test),
+            owner_token_account: test.owner_token_account,
+            token_mint: test.mint,
+            owner: test.owner.pubkey(),
+            token_program: test.token_program,
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::WithdrawSharedVault { amount }.data(),
+    };
+    test.send(&[ix], &[]).await
+}
+
+async fn allocated_amount(test: &mut TestDistributor) -> u64 {
+    let address = shared_vault(test);
+    let data = test.account(&address).await.unwrap().data;
+    SharedVault::try_deserialize(&mut data.as_slice()).unwrap().allocated_amount
+}
+
+/// Budgets are reserved out of the shared vault, claims consume them and closing releases the unclaimed rest
+#[tokio::test]
+async fn test_shared_vault_budgets() {
+    let mut test = TestDistributor::new(false, AMOUNT, DURATION, None).await;
+    create_shared_vault(&mut test, 4 * AMOUNT).await;
+    let token_vault = shared_token_vault(&test);
+
+    // Budgets are allocated from the unallocated balance only
+    create_shared_distributor(&mut test, 2, 3 * AMOUNT).await.unwrap();
+    let first = test.distributor;
+    assert_error(create_shared_distributor(&mut test, 3, 2 * AMOUNT).await, TokenDistributorError::InsufficientUnallocatedBalance);
+    create_shared_distributor(&mut test, 3, AMOUNT).await.unwrap();
+    let second = test.distributor;
+    assert_eq!(allocated_amount(&mut test).await, 4 * AMOUNT);
+
+    // Allocated tokens cannot be withdrawn, later deposits can
+    assert_error(withdraw_shared_vault(&mut test, 1).await, TokenDistributorError::InsufficientUnallocatedBalance);
+    let owner = test.owner.pubkey();
+    let deposit = spl_token_2022::instruction::mint_to(&test.token_program, &test.mint, &token_vault, &owner, &[], AMOUNT).unwrap();
+    test.send(&[deposit], &[]).await.unwrap();
+    assert_error(withdraw_shared_vault(&mut test, AMOUNT + 1).await, TokenDistributorError::InsufficientUnallocatedBalance);
+    withdraw_shared_vault(&mut test, AMOUNT).await.unwrap();
+    assert_eq!(test.token_balance(&token_vault).await, 4 * AMOUNT);
+
+    // Claims from the first distributor are capped by its budget and consume the allocation
+    test.distributor = first;
+    let (claimant, greedy) = (Keypair::new(), Keypair::new());
+    let leaf = hash_leaf(&first, &claimant.pubkey(), AMOUNT);
+    let greedy_leaf = hash_leaf(&first, &greedy.pubkey(), 3 * AMOUNT);
+    test.set_merkle_root(hash_node(&leaf, &greedy_leaf)).await.unwrap();
+    let start_time = GENESIS + DAY;
+    test.set_time(start_time).await.unwrap();
+    test.warp_to(start_time).await;
+    let token_account = test.create_token_account(&claimant.pubkey()).await;
+    let greedy_account = test.create_token_account(&greedy.pubkey()).await;
+    test.claim(&claimant, &token_account, AMOUNT, vec![greedy_leaf]).await.unwrap();
+    assert_error(test.claim(&greedy, &greedy_account, 3 * AMOUNT, vec![leaf]).await, TokenDistributorError::BudgetExceeded);
+    assert_eq!(test.token_balance(&token_account).await, AMOUNT);
+    assert_eq!(allocated_amount(&mut test).await, 3 * AMOUNT);
+    assert_error(withdraw_shared_vault(&mut test, 1).await, TokenDistributorError::InsufficientUnallocatedBalance);
+
+    // Closing releases the unclaimed budget, only once the distribution has ended
+    assert_error(close_shared_distributor(&mut test, &first).await, TokenDistributorError::DistributionNotEnded);
+    test.warp_to(start_time + DURATION + 1).await;
+    close_shared_distributor(&mut test, &first).await.unwrap();
+    assert!(test.account(&first).await.is_none());
+    assert_eq!(allocated_amount(&mut test).await, AMOUNT);
+    assert_error(withdraw_shared_vault(&mut test, 2 * AMOUNT + 1).await, TokenDistributorError::InsufficientUnallocatedBalance);
+    withdraw_shared_vault(&mut test, 2 * AMOUNT).await.unwrap();
+
+    // The never scheduled second distributor releases its whole budget
+    close_shared_distributor(&mut test, &second).await.unwrap();
+    assert_eq!(allocated_amount(&mut test).await, 0);
+    withdraw_shared_vault(&mut test, AMOUNT).await.unwrap();
+    assert_eq!(test.token_balance(&token_vault).await, 0);
+}
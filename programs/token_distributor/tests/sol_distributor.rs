@@ -0,0 +1,192 @@
+//! Native SOL distributors paying lamports out of a PDA vault that keeps its rent reserve
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::system_program;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::token::spl_token::native_mint;
+use common::*;
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+use token_distributor::constants::{DISTRIBUTOR_SEED, OWNER_NONCE_SEED, SOL_VAULT_SEED};
+use token_distributor::error::TokenDistributorError;
+use token_distributor::utils::verify::{hash_leaf, hash_node};
+use token_distributor::{accounts, instruction, ID};
+
+const DURATION: i64 = 7 * DAY;
+/// Lamports, large enough for claims to fund new system accounts
+const AMOUNT: u64 = 1_000_000_000;
+
+fn owner_nonce(test: &TestDistributor) -> Pubkey {
+    pda(&[OWNER_NONCE_SEED.as_bytes(), test.owner.pubkey().as_ref()])
+}
+
+/// Switches the test to a native SOL distributor of `deposit` lamports, the owner's second distributor
+async fn use_sol_distributor(test: &mut TestDistributor, deposit: u64) {
+    let owner = test.owner.pubkey();
+    let distributor = pda(&[DISTRIBUTOR_SEED.as_bytes(), native_mint::ID.as_ref(), owner.as_ref(), &2u32.to_le_bytes()]);
+    let sol_vault = pda(&[SOL_VAULT_SEED.as_bytes(), distributor.as_ref()]);
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::CreateSolDistributor {
+            owner_nonce: owner_nonce(test),
+            distributor,
+            sol_vault,
+            owner,
+            operator: owner,
+            system_program: system_program::ID,
+            config: config(),
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::CreateSolDistributor {
+            initial_total_amount: deposit,
+            duration: Some(DURATION),
+            tree_version: None,
+            withdraw_delay: None,
+        }
+        .data(),
+    };
+    test.send(&[ix], &[]).await.unwrap();
+    test.distributor = distributor;
+    test.vault = sol_vault;
+}
+
+/// Claims `amount` (all that is pending if `None`) of `max_amount` in lamports, the owner paying the rent
+async fn claim_sol(
+    test: &mut TestDistributor,
+    claimant: &Keypair,
+    max_amount: u64,
+    proof: Vec<[u8; 32]>,
+    amount: Option<u64>,
+) -> Result<u64, BanksClientError> {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::ClaimSol {
+            distributor: test.distributor,
+            claim_status: claim_status_address(&test.distributor, &claimant.pubkey()),
+            sol_vault: test.vault,
+            claimant: claimant.pubkey(),
+            payer: test.owner.pubkey(),
+            system_program: system_program::ID,
+            #[cfg(feature = "protocol-fee")]
+            protocol_fee_receiver: None,
+            #[cfg(feature = "claim-gates")]
+            gate_token_account: None,
+            #[cfg(feature = "claim-gates")]
+            attestation: None,
+            config: config(),
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::ClaimSol { max_amount, proof, amount, unlock_time: None }.data(),
+    };
+    test.send(&[ix], &[claimant]).await
+}
+
+async fn withdraw_sol(test: &mut TestDistributor) -> Result<u64, BanksClientError> {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::WithdrawSol {
+            distributor: test.distributor,
+            sol_vault: test.vault,
+            owner: test.owner.pubkey(),
+            owner_nonce: owner_nonce(test),
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: ID,
+        }
+        .to_account_metas(None),
+        data: instruction::WithdrawSol {}.data(),
+    };
+    test.send(&[ix], &[]).await
+}
+
+async fn lamports(test: &mut TestDistributor, address: &Pubkey) -> u64 {
+    test.account(address).await.map_or(0, |account| account.lamports)
+}
+
+/// Claims pay lamports up to each allocation, never out of the vault's rent reserve
+#[tokio::test]
+async fn test_claim_sol() {
+    let mut test = TestDistributor::new(false, AMOUNT, DURATION, None).await;
+    use_sol_distributor(&mut test, 2 * AMOUNT).await;
+    let vault = test.vault;
+    let rent_reserve = test.minimum_balance(0).await;
+    assert_eq!(lamports(&mut test, &vault).await, 2 * AMOUNT + rent_reserve);
+
+    // The tree allocates more than was deposited
+    let (alice, bob) = (Keypair::new(), Keypair::new());
+    let alice_leaf = hash_leaf(&test.distributor, &alice.pubkey(), AMOUNT);
+    let bob_leaf = hash_leaf(&test.distributor, &bob.pubkey(), 2 * AMOUNT);
+    test.set_merkle_root(hash_node(&alice_leaf, &bob_leaf)).await.unwrap();
+    test.set_time(GENESIS + DAY).await.unwrap();
+    test.warp_to(GENESIS + DAY).await;
+
+    // Nothing beyond the proven allocation, in one claim or over several
+    assert_error(claim_sol(&mut test, &alice, 2 * AMOUNT, vec![bob_leaf], None).await, TokenDistributorError::InvalidProof);
+    assert_error(
+        claim_sol(&mut test, &alice, AMOUNT, vec![bob_leaf], Some(AMOUNT + 1)).await,
+        TokenDistributorError::InvalidAmount,
+    );
+    claim_sol(&mut test, &alice, AMOUNT, vec![bob_leaf], Some(AMOUNT / 2)).await.unwrap();
+    claim_sol(&mut test, &alice, AMOUNT, vec![bob_leaf], None).await.unwrap();
+    assert_eq!(lamports(&mut test, &alice.pubkey()).await, AMOUNT);
+    assert_error(claim_sol(&mut test, &alice, AMOUNT, vec![bob_leaf], None).await, TokenDistributorError::InvalidAmount);
+
+    // The vault's rent reserve is not distributable, even to a valid allocation
+    assert_error(claim_sol(&mut test, &bob, 2 * AMOUNT, vec![alice_leaf], None).await, TokenDistributorError::InsufficientVaultBalance);
+    claim_sol(&mut test, &bob, 2 * AMOUNT, vec![alice_leaf], Some(AMOUNT)).await.unwrap();
+    assert_eq!(lamports(&mut test, &bob.pubkey()).await, AMOUNT);
+    assert_eq!(lamports(&mut test, &vault).await, rent_reserve);
+    assert_error(
+        claim_sol(&mut test, &bob, 2 * AMOUNT, vec![alice_leaf], Some(1)).await,
+        TokenDistributorError::InsufficientVaultBalance,
+    );
+    let distributor = test.distributor().await;
+    assert_eq!(distributor.total_claimed, 2 * AMOUNT);
+    assert!(distributor.is_completed());
+
+    // The emptied, completed distributor is withdrawn right away with the rent reserve
+    let (distributor, owner) = (test.distributor, test.owner.pubkey());
+    let distributor_rent = lamports(&mut test, &distributor).await;
+    let owner_balance = lamports(&mut test, &owner).await;
+    withdraw_sol(&mut test).await.unwrap();
+    assert!(test.account(&vault).await.is_none());
+    assert!(test.account(&distributor).await.is_none());
+    // The owner pays the transaction fee of 5,000 lamports
+    assert_eq!(lamports(&mut test, &owner).await, owner_balance + rent_reserve + distributor_rent - 5_000);
+}
+
+/// The owner withdraws what is left, rent reserve included, only once the distribution has ended
+#[tokio::test]
+async fn test_withdraw_sol_after_end() {
+    let mut test = TestDistributor::new(false, AMOUNT, DURATION, None).await;
+    use_sol_distributor(&mut test, 2 * AMOUNT).await;
+    let (distributor, vault) = (test.distributor, test.vault);
+    let rent_reserve = test.minimum_balance(0).await;
+    let claimant = Keypair::new();
+    test.set_merkle_root(hash_leaf(&distributor, &claimant.pubkey(), AMOUNT)).await.unwrap();
+    let start_time = GENESIS + DAY;
+    test.set_time(start_time).await.unwrap();
+    test.warp_to(start_time).await;
+    claim_sol(&mut test, &claimant, AMOUNT, vec![], None).await.unwrap();
+
+    test.warp_to(start_time + DURATION).await;
+    assert_error(withdraw_sol(&mut test).await, TokenDistributorError::DistributionNotEnded);
+    assert_eq!(lamports(&mut test, &vault).await, AMOUNT + rent_reserve);
+
+    test.warp_to(start_time + DURATION + 1).await;
+    let owner = test.owner.pubkey();
+    let distributor_rent = lamports(&mut test, &distributor).await;
+    let owner_balance = lamports(&mut test, &owner).await;
+    withdraw_sol(&mut test).await.unwrap();
+    assert!(test.account(&vault).await.is_none());
+    assert!(test.account(&distributor).await.is_none());
+    // The owner pays the transaction fee of 5,000 lamports
+    assert_eq!(lamports(&mut test, &owner).await, owner_balance + AMOUNT + rent_reserve + distributor_rent - 5_000);
+}
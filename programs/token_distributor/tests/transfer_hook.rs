@@ -0,0 +1,100 @@
+//! Claims of Token 2022 mints with a transfer hook, its extra accounts resolved on-chain
+#![cfg(feature = "transfer-hooks")]
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::AccountMeta;
+use anchor_lang::solana_program::system_instruction;
+use anchor_spl::token_2022::spl_token_2022;
+use anchor_spl::token_2022::spl_token_2022::extension::{transfer_hook, ExtensionType};
+use common::transfer_hook::{counter_address, extra_account_metas_data, COUNTER_LEN, TRANSFER_HOOK_PROGRAM_ID};
+use common::*;
+use solana_sdk::account::Account;
+use solana_sdk::signature::{Keypair, Signer};
+use spl_transfer_hook_interface::get_extra_account_metas_address;
+use token_distributor::utils::verify::{hash_leaf, hash_node};
+
+const DURATION: i64 = 7 * DAY;
+const AMOUNT: u64 = 1_000_000;
+
+/// Switches the test to a new mint using the test transfer hook, with its extra account metas and counter in place
+///
+/// The hook accounts are passed to claims and distributor creation from then on.
+async fn use_hooked_mint(test: &mut TestDistributor) -> Pubkey {
+    let (mint, owner) = (Keypair::new(), test.owner.pubkey());
+    let len = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[ExtensionType::TransferHook]).unwrap();
+    let rent = test.minimum_balance(len).await;
+    let instructions = [
+        system_instruction::create_account(&owner, &mint.pubkey(), rent, len as u64, &spl_token_2022::ID),
+        transfer_hook::instruction::initialize(&spl_token_2022::ID, &mint.pubkey(), Some(owner), Some(TRANSFER_HOOK_PROGRAM_ID))
+            .unwrap(),
+        spl_token_2022::instruction::initialize_mint2(&spl_token_2022::ID, &mint.pubkey(), &owner, None, DECIMALS).unwrap(),
+    ];
+    test.send(&instructions, &[&mint]).await.unwrap();
+    let mint = mint.pubkey();
+
+    // The hook's accounts, as its initialization would have created them
+    let extra_account_metas = get_extra_account_metas_address(&mint, &TRANSFER_HOOK_PROGRAM_ID);
+    let data = extra_account_metas_data();
+    let lamports = test.minimum_balance(data.len()).await;
+    let account = Account { lamports, data, owner: TRANSFER_HOOK_PROGRAM_ID, executable: false, rent_epoch: 0 };
+    test.context.set_account(&extra_account_metas, &account.into());
+    let lamports = test.minimum_balance(COUNTER_LEN).await;
+    let account =
+        Account { lamports, data: vec![0; COUNTER_LEN], owner: TRANSFER_HOOK_PROGRAM_ID, executable: false, rent_epoch: 0 };
+    test.context.set_account(&counter_address(&mint), &account.into());
+
+    test.mint = mint;
+    test.owner_token_account = test.create_token_account(&owner).await;
+    test.remaining_accounts = vec![
+        AccountMeta::new_readonly(TRANSFER_HOOK_PROGRAM_ID, false),
+        AccountMeta::new_readonly(extra_account_metas, false),
+        AccountMeta::new(counter_address(&mint), false),
+    ];
+    mint
+}
+
+/// Transfers the hook has seen of the mint, and their total amount
+async fn hook_counter(test: &mut TestDistributor, mint: &Pubkey) -> (u64, u64) {
+    let data = test.account(&counter_address(mint)).await.expect("counter exists").data;
+    (u64::from_le_bytes(data[..8].try_into().unwrap()), u64::from_le_bytes(data[8..].try_into().unwrap()))
+}
+
+/// Deposits and claims run the hook when its accounts are given, and fail without them
+#[tokio::test]
+async fn test_claim_with_transfer_hook() {
+    let mut test = TestDistributor::new(true, AMOUNT, DURATION, None).await;
+    let mint = use_hooked_mint(&mut test).await;
+    test.use_distributor(2, 2 * AMOUNT, DURATION, None).await;
+    assert_eq!(hook_counter(&mut test, &mint).await, (1, 2 * AMOUNT));
+
+    let (alice, bob) = (Keypair::new(), Keypair::new());
+    let alice_leaf = hash_leaf(&test.distributor, &alice.pubkey(), AMOUNT);
+    let bob_leaf = hash_leaf(&test.distributor, &bob.pubkey(), AMOUNT);
+    test.set_merkle_root(hash_node(&alice_leaf, &bob_leaf)).await.unwrap();
+    test.set_time(GENESIS + DAY).await.unwrap();
+    test.warp_to(GENESIS + DAY).await;
+    let alice_account = test.create_token_account(&alice.pubkey()).await;
+    let bob_account = test.create_token_account(&bob.pubkey()).await;
+
+    // The token program refuses the transfer without the hook program, or without the accounts its metas resolve to
+    let hook_accounts = std::mem::take(&mut test.remaining_accounts);
+    assert!(test.claim(&alice, &alice_account, AMOUNT, vec![bob_leaf]).await.is_err());
+    test.remaining_accounts = hook_accounts[..2].to_vec();
+    assert!(test.claim(&alice, &alice_account, AMOUNT, vec![bob_leaf]).await.is_err());
+    assert!(test.claim_status(&alice.pubkey()).await.is_none());
+    assert_eq!(hook_counter(&mut test, &mint).await, (1, 2 * AMOUNT));
+
+    // With them every claim runs the hook
+    test.remaining_accounts = hook_accounts;
+    test.claim(&alice, &alice_account, AMOUNT, vec![bob_leaf]).await.unwrap();
+    assert_eq!(test.token_balance(&alice_account).await, AMOUNT);
+    assert_eq!(hook_counter(&mut test, &mint).await, (2, 3 * AMOUNT));
+    test.claim_partial(&bob, &bob_account, AMOUNT, vec![alice_leaf], Some(AMOUNT / 4)).await.unwrap();
+    test.claim(&bob, &bob_account, AMOUNT, vec![alice_leaf]).await.unwrap();
+    assert_eq!(test.token_balance(&bob_account).await, AMOUNT);
+    assert_eq!(hook_counter(&mut test, &mint).await, (4, 4 * AMOUNT));
+    let vault = test.vault;
+    assert_eq!(test.token_balance(&vault).await, 0);
+}
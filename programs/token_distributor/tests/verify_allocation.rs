@@ -0,0 +1,128 @@
+//! Read-only verification of a claimant's allocation, returned as return data
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{AnchorDeserialize, InstructionData, ToAccountMetas};
+use common::*;
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::Transaction;
+use token_distributor::constants::UNLOCK_TIME_TREE_VERSION;
+use token_distributor::error::TokenDistributorError;
+use token_distributor::instructions::VerifiedAllocation;
+use token_distributor::utils::verify::{hash_leaf, hash_leaf_with_unlock_time, hash_node};
+use token_distributor::{accounts, instruction, ID};
+
+const DURATION: i64 = 7 * DAY;
+const AMOUNT: u64 = 1_000_000;
+
+/// Simulates verify_allocation and returns the verified allocation, or the transaction error
+///
+/// The claimant's claim status is passed when `with_claim_status` is set.
+async fn verify_allocation(
+    test: &mut TestDistributor,
+    claimant: &Pubkey,
+    with_claim_status: bool,
+    max_amount: u64,
+    proof: Vec<[u8; 32]>,
+    unlock_time: Option<i64>,
+) -> Result<VerifiedAllocation, BanksClientError> {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::VerifyAllocation {
+            distributor: test.distributor,
+            claim_status: with_claim_status.then(|| claim_status_address(&test.distributor, claimant)),
+            claimant: *claimant,
+        }
+        .to_account_metas(None),
+        data: instruction::VerifyAllocation { max_amount, proof, unlock_time }.data(),
+    };
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&test.owner.pubkey()), &[&test.owner], test.context.last_blockhash);
+    let simulation = test.context.banks_client.simulate_transaction(tx).await?;
+    simulation.result.unwrap().map_err(BanksClientError::TransactionError)?;
+    let return_data = simulation.simulation_details.unwrap().return_data.unwrap();
+    Ok(VerifiedAllocation::deserialize(&mut return_data.data.as_slice()).unwrap())
+}
+
+/// A valid proof returns the allocation and what was claimed of it, anything tampered with is rejected
+#[tokio::test]
+async fn test_verify_allocation() {
+    let mut test = TestDistributor::new(false, 3 * AMOUNT, DURATION, None).await;
+    let (alice, bob) = (Keypair::new(), Keypair::new());
+    let alice_leaf = hash_leaf(&test.distributor, &alice.pubkey(), AMOUNT);
+    let bob_leaf = hash_leaf(&test.distributor, &bob.pubkey(), 2 * AMOUNT);
+    assert_error(
+        verify_allocation(&mut test, &alice.pubkey(), false, AMOUNT, vec![bob_leaf], None).await,
+        TokenDistributorError::NoMerkleRoot,
+    );
+    test.set_merkle_root(hash_node(&alice_leaf, &bob_leaf)).await.unwrap();
+
+    let allocation = verify_allocation(&mut test, &alice.pubkey(), false, AMOUNT, vec![bob_leaf], None).await.unwrap();
+    assert_eq!(allocation, VerifiedAllocation { max_amount: AMOUNT, claimed_amount: 0, unlock_time: 0 });
+    let allocation = verify_allocation(&mut test, &bob.pubkey(), false, 2 * AMOUNT, vec![alice_leaf], None).await.unwrap();
+    assert_eq!(allocation.max_amount, 2 * AMOUNT);
+
+    // A raised amount, a corrupted proof or someone else's proof does not verify
+    assert_error(
+        verify_allocation(&mut test, &alice.pubkey(), false, AMOUNT + 1, vec![bob_leaf], None).await,
+        TokenDistributorError::InvalidProof,
+    );
+    let mut corrupted = bob_leaf;
+    corrupted[0] ^= 1;
+    assert_error(
+        verify_allocation(&mut test, &alice.pubkey(), false, AMOUNT, vec![corrupted], None).await,
+        TokenDistributorError::InvalidProof,
+    );
+    assert_error(
+        verify_allocation(&mut test, &alice.pubkey(), false, AMOUNT, vec![], None).await,
+        TokenDistributorError::InvalidProof,
+    );
+    assert_error(
+        verify_allocation(&mut test, &bob.pubkey(), false, AMOUNT, vec![bob_leaf], None).await,
+        TokenDistributorError::InvalidProof,
+    );
+
+    // The claim status reports what was claimed, without changing anything
+    test.set_time(GENESIS + DAY).await.unwrap();
+    test.warp_to(GENESIS + DAY).await;
+    let token_account = test.create_token_account(&bob.pubkey()).await;
+    test.claim_partial(&bob, &token_account, 2 * AMOUNT, vec![alice_leaf], Some(AMOUNT / 2)).await.unwrap();
+    let allocation = verify_allocation(&mut test, &bob.pubkey(), true, 2 * AMOUNT, vec![alice_leaf], None).await.unwrap();
+    assert_eq!((allocation.max_amount, allocation.claimed_amount), (2 * AMOUNT, AMOUNT / 2));
+    assert_eq!(test.claim_status(&bob.pubkey()).await.unwrap().claimed_amount, AMOUNT / 2);
+
+    // Only the claimant's own claim status is accepted
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::VerifyAllocation {
+            distributor: test.distributor,
+            claim_status: Some(claim_status_address(&test.distributor, &bob.pubkey())),
+            claimant: alice.pubkey(),
+        }
+        .to_account_metas(None),
+        data: instruction::VerifyAllocation { max_amount: AMOUNT, proof: vec![bob_leaf], unlock_time: None }.data(),
+    };
+    assert!(test.send(&[ix], &[]).await.is_err());
+}
+
+/// Unlock time trees bind the unlock time into the leaf and return it
+#[tokio::test]
+async fn test_verify_allocation_with_unlock_time() {
+    let mut test = TestDistributor::new(false, AMOUNT, DURATION, Some(UNLOCK_TIME_TREE_VERSION)).await;
+    let claimant = Keypair::new().pubkey();
+    let unlock_time = GENESIS + 2 * DAY;
+    test.set_merkle_root(hash_leaf_with_unlock_time(&test.distributor, &claimant, AMOUNT, unlock_time)).await.unwrap();
+
+    let allocation = verify_allocation(&mut test, &claimant, false, AMOUNT, vec![], Some(unlock_time)).await.unwrap();
+    assert_eq!(allocation, VerifiedAllocation { max_amount: AMOUNT, claimed_amount: 0, unlock_time });
+    assert_error(
+        verify_allocation(&mut test, &claimant, false, AMOUNT, vec![], Some(unlock_time - 1)).await,
+        TokenDistributorError::InvalidProof,
+    );
+    assert_error(
+        verify_allocation(&mut test, &claimant, false, AMOUNT, vec![], None).await,
+        TokenDistributorError::MissingUnlockTime,
+    );
+}
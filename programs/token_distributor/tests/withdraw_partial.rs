@@ -0,0 +1,62 @@
+//! Partial withdrawals of the vault by the owner
+
+mod common;
+
+use common::*;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+use token_distributor::error::TokenDistributorError;
+
+const DURATION: i64 = 7 * DAY;
+const DEPOSIT: u64 = 1_000_000_000;
+
+/// Sweeps part of the vault before the start and after the end, keeping the distributor and vault open
+#[tokio::test]
+async fn test_withdraw_partial() {
+    for token_2022 in [false, true] {
+        let mut test = TestDistributor::new(token_2022, DEPOSIT, DURATION, None).await;
+        let (vault, owner_token_account) = (test.vault, test.owner_token_account);
+
+        // Before the start: an over-deposit goes back to the owner
+        assert_error(test.withdraw_partial(0).await, TokenDistributorError::InvalidAmount);
+        assert_error(test.withdraw_partial(DEPOSIT + 1).await, TokenDistributorError::WithdrawAmountExceedsBalance);
+        test.withdraw_partial(DEPOSIT / 4).await.unwrap();
+        assert_eq!(test.token_balance(&vault).await, DEPOSIT - DEPOSIT / 4);
+        assert_eq!(test.token_balance(&owner_token_account).await, DEPOSIT / 4);
+
+        // While the distribution is live nothing can be swept
+        let start_time = GENESIS + DAY;
+        test.set_time(start_time).await.unwrap();
+        test.warp_to(start_time).await;
+        assert_error(test.withdraw_partial(1).await, TokenDistributorError::DistributionActive);
+
+        // After the end: the rest can be swept in parts, the distributor stays open
+        test.warp_to(start_time + DURATION + 1).await;
+        test.withdraw_partial(DEPOSIT / 4).await.unwrap();
+        test.withdraw_partial(DEPOSIT / 2).await.unwrap();
+        assert_eq!(test.token_balance(&vault).await, 0);
+        assert_eq!(test.token_balance(&owner_token_account).await, DEPOSIT);
+        let distributor = test.distributor().await;
+        assert_eq!(distributor.owner, test.owner.pubkey());
+        assert_eq!(distributor.total_claimed, 0);
+    }
+}
+
+/// A clawback receiver only blocks withdrawals once the distribution has ended, not before it is scheduled
+#[tokio::test]
+async fn test_withdraw_partial_with_clawback_receiver() {
+    let mut test = TestDistributor::new(false, DEPOSIT, DURATION, None).await;
+    test.use_distributor(2, DEPOSIT, DURATION, Some(Pubkey::new_unique())).await;
+    let vault = test.vault;
+
+    // Unscheduled and scheduled but not started: an over-deposit goes back to the owner
+    test.withdraw_partial(DEPOSIT / 4).await.unwrap();
+    let start_time = GENESIS + DAY;
+    test.set_time(start_time).await.unwrap();
+    test.withdraw_partial(DEPOSIT / 4).await.unwrap();
+    assert_eq!(test.token_balance(&vault).await, DEPOSIT / 2);
+
+    // After the end the rest belongs to the clawback receiver
+    test.warp_to(start_time + DURATION + 1).await;
+    assert_error(test.withdraw_partial(1).await, TokenDistributorError::ClawbackReceiverConfigured);
+}
@@ -0,0 +1,29 @@
+import * as anchor from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import * as fs from "fs";
+import * as path from "path";
+import { SimpleMerkleTree, TreeNode } from "./utils/merkle_tree";
+
+// Canonical vectors generated by the Rust tests (programs/token_distributor/src/test/test_vectors.rs)
+const vectors = JSON.parse(fs.readFileSync(path.join(__dirname, "vectors", "merkle_tree_vectors.json"), "utf8"));
+
+describe("merkle tree test vectors", () => {
+  for (const testCase of vectors.cases) {
+    it(`matches ${testCase.name}`, () => {
+      const distributor = new PublicKey(testCase.distributor);
+      const nodes: TreeNode[] = testCase.entries.map((entry: any) => ({
+        claimant: new PublicKey(entry.claimant),
+        amount: new anchor.BN(entry.amount),
+        unlockTime: entry.unlock_time === null ? undefined : new anchor.BN(entry.unlock_time),
+      }));
+      const tree = new SimpleMerkleTree(nodes, distributor);
+
+      expect(Buffer.from(tree.getMerkleRoot()).toString("hex")).to.equal(testCase.root);
+      nodes.forEach((_, index) => {
+        const proof = tree.getProof(index).map((node) => node.toString("hex"));
+        expect(proof).to.deep.equal(testCase.proofs[index]);
+      });
+    });
+  }
+});
@@ -226,7 +226,7 @@ describe("token_distributor", () => {
       console.log("Calling createDistributor with SPL Token, totalAmount:", totalAmount.toString());
 
       const tx = await program.methods
-        .createDistributor(totalAmount)
+        .createDistributor(totalAmount, null, null, null, null, null)
         .accounts({
           ownerNonce: ownerNoncePda,
           distributor: distributorPda,
@@ -269,7 +269,7 @@ describe("token_distributor", () => {
 
       console.log("SPL Token Distributor account data:", {
         owner: distributorAccount.owner.toString(),
-        operator: distributorAccount.operator.toString(),
+        operator: distributorAccount.operators[0].toString(),
         tokenMint: distributorAccount.tokenMint.toString(),
         initialTotalAmount: distributorAccount.initialTotalAmount.toString(),
         totalClaimed: distributorAccount.totalClaimed.toString(),
@@ -278,7 +278,7 @@ describe("token_distributor", () => {
 
       // Basic verification
       expect(distributorAccount.owner.toString()).to.equal(owner.publicKey.toString());
-      expect(distributorAccount.operator.toString()).to.equal(operator.publicKey.toString());
+      expect(distributorAccount.operators[0].toString()).to.equal(operator.publicKey.toString());
       expect(distributorAccount.tokenMint.toString()).to.equal(tokenMint.toString());
       expect(distributorAccount.initialTotalAmount.toString()).to.equal(totalAmount.toString());
       expect(distributorAccount.totalClaimed.toString()).to.equal("0"); // Should be 0 initially
@@ -298,7 +298,7 @@ describe("token_distributor", () => {
       console.log("Calling createDistributor with Token 2022, totalAmount:", totalAmount.toString());
 
       const tx = await program.methods
-        .createDistributor(totalAmount)
+        .createDistributor(totalAmount, null, null, null, null, null)
         .accounts({
           ownerNonce: ownerNoncePda,
           distributor: distributorPda2022,
@@ -346,7 +346,7 @@ describe("token_distributor", () => {
 
       console.log("Token 2022 Distributor account data:", {
         owner: distributorAccount.owner.toString(),
-        operator: distributorAccount.operator.toString(),
+        operator: distributorAccount.operators[0].toString(),
         tokenMint: distributorAccount.tokenMint.toString(),
         initialTotalAmount: distributorAccount.initialTotalAmount.toString(),
         totalClaimed: distributorAccount.totalClaimed.toString(),
@@ -355,7 +355,7 @@ describe("token_distributor", () => {
 
       // Basic verification
       expect(distributorAccount.owner.toString()).to.equal(owner.publicKey.toString());
-      expect(distributorAccount.operator.toString()).to.equal(operator.publicKey.toString());
+      expect(distributorAccount.operators[0].toString()).to.equal(operator.publicKey.toString());
       expect(distributorAccount.tokenMint.toString()).to.equal(tokenMint2022.toString());
       expect(distributorAccount.initialTotalAmount.toString()).to.equal(totalAmount.toString());
       expect(distributorAccount.totalClaimed.toString()).to.equal("0"); // Should be 0 initially
@@ -381,8 +381,9 @@ describe("token_distributor", () => {
       ];
 
       // Generate merkle root from hardcoded data
-      const testMerkleTree = new SimpleMerkleTree(testTreeNodes);
-      const merkleRoot = testMerkleTree.getMerkleRoot();
+      // Leaves are bound to the distributor, so each distributor gets its own tree
+      const merkleRoot = new SimpleMerkleTree(testTreeNodes, distributorPda).getMerkleRoot();
+      const merkleRoot2022 = new SimpleMerkleTree(testTreeNodes, distributorPda2022).getMerkleRoot();
 
       console.log("Generated merkle root:", merkleRoot);
       console.log("Merkle root length:", merkleRoot.length);
@@ -403,7 +404,7 @@ describe("token_distributor", () => {
       console.log("Setting merkle root for Token 2022 distributor (nonce 2)...");
 
       const tx2 = await program.methods
-        .setMerkleRoot(merkleRoot)
+        .setMerkleRoot(merkleRoot2022)
         .accounts({
           distributor: distributorPda2022,
           operator: operator.publicKey,
@@ -422,7 +423,7 @@ describe("token_distributor", () => {
 
       // Verify merkle root matches what we set
       expect(distributorAccount1.merkleRoot).to.deep.equal(merkleRoot);
-      expect(distributorAccount2022.merkleRoot).to.deep.equal(merkleRoot);
+      expect(distributorAccount2022.merkleRoot).to.deep.equal(merkleRoot2022);
 
       console.log("✅ Set merkle root test passed for both distributors!");
     } catch (error) {
@@ -687,7 +688,7 @@ describe("token_distributor", () => {
       ];
 
       // Create merkle tree with our test data
-      const testMerkleTree = new SimpleMerkleTree(testTreeNodes);
+      const testMerkleTree = new SimpleMerkleTree(testTreeNodes, distributorPda);
       const testMerkleRoot = testMerkleTree.getMerkleRoot();
 
       console.log("Created test merkle tree with controlled keypairs");
@@ -768,16 +769,21 @@ describe("token_distributor", () => {
         console.log("Time until start:", distributorAccount.startTime.toNumber() - (currentBlockTime || 0), "seconds");
 
         await program.methods
-          .claim(claimAmount, proofArray)
+          .claim(claimAmount, proofArray, null, null, null)
           .accounts({
             distributor: distributorPda,
             claimStatus: claimStatusPda,
             tokenVault: tokenVaultPda,
+            sharedVault: null,
             claimantTokenAccount: claimant1TokenAccount,
             tokenMint: tokenMint,
             claimant: claimant1.publicKey,
+            payer: claimant1.publicKey,
             systemProgram: anchor.web3.SystemProgram.programId,
             tokenProgram: TOKEN_PROGRAM_ID,
+            memoProgram: null,
+            protocolFeeAccount: null,
+            gateTokenAccount: null,
           })
           .signers([claimant1])
           .rpc();
@@ -800,16 +806,21 @@ describe("token_distributor", () => {
       // Execute the claim transaction
       console.log("Executing claim transaction...");
       const claimTx = await program.methods
-        .claim(claimAmount, proofArray)
+        .claim(claimAmount, proofArray, null, null, null)
         .accounts({
           distributor: distributorPda,
           claimStatus: claimStatusPda,
           tokenVault: tokenVaultPda,
+          sharedVault: null,
           claimantTokenAccount: claimant1TokenAccount,
           tokenMint: tokenMint,
           claimant: claimant1.publicKey,
+          payer: claimant1.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
           tokenProgram: TOKEN_PROGRAM_ID,
+          memoProgram: null,
+          protocolFeeAccount: null,
+          gateTokenAccount: null,
         })
         .signers([claimant1])
         .rpc();
@@ -848,16 +859,21 @@ describe("token_distributor", () => {
       console.log("Testing double claim prevention...");
       try {
         await program.methods
-          .claim(claimAmount, proofArray)
+          .claim(claimAmount, proofArray, null, null, null)
           .accounts({
             distributor: distributorPda,
             claimStatus: claimStatusPda,
             tokenVault: tokenVaultPda,
+            sharedVault: null,
             claimantTokenAccount: claimant1TokenAccount,
             tokenMint: tokenMint,
             claimant: claimant1.publicKey,
+            payer: claimant1.publicKey,
             systemProgram: anchor.web3.SystemProgram.programId,
             tokenProgram: TOKEN_PROGRAM_ID,
+            memoProgram: null,
+            protocolFeeAccount: null,
+            gateTokenAccount: null,
           })
           .signers([claimant1])
           .rpc();
@@ -905,7 +921,7 @@ describe("token_distributor", () => {
       ];
 
       // Create merkle tree with our test data
-      const testMerkleTree = new SimpleMerkleTree(testTreeNodes);
+      const testMerkleTree = new SimpleMerkleTree(testTreeNodes, distributorPda2022);
       const testMerkleRoot = testMerkleTree.getMerkleRoot();
 
       console.log("Created test merkle tree with controlled keypairs");
@@ -991,16 +1007,21 @@ describe("token_distributor", () => {
         console.log("Time until start:", distributorAccount.startTime.toNumber() - (currentBlockTime || 0), "seconds");
 
         await program.methods
-          .claim(claimAmount, proofArray)
+          .claim(claimAmount, proofArray, null, null, null)
           .accounts({
             distributor: distributorPda2022,
             claimStatus: claimStatusPda,
             tokenVault: tokenVaultPda2022,
+            sharedVault: null,
             claimantTokenAccount: claimant1TokenAccount,
             tokenMint: tokenMint2022,
             claimant: claimant1.publicKey,
+            payer: claimant1.publicKey,
             systemProgram: anchor.web3.SystemProgram.programId,
             tokenProgram: TOKEN_2022_PROGRAM_ID,
+            memoProgram: null,
+            protocolFeeAccount: null,
+            gateTokenAccount: null,
           })
           .signers([claimant1])
           .rpc();
@@ -1024,16 +1045,21 @@ describe("token_distributor", () => {
       // Execute the claim transaction
       console.log("Executing claim transaction...");
       const claimTx = await program.methods
-        .claim(claimAmount, proofArray)
+        .claim(claimAmount, proofArray, null, null, null)
         .accounts({
           distributor: distributorPda2022,
           claimStatus: claimStatusPda,
           tokenVault: tokenVaultPda2022,
+          sharedVault: null,
           claimantTokenAccount: claimant1TokenAccount,
           tokenMint: tokenMint2022,
           claimant: claimant1.publicKey,
+          payer: claimant1.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
           tokenProgram: TOKEN_2022_PROGRAM_ID,
+          memoProgram: null,
+          protocolFeeAccount: null,
+          gateTokenAccount: null,
         })
         .signers([claimant1])
         .rpc();
@@ -1077,16 +1103,21 @@ describe("token_distributor", () => {
       console.log("Testing double claim prevention...");
       try {
         await program.methods
-          .claim(claimAmount, proofArray)
+          .claim(claimAmount, proofArray, null, null, null)
           .accounts({
             distributor: distributorPda2022,
             claimStatus: claimStatusPda,
             tokenVault: tokenVaultPda2022,
+            sharedVault: null,
             claimantTokenAccount: claimant1TokenAccount,
             tokenMint: tokenMint2022,
             claimant: claimant1.publicKey,
+            payer: claimant1.publicKey,
             systemProgram: anchor.web3.SystemProgram.programId,
             tokenProgram: TOKEN_2022_PROGRAM_ID,
+            memoProgram: null,
+            protocolFeeAccount: null,
+            gateTokenAccount: null,
           })
           .signers([claimant1])
           .rpc();
@@ -1129,7 +1160,7 @@ describe("token_distributor", () => {
       // Create distributor for withdraw test
       console.log("Creating distributor for withdraw test (nonce", nextNonce, ")...");
       const createTx = await program.methods
-        .createDistributor(totalAmount)
+        .createDistributor(totalAmount, null, null, null, null, null)
         .accounts({
           ownerNonce: ownerNoncePda,
           distributor: withdrawTestDistributorPda,
@@ -1271,7 +1302,7 @@ describe("token_distributor", () => {
       // Create distributor for withdraw test
       console.log("Creating distributor for withdraw test (nonce", nextNonce, ")...");
       const createTx = await program.methods
-        .createDistributor(totalAmount)
+        .createDistributor(totalAmount, null, null, null, null, null)
         .accounts({
           ownerNonce: ownerNoncePda,
           distributor: withdrawTestDistributorPda2022,
@@ -199,8 +199,9 @@ describe("token_distributor_bankrun", () => {
   let claimant1: Keypair;
   let claimant2: Keypair;
   let testTreeNodes: Array<{ claimant: PublicKey; amount: anchor.BN }>;
-  let testMerkleTree: SimpleMerkleTree;
-  let testMerkleRoot: number[];
+
+  // Leaves are bound to the distributor, so each distributor needs its own merkle tree
+  const merkleTreeFor = (distributor: PublicKey) => new SimpleMerkleTree(testTreeNodes, distributor);
 
   // Helper function to get next nonce for an owner
   async function getNextNonceForOwner(ownerKey: PublicKey): Promise<number> {
@@ -308,11 +309,6 @@ describe("token_distributor_bankrun", () => {
       { claimant: operator.publicKey, amount: new anchor.BN(4000) }, // Use operator as fourth claimant
     ];
 
-    // Create merkle tree with test data
-    testMerkleTree = new SimpleMerkleTree(testTreeNodes);
-    testMerkleRoot = testMerkleTree.getMerkleRoot();
-    console.log("Created test merkle tree with controlled keypairs");
-    console.log("Test merkle root:", testMerkleRoot);
 
     // Create SPL token mint (nonce 1) using manual approach
     const tokenMintKeypair = await manualCreateMint(provider, owner.publicKey, 9);
@@ -378,7 +374,7 @@ describe("token_distributor_bankrun", () => {
       console.log("Calling createDistributor with SPL Token, totalAmount:", totalAmount.toString());
 
       const tx = await program.methods
-        .createDistributor(totalAmount)
+        .createDistributor(totalAmount, null, null, null, null, null)
         .accounts({
           ownerNonce: ownerNoncePda,
           distributor: distributorPda,
@@ -421,7 +417,7 @@ describe("token_distributor_bankrun", () => {
 
       console.log("SPL Token Distributor account data:", {
         owner: distributorAccount.owner.toString(),
-        operator: distributorAccount.operator.toString(),
+        operator: distributorAccount.operators[0].toString(),
         tokenMint: distributorAccount.tokenMint.toString(),
         initialTotalAmount: distributorAccount.initialTotalAmount.toString(),
         totalClaimed: distributorAccount.totalClaimed.toString(),
@@ -430,7 +426,7 @@ describe("token_distributor_bankrun", () => {
 
       // Basic verification
       expect(distributorAccount.owner.toString()).to.equal(owner.publicKey.toString());
-      expect(distributorAccount.operator.toString()).to.equal(operator.publicKey.toString());
+      expect(distributorAccount.operators[0].toString()).to.equal(operator.publicKey.toString());
       expect(distributorAccount.tokenMint.toString()).to.equal(tokenMint.toString());
       expect(distributorAccount.initialTotalAmount.toString()).to.equal(totalAmount.toString());
       expect(distributorAccount.totalClaimed.toString()).to.equal("0"); // Should be 0 initially
@@ -450,7 +446,7 @@ describe("token_distributor_bankrun", () => {
       console.log("Calling createDistributor with Token 2022, totalAmount:", totalAmount.toString());
 
       const tx = await program.methods
-        .createDistributor(totalAmount)
+        .createDistributor(totalAmount, null, null, null, null, null)
         .accounts({
           ownerNonce: ownerNoncePda,
           distributor: distributorPda2022,
@@ -493,7 +489,7 @@ describe("token_distributor_bankrun", () => {
 
       console.log("Token 2022 Distributor account data:", {
         owner: distributorAccount.owner.toString(),
-        operator: distributorAccount.operator.toString(),
+        operator: distributorAccount.operators[0].toString(),
         tokenMint: distributorAccount.tokenMint.toString(),
         initialTotalAmount: distributorAccount.initialTotalAmount.toString(),
         totalClaimed: distributorAccount.totalClaimed.toString(),
@@ -502,7 +498,7 @@ describe("token_distributor_bankrun", () => {
 
       // Basic verification
       expect(distributorAccount.owner.toString()).to.equal(owner.publicKey.toString());
-      expect(distributorAccount.operator.toString()).to.equal(operator.publicKey.toString());
+      expect(distributorAccount.operators[0].toString()).to.equal(operator.publicKey.toString());
       expect(distributorAccount.tokenMint.toString()).to.equal(tokenMint2022.toString());
       expect(distributorAccount.initialTotalAmount.toString()).to.equal(totalAmount.toString());
       expect(distributorAccount.totalClaimed.toString()).to.equal("0"); // Should be 0 initially
@@ -516,10 +512,11 @@ describe("token_distributor_bankrun", () => {
   });
 
   it("Set merkle root for both distributors", async () => {
-    console.log("Using predefined test merkle root...");
+    console.log("Using predefined test tree nodes...");
 
-    // Use the predefined test merkle root
-    const merkleRoot = testMerkleRoot;
+    // Leaves are bound to the distributor, so each distributor gets its own root
+    const merkleRoot = merkleTreeFor(distributorPda).getMerkleRoot();
+    const merkleRoot2022 = merkleTreeFor(distributorPda2022).getMerkleRoot();
 
     console.log("Test merkle root:", merkleRoot);
     console.log("Merkle root length:", merkleRoot.length);
@@ -540,7 +537,7 @@ describe("token_distributor_bankrun", () => {
     console.log("Setting merkle root for Token 2022 distributor (nonce 2)...");
 
     const tx2 = await program.methods
-      .setMerkleRoot(merkleRoot)
+      .setMerkleRoot(merkleRoot2022)
       .accounts({
         distributor: distributorPda2022,
         operator: operator.publicKey,
@@ -559,7 +556,7 @@ describe("token_distributor_bankrun", () => {
 
     // Verify merkle root matches what we set
     expect(distributorAccount1.merkleRoot).to.deep.equal(merkleRoot);
-    expect(distributorAccount2022.merkleRoot).to.deep.equal(merkleRoot);
+    expect(distributorAccount2022.merkleRoot).to.deep.equal(merkleRoot2022);
 
     console.log("✅ Set merkle root test passed for both distributors!");
   });
@@ -640,7 +637,7 @@ describe("token_distributor_bankrun", () => {
       console.log("  Amount:", claimAmount.toString());
 
       // Generate proof for claimant1
-      const proof = testMerkleTree.getProof(claimIndex);
+      const proof = merkleTreeFor(distributorPda).getProof(claimIndex);
       console.log("Generated proof length:", proof.length);
 
       // Convert proof to the format expected by the program
@@ -690,16 +687,21 @@ describe("token_distributor_bankrun", () => {
         console.log("Time until start:", distributorAccount.startTime.toNumber() - currentBlockTime, "seconds");
 
         await program.methods
-          .claim(claimAmount, proofArray)
+          .claim(claimAmount, proofArray, null, null, null)
           .accounts({
             distributor: distributorPda,
             claimStatus: claimStatusPda,
             tokenVault: tokenVaultPda,
+            sharedVault: null,
             claimantTokenAccount: claimant1TokenAccount.publicKey,
             tokenMint: tokenMint,
             claimant: claimant1.publicKey,
+            payer: claimant1.publicKey,
             systemProgram: anchor.web3.SystemProgram.programId,
             tokenProgram: TOKEN_PROGRAM_ID,
+            memoProgram: null,
+            protocolFeeAccount: null,
+            gateTokenAccount: null,
           })
           .signers([claimant1])
           .rpc();
@@ -744,16 +746,21 @@ describe("token_distributor_bankrun", () => {
       // Execute the claim transaction
       console.log("Executing claim transaction...");
       const claimTx = await program.methods
-        .claim(claimAmount, proofArray)
+        .claim(claimAmount, proofArray, null, null, null)
         .accounts({
           distributor: distributorPda,
           claimStatus: claimStatusPda,
           tokenVault: tokenVaultPda,
+          sharedVault: null,
           claimantTokenAccount: claimant1TokenAccount.publicKey,
           tokenMint: tokenMint,
           claimant: claimant1.publicKey,
+          payer: claimant1.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
           tokenProgram: TOKEN_PROGRAM_ID,
+          memoProgram: null,
+          protocolFeeAccount: null,
+          gateTokenAccount: null,
         })
         .signers([claimant1])
         .rpc();
@@ -792,16 +799,21 @@ describe("token_distributor_bankrun", () => {
       console.log("Testing double claim prevention...");
       try {
         await program.methods
-          .claim(claimAmount, proofArray)
+          .claim(claimAmount, proofArray, null, null, null)
           .accounts({
             distributor: distributorPda,
             claimStatus: claimStatusPda,
             tokenVault: tokenVaultPda,
+            sharedVault: null,
             claimantTokenAccount: claimant1TokenAccount.publicKey,
             tokenMint: tokenMint,
             claimant: claimant1.publicKey,
+            payer: claimant1.publicKey,
             systemProgram: anchor.web3.SystemProgram.programId,
             tokenProgram: TOKEN_PROGRAM_ID,
+            memoProgram: null,
+            protocolFeeAccount: null,
+            gateTokenAccount: null,
           })
           .signers([claimant1])
           .rpc();
@@ -834,7 +846,7 @@ describe("token_distributor_bankrun", () => {
       console.log("  Amount:", claimAmount.toString());
 
       // Generate proof for claimant2
-      const proof = testMerkleTree.getProof(claimIndex);
+      const proof = merkleTreeFor(distributorPda2022).getProof(claimIndex);
       console.log("Generated proof length:", proof.length);
 
       // Convert proof to the format expected by the program
@@ -903,16 +915,21 @@ describe("token_distributor_bankrun", () => {
       // Execute the claim transaction
       console.log("Executing claim transaction...");
       const claimTx = await program.methods
-        .claim(claimAmount, proofArray)
+        .claim(claimAmount, proofArray, null, null, null)
         .accounts({
           distributor: distributorPda2022,
           claimStatus: claimStatusPda,
           tokenVault: tokenVaultPda2022,
+          sharedVault: null,
           claimantTokenAccount: claimant2TokenAccount.publicKey,
           tokenMint: tokenMint2022,
           claimant: claimant2.publicKey,
+          payer: claimant2.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
           tokenProgram: TOKEN_2022_PROGRAM_ID,
+          memoProgram: null,
+          protocolFeeAccount: null,
+          gateTokenAccount: null,
         })
         .signers([claimant2])
         .rpc();
@@ -956,16 +973,21 @@ describe("token_distributor_bankrun", () => {
       console.log("Testing double claim prevention...");
       try {
         await program.methods
-          .claim(claimAmount, proofArray)
+          .claim(claimAmount, proofArray, null, null, null)
           .accounts({
             distributor: distributorPda2022,
             claimStatus: claimStatusPda,
             tokenVault: tokenVaultPda2022,
+            sharedVault: null,
             claimantTokenAccount: claimant2TokenAccount.publicKey,
             tokenMint: tokenMint2022,
             claimant: claimant2.publicKey,
+            payer: claimant2.publicKey,
             systemProgram: anchor.web3.SystemProgram.programId,
             tokenProgram: TOKEN_2022_PROGRAM_ID,
+            memoProgram: null,
+            protocolFeeAccount: null,
+            gateTokenAccount: null,
           })
           .signers([claimant2])
           .rpc();
@@ -1008,7 +1030,7 @@ describe("token_distributor_bankrun", () => {
       // Create distributor for withdraw test
       console.log("Creating distributor for withdraw test (nonce", nextnonce, ")...");
       const createTx = await program.methods
-        .createDistributor(totalAmount)
+        .createDistributor(totalAmount, null, null, null, null, null)
         .accounts({
           ownerNonce: ownerNoncePda,
           distributor: withdrawTestDistributorPda,
@@ -1158,7 +1180,7 @@ describe("token_distributor_bankrun", () => {
       // Create distributor for withdraw test
       console.log("Creating distributor for withdraw test (nonce", nextnonce, ")...");
       const createTx = await program.methods
-        .createDistributor(totalAmount)
+        .createDistributor(totalAmount, null, null, null, null, null)
         .accounts({
           ownerNonce: ownerNoncePda,
           distributor: withdrawTestDistributorPda2022,
@@ -1343,21 +1365,26 @@ describe("token_distributor_bankrun", () => {
       // Use owner (testTreeNodes[2]: 3000 tokens)
       const claimIndex = 2;
       const claimAmount = testTreeNodes[claimIndex].amount;
-      const proof = testMerkleTree.getProof(claimIndex);
+      const proof = merkleTreeFor(distributorPda).getProof(claimIndex);
       const proofArray: number[][] = proof.map((p) => Array.from(p));
 
       console.log("Executing claim to create claim status account...");
       const claimTx = await program.methods
-        .claim(claimAmount, proofArray)
+        .claim(claimAmount, proofArray, null, null, null)
         .accounts({
           distributor: distributorPda,
           claimStatus: claimStatusPda,
           tokenVault: tokenVaultPda,
+          sharedVault: null,
           claimantTokenAccount: testClaimantTokenAccount.publicKey,
           tokenMint: tokenMint,
           claimant: testClaimant.publicKey,
+          payer: testClaimant.publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
           tokenProgram: TOKEN_PROGRAM_ID,
+          memoProgram: null,
+          protocolFeeAccount: null,
+          gateTokenAccount: null,
         })
         .signers([testClaimant])
         .rpc();
@@ -1396,6 +1423,7 @@ describe("token_distributor_bankrun", () => {
         .accounts({
           claimStatus: claimStatusPda,
           claimant: testClaimant.publicKey,
+          rentPayer: testClaimant.publicKey,
           distributorKey: distributorPda,
         })
         .signers([testClaimant])
@@ -207,8 +207,9 @@ describe("token_distributor_litesvm", () => {
   let claimant1: Keypair;
   let claimant2: Keypair;
   let testTreeNodes: Array<{ claimant: PublicKey; amount: anchor.BN }>;
-  let testMerkleTree: SimpleMerkleTree;
-  let testMerkleRoot: number[];
+
+  // Leaves are bound to the distributor, so each distributor needs its own merkle tree
+  const merkleTreeFor = (distributor: PublicKey) => new SimpleMerkleTree(testTreeNodes, distributor);
 
   // Helper function to get next nonce for an owner
   async function getNextNonceForOwner(ownerKey: PublicKey): Promise<number> {
@@ -391,11 +392,6 @@ describe("token_distributor_litesvm", () => {
       { claimant: operator.publicKey, amount: new anchor.BN(4000) }, // Use operator as fourth claimant
     ];
 
-    // Create merkle tree with test data
-    testMerkleTree = new SimpleMerkleTree(testTreeNodes);
-    testMerkleRoot = testMerkleTree.getMerkleRoot();
-    console.log("✅ Created test merkle tree with controlled keypairs");
-    console.log("Test merkle root:", testMerkleRoot);
 
     // Create SPL token mint (nonce 1) using LiteSVM approach
     console.log("Creating SPL token mint...");
@@ -544,7 +540,7 @@ describe("token_distributor_litesvm", () => {
       console.log("Building createDistributor instruction...");
 
       const ix = await program.methods
-        .createDistributor(totalAmount)
+        .createDistributor(totalAmount, null, null, null, null, null)
         .accounts({
           ownerNonce: ownerNoncePda,
           distributor: distributorPda,
@@ -627,7 +623,7 @@ describe("token_distributor_litesvm", () => {
 
       console.log("SPL Token Distributor account data:", {
         owner: fetchedDistributorAccount.owner.toString(),
-        operator: fetchedDistributorAccount.operator.toString(),
+        operator: fetchedDistributorAccount.operators[0].toString(),
         tokenMint: fetchedDistributorAccount.tokenMint.toString(),
         initialTotalAmount: fetchedDistributorAccount.initialTotalAmount.toString(),
         totalClaimed: fetchedDistributorAccount.totalClaimed.toString(),
@@ -636,7 +632,7 @@ describe("token_distributor_litesvm", () => {
 
       // Basic verification
       expect(fetchedDistributorAccount.owner.toString()).to.equal(owner.publicKey.toString());
-      expect(fetchedDistributorAccount.operator.toString()).to.equal(operator.publicKey.toString());
+      expect(fetchedDistributorAccount.operators[0].toString()).to.equal(operator.publicKey.toString());
       expect(fetchedDistributorAccount.tokenMint.toString()).to.equal(tokenMint.toString());
       expect(fetchedDistributorAccount.initialTotalAmount.toString()).to.equal(totalAmount.toString());
       expect(fetchedDistributorAccount.totalClaimed.toString()).to.equal("0"); // Should be 0 initially
@@ -657,7 +653,7 @@ describe("token_distributor_litesvm", () => {
 
       // Build the instruction
       const ix = await program.methods
-        .createDistributor(totalAmount)
+        .createDistributor(totalAmount, null, null, null, null, null)
         .accounts({
           ownerNonce: ownerNoncePda,
           distributor: distributorPda2022,
@@ -743,7 +739,7 @@ describe("token_distributor_litesvm", () => {
 
       console.log("Token 2022 Distributor account data:", {
         owner: fetchedDistributorAccount.owner.toString(),
-        operator: fetchedDistributorAccount.operator.toString(),
+        operator: fetchedDistributorAccount.operators[0].toString(),
         tokenMint: fetchedDistributorAccount.tokenMint.toString(),
         initialTotalAmount: fetchedDistributorAccount.initialTotalAmount.toString(),
         totalClaimed: fetchedDistributorAccount.totalClaimed.toString(),
@@ -752,7 +748,7 @@ describe("token_distributor_litesvm", () => {
 
       // Basic verification
       expect(fetchedDistributorAccount.owner.toString()).to.equal(owner.publicKey.toString());
-      expect(fetchedDistributorAccount.operator.toString()).to.equal(operator.publicKey.toString());
+      expect(fetchedDistributorAccount.operators[0].toString()).to.equal(operator.publicKey.toString());
       expect(fetchedDistributorAccount.tokenMint.toString()).to.equal(tokenMint2022.toString());
       expect(fetchedDistributorAccount.initialTotalAmount.toString()).to.equal(totalAmount.toString());
       expect(fetchedDistributorAccount.totalClaimed.toString()).to.equal("0"); // Should be 0 initially
@@ -769,14 +765,14 @@ describe("token_distributor_litesvm", () => {
     try {
       console.log("Setting merkle root using test data...");
 
-      console.log("Generated merkle root:", testMerkleRoot);
-      console.log("Merkle root length:", testMerkleRoot.length);
+      console.log("Generated merkle root:", merkleTreeFor(distributorPda).getMerkleRoot());
+      console.log("Merkle root length:", merkleTreeFor(distributorPda).getMerkleRoot().length);
 
       console.log("Setting merkle root for SPL Token distributor (nonce 1)...");
 
       // Build the instruction for nonce 1 (SPL Token)
       const ix1 = await program.methods
-        .setMerkleRoot(testMerkleRoot)
+        .setMerkleRoot(merkleTreeFor(distributorPda).getMerkleRoot())
         .accounts({
           distributor: distributorPda,
           operator: operator.publicKey,
@@ -811,7 +807,7 @@ describe("token_distributor_litesvm", () => {
 
       // Build the instruction for nonce 2 (Token 2022)
       const ix2 = await program.methods
-        .setMerkleRoot(testMerkleRoot)
+        .setMerkleRoot(merkleTreeFor(distributorPda2022).getMerkleRoot())
         .accounts({
           distributor: distributorPda2022,
           operator: operator.publicKey,
@@ -850,8 +846,8 @@ describe("token_distributor_litesvm", () => {
       console.log("Merkle root set for nonce 2:", distributorAccount2022.merkleRoot);
 
       // Verify merkle root matches what we set
-      expect(distributorAccount1.merkleRoot).to.deep.equal(testMerkleRoot);
-      expect(distributorAccount2022.merkleRoot).to.deep.equal(testMerkleRoot);
+      expect(distributorAccount1.merkleRoot).to.deep.equal(merkleTreeFor(distributorPda).getMerkleRoot());
+      expect(distributorAccount2022.merkleRoot).to.deep.equal(merkleTreeFor(distributorPda2022).getMerkleRoot());
 
       console.log("✅ Set merkle root test passed for both distributors!");
     } catch (error) {
@@ -1172,7 +1168,7 @@ describe("token_distributor_litesvm", () => {
       console.log("  Amount:", claimAmount.toString());
 
       // Generate proof for claimant1
-      const proof = testMerkleTree.getProof(claimIndex);
+      const proof = merkleTreeFor(distributorPda).getProof(claimIndex);
       console.log("Generated proof length:", proof.length);
 
       // Convert proof to the format expected by the program
@@ -1245,16 +1241,21 @@ describe("token_distributor_litesvm", () => {
 
       // Build the claim instruction
       const claimIx = await program.methods
-        .claim(claimAmount, proofArray)
+        .claim(claimAmount, proofArray, null, null, null)
         .accounts({
           distributor: distributorPda,
           claimStatus: claimStatusPda,
           tokenVault: tokenVaultPda,
+          sharedVault: null,
           claimantTokenAccount: claimant1TokenAccount,
           tokenMint: tokenMint,
           claimant: claimant1.publicKey,
+          payer: claimant1.publicKey,
           systemProgram: SystemProgram.programId,
           tokenProgram: TOKEN_PROGRAM_ID,
+          memoProgram: null,
+          protocolFeeAccount: null,
+          gateTokenAccount: null,
         })
         .instruction();
 
@@ -1316,16 +1317,21 @@ describe("token_distributor_litesvm", () => {
       try {
         // Build the double claim instruction
         const doubleClaimIx = await program.methods
-          .claim(claimAmount, proofArray)
+          .claim(claimAmount, proofArray, null, null, null)
           .accounts({
             distributor: distributorPda,
             claimStatus: claimStatusPda,
             tokenVault: tokenVaultPda,
+            sharedVault: null,
             claimantTokenAccount: claimant1TokenAccount,
             tokenMint: tokenMint,
             claimant: claimant1.publicKey,
+            payer: claimant1.publicKey,
             systemProgram: SystemProgram.programId,
             tokenProgram: TOKEN_PROGRAM_ID,
+            memoProgram: null,
+            protocolFeeAccount: null,
+            gateTokenAccount: null,
           })
           .instruction();
 
@@ -1371,7 +1377,7 @@ describe("token_distributor_litesvm", () => {
       console.log("  Amount:", claimAmount.toString());
 
       // Generate proof for claimant2
-      const proof = testMerkleTree.getProof(claimIndex);
+      const proof = merkleTreeFor(distributorPda2022).getProof(claimIndex);
       console.log("Generated proof length:", proof.length);
 
       // Convert proof to the format expected by the program
@@ -1449,16 +1455,21 @@ describe("token_distributor_litesvm", () => {
 
       // Build the claim instruction
       const claimIx = await program.methods
-        .claim(claimAmount, proofArray)
+        .claim(claimAmount, proofArray, null, null, null)
         .accounts({
           distributor: distributorPda2022,
           claimStatus: claimStatusPda,
           tokenVault: tokenVaultPda2022,
+          sharedVault: null,
           claimantTokenAccount: claimant2TokenAccount,
           tokenMint: tokenMint2022,
           claimant: claimant2.publicKey,
+          payer: claimant2.publicKey,
           systemProgram: SystemProgram.programId,
           tokenProgram: TOKEN_2022_PROGRAM_ID,
+          memoProgram: null,
+          protocolFeeAccount: null,
+          gateTokenAccount: null,
         })
         .instruction();
 
@@ -1548,7 +1559,7 @@ describe("token_distributor_litesvm", () => {
       console.log("Creating distributor for withdraw test (nonce", nextnonce, ")...");
 
       const createIx = await program.methods
-        .createDistributor(totalAmount)
+        .createDistributor(totalAmount, null, null, null, null, null)
         .accounts({
           ownerNonce: ownerNoncePda,
           distributor: withdrawTestDistributorPda,
@@ -1671,7 +1682,7 @@ describe("token_distributor_litesvm", () => {
       console.log("Creating distributor for withdraw test (nonce", nextnonce, ")...");
 
       const createIx = await program.methods
-        .createDistributor(totalAmount)
+        .createDistributor(totalAmount, null, null, null, null, null)
         .accounts({
           ownerNonce: ownerNoncePda,
           distributor: withdrawTestDistributorPda2022,
@@ -1816,7 +1827,7 @@ describe("token_distributor_litesvm", () => {
 
         // Create distributor
         const createIx = await program.methods
-          .createDistributor(totalAmount)
+          .createDistributor(totalAmount, null, null, null, null, null)
           .accounts({
             ownerNonce: ownerNoncePda,
             distributor: delayedDistributorPda,
@@ -1842,7 +1853,7 @@ describe("token_distributor_litesvm", () => {
 
         // Set merkle root
         const setMerkleIx = await program.methods
-          .setMerkleRoot(testMerkleRoot)
+          .setMerkleRoot(merkleTreeFor(delayedDistributorPda).getMerkleRoot())
           .accounts({
             distributor: delayedDistributorPda,
             operator: operator.publicKey,
@@ -1915,7 +1926,7 @@ describe("token_distributor_litesvm", () => {
 
         const claimIndex = 0;
         const claimAmount = testTreeNodes[claimIndex].amount;
-        const proof = testMerkleTree.getProof(claimIndex);
+        const proof = merkleTreeFor(delayedDistributorPda).getProof(claimIndex);
         const proofArray: number[][] = proof.map((p) => Array.from(p));
 
         // Verify current time is before start time
@@ -1929,16 +1940,21 @@ describe("token_distributor_litesvm", () => {
 
         // Try to claim (should fail)
         const claimIx = await program.methods
-          .claim(claimAmount, proofArray)
+          .claim(claimAmount, proofArray, null, null, null)
           .accounts({
             distributor: delayedDistributorPda,
             claimStatus: delayedClaimStatusPda,
             tokenVault: delayedTokenVaultPda,
+            sharedVault: null,
             claimantTokenAccount: delayedClaimant1TokenAccount,
             tokenMint: tokenMint,
             claimant: claimant1.publicKey,
+            payer: claimant1.publicKey,
             systemProgram: SystemProgram.programId,
             tokenProgram: TOKEN_PROGRAM_ID,
+            memoProgram: null,
+            protocolFeeAccount: null,
+            gateTokenAccount: null,
           })
           .instruction();
 
@@ -1984,7 +2000,7 @@ describe("token_distributor_litesvm", () => {
 
         const claimIndex = 0;
         const claimAmount = testTreeNodes[claimIndex].amount;
-        const proof = testMerkleTree.getProof(claimIndex);
+        const proof = merkleTreeFor(delayedDistributorPda).getProof(claimIndex);
         const proofArray: number[][] = proof.map((p) => Array.from(p));
 
         // Get initial balances
@@ -2006,16 +2022,21 @@ describe("token_distributor_litesvm", () => {
 
         // Execute claim
         const claimIx = await program.methods
-          .claim(claimAmount, proofArray)
+          .claim(claimAmount, proofArray, null, null, null)
           .accounts({
             distributor: delayedDistributorPda,
             claimStatus: delayedClaimStatusPda,
             tokenVault: delayedTokenVaultPda,
+            sharedVault: null,
             claimantTokenAccount: delayedClaimant1TokenAccount,
             tokenMint: tokenMint,
             claimant: claimant1.publicKey,
+            payer: claimant1.publicKey,
             systemProgram: SystemProgram.programId,
             tokenProgram: TOKEN_PROGRAM_ID,
+            memoProgram: null,
+            protocolFeeAccount: null,
+            gateTokenAccount: null,
           })
           .instruction();
 
@@ -2089,6 +2110,7 @@ describe("token_distributor_litesvm", () => {
             distributorKey: delayedDistributorPda,
             claimStatus: delayedClaimStatusPda,
             claimant: claimant1.publicKey,
+            rentPayer: claimant1.publicKey,
           })
           .instruction();
 
@@ -2192,6 +2214,7 @@ describe("token_distributor_litesvm", () => {
             distributorKey: delayedDistributorPda,
             claimStatus: delayedClaimStatusPda,
             claimant: claimant1.publicKey,
+            rentPayer: claimant1.publicKey,
           })
           .instruction();
 
@@ -2313,7 +2336,7 @@ describe("token_distributor_litesvm", () => {
 
         // Create distributor
         const createIx = await program.methods
-          .createDistributor(totalAmount)
+          .createDistributor(totalAmount, null, null, null, null, null)
           .accounts({
             ownerNonce: ownerNoncePda,
             distributor: delayedDistributorPda2022,
@@ -2339,7 +2362,7 @@ describe("token_distributor_litesvm", () => {
 
         // Set merkle root
         const setMerkleIx = await program.methods
-          .setMerkleRoot(testMerkleRoot)
+          .setMerkleRoot(merkleTreeFor(delayedDistributorPda2022).getMerkleRoot())
           .accounts({
             distributor: delayedDistributorPda2022,
             operator: operator.publicKey,
@@ -2412,7 +2435,7 @@ describe("token_distributor_litesvm", () => {
 
         const claimIndex = 1;
         const claimAmount = testTreeNodes[claimIndex].amount;
-        const proof = testMerkleTree.getProof(claimIndex);
+        const proof = merkleTreeFor(delayedDistributorPda2022).getProof(claimIndex);
         const proofArray: number[][] = proof.map((p) => Array.from(p));
 
         // Verify current time is before start time
@@ -2424,16 +2447,21 @@ describe("token_distributor_litesvm", () => {
 
         // Try to claim (should fail)
         const claimIx = await program.methods
-          .claim(claimAmount, proofArray)
+          .claim(claimAmount, proofArray, null, null, null)
           .accounts({
             distributor: delayedDistributorPda2022,
             claimStatus: delayedClaimStatusPda2022,
             tokenVault: delayedTokenVaultPda2022,
+            sharedVault: null,
             claimantTokenAccount: delayedClaimant2TokenAccount2022,
             tokenMint: tokenMint2022,
             claimant: claimant2.publicKey,
+            payer: claimant2.publicKey,
             systemProgram: SystemProgram.programId,
             tokenProgram: TOKEN_2022_PROGRAM_ID,
+            memoProgram: null,
+            protocolFeeAccount: null,
+            gateTokenAccount: null,
           })
           .instruction();
 
@@ -2479,7 +2507,7 @@ describe("token_distributor_litesvm", () => {
 
         const claimIndex = 1;
         const claimAmount = testTreeNodes[claimIndex].amount;
-        const proof = testMerkleTree.getProof(claimIndex);
+        const proof = merkleTreeFor(delayedDistributorPda2022).getProof(claimIndex);
         const proofArray: number[][] = proof.map((p) => Array.from(p));
 
         // Get initial balances
@@ -2501,16 +2529,21 @@ describe("token_distributor_litesvm", () => {
 
         // Execute claim
         const claimIx = await program.methods
-          .claim(claimAmount, proofArray)
+          .claim(claimAmount, proofArray, null, null, null)
           .accounts({
             distributor: delayedDistributorPda2022,
             claimStatus: delayedClaimStatusPda2022,
             tokenVault: delayedTokenVaultPda2022,
+            sharedVault: null,
             claimantTokenAccount: delayedClaimant2TokenAccount2022,
             tokenMint: tokenMint2022,
             claimant: claimant2.publicKey,
+            payer: claimant2.publicKey,
             systemProgram: SystemProgram.programId,
             tokenProgram: TOKEN_2022_PROGRAM_ID,
+            memoProgram: null,
+            protocolFeeAccount: null,
+            gateTokenAccount: null,
           })
           .instruction();
 
@@ -2584,6 +2617,7 @@ describe("token_distributor_litesvm", () => {
             distributorKey: delayedDistributorPda2022,
             claimStatus: delayedClaimStatusPda2022,
             claimant: claimant2.publicKey,
+            rentPayer: claimant2.publicKey,
           })
           .instruction();
 
@@ -2689,6 +2723,7 @@ describe("token_distributor_litesvm", () => {
             distributorKey: delayedDistributorPda2022,
             claimStatus: delayedClaimStatusPda2022,
             claimant: claimant2.publicKey,
+            rentPayer: claimant2.publicKey,
           })
           .instruction();
 
@@ -5,6 +5,8 @@ import { sha256 } from "@noble/hashes/sha256";
 export interface TreeNode {
   claimant: PublicKey;
   amount: anchor.BN;
+  // Per-user unlock time (tree version 2 only)
+  unlockTime?: anchor.BN;
   proof?: Buffer[];
 }
 
@@ -14,19 +16,25 @@ function hashv(data: Buffer[]): Buffer {
   return Buffer.from(sha256(combined));
 }
 
+// Domain separators (tree versions 1 and 2)
+const LEAF_PREFIX = Buffer.from([0]);
+const NODE_PREFIX = Buffer.from([1]);
+
 export class SimpleMerkleTree {
   private leaves: Buffer[];
   private nodes: Buffer[];
   private leafCount: number;
+  private distributor: PublicKey;
 
-  constructor(treeNodes: TreeNode[]) {
+  constructor(treeNodes: TreeNode[], distributor: PublicKey) {
     this.leafCount = treeNodes.length;
     this.leaves = [];
     this.nodes = [];
+    this.distributor = distributor;
 
     // Generate leaf hashes
     for (const node of treeNodes) {
-      const leafHash = this.hashLeaf(node.claimant, node.amount);
+      const leafHash = this.hashLeaf(node.claimant, node.amount, node.unlockTime);
       this.leaves.push(leafHash);
       this.nodes.push(leafHash);
     }
@@ -35,17 +43,22 @@ export class SimpleMerkleTree {
     this.buildTree();
   }
 
-  private hashLeaf(claimant: PublicKey, amount: anchor.BN): Buffer {
-    // Hash leaf without prefix
-    return hashv([claimant.toBuffer(), Buffer.from(amount.toArray("le", 8))]);
+  private hashLeaf(claimant: PublicKey, amount: anchor.BN, unlockTime?: anchor.BN): Buffer {
+    // Hash leaf with leaf prefix, bound to the distributor
+    const data = [LEAF_PREFIX, this.distributor.toBuffer(), claimant.toBuffer(), Buffer.from(amount.toArray("le", 8))];
+    if (unlockTime !== undefined) {
+      // Tree version 2 appends the per-user unlock time
+      data.push(Buffer.from(unlockTime.toTwos(64).toArray("le", 8)));
+    }
+    return hashv(data);
   }
 
   private hashIntermediate(left: Buffer, right: Buffer): Buffer {
-    // Hash intermediate nodes without prefix, using lexicographic ordering
+    // Hash intermediate nodes with node prefix, using lexicographic ordering
     if (left.compare(right) <= 0) {
-      return hashv([left, right]);
+      return hashv([NODE_PREFIX, left, right]);
     } else {
-      return hashv([right, left]);
+      return hashv([NODE_PREFIX, right, left]);
     }
   }
 
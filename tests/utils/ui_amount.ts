@@ -0,0 +1,62 @@
+import * as anchor from "@coral-xyz/anchor";
+
+// Mirrors the `AmountScaling` struct reported in program events
+export interface InterestBearingScaling {
+  initializationTimestamp: anchor.BN;
+  preUpdateAverageRate: number;
+  lastUpdateTimestamp: anchor.BN;
+  currentRate: number;
+}
+
+export interface ScaledUiAmountScaling {
+  multiplier: number;
+  newMultiplierEffectiveTimestamp: anchor.BN;
+  newMultiplier: number;
+}
+
+export interface AmountScaling {
+  decimals: number;
+  interestBearing: InterestBearingScaling | null;
+  scaledUiAmount: ScaledUiAmountScaling | null;
+}
+
+// Same constants as the Token 2022 interest-bearing extension
+const SECONDS_PER_YEAR = 60 * 60 * 24 * 365.24;
+const ONE_IN_BASIS_POINTS = 10_000;
+
+// Total UI multiplier of a mint at the given unix timestamp
+export function uiMultiplier(scaling: AmountScaling, unixTimestamp: number): number {
+  let multiplier = 1;
+
+  const interest = scaling.interestBearing;
+  if (interest) {
+    const initialized = interest.initializationTimestamp.toNumber();
+    const lastUpdate = interest.lastUpdateTimestamp.toNumber();
+    const preUpdateExp = Math.exp(
+      (interest.preUpdateAverageRate * (lastUpdate - initialized)) / SECONDS_PER_YEAR / ONE_IN_BASIS_POINTS
+    );
+    const postUpdateExp = Math.exp(
+      (interest.currentRate * (unixTimestamp - lastUpdate)) / SECONDS_PER_YEAR / ONE_IN_BASIS_POINTS
+    );
+    multiplier *= preUpdateExp * postUpdateExp;
+  }
+
+  const scaled = scaling.scaledUiAmount;
+  if (scaled) {
+    multiplier *=
+      unixTimestamp >= scaled.newMultiplierEffectiveTimestamp.toNumber() ? scaled.newMultiplier : scaled.multiplier;
+  }
+
+  return multiplier;
+}
+
+// Convert a raw amount (as reported in events) to a UI amount
+export function rawToUiAmount(amount: anchor.BN, scaling: AmountScaling, unixTimestamp: number): number {
+  return (amount.toNumber() * uiMultiplier(scaling, unixTimestamp)) / 10 ** scaling.decimals;
+}
+
+// Convert a UI amount to the raw amount, rounding down
+export function uiToRawAmount(uiAmount: number, scaling: AmountScaling, unixTimestamp: number): anchor.BN {
+  const raw = Math.floor((uiAmount * 10 ** scaling.decimals) / uiMultiplier(scaling, unixTimestamp));
+  return new anchor.BN(raw.toString());
+}